@@ -0,0 +1,340 @@
+//! Contains the [`Checker`], which verifies the steps of a proof one by one.
+use std::collections::HashMap;
+use std::num::NonZero;
+
+use anyhow::bail;
+use anyhow::Context;
+use drcp_format::reader::ReadStep;
+use drcp_format::steps::Conclusion;
+use drcp_format::steps::Inference;
+use drcp_format::steps::Nogood;
+use drcp_format::steps::Step;
+use drcp_format::steps::StepId;
+use drcp_format::AtomicConstraint;
+use drcp_format::Comparison;
+
+use crate::domains::Atomic;
+use crate::domains::Domains;
+use crate::inferences;
+use crate::inferences::Verdict;
+use crate::model::Direction;
+use crate::model::Model;
+
+/// An atomic constraint as it is read from the proof, or the literal if it has no definition.
+pub(crate) type ProofAtomic = Result<AtomicConstraint<String>, NonZero<i32>>;
+
+/// The statistics of a checked proof.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Statistics {
+    /// The number of inferences in the proof.
+    pub num_inferences: u64,
+    /// The number of inferences which are validated against the model.
+    pub num_validated_inferences: u64,
+    /// The first inference which could not be validated against the model.
+    pub first_unchecked_inference: Option<StepId>,
+    /// The number of nogoods in the proof.
+    pub num_nogoods: u64,
+    /// The number of deletions in the proof.
+    pub num_deletions: u64,
+}
+
+impl Statistics {
+    /// The number of inferences which could not be validated against the model.
+    pub fn num_unchecked_inferences(&self) -> u64 {
+        self.num_inferences - self.num_validated_inferences
+    }
+}
+
+/// Verifies the steps of a proof for a model.
+///
+/// Every step which is checked is stored as a clause, which can be used as a hint by later nogood
+/// steps. An inference `premises -> propagated` is stored as the clause `!premises \/ propagated`.
+#[derive(Debug)]
+pub(crate) struct Checker {
+    model: Model,
+    clauses: HashMap<StepId, Vec<Atomic>>,
+    has_derived_empty_nogood: bool,
+    statistics: Statistics,
+}
+
+impl Checker {
+    pub(crate) fn new(model: Model) -> Checker {
+        Checker {
+            model,
+            clauses: HashMap::new(),
+            has_derived_empty_nogood: false,
+            statistics: Statistics::default(),
+        }
+    }
+
+    pub(crate) fn statistics(&self) -> Statistics {
+        self.statistics
+    }
+
+    /// Prepare checking a proof which ends in the given conclusion.
+    ///
+    /// When the proof claims optimality, the objective bound is the value of the best solution.
+    /// The proof then derives that there is no solution with a strictly better objective value,
+    /// which is assumed in the initial domains of all subsequent checks.
+    pub(crate) fn set_conclusion(
+        &mut self,
+        conclusion: &Conclusion<ProofAtomic>,
+    ) -> anyhow::Result<()> {
+        let Conclusion::Optimal(bound) = conclusion else {
+            return Ok(());
+        };
+
+        let bound = self.atomic(bound)?;
+
+        let Some(objective) = self.model.objective() else {
+            bail!("the proof claims optimality, but the model has no objective");
+        };
+
+        if bound.variable != objective.variable {
+            bail!("the conclusion is not a bound on the objective");
+        }
+
+        // The conclusion is a dual bound, so it has to bound the objective in the direction
+        // opposite to the optimisation direction.
+        let improving_solutions = match (objective.direction, bound.comparison) {
+            (Direction::Minimise, Comparison::GreaterThanEqual) => {
+                (Comparison::LessThanEqual, bound.value - 1)
+            }
+            (Direction::Maximise, Comparison::LessThanEqual) => {
+                (Comparison::GreaterThanEqual, bound.value + 1)
+            }
+            _ => bail!("the conclusion does not bound the objective in the optimisation direction"),
+        };
+
+        let objective_domain = &mut self.model.initial_domains_mut()[objective.variable.0];
+        objective_domain.apply(improving_solutions.0, improving_solutions.1);
+
+        // If no value of the objective improves on the bound, the claim holds without any
+        // reasoning.
+        if objective_domain.is_empty() {
+            self.has_derived_empty_nogood = true;
+        }
+
+        Ok(())
+    }
+
+    /// Check the next step in the proof.
+    pub(crate) fn check_step(&mut self, step: ReadStep<'_, ProofAtomic>) -> anyhow::Result<()> {
+        match step {
+            Step::Inference(inference) => {
+                let id = inference.id;
+                self.check_inference(inference)
+                    .with_context(|| format!("invalid inference {id}"))
+            }
+            Step::Nogood(nogood) => {
+                let id = nogood.id;
+                self.check_nogood(nogood)
+                    .with_context(|| format!("invalid nogood {id}"))
+            }
+            Step::Delete(deletion) => {
+                self.statistics.num_deletions += 1;
+
+                if self.clauses.remove(&deletion.id).is_none() {
+                    bail!("deleted step {} is not active", deletion.id);
+                }
+
+                Ok(())
+            }
+            Step::Conclusion(_) => self.check_conclusion(),
+        }
+    }
+
+    fn check_inference(
+        &mut self,
+        inference: Inference<'_, Vec<ProofAtomic>, ProofAtomic>,
+    ) -> anyhow::Result<()> {
+        self.statistics.num_inferences += 1;
+
+        let premises = inference
+            .premises
+            .iter()
+            .map(|premise| self.atomic(premise))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let propagated = inference
+            .propagated
+            .as_ref()
+            .map(|propagated| self.atomic(propagated))
+            .transpose()?;
+
+        let mut domains = Domains::new(self.model.initial_domains());
+        let is_consistent = premises
+            .iter()
+            .copied()
+            .chain(propagated.map(|propagated| !propagated))
+            .all(|atomic| domains.apply(atomic));
+
+        let verdict = if !is_consistent {
+            // The premises and the negated conclusion already contradict the initial domains.
+            Verdict::Conflict
+        } else if let Some(tag) = inference.hint_constraint_id {
+            let constraint = self
+                .model
+                .constraint(tag)
+                .with_context(|| format!("constraint {tag} does not exist"))?;
+
+            inferences::check_constraint(&self.model, constraint, inference.hint_label, &domains)?
+        } else {
+            Verdict::Unsupported
+        };
+
+        match verdict {
+            Verdict::Conflict => self.statistics.num_validated_inferences += 1,
+            Verdict::NoConflict => bail!("the inference is not implied by its constraint"),
+            Verdict::Unsupported => {
+                let _ = self
+                    .statistics
+                    .first_unchecked_inference
+                    .get_or_insert(inference.id);
+            }
+        }
+
+        let clause = premises
+            .into_iter()
+            .map(|premise| !premise)
+            .chain(propagated)
+            .collect();
+        let _ = self.clauses.insert(inference.id, clause);
+
+        Ok(())
+    }
+
+    fn check_nogood(
+        &mut self,
+        nogood: Nogood<Vec<ProofAtomic>, Vec<StepId>>,
+    ) -> anyhow::Result<()> {
+        self.statistics.num_nogoods += 1;
+
+        let clause = nogood
+            .literals
+            .iter()
+            .map(|literal| self.atomic(literal))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        if !self.is_implied_by_rup(&clause, nogood.hints.as_deref())? {
+            bail!("the nogood is not implied by reverse unit propagation");
+        }
+
+        if clause.is_empty() {
+            self.has_derived_empty_nogood = true;
+        }
+
+        let _ = self.clauses.insert(nogood.id, clause);
+
+        Ok(())
+    }
+
+    fn check_conclusion(&self) -> anyhow::Result<()> {
+        if self.has_derived_empty_nogood || self.is_implied_by_rup(&[], None)? {
+            Ok(())
+        } else {
+            bail!("the proof does not derive the empty nogood")
+        }
+    }
+
+    /// Determine whether the clause is implied by reverse unit propagation; i.e. whether unit
+    /// propagation on the hints (or all active steps if there are no hints) leads to a conflict
+    /// when the clause is falsified.
+    fn is_implied_by_rup(
+        &self,
+        clause: &[Atomic],
+        hints: Option<&[StepId]>,
+    ) -> anyhow::Result<bool> {
+        let mut domains = Domains::new(self.model.initial_domains());
+
+        if !clause.iter().all(|&atomic| domains.apply(!atomic)) {
+            return Ok(true);
+        }
+
+        let clauses = match hints {
+            Some(hints) => hints
+                .iter()
+                .map(|hint| {
+                    self.clauses
+                        .get(hint)
+                        .map(Vec::as_slice)
+                        .with_context(|| format!("hint {hint} is not an active step"))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?,
+            None => self.clauses.values().map(Vec::as_slice).collect(),
+        };
+
+        Ok(propagate_until_conflict(&mut domains, &clauses))
+    }
+
+    fn atomic(&mut self, atomic: &ProofAtomic) -> anyhow::Result<Atomic> {
+        let atomic = atomic
+            .as_ref()
+            .map_err(|literal| anyhow::anyhow!("literal {literal} has no definition"))?;
+
+        let (name, comparison, value) = match atomic {
+            AtomicConstraint::Bool(atomic) => {
+                (&atomic.name, Comparison::Equal, atomic.value as i64)
+            }
+            AtomicConstraint::Int(atomic) => (&atomic.name, atomic.comparison, atomic.value),
+        };
+
+        let variable = self
+            .model
+            .variable(name)
+            .with_context(|| format!("unknown variable {name}"))?;
+
+        Ok(Atomic {
+            variable,
+            comparison,
+            value,
+        })
+    }
+}
+
+/// Apply unit propagation with the clauses until there is a conflict or no more atomics can be
+/// propagated. Returns whether a conflict was found.
+fn propagate_until_conflict(domains: &mut Domains<'_>, clauses: &[&[Atomic]]) -> bool {
+    loop {
+        let mut has_propagated = false;
+
+        for clause in clauses {
+            let mut unassigned = None;
+            let mut is_unit = true;
+
+            for &atomic in clause.iter() {
+                match domains.evaluate(atomic) {
+                    Some(true) => {
+                        is_unit = false;
+                        break;
+                    }
+                    Some(false) => {}
+                    None if unassigned.is_none() => unassigned = Some(atomic),
+                    None => {
+                        is_unit = false;
+                        break;
+                    }
+                }
+            }
+
+            if !is_unit {
+                continue;
+            }
+
+            match unassigned {
+                // Every atomic in the clause is false.
+                None => return true,
+                Some(atomic) => {
+                    if !domains.apply(atomic) {
+                        return true;
+                    }
+
+                    has_propagated = true;
+                }
+            }
+        }
+
+        if !has_propagated {
+            return false;
+        }
+    }
+}
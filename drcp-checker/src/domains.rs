@@ -0,0 +1,295 @@
+//! Contains the domains of the variables in the model, and the atomic constraints which restrict
+//! them.
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::ops::Not;
+
+use drcp_format::Comparison;
+
+/// Identifies a variable of the model.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) struct VariableId(pub(crate) usize);
+
+/// An atomic constraint over a variable of the model.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Atomic {
+    pub(crate) variable: VariableId,
+    pub(crate) comparison: Comparison,
+    pub(crate) value: i64,
+}
+
+impl Not for Atomic {
+    type Output = Atomic;
+
+    fn not(self) -> Self::Output {
+        let (comparison, value) = match self.comparison {
+            Comparison::GreaterThanEqual => (Comparison::LessThanEqual, self.value - 1),
+            Comparison::LessThanEqual => (Comparison::GreaterThanEqual, self.value + 1),
+            Comparison::Equal => (Comparison::NotEqual, self.value),
+            Comparison::NotEqual => (Comparison::Equal, self.value),
+        };
+
+        Atomic {
+            variable: self.variable,
+            comparison,
+            value,
+        }
+    }
+}
+
+/// The domain of an integer variable, which is represented by its bounds and the values between
+/// the bounds which are not in the domain.
+///
+/// The bounds are always kept tight; i.e. if the domain is not empty, then both bounds are in the
+/// domain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Domain {
+    lower_bound: i64,
+    upper_bound: i64,
+    holes: BTreeSet<i64>,
+}
+
+impl Domain {
+    /// Create the domain `lower_bound..=upper_bound`.
+    pub(crate) fn new(lower_bound: i64, upper_bound: i64) -> Domain {
+        Domain {
+            lower_bound,
+            upper_bound,
+            holes: BTreeSet::new(),
+        }
+    }
+
+    /// Create the domain which contains exactly the given values.
+    pub(crate) fn from_values(values: impl IntoIterator<Item = i64>) -> Domain {
+        let values = values.into_iter().collect::<BTreeSet<_>>();
+
+        let (Some(&lower_bound), Some(&upper_bound)) = (values.first(), values.last()) else {
+            return Domain::new(1, 0);
+        };
+
+        Domain {
+            lower_bound,
+            upper_bound,
+            holes: (lower_bound..=upper_bound)
+                .filter(|value| !values.contains(value))
+                .collect(),
+        }
+    }
+
+    pub(crate) fn lower_bound(&self) -> i64 {
+        self.lower_bound
+    }
+
+    pub(crate) fn upper_bound(&self) -> i64 {
+        self.upper_bound
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.lower_bound > self.upper_bound
+    }
+
+    pub(crate) fn is_fixed(&self) -> bool {
+        self.lower_bound == self.upper_bound
+    }
+
+    pub(crate) fn contains(&self, value: i64) -> bool {
+        self.lower_bound <= value && value <= self.upper_bound && !self.holes.contains(&value)
+    }
+
+    /// The number of values in the domain.
+    pub(crate) fn size(&self) -> u64 {
+        if self.is_empty() {
+            return 0;
+        }
+
+        let num_holes = self
+            .holes
+            .range(self.lower_bound..=self.upper_bound)
+            .count() as u64;
+        self.lower_bound.abs_diff(self.upper_bound) + 1 - num_holes
+    }
+
+    /// Iterate over the values in the domain in increasing order.
+    pub(crate) fn values(&self) -> impl Iterator<Item = i64> + '_ {
+        (self.lower_bound..=self.upper_bound).filter(|value| !self.holes.contains(value))
+    }
+
+    /// Find the smallest value in the domain which is at least `value`.
+    pub(crate) fn next_value(&self, value: i64) -> Option<i64> {
+        let mut candidate = value.max(self.lower_bound);
+
+        while self.holes.contains(&candidate) {
+            candidate += 1;
+        }
+
+        (candidate <= self.upper_bound).then_some(candidate)
+    }
+
+    /// Determine whether the domain has a value in common with `other`.
+    pub(crate) fn intersects(&self, other: &Domain) -> bool {
+        let mut candidate = self.lower_bound.max(other.lower_bound);
+
+        // Every iteration either finds a common value, or skips a hole of one of the domains.
+        // Hence, this loop terminates.
+        while let Some(value) = self.next_value(candidate) {
+            match other.next_value(value) {
+                Some(other_value) if other_value == value => return true,
+                Some(other_value) => candidate = other_value,
+                None => return false,
+            }
+        }
+
+        false
+    }
+
+    /// Determine the truth value of the atomic constraint `[x comparison value]` given this
+    /// domain for `x`, or [`None`] if it is not yet known.
+    pub(crate) fn evaluate(&self, comparison: Comparison, value: i64) -> Option<bool> {
+        match comparison {
+            Comparison::GreaterThanEqual if self.lower_bound >= value => Some(true),
+            Comparison::GreaterThanEqual if self.upper_bound < value => Some(false),
+            Comparison::LessThanEqual if self.upper_bound <= value => Some(true),
+            Comparison::LessThanEqual if self.lower_bound > value => Some(false),
+            Comparison::Equal if !self.contains(value) => Some(false),
+            Comparison::Equal if self.is_fixed() => Some(true),
+            Comparison::NotEqual if !self.contains(value) => Some(true),
+            Comparison::NotEqual if self.is_fixed() => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Restrict the domain such that the atomic constraint `[x comparison value]` holds for all
+    /// remaining values.
+    pub(crate) fn apply(&mut self, comparison: Comparison, value: i64) {
+        match comparison {
+            Comparison::GreaterThanEqual => self.lower_bound = self.lower_bound.max(value),
+            Comparison::LessThanEqual => self.upper_bound = self.upper_bound.min(value),
+            Comparison::Equal => {
+                if self.contains(value) {
+                    self.lower_bound = value;
+                    self.upper_bound = value;
+                } else {
+                    self.upper_bound = self.lower_bound - 1;
+                }
+            }
+            Comparison::NotEqual => {
+                if self.lower_bound < value && value < self.upper_bound {
+                    let _ = self.holes.insert(value);
+                } else if value == self.lower_bound {
+                    self.lower_bound += 1;
+                } else if value == self.upper_bound {
+                    self.upper_bound -= 1;
+                }
+            }
+        }
+
+        self.tighten_bounds();
+    }
+
+    /// Remove all values from the domain which are not in `other`.
+    pub(crate) fn intersect(&mut self, other: &Domain) {
+        self.lower_bound = self.lower_bound.max(other.lower_bound);
+        self.upper_bound = self.upper_bound.min(other.upper_bound);
+        self.holes.extend(
+            other
+                .holes
+                .range(self.lower_bound..=self.upper_bound.max(self.lower_bound)),
+        );
+
+        self.tighten_bounds();
+    }
+
+    fn tighten_bounds(&mut self) {
+        while self.lower_bound <= self.upper_bound && self.holes.contains(&self.lower_bound) {
+            self.lower_bound += 1;
+        }
+
+        while self.lower_bound <= self.upper_bound && self.holes.contains(&self.upper_bound) {
+            self.upper_bound -= 1;
+        }
+    }
+}
+
+/// The domains of the variables after applying a set of atomic constraints to the initial
+/// domains.
+///
+/// Only the domains which are changed are stored, which makes it cheap to create a fresh instance
+/// for every step in the proof.
+#[derive(Debug)]
+pub(crate) struct Domains<'a> {
+    initial_domains: &'a [Domain],
+    changed_domains: HashMap<VariableId, Domain>,
+}
+
+impl<'a> Domains<'a> {
+    pub(crate) fn new(initial_domains: &'a [Domain]) -> Domains<'a> {
+        Domains {
+            initial_domains,
+            changed_domains: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn get(&self, variable: VariableId) -> &Domain {
+        self.changed_domains
+            .get(&variable)
+            .unwrap_or(&self.initial_domains[variable.0])
+    }
+
+    /// Determine the truth value of the atomic constraint, or [`None`] if it is not yet known.
+    pub(crate) fn evaluate(&self, atomic: Atomic) -> Option<bool> {
+        self.get(atomic.variable)
+            .evaluate(atomic.comparison, atomic.value)
+    }
+
+    /// Restrict the domains such that the atomic constraint holds.
+    ///
+    /// Returns `false` if the domain of the variable becomes empty.
+    pub(crate) fn apply(&mut self, atomic: Atomic) -> bool {
+        let domain = self
+            .changed_domains
+            .entry(atomic.variable)
+            .or_insert_with(|| self.initial_domains[atomic.variable.0].clone());
+
+        domain.apply(atomic.comparison, atomic.value);
+        !domain.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removing_a_bound_skips_holes() {
+        let mut domain = Domain::from_values([1, 3, 4, 7]);
+        domain.apply(Comparison::NotEqual, 1);
+
+        assert_eq!(3, domain.lower_bound());
+        assert_eq!(3, domain.size());
+
+        domain.apply(Comparison::LessThanEqual, 6);
+        assert_eq!(4, domain.upper_bound());
+        assert_eq!(vec![3, 4], domain.values().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn atomics_are_evaluated_on_the_domain() {
+        let domain = Domain::from_values([2, 4, 6]);
+
+        assert_eq!(Some(true), domain.evaluate(Comparison::GreaterThanEqual, 2));
+        assert_eq!(None, domain.evaluate(Comparison::GreaterThanEqual, 3));
+        assert_eq!(Some(false), domain.evaluate(Comparison::LessThanEqual, 1));
+        assert_eq!(Some(false), domain.evaluate(Comparison::Equal, 3));
+        assert_eq!(Some(true), domain.evaluate(Comparison::NotEqual, 5));
+        assert_eq!(None, domain.evaluate(Comparison::Equal, 4));
+    }
+
+    #[test]
+    fn domains_with_interleaved_holes_do_not_intersect() {
+        let odd = Domain::from_values([1, 3, 5]);
+        let even = Domain::from_values([0, 2, 4, 6]);
+
+        assert!(!odd.intersects(&even));
+        assert!(odd.intersects(&Domain::new(5, 10)));
+    }
+}
@@ -0,0 +1,85 @@
+//! Checks the all-different constraint by computing a maximum matching between the variables and
+//! the values in their domains.
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use super::term_domain;
+use crate::domains::Domain;
+use crate::domains::Domains;
+use crate::model::Term;
+
+pub(super) fn is_conflicting(terms: &[Term], domains: &Domains<'_>) -> bool {
+    // By Hall's theorem, there is no matching if and only if there is a set of variables whose
+    // domains together contain fewer values than the number of variables in the set. A variable
+    // with at least `terms.len()` values can never be part of such a set, so it can be ignored.
+    // This also bounds the number of values which are considered.
+    let domains = terms
+        .iter()
+        .map(|&term| term_domain(term, domains))
+        .filter(|domain| domain.size() < terms.len() as u64)
+        .collect::<Vec<_>>();
+
+    let mut matched_variables = HashMap::new();
+
+    domains.iter().enumerate().any(|(variable, _)| {
+        let mut visited_values = HashSet::new();
+        !find_augmenting_path(
+            variable,
+            &domains,
+            &mut matched_variables,
+            &mut visited_values,
+        )
+    })
+}
+
+/// Try to match `variable` to a value, possibly by re-matching other variables.
+fn find_augmenting_path(
+    variable: usize,
+    domains: &[impl AsRef<Domain>],
+    matched_variables: &mut HashMap<i64, usize>,
+    visited_values: &mut HashSet<i64>,
+) -> bool {
+    for value in domains[variable].as_ref().values() {
+        if !visited_values.insert(value) {
+            continue;
+        }
+
+        let can_match = match matched_variables.get(&value) {
+            None => true,
+            Some(&other) => find_augmenting_path(other, domains, matched_variables, visited_values),
+        };
+
+        if can_match {
+            let _ = matched_variables.insert(value, variable);
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::VariableId;
+
+    #[test]
+    fn pigeonhole_is_a_conflict() {
+        let initial_domains = [Domain::new(1, 2), Domain::new(1, 2), Domain::new(1, 10)];
+        let domains = Domains::new(&initial_domains);
+
+        let terms = [
+            Term::Variable(VariableId(0)),
+            Term::Variable(VariableId(1)),
+            Term::Variable(VariableId(2)),
+        ];
+        assert!(!is_conflicting(&terms, &domains));
+
+        let terms = [
+            Term::Variable(VariableId(0)),
+            Term::Variable(VariableId(1)),
+            Term::Constant(2),
+        ];
+        assert!(is_conflicting(&terms, &domains));
+    }
+}
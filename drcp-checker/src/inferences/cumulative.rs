@@ -0,0 +1,180 @@
+//! Checks the cumulative constraint using time-table reasoning.
+//!
+//! Every task has a mandatory part, which is the interval `[ub(s), lb(s) + d)` in which it is
+//! executed regardless of its start time. The constraint is in conflict if there is a task which
+//! cannot start at any time in its domain without exceeding the capacity when combined with the
+//! mandatory parts of the other tasks. For variable durations, resource usages and capacity, the
+//! bounds which are least restrictive are used.
+use super::term_bounds;
+use super::term_domain;
+use crate::domains::Domains;
+use crate::model::Term;
+
+/// The label of the inferences which are derived by time-table reasoning.
+pub(super) const TIME_TABLE_LABEL: &str = "time_table";
+
+/// The mandatory part of a task, in which it uses `resource_usage` of the resource.
+#[derive(Clone, Copy, Debug)]
+struct MandatoryPart {
+    task: usize,
+    start: i64,
+    end: i64,
+    resource_usage: i64,
+}
+
+pub(super) fn is_conflicting(
+    start_times: &[Term],
+    durations: &[Term],
+    resource_usages: &[Term],
+    capacity: Term,
+    domains: &Domains<'_>,
+) -> bool {
+    let (_, capacity) = term_bounds(capacity, domains);
+    let duration = |task: usize| term_bounds(durations[task], domains).0;
+    let resource_usage = |task: usize| term_bounds(resource_usages[task], domains).0;
+
+    let mandatory_parts = (0..start_times.len())
+        .filter_map(|task| {
+            let (earliest_start, latest_start) = term_bounds(start_times[task], domains);
+
+            let part = MandatoryPart {
+                task,
+                start: latest_start,
+                end: earliest_start + duration(task),
+                resource_usage: resource_usage(task),
+            };
+            (part.start < part.end && part.resource_usage > 0).then_some(part)
+        })
+        .collect::<Vec<_>>();
+
+    (0..start_times.len()).any(|task| {
+        if duration(task) <= 0 || resource_usage(task) <= 0 {
+            return false;
+        }
+
+        let forbidden_starts = forbidden_start_times(
+            &mandatory_parts,
+            task,
+            duration(task),
+            capacity - resource_usage(task),
+        );
+
+        // Find a start time in the domain which is not forbidden.
+        let start_domain = term_domain(start_times[task], domains);
+        let mut candidate = start_domain.next_value(start_domain.lower_bound());
+
+        while let Some(start) = candidate {
+            match forbidden_starts
+                .iter()
+                .find(|&&(from, to)| from <= start && start <= to)
+            {
+                Some(&(_, to)) => candidate = start_domain.next_value(to + 1),
+                None => return false,
+            }
+        }
+
+        true
+    })
+}
+
+/// Compute the intervals of start times at which `task` overlaps with a time point where the
+/// mandatory parts of the other tasks use more than `remaining_capacity`.
+fn forbidden_start_times(
+    mandatory_parts: &[MandatoryPart],
+    task: usize,
+    duration: i64,
+    remaining_capacity: i64,
+) -> Vec<(i64, i64)> {
+    if remaining_capacity < 0 {
+        // The task cannot be scheduled at all.
+        return vec![(i64::MIN, i64::MAX - 1)];
+    }
+
+    let mut events = mandatory_parts
+        .iter()
+        .filter(|part| part.task != task)
+        .flat_map(|part| {
+            [
+                (part.start, part.resource_usage),
+                (part.end, -part.resource_usage),
+            ]
+        })
+        .collect::<Vec<_>>();
+    events.sort_unstable();
+
+    let mut forbidden_starts = Vec::new();
+    let mut height = 0;
+
+    for (index, &(time, change)) in events.iter().enumerate() {
+        height += change;
+
+        let Some(&(next_time, _)) = events.get(index + 1) else {
+            break;
+        };
+
+        if next_time > time && height > remaining_capacity {
+            // The profile is overloaded on [time, next_time), so the task cannot start in
+            // [time - duration + 1, next_time - 1].
+            forbidden_starts.push((time - duration + 1, next_time - 1));
+        }
+    }
+
+    forbidden_starts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::Domain;
+    use crate::domains::VariableId;
+
+    #[test]
+    fn task_without_room_in_the_profile_is_a_conflict() {
+        // The first task is fixed at [5, 8), and the second task has to start in [3, 7] with a
+        // duration of 3, which always overlaps with the first task.
+        let initial_domains = [Domain::new(5, 5), Domain::new(3, 7)];
+        let domains = Domains::new(&initial_domains);
+
+        let start_times = [Term::Variable(VariableId(0)), Term::Variable(VariableId(1))];
+        let durations = [Term::Constant(3), Term::Constant(3)];
+        let resource_usages = [Term::Constant(1), Term::Constant(1)];
+
+        assert!(is_conflicting(
+            &start_times,
+            &durations,
+            &resource_usages,
+            Term::Constant(1),
+            &domains
+        ));
+        assert!(!is_conflicting(
+            &start_times,
+            &durations,
+            &resource_usages,
+            Term::Constant(2),
+            &domains
+        ));
+    }
+
+    #[test]
+    fn gap_in_the_profile_is_not_a_conflict() {
+        let initial_domains = [Domain::new(2, 2), Domain::new(0, 8), Domain::new(8, 8)];
+        let domains = Domains::new(&initial_domains);
+
+        let start_times = [
+            Term::Variable(VariableId(0)),
+            Term::Variable(VariableId(1)),
+            Term::Variable(VariableId(2)),
+        ];
+        let durations = [Term::Constant(3), Term::Constant(3), Term::Constant(3)];
+        let resource_usages = [Term::Constant(1), Term::Constant(1), Term::Constant(1)];
+
+        // The second task fits in [5, 8).
+        assert!(!is_conflicting(
+            &start_times,
+            &durations,
+            &resource_usages,
+            Term::Constant(1),
+            &domains
+        ));
+    }
+}
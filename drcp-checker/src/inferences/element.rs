@@ -0,0 +1,43 @@
+//! Checks the element constraint `array[index] = value`, where the array is indexed from 1.
+use super::term_domain;
+use crate::domains::Domains;
+use crate::model::Term;
+
+pub(super) fn is_conflicting(
+    index: Term,
+    array: &[Term],
+    value: Term,
+    domains: &Domains<'_>,
+) -> bool {
+    let index_domain = term_domain(index, domains);
+    let value_domain = term_domain(value, domains);
+
+    // The constraint is satisfiable if and only if there is an index in the domain for which the
+    // element can take a value which the value variable can also take.
+    !array.iter().enumerate().any(|(position, &element)| {
+        index_domain.contains(position as i64 + 1)
+            && term_domain(element, domains).intersects(&value_domain)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::Domain;
+    use crate::domains::VariableId;
+
+    #[test]
+    fn no_supported_index_is_a_conflict() {
+        let initial_domains = [Domain::from_values([1, 3]), Domain::new(4, 6)];
+        let domains = Domains::new(&initial_domains);
+
+        let index = Term::Variable(VariableId(0));
+        let value = Term::Variable(VariableId(1));
+
+        let array = [Term::Constant(1), Term::Constant(5), Term::Constant(7)];
+        assert!(is_conflicting(index, &array, value, &domains));
+
+        let array = [Term::Constant(1), Term::Constant(5), Term::Constant(6)];
+        assert!(!is_conflicting(index, &array, value, &domains));
+    }
+}
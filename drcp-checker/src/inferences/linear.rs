@@ -0,0 +1,77 @@
+//! Checks linear constraints `sum(w_i * x_i) <relation> rhs` by reasoning over the bounds of the
+//! variables.
+use super::term_bounds;
+use crate::domains::Domains;
+use crate::model::Term;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Relation {
+    LessThanEqual,
+    Equal,
+    NotEqual,
+}
+
+pub(super) fn is_conflicting(
+    weighted_terms: impl IntoIterator<Item = (i64, Term)>,
+    relation: Relation,
+    rhs: i64,
+    domains: &Domains<'_>,
+) -> bool {
+    // The sums are computed with 128-bit integers such that they cannot overflow.
+    let (minimum, maximum) =
+        weighted_terms
+            .into_iter()
+            .fold((0_i128, 0_i128), |(minimum, maximum), (weight, term)| {
+                let (lower_bound, upper_bound) = term_bounds(term, domains);
+                let first = weight as i128 * lower_bound as i128;
+                let second = weight as i128 * upper_bound as i128;
+
+                (minimum + first.min(second), maximum + first.max(second))
+            });
+
+    let rhs = rhs as i128;
+    match relation {
+        Relation::LessThanEqual => minimum > rhs,
+        Relation::Equal => minimum > rhs || maximum < rhs,
+        Relation::NotEqual => minimum == maximum && minimum == rhs,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::Domain;
+    use crate::domains::VariableId;
+
+    #[test]
+    fn sum_exceeding_rhs_is_a_conflict() {
+        let initial_domains = [Domain::new(2, 5), Domain::new(0, 3)];
+        let domains = Domains::new(&initial_domains);
+
+        let terms = [
+            (1, Term::Variable(VariableId(0))),
+            (-1, Term::Variable(VariableId(1))),
+        ];
+
+        // The smallest value of x - y is 2 - 3 = -1.
+        assert!(!is_conflicting(
+            terms,
+            Relation::LessThanEqual,
+            -1,
+            &domains
+        ));
+        assert!(is_conflicting(terms, Relation::LessThanEqual, -2, &domains));
+        assert!(is_conflicting(terms, Relation::Equal, 6, &domains));
+    }
+
+    #[test]
+    fn fixed_not_equal_is_a_conflict() {
+        let initial_domains = [Domain::new(2, 2)];
+        let domains = Domains::new(&initial_domains);
+
+        let terms = [(3, Term::Variable(VariableId(0))), (1, Term::Constant(1))];
+
+        assert!(is_conflicting(terms, Relation::NotEqual, 7, &domains));
+        assert!(!is_conflicting(terms, Relation::NotEqual, 6, &domains));
+    }
+}
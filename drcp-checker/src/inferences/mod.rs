@@ -0,0 +1,192 @@
+//! Contains the validation of inference steps against the constraints of the model.
+//!
+//! An inference `premises -> propagated` is tagged with the constraint which implies it; the tag
+//! is the 1-based index of the constraint in the model. The inference is valid if the constraint
+//! has no solution in which the premises hold and the propagated atomic does not hold. Hence, the
+//! checks below are given the domains in which the premises and the negation of the propagated
+//! atomic are applied, and they determine whether the constraint is in conflict with those
+//! domains.
+//!
+//! The checks are sound, but not complete. An inference is only rejected when the check covers the
+//! filtering algorithm identified by its label; otherwise, it is reported as unsupported.
+mod all_different;
+mod cumulative;
+mod element;
+mod linear;
+
+use std::borrow::Cow;
+
+use anyhow::bail;
+use anyhow::Context;
+use flatzinc::ConstraintItem;
+
+use self::linear::Relation;
+use crate::domains::Domain;
+use crate::domains::Domains;
+use crate::model::Model;
+use crate::model::Term;
+
+/// The result of checking a constraint against the domains of an inference.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Verdict {
+    /// The constraint has no solution in the domains, so the inference is valid.
+    Conflict,
+    /// No conflict was detected, so the inference could not be validated.
+    NoConflict,
+    /// The constraint is not supported by the checker.
+    Unsupported,
+}
+
+/// Determine whether `constraint` has a solution given the `domains`, for an inference with the
+/// given `label`.
+pub(crate) fn check_constraint(
+    model: &Model,
+    constraint: &ConstraintItem,
+    label: Option<&str>,
+    domains: &Domains<'_>,
+) -> anyhow::Result<Verdict> {
+    check_known_constraint(model, constraint, label, domains)
+        .with_context(|| format!("invalid arguments for {}", constraint.id))
+}
+
+fn check_known_constraint(
+    model: &Model,
+    constraint: &ConstraintItem,
+    label: Option<&str>,
+    domains: &Domains<'_>,
+) -> anyhow::Result<Verdict> {
+    let exprs = &constraint.exprs;
+
+    let is_conflicting = match constraint.id.as_str() {
+        "int_lin_le" | "int_lin_eq" | "int_lin_ne" => {
+            check_arguments(exprs.len(), 3)?;
+
+            let weights = model.resolve_constant_array(&exprs[0])?;
+            let terms = model.resolve_term_array(&exprs[1])?;
+            let rhs = model.resolve_constant(&exprs[2])?;
+
+            if weights.len() != terms.len() {
+                bail!("the weights and variables have a different length");
+            }
+
+            let relation = match constraint.id.as_str() {
+                "int_lin_le" => Relation::LessThanEqual,
+                "int_lin_eq" => Relation::Equal,
+                _ => Relation::NotEqual,
+            };
+
+            let weighted_terms = weights.into_iter().zip(terms.iter().copied());
+            linear::is_conflicting(weighted_terms, relation, rhs, domains)
+        }
+
+        "int_le" | "int_lt" | "int_eq" | "int_ne" => {
+            check_arguments(exprs.len(), 2)?;
+
+            let lhs = model.resolve_term(&exprs[0])?;
+            let rhs = model.resolve_term(&exprs[1])?;
+
+            let (relation, constant) = match constraint.id.as_str() {
+                "int_le" => (Relation::LessThanEqual, 0),
+                "int_lt" => (Relation::LessThanEqual, -1),
+                "int_eq" => (Relation::Equal, 0),
+                _ => (Relation::NotEqual, 0),
+            };
+
+            linear::is_conflicting([(1, lhs), (-1, rhs)], relation, constant, domains)
+        }
+
+        "int_plus" => {
+            check_arguments(exprs.len(), 3)?;
+
+            let terms = [
+                (1, model.resolve_term(&exprs[0])?),
+                (1, model.resolve_term(&exprs[1])?),
+                (-1, model.resolve_term(&exprs[2])?),
+            ];
+
+            linear::is_conflicting(terms, Relation::Equal, 0, domains)
+        }
+
+        "pumpkin_all_different" => {
+            check_arguments(exprs.len(), 1)?;
+
+            let terms = model.resolve_term_array(&exprs[0])?;
+            all_different::is_conflicting(&terms, domains)
+        }
+
+        "array_int_element"
+        | "array_var_int_element"
+        | "array_bool_element"
+        | "array_var_bool_element" => {
+            check_arguments(exprs.len(), 3)?;
+
+            let index = model.resolve_term(&exprs[0])?;
+            let array = model.resolve_term_array(&exprs[1])?;
+            let value = model.resolve_term(&exprs[2])?;
+
+            element::is_conflicting(index, &array, value, domains)
+        }
+
+        "pumpkin_cumulative" | "pumpkin_cumulative_var" => {
+            check_arguments(exprs.len(), 4)?;
+
+            // Only time-table reasoning is checked; the other filtering algorithms for the
+            // cumulative can derive inferences which are not detected by it.
+            if label != Some(cumulative::TIME_TABLE_LABEL) {
+                return Ok(Verdict::Unsupported);
+            }
+
+            let start_times = model.resolve_term_array(&exprs[0])?;
+            let durations = model.resolve_term_array(&exprs[1])?;
+            let resource_usages = model.resolve_term_array(&exprs[2])?;
+            let capacity = model.resolve_term(&exprs[3])?;
+
+            if start_times.len() != durations.len() || start_times.len() != resource_usages.len() {
+                bail!("the tasks are not fully specified");
+            }
+
+            cumulative::is_conflicting(
+                &start_times,
+                &durations,
+                &resource_usages,
+                capacity,
+                domains,
+            )
+        }
+
+        _ => return Ok(Verdict::Unsupported),
+    };
+
+    if is_conflicting {
+        Ok(Verdict::Conflict)
+    } else {
+        Ok(Verdict::NoConflict)
+    }
+}
+
+fn check_arguments(actual: usize, expected: usize) -> anyhow::Result<()> {
+    if actual != expected {
+        bail!("expected {expected} arguments, got {actual}");
+    }
+
+    Ok(())
+}
+
+/// Get the domain of a term, where a constant has a domain with a single value.
+fn term_domain<'a>(term: Term, domains: &'a Domains<'_>) -> Cow<'a, Domain> {
+    match term {
+        Term::Variable(variable) => Cow::Borrowed(domains.get(variable)),
+        Term::Constant(value) => Cow::Owned(Domain::new(value, value)),
+    }
+}
+
+/// Get the lower and upper bound of a term.
+fn term_bounds(term: Term, domains: &Domains<'_>) -> (i64, i64) {
+    match term {
+        Term::Variable(variable) => {
+            let domain = domains.get(variable);
+            (domain.lower_bound(), domain.upper_bound())
+        }
+        Term::Constant(value) => (value, value),
+    }
+}
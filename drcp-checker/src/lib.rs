@@ -0,0 +1,299 @@
+//! A checker for DRCP proofs of FlatZinc models produced by Pumpkin.
+//!
+//! The checker verifies every step of the proof:
+//! - Inferences are validated against the constraint of the model they are tagged with. Only
+//!   linear, all-different, element and cumulative constraints are supported, where only time-table
+//!   inferences are checked for the cumulative. Other inferences are unchecked, and the proof is
+//!   rejected unless unchecked inferences are allowed (`--allow-unchecked` on the command line).
+//! - Nogoods are verified by reverse unit propagation over their hints, or over all active steps if
+//!   they have no hints.
+//! - The conclusion is verified by checking that the empty nogood is derived. For an optimality
+//!   claim, all steps are checked under the assumption that the objective improves on the bound in
+//!   the conclusion.
+mod checker;
+mod domains;
+mod inferences;
+mod model;
+
+use std::fs::File;
+use std::io::Read;
+use std::num::NonZero;
+use std::path::Path;
+
+use anyhow::bail;
+use anyhow::Context;
+use drcp_format::reader::ProofReader;
+use drcp_format::steps::Conclusion;
+use drcp_format::steps::Step;
+pub use drcp_format::Format;
+use drcp_format::LiteralDefinitions;
+
+use crate::checker::Checker;
+use crate::checker::ProofAtomic;
+pub use crate::checker::Statistics;
+use crate::model::Model;
+
+/// A proof which has been checked.
+#[derive(Clone, Debug)]
+pub struct CheckedProof {
+    /// The claim which is established by the proof.
+    pub claim: String,
+    /// The statistics of the checked proof.
+    pub statistics: Statistics,
+}
+
+/// Checks the proof at `proof_path` for the FlatZinc model at `model_path`, where the literals of
+/// the proof are defined in the file at `lits_path`.
+///
+/// If the proof contains inferences which cannot be validated against the model, then the proof is
+/// rejected unless `allow_unchecked` is true.
+pub fn check_proof_files(
+    model_path: &Path,
+    proof_path: &Path,
+    lits_path: &Path,
+    format: Format,
+    allow_unchecked: bool,
+) -> anyhow::Result<CheckedProof> {
+    let model = Model::parse(open(model_path)?)
+        .with_context(|| format!("Failed to parse {}", model_path.display()))?;
+
+    let definitions = LiteralDefinitions::<String>::parse(open(lits_path)?).with_context(|| {
+        format!(
+            "Failed to parse literal definitions from {}.",
+            lits_path.display()
+        )
+    })?;
+
+    // The conclusion is needed before any step can be checked, so the proof is read twice.
+    let conclusion = read_conclusion(open(proof_path)?, format, &definitions)?;
+    let checker = check_proof(model, open(proof_path)?, format, &definitions, &conclusion)?;
+
+    let statistics = checker.statistics();
+    if let Some(step_id) = statistics.first_unchecked_inference {
+        if !allow_unchecked {
+            bail!(
+                "{} inferences could not be validated against the model, the first is step \
+                 {step_id}; unchecked inferences are not allowed",
+                statistics.num_unchecked_inferences()
+            );
+        }
+    }
+
+    let claim = match conclusion {
+        Conclusion::Unsatisfiable => "the model is unsatisfiable".to_owned(),
+        Conclusion::Optimal(Ok(bound)) => format!("no solution improves on the bound {bound}"),
+        Conclusion::Optimal(Err(_)) => unreachable!("the conclusion has been checked"),
+    };
+
+    Ok(CheckedProof { claim, statistics })
+}
+
+fn open(path: &Path) -> anyhow::Result<File> {
+    File::open(path).with_context(|| format!("Failed to open {}", path.display()))
+}
+
+/// Map a literal from the proof to its atomic constraint.
+fn to_atomic(definitions: &LiteralDefinitions<String>, literal: NonZero<i32>) -> ProofAtomic {
+    let atomic = definitions
+        .get(literal.unsigned_abs())
+        .and_then(|atomics| atomics.first())
+        .ok_or(literal)?
+        .clone();
+
+    if literal.is_positive() {
+        Ok(atomic)
+    } else {
+        Ok(!atomic)
+    }
+}
+
+/// Read the proof until its conclusion.
+fn read_conclusion(
+    proof: impl Read,
+    format: Format,
+    definitions: &LiteralDefinitions<String>,
+) -> anyhow::Result<Conclusion<ProofAtomic>> {
+    let mut reader =
+        ProofReader::with_format(format, proof, |literal| to_atomic(definitions, literal));
+
+    while let Some(step) = reader.next_step()? {
+        if let Step::Conclusion(conclusion) = step {
+            return Ok(conclusion);
+        }
+    }
+
+    bail!("The proof has no conclusion.")
+}
+
+fn check_proof(
+    model: Model,
+    proof: impl Read,
+    format: Format,
+    definitions: &LiteralDefinitions<String>,
+    conclusion: &Conclusion<ProofAtomic>,
+) -> anyhow::Result<Checker> {
+    let mut checker = Checker::new(model);
+    checker.set_conclusion(conclusion)?;
+
+    let mut reader =
+        ProofReader::with_format(format, proof, |literal| to_atomic(definitions, literal));
+
+    while let Some(step) = reader.next_step()? {
+        let is_conclusion = matches!(step, Step::Conclusion(_));
+        checker.check_step(step)?;
+
+        if is_conclusion {
+            return Ok(checker);
+        }
+    }
+
+    bail!("The proof has no conclusion.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODEL: &str = r#"
+var 0..2: x;
+var 0..2: y;
+var 0..2: z;
+constraint int_lin_le([1,-1],[x,y],-1);
+constraint int_lin_le([1,-1],[y,z],-1);
+constraint int_lin_le([1,-1],[z,x],-1);
+solve satisfy;
+"#;
+
+    const LITS: &str = r#"
+1 [x <= 0]
+2 [y <= 1]
+3 [z <= 1]
+"#;
+
+    fn check(proof: &str) -> anyhow::Result<Checker> {
+        check_with_model(MODEL, LITS, proof)
+    }
+
+    fn check_with_model(model: &str, lits: &str, proof: &str) -> anyhow::Result<Checker> {
+        let model = Model::parse(model.as_bytes()).expect("valid model");
+        let definitions =
+            LiteralDefinitions::<String>::parse(lits.as_bytes()).expect("valid definitions");
+        let conclusion = read_conclusion(proof.as_bytes(), Format::Text, &definitions)?;
+
+        check_proof(
+            model,
+            proof.as_bytes(),
+            Format::Text,
+            &definitions,
+            &conclusion,
+        )
+    }
+
+    #[test]
+    fn valid_proof_is_accepted() {
+        // x < y < z < x: from z <= 2 we get y <= 1, and then x <= 0; but then z <= -1 follows
+        // from the last constraint.
+        let proof = r#"
+i 1 -2 c:2
+n 2 2 0 1
+i 3 2 -1 c:1
+n 4 1 0 2 3
+i 5 1 c:3
+n 6 0 4 5
+c UNSAT
+"#;
+
+        let checker = check(proof).expect("valid proof");
+        let statistics = checker.statistics();
+        assert_eq!(3, statistics.num_validated_inferences);
+        assert_eq!(None, statistics.first_unchecked_inference);
+    }
+
+    #[test]
+    fn inference_which_does_not_follow_from_its_constraint_is_rejected() {
+        // [y <= 1] does not imply [x <= 0] by the third constraint.
+        let proof = r#"
+i 1 2 -1 c:3
+c UNSAT
+"#;
+
+        assert!(check(proof).is_err());
+    }
+
+    #[test]
+    fn nogood_which_is_not_implied_by_its_hints_is_rejected() {
+        let proof = r#"
+i 1 -2 c:2
+n 2 1 0 1
+c UNSAT
+"#;
+
+        assert!(check(proof).is_err());
+    }
+
+    #[test]
+    fn proof_without_empty_nogood_is_rejected() {
+        let proof = r#"
+i 1 -2 c:2
+n 2 2 0 1
+c UNSAT
+"#;
+
+        assert!(check(proof).is_err());
+    }
+
+    #[test]
+    fn optimality_of_the_largest_value_of_the_objective_needs_no_reasoning() {
+        let model = r#"
+var 1..3: x;
+solve maximize x;
+"#;
+
+        assert!(check_with_model(model, "1 [x <= 3]\n", "c 1\n").is_ok());
+        assert!(check_with_model(model, "1 [x <= 2]\n", "c 1\n").is_err());
+    }
+
+    #[test]
+    fn optimality_bound_in_the_optimisation_direction_is_rejected() {
+        let model = r#"
+var 1..3: x;
+solve maximize x;
+"#;
+
+        assert!(check_with_model(model, "1 [x >= 3]\n", "c 1\n").is_err());
+    }
+
+    #[test]
+    fn cumulative_inference_with_unsupported_label_is_unchecked() {
+        let model = r#"
+var 0..4: s1;
+var 0..4: s2;
+constraint pumpkin_cumulative([s1,s2],[2,2],[1,1],1);
+solve satisfy;
+"#;
+        let lits = "1 [s1 <= 0]\n";
+
+        let proof = |label: &str| format!("i 1 1 c:1 l:{label}\ni 2 -1 c:1 l:{label}\nc UNSAT\n");
+
+        assert!(check_with_model(model, lits, &proof("time_table")).is_err());
+
+        let checker = check_with_model(model, lits, &proof("energetic_reasoning"))
+            .expect("unsupported inferences are not rejected");
+        let statistics = checker.statistics();
+        assert_eq!(0, statistics.num_validated_inferences);
+        assert_eq!(
+            Some(1),
+            statistics.first_unchecked_inference.map(|id| id.get())
+        );
+    }
+
+    #[test]
+    fn optimality_of_a_constant_objective_is_accepted() {
+        let model = r#"
+int: objective = 10;
+solve minimize objective;
+"#;
+
+        assert!(check_with_model(model, "1 [objective >= 10]\n", "c 1\n").is_ok());
+    }
+}
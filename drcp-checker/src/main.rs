@@ -0,0 +1,104 @@
+//! The command line interface of the DRCP proof checker (see [`drcp_checker`]).
+
+use std::fmt::Display;
+use std::path::PathBuf;
+
+use clap::Parser;
+use clap::ValueEnum;
+use drcp_checker::Format;
+
+/// The exit code when the proof is only valid under the assumption that the unchecked inferences
+/// are correct.
+const UNCHECKED_EXIT_CODE: i32 = 2;
+
+#[derive(Parser)]
+struct Cli {
+    /// The FlatZinc model for which the proof was produced.
+    model: PathBuf,
+    /// The proof to check.
+    proof: PathBuf,
+    /// The literal definitions of the proof.
+    ///
+    /// By default, this is the path of the proof with the `lits` extension.
+    #[arg(long)]
+    lits: Option<PathBuf>,
+    /// The format in which the proof is written.
+    #[arg(long, value_enum, default_value_t)]
+    proof_format: ProofFormat,
+    /// Accept the proof if it contains inferences which cannot be validated against the model.
+    ///
+    /// These inferences are assumed to be correct. The proof is then only valid under this
+    /// assumption, which is indicated by exit code 2.
+    #[arg(long)]
+    allow_unchecked: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+enum ProofFormat {
+    /// The proof is written in the textual format.
+    #[default]
+    Text,
+    /// The proof is written in the binary format.
+    Binary,
+}
+
+impl Display for ProofFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProofFormat::Text => write!(f, "text"),
+            ProofFormat::Binary => write!(f, "binary"),
+        }
+    }
+}
+
+impl From<ProofFormat> for Format {
+    fn from(value: ProofFormat) -> Self {
+        match value {
+            ProofFormat::Text => Format::Text,
+            ProofFormat::Binary => Format::Binary,
+        }
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Cli::parse();
+
+    let lits_path = args
+        .lits
+        .clone()
+        .unwrap_or_else(|| args.proof.with_extension("lits"));
+    let checked_proof = drcp_checker::check_proof_files(
+        &args.model,
+        &args.proof,
+        &lits_path,
+        args.proof_format.into(),
+        args.allow_unchecked,
+    )?;
+
+    let statistics = checked_proof.statistics;
+    println!(
+        "Checked {} inferences ({} validated against the model), {} nogoods and {} deletions.",
+        statistics.num_inferences,
+        statistics.num_validated_inferences,
+        statistics.num_nogoods,
+        statistics.num_deletions,
+    );
+
+    if let Some(step_id) = statistics.first_unchecked_inference {
+        println!(
+            "Warning: {} inferences could not be validated against the model, the first is step \
+             {step_id}.",
+            statistics.num_unchecked_inferences()
+        );
+        println!(
+            "The proof is valid assuming {} unchecked inferences: {}.",
+            statistics.num_unchecked_inferences(),
+            checked_proof.claim
+        );
+        std::process::exit(UNCHECKED_EXIT_CODE);
+    }
+
+    println!("The proof is valid: {}.", checked_proof.claim);
+
+    Ok(())
+}
@@ -0,0 +1,451 @@
+//! Contains the FlatZinc model against which a proof is checked.
+//!
+//! The variables in the proof are identified by the names of the FlatZinc variables. Variables
+//! which are declared to be equal to another variable (e.g. `var 1..5: x = y;`) share a
+//! [`VariableId`], such that atomic constraints on either of them restrict the same domain.
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::num::NonZero;
+use std::rc::Rc;
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use anyhow::bail;
+use anyhow::Context;
+use flatzinc::ArrayOfBoolExpr;
+use flatzinc::ArrayOfIntExpr;
+use flatzinc::BoolExpr;
+use flatzinc::ConstraintItem;
+use flatzinc::Expr;
+use flatzinc::Goal;
+use flatzinc::IntExpr;
+use flatzinc::OptimizationType;
+use flatzinc::ParDeclItem;
+use flatzinc::SetLiteral;
+use flatzinc::SetLiteralExpr;
+use flatzinc::Stmt;
+use flatzinc::VarDeclItem;
+
+use crate::domains::Domain;
+use crate::domains::VariableId;
+
+/// An argument of a constraint, which is either a variable or a constant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Term {
+    Variable(VariableId),
+    Constant(i64),
+}
+
+/// The direction in which the objective is optimised.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Direction {
+    Minimise,
+    Maximise,
+}
+
+/// The objective of an optimisation model.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Objective {
+    pub(crate) direction: Direction,
+    pub(crate) variable: VariableId,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Model {
+    /// The initial domains of the variables, indexed by [`VariableId`].
+    domains: Vec<Domain>,
+    /// The variable associated with every variable identifier.
+    variables: HashMap<Rc<str>, VariableId>,
+    /// All integer parameters, including the boolean parameters which are stored as 0 or 1.
+    integer_parameters: HashMap<Rc<str>, i64>,
+    /// All integer array parameters, including the boolean array parameters.
+    integer_array_parameters: HashMap<Rc<str>, Rc<[i64]>>,
+    /// All set parameters.
+    set_parameters: HashMap<Rc<str>, Domain>,
+    /// All arrays of variables.
+    variable_arrays: HashMap<Rc<str>, Rc<[Term]>>,
+    /// The constraints in the order in which they occur in the model.
+    constraints: Vec<ConstraintItem>,
+    objective: Option<Objective>,
+}
+
+impl Model {
+    /// Parse a FlatZinc model.
+    pub(crate) fn parse(source: impl Read) -> anyhow::Result<Model> {
+        let mut model = Model::default();
+
+        for (index, line) in BufReader::new(source).lines().enumerate() {
+            let line = line?;
+
+            let statement = Stmt::from_str(&line)
+                .map_err(|message| anyhow!("syntax error on line {}: {message}", index + 1))?;
+
+            match statement {
+                Stmt::Comment(_) | Stmt::Predicate(_) => {}
+                Stmt::Parameter(declaration) => model.add_parameter(declaration)?,
+                Stmt::Variable(declaration) => model
+                    .add_variable(declaration)
+                    .with_context(|| format!("invalid declaration on line {}", index + 1))?,
+                Stmt::Constraint(constraint) => model.constraints.push(constraint),
+                Stmt::SolveItem(solve_item) => model.set_goal(solve_item.goal)?,
+            }
+        }
+
+        model.restrict_set_in_domains()?;
+
+        Ok(model)
+    }
+
+    /// The initial domains of the variables, indexed by [`VariableId`].
+    pub(crate) fn initial_domains(&self) -> &[Domain] {
+        &self.domains
+    }
+
+    pub(crate) fn initial_domains_mut(&mut self) -> &mut [Domain] {
+        &mut self.domains
+    }
+
+    pub(crate) fn objective(&self) -> Option<Objective> {
+        self.objective
+    }
+
+    /// Get the constraint with the given tag, which is the 1-based index of the constraint in the
+    /// model.
+    pub(crate) fn constraint(&self, tag: NonZero<u32>) -> Option<&ConstraintItem> {
+        self.constraints.get(tag.get() as usize - 1)
+    }
+
+    /// Look up the variable with the given name.
+    ///
+    /// Constants can be referred to by a name which is the value of the constant; for those a
+    /// fixed variable is created.
+    pub(crate) fn variable(&mut self, name: &str) -> Option<VariableId> {
+        if let Some(&variable) = self.variables.get(name) {
+            return Some(variable);
+        }
+
+        let value = self
+            .integer_parameters
+            .get(name)
+            .copied()
+            .or_else(|| name.parse::<i64>().ok())?;
+
+        let variable = self.new_variable(name.into(), Domain::new(value, value));
+        Some(variable)
+    }
+
+    /// Resolve an argument of a constraint to a [`Term`].
+    pub(crate) fn resolve_term(&self, expr: &Expr) -> anyhow::Result<Term> {
+        match expr {
+            Expr::VarParIdentifier(identifier) => self.resolve_identifier(identifier),
+            Expr::Bool(value) => Ok(Term::Constant(*value as i64)),
+            Expr::Int(value) => Ok(Term::Constant(to_i64(*value)?)),
+            _ => bail!("expected an integer or boolean expression"),
+        }
+    }
+
+    /// Resolve an array argument of a constraint to a list of [`Term`]s.
+    pub(crate) fn resolve_term_array(&self, expr: &Expr) -> anyhow::Result<Rc<[Term]>> {
+        match expr {
+            Expr::VarParIdentifier(identifier) => {
+                if let Some(terms) = self.variable_arrays.get(identifier.as_str()) {
+                    Ok(Rc::clone(terms))
+                } else if let Some(values) = self.integer_array_parameters.get(identifier.as_str())
+                {
+                    Ok(values.iter().copied().map(Term::Constant).collect())
+                } else {
+                    bail!("unknown array {identifier}")
+                }
+            }
+            Expr::ArrayOfInt(exprs) => exprs
+                .iter()
+                .map(|expr| self.resolve_int_expr(expr))
+                .collect(),
+            Expr::ArrayOfBool(exprs) => exprs
+                .iter()
+                .map(|expr| self.resolve_bool_expr(expr))
+                .collect(),
+            _ => bail!("expected an array of integers or booleans"),
+        }
+    }
+
+    /// Resolve an argument of a constraint which should be a constant.
+    pub(crate) fn resolve_constant(&self, expr: &Expr) -> anyhow::Result<i64> {
+        match self.resolve_term(expr)? {
+            Term::Constant(value) => Ok(value),
+            Term::Variable(_) => bail!("expected a constant"),
+        }
+    }
+
+    /// Resolve an array argument of a constraint which should only contain constants.
+    pub(crate) fn resolve_constant_array(&self, expr: &Expr) -> anyhow::Result<Vec<i64>> {
+        self.resolve_term_array(expr)?
+            .iter()
+            .map(|term| match term {
+                Term::Constant(value) => Ok(*value),
+                Term::Variable(_) => bail!("expected an array of constants"),
+            })
+            .collect()
+    }
+
+    fn resolve_identifier(&self, identifier: &str) -> anyhow::Result<Term> {
+        if let Some(&variable) = self.variables.get(identifier) {
+            Ok(Term::Variable(variable))
+        } else if let Some(&value) = self.integer_parameters.get(identifier) {
+            Ok(Term::Constant(value))
+        } else {
+            bail!("unknown identifier {identifier}")
+        }
+    }
+
+    fn resolve_int_expr(&self, expr: &IntExpr) -> anyhow::Result<Term> {
+        match expr {
+            IntExpr::Int(value) => Ok(Term::Constant(to_i64(*value)?)),
+            IntExpr::VarParIdentifier(identifier) => self.resolve_identifier(identifier),
+        }
+    }
+
+    fn resolve_bool_expr(&self, expr: &BoolExpr) -> anyhow::Result<Term> {
+        match expr {
+            BoolExpr::Bool(value) => Ok(Term::Constant(*value as i64)),
+            BoolExpr::VarParIdentifier(identifier) => self.resolve_identifier(identifier),
+        }
+    }
+
+    fn new_variable(&mut self, name: Rc<str>, domain: Domain) -> VariableId {
+        let variable = VariableId(self.domains.len());
+        self.domains.push(domain);
+        let _ = self.variables.insert(name, variable);
+        variable
+    }
+
+    /// Declare a variable with the given domain, which may be defined to be equal to `term`.
+    fn declare_variable(&mut self, name: &str, mut domain: Domain, term: Option<Term>) {
+        match term {
+            None => {
+                let _ = self.new_variable(name.into(), domain);
+            }
+            Some(Term::Constant(value)) => {
+                domain.apply(drcp_format::Comparison::Equal, value);
+                let _ = self.new_variable(name.into(), domain);
+            }
+            Some(Term::Variable(variable)) => {
+                self.domains[variable.0].intersect(&domain);
+                let _ = self.variables.insert(name.into(), variable);
+            }
+        }
+    }
+
+    fn add_variable(&mut self, declaration: VarDeclItem) -> anyhow::Result<()> {
+        match declaration {
+            VarDeclItem::Bool { id, expr, .. } => {
+                let term = expr.map(|expr| self.resolve_bool_expr(&expr)).transpose()?;
+                self.declare_variable(&id, Domain::new(0, 1), term);
+            }
+
+            VarDeclItem::IntInRange {
+                id, lb, ub, expr, ..
+            } => {
+                let term = expr.map(|expr| self.resolve_int_expr(&expr)).transpose()?;
+                self.declare_variable(&id, Domain::new(to_i64(lb)?, to_i64(ub)?), term);
+            }
+
+            VarDeclItem::IntInSet { id, set, expr, .. } => {
+                let term = expr.map(|expr| self.resolve_int_expr(&expr)).transpose()?;
+                let values = set.into_iter().map(to_i64).collect::<Result<Vec<_>, _>>()?;
+                self.declare_variable(&id, Domain::from_values(values), term);
+            }
+
+            VarDeclItem::ArrayOfBool { id, array_expr, .. } => {
+                let terms = match array_expr {
+                    Some(ArrayOfBoolExpr::Array(exprs)) => exprs
+                        .iter()
+                        .map(|expr| self.resolve_bool_expr(expr))
+                        .collect::<anyhow::Result<_>>()?,
+                    Some(ArrayOfBoolExpr::VarParIdentifier(identifier)) => {
+                        self.resolve_term_array(&Expr::VarParIdentifier(identifier))?
+                    }
+                    None => bail!("array {id} has no definition"),
+                };
+                let _ = self.variable_arrays.insert(id.into(), terms);
+            }
+
+            VarDeclItem::ArrayOfInt { id, array_expr, .. }
+            | VarDeclItem::ArrayOfIntInRange { id, array_expr, .. }
+            | VarDeclItem::ArrayOfIntInSet { id, array_expr, .. } => {
+                let terms = match array_expr {
+                    Some(ArrayOfIntExpr::Array(exprs)) => exprs
+                        .iter()
+                        .map(|expr| self.resolve_int_expr(expr))
+                        .collect::<anyhow::Result<_>>()?,
+                    Some(ArrayOfIntExpr::VarParIdentifier(identifier)) => {
+                        self.resolve_term_array(&Expr::VarParIdentifier(identifier))?
+                    }
+                    None => bail!("array {id} has no definition"),
+                };
+                let _ = self.variable_arrays.insert(id.into(), terms);
+            }
+
+            VarDeclItem::Int { id, .. } => bail!("unbounded integer variable {id}"),
+
+            _ => bail!("only integer and boolean variables are supported"),
+        }
+
+        Ok(())
+    }
+
+    fn add_parameter(&mut self, declaration: ParDeclItem) -> anyhow::Result<()> {
+        match declaration {
+            ParDeclItem::Bool { id, bool } => {
+                let _ = self.integer_parameters.insert(id.into(), bool as i64);
+            }
+            ParDeclItem::Int { id, int } => {
+                let _ = self.integer_parameters.insert(id.into(), to_i64(int)?);
+            }
+            ParDeclItem::ArrayOfBool { id, v, .. } => {
+                let values = v.into_iter().map(|value| value as i64).collect();
+                let _ = self.integer_array_parameters.insert(id.into(), values);
+            }
+            ParDeclItem::ArrayOfInt { id, v, .. } => {
+                let values = v.into_iter().map(to_i64).collect::<Result<_, _>>()?;
+                let _ = self.integer_array_parameters.insert(id.into(), values);
+            }
+            ParDeclItem::SetOfInt { id, set_literal } => {
+                let domain = match set_literal {
+                    SetLiteral::IntRange(lower_bound, upper_bound) => {
+                        Domain::new(to_i64(lower_bound)?, to_i64(upper_bound)?)
+                    }
+                    SetLiteral::SetInts(values) => Domain::from_values(
+                        values
+                            .into_iter()
+                            .map(to_i64)
+                            .collect::<Result<Vec<_>, _>>()?,
+                    ),
+                    _ => bail!("set {id} is not a set of integers"),
+                };
+                let _ = self.set_parameters.insert(id.into(), domain);
+            }
+
+            // Parameters which cannot occur in integer or boolean constraints are ignored.
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn set_goal(&mut self, goal: Goal) -> anyhow::Result<()> {
+        let (optimisation_type, name) = match goal {
+            Goal::Satisfy => return Ok(()),
+            Goal::OptimizeInt(optimisation_type, IntExpr::VarParIdentifier(identifier))
+            | Goal::OptimizeBool(optimisation_type, BoolExpr::VarParIdentifier(identifier)) => {
+                (optimisation_type, identifier)
+            }
+            Goal::OptimizeInt(optimisation_type, IntExpr::Int(value)) => {
+                (optimisation_type, value.to_string())
+            }
+            Goal::OptimizeBool(optimisation_type, BoolExpr::Bool(value)) => {
+                (optimisation_type, (value as i64).to_string())
+            }
+            _ => bail!("only integer and boolean objectives are supported"),
+        };
+
+        // A constant objective is represented by a fixed variable, so the bound in an optimality
+        // claim can refer to it.
+        let variable = self
+            .variable(&name)
+            .with_context(|| format!("unknown identifier {name}"))?;
+
+        let direction = match optimisation_type {
+            OptimizationType::Minimize => Direction::Minimise,
+            OptimizationType::Maximize => Direction::Maximise,
+        };
+
+        self.objective = Some(Objective {
+            direction,
+            variable,
+        });
+
+        Ok(())
+    }
+
+    /// The `set_in` constraints are not propagated, rather the domains of the variables are
+    /// restricted to the set before solving.
+    fn restrict_set_in_domains(&mut self) -> anyhow::Result<()> {
+        for index in 0..self.constraints.len() {
+            let constraint = &self.constraints[index];
+            if constraint.id != "set_in" || constraint.exprs.len() != 2 {
+                continue;
+            }
+
+            let Term::Variable(variable) = self.resolve_term(&constraint.exprs[0])? else {
+                continue;
+            };
+
+            let set = match &constraint.exprs[1] {
+                Expr::VarParIdentifier(identifier) => self
+                    .set_parameters
+                    .get(identifier.as_str())
+                    .cloned()
+                    .with_context(|| format!("unknown set {identifier}"))?,
+                Expr::Set(SetLiteralExpr::IntInRange(lower_bound, upper_bound)) => Domain::new(
+                    self.resolve_constant(&int_expr_to_expr(lower_bound))?,
+                    self.resolve_constant(&int_expr_to_expr(upper_bound))?,
+                ),
+                Expr::Set(SetLiteralExpr::SetInts(values)) => Domain::from_values(
+                    values
+                        .iter()
+                        .map(|value| self.resolve_constant(&int_expr_to_expr(value)))
+                        .collect::<anyhow::Result<Vec<_>>>()?,
+                ),
+                _ => bail!("the second argument of set_in should be a set of integers"),
+            };
+
+            self.domains[variable.0].intersect(&set);
+        }
+
+        Ok(())
+    }
+}
+
+fn int_expr_to_expr(expr: &IntExpr) -> Expr {
+    match expr {
+        IntExpr::Int(value) => Expr::Int(*value),
+        IntExpr::VarParIdentifier(identifier) => Expr::VarParIdentifier(identifier.clone()),
+    }
+}
+
+fn to_i64(value: i128) -> anyhow::Result<i64> {
+    i64::try_from(value).with_context(|| format!("{value} does not fit in 64 bits"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aliased_variables_share_a_domain() {
+        let source = r#"
+var 0..10: x;
+var 2..20: y = x;
+array [1..2] of var int: xs = [x, 3];
+constraint set_in(y, 4..6);
+solve minimize y;
+"#;
+        let mut model = Model::parse(source.as_bytes()).expect("valid model");
+
+        let x = model.variable("x").expect("x is declared");
+        assert_eq!(Some(x), model.variable("y"));
+        assert_eq!(&Domain::new(4, 6), &model.initial_domains()[x.0]);
+
+        let array = model
+            .resolve_term_array(&Expr::VarParIdentifier("xs".to_owned()))
+            .expect("xs is declared");
+        assert_eq!(&[Term::Variable(x), Term::Constant(3)], array.as_ref());
+
+        let objective = model.objective().expect("the model has an objective");
+        assert_eq!(x, objective.variable);
+        assert_eq!(Direction::Minimise, objective.direction);
+    }
+}
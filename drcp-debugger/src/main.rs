@@ -1,3 +1,6 @@
+mod trim;
+
+use std::fmt::Display;
 use std::fs::File;
 use std::io::Write;
 use std::path::PathBuf;
@@ -5,6 +8,7 @@ use std::path::PathBuf;
 use anyhow::Context;
 use clap::Parser;
 use drcp_format::reader::ProofReader;
+use drcp_format::reader::ReadStep;
 use drcp_format::steps::Conclusion;
 use drcp_format::steps::Step;
 use drcp_format::LiteralDefinitions;
@@ -14,9 +18,19 @@ struct Cli {
     /// The input proof.
     input_proof: PathBuf,
     /// The input literals.
-    input_lits: PathBuf,
+    #[arg(required_unless_present = "trim", conflicts_with = "trim")]
+    input_lits: Option<PathBuf>,
     /// The output proof where literals are replaced with atomics.
-    output: PathBuf,
+    #[arg(required_unless_present = "trim", conflicts_with = "trim")]
+    output: Option<PathBuf>,
+    /// Write a proof to the given path which only contains the steps needed to derive the
+    /// conclusion, instead of replacing the literals with atomics. The trimmed proof uses the same
+    /// literals as the input proof, so no literal definitions are needed.
+    ///
+    /// The needed steps are found by walking the proof backwards from the conclusion and following
+    /// the hints of the nogoods.
+    #[arg(long, value_name = "OUTPUT")]
+    trim: Option<PathBuf>,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -25,64 +39,91 @@ fn main() -> anyhow::Result<()> {
     let input_proof = File::open(&args.input_proof)
         .with_context(|| format!("Failed to open {}", args.input_proof.display()))?;
 
-    let input_lits = File::open(&args.input_lits)
-        .with_context(|| format!("Failed to open {}", args.input_proof.display()))?;
+    if let Some(trimmed_proof) = &args.trim {
+        let mut output = File::create(trimmed_proof)
+            .with_context(|| format!("Failed to create {}", trimmed_proof.display()))?;
+
+        let statistics = trim::trim(input_proof, &mut output)?;
+        statistics.print();
+        return Ok(());
+    }
+
+    // Both are required by clap when not trimming
+    let input_lits_path = args.input_lits.expect("literals are required");
+    let output_path = args.output.expect("output is required");
+
+    let mut output = File::create(&output_path)
+        .with_context(|| format!("Failed to create {}", output_path.display()))?;
+
+    let input_lits = File::open(&input_lits_path)
+        .with_context(|| format!("Failed to open {}", input_lits_path.display()))?;
 
     let literals = LiteralDefinitions::<String>::parse(input_lits).with_context(|| {
         format!(
             "Failed to parse literal definitions from {}.",
-            args.input_lits.display()
+            input_lits_path.display()
         )
     })?;
 
     let mut reader = ProofReader::new(input_proof, literals);
-    let mut output = File::create(&args.output)
-        .with_context(|| format!("Failed to create {}", args.output.display()))?;
 
     while let Some(step) = reader.next_step()? {
-        match step {
-            Step::Inference(inference) => {
-                write!(output, "i {}", inference.id)?;
+        write_step(&mut output, &step)?;
+    }
 
-                for premise in inference.premises {
-                    write!(output, " {}", premise)?;
-                }
+    Ok(())
+}
 
-                if let Some(propagated) = inference.propagated {
-                    write!(output, " 0 {}", propagated)?;
-                }
+/// Write a step in the textual format, where the literals are written through their [`Display`]
+/// implementation.
+fn write_step<Literal: Display>(
+    output: &mut impl Write,
+    step: &ReadStep<'_, Literal>,
+) -> std::io::Result<()> {
+    match step {
+        Step::Inference(inference) => {
+            write!(output, "i {}", inference.id)?;
+
+            for premise in &inference.premises {
+                write!(output, " {}", premise)?;
+            }
 
-                if let Some(label) = inference.hint_label {
-                    write!(output, " l:{label}")?;
-                }
+            if let Some(propagated) = &inference.propagated {
+                write!(output, " 0 {}", propagated)?;
+            }
 
-                if let Some(constraint_id) = inference.hint_constraint_id {
-                    write!(output, " c:{constraint_id}")?;
-                }
+            if let Some(constraint_id) = inference.hint_constraint_id {
+                write!(output, " c:{constraint_id}")?;
+            }
 
-                writeln!(output)?;
+            if let Some(label) = inference.hint_label {
+                write!(output, " l:{label}")?;
             }
-            Step::Nogood(nogood) => {
-                write!(output, "n {}", nogood.id)?;
 
-                for literal in nogood.literals {
-                    write!(output, " {}", literal)?;
-                }
+            writeln!(output)?;
+        }
+        Step::Nogood(nogood) => {
+            write!(output, "n {}", nogood.id)?;
+
+            for literal in &nogood.literals {
+                write!(output, " {}", literal)?;
+            }
 
+            if let Some(hints) = &nogood.hints {
                 write!(output, " 0")?;
 
-                for hint in nogood.hints.iter().flatten() {
+                for hint in hints {
                     write!(output, " {}", hint)?;
                 }
-
-                writeln!(output)?;
             }
-            Step::Delete(step) => writeln!(output, "d {}", step.id)?,
-            Step::Conclusion(conclusion) => match conclusion {
-                Conclusion::Unsatisfiable => writeln!(output, "c UNSAT")?,
-                Conclusion::Optimal(bound) => writeln!(output, "c {bound}")?,
-            },
+
+            writeln!(output)?;
         }
+        Step::Delete(step) => writeln!(output, "d {}", step.id)?,
+        Step::Conclusion(conclusion) => match conclusion {
+            Conclusion::Unsatisfiable => writeln!(output, "c UNSAT")?,
+            Conclusion::Optimal(bound) => writeln!(output, "c {bound}")?,
+        },
     }
 
     Ok(())
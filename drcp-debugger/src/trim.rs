@@ -0,0 +1,377 @@
+//! Trims a proof to the steps which are needed to derive its conclusion.
+//!
+//! The proof is walked backwards from the conclusion. The conclusion needs the step which derives
+//! the empty nogood, or for an optimality conclusion the nogood which is the negation of the bound.
+//! Every needed nogood needs the steps in its hints. A nogood without hints
+//! may be derived from any step which is active at that point in the proof, so all of those steps
+//! are needed.
+use std::collections::HashMap;
+use std::io::Read;
+use std::io::Write;
+use std::num::NonZero;
+
+use anyhow::bail;
+use anyhow::Context;
+use drcp_format::reader::ProofReader;
+use drcp_format::steps::Conclusion;
+use drcp_format::steps::Step;
+use drcp_format::steps::StepId;
+
+use crate::write_step;
+
+/// The statistics on how much of the proof is needed to derive the conclusion.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct TrimStatistics {
+    num_inferences: usize,
+    num_needed_inferences: usize,
+    num_nogoods: usize,
+    num_needed_nogoods: usize,
+    num_deletions: usize,
+    num_needed_deletions: usize,
+}
+
+impl TrimStatistics {
+    pub(crate) fn print(&self) {
+        let print_line = |name: &str, needed: usize, total: usize| {
+            let percentage = if total == 0 {
+                100.0
+            } else {
+                100.0 * needed as f64 / total as f64
+            };
+            println!("{name}: {needed} of {total} needed ({percentage:.2}%)");
+        };
+
+        print_line(
+            "inferences",
+            self.num_needed_inferences,
+            self.num_inferences,
+        );
+        print_line("nogoods", self.num_needed_nogoods, self.num_nogoods);
+        print_line("deletions", self.num_needed_deletions, self.num_deletions);
+    }
+}
+
+#[derive(Debug)]
+enum StepKind {
+    Inference(StepId),
+    Nogood {
+        id: StepId,
+        hints: Option<Vec<StepId>>,
+        literals: Vec<NonZero<i32>>,
+    },
+    Deletion(StepId),
+    /// The conclusion, with the objective bound if it is an optimality conclusion.
+    Conclusion(Option<NonZero<i32>>),
+}
+
+/// A step of the proof, together with how it is written to the trimmed proof.
+#[derive(Debug)]
+struct ProofLine {
+    kind: StepKind,
+    line: Vec<u8>,
+}
+
+/// Read the proof from `source` and write the steps which are needed to derive the conclusion to
+/// `output`.
+pub(crate) fn trim(source: impl Read, output: &mut impl Write) -> anyhow::Result<TrimStatistics> {
+    let lines = read_proof(source)?;
+    let needed = mark_needed_steps(&lines)?;
+
+    let mut statistics = TrimStatistics::default();
+    let mut positions = HashMap::new();
+
+    for (position, proof_line) in lines.iter().enumerate() {
+        let is_needed = match &proof_line.kind {
+            StepKind::Inference(id) => {
+                let _ = positions.insert(*id, position);
+
+                statistics.num_inferences += 1;
+                statistics.num_needed_inferences += needed[position] as usize;
+                needed[position]
+            }
+            StepKind::Nogood { id, .. } => {
+                let _ = positions.insert(*id, position);
+
+                statistics.num_nogoods += 1;
+                statistics.num_needed_nogoods += needed[position] as usize;
+                needed[position]
+            }
+            StepKind::Deletion(id) => {
+                // A deletion is only kept if the deleted step is kept.
+                let is_needed = positions
+                    .get(id)
+                    .is_some_and(|&deleted_position| needed[deleted_position]);
+
+                statistics.num_deletions += 1;
+                statistics.num_needed_deletions += is_needed as usize;
+                is_needed
+            }
+            StepKind::Conclusion(_) => true,
+        };
+
+        if is_needed {
+            output.write_all(&proof_line.line)?;
+        }
+    }
+
+    Ok(statistics)
+}
+
+/// Read the proof up to and including the conclusion.
+fn read_proof(source: impl Read) -> anyhow::Result<Vec<ProofLine>> {
+    let mut reader = ProofReader::new(source, |literal: NonZero<i32>| literal);
+    let mut lines = Vec::new();
+
+    while let Some(step) = reader.next_step()? {
+        let mut line = Vec::new();
+        write_step(&mut line, &step)?;
+
+        let kind = match step {
+            Step::Inference(inference) => StepKind::Inference(inference.id),
+            Step::Nogood(nogood) => StepKind::Nogood {
+                id: nogood.id,
+                literals: nogood.literals,
+                hints: nogood.hints,
+            },
+            Step::Delete(deletion) => StepKind::Deletion(deletion.id),
+            Step::Conclusion(Conclusion::Unsatisfiable) => StepKind::Conclusion(None),
+            Step::Conclusion(Conclusion::Optimal(bound)) => StepKind::Conclusion(Some(bound)),
+        };
+
+        let is_conclusion = matches!(kind, StepKind::Conclusion(_));
+        lines.push(ProofLine { kind, line });
+
+        if is_conclusion {
+            return Ok(lines);
+        }
+    }
+
+    bail!("The proof has no conclusion.")
+}
+
+/// Determine for every step in the proof whether it is needed to derive the conclusion.
+fn mark_needed_steps(lines: &[ProofLine]) -> anyhow::Result<Vec<bool>> {
+    let mut positions = HashMap::new();
+    let mut deletion_positions = HashMap::new();
+
+    for (position, proof_line) in lines.iter().enumerate() {
+        match proof_line.kind {
+            StepKind::Inference(id) | StepKind::Nogood { id, .. } => {
+                let _ = positions.insert(id, position);
+            }
+            StepKind::Deletion(id) => {
+                let _ = deletion_positions.insert(id, position);
+            }
+            StepKind::Conclusion(_) => {}
+        }
+    }
+
+    let mut marker = Marker {
+        lines,
+        positions,
+        deletion_positions,
+        needed: vec![false; lines.len()],
+        covered_until: None,
+    };
+
+    // The conclusion is the last step.
+    let conclusion_position = lines.len() - 1;
+    let StepKind::Conclusion(bound) = lines[conclusion_position].kind else {
+        unreachable!("the proof is read up to and including the conclusion")
+    };
+
+    // An optimality conclusion is derived by the nogood which is the negation of the bound, and
+    // unsatisfiability by the empty nogood. The empty nogood also implies any bound.
+    let concluding_nogood = bound
+        .and_then(|bound| {
+            lines.iter().rposition(|proof_line| {
+                matches!(&proof_line.kind, StepKind::Nogood { literals, .. } if literals[..] == [-bound])
+            })
+        })
+        .or_else(|| {
+            lines.iter().rposition(|proof_line| {
+                matches!(&proof_line.kind, StepKind::Nogood { literals, .. } if literals.is_empty())
+            })
+        });
+
+    match concluding_nogood {
+        Some(position) => marker.needed[position] = true,
+        None => marker.mark_active_before(conclusion_position),
+    }
+
+    for position in (0..conclusion_position).rev() {
+        if !marker.needed[position] {
+            continue;
+        }
+
+        let StepKind::Nogood { id, hints, .. } = &lines[position].kind else {
+            continue;
+        };
+
+        match hints {
+            Some(hints) => {
+                for hint in hints {
+                    let hint_position = marker
+                        .positions
+                        .get(hint)
+                        .copied()
+                        .filter(|&hint_position| hint_position < position)
+                        .with_context(|| format!("Nogood {id} has unknown hint {hint}."))?;
+                    marker.needed[hint_position] = true;
+                }
+            }
+            None => marker.mark_active_before(position),
+        }
+    }
+
+    Ok(marker.needed)
+}
+
+struct Marker<'a> {
+    lines: &'a [ProofLine],
+    positions: HashMap<StepId, usize>,
+    deletion_positions: HashMap<StepId, usize>,
+    needed: Vec<bool>,
+    /// The smallest position for which [`Marker::mark_active_before`] has been called.
+    covered_until: Option<usize>,
+}
+
+impl Marker<'_> {
+    /// Mark every inference and nogood which is active at `position`.
+    ///
+    /// Since the proof is walked backwards, the positions decrease between calls. All steps which
+    /// are active at both positions are already marked by the previous call, so only the steps
+    /// which are deleted in between have to be considered.
+    fn mark_active_before(&mut self, position: usize) {
+        match self.covered_until {
+            None => {
+                for earlier in 0..position {
+                    let (StepKind::Inference(id) | StepKind::Nogood { id, .. }) =
+                        self.lines[earlier].kind
+                    else {
+                        continue;
+                    };
+
+                    let is_active = self
+                        .deletion_positions
+                        .get(&id)
+                        .is_none_or(|&deletion_position| deletion_position > position);
+                    if is_active {
+                        self.needed[earlier] = true;
+                    }
+                }
+            }
+
+            Some(covered_until) => {
+                for later in position + 1..covered_until {
+                    let StepKind::Deletion(id) = self.lines[later].kind else {
+                        continue;
+                    };
+
+                    // The deleted step is active at `position` if it occurs before it.
+                    if let Some(&earlier) = self
+                        .positions
+                        .get(&id)
+                        .filter(|&&earlier| earlier < position)
+                    {
+                        self.needed[earlier] = true;
+                    }
+                }
+            }
+        }
+
+        self.covered_until = Some(position);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trim_proof(proof: &str) -> (String, TrimStatistics) {
+        let mut output = Vec::new();
+        let statistics = trim(proof.as_bytes(), &mut output).expect("valid proof");
+
+        (String::from_utf8(output).expect("valid utf-8"), statistics)
+    }
+
+    #[test]
+    fn steps_which_are_not_hinted_are_removed() {
+        let proof = r#"
+i 1 1 -2 c:1
+n 2 2 0 1
+i 3 -3 c:2
+n 4 3 0 3
+d 4
+i 5 -2 c:3
+n 6 0 2 5
+c UNSAT
+"#;
+
+        let (trimmed, statistics) = trim_proof(proof);
+
+        let expected = r#"i 1 1 -2 c:1
+n 2 2 0 1
+i 5 -2 c:3
+n 6 0 2 5
+c UNSAT
+"#;
+        assert_eq!(expected, trimmed);
+
+        assert_eq!(2, statistics.num_needed_inferences);
+        assert_eq!(3, statistics.num_inferences);
+        assert_eq!(2, statistics.num_needed_nogoods);
+        assert_eq!(0, statistics.num_needed_deletions);
+    }
+
+    #[test]
+    fn nogood_without_hints_needs_all_active_steps() {
+        let proof = r#"
+i 1 1 c:1
+i 2 2 c:1
+d 2
+n 3 -1
+i 4 3 c:2
+n 5 0 3 4
+c UNSAT
+"#;
+
+        let (trimmed, statistics) = trim_proof(proof);
+
+        let expected = r#"i 1 1 c:1
+n 3 -1
+i 4 3 c:2
+n 5 0 3 4
+c UNSAT
+"#;
+        assert_eq!(expected, trimmed);
+
+        assert_eq!(2, statistics.num_needed_inferences);
+        assert_eq!(2, statistics.num_needed_nogoods);
+        assert_eq!(0, statistics.num_needed_deletions);
+    }
+
+    #[test]
+    fn optimality_conclusion_needs_negated_bound() {
+        let proof = r#"
+i 1 -3 1 c:1
+i 2 -1 c:2
+i 3 4 c:3
+n 4 4 0 3
+n 5 -3 0 2 1
+c 3
+"#;
+
+        let (trimmed, statistics) = trim_proof(proof);
+
+        let expected = r#"i 1 -3 1 c:1
+i 2 -1 c:2
+n 5 -3 0 2 1
+c 3
+"#;
+        assert_eq!(expected, trimmed);
+
+        assert_eq!(2, statistics.num_needed_inferences);
+        assert_eq!(1, statistics.num_needed_nogoods);
+    }
+}
@@ -0,0 +1,135 @@
+//! Contains the encoding of the primitive values in the binary format (see [`Format::Binary`]).
+//!
+//! [`Format::Binary`]: crate::Format::Binary
+use std::io::Read;
+use std::io::Write;
+use std::num::NonZero;
+
+use crate::reader::DrcpError;
+
+/// The tag which starts an inference step.
+pub(crate) const INFERENCE_TAG: u8 = b'i';
+/// The tag which starts a nogood step.
+pub(crate) const NOGOOD_TAG: u8 = b'n';
+/// The tag which starts a deletion step.
+pub(crate) const DELETION_TAG: u8 = b'd';
+/// The tag which starts a conclusion step.
+pub(crate) const CONCLUSION_TAG: u8 = b'c';
+
+/// Write an unsigned integer using the variable-length LEB128 encoding; every byte contains 7 bits
+/// of the integer (starting with the least significant bits), and the most significant bit of a
+/// byte indicates whether more bytes follow.
+pub(crate) fn write_unsigned(sink: &mut impl Write, mut value: u64) -> std::io::Result<()> {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            return sink.write_all(&[byte]);
+        }
+
+        sink.write_all(&[byte | 0x80])?;
+    }
+}
+
+/// Write a literal, where [`None`] is encoded as 0.
+///
+/// A literal `l` is encoded as the unsigned integer `2 * |l|` if it is positive and `2 * |l| + 1`
+/// if it is negative, which cannot be 0.
+pub(crate) fn write_literal(
+    sink: &mut impl Write,
+    literal: Option<NonZero<i32>>,
+) -> std::io::Result<()> {
+    let code = literal.map_or(0, |literal| {
+        2 * literal.unsigned_abs().get() as u64 + literal.is_negative() as u64
+    });
+
+    write_unsigned(sink, code)
+}
+
+/// Read an unsigned integer which was written by [`write_unsigned`].
+pub(crate) fn read_unsigned(source: &mut impl Read) -> Result<u64, DrcpError> {
+    let mut value = 0;
+    let mut shift = 0;
+
+    loop {
+        let mut byte = [0];
+        source.read_exact(&mut byte)?;
+
+        // The last group can only contribute the highest bit of the integer
+        if shift >= u64::BITS || (shift == 63 && byte[0] & 0x7e != 0) {
+            return Err(DrcpError::Syntax(
+                "integer does not fit in 64 bits".to_owned(),
+            ));
+        }
+        value |= ((byte[0] & 0x7f) as u64) << shift;
+        shift += 7;
+
+        if byte[0] & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+}
+
+/// Read a literal which was written by [`write_literal`].
+pub(crate) fn read_literal(source: &mut impl Read) -> Result<Option<NonZero<i32>>, DrcpError> {
+    let code = read_unsigned(source)?;
+    if code == 0 {
+        return Ok(None);
+    }
+
+    let magnitude = i32::try_from(code / 2)
+        .ok()
+        .filter(|&magnitude| magnitude != 0)
+        .ok_or_else(|| DrcpError::Syntax(format!("invalid literal code {code}")))?;
+    let literal = if code % 2 == 1 { -magnitude } else { magnitude };
+
+    Ok(NonZero::new(literal))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsigned_integers_round_trip() {
+        for value in [0, 1, 127, 128, 300, u32::MAX as u64, u64::MAX] {
+            let mut buffer = Vec::new();
+            write_unsigned(&mut buffer, value).expect("no error writing");
+
+            let actual = read_unsigned(&mut buffer.as_slice()).expect("valid integer");
+            assert_eq!(value, actual);
+        }
+    }
+
+    #[test]
+    fn unsigned_integers_beyond_64_bits_are_rejected() {
+        // The first nine groups contribute 63 bits, so the tenth group can only contribute the
+        // highest bit
+        let mut buffer = vec![0xff; 9];
+        buffer.push(0x02);
+
+        let result = read_unsigned(&mut buffer.as_slice());
+        assert!(matches!(result, Err(DrcpError::Syntax(_))));
+    }
+
+    #[test]
+    fn small_unsigned_integers_take_one_byte() {
+        let mut buffer = Vec::new();
+        write_unsigned(&mut buffer, 127).expect("no error writing");
+        assert_eq!(vec![0x7f], buffer);
+    }
+
+    #[test]
+    fn literals_round_trip() {
+        for literal in [1, -1, 42, -42, i32::MAX, -i32::MAX] {
+            let literal = NonZero::new(literal);
+
+            let mut buffer = Vec::new();
+            write_literal(&mut buffer, literal).expect("no error writing");
+
+            let actual = read_literal(&mut buffer.as_slice()).expect("valid literal");
+            assert_eq!(literal, actual);
+        }
+    }
+}
@@ -2,11 +2,24 @@
 ///
 /// The textual format takes more disk space, but may be easier to read when looking at the files
 /// directly. On the other hand, the binary format is more compact and marginally faster to write.
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum Format {
     /// A textual UTF-8 encoded proof.
     #[default]
     Text,
     /// A binary encoded proof.
+    ///
+    /// Every step starts with the same character as in the textual format (`i`, `n`, `d` or `c`)
+    /// encoded as a single byte, followed by the fields of the step. All integers are written
+    /// using the variable-length LEB128 encoding, and a literal `l` is written as the integer
+    /// `2 * |l|` if it is positive and `2 * |l| + 1` if it is negative. Since literals are never
+    /// encoded as 0, the value 0 is used to terminate lists of literals or to indicate that an
+    /// optional value is absent. The steps are encoded as follows:
+    /// - Inference: `i <step_id> <premises> 0 <propagated or 0> <constraint tag or 0> <label
+    ///   length> <label bytes>`, where the label length is 0 if there is no label.
+    /// - Nogood: `n <step_id> <literals> 0 <number of hints + 1> <hints>`, where the number of
+    ///   hints is written as 0 if there are no hints.
+    /// - Deletion: `d <step_id>`.
+    /// - Conclusion: `c <objective bound literal or 0>`, where 0 indicates unsatisfiability.
     Binary,
 }
@@ -7,6 +7,7 @@
 //! [`LiteralDefinitions`].
 
 mod atomic;
+mod binary;
 mod format;
 mod literal_definitions;
 
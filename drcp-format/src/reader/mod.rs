@@ -22,13 +22,15 @@ use nom::combinator::map_opt;
 use nom::combinator::opt;
 use nom::combinator::recognize;
 use nom::combinator::value;
+use nom::multi::many0;
 use nom::multi::many0_count;
-use nom::multi::separated_list0;
 use nom::sequence::pair;
 use nom::sequence::preceded;
 use nom::sequence::tuple;
 use nom::IResult;
 
+use crate::binary;
+use crate::format::Format;
 use crate::steps::Conclusion;
 use crate::steps::Deletion;
 use crate::steps::Inference;
@@ -48,6 +50,9 @@ pub type ReadStep<'a, AtomicConstraint> =
 /// assumed this is a total mapping, i.e. all literals encountered in the proof can be mapped to an
 /// atomic constraint.
 ///
+/// Proofs in the [`Format::Binary`] format can be read by creating the reader with
+/// [`ProofReader::with_format`].
+///
 /// Note that the reader does not perform any type of validity checking of the proof. It will
 /// happily parse any garbage that is within the format specification. For example, a valid DRCP
 /// proof will contain exactly one conclusion step, which is the last step of the proof. This
@@ -106,6 +111,8 @@ pub type ReadStep<'a, AtomicConstraint> =
 #[derive(Debug)]
 pub struct ProofReader<R, AtomicConstraints> {
     source: BufReader<R>,
+    /// The format in which the proof is written.
+    format: Format,
     string_buffer: String,
     atomics: AtomicConstraints,
 }
@@ -116,8 +123,20 @@ impl<R: Read, AtomicConstraints> ProofReader<R, AtomicConstraints> {
     /// The `atomics` are used to map the proof literals to atomic constraints. This is likely
     /// based on a parsed `.lits` file, but that does not have to be the case.
     pub fn new(source: R, atomics: AtomicConstraints) -> ProofReader<R, AtomicConstraints> {
+        ProofReader::with_format(Format::Text, source, atomics)
+    }
+
+    /// Construct a new proof reader which reads a proof in the given [`Format`] from `source`.
+    ///
+    /// See [`ProofReader::new`] for more information.
+    pub fn with_format(
+        format: Format,
+        source: R,
+        atomics: AtomicConstraints,
+    ) -> ProofReader<R, AtomicConstraints> {
         ProofReader {
             source: BufReader::new(source),
+            format,
             string_buffer: String::new(),
             atomics,
         }
@@ -139,6 +158,15 @@ where
     ) -> Result<Option<ReadStep<'_, AtomicConstraints::Atomic>>, DrcpError> {
         self.string_buffer.clear();
 
+        if self.format == Format::Binary {
+            let Some(step) = read_binary_step(&mut self.source, &mut self.string_buffer)? else {
+                return Ok(None);
+            };
+
+            let step = Self::map_step(&self.atomics, step);
+            return Ok(Some(step));
+        }
+
         // Read lines until we find a non-empty line. The contents of `line` will be trimmed.
         while self.string_buffer.trim().is_empty() {
             let read_bytes = self.source.read_line(&mut self.string_buffer)?;
@@ -151,14 +179,14 @@ where
 
         let (_, step) = proof_step(self.string_buffer.trim())?;
 
-        let step = self.map_step(step);
+        let step = Self::map_step(&self.atomics, step);
 
         Ok(Some(step))
     }
 
     /// Map the literals to the atomic constraints in the proof step.
     fn map_step<'s>(
-        &self,
+        atomics: &AtomicConstraints,
         step: ReadStep<'s, NonZero<i32>>,
     ) -> ReadStep<'s, AtomicConstraints::Atomic> {
         match step {
@@ -174,9 +202,9 @@ where
                 hint_label,
                 premises: premises
                     .into_iter()
-                    .map(|literal| self.atomics.to_atomic(literal))
+                    .map(|literal| atomics.to_atomic(literal))
                     .collect(),
-                propagated: propagated.map(|p| self.atomics.to_atomic(p)),
+                propagated: propagated.map(|p| atomics.to_atomic(p)),
             }),
 
             Step::Nogood(Nogood {
@@ -187,7 +215,7 @@ where
                 id,
                 literals: literals
                     .into_iter()
-                    .map(|literal| self.atomics.to_atomic(literal))
+                    .map(|literal| atomics.to_atomic(literal))
                     .collect(),
                 hints,
             }),
@@ -201,12 +229,111 @@ where
             }
 
             Step::Conclusion(Conclusion::Optimal(literal)) => {
-                Step::Conclusion(Conclusion::Optimal(self.atomics.to_atomic(literal)))
+                Step::Conclusion(Conclusion::Optimal(atomics.to_atomic(literal)))
             }
         }
     }
 }
 
+/// Read the next step of a proof in the [`Format::Binary`] format, where the label of an inference
+/// is read into `label_buffer`.
+fn read_binary_step<'s>(
+    source: &mut impl Read,
+    label_buffer: &'s mut String,
+) -> Result<Option<ReadStep<'s, NonZero<i32>>>, DrcpError> {
+    let mut tag = [0];
+    if source.read(&mut tag)? == 0 {
+        // The end of the file has been reached.
+        return Ok(None);
+    }
+
+    let step = match tag[0] {
+        binary::INFERENCE_TAG => {
+            let id = read_binary_step_id(source)?;
+            let premises = read_binary_literal_list(source)?;
+            let propagated = binary::read_literal(source)?;
+
+            let hint_constraint_id =
+                u32::try_from(binary::read_unsigned(source)?).map_err(|_| {
+                    DrcpError::Syntax("constraint tag does not fit in 32 bits".to_owned())
+                })?;
+
+            // The length is not trusted, so the label is read without allocating it up front
+            let label_length = binary::read_unsigned(source)?;
+            let mut label = Vec::new();
+            let _ = source.by_ref().take(label_length).read_to_end(&mut label)?;
+            if label.len() as u64 != label_length {
+                return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+            }
+            *label_buffer = String::from_utf8(label)
+                .map_err(|_| DrcpError::Syntax("label is not valid UTF-8".to_owned()))?;
+
+            Step::Inference(Inference {
+                id,
+                hint_constraint_id: NonZero::new(hint_constraint_id),
+                hint_label: (label_length > 0).then_some(label_buffer.as_str()),
+                premises,
+                propagated,
+            })
+        }
+
+        binary::NOGOOD_TAG => {
+            let id = read_binary_step_id(source)?;
+            let literals = read_binary_literal_list(source)?;
+
+            let hints = match binary::read_unsigned(source)? {
+                0 => None,
+                num_hints_plus_one => {
+                    // The number of hints is not trusted, so the hints are not allocated up front
+                    let mut hints = Vec::new();
+                    for _ in 1..num_hints_plus_one {
+                        hints.push(read_binary_step_id(source)?);
+                    }
+                    Some(hints)
+                }
+            };
+
+            Step::Nogood(Nogood {
+                id,
+                literals,
+                hints,
+            })
+        }
+
+        binary::DELETION_TAG => Step::Delete(Deletion {
+            id: read_binary_step_id(source)?,
+        }),
+
+        binary::CONCLUSION_TAG => match binary::read_literal(source)? {
+            Some(literal) => Step::Conclusion(Conclusion::Optimal(literal)),
+            None => Step::Conclusion(Conclusion::Unsatisfiable),
+        },
+
+        unknown => {
+            return Err(DrcpError::Syntax(format!(
+                "unknown step tag {unknown:#04x}"
+            )))
+        }
+    };
+
+    Ok(Some(step))
+}
+
+/// Read a non-zero step id in the binary format.
+fn read_binary_step_id(source: &mut impl Read) -> Result<StepId, DrcpError> {
+    NonZero::new(binary::read_unsigned(source)?)
+        .ok_or_else(|| DrcpError::Syntax("step ids cannot be 0".to_owned()))
+}
+
+/// Read a list of literals in the binary format, which is terminated by 0.
+fn read_binary_literal_list(source: &mut impl Read) -> Result<Vec<NonZero<i32>>, DrcpError> {
+    let mut literals = Vec::new();
+    while let Some(literal) = binary::read_literal(source)? {
+        literals.push(literal);
+    }
+    Ok(literals)
+}
+
 /// Parse a proof step from a line.
 ///
 /// `input` is assumed to be a single line, with leading and trailing whitespace removed. If this
@@ -226,13 +353,12 @@ fn inference_step(input: &str) -> IResult<&str, Inference<'_, Vec<NonZero<i32>>,
         tuple((
             tag("i "),
             step_id,
-            tag(" "),
             literal_list,
             opt(preceded(tag(" 0 "), literal)),
             opt(preceded(tag(" c:"), constraint_id)),
             opt(preceded(tag(" l:"), identifier)),
         )),
-        |(_, id, _, premises, propagated, hint_constraint_id, hint_label)| Inference {
+        |(_, id, premises, propagated, hint_constraint_id, hint_label)| Inference {
             id,
             hint_constraint_id,
             hint_label,
@@ -248,15 +374,10 @@ fn nogood_step(input: &str) -> IResult<&str, Nogood<Vec<NonZero<i32>>, Vec<StepI
         tuple((
             tag("n "),
             step_id,
-            tag(" "),
             literal_list,
-            opt(preceded(
-                // Hack! If `literal_list` is empty, then the space will be parsed already.
-                alt((tag("0 "), tag(" 0 "))),
-                separated_list0(tag(" "), step_id),
-            )),
+            opt(preceded(tag(" 0"), many0(preceded(tag(" "), step_id)))),
         )),
-        |(_, id, _, literals, hints)| Nogood {
+        |(_, id, literals, hints)| Nogood {
             id,
             hints,
             literals,
@@ -280,9 +401,9 @@ fn conclusion_step(input: &str) -> IResult<&str, Conclusion<NonZero<i32>>> {
     )(input)
 }
 
-/// Parses a space-separated list of non-zero signed integers.
+/// Parses a list of non-zero signed integers, each of which is preceded by a space.
 fn literal_list(input: &str) -> IResult<&str, Vec<NonZero<i32>>> {
-    separated_list0(tag(" "), literal)(input)
+    many0(preceded(tag(" "), literal))(input)
 }
 
 /// Parses a single non-zero signed integer.
@@ -311,6 +432,7 @@ fn identifier(input: &str) -> IResult<&str, &str> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::writer::ProofWriter;
 
     #[test]
     fn inference_nogood_without_hints() {
@@ -361,4 +483,135 @@ mod tests {
         };
         assert_eq!(Some(Step::Nogood(expected_nogood)), nogood_step);
     }
+
+    #[test]
+    fn empty_nogood_without_hints() {
+        let source = "n 100\n";
+        let mut reader = ProofReader::new(source.as_bytes(), std::convert::identity);
+
+        let nogood_step = reader.next_step().expect("valid drcp nogood step");
+        let expected_nogood = Nogood {
+            id: NonZero::new(100).unwrap(),
+            literals: vec![],
+            hints: None,
+        };
+        assert_eq!(Some(Step::Nogood(expected_nogood)), nogood_step);
+    }
+
+    #[test]
+    fn binary_proof_round_trips() {
+        let lit = |num: i32| NonZero::new(num).unwrap();
+        let step_id = |num: u64| NonZero::new(num).unwrap();
+
+        let mut proof = Vec::new();
+        let mut writer = ProofWriter::new(Format::Binary, &mut proof, std::convert::identity);
+        let _ = writer
+            .log_inference(
+                NonZero::new(20),
+                Some("linear_bound"),
+                [lit(4), lit(-5)],
+                Some(lit(-2)),
+            )
+            .unwrap();
+        let _ = writer.log_inference(None, None, [lit(7)], None).unwrap();
+        let nogood_id = writer
+            .log_nogood_clause([lit(1), lit(-3), lit(500)], Some([step_id(1), step_id(2)]))
+            .unwrap();
+        let _ = writer.log_nogood_clause([], None::<[StepId; 0]>).unwrap();
+        writer.log_deletion(nogood_id).unwrap();
+        let _ = writer.optimal(lit(-9)).unwrap();
+
+        let mut reader =
+            ProofReader::with_format(Format::Binary, proof.as_slice(), std::convert::identity);
+
+        let expected_inference = Inference {
+            id: step_id(1),
+            hint_constraint_id: NonZero::new(20),
+            hint_label: Some("linear_bound"),
+            premises: vec![lit(4), lit(-5)],
+            propagated: Some(lit(-2)),
+        };
+        assert_eq!(
+            Some(Step::Inference(expected_inference)),
+            reader.next_step().expect("valid inference step")
+        );
+
+        let expected_inference = Inference {
+            id: step_id(2),
+            hint_constraint_id: None,
+            hint_label: None,
+            premises: vec![lit(7)],
+            propagated: None,
+        };
+        assert_eq!(
+            Some(Step::Inference(expected_inference)),
+            reader.next_step().expect("valid inference step")
+        );
+
+        let expected_nogood = Nogood {
+            id: step_id(3),
+            literals: vec![lit(1), lit(-3), lit(500)],
+            hints: Some(vec![step_id(1), step_id(2)]),
+        };
+        assert_eq!(
+            Some(Step::Nogood(expected_nogood)),
+            reader.next_step().expect("valid nogood step")
+        );
+
+        let expected_nogood = Nogood {
+            id: step_id(4),
+            literals: vec![],
+            hints: None,
+        };
+        assert_eq!(
+            Some(Step::Nogood(expected_nogood)),
+            reader.next_step().expect("valid nogood step")
+        );
+
+        assert_eq!(
+            Some(Step::Delete(Deletion { id: step_id(3) })),
+            reader.next_step().expect("valid deletion step")
+        );
+        assert_eq!(
+            Some(Step::Conclusion(Conclusion::Optimal(lit(-9)))),
+            reader.next_step().expect("valid conclusion step")
+        );
+        assert_eq!(None, reader.next_step().expect("finished reading source"));
+    }
+
+    #[test]
+    fn binary_unsat_conclusion_round_trips() {
+        let mut proof = Vec::new();
+        let writer = ProofWriter::new(Format::Binary, &mut proof, std::convert::identity);
+        let _ = writer.unsat().unwrap();
+
+        let mut reader =
+            ProofReader::with_format(Format::Binary, proof.as_slice(), std::convert::identity);
+        assert_eq!(
+            Some(Step::Conclusion(Conclusion::Unsatisfiable)),
+            reader.next_step().expect("valid conclusion step")
+        );
+        assert_eq!(None, reader.next_step().expect("finished reading source"));
+    }
+
+    #[test]
+    fn binary_proof_with_unknown_tag_is_rejected() {
+        let proof = [b'x', 1];
+        let mut reader =
+            ProofReader::with_format(Format::Binary, proof.as_slice(), std::convert::identity);
+        assert!(matches!(reader.next_step(), Err(DrcpError::Syntax(_))));
+    }
+
+    #[test]
+    fn binary_inference_with_truncated_label_is_rejected() {
+        // An inference with id 1, no premises, no propagated literal, no constraint tag, and a
+        // label which is claimed to be much longer than the remainder of the proof
+        let mut proof = vec![binary::INFERENCE_TAG, 1, 0, 0, 0];
+        binary::write_unsigned(&mut proof, u64::MAX).expect("no error writing");
+        proof.extend_from_slice(b"label");
+
+        let mut reader =
+            ProofReader::with_format(Format::Binary, proof.as_slice(), std::convert::identity);
+        assert!(matches!(reader.next_step(), Err(DrcpError::Io(_))));
+    }
 }
@@ -12,6 +12,7 @@ use std::num::NonZeroU64;
 
 pub use literal_code_provider::*;
 
+use crate::binary;
 use crate::format::Format;
 use crate::steps::Conclusion;
 use crate::steps::Deletion;
@@ -235,8 +236,29 @@ where
         Ok(())
     }
 
-    fn write_binary(self, _sink: &mut impl Write) -> std::io::Result<()> {
-        todo!()
+    fn write_binary(self, sink: &mut impl Write) -> std::io::Result<()> {
+        sink.write_all(&[binary::NOGOOD_TAG])?;
+        binary::write_unsigned(sink, self.id.get())?;
+
+        for literal in self.literals {
+            binary::write_literal(sink, Some(literal))?;
+        }
+        binary::write_literal(sink, None)?;
+
+        match self.hints {
+            Some(hints) => {
+                // The number of hints has to be written before the hints themselves
+                let hints = hints.into_iter().collect::<Vec<_>>();
+                binary::write_unsigned(sink, hints.len() as u64 + 1)?;
+
+                for hint in hints {
+                    binary::write_unsigned(sink, hint.get())?;
+                }
+            }
+            None => binary::write_unsigned(sink, 0)?,
+        }
+
+        Ok(())
     }
 }
 
@@ -248,8 +270,13 @@ impl WritableProofStep for Conclusion<NonZeroI32> {
         }
     }
 
-    fn write_binary(self, _sink: &mut impl Write) -> std::io::Result<()> {
-        todo!()
+    fn write_binary(self, sink: &mut impl Write) -> std::io::Result<()> {
+        sink.write_all(&[binary::CONCLUSION_TAG])?;
+
+        match self {
+            Conclusion::Unsatisfiable => binary::write_literal(sink, None),
+            Conclusion::Optimal(literal) => binary::write_literal(sink, Some(literal)),
+        }
     }
 }
 
@@ -281,8 +308,27 @@ where
         Ok(())
     }
 
-    fn write_binary(self, _sink: &mut impl Write) -> std::io::Result<()> {
-        todo!()
+    fn write_binary(self, sink: &mut impl Write) -> std::io::Result<()> {
+        sink.write_all(&[binary::INFERENCE_TAG])?;
+        binary::write_unsigned(sink, self.id.get())?;
+
+        for literal in self.premises {
+            binary::write_literal(sink, Some(literal))?;
+        }
+        binary::write_literal(sink, None)?;
+
+        binary::write_literal(sink, self.propagated)?;
+        binary::write_unsigned(
+            sink,
+            self.hint_constraint_id
+                .map_or(0, |constraint_id| constraint_id.get() as u64),
+        )?;
+
+        let label = self.hint_label.unwrap_or_default();
+        binary::write_unsigned(sink, label.len() as u64)?;
+        sink.write_all(label.as_bytes())?;
+
+        Ok(())
     }
 }
 
@@ -291,8 +337,9 @@ impl WritableProofStep for Deletion {
         writeln!(sink, "d {}", self.id)
     }
 
-    fn write_binary(self, _sink: &mut impl Write) -> std::io::Result<()> {
-        todo!()
+    fn write_binary(self, sink: &mut impl Write) -> std::io::Result<()> {
+        sink.write_all(&[binary::DELETION_TAG])?;
+        binary::write_unsigned(sink, self.id.get())
     }
 }
 
@@ -386,6 +433,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn write_binary_inference() {
+        let mut buffer = Vec::new();
+        Inference {
+            id: TEST_ID,
+            hint_constraint_id: Some(NonZero::new(1).unwrap()),
+            hint_label: Some("l"),
+            premises: [lit(2), lit(-3)],
+            propagated: Some(lit(1)),
+        }
+        .write_binary(&mut buffer)
+        .expect("no error writing");
+
+        assert_eq!(vec![b'i', 1, 4, 7, 0, 2, 1, 1, b'l'], buffer);
+    }
+
+    #[test]
+    fn write_binary_nogood_with_large_literal() {
+        let mut buffer = Vec::new();
+        Nogood {
+            id: TEST_ID,
+            literals: [lit(-100)],
+            hints: Some([TEST_ID]),
+        }
+        .write_binary(&mut buffer)
+        .expect("no error writing");
+
+        // The literal -100 is encoded as 201, which requires two bytes
+        assert_eq!(vec![b'n', 1, 0xc9, 0x01, 0, 2, 1], buffer);
+    }
+
     fn lit(num: i32) -> NonZero<i32> {
         NonZero::new(num).unwrap()
     }
@@ -0,0 +1,79 @@
+use pumpkin_solver::branching::branchers::dynamic_brancher::DynamicBrancher;
+use pumpkin_solver::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use pumpkin_solver::branching::branchers::warm_start::WarmStart;
+use pumpkin_solver::branching::value_selection::DynamicValueSelector;
+use pumpkin_solver::branching::value_selection::InDomainMin;
+use pumpkin_solver::branching::value_selection::SolutionGuided;
+use pumpkin_solver::branching::variable_selection::DomWDeg;
+use pumpkin_solver::branching::variable_selection::Impact;
+use pumpkin_solver::Solver;
+use pyo3::prelude::*;
+
+use crate::variables::Predicate;
+use crate::variables::VariableMap;
+
+/// The strategy which is used to select the integer variable to branch on.
+#[pyclass(eq, eq_int)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum VariableSelection {
+    /// The default search of the solver.
+    Default,
+    /// Select the variable with the smallest ratio between its domain size and its weighted
+    /// degree, where the weights are increased by conflicts.
+    DomWDeg,
+    /// Select the variable of which the decisions have reduced the search space the most.
+    Impact,
+}
+
+impl VariableSelection {
+    /// Creates the brancher which branches over the integer variables using this strategy,
+    /// assigning the smallest value in the domain; the remaining variables are fixed using the
+    /// default search.
+    ///
+    /// When optimising, variables are assigned the value they have in the most recent solution
+    /// instead (if it is still in their domain).
+    ///
+    /// If `hints` are provided, then these are branched on first until the first solution is found.
+    pub fn create_brancher(
+        self,
+        solver: &Solver,
+        variable_map: &VariableMap,
+        is_optimisation: bool,
+        hints: &[Predicate],
+    ) -> DynamicBrancher {
+        let variables = variable_map.integer_domains().collect::<Vec<_>>();
+        let value_selector = || {
+            if is_optimisation {
+                DynamicValueSelector::new(Box::new(SolutionGuided::new(InDomainMin)))
+            } else {
+                DynamicValueSelector::new(Box::new(InDomainMin))
+            }
+        };
+
+        let mut brancher = match self {
+            VariableSelection::Default => DynamicBrancher::new(vec![]),
+            VariableSelection::DomWDeg => DynamicBrancher::new(vec![Box::new(
+                IndependentVariableValueBrancher::new(DomWDeg::new(&variables), value_selector()),
+            )]),
+            VariableSelection::Impact => DynamicBrancher::new(vec![Box::new(
+                IndependentVariableValueBrancher::new(Impact::new(&variables), value_selector()),
+            )]),
+        };
+        brancher.add_brancher(Box::new(solver.default_brancher()));
+
+        if hints.is_empty() {
+            brancher
+        } else {
+            let hints = hints
+                .iter()
+                .map(|hint| hint.to_solver_predicate(variable_map))
+                .collect::<Vec<_>>();
+            DynamicBrancher::new(vec![Box::new(WarmStart::new(hints, brancher))])
+        }
+    }
+}
+
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<VariableSelection>()?;
+    Ok(())
+}
@@ -1,7 +1,9 @@
+use pumpkin_solver::options;
 use pumpkin_solver::variables::AffineView;
 use pumpkin_solver::variables::DomainId;
 use pumpkin_solver::variables::Literal;
 
+use super::globals::AllDifferentConsistency;
 use crate::variables::BoolExpression;
 use crate::variables::IntExpression;
 use crate::variables::VariableMap;
@@ -29,8 +31,8 @@ impl PythonConstraintArg for BoolExpression {
     }
 }
 
-impl PythonConstraintArg for i32 {
-    type Output = i32;
+impl PythonConstraintArg for i64 {
+    type Output = i64;
 
     fn to_solver_constraint_argument(self, _: &VariableMap) -> Self::Output {
         self
@@ -46,3 +48,14 @@ impl<Arg: PythonConstraintArg> PythonConstraintArg for Vec<Arg> {
             .collect()
     }
 }
+
+impl PythonConstraintArg for AllDifferentConsistency {
+    type Output = options::AllDifferentConsistency;
+
+    fn to_solver_constraint_argument(self, _: &VariableMap) -> Self::Output {
+        match self {
+            AllDifferentConsistency::Bounds => options::AllDifferentConsistency::Bounds,
+            AllDifferentConsistency::Domain => options::AllDifferentConsistency::Domain,
+        }
+    }
+}
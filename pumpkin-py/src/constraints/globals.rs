@@ -3,6 +3,7 @@ use pumpkin_solver::constraints::{self};
 use pyo3::pyclass;
 use pyo3::pymethods;
 
+use super::arguments::PythonConstraintArg;
 use crate::variables::*;
 
 macro_rules! python_constraint {
@@ -10,7 +11,7 @@ macro_rules! python_constraint {
         #[pyclass]
         #[derive(Clone)]
         pub(crate) struct $name {
-            $($field: $type),+
+            $(pub(crate) $field: $type),+
         }
 
         #[pymethods]
@@ -63,6 +64,21 @@ python_constraint! {
     }
 }
 
+/// The level of consistency enforced by the propagator of [`AllDifferentWithConsistency`].
+#[pyclass(eq, eq_int)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum AllDifferentConsistency {
+    Bounds,
+    Domain,
+}
+
+python_constraint! {
+    AllDifferentWithConsistency: all_different_with_consistency {
+        variables: Vec<IntExpression>,
+        consistency: AllDifferentConsistency,
+    }
+}
+
 python_constraint! {
     BinaryEquals: binary_equals {
         lhs: IntExpression,
@@ -94,9 +110,25 @@ python_constraint! {
 python_constraint! {
     Cumulative: cumulative {
         start_times: Vec<IntExpression>,
-        durations: Vec<i32>,
-        resource_requirements: Vec<i32>,
-        resource_capacity: i32,
+        durations: Vec<i64>,
+        resource_requirements: Vec<i64>,
+        resource_capacity: i64,
+    }
+}
+
+python_constraint! {
+    CumulativeVar: cumulative {
+        start_times: Vec<IntExpression>,
+        durations: Vec<IntExpression>,
+        resource_requirements: Vec<IntExpression>,
+        resource_capacity: IntExpression,
+    }
+}
+
+python_constraint! {
+    Disjunctive: disjunctive {
+        start_times: Vec<IntExpression>,
+        durations: Vec<i64>,
     }
 }
 
@@ -119,14 +151,14 @@ python_constraint! {
 python_constraint! {
     Equals: equals {
         terms: Vec<IntExpression>,
-        rhs: i32,
+        rhs: i64,
     }
 }
 
 python_constraint! {
     LessThanOrEquals: less_than_or_equals {
         terms: Vec<IntExpression>,
-        rhs: i32,
+        rhs: i64,
     }
 }
 
@@ -147,7 +179,7 @@ python_constraint! {
 python_constraint! {
     NotEquals: not_equals {
         terms: Vec<IntExpression>,
-        rhs: i32,
+        rhs: i64,
     }
 }
 
@@ -167,6 +199,80 @@ python_constraint! {
     }
 }
 
+python_constraint! {
+    Table: table {
+        variables: Vec<IntExpression>,
+        table: Vec<Vec<i64>>,
+    }
+}
+
+python_constraint! {
+    NegativeTable: negative_table {
+        variables: Vec<IntExpression>,
+        table: Vec<Vec<i64>>,
+    }
+}
+
+/// A deterministic finite automaton which is used by [`Regular`] and [`CostRegular`].
+///
+/// The states are numbered `0, ..., num_states - 1` and every transition is given as
+/// `(from, value, to)`.
+#[pyclass]
+#[derive(Clone)]
+pub struct Automaton {
+    num_states: usize,
+    initial_state: usize,
+    accepting_states: Vec<usize>,
+    transitions: Vec<(usize, i64, usize)>,
+}
+
+#[pymethods]
+impl Automaton {
+    #[new]
+    fn new(
+        num_states: usize,
+        initial_state: usize,
+        accepting_states: Vec<usize>,
+        transitions: Vec<(usize, i64, usize)>,
+    ) -> Self {
+        Automaton {
+            num_states,
+            initial_state,
+            accepting_states,
+            transitions,
+        }
+    }
+}
+
+impl PythonConstraintArg for Automaton {
+    type Output = constraints::DeterministicFiniteAutomaton;
+
+    fn to_solver_constraint_argument(self, _: &VariableMap) -> Self::Output {
+        constraints::DeterministicFiniteAutomaton::new(
+            self.num_states,
+            self.initial_state,
+            self.accepting_states,
+            self.transitions,
+        )
+    }
+}
+
+python_constraint! {
+    Regular: regular {
+        variables: Vec<IntExpression>,
+        automaton: Automaton,
+    }
+}
+
+python_constraint! {
+    CostRegular: cost_regular {
+        variables: Vec<IntExpression>,
+        automaton: Automaton,
+        transition_costs: Vec<i64>,
+        cost: IntExpression,
+    }
+}
+
 python_constraint! {
     Conjunction: conjunction {
         literals: Vec<BoolExpression>,
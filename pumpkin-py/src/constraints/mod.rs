@@ -36,7 +36,7 @@ macro_rules! declare_constraints {
             }
         }
 
-        pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+        fn register_constraints(m: &Bound<'_, PyModule>) -> PyResult<()> {
             $(m.add_class::<$constraint>()?;)+
             Ok(())
         }
@@ -47,21 +47,35 @@ declare_constraints! {
     Constraint {
         Absolute,
         AllDifferent,
+        AllDifferentWithConsistency,
         BinaryEquals,
         BinaryLessThanEqual,
         BinaryLessThan,
         BinaryNotEquals,
+        CostRegular,
         Cumulative,
+        CumulativeVar,
+        Disjunctive,
         Division,
         Element,
         Equals,
         LessThanOrEquals,
         Maximum,
         Minimum,
+        NegativeTable,
         NotEquals,
         Plus,
+        Regular,
+        Table,
         Times,
         Clause,
         Conjunction,
     }
 }
+
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    register_constraints(m)?;
+    m.add_class::<AllDifferentConsistency>()?;
+    m.add_class::<Automaton>()?;
+    Ok(())
+}
@@ -1,3 +1,4 @@
+mod branching;
 mod constraints;
 mod model;
 mod optimisation;
@@ -35,6 +36,7 @@ fn pumpkin_py(python: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<result::SatisfactionUnderAssumptionsResult>()?;
     m.add_class::<result::Solution>()?;
 
+    submodule!(branching, python, m);
     submodule!(constraints, python, m);
     submodule!(optimisation, python, m);
 
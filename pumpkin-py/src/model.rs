@@ -1,25 +1,37 @@
 use std::num::NonZero;
 use std::path::PathBuf;
 
+use pumpkin_solver::branching::branchers::dynamic_brancher::DynamicBrancher;
 use pumpkin_solver::containers::KeyedVec;
+use pumpkin_solver::optimisation::bisection::Bisection;
+use pumpkin_solver::optimisation::core_guided::CoreGuided;
+use pumpkin_solver::optimisation::large_neighbourhood_search::LargeNeighbourhoodSearch;
+use pumpkin_solver::optimisation::lexicographic::Lexicographic;
 use pumpkin_solver::optimisation::linear_sat_unsat::LinearSatUnsat;
 use pumpkin_solver::optimisation::linear_unsat_sat::LinearUnsatSat;
-use pumpkin_solver::optimisation::OptimisationDirection;
+use pumpkin_solver::optimisation::neighbourhoods::CostImpactNeighbourhood;
+use pumpkin_solver::optimisation::neighbourhoods::Neighbourhood as NeighbourhoodTrait;
+use pumpkin_solver::optimisation::neighbourhoods::PropagationGuidedNeighbourhood;
+use pumpkin_solver::optimisation::neighbourhoods::RandomNeighbourhood;
+use pumpkin_solver::optimisation::pareto::Pareto;
 use pumpkin_solver::options::SolverOptions;
 use pumpkin_solver::predicate;
 use pumpkin_solver::proof::Format;
 use pumpkin_solver::proof::ProofLog;
 use pumpkin_solver::results::SolutionReference;
 use pumpkin_solver::termination::Indefinite;
+use pumpkin_solver::variables::AffineView;
 use pumpkin_solver::variables::DomainId;
 use pumpkin_solver::variables::Literal;
 use pumpkin_solver::ConstraintOperationError;
-use pumpkin_solver::DefaultBrancher;
 use pumpkin_solver::Solver;
 use pyo3::prelude::*;
 
+use crate::branching::VariableSelection;
+use crate::constraints::globals::Equals;
 use crate::constraints::Constraint;
 use crate::optimisation::Direction;
+use crate::optimisation::Neighbourhood;
 use crate::optimisation::OptimisationResult;
 use crate::optimisation::Optimiser;
 use crate::result::SatisfactionResult;
@@ -51,8 +63,8 @@ impl Model {
     #[pyo3(signature = (lower_bound, upper_bound, name=None))]
     fn new_integer_variable(
         &mut self,
-        lower_bound: i32,
-        upper_bound: i32,
+        lower_bound: i64,
+        upper_bound: i64,
         name: Option<&str>,
     ) -> IntExpression {
         let variable = ModelIntVar {
@@ -152,15 +164,25 @@ impl Model {
         });
     }
 
-    #[pyo3(signature = (proof=None))]
-    fn satisfy(&self, proof: Option<PathBuf>) -> SatisfactionResult {
+    #[pyo3(signature = (proof=None, variable_selection=VariableSelection::Default, hints=None))]
+    fn satisfy(
+        &self,
+        proof: Option<PathBuf>,
+        variable_selection: VariableSelection,
+        hints: Option<Vec<Predicate>>,
+    ) -> SatisfactionResult {
         let solver_setup = self.create_solver(proof);
 
         let Ok((mut solver, variable_map)) = solver_setup else {
             return SatisfactionResult::Unsatisfiable();
         };
 
-        let mut brancher = solver.default_brancher();
+        let mut brancher = variable_selection.create_brancher(
+            &solver,
+            &variable_map,
+            false,
+            &hints.unwrap_or_default(),
+        );
 
         match solver.satisfy(&mut brancher, &mut Indefinite) {
             pumpkin_solver::results::SatisfactionResult::Satisfiable(solution) => {
@@ -176,10 +198,11 @@ impl Model {
         }
     }
 
-    #[pyo3(signature = (assumptions))]
+    #[pyo3(signature = (assumptions, variable_selection=VariableSelection::Default))]
     fn satisfy_under_assumptions(
         &self,
         assumptions: Vec<Predicate>,
+        variable_selection: VariableSelection,
     ) -> SatisfactionUnderAssumptionsResult {
         let solver_setup = self.create_solver(None);
 
@@ -187,7 +210,7 @@ impl Model {
             return SatisfactionUnderAssumptionsResult::Unsatisfiable();
         };
 
-        let mut brancher = solver.default_brancher();
+        let mut brancher = variable_selection.create_brancher(&solver, &variable_map, false, &[]);
 
         let solver_assumptions = assumptions
             .iter()
@@ -240,13 +263,20 @@ impl Model {
         result
     }
 
-    #[pyo3(signature = (objective, optimiser=Optimiser::LinearSatUnsat, direction=Direction::Minimise, proof=None))]
+    #[pyo3(signature = (objective, optimiser=Optimiser::LinearSatUnsat, direction=Direction::Minimise, proof=None, neighbourhood=Neighbourhood::Random, variable_selection=VariableSelection::Default, hints=None))]
+    #[allow(
+        clippy::too_many_arguments,
+        reason = "the arguments are exposed to Python"
+    )]
     fn optimise(
         &self,
         objective: IntExpression,
         optimiser: Optimiser,
         direction: Direction,
         proof: Option<PathBuf>,
+        neighbourhood: Neighbourhood,
+        variable_selection: VariableSelection,
+        hints: Option<Vec<Predicate>>,
     ) -> OptimisationResult {
         let solver_setup = self.create_solver(proof);
 
@@ -254,16 +284,19 @@ impl Model {
             return OptimisationResult::Unsatisfiable();
         };
 
-        let mut brancher = solver.default_brancher();
+        let mut brancher = variable_selection.create_brancher(
+            &solver,
+            &variable_map,
+            true,
+            &hints.unwrap_or_default(),
+        );
 
-        let direction = match direction {
-            Direction::Minimise => OptimisationDirection::Minimise,
-            Direction::Maximise => OptimisationDirection::Maximise,
-        };
+        let direction = direction.into();
 
+        let objective_terms = self.objective_terms(objective, &variable_map);
         let objective = objective.to_affine_view(&variable_map);
 
-        let callback: fn(&Solver, SolutionReference, &DefaultBrancher) = |_, _, _| {};
+        let callback: fn(&Solver, SolutionReference, &DynamicBrancher) = |_, _, _| {};
 
         let result = match optimiser {
             Optimiser::LinearSatUnsat => solver.optimise(
@@ -276,26 +309,114 @@ impl Model {
                 &mut Indefinite,
                 LinearUnsatSat::new(direction, objective, callback),
             ),
+            Optimiser::CoreGuided => solver.optimise(
+                &mut brancher,
+                &mut Indefinite,
+                CoreGuided::new(direction, objective, objective_terms, callback),
+            ),
+            Optimiser::LargeNeighbourhoodSearch => {
+                let neighbourhood: Box<dyn NeighbourhoodTrait> = match neighbourhood {
+                    Neighbourhood::Random => Box::new(RandomNeighbourhood),
+                    Neighbourhood::PropagationGuided => Box::new(PropagationGuidedNeighbourhood),
+                    Neighbourhood::CostImpact => {
+                        Box::new(CostImpactNeighbourhood::new(direction, objective))
+                    }
+                };
+
+                solver.optimise(
+                    &mut brancher,
+                    &mut Indefinite,
+                    LargeNeighbourhoodSearch::new(
+                        direction,
+                        objective,
+                        variable_map.integer_domains(),
+                        neighbourhood,
+                        callback,
+                    ),
+                )
+            }
+            Optimiser::Bisection => solver.optimise(
+                &mut brancher,
+                &mut Indefinite,
+                Bisection::new(direction, objective, callback),
+            ),
         };
 
-        match result {
-            pumpkin_solver::results::OptimisationResult::Satisfiable(solution) => {
-                OptimisationResult::Satisfiable(Solution {
-                    solver_solution: solution,
-                    variable_map,
-                })
-            }
-            pumpkin_solver::results::OptimisationResult::Optimal(solution) => {
-                OptimisationResult::Optimal(Solution {
-                    solver_solution: solution,
-                    variable_map,
-                })
-            }
-            pumpkin_solver::results::OptimisationResult::Unsatisfiable => {
-                OptimisationResult::Unsatisfiable()
-            }
-            pumpkin_solver::results::OptimisationResult::Unknown => OptimisationResult::Unknown(),
-        }
+        OptimisationResult::new(result, variable_map)
+    }
+
+    /// Optimises the provided objectives lexicographically, where the objectives are provided in
+    /// order of decreasing priority.
+    #[pyo3(signature = (objectives, proof=None, variable_selection=VariableSelection::Default))]
+    fn optimise_lexicographic(
+        &self,
+        objectives: Vec<(IntExpression, Direction)>,
+        proof: Option<PathBuf>,
+        variable_selection: VariableSelection,
+    ) -> OptimisationResult {
+        let solver_setup = self.create_solver(proof);
+
+        let Ok((mut solver, variable_map)) = solver_setup else {
+            return OptimisationResult::Unsatisfiable();
+        };
+
+        let mut brancher = variable_selection.create_brancher(&solver, &variable_map, true, &[]);
+
+        let objectives = objectives
+            .into_iter()
+            .map(|(objective, direction)| {
+                (direction.into(), objective.to_affine_view(&variable_map))
+            })
+            .collect::<Vec<_>>();
+
+        let callback: fn(&Solver, SolutionReference, &DynamicBrancher) = |_, _, _| {};
+
+        let result = solver.optimise(
+            &mut brancher,
+            &mut Indefinite,
+            Lexicographic::new(objectives, callback),
+        );
+
+        OptimisationResult::new(result, variable_map)
+    }
+
+    /// Returns the Pareto front of the provided objectives; i.e. the solutions which are not
+    /// dominated by any other solution.
+    #[pyo3(signature = (objectives, proof=None, variable_selection=VariableSelection::Default))]
+    fn pareto_front(
+        &self,
+        objectives: Vec<(IntExpression, Direction)>,
+        proof: Option<PathBuf>,
+        variable_selection: VariableSelection,
+    ) -> Vec<Solution> {
+        let solver_setup = self.create_solver(proof);
+
+        let Ok((mut solver, variable_map)) = solver_setup else {
+            return vec![];
+        };
+
+        let mut brancher = variable_selection.create_brancher(&solver, &variable_map, true, &[]);
+
+        let objectives = objectives
+            .into_iter()
+            .map(|(objective, direction)| {
+                (direction.into(), objective.to_affine_view(&variable_map))
+            })
+            .collect::<Vec<_>>();
+
+        let callback: fn(&Solver, SolutionReference, &DynamicBrancher) = |_, _, _| {};
+
+        let mut pareto = Pareto::new(objectives, callback);
+        let _ = solver.optimise(&mut brancher, &mut Indefinite, &mut pareto);
+
+        pareto
+            .into_front()
+            .into_iter()
+            .map(|solution| Solution {
+                solver_solution: solution,
+                variable_map: variable_map.clone(),
+            })
+            .collect()
     }
 }
 
@@ -307,9 +428,7 @@ impl Model {
         let mut map = VariableMap::default();
 
         for model_int_var in self.integer_variables.iter() {
-            let _ = map
-                .integers
-                .push(model_int_var.create_domain(solver).into());
+            let _ = map.integers.push(model_int_var.create_domain(solver));
         }
 
         for model_bool_var in self.boolean_variables.iter() {
@@ -348,6 +467,59 @@ impl Model {
         Ok(())
     }
 
+    /// Finds the terms `w_i * x_i` of which the objective is the weighted sum, based on the
+    /// unconditional linear equality which defines the objective variable. If there is no such
+    /// equality, then the objective variable is the only term.
+    fn objective_terms(
+        &self,
+        objective: IntExpression,
+        variable_map: &VariableMap,
+    ) -> Vec<(i64, AffineView<DomainId>)> {
+        for model_constraint in self.constraints.iter() {
+            let ModelConstraint {
+                constraint: Constraint::Equals(Equals { terms, .. }),
+                premise: None,
+                ..
+            } = model_constraint
+            else {
+                continue;
+            };
+
+            // The objective variable should occur exactly once, with a scale of 1 or -1
+            let mut occurrences = terms
+                .iter()
+                .enumerate()
+                .filter(|(_, term)| term.variable == objective.variable);
+            let (Some((objective_index, objective_term)), None) =
+                (occurrences.next(), occurrences.next())
+            else {
+                continue;
+            };
+            if objective_term.scale.abs() != 1 {
+                continue;
+            }
+
+            // We have that `s * x + sum s_i * x_i = rhs` (up to a constant), which means that the
+            // objective is equal to `sum (-s_i * s * objective.scale) * x_i` (up to a constant)
+            return terms
+                .iter()
+                .enumerate()
+                .filter(|&(index, _)| index != objective_index)
+                .map(|(_, term)| {
+                    (
+                        -term.scale * objective_term.scale * objective.scale,
+                        variable_map.get_integer(term.variable),
+                    )
+                })
+                .collect();
+        }
+
+        vec![(
+            objective.scale,
+            variable_map.get_integer(objective.variable),
+        )]
+    }
+
     fn create_solver(
         &self,
         proof: Option<PathBuf>,
@@ -384,8 +556,8 @@ struct ModelConstraint {
 }
 
 struct ModelIntVar {
-    lower_bound: i32,
-    upper_bound: i32,
+    lower_bound: i64,
+    upper_bound: i64,
     name: Option<String>,
 }
 
@@ -1,6 +1,8 @@
+use pumpkin_solver::optimisation::OptimisationDirection;
 use pyo3::prelude::*;
 
 use crate::result::Solution;
+use crate::variables::VariableMap;
 
 #[pyclass]
 pub enum OptimisationResult {
@@ -14,11 +16,49 @@ pub enum OptimisationResult {
     Unknown(),
 }
 
+impl OptimisationResult {
+    pub fn new(
+        result: pumpkin_solver::results::OptimisationResult,
+        variable_map: VariableMap,
+    ) -> Self {
+        match result {
+            pumpkin_solver::results::OptimisationResult::Satisfiable(solution) => {
+                OptimisationResult::Satisfiable(Solution {
+                    solver_solution: solution,
+                    variable_map,
+                })
+            }
+            pumpkin_solver::results::OptimisationResult::Optimal(solution) => {
+                OptimisationResult::Optimal(Solution {
+                    solver_solution: solution,
+                    variable_map,
+                })
+            }
+            pumpkin_solver::results::OptimisationResult::Unsatisfiable => {
+                OptimisationResult::Unsatisfiable()
+            }
+            pumpkin_solver::results::OptimisationResult::Unknown => OptimisationResult::Unknown(),
+        }
+    }
+}
+
 #[pyclass(eq, eq_int)]
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Optimiser {
     LinearSatUnsat,
     LinearUnsatSat,
+    CoreGuided,
+    LargeNeighbourhoodSearch,
+    Bisection,
+}
+
+/// The neighbourhood which is used by [`Optimiser::LargeNeighbourhoodSearch`].
+#[pyclass(eq, eq_int)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Neighbourhood {
+    Random,
+    PropagationGuided,
+    CostImpact,
 }
 
 #[pyclass(eq, eq_int)]
@@ -28,8 +68,18 @@ pub enum Direction {
     Maximise,
 }
 
+impl From<Direction> for OptimisationDirection {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Minimise => OptimisationDirection::Minimise,
+            Direction::Maximise => OptimisationDirection::Maximise,
+        }
+    }
+}
+
 pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Optimiser>()?;
+    m.add_class::<Neighbourhood>()?;
     m.add_class::<Direction>()?;
     m.add_class::<OptimisationResult>()?;
     Ok(())
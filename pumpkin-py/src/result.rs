@@ -32,7 +32,7 @@ pub struct Solution {
 
 #[pymethods]
 impl Solution {
-    fn int_value(&self, variable: IntExpression) -> i32 {
+    fn int_value(&self, variable: IntExpression) -> i64 {
         self.solver_solution
             .get_integer_value(variable.to_affine_view(&self.variable_map))
     }
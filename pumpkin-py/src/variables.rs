@@ -24,8 +24,8 @@ impl StorageKey for IntVariable {
 #[derive(Clone, Copy, Debug, PartialOrd, Ord, PartialEq, Eq, Hash)]
 pub struct IntExpression {
     pub variable: IntVariable,
-    pub offset: i32,
-    pub scale: i32,
+    pub offset: i64,
+    pub scale: i64,
 }
 
 impl From<IntVariable> for IntExpression {
@@ -55,7 +55,7 @@ impl IntExpression {
 
 #[pymethods]
 impl IntExpression {
-    fn offset(&self, add_offset: i32) -> IntExpression {
+    fn offset(&self, add_offset: i64) -> IntExpression {
         let IntExpression {
             variable,
             offset,
@@ -69,7 +69,7 @@ impl IntExpression {
         }
     }
 
-    fn scaled(&self, scaling: i32) -> IntExpression {
+    fn scaled(&self, scaling: i64) -> IntExpression {
         let IntExpression {
             variable,
             offset,
@@ -98,13 +98,13 @@ pub enum Comparator {
 pub struct Predicate {
     pub variable: IntExpression,
     pub comparator: Comparator,
-    pub value: i32,
+    pub value: i64,
 }
 
 #[pymethods]
 impl Predicate {
     #[new]
-    fn new(variable: IntExpression, comparator: Comparator, value: i32) -> Self {
+    fn new(variable: IntExpression, comparator: Comparator, value: i64) -> Self {
         Self {
             variable,
             comparator,
@@ -182,13 +182,18 @@ impl BoolExpression {
 
 #[derive(Clone, Default)]
 pub struct VariableMap {
-    pub integers: KeyedVec<IntVariable, AffineView<DomainId>>,
+    pub integers: KeyedVec<IntVariable, DomainId>,
     pub booleans: KeyedVec<BoolVariable, Literal>,
 }
 
 impl VariableMap {
     pub fn get_integer(&self, variable: IntVariable) -> AffineView<DomainId> {
-        self.integers[variable]
+        self.integers[variable].into()
+    }
+
+    /// Returns the domains of all integer variables in the model.
+    pub fn integer_domains(&self) -> impl Iterator<Item = DomainId> + '_ {
+        self.integers.iter().copied()
     }
 
     pub fn get_boolean(&self, variable: BoolVariable) -> Literal {
@@ -81,6 +81,11 @@ def generate_alldiff():
             cons = constraints.AllDifferent(args)
             yield model, cons, "alldifferent", scaled or bool, bool
 
+    model = pumpkin_py.Model()
+    args = [model.new_integer_variable(-3, 5, name=f"x[{i}]") for i in range(3)]
+    cons = constraints.AllDifferentWithConsistency(args, constraints.AllDifferentConsistency.Domain)
+    yield model, cons, "alldifferent_domain", False, False
+
 def generate_cumulative():
     duration = [2, 3, 4]
     demand = [1, 2, 3]
@@ -97,11 +102,67 @@ def generate_cumulative():
     cons = constraints.Cumulative(start, duration, demand, capacity)
     yield model, cons, "cumulative", True, False
 
+    model = pumpkin_py.Model()
+    start = [model.new_integer_variable(-3, 5, name=f"x[{i}]") for i in range(3)]
+    duration = [model.new_integer_variable(2, 4, name=f"d[{i}]") for i in range(3)]
+    demand = [model.new_integer_variable(1, 3, name=f"r[{i}]") for i in range(3)]
+    capacity = model.new_integer_variable(3, 4, name="c")
+    cons = constraints.CumulativeVar(start, duration, demand, capacity)
+    yield model, cons, "cumulative_var", False, False
+
+
+def generate_disjunctive():
+    duration = [2, 3, 4]
+
+    model = pumpkin_py.Model()
+    start = [model.new_integer_variable(-3, 10, name=f"x[{i}]") for i in range(3)]
+    cons = constraints.Disjunctive(start, duration)
+    yield model, cons, "disjunctive", False, False
+
+    model = pumpkin_py.Model()
+    start = [model.new_integer_variable(-3, 10, name=f"x[{i}]") for i in range(3)]
+    start = [a.scaled(-2 * i) for i, a in enumerate(start)]
+    cons = constraints.Disjunctive(start, duration)
+    yield model, cons, "disjunctive", True, False
+
+
+def generate_table():
+    table = [[1, 2, 3], [2, 2, 1], [3, 1, 1]]
+
+    model = pumpkin_py.Model()
+    args = [model.new_integer_variable(-3, 5, name=f"x[{i}]") for i in range(3)]
+    cons = constraints.Table(args, table)
+    yield model, cons, "table", False, False
+
+    model = pumpkin_py.Model()
+    args = [model.new_integer_variable(-3, 5, name=f"x[{i}]") for i in range(3)]
+    cons = constraints.NegativeTable(args, table)
+    yield model, cons, "negative_table", False, False
+
+
+def generate_regular():
+    # The value 2 is never taken twice in a row
+    automaton = constraints.Automaton(2, 0, [0, 1], [(0, 1, 0), (0, 2, 1), (1, 1, 0)])
+
+    model = pumpkin_py.Model()
+    args = [model.new_integer_variable(1, 2, name=f"x[{i}]") for i in range(3)]
+    cons = constraints.Regular(args, automaton)
+    yield model, cons, "regular", False, False
+
+    model = pumpkin_py.Model()
+    args = [model.new_integer_variable(1, 2, name=f"x[{i}]") for i in range(3)]
+    cost = model.new_integer_variable(0, 10, name="cost")
+    cons = constraints.CostRegular(args, automaton, [0, 1, 0], cost)
+    yield model, cons, "cost_regular", False, False
+
 
 def generate_globals():
 
     yield from generate_alldiff()
     yield from generate_cumulative()
+    yield from generate_disjunctive()
+    yield from generate_table()
+    yield from generate_regular()
 
 def label(model, cons, name, scaled, bool):
     return " ".join(["Scaled" if scaled else "Unscaled", "Boolean" if bool else "Integer", name])
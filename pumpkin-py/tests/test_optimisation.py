@@ -1,5 +1,5 @@
-from pumpkin_py import Model
-from pumpkin_py.optimisation import Direction, OptimisationResult
+from pumpkin_py import constraints, Model
+from pumpkin_py.optimisation import Direction, OptimisationResult, Optimiser
 
 
 def test_linear_sat_unsat_minimisation():
@@ -28,3 +28,22 @@ def test_linear_sat_unsat_maximisation():
     assert solution.int_value(objective) == 5
 
 
+
+def test_core_guided_linear_objective():
+    model = Model()
+
+    x = model.new_integer_variable(0, 5, name="x")
+    y = model.new_integer_variable(0, 5, name="y")
+    objective = model.new_integer_variable(0, 20, name="objective")
+
+    # objective = 2x + 3y
+    model.add_constraint(constraints.Equals([x.scaled(2), y.scaled(3), objective.scaled(-1)], 0))
+    model.add_constraint(constraints.LessThanOrEquals([x.scaled(-1), y.scaled(-1)], -3))
+
+    result = model.optimise(objective, optimiser=Optimiser.CoreGuided, direction=Direction.Minimise)
+
+    assert isinstance(result, OptimisationResult.Optimal)
+
+    # The optimal solution is x = 3 and y = 0
+    solution = result._0
+    assert solution.int_value(objective) == 6
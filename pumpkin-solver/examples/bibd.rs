@@ -93,14 +93,14 @@ fn main() {
     // Enforce the row sum.
     for row in matrix.iter() {
         let _ = solver
-            .add_constraint(constraints::equals(row.clone(), bibd.row_sum as i32))
+            .add_constraint(constraints::equals(row.clone(), bibd.row_sum as i64))
             .post();
     }
 
     // Enforce the column sum.
     for row in transpose(&matrix) {
         let _ = solver
-            .add_constraint(constraints::equals(row, bibd.column_sum as i32))
+            .add_constraint(constraints::equals(row, bibd.column_sum as i64))
             .post();
     }
 
@@ -125,7 +125,7 @@ fn main() {
             let _ = solver
                 .add_constraint(constraints::less_than_or_equals(
                     pairwise_product[r1][r2].clone(),
-                    bibd.max_dot_product as i32,
+                    bibd.max_dot_product as i64,
                 ))
                 .post();
         }
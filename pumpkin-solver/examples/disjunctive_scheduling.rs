@@ -1,4 +1,4 @@
-//! A simple model for disjunctive scheduling using reified constraints
+//! A simple model for disjunctive scheduling using the disjunctive constraint
 //! Given a set of tasks and their processing times, it finds a schedule such that none of the jobs
 //! overlap. The optimal schedule is thus all tasks scheduled right after each other.
 //!
@@ -6,15 +6,13 @@
 //! the start time of task i and p_i is then we can express the condition that x ends before y
 //! starts as s_x + p_x <= s_y, and that y ends before x starts as s_y + p_y <= s_x.
 //!
-//! To ensure that one of these occurs, we create two Boolean variables, l_xy and l_yx, to signify
-//! the two possibilities, and then post the constraint (l_xy \/ l_yx).
+//! Rather than posting this condition for every pair of tasks, we post a single disjunctive
+//! constraint over all tasks (see [`constraints::disjunctive`]).
 
 use pumpkin_solver::constraints;
-use pumpkin_solver::constraints::NegatableConstraint;
 use pumpkin_solver::results::ProblemSolution;
 use pumpkin_solver::results::SatisfactionResult;
 use pumpkin_solver::termination::Indefinite;
-use pumpkin_solver::variables::TransformableVariable;
 use pumpkin_solver::Solver;
 
 fn main() {
@@ -41,40 +39,17 @@ fn main() {
     let mut solver = Solver::default();
 
     let start_variables = (0..n_tasks)
-        .map(|i| solver.new_bounded_integer(0, (horizon - processing_times[i]) as i32))
+        .map(|i| solver.new_bounded_integer(0, (horizon - processing_times[i]) as i64))
         .collect::<Vec<_>>();
 
-    // Literal which indicates precedence (i.e. precedence_literals[x][y] <=> x ends before y
-    // starts)
-    let precedence_literals = (0..n_tasks)
-        .map(|_| {
-            (0..n_tasks)
-                .map(|_| solver.new_literal())
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
-
-    for x in 0..n_tasks {
-        for y in 0..n_tasks {
-            if x == y {
-                continue;
-            }
-            // precedence_literals[x][y] <=> x ends before y starts
-            let literal = precedence_literals[x][y];
-            // literal <=> (s_x + p_x <= s_y)
-            // equivelent to literal <=> (s_x - s_y <= -p_x)
-            // So the variables are -s_y and s_x, and the rhs is -p_x
-            let variables = vec![start_variables[y].scaled(-1), start_variables[x].scaled(1)];
-            let _ = constraints::less_than_or_equals(variables, -(processing_times[x] as i32))
-                .reify(&mut solver, literal, None);
-
-            // Either x starts before y or y start before x
-            let _ = solver.add_clause([
-                literal.get_true_predicate(),
-                precedence_literals[y][x].get_true_predicate(),
-            ]);
-        }
-    }
+    let _ = solver
+        .add_constraint(constraints::disjunctive(
+            start_variables.clone(),
+            processing_times
+                .iter()
+                .map(|&processing_time| processing_time as i64),
+        ))
+        .post();
 
     let mut brancher = solver.default_brancher();
     if matches!(
@@ -102,7 +77,7 @@ fn main() {
                     .map(|(var, processing_time)| format!(
                         "[{}, {}]",
                         solution.get_integer_value(**var),
-                        solution.get_integer_value(**var) + *processing_time as i32
+                        solution.get_integer_value(**var) + *processing_time as i64
                     ))
                     .collect::<Vec<_>>()
                     .join(" - ")
@@ -54,7 +54,7 @@ fn main() {
     });
 
     let variables = (0..n)
-        .map(|i| solver.new_named_bounded_integer(0, n as i32 - 1, format!("q{i}")))
+        .map(|i| solver.new_named_bounded_integer(0, n as i64 - 1, format!("q{i}")))
         .collect::<Vec<_>>();
 
     let _ = solver
@@ -66,13 +66,13 @@ fn main() {
         .iter()
         .cloned()
         .enumerate()
-        .map(|(i, var)| var.offset(i as i32))
+        .map(|(i, var)| var.offset(i as i64))
         .collect::<Vec<_>>();
     let diag2 = variables
         .iter()
         .cloned()
         .enumerate()
-        .map(|(i, var)| var.offset(-(i as i32)))
+        .map(|(i, var)| var.offset(-(i as i64)))
         .collect::<Vec<_>>();
 
     let _ = solver
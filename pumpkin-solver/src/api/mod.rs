@@ -61,15 +61,24 @@ pub mod options {
     //! - The restart strategy of the solver
     //! - The learned clause database management approach
     //! - The proof logging
+    //! - The exchange of nogoods between solvers running in parallel
+    //! - The heuristic used by the default brancher
     pub use crate::basic_types::sequence_generators::SequenceGeneratorType;
+    pub use crate::branching::branchers::default_brancher::BranchingHeuristic;
     pub use crate::engine::ConflictResolver;
     pub use crate::engine::RestartOptions;
     pub use crate::engine::SatisfactionSolverOptions as SolverOptions;
     pub use crate::propagators::nogoods::LearnedNogoodSortingStrategy;
     pub use crate::propagators::nogoods::LearningOptions;
+    pub use crate::propagators::nogoods::NogoodExchange;
+    pub use crate::propagators::nogoods::NogoodSharingOptions;
+    pub use crate::propagators::AllDifferentConsistency;
     pub use crate::propagators::CumulativeExplanationType;
     pub use crate::propagators::CumulativeOptions;
     pub use crate::propagators::CumulativePropagationMethod;
+    pub use crate::propagators::DisjunctiveExplanationType;
+    pub use crate::propagators::DisjunctiveOptionsError;
+    pub use crate::propagators::DisjunctivePropagatorOptions;
     #[cfg(doc)]
     use crate::Solver;
 }
@@ -85,8 +94,10 @@ pub mod termination {
     //! The most common example would be [`TimeBudget`], which terminates the [`Solver`] whenever
     //! the time budget is exceeded.
     pub use crate::engine::termination::combinator::*;
+    pub use crate::engine::termination::conflict_budget::*;
     pub use crate::engine::termination::indefinite::*;
     pub use crate::engine::termination::os_signal::*;
+    pub use crate::engine::termination::stop_signal::*;
     pub use crate::engine::termination::time_budget::*;
     pub use crate::engine::termination::TerminationCondition;
     #[cfg(doc)]
@@ -111,6 +122,109 @@ pub mod predicates {
     use crate::variables::Literal;
 }
 
+pub mod propagation {
+    //! Contains the API for defining custom propagators which can be added to the [`Solver`].
+    //!
+    //! A propagator is defined by implementing the [`Propagator`] trait; see its documentation
+    //! for which functions are required and which are optional. Any [`Propagator`] is also a
+    //! [`Constraint`], which means that it can be posted to the solver using
+    //! [`Solver::add_constraint`].
+    //!
+    //! During initialisation ([`Propagator::initialise_at_root`]), a propagator subscribes to
+    //! [`DomainEvents`] of its variables through the [`PropagatorInitialisationContext`], which
+    //! assigns a [`LocalId`] to each of them. During search, the [`PropagationContextMut`] is used
+    //! to read domains (see [`ReadDomains`]) and to post inferences together with a [`Reason`];
+    //! reasons can either be provided eagerly or lazily through [`Propagator::lazy_explanation`].
+    //! Incremental state which needs to be restored upon backtracking can be stored in a
+    //! [`TrailedInteger`] (see [`ManipulateTrailedValues`]).
+    //!
+    //! # Example
+    //! ```rust
+    //! # use pumpkin_solver::conjunction;
+    //! # use pumpkin_solver::predicates::PropositionalConjunction;
+    //! # use pumpkin_solver::propagation::DomainEvents;
+    //! # use pumpkin_solver::propagation::LocalId;
+    //! # use pumpkin_solver::propagation::PropagationContextMut;
+    //! # use pumpkin_solver::propagation::PropagationStatusCP;
+    //! # use pumpkin_solver::propagation::Propagator;
+    //! # use pumpkin_solver::propagation::PropagatorInitialisationContext;
+    //! # use pumpkin_solver::propagation::ReadDomains;
+    //! # use pumpkin_solver::variables::DomainId;
+    //! # use pumpkin_solver::Solver;
+    //! /// A propagator for the constraint `a < b`.
+    //! struct LessThan {
+    //!     a: DomainId,
+    //!     b: DomainId,
+    //! }
+    //!
+    //! impl Propagator for LessThan {
+    //!     fn name(&self) -> &str {
+    //!         "LessThan"
+    //!     }
+    //!
+    //!     fn initialise_at_root(
+    //!         &mut self,
+    //!         context: &mut PropagatorInitialisationContext,
+    //!     ) -> Result<(), PropositionalConjunction> {
+    //!         let _ = context.register(self.a, DomainEvents::LOWER_BOUND, LocalId::from(0));
+    //!         let _ = context.register(self.b, DomainEvents::UPPER_BOUND, LocalId::from(1));
+    //!         Ok(())
+    //!     }
+    //!
+    //!     fn debug_propagate_from_scratch(
+    //!         &self,
+    //!         mut context: PropagationContextMut,
+    //!     ) -> PropagationStatusCP {
+    //!         let (a, b) = (self.a, self.b);
+    //!
+    //!         let a_lb = context.lower_bound(&a);
+    //!         context.set_lower_bound(&b, a_lb + 1, conjunction!([a >= a_lb]))?;
+    //!
+    //!         let b_ub = context.upper_bound(&b);
+    //!         context.set_upper_bound(&a, b_ub - 1, conjunction!([b <= b_ub]))?;
+    //!
+    //!         Ok(())
+    //!     }
+    //! }
+    //!
+    //! let mut solver = Solver::default();
+    //! let a = solver.new_bounded_integer(0, 5);
+    //! let b = solver.new_bounded_integer(0, 5);
+    //!
+    //! solver
+    //!     .add_constraint(LessThan { a, b })
+    //!     .post()
+    //!     .expect("no root-level conflict");
+    //!
+    //! assert_eq!(solver.lower_bound(&b), 1);
+    //! assert_eq!(solver.upper_bound(&a), 4);
+    //! ```
+    pub use crate::basic_types::Inconsistency;
+    pub use crate::basic_types::PropagationStatusCP;
+    #[cfg(doc)]
+    use crate::constraints::Constraint;
+    pub use crate::engine::cp::trailed::TrailedInteger;
+    pub use crate::engine::cp::EmptyDomain;
+    pub use crate::engine::cp::IntDomainEvent;
+    pub use crate::engine::domain_events::DomainEvents;
+    pub use crate::engine::opaque_domain_event::OpaqueDomainEvent;
+    pub use crate::engine::propagation::contexts::propagation_context::HasAssignments;
+    pub use crate::engine::propagation::contexts::propagation_context::HasTrailedValues;
+    pub use crate::engine::propagation::contexts::propagation_context::ManipulateTrailedValues;
+    pub use crate::engine::propagation::contexts::propagation_context::PropagationContextWithTrailedValues;
+    pub use crate::engine::propagation::EnqueueDecision;
+    pub use crate::engine::propagation::ExplanationContext;
+    pub use crate::engine::propagation::LocalId;
+    pub use crate::engine::propagation::PropagationContext;
+    pub use crate::engine::propagation::PropagationContextMut;
+    pub use crate::engine::propagation::Propagator;
+    pub use crate::engine::propagation::PropagatorInitialisationContext;
+    pub use crate::engine::propagation::ReadDomains;
+    pub use crate::engine::reason::Reason;
+    #[cfg(doc)]
+    use crate::Solver;
+}
+
 pub use crate::basic_types::Function;
 
 #[doc(hidden)]
@@ -4,6 +4,7 @@ use crate::branching::Brancher;
 use crate::engine::constraint_satisfaction_solver::CoreExtractionResult;
 use crate::engine::ConstraintSatisfactionSolver;
 use crate::predicates::Predicate;
+use crate::variables::Literal;
 #[cfg(doc)]
 use crate::Solver;
 
@@ -15,14 +16,22 @@ use crate::Solver;
 pub struct UnsatisfiableUnderAssumptions<'solver, 'brancher, B: Brancher> {
     pub(crate) solver: &'solver mut ConstraintSatisfactionSolver,
     pub(crate) brancher: &'brancher mut B,
+    /// The activation literals of the scopes which were open during solving (see
+    /// [`Solver::push`]); these are not part of the extracted core.
+    scopes: Vec<Literal>,
 }
 
 impl<'solver, 'brancher, B: Brancher> UnsatisfiableUnderAssumptions<'solver, 'brancher, B> {
     pub fn new(
         solver: &'solver mut ConstraintSatisfactionSolver,
         brancher: &'brancher mut B,
+        scopes: Vec<Literal>,
     ) -> Self {
-        UnsatisfiableUnderAssumptions { solver, brancher }
+        UnsatisfiableUnderAssumptions {
+            solver,
+            brancher,
+            scopes,
+        }
     }
 
     /// Extract an unsatisfiable core in terms of the assumptions.
@@ -103,7 +112,15 @@ impl<'solver, 'brancher, B: Brancher> UnsatisfiableUnderAssumptions<'solver, 'br
             CoreExtractionResult::ConflictingAssumption(conflicting_assumption) => {
                 panic!("Conflicting assumptions were provided, found both {conflicting_assumption:?} and {:?}", !conflicting_assumption)
             }
-            CoreExtractionResult::Core(core) => core.into(),
+            CoreExtractionResult::Core(core) => core
+                .into_iter()
+                .filter(|&predicate| {
+                    !self
+                        .scopes
+                        .iter()
+                        .any(|scope| scope.get_true_predicate() == predicate)
+                })
+                .collect(),
         }
     }
 }
@@ -8,15 +8,14 @@ use crate::basic_types::CSPSolverExecutionFlag;
 use crate::basic_types::ConstraintOperationError;
 use crate::basic_types::HashSet;
 use crate::basic_types::Solution;
-use crate::branching::branchers::autonomous_search::AutonomousSearch;
-use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
-use crate::branching::value_selection::RandomSplitter;
+pub use crate::branching::branchers::default_brancher::DefaultBrancher;
 #[cfg(doc)]
 use crate::branching::value_selection::ValueSelector;
-use crate::branching::variable_selection::RandomSelector;
 #[cfg(doc)]
 use crate::branching::variable_selection::VariableSelector;
 use crate::branching::Brancher;
+#[cfg(doc)]
+use crate::constraints::Constraint;
 use crate::constraints::ConstraintPoster;
 use crate::engine::predicates::predicate::Predicate;
 use crate::engine::propagation::Propagator;
@@ -26,14 +25,29 @@ use crate::engine::variables::IntegerVariable;
 use crate::engine::variables::Literal;
 use crate::engine::ConstraintSatisfactionSolver;
 #[cfg(doc)]
+use crate::optimisation::bisection::Bisection;
+#[cfg(doc)]
+use crate::optimisation::core_guided::CoreGuided;
+#[cfg(doc)]
+use crate::optimisation::large_neighbourhood_search::LargeNeighbourhoodSearch;
+#[cfg(doc)]
+use crate::optimisation::lexicographic::Lexicographic;
+#[cfg(doc)]
 use crate::optimisation::linear_sat_unsat::LinearSatUnsat;
 #[cfg(doc)]
 use crate::optimisation::linear_unsat_sat::LinearUnsatSat;
+#[cfg(doc)]
+use crate::optimisation::pareto::Pareto;
 use crate::optimisation::solution_callback::SolutionCallback;
 use crate::optimisation::OptimisationProcedure;
+#[cfg(doc)]
+use crate::options::BranchingHeuristic;
+use crate::options::NogoodExchange;
+use crate::options::RestartOptions;
 use crate::options::SolverOptions;
 #[cfg(doc)]
 use crate::predicates;
+use crate::propagators::ReifiedPropagator;
 use crate::results::solution_iterator::SolutionIterator;
 use crate::results::unsatisfiable::UnsatisfiableUnderAssumptions;
 use crate::statistics::log_statistic;
@@ -93,6 +107,9 @@ pub struct Solver {
     /// The internal [`ConstraintSatisfactionSolver`] which is used to solve the problems.
     pub(crate) satisfaction_solver: ConstraintSatisfactionSolver,
     true_literal: Literal,
+    /// The activation literals of the scopes which are currently open (see [`Solver::push`]),
+    /// from the outermost to the innermost scope.
+    scopes: Vec<Literal>,
 }
 
 impl Default for Solver {
@@ -102,6 +119,7 @@ impl Default for Solver {
         Self {
             satisfaction_solver,
             true_literal,
+            scopes: vec![],
         }
     }
 }
@@ -114,15 +132,93 @@ impl Solver {
         Self {
             satisfaction_solver,
             true_literal,
+            scopes: vec![],
         }
     }
 
+    /// Connects the solver to the provided [`NogoodExchange`], through which it shares nogoods
+    /// with the other connected solvers; this allows multiple solvers to solve the same problem
+    /// cooperatively in different threads.
+    ///
+    /// Every connected solver should have created the same variables before being connected (see
+    /// [`NogoodExchange`] for more information). Since imported nogoods cannot be justified in a
+    /// proof, a solver which logs a proof cannot share nogoods.
+    ///
+    /// # Example
+    /// ```
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::options::NogoodExchange;
+    /// # use pumpkin_solver::results::SatisfactionResult;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// # use pumpkin_solver::Solver;
+    /// let exchange = NogoodExchange::default();
+    ///
+    /// let results = std::thread::scope(|scope| {
+    ///     let workers = (0..2)
+    ///         .map(|_| {
+    ///             scope.spawn(|| {
+    ///                 // Every thread constructs the same model before connecting to the exchange
+    ///                 let mut solver = Solver::default();
+    ///                 let x = solver.new_bounded_integer(0, 2);
+    ///                 let y = solver.new_bounded_integer(0, 2);
+    ///                 let z = solver.new_bounded_integer(0, 2);
+    ///                 let _ = solver
+    ///                     .add_constraint(constraints::all_different(vec![x, y, z]))
+    ///                     .post();
+    ///                 solver.share_nogoods(&exchange);
+    ///
+    ///                 let mut brancher = solver.default_brancher();
+    ///                 matches!(
+    ///                     solver.satisfy(&mut brancher, &mut Indefinite),
+    ///                     SatisfactionResult::Satisfiable(_)
+    ///                 )
+    ///             })
+    ///         })
+    ///         .collect::<Vec<_>>();
+    ///
+    ///     workers
+    ///         .into_iter()
+    ///         .map(|worker| worker.join().unwrap())
+    ///         .collect::<Vec<_>>()
+    /// });
+    ///
+    /// assert!(results.iter().all(|&is_satisfiable| is_satisfiable));
+    /// ```
+    pub fn share_nogoods(&mut self, exchange: &NogoodExchange) {
+        self.satisfaction_solver
+            .connect_to_nogood_exchange(exchange);
+    }
+
+    /// Replaces the [`RestartOptions`] with which the solver was created (see
+    /// [`SolverOptions::restart_options`]); this allows the restart strategy to be changed after
+    /// the problem has been created, e.g. when it is specified as part of the problem.
+    pub fn set_restart_options(&mut self, restart_options: RestartOptions) {
+        self.satisfaction_solver
+            .set_restart_options(restart_options);
+    }
+
     /// Logs the statistics currently present in the solver with the provided objective value.
     pub fn log_statistics_with_objective(&self, objective_value: i64) {
         log_statistic("objective", objective_value);
         self.log_statistics();
     }
 
+    /// Logs the provided objective value and the proven bound on the objective, together with the
+    /// optimality gap; the gap is the difference between the objective value and the bound
+    /// relative to the objective value.
+    ///
+    /// Unlike [`Solver::log_statistics_with_objective`], this does not log the statistics of the
+    /// solver itself; these statistics are logged in the same block by a subsequent call to
+    /// [`Solver::log_statistics`].
+    pub fn log_bound_statistics(&self, objective_value: i64, objective_bound: i64) {
+        let gap = objective_value.abs_diff(objective_bound) as f64
+            / objective_value.unsigned_abs().max(1) as f64;
+
+        log_statistic("objective", objective_value);
+        log_statistic("objectiveBound", objective_bound);
+        log_statistic("optimalityGap", gap);
+    }
+
     /// Logs the statistics currently present in the solver.
     pub fn log_statistics(&self) {
         self.satisfaction_solver.log_statistics();
@@ -143,14 +239,22 @@ impl Solver {
     }
 
     /// Get the lower-bound of the given [`IntegerVariable`] at the root level (after propagation).
-    pub fn lower_bound(&self, variable: &impl IntegerVariable) -> i32 {
+    pub fn lower_bound(&self, variable: &impl IntegerVariable) -> i64 {
         self.satisfaction_solver.get_lower_bound(variable)
     }
 
     /// Get the upper-bound of the given [`IntegerVariable`] at the root level (after propagation).
-    pub fn upper_bound(&self, variable: &impl IntegerVariable) -> i32 {
+    pub fn upper_bound(&self, variable: &impl IntegerVariable) -> i64 {
         self.satisfaction_solver.get_upper_bound(variable)
     }
+
+    /// Get the number of propagators which are registered to the given [`DomainId`] (i.e. its
+    /// degree); this can be used as the number of attached constraints by
+    /// [`MostConstrained`](crate::branching::variable_selection::MostConstrained) and
+    /// [`Occurrence`](crate::branching::variable_selection::Occurrence).
+    pub fn num_attached_propagators(&self, variable: DomainId) -> u32 {
+        self.satisfaction_solver.num_attached_propagators(variable)
+    }
 }
 
 /// Functions to create and retrieve integer and propositional variables.
@@ -229,7 +333,7 @@ impl Solver {
     /// // We can create an integer variable with a domain in the range [0, 10]
     /// let integer_between_bounds = solver.new_bounded_integer(0, 10);
     /// ```
-    pub fn new_bounded_integer(&mut self, lower_bound: i32, upper_bound: i32) -> DomainId {
+    pub fn new_bounded_integer(&mut self, lower_bound: i64, upper_bound: i64) -> DomainId {
         self.satisfaction_solver
             .create_new_integer_variable(lower_bound, upper_bound, None)
     }
@@ -246,8 +350,8 @@ impl Solver {
     /// ```
     pub fn new_named_bounded_integer(
         &mut self,
-        lower_bound: i32,
-        upper_bound: i32,
+        lower_bound: i64,
+        upper_bound: i64,
         name: impl Into<String>,
     ) -> DomainId {
         self.satisfaction_solver.create_new_integer_variable(
@@ -268,8 +372,8 @@ impl Solver {
     /// // We can also create an integer variable with a non-continuous domain in the follow way
     /// let mut sparse_integer = solver.new_sparse_integer(vec![0, 3, 5]);
     /// ```
-    pub fn new_sparse_integer(&mut self, values: impl Into<Vec<i32>>) -> DomainId {
-        let values: HashSet<i32> = values.into().into_iter().collect();
+    pub fn new_sparse_integer(&mut self, values: impl Into<Vec<i64>>) -> DomainId {
+        let values: HashSet<i64> = values.into().into_iter().collect();
 
         self.satisfaction_solver
             .create_new_integer_variable_sparse(values.into_iter().collect(), None)
@@ -287,7 +391,7 @@ impl Solver {
     /// ```
     pub fn new_named_sparse_integer(
         &mut self,
-        values: impl Into<Vec<i32>>,
+        values: impl Into<Vec<i64>>,
         name: impl Into<String>,
     ) -> DomainId {
         self.satisfaction_solver
@@ -305,7 +409,7 @@ impl Solver {
         brancher: &mut B,
         termination: &mut T,
     ) -> SatisfactionResult {
-        match self.satisfaction_solver.solve(termination, brancher) {
+        match self.solve_in_scope(&[], termination, brancher) {
             CSPSolverExecutionFlag::Feasible => {
                 let solution: Solution = self.satisfaction_solver.get_solution_reference().into();
                 self.satisfaction_solver.restore_state_at_root(brancher);
@@ -317,7 +421,7 @@ impl Solver {
             CSPSolverExecutionFlag::Infeasible => {
                 // Reset the state whenever we return a result
                 self.satisfaction_solver.restore_state_at_root(brancher);
-                let _ = self.satisfaction_solver.conclude_proof_unsat();
+                self.conclude_proof_unsat();
 
                 SatisfactionResult::Unsatisfiable
             }
@@ -351,6 +455,9 @@ impl Solver {
     /// (see \[1\] for a more detailed explanation). See the [`predicates`] documentation for how
     /// to construct these predicates.
     ///
+    /// If there are open scopes (see [`Solver::push`]), then their activation literals are added to
+    /// the assumptions; these will never be part of an extracted core.
+    ///
     /// # Bibliography
     /// \[1\] N. Eén and N. Sörensson, ‘Temporal induction by incremental SAT solving’, Electronic
     /// Notes in Theoretical Computer Science, vol. 89, no. 4, pp. 543–560, 2003.
@@ -360,10 +467,7 @@ impl Solver {
         termination: &mut T,
         assumptions: &[Predicate],
     ) -> SatisfactionResultUnderAssumptions<'this, 'brancher, B> {
-        match self
-            .satisfaction_solver
-            .solve_under_assumptions(assumptions, termination, brancher)
-        {
+        match self.solve_in_scope(assumptions, termination, brancher) {
             CSPSolverExecutionFlag::Feasible => {
                 let solution: Solution = self.satisfaction_solver.get_solution_reference().into();
                 // Reset the state whenever we return a result
@@ -379,7 +483,11 @@ impl Solver {
                 {
                     // The state is automatically reset when we return this result
                     SatisfactionResultUnderAssumptions::UnsatisfiableUnderAssumptions(
-                        UnsatisfiableUnderAssumptions::new(&mut self.satisfaction_solver, brancher),
+                        UnsatisfiableUnderAssumptions::new(
+                            &mut self.satisfaction_solver,
+                            brancher,
+                            self.scopes.clone(),
+                        ),
                     )
                 } else {
                     // Reset the state whenever we return a result
@@ -398,8 +506,9 @@ impl Solver {
     /// Solves the model currently in the [`Solver`] to optimality where the provided
     /// `objective_variable` is optimised as indicated by the `direction` (or is indicated to
     /// terminate by the provided [`TerminationCondition`]). Uses a search strategy based on the
-    /// provided [`OptimisationProcedure`], currently [`LinearSatUnsat`] and
-    /// [`LinearUnsatSat`] are supported.
+    /// provided [`OptimisationProcedure`], currently [`LinearSatUnsat`], [`LinearUnsatSat`],
+    /// [`CoreGuided`], [`LargeNeighbourhoodSearch`] and [`Bisection`] are supported. Multiple
+    /// objectives can be optimised using [`Lexicographic`] and [`Pareto`].
     ///
     /// It returns an [`OptimisationResult`] which can be used to retrieve the optimal solution if
     /// it exists.
@@ -415,6 +524,24 @@ impl Solver {
     {
         optimisation_procedure.optimise(brancher, termination, self)
     }
+
+    /// Solves under the provided `assumptions` together with the activation literals of the
+    /// currently open scopes.
+    pub(crate) fn solve_in_scope(
+        &mut self,
+        assumptions: &[Predicate],
+        termination: &mut impl TerminationCondition,
+        brancher: &mut impl Brancher,
+    ) -> CSPSolverExecutionFlag {
+        let assumptions = self
+            .scopes
+            .iter()
+            .map(|activation_literal| activation_literal.get_true_predicate())
+            .chain(assumptions.iter().copied())
+            .collect::<Vec<_>>();
+        self.satisfaction_solver
+            .solve_under_assumptions(&assumptions, termination, brancher)
+    }
 }
 
 /// Functions for adding new constraints to the solver.
@@ -448,47 +575,186 @@ impl Solver {
     /// If the formula becomes trivially unsatisfiable, a [`ConstraintOperationError`] will be
     /// returned. Subsequent calls to this method will always return an error, and no
     /// modification of the solver will take place.
+    ///
+    /// If there is an open scope (see [`Solver::push`]), then the clause is removed when that
+    /// scope is popped.
     pub fn add_clause(
         &mut self,
         clause: impl IntoIterator<Item = Predicate>,
     ) -> Result<(), ConstraintOperationError> {
+        let Some(activation_literal) = self.innermost_scope() else {
+            return self.satisfaction_solver.add_clause(clause);
+        };
+
+        let deactivated = (!activation_literal).get_true_predicate();
+        let mut clause = clause.into_iter().collect::<Vec<_>>();
+        if !clause.contains(&deactivated) {
+            clause.push(deactivated);
+        }
         self.satisfaction_solver.add_clause(clause)
     }
 
     /// Adds a propagator with a tag, which is used to identify inferences made by this propagator
     /// in the proof log.
-    pub(crate) fn add_tagged_propagator(
+    ///
+    /// If there is an open scope (see [`Solver::push`]), then the propagator is removed when that
+    /// scope is popped.
+    pub fn add_tagged_propagator(
         &mut self,
         propagator: impl Propagator + 'static,
         tag: NonZero<u32>,
     ) -> Result<(), ConstraintOperationError> {
-        self.satisfaction_solver
-            .add_propagator(propagator, Some(tag))
+        match self.innermost_scope() {
+            Some(activation_literal) => self.satisfaction_solver.add_propagator(
+                ReifiedPropagator::new(propagator, activation_literal),
+                Some(tag),
+            ),
+            None => self
+                .satisfaction_solver
+                .add_propagator(propagator, Some(tag)),
+        }
     }
 
     /// Post a new propagator to the solver. If unsatisfiability can be immediately determined
     /// through propagation, this will return a [`ConstraintOperationError`].
     ///
-    /// The caller should ensure the solver is in the root state before calling this, either
-    /// because no call to [`Self::solve()`] has been made, or because
-    /// [`Self::restore_state_at_root()`] was called.
+    /// Custom propagators can be defined by implementing [`Propagator`]; see the
+    /// [`crate::propagation`] module for more information. Note that this method is typically
+    /// called from an implementation of [`Constraint::post`], or through
+    /// [`Solver::add_constraint`] as every [`Propagator`] is a [`Constraint`] as well.
+    ///
+    /// The caller should ensure the solver is in the root state before calling this. The solving
+    /// methods (e.g. [`Solver::satisfy`]) restore the root state before they return, so this is
+    /// the case unless the method is called from within a running search, such as from a
+    /// [`Brancher`] or a [`Propagator`].
     ///
     /// If the solver is already in a conflicting state, i.e. a previous call to this method
-    /// already returned `false`, calling this again will not alter the solver in any way, and
-    /// `false` will be returned again.
-    pub(crate) fn add_propagator(
+    /// already returned an error, calling this again will not alter the solver in any way, and
+    /// the error will be returned again.
+    ///
+    /// If there is an open scope (see [`Solver::push`]), then the propagator is removed when that
+    /// scope is popped; the provided propagator should then not be half-reified itself. Instead,
+    /// reified constraints should be added through [`Solver::add_constraint`].
+    pub fn add_propagator(
         &mut self,
         propagator: impl Propagator + 'static,
     ) -> Result<(), ConstraintOperationError> {
-        self.satisfaction_solver.add_propagator(propagator, None)
+        match self.innermost_scope() {
+            Some(activation_literal) => self
+                .satisfaction_solver
+                .add_propagator(ReifiedPropagator::new(propagator, activation_literal), None),
+            None => self.satisfaction_solver.add_propagator(propagator, None),
+        }
+    }
+}
+
+/// Functions for incrementally adding and retracting constraints.
+impl Solver {
+    /// Opens a new scope; every constraint which is added to the solver while this scope is open
+    /// is retracted again when the scope is closed using [`Solver::pop`]. Scopes can be nested, in
+    /// which case [`Solver::pop`] closes the scope which was opened most recently.
+    ///
+    /// Internally, every scope is associated with an activation literal which guards the
+    /// constraints that are added within that scope, and the solver solves under the assumption
+    /// that the activation literals of the open scopes are true. This means that the nogoods
+    /// which are learned while solving remain valid after a scope is closed; nogoods which depend
+    /// on a retracted constraint contain the (now false) activation literal of its scope and
+    /// become inactive.
+    ///
+    /// Note that a constraint which is infeasible at the root within a scope does not lead to an
+    /// error; instead, the solver will report unsatisfiability until the scope is closed.
+    /// Additionally, the solver does not conclude the proof when it detects unsatisfiability
+    /// within a scope.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pumpkin_solver::constraints;
+    /// # use pumpkin_solver::results::SatisfactionResult;
+    /// # use pumpkin_solver::termination::Indefinite;
+    /// # use pumpkin_solver::Solver;
+    /// let mut solver = Solver::default();
+    ///
+    /// let x = solver.new_bounded_integer(0, 5);
+    /// let y = solver.new_bounded_integer(0, 5);
+    /// solver
+    ///     .add_constraint(constraints::less_than_or_equals([x, y], 6))
+    ///     .post()
+    ///     .expect("no root-level conflict");
+    ///
+    /// let mut brancher = solver.default_brancher();
+    /// let mut termination = Indefinite;
+    ///
+    /// // Within a scope, we add constraints which cannot be satisfied together with the first one
+    /// solver.push();
+    /// solver
+    ///     .add_constraint(constraints::equals([x], 5))
+    ///     .post()
+    ///     .expect("no root-level conflict");
+    /// solver
+    ///     .add_constraint(constraints::equals([y], 5))
+    ///     .post()
+    ///     .expect("no root-level conflict");
+    /// let result = solver.satisfy(&mut brancher, &mut termination);
+    /// assert!(matches!(result, SatisfactionResult::Unsatisfiable));
+    ///
+    /// // After closing the scope, these constraints are retracted
+    /// solver.pop();
+    /// let result = solver.satisfy(&mut brancher, &mut termination);
+    /// assert!(matches!(result, SatisfactionResult::Satisfiable(_)));
+    /// ```
+    pub fn push(&mut self) {
+        let activation_literal = self.new_literal();
+        self.scopes.push(activation_literal);
+    }
+
+    /// Closes the scope which was opened most recently using [`Solver::push`], retracting every
+    /// constraint which was added within that scope.
+    ///
+    /// # Panics
+    /// If there is no open scope.
+    pub fn pop(&mut self) {
+        let activation_literal = self
+            .scopes
+            .pop()
+            .expect("there should be an open scope to pop");
+
+        // The activation literal is permanently set to false, which disables the constraints of
+        // the scope (and the nogoods which depend on them). If this fails, the solver was already
+        // infeasible without the constraints of the scope.
+        let _ = self
+            .satisfaction_solver
+            .add_clause([(!activation_literal).get_true_predicate()]);
+    }
+
+    /// Returns the number of scopes which are currently open.
+    pub fn num_scopes(&self) -> usize {
+        self.scopes.len()
+    }
+
+    /// Returns the activation literal of the innermost open scope, if there is one.
+    pub(crate) fn innermost_scope(&self) -> Option<Literal> {
+        self.scopes.last().copied()
+    }
+
+    /// Calls `f` as if there are no open scopes; this is used to add a constraint which is
+    /// already guarded by the activation literal of a scope.
+    pub(crate) fn without_scopes<R>(&mut self, f: impl FnOnce(&mut Solver) -> R) -> R {
+        let scopes = std::mem::take(&mut self.scopes);
+        let result = f(self);
+        self.scopes = scopes;
+        result
     }
 }
 
 /// Default brancher implementation
 impl Solver {
-    /// Creates an instance of the [`DefaultBrancher`].
+    /// Creates an instance of the [`DefaultBrancher`], which uses the [`BranchingHeuristic`]
+    /// specified in the [`SolverOptions`].
     pub fn default_brancher(&self) -> DefaultBrancher {
-        DefaultBrancher::default_over_all_variables(&self.satisfaction_solver.assignments)
+        DefaultBrancher::with_heuristic(
+            self.satisfaction_solver.branching_heuristic(),
+            &self.satisfaction_solver.assignments,
+        )
     }
 }
 
@@ -497,35 +763,32 @@ impl Solver {
     #[doc(hidden)]
     /// Conclude the proof with the unsatisfiable claim.
     ///
-    /// This method will finish the proof. Any new operation will not be logged to the proof.
+    /// This method will finish the proof. Any new operation will not be logged to the proof. If
+    /// there is an open scope, then the proof is not concluded.
     pub fn conclude_proof_unsat(&mut self) {
+        if !self.scopes.is_empty() {
+            return;
+        }
+
         let _ = self.satisfaction_solver.conclude_proof_unsat();
     }
 
     #[doc(hidden)]
     /// Conclude the proof with the optimality claim.
     ///
-    /// This method will finish the proof. Any new operation will not be logged to the proof.
+    /// This method will finish the proof. Any new operation will not be logged to the proof. If
+    /// there is an open scope, then the proof is not concluded.
     pub fn conclude_proof_optimal(&mut self, bound: Literal) {
-        let _ = self
-            .satisfaction_solver
-            .conclude_proof_optimal(bound.get_true_predicate());
+        self.conclude_proof_optimal_with_bound(bound.get_true_predicate());
     }
-}
 
-/// A brancher which makes use of VSIDS \[1\] and solution-based phase saving (both adapted for CP).
-///
-/// If VSIDS does not contain any (unfixed) predicates then it will default to the
-/// [`IndependentVariableValueBrancher`] using [`RandomSelector`] for variable selection
-/// (over the variables in the order in which they were defined) and [`RandomSplitter`] for
-/// value selection.
-///
-/// # Bibliography
-/// \[1\] M. W. Moskewicz, C. F. Madigan, Y. Zhao, L. Zhang, and S. Malik, ‘Chaff: Engineering an
-/// efficient SAT solver’, in Proceedings of the 38th annual Design Automation Conference, 2001.
-///
-/// \[2\] E. Demirović, G. Chu, and P. J. Stuckey, ‘Solution-based phase saving for CP: A
-/// value-selection heuristic to simulate local search behavior in complete solvers’, in the
-/// proceedings of the Principles and Practice of Constraint Programming (CP 2018).
-pub type DefaultBrancher =
-    AutonomousSearch<IndependentVariableValueBrancher<DomainId, RandomSelector, RandomSplitter>>;
+    /// Conclude the proof with the optimality claim, where `bound` is the bound on the objective
+    /// of the best-found solution.
+    pub(crate) fn conclude_proof_optimal_with_bound(&mut self, bound: Predicate) {
+        if !self.scopes.is_empty() {
+            return;
+        }
+
+        let _ = self.satisfaction_solver.conclude_proof_optimal(bound);
+    }
+}
@@ -18,8 +18,8 @@ pub use function::Function;
 pub(crate) use hash_structures::*;
 pub(crate) use predicate_id_generator::PredicateId;
 pub(crate) use predicate_id_generator::PredicateIdGenerator;
-pub(crate) use propagation_status_cp::Inconsistency;
-pub(crate) use propagation_status_cp::PropagationStatusCP;
+pub use propagation_status_cp::Inconsistency;
+pub use propagation_status_cp::PropagationStatusCP;
 pub use propositional_conjunction::PropositionalConjunction;
 pub use random::*;
 pub use solution::ProblemSolution;
@@ -5,10 +5,10 @@ use crate::predicates::Predicate;
 /// The result of invoking a constraint programming propagator. The propagation can either succeed
 /// or identify a conflict. The necessary conditions for the conflict must be captured in the error
 /// variant, i.e. a propositional conjunction.
-pub(crate) type PropagationStatusCP = Result<(), Inconsistency>;
+pub type PropagationStatusCP = Result<(), Inconsistency>;
 
 #[derive(Debug, PartialEq, Eq)]
-pub(crate) enum Inconsistency {
+pub enum Inconsistency {
     EmptyDomain,
     Conflict(PropositionalConjunction),
 }
@@ -59,9 +59,9 @@ pub trait Random: Debug {
     /// ```
     fn generate_usize_in_range(&mut self, range: Range<usize>) -> usize;
 
-    /// Generates a random i32 in the provided range with equal probability; this can be seen as
+    /// Generates a random i64 in the provided range with equal probability; this can be seen as
     /// sampling from a uniform distribution in the range `[range.start, range.end)`
-    fn generate_i32_in_range(&mut self, range: Range<i32>) -> i32;
+    fn generate_i64_in_range(&mut self, range: Range<i64>) -> i64;
 
     /// Generate a random float in the range 0..1.
     fn generate_f64(&mut self) -> f64;
@@ -91,7 +91,7 @@ where
         self.gen_range(range)
     }
 
-    fn generate_i32_in_range(&mut self, range: Range<i32>) -> i32 {
+    fn generate_i64_in_range(&mut self, range: Range<i64>) -> i64 {
         self.gen_range(range)
     }
 
@@ -135,7 +135,7 @@ pub(crate) mod tests {
     #[derive(Debug)]
     pub(crate) struct TestRandom {
         pub(crate) usizes: Vec<usize>,
-        pub(crate) integers: Vec<i32>,
+        pub(crate) integers: Vec<i64>,
         pub(crate) bools: Vec<bool>,
         pub(crate) weighted_choice: fn(&[f64]) -> Option<usize>,
     }
@@ -167,7 +167,7 @@ pub(crate) mod tests {
             selected
         }
 
-        fn generate_i32_in_range(&mut self, range: Range<i32>) -> i32 {
+        fn generate_i64_in_range(&mut self, range: Range<i64>) -> i64 {
             let selected = self.integers.remove(0);
             pumpkin_assert_simple!(
                 range.contains(&selected),
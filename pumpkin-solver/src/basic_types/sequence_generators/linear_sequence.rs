@@ -0,0 +1,43 @@
+use super::SequenceGenerator;
+
+/// Given a constant 'a', the i-th element f(i) in a linear sequence is computed as:
+///  f(i) = f(i-1) + a
+///  f(0) = a
+///
+/// Note that overflows are not taken into account
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct LinearSequence {
+    current_value: i64,
+    increment: i64,
+}
+
+impl LinearSequence {
+    pub(crate) fn new(increment: i64) -> LinearSequence {
+        LinearSequence {
+            current_value: increment,
+            increment,
+        }
+    }
+}
+
+impl SequenceGenerator for LinearSequence {
+    fn next(&mut self) -> i64 {
+        let next_value = self.current_value;
+        self.current_value += self.increment;
+        next_value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinearSequence;
+    use crate::basic_types::sequence_generators::SequenceGenerator;
+
+    #[test]
+    fn test_multiples_of_increment() {
+        let mut linear_sequence = LinearSequence::new(50);
+        for i in 1..1000 {
+            assert!(linear_sequence.next() == 50 * i);
+        }
+    }
+}
@@ -17,6 +17,11 @@ pub enum SequenceGeneratorType {
     /// When `multiplicative_factor` is not an integer, then the above formula is **not** the same
     /// as the formula `f(i) = a * m^i` since intermediate values are founded down.
     Geometric,
+    /// Indicates that the restarts strategy should use linear restarts.
+    ///
+    /// Given a constant `base`, the i-th element `f(i)` in a linear sequence is calculated as
+    /// `f(i) = base * (i + 1)`.
+    Linear,
     /// Indicates that the restart strategy should use Luby restarts \[1\].
     ///
     ///  The Luby sequence is a recursive sequence of the form:
@@ -33,6 +38,7 @@ impl std::fmt::Display for SequenceGeneratorType {
         match self {
             SequenceGeneratorType::Constant => write!(f, "constant"),
             SequenceGeneratorType::Geometric => write!(f, "geometric"),
+            SequenceGeneratorType::Linear => write!(f, "linear"),
             SequenceGeneratorType::Luby => write!(f, "luby"),
         }
     }
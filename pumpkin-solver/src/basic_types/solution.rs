@@ -13,7 +13,7 @@ pub trait ProblemSolution: HasAssignments {
         self.assignments().num_domains() as usize
     }
 
-    fn get_integer_value<Var: IntegerVariable + 'static>(&self, var: Var) -> i32 {
+    fn get_integer_value<Var: IntegerVariable + 'static>(&self, var: Var) -> i64 {
         self.assignments()
             .get_assigned_value(&var)
             .expect("Expected retrieved integer variable from solution to be assigned")
@@ -1,4 +1,5 @@
 use log::warn;
+use pumpkin_solver::branching::branchers::priority_search::PrioritySelection;
 use pumpkin_solver::branching::value_selection::DynamicValueSelector;
 use pumpkin_solver::branching::value_selection::InDomainInterval;
 use pumpkin_solver::branching::value_selection::InDomainMax;
@@ -14,16 +15,23 @@ use pumpkin_solver::branching::value_selection::OutDomainMin;
 use pumpkin_solver::branching::value_selection::OutDomainRandom;
 use pumpkin_solver::branching::value_selection::ReverseInDomainSplit;
 use pumpkin_solver::branching::variable_selection::AntiFirstFail;
+use pumpkin_solver::branching::variable_selection::DomWDeg;
 use pumpkin_solver::branching::variable_selection::DynamicVariableSelector;
 use pumpkin_solver::branching::variable_selection::FirstFail;
+use pumpkin_solver::branching::variable_selection::Impact;
 use pumpkin_solver::branching::variable_selection::InputOrder;
 use pumpkin_solver::branching::variable_selection::Largest;
 use pumpkin_solver::branching::variable_selection::MaxRegret;
+use pumpkin_solver::branching::variable_selection::MostConstrained;
+use pumpkin_solver::branching::variable_selection::Occurrence;
 use pumpkin_solver::branching::variable_selection::Smallest;
+use pumpkin_solver::options::RestartOptions;
+use pumpkin_solver::options::SequenceGeneratorType;
 use pumpkin_solver::pumpkin_assert_eq_simple;
 use pumpkin_solver::pumpkin_assert_simple;
 use pumpkin_solver::variables::DomainId;
 use pumpkin_solver::variables::Literal;
+use pumpkin_solver::Solver;
 
 use super::error::FlatZincError;
 pub(crate) enum VariableSelectionStrategy {
@@ -49,12 +57,18 @@ impl VariableSelectionStrategy {
                 warn!("AntiFirstFail does not make sense for propositional variables, defaulting to input order...");
                 Box::new(InputOrder::new(propositional_variables))
             }
-            VariableSelectionStrategy::DomWDeg => todo!("DomWDeg is not yet implemented"),
+            VariableSelectionStrategy::DomWDeg => {
+                warn!("DomWDeg is not supported for propositional variables, defaulting to input order...");
+                Box::new(InputOrder::new(propositional_variables))
+            }
             VariableSelectionStrategy::FirstFail => {
                 warn!("FirstFail does not make sense for propositional variables, defaulting to input order...");
                 Box::new(InputOrder::new(propositional_variables))
             }
-            VariableSelectionStrategy::Impact => todo!("Impact is not yet implemented"),
+            VariableSelectionStrategy::Impact => {
+                warn!("Impact is not supported for propositional variables, defaulting to input order...");
+                Box::new(InputOrder::new(propositional_variables))
+            }
             VariableSelectionStrategy::InputOrder => {
                 Box::new(InputOrder::new(propositional_variables))
             }
@@ -67,9 +81,13 @@ impl VariableSelectionStrategy {
                 Box::new(InputOrder::new(propositional_variables))
             }
             VariableSelectionStrategy::MostConstrained => {
-                todo!("MostConstrained is not yet implemented")
+                warn!("MostConstrained is not supported for propositional variables, defaulting to input order...");
+                Box::new(InputOrder::new(propositional_variables))
+            }
+            VariableSelectionStrategy::Occurrence => {
+                warn!("Occurrence is not supported for propositional variables, defaulting to input order...");
+                Box::new(InputOrder::new(propositional_variables))
             }
-            VariableSelectionStrategy::Occurrence => todo!("Occurrence is not yet implemented"),
             VariableSelectionStrategy::Smallest => {
                 warn!("Smallest does not make sense for propositional variables, defaulting to input order...");
                 Box::new(InputOrder::new(propositional_variables))
@@ -80,19 +98,31 @@ impl VariableSelectionStrategy {
     pub(crate) fn create_from_domains(
         &self,
         variables: &[DomainId],
+        solver: &Solver,
     ) -> DynamicVariableSelector<DomainId> {
+        // The number of constraints attached to a variable is the number of propagators which are
+        // registered to it
+        let num_occurrences = || {
+            variables
+                .iter()
+                .map(|&variable| solver.num_attached_propagators(variable))
+                .collect::<Vec<_>>()
+        };
+
         DynamicVariableSelector::new(match self {
             VariableSelectionStrategy::AntiFirstFail => Box::new(AntiFirstFail::new(variables)),
-            VariableSelectionStrategy::DomWDeg => todo!("DomWDeg is not yet implemented"),
+            VariableSelectionStrategy::DomWDeg => Box::new(DomWDeg::new(variables)),
             VariableSelectionStrategy::FirstFail => Box::new(FirstFail::new(variables)),
-            VariableSelectionStrategy::Impact => todo!("Impact is not yet implemented"),
+            VariableSelectionStrategy::Impact => Box::new(Impact::new(variables)),
             VariableSelectionStrategy::InputOrder => Box::new(InputOrder::new(variables)),
             VariableSelectionStrategy::Largest => Box::new(Largest::new(variables)),
             VariableSelectionStrategy::MaxRegret => Box::new(MaxRegret::new(variables)),
             VariableSelectionStrategy::MostConstrained => {
-                todo!("MostConstrained is not yet implemented")
+                Box::new(MostConstrained::new(variables, &num_occurrences()))
+            }
+            VariableSelectionStrategy::Occurrence => {
+                Box::new(Occurrence::new(variables, &num_occurrences()))
             }
-            VariableSelectionStrategy::Occurrence => todo!("Occurrence is not yet implemented"),
             VariableSelectionStrategy::Smallest => Box::new(Smallest::new(variables)),
         })
     }
@@ -168,9 +198,24 @@ pub(crate) enum Search {
     Bool(SearchStrategy),
     Int(SearchStrategy),
     Seq(Vec<Search>),
+    Priority(PrioritySearchStrategy),
+    WarmStart(Vec<WarmStartHint>),
     Unspecified,
 }
 
+pub(crate) struct PrioritySearchStrategy {
+    pub(crate) priority_variables: flatzinc::AnnExpr,
+    pub(crate) searches: Vec<Search>,
+    pub(crate) selection: PrioritySelection,
+}
+
+/// The hints provided by a `warm_start` annotation; the variables (either Boolean or integer) are
+/// hinted to take the value at the same index in `values`.
+pub(crate) struct WarmStartHint {
+    pub(crate) variables: flatzinc::AnnExpr,
+    pub(crate) values: flatzinc::AnnExpr,
+}
+
 pub(crate) struct SearchStrategy {
     pub(crate) variables: flatzinc::AnnExpr,
     pub(crate) variable_selection_strategy: VariableSelectionStrategy,
@@ -184,6 +229,8 @@ pub(crate) struct FlatZincAst {
     pub(crate) constraint_decls: Vec<flatzinc::ConstraintItem>,
     pub(crate) solve_item: flatzinc::SolveItem,
     pub(crate) search: Search,
+    /// The restart options specified by a `restart_*` annotation, if any.
+    pub(crate) restart_options: Option<RestartOptions>,
 }
 
 impl FlatZincAst {
@@ -195,6 +242,7 @@ impl FlatZincAst {
             constraint_decls: vec![],
             solve_item: None,
             search: None,
+            restart_options: None,
         }
     }
 }
@@ -207,6 +255,7 @@ pub(crate) struct FlatZincAstBuilder {
     solve_item: Option<flatzinc::SolveItem>,
 
     search: Option<Search>,
+    restart_options: Option<RestartOptions>,
 }
 
 impl FlatZincAstBuilder {
@@ -227,10 +276,40 @@ impl FlatZincAstBuilder {
     }
 
     pub(crate) fn set_solve_item(&mut self, solve_item: flatzinc::SolveItem) {
-        if let Some(annotation) = solve_item.annotations.first() {
-            self.search = Some(FlatZincAstBuilder::find_search(annotation));
+        // Warm-start annotations can be provided alongside another search annotation, in which
+        // case the hints are used before that search
+        let mut warm_start_annotations = vec![];
+        let mut search_annotations = vec![];
+        for annotation in solve_item.annotations.iter() {
+            match &annotation.id[..] {
+                "warm_start" | "warm_start_array" => warm_start_annotations.push(annotation),
+                "restart_constant" | "restart_geometric" | "restart_linear" | "restart_luby"
+                | "restart_none" => {
+                    self.restart_options =
+                        Some(FlatZincAstBuilder::find_restart_options(annotation))
+                }
+                _ => search_annotations.push(annotation),
+            }
+        }
+
+        let search = search_annotations
+            .first()
+            .map(|annotation| FlatZincAstBuilder::find_search(annotation))
+            .unwrap_or(Search::Unspecified);
+
+        if warm_start_annotations.is_empty() {
+            self.search = Some(search);
         } else {
-            self.search = Some(Search::Unspecified)
+            let warm_start = Search::WarmStart(
+                warm_start_annotations
+                    .into_iter()
+                    .flat_map(FlatZincAstBuilder::find_warm_start_hints)
+                    .collect(),
+            );
+            self.search = Some(match search {
+                Search::Unspecified => warm_start,
+                search => Search::Seq(vec![warm_start, search]),
+            });
         }
         let _ = self.solve_item.insert(solve_item);
     }
@@ -238,7 +317,10 @@ impl FlatZincAstBuilder {
     fn find_search(annotation: &flatzinc::Annotation) -> Search {
         match &annotation.id[..] {
             "bool_search" => Search::Bool(FlatZincAstBuilder::find_direct_search(annotation)),
-            "float_search" => panic!("Search over floats is currently not supported"),
+            "float_search" => {
+                warn!("Search over floats is currently not supported, ignoring float_search...");
+                Search::Unspecified
+            }
             "int_search" => Search::Int(FlatZincAstBuilder::find_direct_search(annotation)),
             "seq_search" => {
                 pumpkin_assert_eq_simple!(
@@ -256,11 +338,79 @@ impl FlatZincAstBuilder {
                     }
                 })
             }
-            "set_search" => panic!("Search over sets is currently not supported"),
+            "priority_search" => {
+                // First element is the array of priority variables
+                // Second element is the array of searches, one for every priority variable
+                // Third element is the selection strategy for the priority variables
+                // (Optional) Fourth element is the exploration strategy (e.g. complete search)
+                pumpkin_assert_simple!(
+                    annotation.expressions.len() >= 3,
+                    "Expected the priority search annotation to have 3 or 4 elements but it has {} elements",
+                    annotation.expressions.len()
+                );
+                let searches = match &annotation.expressions[1] {
+                    flatzinc::AnnExpr::Annotations(annotations) => annotations
+                        .iter()
+                        .map(FlatZincAstBuilder::find_search)
+                        .collect::<Vec<_>>(),
+                    other => panic!(
+                        "Expected a list of annotations for `priority_search` but was {other:?}"
+                    ),
+                };
+                Search::Priority(PrioritySearchStrategy {
+                    priority_variables: annotation.expressions[0].clone(),
+                    searches,
+                    selection: FlatZincAstBuilder::find_priority_selection(
+                        &annotation.expressions[2],
+                    ),
+                })
+            }
+            "set_search" => {
+                warn!("Search over sets is currently not supported, ignoring set_search...");
+                Search::Unspecified
+            }
+            "warm_start" | "warm_start_array" => {
+                Search::WarmStart(FlatZincAstBuilder::find_warm_start_hints(annotation))
+            }
             other => panic!("Did not recognise search strategy {other}"),
         }
     }
 
+    fn find_warm_start_hints(annotation: &flatzinc::Annotation) -> Vec<WarmStartHint> {
+        match &annotation.id[..] {
+            "warm_start" => {
+                // First element is the array of variables
+                // Second element is the array of values which the variables are hinted to take
+                pumpkin_assert_eq_simple!(
+                    annotation.expressions.len(),
+                    2,
+                    "Expected a variable array and a value array for `warm_start`"
+                );
+                vec![WarmStartHint {
+                    variables: annotation.expressions[0].clone(),
+                    values: annotation.expressions[1].clone(),
+                }]
+            }
+            "warm_start_array" => {
+                pumpkin_assert_eq_simple!(
+                    annotation.expressions.len(),
+                    1,
+                    "Expected a single expression for `warm_start_array`"
+                );
+                match &annotation.expressions[0] {
+                    flatzinc::AnnExpr::Annotations(annotations) => annotations
+                        .iter()
+                        .flat_map(FlatZincAstBuilder::find_warm_start_hints)
+                        .collect(),
+                    other => panic!(
+                        "Expected a list of annotations for `warm_start_array` but was {other:?}"
+                    ),
+                }
+            }
+            other => panic!("Expected a warm-start annotation but was {other}"),
+        }
+    }
+
     fn find_direct_search(annotation: &flatzinc::Annotation) -> SearchStrategy {
         // First element is the optimization variable
         // Second element is the variable selection strategy
@@ -283,6 +433,81 @@ impl FlatZincAstBuilder {
         }
     }
 
+    fn find_priority_selection(input: &flatzinc::AnnExpr) -> PrioritySelection {
+        match input {
+            flatzinc::AnnExpr::Expr(inner) => match inner {
+                flatzinc::Expr::VarParIdentifier(identifier) => match &identifier[..] {
+                    "anti_first_fail" => PrioritySelection::AntiFirstFail,
+                    "first_fail" => PrioritySelection::FirstFail,
+                    "input_order" => PrioritySelection::InputOrder,
+                    "largest" => PrioritySelection::Largest,
+                    "smallest" => PrioritySelection::Smallest,
+                    other => panic!("Did not recognise priority selection strategy {other}"),
+                },
+                other => panic!("Expected VarParIdentifier but got {other:?}"),
+            },
+            other => panic!("Expected an expression but got {other:?}"),
+        }
+    }
+
+    fn find_restart_options(annotation: &flatzinc::Annotation) -> RestartOptions {
+        // The restarts follow the specified sequence; they are not forced or blocked based on
+        // the learned nogoods (see `RestartOptions`)
+        let restart_options = RestartOptions {
+            min_num_conflicts_before_first_restart: 0,
+            lbd_coef: 0.0,
+            num_assigned_coef: f64::INFINITY,
+            ..Default::default()
+        };
+
+        match &annotation.id[..] {
+            "restart_constant" => RestartOptions {
+                sequence_generator_type: SequenceGeneratorType::Constant,
+                base_interval: FlatZincAstBuilder::find_restart_scale(annotation, 0),
+                ..restart_options
+            },
+            "restart_geometric" => RestartOptions {
+                sequence_generator_type: SequenceGeneratorType::Geometric,
+                base_interval: FlatZincAstBuilder::find_restart_scale(annotation, 1),
+                geometric_coef: match annotation.expressions.first() {
+                    Some(flatzinc::AnnExpr::Expr(flatzinc::Expr::Float(base))) => Some(*base),
+                    other => {
+                        panic!("Expected a float as the base of the restarts but got {other:?}")
+                    }
+                },
+                ..restart_options
+            },
+            "restart_linear" => RestartOptions {
+                sequence_generator_type: SequenceGeneratorType::Linear,
+                base_interval: FlatZincAstBuilder::find_restart_scale(annotation, 0),
+                ..restart_options
+            },
+            "restart_luby" => RestartOptions {
+                sequence_generator_type: SequenceGeneratorType::Luby,
+                base_interval: FlatZincAstBuilder::find_restart_scale(annotation, 0),
+                ..restart_options
+            },
+            "restart_none" => RestartOptions {
+                no_restarts: true,
+                ..restart_options
+            },
+            other => panic!("Did not recognise restart annotation {other}"),
+        }
+    }
+
+    /// Returns the scale (i.e. the number of conflicts by which the restart sequence is
+    /// multiplied) which is the element at the provided index of the restart annotation.
+    fn find_restart_scale(annotation: &flatzinc::Annotation, index: usize) -> u64 {
+        match annotation.expressions.get(index) {
+            Some(flatzinc::AnnExpr::Expr(flatzinc::Expr::Int(scale))) if *scale > 0 => {
+                u64::try_from(*scale).expect("Expected the scale of the restarts to fit in a u64")
+            }
+            other => {
+                panic!("Expected a positive integer as the scale of the restarts but got {other:?}")
+            }
+        }
+    }
+
     fn find_variable_selection_strategy(input: &flatzinc::AnnExpr) -> VariableSelectionStrategy {
         match input {
             flatzinc::AnnExpr::Expr(inner) => match inner {
@@ -339,6 +564,7 @@ impl FlatZincAstBuilder {
             constraint_decls,
             solve_item,
             search,
+            restart_options,
         } = self;
 
         Ok(FlatZincAst {
@@ -348,6 +574,7 @@ impl FlatZincAstBuilder {
             constraint_decls,
             solve_item: solve_item.ok_or(FlatZincError::MissingSolveItem)?,
             search: search.ok_or(FlatZincError::MissingSolveItem)?,
+            restart_options,
         })
     }
 }
@@ -67,7 +67,7 @@ pub(crate) fn run(
                 let id = context.identifiers.get_interned(id);
 
                 let domain_id = if set.len() == 1 {
-                    let value = i32::try_from(set[0])?;
+                    let value = i64::try_from(set[0])?;
 
                     *context.constant_domain_ids.entry(value).or_insert_with(|| {
                         context
@@ -78,7 +78,7 @@ pub(crate) fn run(
                     let values = set
                         .iter()
                         .copied()
-                        .map(i32::try_from)
+                        .map(i64::try_from)
                         .collect::<Result<Vec<_>, _>>()?;
 
                     let domain_id = context
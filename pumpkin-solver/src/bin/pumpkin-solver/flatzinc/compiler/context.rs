@@ -1,6 +1,7 @@
 use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::num::NonZero;
 use std::rc::Rc;
 
 use log::warn;
@@ -41,30 +42,37 @@ pub(crate) struct CompilationContext<'a> {
     // A literal which is always false, can be used when using bool constants in the solver
     // pub(crate) constant_bool_false: BooleanDomainId,
     /// All integer parameters.
-    pub(crate) integer_parameters: HashMap<Rc<str>, i32>,
+    pub(crate) integer_parameters: HashMap<Rc<str>, i64>,
     /// All integer array parameters.
-    pub(crate) integer_array_parameters: HashMap<Rc<str>, Rc<[i32]>>,
+    pub(crate) integer_array_parameters: HashMap<Rc<str>, Rc<[i64]>>,
     /// A mapping from integer model variables to solver literals.
     pub(crate) integer_variable_map: HashMap<Rc<str>, DomainId>,
     /// The equivalence classes for integer variables. The associated data is the bounds for the
     /// domain of the representative of the equivalence class..
     pub(crate) integer_equivalences: VariableEquivalences,
     /// Only instantiate single domain for every constant variable.
-    pub(crate) constant_domain_ids: HashMap<i32, DomainId>,
+    pub(crate) constant_domain_ids: HashMap<i64, DomainId>,
     /// A mapping from integer variable array identifiers to slices of domain ids.
     pub(crate) integer_variable_arrays: HashMap<Rc<str>, Rc<[DomainId]>>,
 
     /// All set parameters.
     pub(crate) set_constants: HashMap<Rc<str>, Set>,
+
+    /// The tag of the constraint which is currently being compiled. The tag of a constraint is its
+    /// 1-based index in the constraint items of the model, which allows proof checkers to link
+    /// inferences back to the constraint in the model.
+    ///
+    /// Clauses cannot be tagged, so constraints which are posted as clauses remain untagged.
+    pub(crate) constraint_tag: Option<NonZero<u32>>,
 }
 
 /// A set parameter.
 #[derive(Clone, Debug)]
 pub(crate) enum Set {
     /// A set defined by the interval `lower_bound..=upper_bound`.
-    Interval { lower_bound: i32, upper_bound: i32 },
+    Interval { lower_bound: i64, upper_bound: i64 },
     /// A set defined by some values.
-    Sparse { values: Box<[i32]> },
+    Sparse { values: Box<[i64]> },
 }
 
 impl CompilationContext<'_> {
@@ -93,6 +101,8 @@ impl CompilationContext<'_> {
             integer_variable_arrays: Default::default(),
 
             set_constants: Default::default(),
+
+            constraint_tag: None,
         }
     }
 
@@ -104,7 +114,7 @@ impl CompilationContext<'_> {
     //     self.boolean_parameters.get(identifier).copied()
     // }
 
-    // pub fn resolve_int_constant(&self, identifier: &str) -> Option<i32> {
+    // pub fn resolve_int_constant(&self, identifier: &str) -> Option<i64> {
     //     self.integer_parameters.get(identifier).copied()
     // }
 
@@ -206,7 +216,7 @@ impl CompilationContext<'_> {
     pub(crate) fn resolve_array_integer_constants(
         &self,
         expr: &flatzinc::Expr,
-    ) -> Result<Rc<[i32]>, FlatZincError> {
+    ) -> Result<Rc<[i64]>, FlatZincError> {
         match expr {
             flatzinc::Expr::VarParIdentifier(id) => self
                 .integer_array_parameters
@@ -219,7 +229,38 @@ impl CompilationContext<'_> {
             flatzinc::Expr::ArrayOfInt(exprs) => exprs
                 .iter()
                 .map(|e| self.resolve_int_expr_to_const(e))
-                .collect::<Result<Rc<[i32]>, _>>(),
+                .collect::<Result<Rc<[i64]>, _>>(),
+            _ => Err(FlatZincError::UnexpectedExpr),
+        }
+    }
+
+    pub(crate) fn resolve_array_bool_constants(
+        &self,
+        expr: &flatzinc::Expr,
+    ) -> Result<Rc<[bool]>, FlatZincError> {
+        match expr {
+            flatzinc::Expr::VarParIdentifier(id) => self
+                .boolean_array_parameters
+                .get(id.as_str())
+                .cloned()
+                .ok_or_else(|| FlatZincError::InvalidIdentifier {
+                    identifier: id.as_str().into(),
+                    expected_type: "constant boolean array".into(),
+                }),
+            flatzinc::Expr::ArrayOfBool(exprs) => exprs
+                .iter()
+                .map(|e| match e {
+                    flatzinc::BoolExpr::Bool(value) => Ok(*value),
+                    flatzinc::BoolExpr::VarParIdentifier(id) => self
+                        .boolean_parameters
+                        .get(id.as_str())
+                        .copied()
+                        .ok_or_else(|| FlatZincError::InvalidIdentifier {
+                            identifier: id.as_str().into(),
+                            expected_type: "constant boolean".into(),
+                        }),
+                })
+                .collect::<Result<Rc<[bool]>, _>>(),
             _ => Err(FlatZincError::UnexpectedExpr),
         }
     }
@@ -240,7 +281,7 @@ impl CompilationContext<'_> {
     pub(crate) fn resolve_integer_constant_from_expr(
         &self,
         expr: &flatzinc::Expr,
-    ) -> Result<i32, FlatZincError> {
+    ) -> Result<i64, FlatZincError> {
         fn try_into_int_expr(expr: flatzinc::Expr) -> Option<flatzinc::IntExpr> {
             match expr {
                 flatzinc::Expr::VarParIdentifier(id) => {
@@ -258,9 +299,9 @@ impl CompilationContext<'_> {
     pub(crate) fn resolve_int_expr_to_const(
         &self,
         expr: &flatzinc::IntExpr,
-    ) -> Result<i32, FlatZincError> {
+    ) -> Result<i64, FlatZincError> {
         match expr {
-            flatzinc::IntExpr::Int(value) => i32::try_from(*value).map_err(Into::into),
+            flatzinc::IntExpr::Int(value) => i64::try_from(*value).map_err(Into::into),
             flatzinc::IntExpr::VarParIdentifier(id) => self
                 .integer_parameters
                 .get(id.as_str())
@@ -279,11 +320,11 @@ impl CompilationContext<'_> {
         match expr {
             flatzinc::IntExpr::Int(value) => Ok(*self
                 .constant_domain_ids
-                .entry(*value as i32)
+                .entry(*value as i64)
                 .or_insert_with(|| {
                     self.solver.new_named_bounded_integer(
-                        *value as i32,
-                        *value as i32,
+                        *value as i64,
+                        *value as i64,
                         value.to_string(),
                     )
                 })),
@@ -303,10 +344,10 @@ impl CompilationContext<'_> {
             }
             flatzinc::Expr::Int(val) => Ok(*self
                 .constant_domain_ids
-                .entry(*val as i32)
+                .entry(*val as i64)
                 .or_insert_with(|| {
                     self.solver
-                        .new_named_bounded_integer(*val as i32, *val as i32, val.to_string())
+                        .new_named_bounded_integer(*val as i64, *val as i64, val.to_string())
                 })),
             _ => Err(FlatZincError::UnexpectedExpr),
         }
@@ -496,7 +537,7 @@ impl VariableEquivalences {
     }
 
     /// Create a new equivalence class with the given representative.
-    pub(crate) fn create_equivalence_class(&mut self, representative: Rc<str>, lb: i32, ub: i32) {
+    pub(crate) fn create_equivalence_class(&mut self, representative: Rc<str>, lb: i64, ub: i64) {
         let _ = self
             .belongs_to
             .insert(Rc::clone(&representative), self.classes.len());
@@ -535,8 +576,8 @@ impl VariableEquivalences {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub(crate) enum Domain {
-    IntervalDomain { lb: i32, ub: i32 },
-    SparseDomain { values: Vec<i32> },
+    IntervalDomain { lb: i64, ub: i64 },
+    SparseDomain { values: Vec<i64> },
 }
 
 impl From<Set> for Domain {
@@ -566,8 +607,8 @@ impl Domain {
                     ub: ub_other,
                 },
             ) => {
-                let lb = i32::max(lb, lb_other);
-                let ub = i32::min(ub, ub_other);
+                let lb = i64::max(lb, lb_other);
+                let ub = i64::min(ub, ub_other);
 
                 Domain::from_lower_bound_and_upper_bound(lb, ub)
             }
@@ -609,7 +650,7 @@ impl Domain {
         }
     }
 
-    pub(crate) fn from_lower_bound_and_upper_bound(lb: i32, ub: i32) -> Self {
+    pub(crate) fn from_lower_bound_and_upper_bound(lb: i64, ub: i64) -> Self {
         Domain::IntervalDomain { lb, ub }
     }
 
@@ -2,6 +2,7 @@
 
 use flatzinc::BoolExpr;
 use flatzinc::Goal;
+use pumpkin_solver::variables::DomainId;
 
 use super::context::CompilationContext;
 use crate::flatzinc::ast::FlatZincAst;
@@ -42,3 +43,47 @@ pub(crate) fn run(
         ),
     }
 }
+
+/// Finds the terms `w_i * x_i` of which the objective is the weighted sum, based on the linear
+/// equality which defines the objective variable. If there is no such equality, then the objective
+/// variable is the only term.
+pub(crate) fn find_objective_terms(
+    ast: &FlatZincAst,
+    context: &mut CompilationContext,
+    objective: DomainId,
+) -> Result<Vec<(i64, DomainId)>, FlatZincError> {
+    for constraint_item in &ast.constraint_decls {
+        let flatzinc::ConstraintItem { id, exprs, .. } = constraint_item;
+        if id != "int_lin_eq" || exprs.len() != 3 {
+            continue;
+        }
+
+        let weights = context.resolve_array_integer_constants(&exprs[0])?;
+        let variables = context.resolve_integer_variable_array(&exprs[1])?;
+
+        // The objective should occur exactly once, with a weight of 1 or -1
+        let mut occurrences = variables
+            .iter()
+            .enumerate()
+            .filter(|&(_, &variable)| variable == objective);
+        let (Some((objective_index, _)), None) = (occurrences.next(), occurrences.next()) else {
+            continue;
+        };
+        let objective_weight = weights[objective_index];
+        if objective_weight.abs() != 1 {
+            continue;
+        }
+
+        // We have that `objective_weight * objective + sum w_i * x_i = rhs`, which means that the
+        // objective is equal to `sum (-w_i * objective_weight) * x_i` (up to a constant)
+        return Ok(weights
+            .iter()
+            .zip(variables.iter())
+            .enumerate()
+            .filter(|&(index, _)| index != objective_index)
+            .map(|(_, (&weight, &variable))| (-weight * objective_weight, variable))
+            .collect());
+    }
+
+    Ok(vec![(1, objective)])
+}
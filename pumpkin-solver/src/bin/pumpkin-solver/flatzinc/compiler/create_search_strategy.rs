@@ -2,29 +2,49 @@ use std::rc::Rc;
 
 use pumpkin_solver::branching::branchers::dynamic_brancher::DynamicBrancher;
 use pumpkin_solver::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use pumpkin_solver::branching::branchers::priority_search::PrioritySearch;
+use pumpkin_solver::branching::branchers::warm_start::WarmStart;
+use pumpkin_solver::branching::value_selection::DynamicValueSelector;
+use pumpkin_solver::branching::value_selection::SolutionGuided;
 use pumpkin_solver::branching::Brancher;
+use pumpkin_solver::predicate;
+use pumpkin_solver::predicates::Predicate;
 use pumpkin_solver::variables::DomainId;
+use pumpkin_solver::variables::IntegerVariable;
 use pumpkin_solver::variables::Literal;
+use pumpkin_solver::Solver;
 
 use super::context::CompilationContext;
 use crate::flatzinc::ast::FlatZincAst;
+use crate::flatzinc::ast::PrioritySearchStrategy;
 use crate::flatzinc::ast::Search;
 use crate::flatzinc::ast::SearchStrategy;
 use crate::flatzinc::ast::ValueSelectionStrategy;
 use crate::flatzinc::ast::VariableSelectionStrategy;
+use crate::flatzinc::ast::WarmStartHint;
 use crate::flatzinc::error::FlatZincError;
 
 pub(crate) fn run(
     ast: &FlatZincAst,
     context: &mut CompilationContext,
+    solution_guided_search: bool,
 ) -> Result<DynamicBrancher, FlatZincError> {
-    create_from_search_strategy(&ast.search, context, true)
+    if let Some(restart_options) = ast.restart_options {
+        context.solver.set_restart_options(restart_options);
+    }
+
+    // When optimising, the annotated search selects values based on the most recent solution
+    // unless this has been disabled
+    let is_solution_guided =
+        solution_guided_search && !matches!(ast.solve_item.goal, flatzinc::Goal::Satisfy);
+    create_from_search_strategy(&ast.search, context, true, is_solution_guided)
 }
 
 fn create_from_search_strategy(
     strategy: &Search,
     context: &mut CompilationContext,
     append_default_search: bool,
+    is_solution_guided: bool,
 ) -> Result<DynamicBrancher, FlatZincError> {
     let mut brancher = match strategy {
         Search::Bool(SearchStrategy {
@@ -46,6 +66,7 @@ fn create_from_search_strategy(
                 &search_variables,
                 variable_selection_strategy,
                 value_selection_strategy,
+                is_solution_guided,
             )
         }
         Search::Int(SearchStrategy {
@@ -65,6 +86,8 @@ fn create_from_search_strategy(
                 &search_variables,
                 variable_selection_strategy,
                 value_selection_strategy,
+                context.solver,
+                is_solution_guided,
             )
         }
         Search::Seq(search_strategies) => DynamicBrancher::new(
@@ -72,21 +95,53 @@ fn create_from_search_strategy(
                 .iter()
                 .map(|strategy| {
                     let downcast: Box<dyn Brancher> = Box::new(
-                        create_from_search_strategy(strategy, context, false)
+                        create_from_search_strategy(strategy, context, false, is_solution_guided)
                             .expect("Expected nested sequential strategy to be able to be created"),
                     );
                     downcast
                 })
                 .collect::<Vec<_>>(),
         ),
+        Search::Priority(PrioritySearchStrategy {
+            priority_variables,
+            searches,
+            selection,
+        }) => {
+            let priority_variables = match priority_variables {
+                flatzinc::AnnExpr::Expr(expr) => context.resolve_integer_variable_array(expr)?,
+                other => panic!("Expected expression but got {other:?}"),
+            };
+            let branchers = searches
+                .iter()
+                .map(|search| {
+                    let downcast: Box<dyn Brancher> = Box::new(create_from_search_strategy(
+                        search,
+                        context,
+                        false,
+                        is_solution_guided,
+                    )?);
+                    Ok(downcast)
+                })
+                .collect::<Result<Vec<_>, FlatZincError>>()?;
 
-        Search::Unspecified => {
-            assert!(
-                append_default_search,
-                "when no search is specified, we must add a default search"
-            );
+            DynamicBrancher::new(vec![Box::new(PrioritySearch::new(
+                priority_variables.to_vec(),
+                branchers,
+                *selection,
+            ))])
+        }
+        Search::WarmStart(hints) => {
+            // The hints are only used for branching; the remaining decisions are made by the
+            // subsequent search (if any)
+            DynamicBrancher::new(vec![Box::new(WarmStart::new(
+                create_warm_start_hints(hints, context)?,
+                DynamicBrancher::new(vec![]),
+            ))])
+        }
 
-            // The default search will be added below, so we give an empty brancher here.
+        Search::Unspecified => {
+            // Either the default search will be added below, or the search was ignored as part
+            // of a larger search, so we give an empty brancher here.
             DynamicBrancher::new(vec![])
         }
     };
@@ -102,14 +157,57 @@ fn create_from_search_strategy(
     Ok(brancher)
 }
 
+fn create_warm_start_hints(
+    hints: &[WarmStartHint],
+    context: &mut CompilationContext,
+) -> Result<Vec<Predicate>, FlatZincError> {
+    let mut predicates = vec![];
+
+    for WarmStartHint { variables, values } in hints {
+        let (flatzinc::AnnExpr::Expr(variables), flatzinc::AnnExpr::Expr(values)) =
+            (variables, values)
+        else {
+            panic!("Expected expressions for `warm_start` but got {variables:?} and {values:?}")
+        };
+
+        // The values determine whether the hints are over Boolean or integer variables
+        if let Ok(values) = context.resolve_array_bool_constants(values) {
+            let literals = context.resolve_bool_variable_array(variables)?;
+            predicates.extend(literals.iter().zip(values.iter()).map(|(literal, &value)| {
+                if value {
+                    literal.get_true_predicate()
+                } else {
+                    literal.get_false_predicate()
+                }
+            }));
+        } else {
+            let values = context.resolve_array_integer_constants(values)?;
+            let domains = context.resolve_integer_variable_array(variables)?;
+            predicates.extend(
+                domains
+                    .iter()
+                    .zip(values.iter())
+                    .map(|(&domain, &value)| predicate!(domain == value)),
+            );
+        }
+    }
+
+    Ok(predicates)
+}
+
 fn create_search_over_domains(
     search_variables: &[DomainId],
     variable_selection_strategy: &VariableSelectionStrategy,
     value_selection_strategy: &ValueSelectionStrategy,
+    solver: &Solver,
+    is_solution_guided: bool,
 ) -> DynamicBrancher {
     DynamicBrancher::new(vec![Box::new(IndependentVariableValueBrancher::new(
-        variable_selection_strategy.create_from_domains(search_variables),
-        value_selection_strategy.create_for_domains(),
+        variable_selection_strategy.create_from_domains(search_variables, solver),
+        guide_by_solutions(
+            value_selection_strategy.create_for_domains(),
+            is_solution_guided,
+        ),
     ))])
 }
 
@@ -117,9 +215,27 @@ fn create_search_over_propositional_variables(
     search_variables: &[Literal],
     variable_selection_strategy: &VariableSelectionStrategy,
     value_selection_strategy: &ValueSelectionStrategy,
+    is_solution_guided: bool,
 ) -> DynamicBrancher {
     DynamicBrancher::new(vec![Box::new(IndependentVariableValueBrancher::new(
         variable_selection_strategy.create_from_literals(search_variables),
-        value_selection_strategy.create_for_literals(),
+        guide_by_solutions(
+            value_selection_strategy.create_for_literals(),
+            is_solution_guided,
+        ),
     ))])
 }
+
+/// If the search is solution-guided, wraps the provided value selector such that variables are
+/// assigned the value they have in the most recent solution (see [`SolutionGuided`]); the provided
+/// selector is used until a solution is found.
+fn guide_by_solutions<Var: IntegerVariable + Copy + 'static>(
+    value_selector: DynamicValueSelector<Var>,
+    is_solution_guided: bool,
+) -> DynamicValueSelector<Var> {
+    if is_solution_guided {
+        DynamicValueSelector::new(Box::new(SolutionGuided::new(value_selector)))
+    } else {
+        value_selector
+    }
+}
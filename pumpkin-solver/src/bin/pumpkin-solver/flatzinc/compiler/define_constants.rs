@@ -20,7 +20,7 @@ pub(crate) fn run(
             }
 
             flatzinc::ParDeclItem::Int { id, int } => {
-                let value = i32::try_from(*int)?;
+                let value = i64::try_from(*int)?;
 
                 let _ = context
                     .integer_parameters
@@ -36,7 +36,7 @@ pub(crate) fn run(
             flatzinc::ParDeclItem::ArrayOfInt { id, v, .. } => {
                 let value = v
                     .iter()
-                    .map(|value| i32::try_from(*value))
+                    .map(|value| i64::try_from(*value))
                     .collect::<Result<Rc<[_]>, _>>()?;
 
                 let _ = context
@@ -47,15 +47,15 @@ pub(crate) fn run(
             flatzinc::ParDeclItem::SetOfInt { id, set_literal } => {
                 let set = match set_literal {
                     flatzinc::SetLiteral::IntRange(lower_bound, upper_bound) => Set::Interval {
-                        lower_bound: i32::try_from(*lower_bound)?,
-                        upper_bound: i32::try_from(*upper_bound)?,
+                        lower_bound: i64::try_from(*lower_bound)?,
+                        upper_bound: i64::try_from(*upper_bound)?,
                     },
 
                     flatzinc::SetLiteral::SetInts(values) => {
                         let values = values
                             .iter()
                             .copied()
-                            .map(i32::try_from)
+                            .map(i64::try_from)
                             .collect::<Result<_, _>>()?;
 
                         Set::Sparse { values }
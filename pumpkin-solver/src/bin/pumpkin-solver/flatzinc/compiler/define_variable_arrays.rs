@@ -79,7 +79,7 @@ pub(crate) fn run(
                             .iter()
                             .map(|expr| match expr {
                                 IntExpr::Int(int) => {
-                                    let value = i32::try_from(*int)?;
+                                    let value = i64::try_from(*int)?;
 
                                     Ok(*context.constant_domain_ids.entry(value).or_insert_with(
                                         || context.solver.new_bounded_integer(value, value),
@@ -120,7 +120,7 @@ pub(crate) fn run(
     Ok(())
 }
 
-fn is_output_array(annos: &[Annotation]) -> Option<Box<[(i32, i32)]>> {
+fn is_output_array(annos: &[Annotation]) -> Option<Box<[(i64, i64)]>> {
     annos.iter().find_map(|annotation| {
         if annotation.id == "output_array" {
             assert_eq!(1, annotation.expressions.len());
@@ -168,8 +168,8 @@ fn is_output_array(annos: &[Annotation]) -> Option<Box<[(i32, i32)]>> {
                             })
                             .map(|(&min, &max)| {
                                 (
-                                    i32::try_from(min).expect("integer too large"),
-                                    i32::try_from(max).expect("integer too large"),
+                                    i64::try_from(min).expect("integer too large"),
+                                    i64::try_from(max).expect("integer too large"),
                                 )
                             })
                             .collect(),
@@ -10,6 +10,7 @@ mod post_constraints;
 mod prepare_variables;
 
 use context::CompilationContext;
+use pumpkin_solver::optimisation::OptimisationStrategy;
 use pumpkin_solver::Solver;
 
 use super::ast::FlatZincAst;
@@ -32,11 +33,19 @@ pub(crate) fn compile(
     define_variable_arrays::run(&ast, &mut context)?;
     post_constraints::run(&ast, &mut context, options)?;
     let objective_function = create_objective::run(&ast, &mut context)?;
-    let search = create_search_strategy::run(&ast, &mut context)?;
+    let objective_terms = match (objective_function, options.optimisation_strategy) {
+        (Some(objective), OptimisationStrategy::CoreGuided) => {
+            let (_, objective) = objective.into();
+            create_objective::find_objective_terms(&ast, &mut context, objective)?
+        }
+        _ => vec![],
+    };
+    let search = create_search_strategy::run(&ast, &mut context, options.solution_guided_search)?;
 
     Ok(FlatZincInstance {
         outputs: context.outputs,
         objective_function,
+        objective_terms,
         search: Some(search),
     })
 }
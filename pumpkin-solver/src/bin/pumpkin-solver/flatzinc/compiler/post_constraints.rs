@@ -1,9 +1,11 @@
 //! Compile constraints into CP propagators
 
+use std::num::NonZero;
 use std::rc::Rc;
 
 use pumpkin_solver::constraints;
 use pumpkin_solver::constraints::Constraint;
+use pumpkin_solver::constraints::DeterministicFiniteAutomaton;
 use pumpkin_solver::constraints::NegatableConstraint;
 use pumpkin_solver::predicate;
 use pumpkin_solver::predicates::Predicate;
@@ -22,8 +24,9 @@ pub(crate) fn run(
     context: &mut CompilationContext,
     options: FlatZincOptions,
 ) -> Result<(), FlatZincError> {
-    for constraint_item in &ast.constraint_decls {
+    for (index, constraint_item) in ast.constraint_decls.iter().enumerate() {
         let flatzinc::ConstraintItem { id, exprs, annos } = constraint_item;
+        context.constraint_tag = u32::try_from(index + 1).ok().and_then(NonZero::new);
 
         let is_satisfiable: bool = match id.as_str() {
             "array_int_maximum" => compile_array_int_maximum(context, exprs)?,
@@ -164,7 +167,7 @@ pub(crate) fn run(
                 constraints::absolute,
             )?,
 
-            "pumpkin_all_different" => compile_all_different(context, exprs, annos)?,
+            "pumpkin_all_different" => compile_all_different(context, exprs, annos, &options)?,
 
             "array_bool_and" => compile_array_bool_and(context, exprs)?,
             "array_bool_element" => {
@@ -179,13 +182,9 @@ pub(crate) fn run(
 
             "bool2int" => compile_bool2int(context, exprs)?,
 
-            "bool_lin_eq" => {
-                compile_bool_lin_eq_predicate(context, exprs)?
-            }
+            "bool_lin_eq" => compile_bool_lin_eq_predicate(context, exprs)?,
 
-            "bool_lin_le" => {
-                compile_bool_lin_le_predicate(context, exprs)?
-            }
+            "bool_lin_le" => compile_bool_lin_le_predicate(context, exprs)?,
 
             "bool_and" => compile_bool_and(context, exprs)?,
             "bool_clause" => compile_bool_clause(context, exprs)?,
@@ -200,7 +199,12 @@ pub(crate) fn run(
             }
 
             "pumpkin_cumulative" => compile_cumulative(context, exprs, &options)?,
-            "pumpkin_cumulative_var" => todo!("The `cumulative` constraint with variable duration/resource consumption/bound is not implemented yet!"),
+            "pumpkin_cumulative_var" => compile_cumulative_var(context, exprs, &options)?,
+            "pumpkin_disjunctive_strict" => compile_disjunctive_strict(context, exprs, &options)?,
+            "pumpkin_table_int" => compile_table_int(context, exprs)?,
+            "pumpkin_table_bool" => compile_table_bool(context, exprs)?,
+            "pumpkin_regular" => compile_regular(context, exprs)?,
+            "pumpkin_cost_regular" => compile_cost_regular(context, exprs)?,
             unknown => todo!("unsupported constraint {unknown}"),
         };
 
@@ -243,10 +247,170 @@ fn compile_cumulative(
         resource_capacity,
         options.cumulative_options,
     )
-    .post(context.solver, None);
+    .post(context.solver, context.constraint_tag);
+    Ok(post_result.is_ok())
+}
+
+fn compile_cumulative_var(
+    context: &mut CompilationContext<'_>,
+    exprs: &[flatzinc::Expr],
+    options: &FlatZincOptions,
+) -> Result<bool, FlatZincError> {
+    check_parameters!(exprs, 4, "pumpkin_cumulative_var");
+
+    let start_times = context.resolve_integer_variable_array(&exprs[0])?;
+    let durations = context.resolve_integer_variable_array(&exprs[1])?;
+    let resource_requirements = context.resolve_integer_variable_array(&exprs[2])?;
+    let resource_capacity = context.resolve_integer_variable(&exprs[3])?;
+
+    let post_result = constraints::cumulative_with_options(
+        start_times.iter().copied(),
+        durations.iter().copied(),
+        resource_requirements.iter().copied(),
+        resource_capacity,
+        options.cumulative_options,
+    )
+    .post(context.solver, context.constraint_tag);
+    Ok(post_result.is_ok())
+}
+
+fn compile_disjunctive_strict(
+    context: &mut CompilationContext<'_>,
+    exprs: &[flatzinc::Expr],
+    options: &FlatZincOptions,
+) -> Result<bool, FlatZincError> {
+    check_parameters!(exprs, 2, "pumpkin_disjunctive_strict");
+
+    let start_times = context.resolve_integer_variable_array(&exprs[0])?;
+    let durations = context.resolve_array_integer_constants(&exprs[1])?;
+
+    let post_result = constraints::disjunctive_with_options(
+        start_times.iter().copied(),
+        durations.iter().copied(),
+        options.disjunctive_options,
+    )
+    .post(context.solver, context.constraint_tag);
+    Ok(post_result.is_ok())
+}
+
+fn compile_table_int(
+    context: &mut CompilationContext<'_>,
+    exprs: &[flatzinc::Expr],
+) -> Result<bool, FlatZincError> {
+    check_parameters!(exprs, 2, "pumpkin_table_int");
+
+    let variables = context.resolve_integer_variable_array(&exprs[0])?;
+    let tuples = context
+        .resolve_array_integer_constants(&exprs[1])?
+        .chunks_exact(variables.len())
+        .map(|tuple| tuple.to_vec())
+        .collect::<Vec<_>>();
+
+    let post_result =
+        constraints::table(variables.to_vec(), tuples).post(context.solver, context.constraint_tag);
+    Ok(post_result.is_ok())
+}
+
+fn compile_table_bool(
+    context: &mut CompilationContext<'_>,
+    exprs: &[flatzinc::Expr],
+) -> Result<bool, FlatZincError> {
+    check_parameters!(exprs, 2, "pumpkin_table_bool");
+
+    let variables = context.resolve_bool_variable_array(&exprs[0])?;
+    let tuples = context
+        .resolve_array_bool_constants(&exprs[1])?
+        .chunks_exact(variables.len())
+        .map(|tuple| tuple.iter().map(|&value| value as i64).collect())
+        .collect::<Vec<_>>();
+
+    let post_result =
+        constraints::table(variables.to_vec(), tuples).post(context.solver, context.constraint_tag);
     Ok(post_result.is_ok())
 }
 
+fn compile_regular(
+    context: &mut CompilationContext<'_>,
+    exprs: &[flatzinc::Expr],
+) -> Result<bool, FlatZincError> {
+    check_parameters!(exprs, 6, "pumpkin_regular");
+
+    let variables = context.resolve_integer_variable_array(&exprs[0])?;
+    let (automaton, _) = resolve_automaton(context, &exprs[1..6])?;
+
+    let post_result = constraints::regular(variables.to_vec(), automaton)
+        .post(context.solver, context.constraint_tag);
+    Ok(post_result.is_ok())
+}
+
+fn compile_cost_regular(
+    context: &mut CompilationContext<'_>,
+    exprs: &[flatzinc::Expr],
+) -> Result<bool, FlatZincError> {
+    check_parameters!(exprs, 8, "pumpkin_cost_regular");
+
+    let variables = context.resolve_integer_variable_array(&exprs[0])?;
+    let (automaton, transition_positions) = resolve_automaton(context, &exprs[1..6])?;
+    let costs = context.resolve_array_integer_constants(&exprs[6])?;
+    let cost = context.resolve_integer_variable(&exprs[7])?;
+
+    let transition_costs = transition_positions
+        .into_iter()
+        .map(|position| costs[position])
+        .collect();
+
+    let post_result =
+        constraints::cost_regular(variables.to_vec(), automaton, transition_costs, cost)
+            .post(context.solver, context.constraint_tag);
+    Ok(post_result.is_ok())
+}
+
+/// Creates the automaton from the arguments `Q, S, d, q0, F` of the MiniZinc `regular` constraint,
+/// where `d` is the flattened transition table in which state 0 is the failure state (and the
+/// other states are numbered `1..=Q`).
+///
+/// Also returns, for every transition, its position in `d`.
+fn resolve_automaton(
+    context: &CompilationContext<'_>,
+    exprs: &[flatzinc::Expr],
+) -> Result<(DeterministicFiniteAutomaton, Vec<usize>), FlatZincError> {
+    let num_states = context.resolve_integer_constant_from_expr(&exprs[0])? as usize;
+    let num_values = context.resolve_integer_constant_from_expr(&exprs[1])? as usize;
+    let table = context.resolve_array_integer_constants(&exprs[2])?;
+    let initial_state = context.resolve_integer_constant_from_expr(&exprs[3])? as usize;
+    let accepting_states = match context.resolve_set_constant(&exprs[4])? {
+        Set::Interval {
+            lower_bound,
+            upper_bound,
+        } => (lower_bound..=upper_bound).collect::<Vec<_>>(),
+        Set::Sparse { values } => values.to_vec(),
+    };
+
+    let mut transitions = Vec::new();
+    let mut transition_positions = Vec::new();
+    for (position, &to) in table.iter().enumerate() {
+        if to == 0 {
+            continue;
+        }
+
+        let from = position / num_values;
+        let value = (position % num_values + 1) as i64;
+        transitions.push((from, value, to as usize - 1));
+        transition_positions.push(position);
+    }
+
+    let automaton = DeterministicFiniteAutomaton::new(
+        num_states,
+        initial_state - 1,
+        accepting_states
+            .into_iter()
+            .map(|state| state as usize - 1)
+            .collect(),
+        transitions,
+    );
+    Ok((automaton, transition_positions))
+}
+
 fn compile_array_int_maximum(
     context: &mut CompilationContext<'_>,
     exprs: &[flatzinc::Expr],
@@ -257,7 +421,7 @@ fn compile_array_int_maximum(
     let array = context.resolve_integer_variable_array(&exprs[1])?;
 
     Ok(constraints::maximum(array.as_ref().to_owned(), rhs)
-        .post(context.solver, None)
+        .post(context.solver, context.constraint_tag)
         .is_ok())
 }
 
@@ -271,7 +435,7 @@ fn compile_array_int_minimum(
     let array = context.resolve_integer_variable_array(&exprs[1])?;
 
     Ok(constraints::minimum(array.as_ref().to_owned(), rhs)
-        .post(context.solver, None)
+        .post(context.solver, context.constraint_tag)
         .is_ok())
 }
 
@@ -351,7 +515,7 @@ fn compile_array_var_int_element(
     let rhs = context.resolve_integer_variable(&exprs[2])?;
 
     Ok(constraints::element(index, array.as_ref().to_owned(), rhs)
-        .post(context.solver, None)
+        .post(context.solver, context.constraint_tag)
         .is_ok())
 }
 
@@ -369,7 +533,7 @@ fn compile_bool_not(
     let b = context.resolve_bool_variable(&exprs[1])?;
 
     Ok(constraints::binary_not_equals(a, b)
-        .post(context.solver, None)
+        .post(context.solver, context.constraint_tag)
         .is_ok())
 }
 
@@ -384,7 +548,7 @@ fn compile_bool_eq_reif(
     let r = context.resolve_bool_variable(&exprs[2])?;
 
     Ok(constraints::binary_equals(a, b)
-        .reify(context.solver, r, None)
+        .reify(context.solver, r, context.constraint_tag)
         .is_ok())
 }
 
@@ -400,7 +564,7 @@ fn compile_bool_eq(
     let b = context.resolve_bool_variable(&exprs[1])?;
 
     Ok(constraints::binary_equals(a, b)
-        .post(context.solver, None)
+        .post(context.solver, context.constraint_tag)
         .is_ok())
 }
 
@@ -453,7 +617,7 @@ fn compile_bool2int(
 
     Ok(
         constraints::binary_equals(a.get_integer_variable(), b.scaled(1))
-            .post(context.solver, None)
+            .post(context.solver, context.constraint_tag)
             .is_ok(),
     )
 }
@@ -529,7 +693,7 @@ fn compile_array_var_bool_element(
     let rhs = context.resolve_bool_variable(&exprs[2])?;
 
     Ok(constraints::element(index, array.iter().cloned(), rhs)
-        .post(context.solver, None)
+        .post(context.solver, context.constraint_tag)
         .is_ok())
 }
 
@@ -561,7 +725,9 @@ fn compile_ternary_int_predicate<C: Constraint>(
     let c = context.resolve_integer_variable(&exprs[2])?;
 
     let constraint = create_constraint(a, b, c);
-    Ok(constraint.post(context.solver, None).is_ok())
+    Ok(constraint
+        .post(context.solver, context.constraint_tag)
+        .is_ok())
 }
 
 fn compile_binary_int_predicate<C: Constraint>(
@@ -577,7 +743,9 @@ fn compile_binary_int_predicate<C: Constraint>(
     let b = context.resolve_integer_variable(&exprs[1])?;
 
     let constraint = create_constraint(a, b);
-    Ok(constraint.post(context.solver, None).is_ok())
+    Ok(constraint
+        .post(context.solver, context.constraint_tag)
+        .is_ok())
 }
 
 fn compile_reified_binary_int_predicate<C: NegatableConstraint>(
@@ -594,10 +762,12 @@ fn compile_reified_binary_int_predicate<C: NegatableConstraint>(
     let reif = context.resolve_bool_variable(&exprs[2])?;
 
     let constraint = create_constraint(a, b);
-    Ok(constraint.reify(context.solver, reif, None).is_ok())
+    Ok(constraint
+        .reify(context.solver, reif, context.constraint_tag)
+        .is_ok())
 }
 
-fn weighted_vars(weights: Rc<[i32]>, vars: Rc<[DomainId]>) -> Box<[AffineView<DomainId>]> {
+fn weighted_vars(weights: Rc<[i64]>, vars: Rc<[DomainId]>) -> Box<[AffineView<DomainId>]> {
     vars.iter()
         .zip(weights.iter())
         .map(|(x_i, &w_i)| x_i.scaled(w_i))
@@ -609,7 +779,7 @@ fn compile_int_lin_predicate<C: Constraint>(
     exprs: &[flatzinc::Expr],
     _: &[flatzinc::Annotation],
     predicate_name: &str,
-    create_constraint: impl FnOnce(Box<[AffineView<DomainId>]>, i32) -> C,
+    create_constraint: impl FnOnce(Box<[AffineView<DomainId>]>, i64) -> C,
 ) -> Result<bool, FlatZincError> {
     check_parameters!(exprs, 3, predicate_name);
 
@@ -620,7 +790,9 @@ fn compile_int_lin_predicate<C: Constraint>(
     let terms = weighted_vars(weights, vars);
 
     let constraint = create_constraint(terms, rhs);
-    Ok(constraint.post(context.solver, None).is_ok())
+    Ok(constraint
+        .post(context.solver, context.constraint_tag)
+        .is_ok())
 }
 
 fn compile_reified_int_lin_predicate<C: NegatableConstraint>(
@@ -628,7 +800,7 @@ fn compile_reified_int_lin_predicate<C: NegatableConstraint>(
     exprs: &[flatzinc::Expr],
     _: &[flatzinc::Annotation],
     predicate_name: &str,
-    create_constraint: impl FnOnce(Box<[AffineView<DomainId>]>, i32) -> C,
+    create_constraint: impl FnOnce(Box<[AffineView<DomainId>]>, i64) -> C,
 ) -> Result<bool, FlatZincError> {
     check_parameters!(exprs, 4, predicate_name);
 
@@ -640,7 +812,9 @@ fn compile_reified_int_lin_predicate<C: NegatableConstraint>(
     let terms = weighted_vars(weights, vars);
 
     let constraint = create_constraint(terms, rhs);
-    Ok(constraint.reify(context.solver, reif, None).is_ok())
+    Ok(constraint
+        .reify(context.solver, reif, context.constraint_tag)
+        .is_ok())
 }
 
 fn compile_bool_lin_eq_predicate(
@@ -655,7 +829,7 @@ fn compile_bool_lin_eq_predicate(
 
     Ok(
         constraints::boolean_equals(weights.as_ref().to_owned(), bools.as_ref().to_owned(), rhs)
-            .post(context.solver, None)
+            .post(context.solver, context.constraint_tag)
             .is_ok(),
     )
 }
@@ -675,7 +849,7 @@ fn compile_bool_lin_le_predicate(
         bools.as_ref().to_owned(),
         rhs,
     )
-    .post(context.solver, None)
+    .post(context.solver, context.constraint_tag)
     .is_ok())
 }
 
@@ -683,11 +857,14 @@ fn compile_all_different(
     context: &mut CompilationContext,
     exprs: &[flatzinc::Expr],
     _: &[flatzinc::Annotation],
+    options: &FlatZincOptions,
 ) -> Result<bool, FlatZincError> {
     check_parameters!(exprs, 1, "fzn_all_different");
 
     let variables = context.resolve_integer_variable_array(&exprs[0])?.to_vec();
-    Ok(constraints::all_different(variables)
-        .post(context.solver, None)
-        .is_ok())
+    Ok(
+        constraints::all_different_with_consistency(variables, options.all_different_consistency)
+            .post(context.solver, context.constraint_tag)
+            .is_ok(),
+    )
 }
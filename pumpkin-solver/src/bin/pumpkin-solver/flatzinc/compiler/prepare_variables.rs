@@ -48,14 +48,14 @@ pub(crate) fn run(
             } => {
                 let id = context.identifiers.get_interned(id);
 
-                let lb = i32::try_from(*lb)?;
-                let ub = i32::try_from(*ub)?;
+                let lb = i64::try_from(*lb)?;
+                let ub = i64::try_from(*ub)?;
 
                 let (lb, ub) = match expr {
                     None => (lb, ub),
 
                     Some(IntExpr::Int(value)) => {
-                        let value = i32::try_from(*value)?;
+                        let value = i64::try_from(*value)?;
                         (value, value)
                     }
 
@@ -77,8 +77,8 @@ pub(crate) fn run(
             SingleVarDecl::IntInSet { id, set, .. } => {
                 let id = context.identifiers.get_interned(id);
 
-                let lb = i32::try_from(set[0])?;
-                let ub = i32::try_from(set[set.len() - 1])?;
+                let lb = i64::try_from(set[0])?;
+                let ub = i64::try_from(set[set.len() - 1])?;
 
                 context
                     .integer_equivalences
@@ -245,6 +245,7 @@ mod tests {
                     crate::flatzinc::ast::VariableSelectionStrategy::AntiFirstFail,
                 value_selection_strategy: crate::flatzinc::ast::ValueSelectionStrategy::InDomain,
             }),
+            restart_options: None,
         }
     }
 }
@@ -29,6 +29,9 @@ impl From<FlatzincObjective> for (OptimisationDirection, DomainId) {
 pub(crate) struct FlatZincInstance {
     pub(super) outputs: Vec<Output>,
     pub(super) objective_function: Option<FlatzincObjective>,
+    /// The weighted terms of which the objective is the sum; these are only determined when
+    /// core-guided search is used.
+    pub(super) objective_terms: Vec<(i64, DomainId)>,
     pub(super) search: Option<DynamicBrancher>,
 }
 
@@ -57,7 +60,7 @@ impl Output {
 
     pub(crate) fn array_of_bool(
         id: Rc<str>,
-        shape: Box<[(i32, i32)]>,
+        shape: Box<[(i64, i64)]>,
         contents: Rc<[Literal]>,
     ) -> Output {
         Output::ArrayOfBool(ArrayOutput {
@@ -76,7 +79,7 @@ impl Output {
 
     pub(crate) fn array_of_int(
         id: Rc<str>,
-        shape: Box<[(i32, i32)]>,
+        shape: Box<[(i64, i64)]>,
         contents: Rc<[DomainId]>,
     ) -> Output {
         Output::ArrayOfInt(ArrayOutput {
@@ -85,6 +88,15 @@ impl Output {
             contents,
         })
     }
+
+    /// Returns the integer variables which are output.
+    pub(crate) fn integer_variables(&self) -> Vec<DomainId> {
+        match self {
+            Output::Int(output) => vec![output.variable],
+            Output::ArrayOfInt(output) => output.contents.to_vec(),
+            Output::Bool(_) | Output::ArrayOfBool(_) => vec![],
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -107,7 +119,7 @@ pub(crate) struct ArrayOutput<T> {
     /// denotes the index set used in dimension i.
     /// Example: [(1, 5), (2, 4)] describes a 2d array, where the first dimension in indexed with
     /// an element of 1..5, and the second dimension is indexed with an element from 2..4.
-    shape: Box<[(i32, i32)]>,
+    shape: Box<[(i64, i64)]>,
     contents: Rc<[T]>,
 }
 
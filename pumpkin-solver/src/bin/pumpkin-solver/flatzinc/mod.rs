@@ -3,6 +3,7 @@ mod compiler;
 pub(crate) mod error;
 mod instance;
 mod parser;
+pub(crate) mod portfolio;
 
 use std::fs::File;
 use std::io::Read;
@@ -14,12 +15,27 @@ use pumpkin_solver::branching::branchers::alternating_brancher::AlternatingStrat
 use pumpkin_solver::branching::branchers::dynamic_brancher::DynamicBrancher;
 use pumpkin_solver::branching::Brancher;
 #[cfg(doc)]
+use pumpkin_solver::constraints::all_different;
+#[cfg(doc)]
 use pumpkin_solver::constraints::cumulative;
+#[cfg(doc)]
+use pumpkin_solver::constraints::disjunctive;
+use pumpkin_solver::optimisation::bisection::Bisection;
+use pumpkin_solver::optimisation::core_guided::CoreGuided;
+use pumpkin_solver::optimisation::large_neighbourhood_search::LargeNeighbourhoodSearch;
+use pumpkin_solver::optimisation::large_neighbourhood_search::LargeNeighbourhoodSearchOptions;
 use pumpkin_solver::optimisation::linear_sat_unsat::LinearSatUnsat;
 use pumpkin_solver::optimisation::linear_unsat_sat::LinearUnsatSat;
+use pumpkin_solver::optimisation::neighbourhoods::CostImpactNeighbourhood;
+use pumpkin_solver::optimisation::neighbourhoods::Neighbourhood;
+use pumpkin_solver::optimisation::neighbourhoods::NeighbourhoodStrategy;
+use pumpkin_solver::optimisation::neighbourhoods::PropagationGuidedNeighbourhood;
+use pumpkin_solver::optimisation::neighbourhoods::RandomNeighbourhood;
 use pumpkin_solver::optimisation::OptimisationDirection;
 use pumpkin_solver::optimisation::OptimisationStrategy;
+use pumpkin_solver::options::AllDifferentConsistency;
 use pumpkin_solver::options::CumulativeOptions;
+use pumpkin_solver::options::DisjunctivePropagatorOptions;
 use pumpkin_solver::results::solution_iterator::IteratedSolution;
 use pumpkin_solver::results::OptimisationResult;
 use pumpkin_solver::results::ProblemSolution;
@@ -45,6 +61,10 @@ pub(crate) struct FlatZincOptions {
     /// If `true`, the solver will not strictly keep to the search annotations in the flatzinc.
     pub(crate) free_search: bool,
 
+    /// If `true`, the values in the search annotations are selected based on the most recent
+    /// solution when optimising; this is the default.
+    pub(crate) solution_guided_search: bool,
+
     /// For satisfaction problems, print all solutions. For optimisation problems, this instructs
     /// the solver to print intermediate solutions.
     pub(crate) all_solutions: bool,
@@ -52,8 +72,21 @@ pub(crate) struct FlatZincOptions {
     /// Options used for the cumulative constraint (see [`cumulative`]).
     pub(crate) cumulative_options: CumulativeOptions,
 
+    /// Options used for the disjunctive constraint (see [`disjunctive`]).
+    pub(crate) disjunctive_options: DisjunctivePropagatorOptions,
+
+    /// The consistency enforced by the propagator of the all-different constraint (see
+    /// [`all_different`]).
+    pub(crate) all_different_consistency: AllDifferentConsistency,
+
     /// Determines which type of search is performed by the solver
     pub(crate) optimisation_strategy: OptimisationStrategy,
+
+    /// Determines which neighbourhood is used by large neighbourhood search.
+    pub(crate) neighbourhood_strategy: NeighbourhoodStrategy,
+
+    /// Options used by large neighbourhood search (see [`LargeNeighbourhoodSearch`]).
+    pub(crate) large_neighbourhood_search_options: LargeNeighbourhoodSearchOptions,
 }
 
 fn solution_callback(
@@ -67,7 +100,7 @@ fn solution_callback(
     if options_all_solutions || instance_objective_function.is_none() {
         brancher.log_statistics(StatisticLogger::default());
         if let Some(objective) = instance_objective_function {
-            solver.log_statistics_with_objective(solution.get_integer_value(objective) as i64);
+            solver.log_statistics_with_objective(solution.get_integer_value(objective));
         } else {
             solver.log_statistics()
         }
@@ -91,16 +124,11 @@ pub(crate) fn solve(
     let instance = parse_and_compile(&mut solver, instance, options)?;
     let outputs = instance.outputs.clone();
 
-    let mut brancher = if options.free_search {
-        // The free search flag is active, we just use the default brancher
-        DynamicBrancher::new(vec![Box::new(AlternatingBrancher::new(
-            &solver,
-            instance.search.expect("Expected a search to be defined"),
-            AlternatingStrategy::SwitchToDefaultAfterFirstSolution,
-        ))])
-    } else {
-        instance.search.expect("Expected a search to be defined")
-    };
+    let mut brancher = create_brancher(
+        &solver,
+        instance.search.expect("Expected a search to be defined"),
+        options.free_search,
+    );
 
     let (direction, objective): (OptimisationDirection, DomainId) =
         match instance.objective_function {
@@ -123,18 +151,17 @@ pub(crate) fn solve(
             );
         };
 
-    let result = match options.optimisation_strategy {
-        OptimisationStrategy::LinearSatUnsat => solver.optimise(
-            &mut brancher,
-            &mut termination,
-            LinearSatUnsat::new(direction, objective, callback),
-        ),
-        OptimisationStrategy::LinearUnsatSat => solver.optimise(
-            &mut brancher,
-            &mut termination,
-            LinearUnsatSat::new(direction, objective, callback),
-        ),
-    };
+    let result = optimise(
+        &mut solver,
+        &mut brancher,
+        &mut termination,
+        direction,
+        objective,
+        &instance.outputs,
+        &instance.objective_terms,
+        options,
+        callback,
+    );
 
     match result {
         OptimisationResult::Optimal(optimal_solution) => {
@@ -163,6 +190,95 @@ pub(crate) fn solve(
     Ok(())
 }
 
+/// Creates the brancher which follows the provided search; if free search is allowed, then the
+/// search is only followed until the first solution is found, after which the default brancher is
+/// used.
+fn create_brancher(solver: &Solver, search: DynamicBrancher, free_search: bool) -> DynamicBrancher {
+    if free_search {
+        DynamicBrancher::new(vec![Box::new(AlternatingBrancher::new(
+            solver,
+            search,
+            AlternatingStrategy::SwitchToDefaultAfterFirstSolution,
+        ))])
+    } else {
+        search
+    }
+}
+
+/// Optimises the provided objective using the optimisation strategy specified in the options.
+#[allow(
+    clippy::too_many_arguments,
+    reason = "the optimisation strategies require different parts of the instance"
+)]
+fn optimise(
+    solver: &mut Solver,
+    brancher: &mut DynamicBrancher,
+    termination: &mut impl TerminationCondition,
+    direction: OptimisationDirection,
+    objective: DomainId,
+    outputs: &[Output],
+    objective_terms: &[(i64, DomainId)],
+    options: FlatZincOptions,
+    callback: impl Fn(&Solver, SolutionReference<'_>, &DynamicBrancher),
+) -> OptimisationResult {
+    match options.optimisation_strategy {
+        OptimisationStrategy::LinearSatUnsat => solver.optimise(
+            brancher,
+            termination,
+            LinearSatUnsat::new(direction, objective, callback),
+        ),
+        OptimisationStrategy::LinearUnsatSat => solver.optimise(
+            brancher,
+            termination,
+            LinearUnsatSat::new(direction, objective, callback),
+        ),
+        OptimisationStrategy::CoreGuided => solver.optimise(
+            brancher,
+            termination,
+            CoreGuided::new(
+                direction,
+                objective,
+                objective_terms.iter().copied(),
+                callback,
+            ),
+        ),
+        OptimisationStrategy::LargeNeighbourhoodSearch => {
+            let neighbourhood: Box<dyn Neighbourhood> = match options.neighbourhood_strategy {
+                NeighbourhoodStrategy::Random => Box::new(RandomNeighbourhood),
+                NeighbourhoodStrategy::PropagationGuided => {
+                    Box::new(PropagationGuidedNeighbourhood)
+                }
+                NeighbourhoodStrategy::CostImpact => {
+                    Box::new(CostImpactNeighbourhood::new(direction, objective))
+                }
+            };
+            // The neighbourhoods are defined over the integer variables which are output
+            let variables = outputs
+                .iter()
+                .flat_map(Output::integer_variables)
+                .collect::<Vec<_>>();
+
+            solver.optimise(
+                brancher,
+                termination,
+                LargeNeighbourhoodSearch::with_options(
+                    direction,
+                    objective,
+                    variables,
+                    neighbourhood,
+                    callback,
+                    options.large_neighbourhood_search_options,
+                ),
+            )
+        }
+        OptimisationStrategy::Bisection => solver.optimise(
+            brancher,
+            termination,
+            Bisection::new(direction, objective, callback),
+        ),
+    }
+}
+
 fn satisfy(
     options: FlatZincOptions,
     solver: &mut Solver,
@@ -0,0 +1,297 @@
+//! Solves a FlatZinc instance using a portfolio of diversified solvers which run in parallel.
+//!
+//! Every worker thread constructs its own solver from the instance; the solvers differ in their
+//! random seed, restart sequence and brancher. They exchange short learned nogoods with a low LBD
+//! through a [`NogoodExchange`], and all workers are stopped as soon as one of them finds a
+//! definitive answer.
+//!
+//! The best solution found by any of the workers is shared; when a worker proves optimality (or
+//! unsatisfiability), then this proof holds for the best solution found by all of the workers,
+//! since the nogoods which it has imported are only implied when looking for better solutions.
+
+use std::fs::File;
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::time::Duration;
+
+use pumpkin_solver::branching::branchers::dynamic_brancher::DynamicBrancher;
+use pumpkin_solver::branching::Brancher;
+use pumpkin_solver::optimisation::OptimisationDirection;
+use pumpkin_solver::options::NogoodExchange;
+use pumpkin_solver::options::SequenceGeneratorType;
+use pumpkin_solver::options::SolverOptions;
+use pumpkin_solver::results::OptimisationResult;
+use pumpkin_solver::results::ProblemSolution;
+use pumpkin_solver::results::SatisfactionResult;
+use pumpkin_solver::results::Solution;
+use pumpkin_solver::results::SolutionReference;
+use pumpkin_solver::statistics::StatisticLogger;
+use pumpkin_solver::termination::Combinator;
+use pumpkin_solver::termination::OsSignal;
+use pumpkin_solver::termination::StopSignal;
+use pumpkin_solver::termination::TerminationCondition;
+use pumpkin_solver::termination::TimeBudget;
+use pumpkin_solver::variables::DomainId;
+use pumpkin_solver::Solver;
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+
+use super::create_brancher;
+use super::optimise;
+use super::parse_and_compile;
+use super::print_solution_from_solver;
+use super::satisfy;
+use super::FlatZincOptions;
+use super::MSG_UNKNOWN;
+use super::MSG_UNSATISFIABLE;
+use crate::flatzinc::error::FlatZincError;
+
+/// The coefficient of the geometric restart sequence used by the workers which are diversified
+/// to use geometric restarts.
+const GEOMETRIC_RESTART_COEFFICIENT: f64 = 1.5;
+
+/// The state which is shared between the workers of the portfolio.
+#[derive(Debug, Default)]
+struct Portfolio {
+    exchange: NogoodExchange,
+    stop_signal: StopSignal,
+    output: Mutex<PortfolioOutput>,
+}
+
+#[derive(Debug, Default)]
+struct PortfolioOutput {
+    /// The best solution which has been found by any of the workers, together with its objective
+    /// value (in case of an optimisation problem).
+    best_solution: Option<(i64, Solution)>,
+    /// Whether a definitive answer has been reported by one of the workers.
+    is_concluded: bool,
+}
+
+impl Portfolio {
+    fn output(&self) -> MutexGuard<'_, PortfolioOutput> {
+        self.output
+            .lock()
+            .expect("a worker panicked while reporting")
+    }
+
+    /// Reports a definitive answer using `report`, unless another worker has already done so, and
+    /// stops all of the workers.
+    fn conclude(&self, report: impl FnOnce(&PortfolioOutput)) {
+        let mut output = self.output();
+        if !output.is_concluded {
+            output.is_concluded = true;
+            report(&output);
+        }
+        self.stop_signal.stop();
+    }
+}
+
+/// Solves the instance using `num_workers` solvers in parallel; the options of every solver are
+/// created using `create_solver_options`, after which they are diversified per worker.
+pub(crate) fn solve(
+    num_workers: usize,
+    create_solver_options: impl Fn() -> SolverOptions + Sync,
+    random_seed: u64,
+    instance: impl AsRef<Path>,
+    time_limit: Option<Duration>,
+    options: FlatZincOptions,
+) -> Result<(), FlatZincError> {
+    let instance = instance.as_ref();
+    let portfolio = Portfolio::default();
+
+    // The termination conditions are created once, such that every worker shares the same time
+    // budget and listens to the same signal
+    let os_signal = OsSignal::install();
+    let time_budget = time_limit.map(TimeBudget::starting_now);
+
+    let results = std::thread::scope(|scope| {
+        let workers = (0..num_workers)
+            .map(|worker| {
+                let portfolio = &portfolio;
+                let create_solver_options = &create_solver_options;
+                let termination = Combinator::new(
+                    Combinator::new(os_signal.clone(), portfolio.stop_signal.clone()),
+                    time_budget,
+                );
+
+                scope.spawn(move || {
+                    let solver_options =
+                        diversify_options(worker, random_seed, create_solver_options());
+                    run_worker(
+                        worker,
+                        portfolio,
+                        solver_options,
+                        instance,
+                        termination,
+                        options,
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+
+        workers
+            .into_iter()
+            .map(|worker| worker.join().expect("a worker panicked"))
+            .collect::<Vec<_>>()
+    });
+
+    // If a worker failed to construct its solver, then all of them have failed
+    results.into_iter().collect::<Result<(), _>>()?;
+
+    let output = portfolio.output();
+    if !output.is_concluded && output.best_solution.is_none() {
+        println!("{MSG_UNKNOWN}");
+    }
+
+    Ok(())
+}
+
+/// Diversifies the options of the provided worker; every worker uses a different random seed,
+/// and the restart sequence alternates between the configured sequence, the Luby sequence and a
+/// geometric sequence.
+fn diversify_options(
+    worker: usize,
+    random_seed: u64,
+    mut solver_options: SolverOptions,
+) -> SolverOptions {
+    solver_options.random_generator =
+        SmallRng::seed_from_u64(random_seed.wrapping_add(worker as u64));
+
+    let restart_options = &mut solver_options.restart_options;
+    match worker % 3 {
+        1 => restart_options.sequence_generator_type = SequenceGeneratorType::Luby,
+        2 => {
+            restart_options.sequence_generator_type = SequenceGeneratorType::Geometric;
+            restart_options.geometric_coef = Some(GEOMETRIC_RESTART_COEFFICIENT);
+        }
+        _ => {}
+    }
+
+    solver_options
+}
+
+/// Creates the brancher of the provided worker; the first worker uses the brancher which is used
+/// when solving with a single thread, the other workers alternate between free search and the
+/// default brancher.
+fn diversify_brancher(
+    worker: usize,
+    solver: &Solver,
+    search: DynamicBrancher,
+    options: FlatZincOptions,
+) -> DynamicBrancher {
+    if worker == 0 {
+        create_brancher(solver, search, options.free_search)
+    } else if worker % 2 == 1 {
+        create_brancher(solver, search, true)
+    } else {
+        DynamicBrancher::new(vec![Box::new(solver.default_brancher())])
+    }
+}
+
+fn run_worker(
+    worker: usize,
+    portfolio: &Portfolio,
+    solver_options: SolverOptions,
+    instance: &Path,
+    mut termination: impl TerminationCondition,
+    options: FlatZincOptions,
+) -> Result<(), FlatZincError> {
+    let mut solver = Solver::with_options(solver_options);
+    let instance = parse_and_compile(&mut solver, File::open(instance)?, options)?;
+    solver.share_nogoods(&portfolio.exchange);
+
+    let mut brancher = diversify_brancher(
+        worker,
+        &solver,
+        instance.search.expect("Expected a search to be defined"),
+        options,
+    );
+    let outputs = instance.outputs;
+
+    let Some(objective_function) = instance.objective_function else {
+        if options.all_solutions {
+            // All solutions are enumerated by a single worker, since the workers would otherwise
+            // report the same solutions
+            if worker == 0 {
+                satisfy(options, &mut solver, brancher, termination, outputs);
+                portfolio.conclude(|_| {});
+            }
+            return Ok(());
+        }
+
+        match solver.satisfy(&mut brancher, &mut termination) {
+            SatisfactionResult::Satisfiable(solution) => portfolio.conclude(|_| {
+                brancher.log_statistics(StatisticLogger::default());
+                solver.log_statistics();
+                print_solution_from_solver(solution.as_reference(), &outputs);
+            }),
+            SatisfactionResult::Unsatisfiable => portfolio.conclude(|_| {
+                println!("{MSG_UNSATISFIABLE}");
+                solver.log_statistics();
+            }),
+            SatisfactionResult::Unknown => {}
+        }
+        return Ok(());
+    };
+    let (direction, objective): (OptimisationDirection, DomainId) = objective_function.into();
+
+    let callback =
+        |solver: &Solver, solution: SolutionReference<'_>, brancher: &DynamicBrancher| {
+            let objective_value = solution.get_integer_value(objective);
+            let mut output = portfolio.output();
+
+            let is_improving = match output.best_solution {
+                Some((best_objective_value, _)) => match direction {
+                    OptimisationDirection::Minimise => objective_value < best_objective_value,
+                    OptimisationDirection::Maximise => objective_value > best_objective_value,
+                },
+                None => true,
+            };
+            if !is_improving || output.is_concluded {
+                return;
+            }
+
+            output.best_solution = Some((objective_value, solution.into()));
+            if options.all_solutions {
+                brancher.log_statistics(StatisticLogger::default());
+                solver.log_statistics_with_objective(objective_value);
+                print_solution_from_solver(solution, &outputs);
+            }
+        };
+
+    let result = optimise(
+        &mut solver,
+        &mut brancher,
+        &mut termination,
+        direction,
+        objective,
+        &outputs,
+        &instance.objective_terms,
+        options,
+        callback,
+    );
+
+    match result {
+        OptimisationResult::Optimal(_) | OptimisationResult::Unsatisfiable => {
+            // The imported nogoods only hold when looking for solutions which are better than
+            // the best solution found by any of the workers, so that solution is optimal
+            portfolio.conclude(|output| {
+                match &output.best_solution {
+                    Some((_, best_solution)) => {
+                        if !options.all_solutions {
+                            brancher.log_statistics(StatisticLogger::default());
+                            print_solution_from_solver(best_solution.as_reference(), &outputs);
+                        }
+                        println!("==========");
+                    }
+                    None => println!("{MSG_UNSATISFIABLE}"),
+                }
+                solver.log_statistics();
+            })
+        }
+        OptimisationResult::Satisfiable(_) | OptimisationResult::Unknown => {}
+    }
+
+    Ok(())
+}
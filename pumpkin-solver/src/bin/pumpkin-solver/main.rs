@@ -8,6 +8,7 @@ use std::fmt::Debug;
 use std::fmt::Display;
 use std::fs::File;
 use std::io::Write;
+use std::num::NonZero;
 use std::path::Path;
 use std::path::PathBuf;
 use std::time::Duration;
@@ -26,6 +27,8 @@ use maxsat::PseudoBooleanEncoding;
 use parsers::dimacs::parse_cnf;
 use parsers::dimacs::SolverArgs;
 use parsers::dimacs::SolverDimacsSink;
+use pumpkin_solver::optimisation::large_neighbourhood_search::LargeNeighbourhoodSearchOptions;
+use pumpkin_solver::optimisation::neighbourhoods::NeighbourhoodStrategy;
 use pumpkin_solver::optimisation::OptimisationStrategy;
 use pumpkin_solver::options::*;
 use pumpkin_solver::proof::Format;
@@ -73,17 +76,26 @@ struct Args {
 
     /// The output path for the proof file.
     ///
-    /// When solving a DIMACS instance, a DRAT proof is logged. In case of a FlatZinc model, a DRCP
-    /// proof is logged.
+    /// When solving a DIMACS instance, a DRAT proof is logged, or an LRAT proof if the proof type
+    /// is `with-hints`. In case of a FlatZinc model, a DRCP proof is logged.
     #[arg(long, verbatim_doc_comment)]
     proof_path: Option<PathBuf>,
 
     /// What type of proof to log.
     ///
+    /// For DIMACS instances, `with-hints` logs an LRAT proof and the other types log a DRAT proof.
     /// If the `proof_path` option is not provided, this is ignored.
     #[arg(long, value_enum, default_value_t)]
     proof_type: ProofType,
 
+    /// The format in which the proof is logged.
+    ///
+    /// The binary format is more compact than the textual format. For DIMACS instances, this is
+    /// the binary DRAT or LRAT format. If the `proof_path` option is not provided, this is
+    /// ignored.
+    #[arg(long, value_enum, default_value_t)]
+    proof_format: ProofFormat,
+
     /// The number of high lbd learned clauses that are kept in the database.
     /// Learned clauses are kept based on the tiered system introduced in "Improving
     /// SAT Solvers by Exploiting Empirical Characteristics of CDCL - Chanseok Oh (2016)".
@@ -129,6 +141,7 @@ struct Args {
     /// - The "constant" approach uses a constant number of conflicts before another restart is
     ///   triggered
     /// - The "geometric" approach uses a geometrically increasing sequence
+    /// - The "linear" approach uses a linearly increasing sequence
     /// - The "luby" approach uses a recursive sequence of the form 1, 1, 2, 1, 1, 2, 4, 1, 1, 2,
     ///   1, 1, 2, 4, 8, 1, 1, 2.... (see "Optimal speedup of Las Vegas algorithms - Luby et al.
     ///   (1993)")
@@ -268,6 +281,15 @@ struct Args {
     #[arg(short = 'f', long = "free-search", verbatim_doc_comment)]
     free_search: bool,
 
+    /// When optimising, the solver assigns the variables in the search annotations of a MiniZinc
+    /// model the value they have in the most recent solution, rather than the value given by the
+    /// annotation; until a solution is found, the annotation is followed. This option instructs
+    /// the solver to always follow the value selection of the annotations instead.
+    ///
+    /// Possible values: bool
+    #[arg(long = "no-solution-guided-search", verbatim_doc_comment)]
+    no_solution_guided_search: bool,
+
     /// Instructs the solver to report all solutions in the case of satisfaction problems,
     /// or print intermediate solutions of increasing quality in the case of optimisation
     /// problems.
@@ -279,6 +301,26 @@ struct Args {
     #[arg(short = 'a', long = "all-solutions", verbatim_doc_comment)]
     all_solutions: bool,
 
+    /// The number of threads which are used to solve a MiniZinc model.
+    ///
+    /// When more than one thread is used, a portfolio of diversified solvers is run in parallel;
+    /// the solvers differ in their random seed, restart sequence and brancher, and they share
+    /// short learned clauses with a low LBD. Only the first solver adheres to the search
+    /// annotations specified in the model. Note that parallel solving cannot be combined with
+    /// proof logging.
+    ///
+    /// See the MiniZinc specification (<https://docs.minizinc.dev/en/stable/fzn-spec.html#cmdoption-p>)
+    /// for more information.
+    ///
+    /// Possible values: usize
+    #[arg(
+        short = 'p',
+        long = "threads",
+        default_value_t = NonZero::<usize>::MIN,
+        verbatim_doc_comment
+    )]
+    threads: NonZero<usize>,
+
     /// If `--verbose` is enabled then this option removes the timestamp information from the log
     /// messages. Note that this option will only take affect in the case of a (W)CNF instance.
     ///
@@ -315,6 +357,17 @@ struct Args {
     #[arg(long, value_enum, default_value_t)]
     conflict_resolver: ConflictResolver,
 
+    /// Determines the heuristic used by the default brancher.
+    ///
+    /// - The "vsids" value branches on the predicates which have appeared in recent conflicts
+    ///   (VSIDS).
+    /// - The "chb" value branches on the integer variables with the highest Conflict History-based
+    ///   Branching (CHB) score.
+    /// - The "lrb" value branches on the integer variables with the highest Learning Rate-based
+    ///   Branching (LRB) score.
+    #[arg(long, value_enum, default_value_t)]
+    branching_heuristic: BranchingHeuristic,
+
     /// Determines that the cumulative propagator(s) are allowed to create holes in the domain.
     ///
     /// Possible values: bool
@@ -347,9 +400,73 @@ struct Args {
     #[arg(long = "cumulative-incremental-backtracking")]
     cumulative_incremental_backtracking: bool,
 
+    /// Determines the type of explanation used by the disjunctive propagator(s) to explain
+    /// propagations/conflicts.
+    #[arg(long, value_enum, default_value_t)]
+    disjunctive_explanation_type: DisjunctiveExplanationType,
+
+    /// Disables the overload checking rule of the disjunctive.
+    ///
+    /// Possible values: bool
+    #[arg(long = "disjunctive-no-overload-checking")]
+    disjunctive_no_overload_checking: bool,
+
+    /// Disables the detectable precedences rule of the disjunctive.
+    ///
+    /// Possible values: bool
+    #[arg(long = "disjunctive-no-detectable-precedences")]
+    disjunctive_no_detectable_precedences: bool,
+
+    /// Disables the edge-finding rule of the disjunctive.
+    ///
+    /// Possible values: bool
+    #[arg(long = "disjunctive-no-edge-finding")]
+    disjunctive_no_edge_finding: bool,
+
+    /// Disables the not-first/not-last rules of the disjunctive.
+    ///
+    /// Note that at least one of detectable precedences, edge-finding or not-first/not-last
+    /// should be enabled.
+    ///
+    /// Possible values: bool
+    #[arg(long = "disjunctive-no-not-first-not-last")]
+    disjunctive_no_not_first_not_last: bool,
+
+    /// Determines the level of consistency enforced by the all-different propagator(s).
+    ///
+    /// The "bounds" value enforces bounds consistency by detecting Hall intervals (and removes the
+    /// values of fixed variables), while the "domain" value enforces domain consistency using the
+    /// matching-based algorithm of Régin.
+    #[arg(long, value_enum, default_value_t, verbatim_doc_comment)]
+    all_different_consistency: AllDifferentConsistency,
+
     /// Determine what type of optimisation strategy is used by the solver
     #[arg(long = "optimisation-strategy", default_value_t)]
     optimisation_strategy: OptimisationStrategy,
+
+    /// Determines which neighbourhood is used when the optimisation strategy is
+    /// "large-neighbourhood-search".
+    ///
+    /// The "random" value relaxes a random subset of the variables, the "propagation-guided" value
+    /// fixes the variables based on the effect of their propagation, and the "cost-impact" value
+    /// prefers to relax the variables which have a large impact on the objective.
+    #[arg(
+        long = "lns-neighbourhood",
+        value_enum,
+        default_value_t,
+        verbatim_doc_comment
+    )]
+    lns_neighbourhood: NeighbourhoodStrategy,
+
+    /// The maximum number of conflicts which can be encountered while searching a single
+    /// neighbourhood when the optimisation strategy is "large-neighbourhood-search".
+    ///
+    /// Possible values: u64
+    #[arg(
+        long = "lns-conflict-limit",
+        default_value_t = LargeNeighbourhoodSearchOptions::default().conflict_limit
+    )]
+    lns_conflict_limit: u64,
 }
 
 fn configure_logging(
@@ -480,17 +597,32 @@ fn run() -> PumpkinResult<()> {
         warn!("Potential performance degradation: the Pumpkin assert level is set to {}, meaning many debug asserts are active which may result in performance degradation.", pumpkin_solver::asserts::PUMPKIN_ASSERT_LEVEL_DEFINITION);
     };
 
+    let num_threads = args.threads.get();
+    if num_threads > 1 && file_format != FileFormat::FlatZinc {
+        warn!("Parallel solving is only supported for FlatZinc instances, a single thread is used");
+    }
+    if num_threads > 1 && file_format == FileFormat::FlatZinc && args.proof_path.is_some() {
+        return Err(PumpkinError::ProofGenerationNotSupported(
+            "parallel solving".to_owned(),
+        ));
+    }
+
     let proof_log = if let Some(path_buf) = args.proof_path {
+        let log_hints = matches!(args.proof_type, ProofType::WithHints);
+        let format = match args.proof_format {
+            ProofFormat::Text => Format::Text,
+            ProofFormat::Binary => Format::Binary,
+        };
+
         match file_format {
-            FileFormat::CnfDimacsPLine => ProofLog::dimacs(&path_buf)?,
+            FileFormat::CnfDimacsPLine => ProofLog::dimacs(&path_buf, format, log_hints)?,
             FileFormat::WcnfDimacsPLine => {
                 return Err(PumpkinError::ProofGenerationNotSupported("wcnf".to_owned()))
             }
             FileFormat::FlatZinc => {
                 let log_inferences =
                     matches!(args.proof_type, ProofType::Full | ProofType::WithHints);
-                let log_hints = matches!(args.proof_type, ProofType::WithHints);
-                ProofLog::cp(&path_buf, Format::Text, log_inferences, log_hints)?
+                ProofLog::cp(&path_buf, format, log_inferences, log_hints)?
             }
         }
     } else {
@@ -516,13 +648,14 @@ fn run() -> PumpkinResult<()> {
         activity_bump_increment: 1.0,
     };
 
-    let solver_options = SolverOptions {
+    let create_solver_options = |proof_log| SolverOptions {
         restart_options,
         learning_clause_minimisation: !args.no_learning_clause_minimisation,
         random_generator: SmallRng::seed_from_u64(args.random_seed),
         proof_log,
         conflict_resolver: args.conflict_resolver,
         learning_options,
+        branching_heuristic: args.branching_heuristic,
     };
 
     let time_limit = args.time_limit.map(Duration::from_millis);
@@ -532,19 +665,19 @@ fn run() -> PumpkinResult<()> {
         .ok_or(PumpkinError::invalid_instance(args.instance_path.display()))?;
 
     match file_format {
-        FileFormat::CnfDimacsPLine => cnf_problem(solver_options, time_limit, instance_path)?,
+        FileFormat::CnfDimacsPLine => {
+            cnf_problem(create_solver_options(proof_log), time_limit, instance_path)?
+        }
         FileFormat::WcnfDimacsPLine => wcnf_problem(
-            solver_options,
+            create_solver_options(proof_log),
             time_limit,
             instance_path,
             args.upper_bound_encoding,
         )?,
-        FileFormat::FlatZinc => flatzinc::solve(
-            Solver::with_options(solver_options),
-            instance_path,
-            time_limit,
-            FlatZincOptions {
+        FileFormat::FlatZinc => {
+            let options = FlatZincOptions {
                 free_search: args.free_search,
+                solution_guided_search: !args.no_solution_guided_search,
                 all_solutions: args.all_solutions,
                 cumulative_options: CumulativeOptions::new(
                     args.cumulative_allow_holes,
@@ -553,9 +686,40 @@ fn run() -> PumpkinResult<()> {
                     args.cumulative_propagation_method,
                     args.cumulative_incremental_backtracking,
                 ),
+                disjunctive_options: DisjunctivePropagatorOptions::new(
+                    args.disjunctive_explanation_type,
+                )
+                .with_overload_checking(!args.disjunctive_no_overload_checking)
+                .with_detectable_precedences(!args.disjunctive_no_detectable_precedences)?
+                .with_edge_finding(!args.disjunctive_no_edge_finding)?
+                .with_not_first_not_last(!args.disjunctive_no_not_first_not_last)?,
+                all_different_consistency: args.all_different_consistency,
                 optimisation_strategy: args.optimisation_strategy,
-            },
-        )?,
+                neighbourhood_strategy: args.lns_neighbourhood,
+                large_neighbourhood_search_options: LargeNeighbourhoodSearchOptions {
+                    conflict_limit: args.lns_conflict_limit,
+                    ..Default::default()
+                },
+            };
+
+            if num_threads > 1 {
+                flatzinc::portfolio::solve(
+                    num_threads,
+                    || create_solver_options(ProofLog::default()),
+                    args.random_seed,
+                    instance_path,
+                    time_limit,
+                    options,
+                )?
+            } else {
+                flatzinc::solve(
+                    Solver::with_options(create_solver_options(proof_log)),
+                    instance_path,
+                    time_limit,
+                    options,
+                )?
+            }
+        }
     }
 
     Ok(())
@@ -642,3 +806,21 @@ impl Display for ProofType {
         }
     }
 }
+
+#[derive(Default, Clone, Copy, Debug, ValueEnum)]
+enum ProofFormat {
+    /// Log the proof in the textual format.
+    #[default]
+    Text,
+    /// Log the proof in the binary format.
+    Binary,
+}
+
+impl Display for ProofFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProofFormat::Text => write!(f, "text"),
+            ProofFormat::Binary => write!(f, "binary"),
+        }
+    }
+}
@@ -30,8 +30,8 @@ pub(crate) trait DimacsSink {
     /// The arguments to the dimacs sink.
     type ConstructorArgs;
 
-    /// Create an empty formula.
-    fn empty(args: Self::ConstructorArgs, num_variables: usize) -> Self;
+    /// Create an empty formula, which will contain `num_clauses` clauses once it is read.
+    fn empty(args: Self::ConstructorArgs, num_variables: usize, num_clauses: usize) -> Self;
 
     /// Add a new hard clause to the formula. Consistency does not have to be checked at every
     /// insertion. As such, after the formula is constructed from the file format, consistency
@@ -297,6 +297,7 @@ where
                 .take()
                 .ok_or(DimacsParseError::DuplicateHeader)?,
             header.num_variables(),
+            header.num_clauses(),
         ));
 
         self.header = Some(header);
@@ -467,8 +468,11 @@ impl SolverDimacsSink {
 impl DimacsSink for SolverDimacsSink {
     type ConstructorArgs = SolverArgs;
 
-    fn empty(args: Self::ConstructorArgs, num_variables: usize) -> Self {
-        let SolverArgs { solver_options } = args;
+    fn empty(args: Self::ConstructorArgs, num_variables: usize, num_clauses: usize) -> Self {
+        let SolverArgs { mut solver_options } = args;
+        solver_options
+            .proof_log
+            .set_num_input_clauses(num_clauses as u64);
 
         let mut solver = Solver::with_options(solver_options);
         let variables = (0..num_variables)
@@ -664,7 +668,7 @@ mod tests {
     impl DimacsSink for Vec<Vec<i32>> {
         type ConstructorArgs = ();
 
-        fn empty(_: Self::ConstructorArgs, _: usize) -> Self {
+        fn empty(_: Self::ConstructorArgs, _: usize, _: usize) -> Self {
             vec![]
         }
 
@@ -680,7 +684,7 @@ mod tests {
     impl DimacsSink for (Vec<(u32, i32)>, Vec<Vec<i32>>) {
         type ConstructorArgs = ();
 
-        fn empty(_: Self::ConstructorArgs, _: usize) -> Self {
+        fn empty(_: Self::ConstructorArgs, _: usize, _: usize) -> Self {
             (vec![], vec![])
         }
 
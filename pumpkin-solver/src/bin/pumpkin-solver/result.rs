@@ -1,5 +1,6 @@
 use std::fmt::Display;
 
+use pumpkin_solver::options::DisjunctiveOptionsError;
 use thiserror::Error;
 
 use crate::flatzinc::error::FlatZincError;
@@ -20,6 +21,8 @@ pub(crate) enum PumpkinError {
     FlatZinc(#[from] FlatZincError),
     #[error("Proof generation for {0} is not supported.")]
     ProofGenerationNotSupported(String),
+    #[error("The disjunctive options were invalid, more details: {0}")]
+    InvalidDisjunctiveOptions(#[from] DisjunctiveOptionsError),
 }
 
 impl PumpkinError {
@@ -79,7 +79,7 @@ pub trait Brancher {
     ///
     /// To receive information about this event, use [`BrancherEvent::UnassignInteger`] in
     /// [`Self::subscribe_to_events`]
-    fn on_unassign_integer(&mut self, _variable: DomainId, _value: i32) {}
+    fn on_unassign_integer(&mut self, _variable: DomainId, _value: i64) {}
 
     /// A function which is called when a [`Predicate`] appears in a conflict during conflict
     /// analysis.
@@ -88,6 +88,14 @@ pub trait Brancher {
     /// [`BrancherEvent::AppearanceInConflictPredicate`] in [`Self::subscribe_to_events`]
     fn on_appearance_in_conflict_predicate(&mut self, _predicate: Predicate) {}
 
+    /// A function which is called when a propagator reports a conflict, where `scope` contains
+    /// the [`DomainId`]s which the failing propagator is registered to. The scope is sorted and
+    /// does not contain duplicates.
+    ///
+    /// To receive information about this event, use [`BrancherEvent::PropagatorFailure`] in
+    /// [`Self::subscribe_to_events`]
+    fn on_propagator_failure(&mut self, _scope: &[DomainId]) {}
+
     /// This method is called whenever a restart is performed.
     /// To receive information about this event, use [`BrancherEvent::Restart`] in
     /// [`Self::subscribe_to_events`]
@@ -136,6 +144,8 @@ pub enum BrancherEvent {
     UnassignInteger,
     /// Event for when a predicate appears during conflict analysis
     AppearanceInConflictPredicate,
+    /// Event for when a propagator reports a conflict
+    PropagatorFailure,
     /// Event for when a restart occurs
     Restart,
     /// Event which is called with the new state after a backtrack has occurred
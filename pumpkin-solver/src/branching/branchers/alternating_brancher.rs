@@ -126,6 +126,13 @@ impl<OtherBrancher: Brancher> Brancher for AlternatingBrancher<OtherBrancher> {
         }
     }
 
+    fn on_propagator_failure(&mut self, scope: &[DomainId]) {
+        self.default_brancher.on_propagator_failure(scope);
+        if !self.will_always_use_default() {
+            self.other_brancher.on_propagator_failure(scope)
+        }
+    }
+
     fn on_conflict(&mut self) {
         self.default_brancher.on_conflict();
         if !self.will_always_use_default() {
@@ -163,7 +170,7 @@ impl<OtherBrancher: Brancher> Brancher for AlternatingBrancher<OtherBrancher> {
         }
     }
 
-    fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i64) {
         self.default_brancher.on_unassign_integer(variable, value);
         if !self.will_always_use_default() {
             self.other_brancher.on_unassign_integer(variable, value)
@@ -1,3 +1,4 @@
+use super::default_brancher::DefaultBackupBrancher;
 use super::independent_variable_value_brancher::IndependentVariableValueBrancher;
 use crate::basic_types::moving_averages::CumulativeMovingAverage;
 use crate::basic_types::moving_averages::MovingAverage;
@@ -18,7 +19,9 @@ use crate::results::Solution;
 use crate::statistics::Statistic;
 use crate::statistics::StatisticLogger;
 use crate::variables::DomainId;
+#[cfg(doc)]
 use crate::DefaultBrancher;
+
 /// A [`Brancher`] that combines [VSIDS \[1\]](https://dl.acm.org/doi/pdf/10.1145/378239.379017)
 /// and [Solution-based phase saving \[2\]](https://people.eng.unimelb.edu.au/pstuckey/papers/lns-restarts.pdf).
 ///
@@ -105,14 +108,14 @@ const DEFAULT_VSIDS_MAX_THRESHOLD: f64 = 1e100;
 const DEFAULT_VSIDS_DECAY_FACTOR: f64 = 0.95;
 const DEFAULT_VSIDS_VALUE: f64 = 0.0;
 
-impl DefaultBrancher {
+impl AutonomousSearch<DefaultBackupBrancher> {
     /// Creates a new instance with default values for
     /// the parameters (`1.0` for the increment, `1e100` for the max threshold,
     /// `0.95` for the decay factor and `0.0` for the initial VSIDS value).
     ///
     /// If there are no more predicates left to select, this [`Brancher`] switches to
     /// [`RandomSelector`] with [`RandomSplitter`].
-    pub fn default_over_all_variables(assignments: &Assignments) -> DefaultBrancher {
+    pub fn default_over_all_variables(assignments: &Assignments) -> Self {
         AutonomousSearch {
             predicate_id_info: PredicateIdGenerator::default(),
             heap: KeyValueHeap::default(),
@@ -312,11 +315,15 @@ impl<BackupBrancher: Brancher> Brancher for AutonomousSearch<BackupBrancher> {
             .on_appearance_in_conflict_predicate(predicate);
     }
 
+    fn on_propagator_failure(&mut self, scope: &[DomainId]) {
+        self.backup_brancher.on_propagator_failure(scope)
+    }
+
     fn on_restart(&mut self) {
         self.backup_brancher.on_restart();
     }
 
-    fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i64) {
         self.backup_brancher.on_unassign_integer(variable, value)
     }
 
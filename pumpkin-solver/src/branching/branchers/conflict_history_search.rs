@@ -0,0 +1,341 @@
+use super::default_brancher::DefaultBackupBrancher;
+use super::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use super::variable_scores::VariableScores;
+use crate::basic_types::SolutionReference;
+#[cfg(doc)]
+use crate::branching::branchers::autonomous_search::AutonomousSearch;
+use crate::branching::value_selection::RandomSplitter;
+use crate::branching::variable_selection::RandomSelector;
+use crate::branching::Brancher;
+use crate::branching::BrancherEvent;
+use crate::branching::SelectionContext;
+use crate::containers::KeyedVec;
+use crate::containers::StorageKey;
+use crate::create_statistics_struct;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::Assignments;
+use crate::statistics::Statistic;
+use crate::statistics::StatisticLogger;
+use crate::variables::DomainId;
+
+/// A [`Brancher`] that selects the integer variable based on Conflict History-based Branching
+/// (CHB) \[1\] (adapted for CP) and assigns it a value using solution-based phase saving \[2\].
+///
+/// # Variable selection
+/// Every variable has a score which is an exponential recency weighted average of the rewards
+/// which it receives whenever a conflict is found. A variable is rewarded if it appears in a
+/// predicate which is encountered during conflict analysis, or if it is unassigned by the
+/// backtrack which resolves the conflict; the reward is `1 / (c - l + 1)`, where `c` is the
+/// number of conflicts found so far and `l` is the most recent conflict in whose analysis the
+/// variable appeared. Contrary to \[1\], variables are only rewarded when a conflict is found.
+///
+/// The step-size of the average starts at `0.4` and is decreased by `1e-6` after every conflict
+/// until it reaches `0.06`.
+///
+/// # Value selection
+/// The variable is assigned the value which it has in the best solution known so far (if it is
+/// still in its domain), in the same manner as [`AutonomousSearch`]. If there is no known
+/// solution, the variable is assigned its lower bound.
+///
+/// # Backup selection
+/// Variables are only considered by CHB once they have been rewarded; if all of the variables
+/// which CHB has discovered are fixed, then [`ConflictHistorySearch`] defaults either to a
+/// [`RandomSelector`] with a [`RandomSplitter`] (when created using
+/// [`ConflictHistorySearch::default_over_all_variables`]) or to the [`Brancher`] provided to
+/// [`ConflictHistorySearch::new`].
+///
+/// # Bibliography
+/// \[1\] J. H. Liang, V. Ganesh, P. Poupart, and K. Czarnecki, ‘Exponential recency weighted
+/// average branching heuristic for SAT solvers’, in Proceedings of the AAAI Conference on
+/// Artificial Intelligence, 2016.
+///
+/// \[2\] E. Demirović, G. Chu, and P. J. Stuckey, ‘Solution-based phase saving for CP: A
+/// value-selection heuristic to simulate local search behavior in complete solvers’, in the
+/// proceedings of the Principles and Practice of Constraint Programming (CP 2018).
+#[derive(Debug)]
+pub struct ConflictHistorySearch<BackupBrancher> {
+    /// Stores the scores of the variables and the best-known solution.
+    scores: VariableScores,
+    /// The most recent conflict in whose analysis a variable has appeared.
+    last_conflict: KeyedVec<DomainId, u64>,
+    /// The number of conflicts which have been found so far.
+    num_conflicts: u64,
+    /// The weight of a new reward in the exponential recency weighted average.
+    step_size: f64,
+    /// The variables which have appeared in the analysis of the current conflict.
+    participating_variables: Vec<DomainId>,
+    /// The variables which have been unassigned by the most recent backtrack.
+    unassigned_variables: Vec<DomainId>,
+    /// If the heap does not contain any more unfixed variables then this backup_brancher will be
+    /// used instead.
+    backup_brancher: BackupBrancher,
+
+    statistics: ConflictHistorySearchStatistics,
+}
+
+create_statistics_struct!(ConflictHistorySearchStatistics {
+    num_backup_called: usize,
+    num_calls: usize,
+    num_fixed_variables_encountered: usize,
+});
+
+const DEFAULT_CHB_STEP_SIZE: f64 = 0.4;
+const DEFAULT_CHB_MIN_STEP_SIZE: f64 = 0.06;
+const DEFAULT_CHB_STEP_SIZE_DECREMENT: f64 = 1e-6;
+
+impl ConflictHistorySearch<DefaultBackupBrancher> {
+    /// Creates a new instance with default values for the parameters (`0.4` for the initial
+    /// step-size, `0.06` for the minimum step-size and `1e-6` for the decrement of the
+    /// step-size).
+    ///
+    /// If there are no more variables left to select, this [`Brancher`] switches to
+    /// [`RandomSelector`] with [`RandomSplitter`].
+    pub fn default_over_all_variables(assignments: &Assignments) -> Self {
+        ConflictHistorySearch::new(IndependentVariableValueBrancher::new(
+            RandomSelector::new(assignments.get_domains()),
+            RandomSplitter,
+        ))
+    }
+}
+
+impl<BackupBrancher> ConflictHistorySearch<BackupBrancher> {
+    /// Creates a new instance with default values for the parameters (`0.4` for the initial
+    /// step-size, `0.06` for the minimum step-size and `1e-6` for the decrement of the
+    /// step-size).
+    ///
+    /// Uses the `backup_brancher` in case there are no more variables to be selected by CHB.
+    pub fn new(backup_brancher: BackupBrancher) -> Self {
+        ConflictHistorySearch {
+            scores: VariableScores::default(),
+            last_conflict: KeyedVec::default(),
+            num_conflicts: 0,
+            step_size: DEFAULT_CHB_STEP_SIZE,
+            participating_variables: vec![],
+            unassigned_variables: vec![],
+            backup_brancher,
+            statistics: Default::default(),
+        }
+    }
+
+    /// Resizes the scores and the other per-variable data to accommodate for the variable.
+    fn resize(&mut self, variable: DomainId) {
+        self.scores.resize(variable);
+        let num_variables = self.scores.num_variables();
+        self.last_conflict.resize(num_variables, 0);
+    }
+
+    /// Rewards the variables which have participated in the current conflict or which have been
+    /// unassigned when resolving it, and decreases the step-size.
+    fn reward_variables(&mut self) {
+        self.num_conflicts += 1;
+
+        for &variable in self.participating_variables.iter() {
+            self.last_conflict[variable] = self.num_conflicts;
+        }
+
+        let mut rewarded_variables = std::mem::take(&mut self.participating_variables);
+        rewarded_variables.append(&mut self.unassigned_variables);
+        rewarded_variables.sort_by_key(|variable| variable.index());
+        rewarded_variables.dedup();
+
+        for variable in rewarded_variables {
+            let reward = 1.0 / (self.num_conflicts - self.last_conflict[variable] + 1) as f64;
+            self.scores.reward(variable, reward, self.step_size);
+        }
+
+        self.step_size =
+            (self.step_size - DEFAULT_CHB_STEP_SIZE_DECREMENT).max(DEFAULT_CHB_MIN_STEP_SIZE);
+    }
+}
+
+impl<BackupBrancher: Brancher> Brancher for ConflictHistorySearch<BackupBrancher> {
+    fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+        self.statistics.num_calls += 1;
+        let result = self
+            .scores
+            .next_candidate_variable(
+                context,
+                &mut self.statistics.num_fixed_variables_encountered,
+            )
+            .map(|variable| self.scores.select_value(context, variable));
+        if result.is_none() && !context.are_all_variables_assigned() {
+            // There are variables which CHB has not discovered yet, rely on the backup
+            self.statistics.num_backup_called += 1;
+            self.backup_brancher.next_decision(context)
+        } else {
+            result
+        }
+    }
+
+    fn log_statistics(&self, statistic_logger: StatisticLogger) {
+        let statistic_logger = statistic_logger.attach_to_prefix("ConflictHistorySearch");
+        self.statistics.log(statistic_logger);
+    }
+
+    fn on_backtrack(&mut self) {
+        // Only the variables unassigned by the backtrack resolving a conflict are rewarded
+        self.unassigned_variables.clear();
+        self.backup_brancher.on_backtrack()
+    }
+
+    fn synchronise(&mut self, assignments: &Assignments) {
+        self.backup_brancher.synchronise(assignments);
+    }
+
+    fn on_conflict(&mut self) {
+        // Note that this event is called after the backtrack which resolves the conflict
+        self.reward_variables();
+        self.backup_brancher.on_conflict();
+    }
+
+    fn on_solution(&mut self, solution: SolutionReference) {
+        // We store the best known solution
+        self.scores.on_solution(solution);
+        self.backup_brancher.on_solution(solution);
+    }
+
+    fn on_appearance_in_conflict_predicate(&mut self, predicate: Predicate) {
+        self.resize(predicate.get_domain());
+        self.participating_variables.push(predicate.get_domain());
+        self.backup_brancher
+            .on_appearance_in_conflict_predicate(predicate);
+    }
+
+    fn on_propagator_failure(&mut self, scope: &[DomainId]) {
+        self.backup_brancher.on_propagator_failure(scope)
+    }
+
+    fn on_restart(&mut self) {
+        self.backup_brancher.on_restart();
+    }
+
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i64) {
+        self.resize(variable);
+        self.scores.restore(variable);
+        self.unassigned_variables.push(variable);
+        self.backup_brancher.on_unassign_integer(variable, value)
+    }
+
+    fn is_restart_pointless(&mut self) -> bool {
+        false
+    }
+
+    fn subscribe_to_events(&self) -> Vec<BrancherEvent> {
+        [
+            BrancherEvent::Solution,
+            BrancherEvent::Conflict,
+            BrancherEvent::Backtrack,
+            BrancherEvent::UnassignInteger,
+            BrancherEvent::AppearanceInConflictPredicate,
+        ]
+        .into_iter()
+        .chain(self.backup_brancher.subscribe_to_events())
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConflictHistorySearch;
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::Brancher;
+    use crate::branching::SelectionContext;
+    use crate::engine::Assignments;
+    use crate::predicate;
+    use crate::results::SolutionReference;
+
+    #[test]
+    fn variable_in_recent_conflicts_is_selected() {
+        let mut assignments = Assignments::default();
+        let x = assignments.grow(0, 10);
+        let y = assignments.grow(0, 10);
+
+        let mut brancher = ConflictHistorySearch::default_over_all_variables(&assignments);
+        brancher.on_appearance_in_conflict_predicate(predicate!(x >= 5));
+        brancher.on_conflict();
+        brancher.on_appearance_in_conflict_predicate(predicate!(y >= 5));
+        brancher.on_appearance_in_conflict_predicate(predicate!(y <= 7));
+        brancher.on_conflict();
+
+        // Both variables receive a reward of 1, but the step-size has decreased before `y` is
+        // rewarded
+        assert!(brancher.scores.score(x) > brancher.scores.score(y));
+        let decision = brancher.next_decision(&mut SelectionContext::new(
+            &assignments,
+            &mut TestRandom::default(),
+        ));
+        assert_eq!(decision, Some(predicate!(x <= 0)));
+
+        brancher.on_appearance_in_conflict_predicate(predicate!(y >= 5));
+        brancher.on_conflict();
+
+        let decision = brancher.next_decision(&mut SelectionContext::new(
+            &assignments,
+            &mut TestRandom::default(),
+        ));
+        assert_eq!(decision, Some(predicate!(y <= 0)));
+    }
+
+    #[test]
+    fn unassigned_variables_are_rewarded_by_recency() {
+        let mut assignments = Assignments::default();
+        let x = assignments.grow(0, 10);
+
+        let mut brancher = ConflictHistorySearch::default_over_all_variables(&assignments);
+        brancher.on_appearance_in_conflict_predicate(predicate!(x >= 5));
+        brancher.on_conflict();
+        let score = brancher.scores.score(x);
+
+        // The variable last participated in the first conflict, so it receives a reward of 1 / 2
+        brancher.on_backtrack();
+        brancher.on_unassign_integer(x, 5);
+        brancher.on_conflict();
+
+        let step_size = 0.4 - 1e-6;
+        let expected = (1.0 - step_size) * score + step_size * 0.5;
+        assert!((brancher.scores.score(x) - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fixed_variables_are_restored_when_unassigned() {
+        let mut assignments = Assignments::default();
+        let x = assignments.grow(0, 10);
+
+        let mut brancher = ConflictHistorySearch::default_over_all_variables(&assignments);
+        brancher.on_appearance_in_conflict_predicate(predicate!(x >= 5));
+        brancher.on_conflict();
+
+        assignments.increase_decision_level();
+        let _ = assignments.tighten_lower_bound(x, 10, None);
+        let decision = brancher.next_decision(&mut SelectionContext::new(
+            &assignments,
+            &mut TestRandom::default(),
+        ));
+        assert!(decision.is_none());
+        assert!(!brancher.scores.is_present(x));
+
+        let _ = assignments.synchronise(0, usize::MAX, false);
+        brancher.on_unassign_integer(x, 10);
+        assert!(brancher.scores.is_present(x));
+    }
+
+    #[test]
+    fn value_of_best_solution_is_selected() {
+        let mut assignments = Assignments::default();
+        let x = assignments.grow(0, 10);
+
+        let mut brancher = ConflictHistorySearch::default_over_all_variables(&assignments);
+        brancher.on_appearance_in_conflict_predicate(predicate!(x >= 5));
+        brancher.on_conflict();
+
+        let mut solution = Assignments::default();
+        let _ = solution.grow(7, 7);
+        brancher.on_solution(SolutionReference::new(&solution));
+
+        let decision = brancher.next_decision(&mut SelectionContext::new(
+            &assignments,
+            &mut TestRandom::default(),
+        ));
+        assert_eq!(decision, Some(predicate!(x == 7)));
+    }
+}
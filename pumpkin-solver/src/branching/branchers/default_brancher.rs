@@ -0,0 +1,155 @@
+//! Contains the [`DefaultBrancher`], which is the [`Brancher`] used by the solver when no search
+//! strategy is provided, and the [`BranchingHeuristic`] which determines its behaviour.
+use clap::ValueEnum;
+
+use super::autonomous_search::AutonomousSearch;
+use super::conflict_history_search::ConflictHistorySearch;
+use super::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use super::learning_rate_search::LearningRateSearch;
+use crate::basic_types::SolutionReference;
+use crate::branching::value_selection::RandomSplitter;
+use crate::branching::variable_selection::RandomSelector;
+use crate::branching::Brancher;
+use crate::branching::BrancherEvent;
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::Assignments;
+use crate::statistics::StatisticLogger;
+use crate::variables::DomainId;
+
+/// The heuristic which is used by the [`DefaultBrancher`] to select what to branch on based on
+/// the conflicts which have been found.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum BranchingHeuristic {
+    /// Selects predicates based on VSIDS (see [`AutonomousSearch`]).
+    #[default]
+    Vsids,
+    /// Selects integer variables based on Conflict History-based Branching (see
+    /// [`ConflictHistorySearch`]).
+    Chb,
+    /// Selects integer variables based on Learning Rate-based Branching (see
+    /// [`LearningRateSearch`]).
+    Lrb,
+}
+
+/// The [`Brancher`] which is used by the [`DefaultBrancher`] when none of the predicates or
+/// variables discovered by its [`BranchingHeuristic`] can be branched on; it selects a random
+/// variable (over the variables in the order in which they were defined) using
+/// [`RandomSelector`] and splits its domain using [`RandomSplitter`].
+pub type DefaultBackupBrancher =
+    IndependentVariableValueBrancher<DomainId, RandomSelector, RandomSplitter>;
+
+/// The default [`Brancher`] of the solver, which selects what to branch on based on the conflicts
+/// which have been found, and selects values using solution-based phase saving \[1\].
+///
+/// Which heuristic is used is determined by the [`BranchingHeuristic`] in the
+/// [`SolverOptions`](crate::options::SolverOptions) (see [`crate::Solver::default_brancher`]);
+/// by default, VSIDS over predicates is used (see [`AutonomousSearch`]).
+///
+/// If the heuristic does not have any (unfixed) predicates or variables to branch on, then it
+/// will default to the [`DefaultBackupBrancher`].
+///
+/// # Bibliography
+/// \[1\] E. Demirović, G. Chu, and P. J. Stuckey, ‘Solution-based phase saving for CP: A
+/// value-selection heuristic to simulate local search behavior in complete solvers’, in the
+/// proceedings of the Principles and Practice of Constraint Programming (CP 2018).
+#[derive(Debug)]
+pub enum DefaultBrancher {
+    Vsids(AutonomousSearch<DefaultBackupBrancher>),
+    Chb(ConflictHistorySearch<DefaultBackupBrancher>),
+    Lrb(LearningRateSearch<DefaultBackupBrancher>),
+}
+
+impl DefaultBrancher {
+    /// Creates a [`DefaultBrancher`] which uses VSIDS over all the variables in the provided
+    /// assignments (see [`AutonomousSearch::default_over_all_variables`]).
+    pub fn default_over_all_variables(assignments: &Assignments) -> DefaultBrancher {
+        DefaultBrancher::with_heuristic(BranchingHeuristic::Vsids, assignments)
+    }
+
+    /// Creates a [`DefaultBrancher`] which uses the provided [`BranchingHeuristic`] over all the
+    /// variables in the provided assignments.
+    pub fn with_heuristic(
+        heuristic: BranchingHeuristic,
+        assignments: &Assignments,
+    ) -> DefaultBrancher {
+        match heuristic {
+            BranchingHeuristic::Vsids => {
+                DefaultBrancher::Vsids(AutonomousSearch::default_over_all_variables(assignments))
+            }
+            BranchingHeuristic::Chb => DefaultBrancher::Chb(
+                ConflictHistorySearch::default_over_all_variables(assignments),
+            ),
+            BranchingHeuristic::Lrb => {
+                DefaultBrancher::Lrb(LearningRateSearch::default_over_all_variables(assignments))
+            }
+        }
+    }
+
+    fn brancher(&self) -> &dyn Brancher {
+        match self {
+            DefaultBrancher::Vsids(brancher) => brancher,
+            DefaultBrancher::Chb(brancher) => brancher,
+            DefaultBrancher::Lrb(brancher) => brancher,
+        }
+    }
+
+    fn brancher_mut(&mut self) -> &mut dyn Brancher {
+        match self {
+            DefaultBrancher::Vsids(brancher) => brancher,
+            DefaultBrancher::Chb(brancher) => brancher,
+            DefaultBrancher::Lrb(brancher) => brancher,
+        }
+    }
+}
+
+impl Brancher for DefaultBrancher {
+    fn log_statistics(&self, statistic_logger: StatisticLogger) {
+        self.brancher().log_statistics(statistic_logger)
+    }
+
+    fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+        self.brancher_mut().next_decision(context)
+    }
+
+    fn on_conflict(&mut self) {
+        self.brancher_mut().on_conflict()
+    }
+
+    fn on_backtrack(&mut self) {
+        self.brancher_mut().on_backtrack()
+    }
+
+    fn on_solution(&mut self, solution: SolutionReference) {
+        self.brancher_mut().on_solution(solution)
+    }
+
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i64) {
+        self.brancher_mut().on_unassign_integer(variable, value)
+    }
+
+    fn on_appearance_in_conflict_predicate(&mut self, predicate: Predicate) {
+        self.brancher_mut()
+            .on_appearance_in_conflict_predicate(predicate)
+    }
+
+    fn on_propagator_failure(&mut self, scope: &[DomainId]) {
+        self.brancher_mut().on_propagator_failure(scope)
+    }
+
+    fn on_restart(&mut self) {
+        self.brancher_mut().on_restart()
+    }
+
+    fn synchronise(&mut self, assignments: &Assignments) {
+        self.brancher_mut().synchronise(assignments)
+    }
+
+    fn is_restart_pointless(&mut self) -> bool {
+        self.brancher_mut().is_restart_pointless()
+    }
+
+    fn subscribe_to_events(&self) -> Vec<BrancherEvent> {
+        self.brancher().subscribe_to_events()
+    }
+}
@@ -120,7 +120,7 @@ impl Brancher for DynamicBrancher {
             .for_each(|&brancher_index| self.branchers[brancher_index].on_backtrack());
     }
 
-    fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i64) {
         self.relevant_event_to_index[BrancherEvent::UnassignInteger]
             .iter()
             .for_each(|&brancher_index| {
@@ -136,6 +136,14 @@ impl Brancher for DynamicBrancher {
             });
     }
 
+    fn on_propagator_failure(&mut self, scope: &[DomainId]) {
+        self.relevant_event_to_index[BrancherEvent::PropagatorFailure]
+            .iter()
+            .for_each(|&brancher_index| {
+                self.branchers[brancher_index].on_propagator_failure(scope)
+            });
+    }
+
     fn on_solution(&mut self, solution: SolutionReference) {
         self.brancher_index = 0;
         self.relevant_event_to_index[BrancherEvent::Solution]
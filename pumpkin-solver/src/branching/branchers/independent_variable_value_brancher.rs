@@ -73,7 +73,7 @@ where
         self.variable_selector.on_conflict()
     }
 
-    fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i64) {
         self.variable_selector.on_unassign_integer(variable, value);
         self.value_selector.on_unassign_integer(variable, value)
     }
@@ -83,6 +83,10 @@ where
             .on_appearance_in_conflict_predicate(predicate)
     }
 
+    fn on_propagator_failure(&mut self, scope: &[DomainId]) {
+        self.variable_selector.on_propagator_failure(scope)
+    }
+
     fn on_solution(&mut self, solution: SolutionReference) {
         self.value_selector.on_solution(solution);
     }
@@ -0,0 +1,321 @@
+use super::default_brancher::DefaultBackupBrancher;
+use super::independent_variable_value_brancher::IndependentVariableValueBrancher;
+use super::variable_scores::VariableScores;
+use crate::basic_types::SolutionReference;
+#[cfg(doc)]
+use crate::branching::branchers::autonomous_search::AutonomousSearch;
+use crate::branching::value_selection::RandomSplitter;
+use crate::branching::variable_selection::RandomSelector;
+use crate::branching::Brancher;
+use crate::branching::BrancherEvent;
+use crate::branching::SelectionContext;
+use crate::containers::KeyedVec;
+use crate::create_statistics_struct;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::Assignments;
+use crate::statistics::Statistic;
+use crate::statistics::StatisticLogger;
+use crate::variables::DomainId;
+
+/// A [`Brancher`] that selects the integer variable based on Learning Rate-based Branching (LRB)
+/// \[1\] (adapted for CP) and assigns it a value using solution-based phase saving \[2\].
+///
+/// # Variable selection
+/// The learning rate of a variable over an interval is the fraction of the conflicts found in
+/// that interval in whose analysis the variable appeared. Whenever a variable is unassigned, the
+/// learning rate over the interval since it was last unassigned is used as a reward, and the
+/// score of the variable is an exponential recency weighted average of these rewards. Note that
+/// the interval in \[1\] starts when the variable is assigned; since a [`Brancher`] is not notified
+/// of assignments, the interval here starts when the variable was last unassigned. The extensions
+/// of \[1\] (the reason side rate and the locality of the scores) are not used.
+///
+/// The step-size of the average starts at `0.4` and is decreased by `1e-6` after every conflict
+/// until it reaches `0.06`.
+///
+/// # Value selection
+/// The variable is assigned the value which it has in the best solution known so far (if it is
+/// still in its domain), in the same manner as [`AutonomousSearch`]. If there is no known
+/// solution, the variable is assigned its lower bound.
+///
+/// # Backup selection
+/// Variables are only considered by LRB once they have appeared in a conflict or have been
+/// unassigned; if all of the variables which LRB has discovered are fixed, then
+/// [`LearningRateSearch`] defaults either to a [`RandomSelector`] with a [`RandomSplitter`] (when
+/// created using [`LearningRateSearch::default_over_all_variables`]) or to the [`Brancher`]
+/// provided to [`LearningRateSearch::new`].
+///
+/// # Bibliography
+/// \[1\] J. H. Liang, V. Ganesh, P. Poupart, and K. Czarnecki, ‘Learning rate based branching
+/// heuristic for SAT solvers’, in Theory and Applications of Satisfiability Testing – SAT 2016,
+/// 2016, pp. 123–140.
+///
+/// \[2\] E. Demirović, G. Chu, and P. J. Stuckey, ‘Solution-based phase saving for CP: A
+/// value-selection heuristic to simulate local search behavior in complete solvers’, in the
+/// proceedings of the Principles and Practice of Constraint Programming (CP 2018).
+#[derive(Debug)]
+pub struct LearningRateSearch<BackupBrancher> {
+    /// Stores the scores of the variables and the best-known solution.
+    scores: VariableScores,
+    /// The number of conflicts which had been found when a variable was last unassigned.
+    interval_start: KeyedVec<DomainId, u64>,
+    /// The number of conflicts since the start of the interval in whose analysis a variable has
+    /// appeared.
+    num_participations: KeyedVec<DomainId, u64>,
+    /// The most recent conflict in whose analysis a variable has appeared.
+    last_participation: KeyedVec<DomainId, u64>,
+    /// The number of conflicts which have been found so far.
+    num_conflicts: u64,
+    /// Whether a conflict is currently being analysed; the conflict is counted as soon as the
+    /// first predicate appears in its analysis, such that it is included in the intervals of the
+    /// variables which are unassigned to resolve it.
+    is_analysing_conflict: bool,
+    /// The weight of a new reward in the exponential recency weighted average.
+    step_size: f64,
+    /// If the heap does not contain any more unfixed variables then this backup_brancher will be
+    /// used instead.
+    backup_brancher: BackupBrancher,
+
+    statistics: LearningRateSearchStatistics,
+}
+
+create_statistics_struct!(LearningRateSearchStatistics {
+    num_backup_called: usize,
+    num_calls: usize,
+    num_fixed_variables_encountered: usize,
+});
+
+const DEFAULT_LRB_STEP_SIZE: f64 = 0.4;
+const DEFAULT_LRB_MIN_STEP_SIZE: f64 = 0.06;
+const DEFAULT_LRB_STEP_SIZE_DECREMENT: f64 = 1e-6;
+
+impl LearningRateSearch<DefaultBackupBrancher> {
+    /// Creates a new instance with default values for the parameters (`0.4` for the initial
+    /// step-size, `0.06` for the minimum step-size and `1e-6` for the decrement of the
+    /// step-size).
+    ///
+    /// If there are no more variables left to select, this [`Brancher`] switches to
+    /// [`RandomSelector`] with [`RandomSplitter`].
+    pub fn default_over_all_variables(assignments: &Assignments) -> Self {
+        LearningRateSearch::new(IndependentVariableValueBrancher::new(
+            RandomSelector::new(assignments.get_domains()),
+            RandomSplitter,
+        ))
+    }
+}
+
+impl<BackupBrancher> LearningRateSearch<BackupBrancher> {
+    /// Creates a new instance with default values for the parameters (`0.4` for the initial
+    /// step-size, `0.06` for the minimum step-size and `1e-6` for the decrement of the
+    /// step-size).
+    ///
+    /// Uses the `backup_brancher` in case there are no more variables to be selected by LRB.
+    pub fn new(backup_brancher: BackupBrancher) -> Self {
+        LearningRateSearch {
+            scores: VariableScores::default(),
+            interval_start: KeyedVec::default(),
+            num_participations: KeyedVec::default(),
+            last_participation: KeyedVec::default(),
+            num_conflicts: 0,
+            is_analysing_conflict: false,
+            step_size: DEFAULT_LRB_STEP_SIZE,
+            backup_brancher,
+            statistics: Default::default(),
+        }
+    }
+
+    /// Resizes the scores and the other per-variable data to accommodate for the variable.
+    fn resize(&mut self, variable: DomainId) {
+        self.scores.resize(variable);
+        let num_variables = self.scores.num_variables();
+        self.interval_start.resize(num_variables, 0);
+        self.num_participations.resize(num_variables, 0);
+        self.last_participation.resize(num_variables, 0);
+    }
+
+    /// Rewards the variable with its learning rate over the interval which ends now, and starts
+    /// a new interval.
+    fn reward_variable(&mut self, variable: DomainId) {
+        let interval = self.num_conflicts - self.interval_start[variable];
+        if interval > 0 {
+            let reward = self.num_participations[variable] as f64 / interval as f64;
+            self.scores.reward(variable, reward, self.step_size);
+        }
+
+        self.interval_start[variable] = self.num_conflicts;
+        self.num_participations[variable] = 0;
+    }
+}
+
+impl<BackupBrancher: Brancher> Brancher for LearningRateSearch<BackupBrancher> {
+    fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+        self.statistics.num_calls += 1;
+        let result = self
+            .scores
+            .next_candidate_variable(
+                context,
+                &mut self.statistics.num_fixed_variables_encountered,
+            )
+            .map(|variable| self.scores.select_value(context, variable));
+        if result.is_none() && !context.are_all_variables_assigned() {
+            // There are variables which LRB has not discovered yet, rely on the backup
+            self.statistics.num_backup_called += 1;
+            self.backup_brancher.next_decision(context)
+        } else {
+            result
+        }
+    }
+
+    fn log_statistics(&self, statistic_logger: StatisticLogger) {
+        let statistic_logger = statistic_logger.attach_to_prefix("LearningRateSearch");
+        self.statistics.log(statistic_logger);
+    }
+
+    fn on_backtrack(&mut self) {
+        self.backup_brancher.on_backtrack()
+    }
+
+    fn synchronise(&mut self, assignments: &Assignments) {
+        self.backup_brancher.synchronise(assignments);
+    }
+
+    fn on_conflict(&mut self) {
+        self.is_analysing_conflict = false;
+        self.step_size =
+            (self.step_size - DEFAULT_LRB_STEP_SIZE_DECREMENT).max(DEFAULT_LRB_MIN_STEP_SIZE);
+        self.backup_brancher.on_conflict();
+    }
+
+    fn on_solution(&mut self, solution: SolutionReference) {
+        // We store the best known solution
+        self.scores.on_solution(solution);
+        self.backup_brancher.on_solution(solution);
+    }
+
+    fn on_appearance_in_conflict_predicate(&mut self, predicate: Predicate) {
+        if !self.is_analysing_conflict {
+            self.is_analysing_conflict = true;
+            self.num_conflicts += 1;
+        }
+
+        let variable = predicate.get_domain();
+        self.resize(variable);
+        // A variable participates at most once in every conflict
+        if self.last_participation[variable] != self.num_conflicts {
+            self.last_participation[variable] = self.num_conflicts;
+            self.num_participations[variable] += 1;
+        }
+
+        self.backup_brancher
+            .on_appearance_in_conflict_predicate(predicate);
+    }
+
+    fn on_propagator_failure(&mut self, scope: &[DomainId]) {
+        self.backup_brancher.on_propagator_failure(scope)
+    }
+
+    fn on_restart(&mut self) {
+        self.backup_brancher.on_restart();
+    }
+
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i64) {
+        self.resize(variable);
+        self.reward_variable(variable);
+        self.scores.restore(variable);
+        self.backup_brancher.on_unassign_integer(variable, value)
+    }
+
+    fn is_restart_pointless(&mut self) -> bool {
+        false
+    }
+
+    fn subscribe_to_events(&self) -> Vec<BrancherEvent> {
+        [
+            BrancherEvent::Solution,
+            BrancherEvent::Conflict,
+            BrancherEvent::UnassignInteger,
+            BrancherEvent::AppearanceInConflictPredicate,
+        ]
+        .into_iter()
+        .chain(self.backup_brancher.subscribe_to_events())
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LearningRateSearch;
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::Brancher;
+    use crate::branching::SelectionContext;
+    use crate::engine::Assignments;
+    use crate::predicate;
+
+    #[test]
+    fn variable_with_highest_learning_rate_is_selected() {
+        let mut assignments = Assignments::default();
+        let x = assignments.grow(0, 10);
+        let y = assignments.grow(0, 10);
+
+        let mut brancher = LearningRateSearch::default_over_all_variables(&assignments);
+        brancher.on_appearance_in_conflict_predicate(predicate!(x >= 5));
+        brancher.on_appearance_in_conflict_predicate(predicate!(y >= 5));
+        brancher.on_conflict();
+        brancher.on_appearance_in_conflict_predicate(predicate!(y >= 5));
+        brancher.on_appearance_in_conflict_predicate(predicate!(y <= 7));
+
+        // `x` has participated in one of the two conflicts, and `y` in both of them
+        brancher.on_unassign_integer(x, 5);
+        brancher.on_unassign_integer(y, 5);
+        brancher.on_conflict();
+
+        let step_size = 0.4 - 1e-6;
+        assert!((brancher.scores.score(x) - step_size * 0.5).abs() < 1e-9);
+        assert!((brancher.scores.score(y) - step_size).abs() < 1e-9);
+
+        let decision = brancher.next_decision(&mut SelectionContext::new(
+            &assignments,
+            &mut TestRandom::default(),
+        ));
+        assert_eq!(decision, Some(predicate!(y <= 0)));
+    }
+
+    #[test]
+    fn interval_starts_when_unassigned() {
+        let mut assignments = Assignments::default();
+        let x = assignments.grow(0, 10);
+
+        let mut brancher = LearningRateSearch::default_over_all_variables(&assignments);
+        brancher.on_appearance_in_conflict_predicate(predicate!(x >= 5));
+        brancher.on_unassign_integer(x, 5);
+        brancher.on_conflict();
+        let score = brancher.scores.score(x);
+
+        // Without any conflicts since the previous unassignment, the score is unchanged
+        brancher.on_unassign_integer(x, 5);
+        assert_eq!(brancher.scores.score(x), score);
+        assert_eq!(brancher.interval_start[x], 1);
+    }
+
+    #[test]
+    fn fixed_variables_are_restored_when_unassigned() {
+        let mut assignments = Assignments::default();
+        let x = assignments.grow(0, 10);
+
+        let mut brancher = LearningRateSearch::default_over_all_variables(&assignments);
+        brancher.on_appearance_in_conflict_predicate(predicate!(x >= 5));
+        brancher.on_conflict();
+
+        assignments.increase_decision_level();
+        let _ = assignments.tighten_lower_bound(x, 10, None);
+        let decision = brancher.next_decision(&mut SelectionContext::new(
+            &assignments,
+            &mut TestRandom::default(),
+        ));
+        assert!(decision.is_none());
+        assert!(!brancher.scores.is_present(x));
+
+        let _ = assignments.synchronise(0, usize::MAX, false);
+        brancher.on_unassign_integer(x, 10);
+        assert!(brancher.scores.is_present(x));
+    }
+}
@@ -2,7 +2,13 @@
 
 pub mod alternating_brancher;
 pub mod autonomous_search;
+pub mod conflict_history_search;
+pub mod default_brancher;
 pub mod dynamic_brancher;
 pub mod independent_variable_value_brancher;
+pub mod learning_rate_search;
+pub mod priority_search;
+mod variable_scores;
+pub mod warm_start;
 #[cfg(doc)]
 use super::Brancher;
@@ -0,0 +1,342 @@
+//! A [`Brancher`] which selects which of its [`Brancher`]s to apply based on the domains of a list
+//! of priority variables.
+use std::fmt::Debug;
+
+use crate::basic_types::SolutionReference;
+use crate::branching::Brancher;
+use crate::branching::BrancherEvent;
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::Assignments;
+use crate::statistics::StatisticLogger;
+use crate::variables::DomainId;
+
+/// The criterion which is used by [`PrioritySearch`] to select the priority variable (and thus the
+/// [`Brancher`]) which is applied next; ties are broken by selecting the variable which was
+/// provided first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrioritySelection {
+    /// Selects the first priority variable.
+    InputOrder,
+    /// Selects the priority variable with the smallest lower bound.
+    Smallest,
+    /// Selects the priority variable with the largest upper bound.
+    Largest,
+    /// Selects the priority variable with the smallest domain.
+    FirstFail,
+    /// Selects the priority variable with the largest domain.
+    AntiFirstFail,
+}
+
+impl PrioritySelection {
+    /// Returns the key of the provided variable; the variable with the smallest key is selected.
+    fn key(self, context: &SelectionContext, variable: DomainId) -> i64 {
+        match self {
+            PrioritySelection::InputOrder => 0,
+            PrioritySelection::Smallest => context.lower_bound(variable),
+            PrioritySelection::Largest => -context.upper_bound(variable),
+            PrioritySelection::FirstFail => context.get_size_of_domain(variable),
+            PrioritySelection::AntiFirstFail => -context.get_size_of_domain(variable),
+        }
+    }
+}
+
+/// A [`Brancher`] which is provided with a priority variable for each of its [`Brancher`]s; the
+/// [`Brancher`] which is applied is the one of which the priority variable is selected based on
+/// the [`PrioritySelection`].
+///
+/// The selected [`Brancher`] is applied until it does not have any decisions to make, after which
+/// the next [`Brancher`] is selected among the ones which still have decisions to make. Upon
+/// backtracking, all of the [`Brancher`]s are considered again.
+///
+/// This corresponds to the `priority_search` annotation in MiniZinc.
+///
+/// # Example
+/// ```
+/// # use pumpkin_solver::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+/// # use pumpkin_solver::branching::branchers::priority_search::PrioritySearch;
+/// # use pumpkin_solver::branching::branchers::priority_search::PrioritySelection;
+/// # use pumpkin_solver::branching::value_selection::InDomainMin;
+/// # use pumpkin_solver::branching::variable_selection::InputOrder;
+/// # use pumpkin_solver::results::ProblemSolution;
+/// # use pumpkin_solver::results::SatisfactionResult;
+/// # use pumpkin_solver::termination::Indefinite;
+/// # use pumpkin_solver::Solver;
+/// let mut solver = Solver::default();
+/// let x = solver.new_bounded_integer(5, 10);
+/// let y = solver.new_bounded_integer(0, 10);
+///
+/// // The brancher over `y` is applied first since its lower bound is the smallest
+/// let mut brancher = PrioritySearch::new(
+///     vec![x, y],
+///     vec![
+///         Box::new(IndependentVariableValueBrancher::new(
+///             InputOrder::new(&[x]),
+///             InDomainMin,
+///         )),
+///         Box::new(IndependentVariableValueBrancher::new(
+///             InputOrder::new(&[y]),
+///             InDomainMin,
+///         )),
+///     ],
+///     PrioritySelection::Smallest,
+/// );
+/// let SatisfactionResult::Satisfiable(solution) = solver.satisfy(&mut brancher, &mut Indefinite)
+/// else {
+///     panic!("The problem should be satisfiable")
+/// };
+/// assert_eq!(solution.get_integer_value(x), 5);
+/// assert_eq!(solution.get_integer_value(y), 0);
+/// ```
+pub struct PrioritySearch {
+    /// The priority variable of each of the [`Brancher`]s.
+    priority_variables: Vec<DomainId>,
+    branchers: Vec<Box<dyn Brancher>>,
+    selection: PrioritySelection,
+    /// The index of the [`Brancher`] which is currently applied, or [`None`] if a new
+    /// [`Brancher`] should be selected.
+    current_brancher: Option<usize>,
+    /// Whether the [`Brancher`] did not have any decisions to make since the last backtrack.
+    is_exhausted: Vec<bool>,
+}
+
+impl Debug for PrioritySearch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PrioritySearch")
+            .field("priority_variables", &self.priority_variables)
+            .field("selection", &self.selection)
+            .finish()
+    }
+}
+
+impl PrioritySearch {
+    /// Creates a new [`PrioritySearch`] where the [`Brancher`] at index `i` of `branchers` has
+    /// the priority variable at index `i` of `priority_variables`.
+    pub fn new(
+        priority_variables: Vec<DomainId>,
+        branchers: Vec<Box<dyn Brancher>>,
+        selection: PrioritySelection,
+    ) -> Self {
+        assert_eq!(
+            priority_variables.len(),
+            branchers.len(),
+            "Expected a priority variable for every brancher"
+        );
+
+        let num_branchers = branchers.len();
+        Self {
+            priority_variables,
+            branchers,
+            selection,
+            current_brancher: None,
+            is_exhausted: vec![false; num_branchers],
+        }
+    }
+
+    /// Selects the [`Brancher`] which is applied next among the ones which are not exhausted.
+    fn select_brancher(&self, context: &SelectionContext) -> Option<usize> {
+        (0..self.branchers.len())
+            .filter(|&index| !self.is_exhausted[index])
+            .min_by_key(|&index| {
+                (
+                    self.selection.key(context, self.priority_variables[index]),
+                    index,
+                )
+            })
+    }
+}
+
+impl Brancher for PrioritySearch {
+    fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+        loop {
+            let index = match self.current_brancher {
+                Some(index) => index,
+                None => *self.current_brancher.insert(self.select_brancher(context)?),
+            };
+
+            if let Some(decision) = self.branchers[index].next_decision(context) {
+                return Some(decision);
+            }
+
+            self.is_exhausted[index] = true;
+            self.current_brancher = None;
+        }
+    }
+
+    fn log_statistics(&self, statistic_logger: StatisticLogger) {
+        self.branchers
+            .iter()
+            .enumerate()
+            .for_each(move |(index, brancher)| {
+                brancher.log_statistics(statistic_logger.attach_to_prefix(index))
+            })
+    }
+
+    fn on_conflict(&mut self) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_conflict());
+    }
+
+    fn on_backtrack(&mut self) {
+        // The exhausted branchers could have decisions to make again
+        self.current_brancher = None;
+        self.is_exhausted.fill(false);
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_backtrack());
+    }
+
+    fn on_solution(&mut self, solution: SolutionReference) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_solution(solution));
+    }
+
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i64) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_unassign_integer(variable, value));
+    }
+
+    fn on_appearance_in_conflict_predicate(&mut self, predicate: Predicate) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_appearance_in_conflict_predicate(predicate));
+    }
+
+    fn on_propagator_failure(&mut self, scope: &[DomainId]) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_propagator_failure(scope));
+    }
+
+    fn on_restart(&mut self) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.on_restart());
+    }
+
+    fn synchronise(&mut self, assignments: &Assignments) {
+        self.branchers
+            .iter_mut()
+            .for_each(|brancher| brancher.synchronise(assignments));
+    }
+
+    fn is_restart_pointless(&mut self) -> bool {
+        // The selection only depends on the domains of the priority variables, which are the same
+        // after every restart
+        self.branchers
+            .iter_mut()
+            .all(|brancher| brancher.is_restart_pointless())
+    }
+
+    fn subscribe_to_events(&self) -> Vec<BrancherEvent> {
+        let mut events = vec![BrancherEvent::Backtrack];
+        for event in self
+            .branchers
+            .iter()
+            .flat_map(|brancher| brancher.subscribe_to_events())
+        {
+            if !events.contains(&event) {
+                events.push(event);
+            }
+        }
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PrioritySearch;
+    use super::PrioritySelection;
+    use crate::basic_types::tests::TestRandom;
+    use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+    use crate::branching::value_selection::InDomainMin;
+    use crate::branching::variable_selection::InputOrder;
+    use crate::branching::Brancher;
+    use crate::branching::SelectionContext;
+    use crate::engine::Assignments;
+    use crate::predicate;
+    use crate::variables::DomainId;
+
+    fn search_over(variables: &[DomainId], selection: PrioritySelection) -> PrioritySearch {
+        PrioritySearch::new(
+            variables.to_vec(),
+            variables
+                .iter()
+                .map(|&variable| {
+                    let brancher: Box<dyn Brancher> =
+                        Box::new(IndependentVariableValueBrancher::new(
+                            InputOrder::new(&[variable]),
+                            InDomainMin,
+                        ));
+                    brancher
+                })
+                .collect(),
+            selection,
+        )
+    }
+
+    #[test]
+    fn brancher_is_selected_based_on_priority_variable() {
+        let mut assignments = Assignments::default();
+        let x = assignments.grow(0, 10);
+        let y = assignments.grow(0, 5);
+
+        let mut brancher = search_over(&[x, y], PrioritySelection::FirstFail);
+        let decision = brancher.next_decision(&mut SelectionContext::new(
+            &assignments,
+            &mut TestRandom::default(),
+        ));
+        assert_eq!(decision, Some(predicate!(y <= 0)));
+    }
+
+    #[test]
+    fn exhausted_brancher_is_not_selected_until_backtrack() {
+        let mut assignments = Assignments::default();
+        let x = assignments.grow(0, 10);
+        let y = assignments.grow(5, 10);
+
+        let mut brancher = search_over(&[x, y], PrioritySelection::Smallest);
+
+        assignments.increase_decision_level();
+        let _ = assignments.post_predicate(predicate!(x == 7), None);
+        let decision = brancher.next_decision(&mut SelectionContext::new(
+            &assignments,
+            &mut TestRandom::default(),
+        ));
+        assert_eq!(decision, Some(predicate!(y <= 5)));
+
+        let _ = assignments.synchronise(0, usize::MAX, false);
+        brancher.on_backtrack();
+        let decision = brancher.next_decision(&mut SelectionContext::new(
+            &assignments,
+            &mut TestRandom::default(),
+        ));
+        assert_eq!(decision, Some(predicate!(x <= 0)));
+    }
+
+    #[test]
+    fn selected_brancher_is_applied_until_exhausted() {
+        let mut assignments = Assignments::default();
+        let x = assignments.grow(0, 10);
+        let y = assignments.grow(1, 10);
+
+        let mut brancher = search_over(&[x, y], PrioritySelection::Smallest);
+        let decision = brancher.next_decision(&mut SelectionContext::new(
+            &assignments,
+            &mut TestRandom::default(),
+        ));
+        assert_eq!(decision, Some(predicate!(x <= 0)));
+
+        // The lower bound of `x` is now larger than the lower bound of `y`, but `x` is not fixed
+        assignments.increase_decision_level();
+        let _ = assignments.post_predicate(predicate!(x >= 5), None);
+        let decision = brancher.next_decision(&mut SelectionContext::new(
+            &assignments,
+            &mut TestRandom::default(),
+        ));
+        assert_eq!(decision, Some(predicate!(x <= 5)));
+    }
+}
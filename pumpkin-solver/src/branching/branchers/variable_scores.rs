@@ -0,0 +1,108 @@
+use crate::basic_types::ProblemSolution;
+use crate::basic_types::SolutionReference;
+#[cfg(doc)]
+use crate::branching::branchers::conflict_history_search::ConflictHistorySearch;
+#[cfg(doc)]
+use crate::branching::branchers::learning_rate_search::LearningRateSearch;
+use crate::branching::SelectionContext;
+use crate::containers::KeyValueHeap;
+use crate::containers::StorageKey;
+use crate::engine::predicates::predicate::Predicate;
+use crate::predicate;
+use crate::results::Solution;
+use crate::variables::DomainId;
+
+const DEFAULT_SCORE: f64 = 0.0;
+
+/// The scores of the integer variables for the branchers which select the unfixed variable with
+/// the highest score and assign it a value using solution-based phase saving (i.e.
+/// [`ConflictHistorySearch`] and [`LearningRateSearch`]).
+///
+/// The score of a variable is an exponential recency weighted average of the rewards which it
+/// receives; how these rewards are determined is up to the brancher.
+#[derive(Debug, Default)]
+pub(crate) struct VariableScores {
+    /// Stores the scores of the variables; variables which are fixed are (temporarily) removed
+    /// from the heap and restored when they are unassigned.
+    heap: KeyValueHeap<DomainId, f64>,
+    /// Contains the best-known solution or [`None`] if no solution has been found.
+    best_known_solution: Option<Solution>,
+}
+
+impl VariableScores {
+    /// Returns the number of variables for which a score is stored.
+    pub(crate) fn num_variables(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Resizes the heap to accommodate for the variable; new variables have a score of `0`.
+    /// Recall that the underlying heap uses direct hashing.
+    pub(crate) fn resize(&mut self, variable: DomainId) {
+        while self.heap.len() <= variable.index() {
+            self.heap
+                .grow(DomainId::create_from_index(self.heap.len()), DEFAULT_SCORE);
+        }
+    }
+
+    pub(crate) fn score(&self, variable: DomainId) -> f64 {
+        *self.heap.get_value(variable)
+    }
+
+    /// Adds the `reward` to the exponential recency weighted average of the variable, where the
+    /// new reward has weight `step_size`.
+    pub(crate) fn reward(&mut self, variable: DomainId, reward: f64, step_size: f64) {
+        let score = self.score(variable);
+        self.heap
+            .set_value(variable, (1.0 - step_size) * score + step_size * reward);
+    }
+
+    /// Restores the variable to the heap after it has been unassigned.
+    pub(crate) fn restore(&mut self, variable: DomainId) {
+        self.heap.restore_key(variable);
+    }
+
+    #[cfg(test)]
+    pub(crate) fn is_present(&self, variable: DomainId) -> bool {
+        self.heap.is_key_present(variable)
+    }
+
+    /// Stores the provided solution as the best-known solution.
+    pub(crate) fn on_solution(&mut self, solution: SolutionReference) {
+        self.best_known_solution = Some(solution.into());
+    }
+
+    /// Returns the unfixed variable with the highest score, or [`None`] if all of the variables
+    /// in the heap are fixed; `num_fixed_variables_encountered` is incremented for every fixed
+    /// variable which is removed from the heap.
+    pub(crate) fn next_candidate_variable(
+        &mut self,
+        context: &mut SelectionContext,
+        num_fixed_variables_encountered: &mut usize,
+    ) -> Option<DomainId> {
+        loop {
+            let (&candidate, _) = self.heap.peek_max()?;
+            if context.is_integer_fixed(candidate) {
+                // The variable is restored to the heap when it is unassigned
+                *num_fixed_variables_encountered += 1;
+                let _ = self.heap.pop_max();
+            } else {
+                return Some(candidate);
+            }
+        }
+    }
+
+    /// Returns the decision for the provided variable; this is the value of the variable in the
+    /// best-known solution if it is still in the domain, and its lower bound otherwise.
+    pub(crate) fn select_value(&self, context: &SelectionContext, variable: DomainId) -> Predicate {
+        if let Some(solution) = &self.best_known_solution {
+            if solution.contains_domain_id(variable) {
+                let value = solution.get_integer_value(variable);
+                if context.contains(variable, value) {
+                    return predicate!(variable == value);
+                }
+            }
+        }
+
+        predicate!(variable <= context.lower_bound(variable))
+    }
+}
@@ -0,0 +1,239 @@
+//! A [`Brancher`] which first tries to satisfy a list of hints before deferring to another
+//! [`Brancher`].
+use crate::basic_types::SolutionReference;
+use crate::branching::Brancher;
+use crate::branching::BrancherEvent;
+use crate::branching::SelectionContext;
+use crate::create_statistics_struct;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::Assignments;
+use crate::statistics::Statistic;
+use crate::statistics::StatisticLogger;
+use crate::variables::DomainId;
+
+/// A [`Brancher`] which wraps another [`Brancher`] and first branches on the provided hints (e.g.
+/// `[x == 5]` to indicate that `x` is expected to take the value 5 in a good solution) in the
+/// order in which they were provided.
+///
+/// Hints which are already assigned (i.e. which are either satisfied or falsified by the current
+/// partial assignment) are skipped; once all hints are assigned, the decisions are made by the
+/// wrapped [`Brancher`]. The hints are only used until the first solution is found, after which
+/// only the wrapped [`Brancher`] is used.
+///
+/// # Example
+/// ```
+/// # use pumpkin_solver::branching::branchers::warm_start::WarmStart;
+/// # use pumpkin_solver::constraints;
+/// # use pumpkin_solver::predicate;
+/// # use pumpkin_solver::results::ProblemSolution;
+/// # use pumpkin_solver::results::SatisfactionResult;
+/// # use pumpkin_solver::termination::Indefinite;
+/// # use pumpkin_solver::Solver;
+/// let mut solver = Solver::default();
+/// let x = solver.new_bounded_integer(0, 10);
+/// let y = solver.new_bounded_integer(0, 10);
+/// let _ = solver
+///     .add_constraint(constraints::not_equals(vec![x, y], 0))
+///     .post();
+///
+/// let mut brancher = WarmStart::new(
+///     vec![predicate!(x == 4), predicate!(y == 7)],
+///     solver.default_brancher(),
+/// );
+/// let SatisfactionResult::Satisfiable(solution) = solver.satisfy(&mut brancher, &mut Indefinite)
+/// else {
+///     panic!("The problem should be satisfiable")
+/// };
+/// assert_eq!(solution.get_integer_value(x), 4);
+/// assert_eq!(solution.get_integer_value(y), 7);
+/// ```
+#[derive(Debug)]
+pub struct WarmStart<OtherBrancher> {
+    /// The hints, in the order in which they are branched on.
+    hints: Vec<Predicate>,
+    /// The index of the first hint which could be unassigned; the hints before it have been
+    /// assigned since the last backtrack.
+    next_hint: usize,
+    /// Whether a solution has been found, after which the hints are no longer used.
+    has_found_solution: bool,
+    /// The [`Brancher`] which is used once all hints are assigned.
+    brancher: OtherBrancher,
+
+    statistics: WarmStartStatistics,
+}
+
+create_statistics_struct!(WarmStartStatistics {
+    num_hint_decisions: usize,
+});
+
+impl<OtherBrancher> WarmStart<OtherBrancher> {
+    /// Creates a new [`WarmStart`] which branches on the provided `hints` before deferring to
+    /// `brancher`.
+    pub fn new(hints: impl IntoIterator<Item = Predicate>, brancher: OtherBrancher) -> Self {
+        Self {
+            hints: hints.into_iter().collect(),
+            next_hint: 0,
+            has_found_solution: false,
+            brancher,
+            statistics: WarmStartStatistics::default(),
+        }
+    }
+}
+
+impl<OtherBrancher: Brancher> Brancher for WarmStart<OtherBrancher> {
+    fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+        if !self.has_found_solution {
+            while self.next_hint < self.hints.len() {
+                let hint = self.hints[self.next_hint];
+                if !context.is_predicate_assigned(hint) {
+                    self.statistics.num_hint_decisions += 1;
+                    return Some(hint);
+                }
+                self.next_hint += 1;
+            }
+        }
+
+        self.brancher.next_decision(context)
+    }
+
+    fn log_statistics(&self, statistic_logger: StatisticLogger) {
+        self.statistics
+            .log(statistic_logger.attach_to_prefix("WarmStart"));
+        self.brancher.log_statistics(statistic_logger);
+    }
+
+    fn on_conflict(&mut self) {
+        self.brancher.on_conflict()
+    }
+
+    fn on_backtrack(&mut self) {
+        // The hints which were assigned before the backtrack could now be unassigned
+        self.next_hint = 0;
+        self.brancher.on_backtrack()
+    }
+
+    fn on_solution(&mut self, solution: SolutionReference) {
+        self.has_found_solution = true;
+        self.brancher.on_solution(solution)
+    }
+
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i64) {
+        self.brancher.on_unassign_integer(variable, value)
+    }
+
+    fn on_appearance_in_conflict_predicate(&mut self, predicate: Predicate) {
+        self.brancher.on_appearance_in_conflict_predicate(predicate)
+    }
+
+    fn on_propagator_failure(&mut self, scope: &[DomainId]) {
+        self.brancher.on_propagator_failure(scope)
+    }
+
+    fn on_restart(&mut self) {
+        self.brancher.on_restart()
+    }
+
+    fn synchronise(&mut self, assignments: &Assignments) {
+        self.brancher.synchronise(assignments)
+    }
+
+    fn is_restart_pointless(&mut self) -> bool {
+        self.brancher.is_restart_pointless()
+    }
+
+    fn subscribe_to_events(&self) -> Vec<BrancherEvent> {
+        [BrancherEvent::Backtrack, BrancherEvent::Solution]
+            .into_iter()
+            .chain(self.brancher.subscribe_to_events())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WarmStart;
+    use crate::basic_types::tests::TestRandom;
+    use crate::basic_types::SolutionReference;
+    use crate::branching::branchers::independent_variable_value_brancher::IndependentVariableValueBrancher;
+    use crate::branching::value_selection::InDomainMin;
+    use crate::branching::variable_selection::InputOrder;
+    use crate::branching::Brancher;
+    use crate::branching::SelectionContext;
+    use crate::engine::Assignments;
+    use crate::predicate;
+
+    #[test]
+    fn hints_are_used_in_order() {
+        let mut assignments = Assignments::default();
+        let x = assignments.grow(0, 10);
+        let y = assignments.grow(0, 10);
+
+        let mut brancher = WarmStart::new(
+            [predicate!(x == 4), predicate!(y == 7)],
+            IndependentVariableValueBrancher::new(InputOrder::new(&[x, y]), InDomainMin),
+        );
+
+        let decision = brancher.next_decision(&mut SelectionContext::new(
+            &assignments,
+            &mut TestRandom::default(),
+        ));
+        assert_eq!(decision, Some(predicate!(x == 4)));
+
+        assignments.increase_decision_level();
+        let _ = assignments.post_predicate(predicate!(x == 4), None);
+        let decision = brancher.next_decision(&mut SelectionContext::new(
+            &assignments,
+            &mut TestRandom::default(),
+        ));
+        assert_eq!(decision, Some(predicate!(y == 7)));
+    }
+
+    #[test]
+    fn assigned_hints_are_skipped_until_backtrack() {
+        let mut assignments = Assignments::default();
+        let x = assignments.grow(0, 10);
+
+        let mut brancher = WarmStart::new(
+            [predicate!(x == 4)],
+            IndependentVariableValueBrancher::new(InputOrder::new(&[x]), InDomainMin),
+        );
+
+        // The hint is falsified, so the wrapped brancher is used
+        assignments.increase_decision_level();
+        let _ = assignments.post_predicate(predicate!(x >= 5), None);
+        let decision = brancher.next_decision(&mut SelectionContext::new(
+            &assignments,
+            &mut TestRandom::default(),
+        ));
+        assert_eq!(decision, Some(predicate!(x <= 5)));
+
+        let _ = assignments.synchronise(0, usize::MAX, false);
+        brancher.on_backtrack();
+        let decision = brancher.next_decision(&mut SelectionContext::new(
+            &assignments,
+            &mut TestRandom::default(),
+        ));
+        assert_eq!(decision, Some(predicate!(x == 4)));
+    }
+
+    #[test]
+    fn hints_are_not_used_after_solution() {
+        let mut assignments = Assignments::default();
+        let x = assignments.grow(0, 10);
+
+        let mut brancher = WarmStart::new(
+            [predicate!(x == 4)],
+            IndependentVariableValueBrancher::new(InputOrder::new(&[x]), InDomainMin),
+        );
+
+        let mut solution = Assignments::default();
+        let _ = solution.grow(2, 2);
+        brancher.on_solution(SolutionReference::new(&solution));
+
+        let decision = brancher.next_decision(&mut SelectionContext::new(
+            &assignments,
+            &mut TestRandom::default(),
+        ));
+        assert_eq!(decision, Some(predicate!(x <= 0)));
+    }
+}
@@ -44,22 +44,25 @@ impl<'a> SelectionContext<'a> {
     /// Returns the difference between the upper-bound and the lower-bound of the provided
     /// [`IntegerVariable`]. Note that this is different from the number of values which are in the
     /// domain of `var` since this calculation does not take into account holes in the domain.
-    pub fn get_size_of_domain<Var: IntegerVariable>(&self, var: Var) -> i32 {
-        var.upper_bound(self.assignments) - var.lower_bound(self.assignments)
+    ///
+    /// The difference saturates at [`i64::MAX`].
+    pub fn get_size_of_domain<Var: IntegerVariable>(&self, var: Var) -> i64 {
+        var.upper_bound(self.assignments)
+            .saturating_sub(var.lower_bound(self.assignments))
     }
 
     /// Returns the lower bound of the provided [`IntegerVariable`]
-    pub fn lower_bound<Var: IntegerVariable>(&self, var: Var) -> i32 {
+    pub fn lower_bound<Var: IntegerVariable>(&self, var: Var) -> i64 {
         var.lower_bound(self.assignments)
     }
 
     /// Returns the upper bound of the provided [`IntegerVariable`]
-    pub fn upper_bound<Var: IntegerVariable>(&self, var: Var) -> i32 {
+    pub fn upper_bound<Var: IntegerVariable>(&self, var: Var) -> i64 {
         var.upper_bound(self.assignments)
     }
 
     /// Determines whether the provided value is in the domain of the provided [`IntegerVariable`]
-    pub fn contains<Var: IntegerVariable>(&self, var: Var, value: i32) -> bool {
+    pub fn contains<Var: IntegerVariable>(&self, var: Var, value: i64) -> bool {
         var.contains(self.assignments, value)
     }
 
@@ -80,7 +83,7 @@ impl<'a> SelectionContext<'a> {
 
     #[cfg(test)]
     /// Create an ['Assignments'] with the variables having the input bounds.
-    pub fn create_for_testing(domains: Vec<(i32, i32)>) -> Assignments {
+    pub fn create_for_testing(domains: Vec<(i64, i64)>) -> Assignments {
         let mut assignments = Assignments::default();
 
         for (lower_bound, upper_bound) in domains {
@@ -128,7 +128,7 @@ mod tests {
     #[test]
     fn test_selection_new_value() {
         let rng = TestRandom::default();
-        let mut breaker: RandomTieBreaker<i32, i32> =
+        let mut breaker: RandomTieBreaker<i64, i64> =
             RandomTieBreaker::new(Direction::Minimum, Box::new(rng));
 
         assert!(breaker.select().is_none());
@@ -144,7 +144,7 @@ mod tests {
     #[test]
     fn test_selection_between_values_chooses_maximum() {
         let rng = TestRandom::default();
-        let mut breaker: RandomTieBreaker<i32, i32> =
+        let mut breaker: RandomTieBreaker<i64, i64> =
             RandomTieBreaker::new(Direction::Maximum, Box::new(rng));
 
         breaker.consider(0, 5);
@@ -159,7 +159,7 @@ mod tests {
     #[test]
     fn test_selection_between_values_chooses_minimum() {
         let rng = TestRandom::default();
-        let mut breaker: RandomTieBreaker<i32, i32> =
+        let mut breaker: RandomTieBreaker<i64, i64> =
             RandomTieBreaker::new(Direction::Minimum, Box::new(rng));
 
         breaker.consider(0, 5);
@@ -177,7 +177,7 @@ mod tests {
             bools: vec![true],
             ..Default::default()
         };
-        let mut breaker: RandomTieBreaker<i32, i32> =
+        let mut breaker: RandomTieBreaker<i64, i64> =
             RandomTieBreaker::new(Direction::Maximum, Box::new(rng));
 
         breaker.consider(0, 5);
@@ -196,7 +196,7 @@ mod tests {
             bools: vec![false],
             ..Default::default()
         };
-        let mut breaker: RandomTieBreaker<i32, i32> =
+        let mut breaker: RandomTieBreaker<i64, i64> =
             RandomTieBreaker::new(Direction::Maximum, Box::new(rng));
 
         breaker.consider(0, 5);
@@ -40,7 +40,7 @@ impl<Var> ValueSelector<Var> for DynamicValueSelector<Var> {
         self.selector.on_solution(solution)
     }
 
-    fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i64) {
         self.selector.on_unassign_integer(variable, value)
     }
 
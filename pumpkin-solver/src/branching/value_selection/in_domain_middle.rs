@@ -23,7 +23,7 @@ impl<Var: IntegerVariable + Copy> ValueSelector<Var> for InDomainMiddle {
         decision_variable: Var,
     ) -> Predicate {
         let bound = context.lower_bound(decision_variable)
-            + (context.get_size_of_domain(decision_variable) as f64 / 2.0).floor() as i32;
+            + (context.get_size_of_domain(decision_variable) as f64 / 2.0).floor() as i64;
         pumpkin_assert_simple!(
             bound >= context.lower_bound(decision_variable)
                 && bound < context.upper_bound(decision_variable),
@@ -36,7 +36,7 @@ impl InDomainSplit {
         // Note that the domain of the variable should always have at least 2 values in it
         // (otherwise it should have been reported as fixed and not selected)
         let bound = context.lower_bound(decision_variable)
-            + (context.get_size_of_domain(decision_variable) as f64 / 2.0).floor() as i32;
+            + (context.get_size_of_domain(decision_variable) as f64 / 2.0).floor() as i64;
         pumpkin_assert_advanced!(
             bound >= context.lower_bound(decision_variable)
                 && bound < context.upper_bound(decision_variable),
@@ -18,7 +18,7 @@ impl ValueSelector<DomainId> for InDomainSplitRandom {
         decision_variable: DomainId,
     ) -> Predicate {
         let bound = context.lower_bound(decision_variable)
-            + (context.get_size_of_domain(decision_variable) as f64 / 2.0).floor() as i32;
+            + (context.get_size_of_domain(decision_variable) as f64 / 2.0).floor() as i64;
 
         if context.random().generate_bool(0.5) {
             predicate!(decision_variable >= bound)
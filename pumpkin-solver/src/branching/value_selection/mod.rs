@@ -20,6 +20,7 @@ mod out_domain_min;
 mod out_domain_random;
 mod random_splitter;
 mod reverse_in_domain_split;
+mod solution_guided;
 mod value_selector;
 
 pub use dynamic_value_selector::*;
@@ -37,4 +38,5 @@ pub use out_domain_min::*;
 pub use out_domain_random::*;
 pub use random_splitter::*;
 pub use reverse_in_domain_split::*;
+pub use solution_guided::*;
 pub use value_selector::ValueSelector;
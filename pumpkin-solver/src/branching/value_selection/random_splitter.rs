@@ -20,7 +20,7 @@ impl ValueSelector<DomainId> for RandomSplitter {
         // Randomly generate a value within the lower-bound and upper-bound
         let range =
             context.lower_bound(decision_variable)..context.upper_bound(decision_variable) + 1;
-        let bound = context.random().generate_i32_in_range(range);
+        let bound = context.random().generate_i64_in_range(range);
 
         // We need to handle two special cases:
         //
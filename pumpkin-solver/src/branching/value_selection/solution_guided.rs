@@ -0,0 +1,160 @@
+use super::ValueSelector;
+use crate::basic_types::ProblemSolution;
+use crate::basic_types::Solution;
+use crate::basic_types::SolutionReference;
+use crate::branching::brancher::BrancherEvent;
+use crate::branching::SelectionContext;
+use crate::engine::predicates::predicate::Predicate;
+use crate::engine::variables::DomainId;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// [`ValueSelector`] which assigns the provided variable to the value it has in the most recently
+/// found solution, based on solution-based phase saving \[1\].
+///
+/// When no solution has been found yet, or the value of the variable in the most recent solution
+/// is no longer in its domain, the value is selected by the provided backup [`ValueSelector`].
+/// This steers the search towards the neighbourhood of the incumbent solution, which is why this
+/// [`ValueSelector`] is mainly useful when optimising.
+///
+/// # Bibliography
+/// \[1\] E. Demirović, G. Chu, and P. J. Stuckey, ‘Solution-based phase saving for CP: A
+/// value-selection heuristic to simulate local search behavior in complete solvers’, in the
+/// proceedings of the Principles and Practice of Constraint Programming (CP 2018).
+#[derive(Debug)]
+pub struct SolutionGuided<ValueSelection> {
+    /// The most recently found solution or [`None`] if no solution has been found.
+    solution: Option<Solution>,
+    /// The [`ValueSelector`] which is used when the variable cannot be assigned the value it has
+    /// in the most recent solution.
+    backup_selector: ValueSelection,
+}
+
+impl<ValueSelection> SolutionGuided<ValueSelection> {
+    pub fn new(backup_selector: ValueSelection) -> Self {
+        Self {
+            solution: None,
+            backup_selector,
+        }
+    }
+}
+
+impl<Var, ValueSelection> ValueSelector<Var> for SolutionGuided<ValueSelection>
+where
+    Var: IntegerVariable + Copy + 'static,
+    ValueSelection: ValueSelector<Var>,
+{
+    fn select_value(
+        &mut self,
+        context: &mut SelectionContext,
+        decision_variable: Var,
+    ) -> Predicate {
+        if let Some(solution) = &self.solution {
+            // The variable could have been created after the solution was found, in which case
+            // it does not have a value in the solution
+            let is_in_solution = decision_variable
+                .domain_id()
+                .is_none_or(|domain| solution.contains_domain_id(domain));
+            if is_in_solution {
+                let value = solution.get_integer_value(decision_variable);
+                if context.contains(decision_variable, value) {
+                    return predicate!(decision_variable == value);
+                }
+            }
+        }
+
+        self.backup_selector
+            .select_value(context, decision_variable)
+    }
+
+    fn on_solution(&mut self, solution: SolutionReference) {
+        self.solution = Some(solution.into());
+        self.backup_selector.on_solution(solution);
+    }
+
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i64) {
+        self.backup_selector.on_unassign_integer(variable, value)
+    }
+
+    fn is_restart_pointless(&mut self) -> bool {
+        // Once a solution has been found, restarting allows the search to move towards it
+        self.solution.is_none() && self.backup_selector.is_restart_pointless()
+    }
+
+    fn subscribe_to_events(&self) -> Vec<BrancherEvent> {
+        [BrancherEvent::Solution]
+            .into_iter()
+            .chain(self.backup_selector.subscribe_to_events())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SolutionGuided;
+    use crate::basic_types::tests::TestRandom;
+    use crate::basic_types::SolutionReference;
+    use crate::branching::value_selection::InDomainMin;
+    use crate::branching::value_selection::ValueSelector;
+    use crate::branching::SelectionContext;
+    use crate::engine::variables::DomainId;
+    use crate::engine::Assignments;
+    use crate::predicate;
+
+    #[test]
+    fn backup_is_used_without_solution() {
+        let assignments = SelectionContext::create_for_testing(vec![(0, 10)]);
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(&assignments, &mut test_rng);
+        let domain_ids = context.get_domains().collect::<Vec<_>>();
+
+        let mut selector = SolutionGuided::new(InDomainMin);
+        let selected_predicate = selector.select_value(&mut context, domain_ids[0]);
+        assert_eq!(selected_predicate, predicate!(domain_ids[0] <= 0));
+    }
+
+    #[test]
+    fn value_in_solution_is_selected() {
+        let assignments = SelectionContext::create_for_testing(vec![(0, 10)]);
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(&assignments, &mut test_rng);
+        let domain_ids = context.get_domains().collect::<Vec<_>>();
+
+        let solution = SelectionContext::create_for_testing(vec![(7, 7)]);
+        let mut selector = SolutionGuided::new(InDomainMin);
+        ValueSelector::<DomainId>::on_solution(&mut selector, SolutionReference::new(&solution));
+
+        let selected_predicate = selector.select_value(&mut context, domain_ids[0]);
+        assert_eq!(selected_predicate, predicate!(domain_ids[0] == 7));
+    }
+
+    #[test]
+    fn backup_is_used_when_value_is_not_in_domain() {
+        let assignments = SelectionContext::create_for_testing(vec![(0, 5)]);
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(&assignments, &mut test_rng);
+        let domain_ids = context.get_domains().collect::<Vec<_>>();
+
+        let solution = SelectionContext::create_for_testing(vec![(7, 7)]);
+        let mut selector = SolutionGuided::new(InDomainMin);
+        ValueSelector::<DomainId>::on_solution(&mut selector, SolutionReference::new(&solution));
+
+        let selected_predicate = selector.select_value(&mut context, domain_ids[0]);
+        assert_eq!(selected_predicate, predicate!(domain_ids[0] <= 0));
+    }
+
+    #[test]
+    fn backup_is_used_for_variables_created_after_solution() {
+        let mut assignments = Assignments::default();
+        let solution = assignments.clone();
+        let x = assignments.grow(0, 10);
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(&assignments, &mut test_rng);
+
+        let mut selector = SolutionGuided::new(InDomainMin);
+        ValueSelector::<DomainId>::on_solution(&mut selector, SolutionReference::new(&solution));
+
+        let selected_predicate = selector.select_value(&mut context, x);
+        assert_eq!(selected_predicate, predicate!(x <= 0));
+    }
+}
@@ -33,7 +33,7 @@ pub trait ValueSelector<Var> {
     ///
     /// To receive information about this event, use [`BrancherEvent::UnassignInteger`] in
     /// [`Self::subscribe_to_events`]
-    fn on_unassign_integer(&mut self, _variable: DomainId, _value: i32) {}
+    fn on_unassign_integer(&mut self, _variable: DomainId, _value: i64) {}
 
     /// This method is called when a solution is found; either when iterating over all solutions in
     /// the case of a satisfiable problem or on solutions of increasing quality when solving an
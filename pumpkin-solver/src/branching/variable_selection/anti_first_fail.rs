@@ -26,7 +26,7 @@ impl<Var, TieBreaking> std::fmt::Debug for AntiFirstFail<Var, TieBreaking> {
     }
 }
 
-impl<Var: Clone + 'static> AntiFirstFail<Var, InOrderTieBreaker<Var, i32>> {
+impl<Var: Clone + 'static> AntiFirstFail<Var, InOrderTieBreaker<Var, i64>> {
     pub fn new(variables: &[Var]) -> Self {
         if variables.is_empty() {
             warn!("The AntiFirstFail variable selector was not provided with any variables");
@@ -38,7 +38,7 @@ impl<Var: Clone + 'static> AntiFirstFail<Var, InOrderTieBreaker<Var, i32>> {
     }
 }
 
-impl<Var: Clone + 'static, TieBreaking: TieBreaker<Var, i32>> AntiFirstFail<Var, TieBreaking> {
+impl<Var: Clone + 'static, TieBreaking: TieBreaker<Var, i64>> AntiFirstFail<Var, TieBreaking> {
     pub fn with_tie_breaker(variables: &[Var], tie_breaker: TieBreaking) -> Self {
         pumpkin_assert_eq_simple!(
             tie_breaker.get_direction(),
@@ -60,7 +60,7 @@ impl<Var: Clone + 'static, TieBreaking: TieBreaker<Var, i32>> AntiFirstFail<Var,
     }
 }
 
-impl<TieBreaking: TieBreaker<DomainId, i32>> VariableSelector<DomainId>
+impl<TieBreaking: TieBreaker<DomainId, i64>> VariableSelector<DomainId>
     for AntiFirstFail<DomainId, TieBreaking>
 {
     fn select_variable(&mut self, context: &mut SelectionContext) -> Option<DomainId> {
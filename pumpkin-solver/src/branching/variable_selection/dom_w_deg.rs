@@ -0,0 +1,178 @@
+use log::warn;
+
+use crate::branching::brancher::BrancherEvent;
+use crate::branching::tie_breaking::Direction;
+use crate::branching::tie_breaking::InOrderTieBreaker;
+use crate::branching::tie_breaking::TieBreaker;
+use crate::branching::variable_selection::VariableSelector;
+use crate::branching::SelectionContext;
+use crate::containers::KeyedVec;
+use crate::containers::StorageKey;
+use crate::engine::variables::DomainId;
+use crate::pumpkin_assert_eq_simple;
+
+/// A [`VariableSelector`] which selects the variable with the smallest ratio between the size of
+/// its domain and its weighted degree, based on \[1\].
+///
+/// Whenever a constraint reports a conflict (see [`VariableSelector::on_propagator_failure`]), the
+/// weight of every variable in its scope is increased by 1; the weighted degree of a variable is 1
+/// plus its weight. Conflicts reported by learned nogoods are attributed to the variables in the
+/// nogood.
+///
+/// Unlike \[1\], conflicts of constraints without another unfixed variable are not ignored, which
+/// allows the weighted degrees to be maintained incrementally rather than recomputed from the
+/// constraints on every selection.
+///
+/// Uses a [`TieBreaker`] to break ties, the default is the [`InOrderTieBreaker`] but it is
+/// possible to construct the variable selector with a custom [`TieBreaker`] by using
+/// the method [`DomWDeg::with_tie_breaker`].
+///
+/// # Bibliography
+/// \[1\] F. Boussemart, F. Hemery, C. Lecoutre, and L. Sais, ‘Boosting systematic search by
+/// weighting constraints’, in ECAI, 2004, pp. 146–150.
+pub struct DomWDeg<Var, TieBreaking> {
+    variables: Vec<Var>,
+    tie_breaker: TieBreaking,
+    /// The number of conflicts reported by constraints containing the variable, indexed by its
+    /// [`DomainId`].
+    weights: KeyedVec<DomainId, u64>,
+}
+
+impl<Var, TieBreaking> std::fmt::Debug for DomWDeg<Var, TieBreaking> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DomWDeg").finish()
+    }
+}
+
+impl DomWDeg<DomainId, InOrderTieBreaker<DomainId, f64>> {
+    pub fn new(variables: &[DomainId]) -> Self {
+        Self::with_tie_breaker(variables, InOrderTieBreaker::new(Direction::Minimum))
+    }
+}
+
+impl<TieBreaking: TieBreaker<DomainId, f64>> DomWDeg<DomainId, TieBreaking> {
+    pub fn with_tie_breaker(variables: &[DomainId], tie_breaker: TieBreaking) -> Self {
+        pumpkin_assert_eq_simple!(
+            tie_breaker.get_direction(),
+            Direction::Minimum,
+            "The provided tie-breaker to DomWDeg attempts to find the Maximum value
+             instead of the Minimum value, please ensure that you have passed the correct tie-breaker");
+        if variables.is_empty() {
+            warn!("The DomWDeg variable selector was not provided with any variables");
+        }
+
+        let mut weights = KeyedVec::default();
+        let num_domains = variables
+            .iter()
+            .map(|variable| variable.index() + 1)
+            .max()
+            .unwrap_or_default();
+        weights.resize(num_domains, 0);
+
+        Self {
+            variables: variables.to_vec(),
+            tie_breaker,
+            weights,
+        }
+    }
+}
+
+impl<TieBreaking> VariableSelector<DomainId> for DomWDeg<DomainId, TieBreaking>
+where
+    TieBreaking: TieBreaker<DomainId, f64>,
+{
+    fn select_variable(&mut self, context: &mut SelectionContext) -> Option<DomainId> {
+        for &variable in &self.variables {
+            if context.is_integer_fixed(variable) {
+                continue;
+            }
+
+            let domain_size = context.get_size_of_domain(variable) as f64;
+            let weighted_degree = (1 + self.weights[variable]) as f64;
+            self.tie_breaker
+                .consider(variable, domain_size / weighted_degree);
+        }
+        self.tie_breaker.select()
+    }
+
+    fn on_propagator_failure(&mut self, scope: &[DomainId]) {
+        for &variable in scope {
+            if variable.index() < self.weights.len() {
+                self.weights[variable] += 1;
+            }
+        }
+    }
+
+    fn is_restart_pointless(&mut self) -> bool {
+        false
+    }
+
+    fn subscribe_to_events(&self) -> Vec<BrancherEvent> {
+        vec![BrancherEvent::PropagatorFailure]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::tests::TestRandom;
+
+    #[test]
+    fn smallest_domain_is_selected_without_failures() {
+        let assignments = SelectionContext::create_for_testing(vec![(0, 10), (5, 10)]);
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(&assignments, &mut test_rng);
+        let integer_variables = context.get_domains().collect::<Vec<_>>();
+
+        let mut strategy = DomWDeg::new(&integer_variables);
+        let selected = strategy.select_variable(&mut context);
+        assert_eq!(selected, Some(integer_variables[1]));
+    }
+
+    #[test]
+    fn failures_increase_weighted_degree() {
+        let assignments = SelectionContext::create_for_testing(vec![(0, 10), (5, 10), (0, 10)]);
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(&assignments, &mut test_rng);
+        let integer_variables = context.get_domains().collect::<Vec<_>>();
+
+        let mut strategy = DomWDeg::new(&integer_variables);
+        for _ in 0..2 {
+            strategy.on_propagator_failure(&[integer_variables[0], integer_variables[2]]);
+        }
+
+        // The ratio of the first variable is now 11 / 3, and that of the second is 6 / 1
+        let selected = strategy.select_variable(&mut context);
+        assert_eq!(selected, Some(integer_variables[0]));
+    }
+
+    #[test]
+    fn constraints_without_other_unfixed_variables_are_counted() {
+        let assignments = SelectionContext::create_for_testing(vec![(0, 10), (5, 5), (0, 6)]);
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(&assignments, &mut test_rng);
+        let integer_variables = context.get_domains().collect::<Vec<_>>();
+
+        let mut strategy = DomWDeg::new(&integer_variables);
+        for _ in 0..2 {
+            strategy.on_propagator_failure(&integer_variables[..2]);
+        }
+
+        // Even though the only other variable of the failing constraint is fixed, the ratio of the
+        // first variable is now 11 / 3, while that of the third is 7 / 1
+        let selected = strategy.select_variable(&mut context);
+        assert_eq!(selected, Some(integer_variables[0]));
+    }
+
+    #[test]
+    fn fixed_variables_are_not_selected() {
+        let assignments = SelectionContext::create_for_testing(vec![(10, 10), (20, 20)]);
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(&assignments, &mut test_rng);
+        let integer_variables = context.get_domains().collect::<Vec<_>>();
+
+        let mut strategy = DomWDeg::new(&integer_variables);
+        let selected = strategy.select_variable(&mut context);
+        assert!(selected.is_none());
+    }
+}
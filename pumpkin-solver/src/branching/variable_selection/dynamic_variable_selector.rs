@@ -35,11 +35,15 @@ impl<Var> VariableSelector<Var> for DynamicVariableSelector<Var> {
         self.selector.on_appearance_in_conflict_predicate(predicate)
     }
 
+    fn on_propagator_failure(&mut self, scope: &[DomainId]) {
+        self.selector.on_propagator_failure(scope)
+    }
+
     fn on_conflict(&mut self) {
         self.selector.on_conflict()
     }
 
-    fn on_unassign_integer(&mut self, variable: DomainId, value: i32) {
+    fn on_unassign_integer(&mut self, variable: DomainId, value: i64) {
         self.selector.on_unassign_integer(variable, value)
     }
 
@@ -26,7 +26,7 @@ impl<Var, TieBreaking> std::fmt::Debug for FirstFail<Var, TieBreaking> {
     }
 }
 
-impl<Var: Clone> FirstFail<Var, InOrderTieBreaker<Var, i32>> {
+impl<Var: Clone> FirstFail<Var, InOrderTieBreaker<Var, i64>> {
     pub fn new(variables: &[Var]) -> Self {
         if variables.is_empty() {
             warn!("The FirstFail variable selector was not provided with any variables");
@@ -38,7 +38,7 @@ impl<Var: Clone> FirstFail<Var, InOrderTieBreaker<Var, i32>> {
     }
 }
 
-impl<Var: Clone + 'static, TieBreaking: TieBreaker<Var, i32>> FirstFail<Var, TieBreaking> {
+impl<Var: Clone + 'static, TieBreaking: TieBreaker<Var, i64>> FirstFail<Var, TieBreaking> {
     pub fn with_tie_breaker(variables: &[Var], tie_breaker: TieBreaking) -> Self {
         pumpkin_assert_eq_simple!(
             tie_breaker.get_direction(),
@@ -62,7 +62,7 @@ impl<Var: Clone + 'static, TieBreaking: TieBreaker<Var, i32>> FirstFail<Var, Tie
 
 impl<TieBreaking> VariableSelector<DomainId> for FirstFail<DomainId, TieBreaking>
 where
-    TieBreaking: TieBreaker<DomainId, i32>,
+    TieBreaking: TieBreaker<DomainId, i64>,
 {
     fn select_variable(&mut self, context: &mut SelectionContext) -> Option<DomainId> {
         self.variables
@@ -0,0 +1,219 @@
+use log::warn;
+
+use crate::branching::brancher::BrancherEvent;
+use crate::branching::tie_breaking::Direction;
+use crate::branching::tie_breaking::InOrderTieBreaker;
+use crate::branching::tie_breaking::TieBreaker;
+use crate::branching::variable_selection::VariableSelector;
+use crate::branching::SelectionContext;
+use crate::engine::variables::DomainId;
+use crate::pumpkin_assert_eq_simple;
+
+/// A [`VariableSelector`] which selects the variable with the largest impact, based on \[1\].
+///
+/// The impact of a decision is the relative reduction of the search space caused by the
+/// decision and its propagation; the search space is the product of the domain sizes of the
+/// variables provided to the selector. A decision which leads to a conflict has an impact of 1.
+/// The impact of a variable is the average impact of the decisions made on it (rather than
+/// measuring the impact of every individual value as in \[1\]), where variables which have not
+/// been branched on yet are assumed to have an impact of 1; this ensures that the impact of every
+/// variable is measured at some point.
+///
+/// Uses a [`TieBreaker`] to break ties, the default is the [`InOrderTieBreaker`] but it is
+/// possible to construct the variable selector with a custom [`TieBreaker`] by using
+/// the method [`Impact::with_tie_breaker`].
+///
+/// # Bibliography
+/// \[1\] P. Refalo, ‘Impact-based search strategies for constraint programming’, in Principles
+/// and Practice of Constraint Programming - CP 2004, 2004, pp. 557–571.
+pub struct Impact<Var, TieBreaking> {
+    variables: Vec<Var>,
+    tie_breaker: TieBreaking,
+    /// The average impact of the decisions on every variable.
+    impacts: Vec<f64>,
+    /// The number of decisions on every variable for which the impact has been measured.
+    num_measurements: Vec<u32>,
+    /// The most recently selected variable, for which the impact has not yet been measured.
+    pending_measurement: Option<PendingMeasurement>,
+    /// Whether a backtrack has occurred since the most recent selection; in that case, the
+    /// search space can no longer be compared to that before the decision.
+    has_backtracked: bool,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct PendingMeasurement {
+    /// The index of the selected variable.
+    index: usize,
+    /// The logarithm of the size of the search space before the decision.
+    log_search_space: f64,
+}
+
+impl<Var, TieBreaking> std::fmt::Debug for Impact<Var, TieBreaking> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Impact").finish()
+    }
+}
+
+impl Impact<DomainId, InOrderTieBreaker<DomainId, f64>> {
+    pub fn new(variables: &[DomainId]) -> Self {
+        Self::with_tie_breaker(variables, InOrderTieBreaker::new(Direction::Maximum))
+    }
+}
+
+impl<TieBreaking: TieBreaker<DomainId, f64>> Impact<DomainId, TieBreaking> {
+    pub fn with_tie_breaker(variables: &[DomainId], tie_breaker: TieBreaking) -> Self {
+        pumpkin_assert_eq_simple!(
+            tie_breaker.get_direction(),
+            Direction::Maximum,
+            "The provided tie-breaker to Impact attempts to find the Minimum value
+             instead of the Maximum value, please ensure that you have passed the correct tie-breaker");
+        if variables.is_empty() {
+            warn!("The Impact variable selector was not provided with any variables");
+        }
+
+        Self {
+            variables: variables.to_vec(),
+            tie_breaker,
+            impacts: vec![1.0; variables.len()],
+            num_measurements: vec![0; variables.len()],
+            pending_measurement: None,
+            has_backtracked: false,
+        }
+    }
+
+    /// Returns the logarithm of the size of the search space spanned by the variables.
+    fn log_search_space(&self, context: &SelectionContext) -> f64 {
+        self.variables
+            .iter()
+            .map(|&variable| ((context.get_size_of_domain(variable) + 1) as f64).ln())
+            .sum()
+    }
+
+    /// Updates the average impact of the variable at the provided index.
+    fn record_impact(&mut self, index: usize, impact: f64) {
+        self.num_measurements[index] += 1;
+        let num_measurements = self.num_measurements[index] as f64;
+        self.impacts[index] += (impact - self.impacts[index]) / num_measurements;
+    }
+}
+
+impl<TieBreaking> VariableSelector<DomainId> for Impact<DomainId, TieBreaking>
+where
+    TieBreaking: TieBreaker<DomainId, f64>,
+{
+    fn select_variable(&mut self, context: &mut SelectionContext) -> Option<DomainId> {
+        let log_search_space = self.log_search_space(context);
+
+        if let Some(pending_measurement) = self.pending_measurement.take() {
+            if !self.has_backtracked {
+                let impact = 1.0 - (log_search_space - pending_measurement.log_search_space).exp();
+                self.record_impact(pending_measurement.index, impact);
+            }
+        }
+        self.has_backtracked = false;
+
+        self.variables
+            .iter()
+            .enumerate()
+            .filter(|(_, variable)| !context.is_integer_fixed(**variable))
+            .for_each(|(index, &variable)| {
+                self.tie_breaker.consider(variable, self.impacts[index]);
+            });
+        let selected = self.tie_breaker.select()?;
+
+        let index = self
+            .variables
+            .iter()
+            .position(|&variable| variable == selected)
+            .expect("the selected variable should be one of the provided variables");
+        self.pending_measurement = Some(PendingMeasurement {
+            index,
+            log_search_space,
+        });
+
+        Some(selected)
+    }
+
+    fn on_conflict(&mut self) {
+        // The decision (and its propagation) has led to a conflict; note that this event is
+        // called after the backtrack which resolves the conflict
+        if let Some(pending_measurement) = self.pending_measurement.take() {
+            self.record_impact(pending_measurement.index, 1.0);
+        }
+    }
+
+    fn on_backtrack(&mut self) {
+        self.has_backtracked = true;
+    }
+
+    fn is_restart_pointless(&mut self) -> bool {
+        false
+    }
+
+    fn subscribe_to_events(&self) -> Vec<BrancherEvent> {
+        vec![BrancherEvent::Conflict, BrancherEvent::Backtrack]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::tests::TestRandom;
+
+    #[test]
+    fn variable_with_largest_impact_is_selected() {
+        let mut assignments = SelectionContext::create_for_testing(vec![(0, 9), (0, 9)]);
+        let mut test_rng = TestRandom::default();
+        let integer_variables = assignments.get_domains().collect::<Vec<_>>();
+        let mut strategy = Impact::new(&integer_variables);
+
+        {
+            let mut context = SelectionContext::new(&assignments, &mut test_rng);
+            let selected = strategy.select_variable(&mut context);
+            assert_eq!(selected, Some(integer_variables[0]));
+        }
+
+        // The decision halves the search space, so the first variable has an impact of 0.5,
+        // while the second variable has not been measured yet
+        assignments.increase_decision_level();
+        let _ = assignments.tighten_upper_bound(integer_variables[0], 4, None);
+        {
+            let mut context = SelectionContext::new(&assignments, &mut test_rng);
+            let selected = strategy.select_variable(&mut context);
+            assert_eq!(selected, Some(integer_variables[1]));
+        }
+
+        // The decision on the second variable leads to a conflict, giving it an impact of 1
+        strategy.on_backtrack();
+        strategy.on_conflict();
+        assert!((strategy.impacts[0] - 0.5).abs() < 1e-9);
+        assert_eq!(strategy.impacts[1], 1.0);
+    }
+
+    #[test]
+    fn impact_is_not_measured_after_backtracking() {
+        let assignments = SelectionContext::create_for_testing(vec![(0, 9), (0, 9)]);
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(&assignments, &mut test_rng);
+        let integer_variables = context.get_domains().collect::<Vec<_>>();
+        let mut strategy = Impact::new(&integer_variables);
+
+        let _ = strategy.select_variable(&mut context);
+        strategy.on_backtrack();
+        let _ = strategy.select_variable(&mut context);
+
+        assert_eq!(strategy.num_measurements, vec![0, 0]);
+    }
+
+    #[test]
+    fn fixed_variables_are_not_selected() {
+        let assignments = SelectionContext::create_for_testing(vec![(10, 10), (20, 20)]);
+        let mut test_rng = TestRandom::default();
+        let mut context = SelectionContext::new(&assignments, &mut test_rng);
+        let integer_variables = context.get_domains().collect::<Vec<_>>();
+
+        let mut strategy = Impact::new(&integer_variables);
+        let selected = strategy.select_variable(&mut context);
+        assert!(selected.is_none());
+    }
+}
@@ -25,7 +25,7 @@ impl<Var, TieBreaking> std::fmt::Debug for Largest<Var, TieBreaking> {
     }
 }
 
-impl<Var: Clone + 'static> Largest<Var, InOrderTieBreaker<Var, i32>> {
+impl<Var: Clone + 'static> Largest<Var, InOrderTieBreaker<Var, i64>> {
     pub fn new(variables: &[Var]) -> Self {
         if variables.is_empty() {
             warn!("The Largest variable selector was not provided with any variables");
@@ -41,7 +41,7 @@ impl<Var: Clone + 'static> Largest<Var, InOrderTieBreaker<Var, i32>> {
     }
 }
 
-impl<Var: Clone + 'static, TieBreaking: TieBreaker<Var, i32>> Largest<Var, TieBreaking> {
+impl<Var: Clone + 'static, TieBreaking: TieBreaker<Var, i64>> Largest<Var, TieBreaking> {
     pub fn with_tie_breaker(variables: &[Var], tie_breaker: TieBreaking) -> Self {
         pumpkin_assert_eq_simple!(
             tie_breaker.get_direction(),
@@ -65,7 +65,7 @@ impl<Var: Clone + 'static, TieBreaking: TieBreaker<Var, i32>> Largest<Var, TieBr
 
 impl<TieBreaking> VariableSelector<DomainId> for Largest<DomainId, TieBreaking>
 where
-    TieBreaking: TieBreaker<DomainId, i32>,
+    TieBreaking: TieBreaker<DomainId, i64>,
 {
     fn select_variable(&mut self, context: &mut SelectionContext) -> Option<DomainId> {
         self.variables
@@ -30,7 +30,7 @@ impl<Var, TieBreaking> std::fmt::Debug for MaxRegret<Var, TieBreaking> {
     }
 }
 
-impl<Var: Clone + 'static> MaxRegret<Var, InOrderTieBreaker<Var, i32>> {
+impl<Var: Clone + 'static> MaxRegret<Var, InOrderTieBreaker<Var, i64>> {
     pub fn new(variables: &[Var]) -> Self {
         if variables.is_empty() {
             warn!("The MaxRegret variable selector was not provided with any variables");
@@ -46,7 +46,7 @@ impl<Var: Clone + 'static> MaxRegret<Var, InOrderTieBreaker<Var, i32>> {
     }
 }
 
-impl<Var: Clone + 'static, TieBreaking: TieBreaker<Var, i32>> MaxRegret<Var, TieBreaking> {
+impl<Var: Clone + 'static, TieBreaking: TieBreaker<Var, i64>> MaxRegret<Var, TieBreaking> {
     pub fn with_tie_breaker(variables: &[Var], tie_breaker: TieBreaking) -> Self {
         pumpkin_assert_eq_simple!(
             tie_breaker.get_direction(),
@@ -70,7 +70,7 @@ impl<Var: Clone + 'static, TieBreaking: TieBreaker<Var, i32>> MaxRegret<Var, Tie
 
 impl<TieBreaking> VariableSelector<DomainId> for MaxRegret<DomainId, TieBreaking>
 where
-    TieBreaking: TieBreaker<DomainId, i32>,
+    TieBreaking: TieBreaker<DomainId, i64>,
 {
     fn select_variable(&mut self, context: &mut SelectionContext) -> Option<DomainId> {
         self.variables
@@ -6,8 +6,10 @@
 //! [`VariableSelector`] should only select variables which have a domain of size 2 or larger.
 
 mod anti_first_fail;
+mod dom_w_deg;
 mod dynamic_variable_selector;
 mod first_fail;
+mod impact;
 mod input_order;
 mod largest;
 mod max_regret;
@@ -19,8 +21,10 @@ mod smallest;
 mod variable_selector;
 
 pub use anti_first_fail::*;
+pub use dom_w_deg::*;
 pub use dynamic_variable_selector::*;
 pub use first_fail::*;
+pub use impact::*;
 pub use input_order::*;
 pub use largest::*;
 pub use max_regret::*;
@@ -30,9 +30,11 @@ impl<Var, TieBreaking> std::fmt::Debug for MostConstrained<Var, TieBreaking> {
     }
 }
 
-#[derive(PartialEq)]
-struct MostConstrainedValue {
-    domain_size: i32,
+/// The value which is used by [`MostConstrained`] to compare variables; it consists of the size
+/// of the domain and the number of attached constraints.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MostConstrainedValue {
+    domain_size: i64,
     number_of_attached_constraints: u32,
 }
 
@@ -50,7 +50,7 @@ impl VariableSelector<DomainId> for RandomSelector {
         Some(variable)
     }
 
-    fn on_unassign_integer(&mut self, variable: DomainId, _value: i32) {
+    fn on_unassign_integer(&mut self, variable: DomainId, _value: i64) {
         self.variables.insert(variable);
     }
 
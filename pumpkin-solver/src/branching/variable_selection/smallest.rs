@@ -25,7 +25,7 @@ impl<Var, TieBreaking> std::fmt::Debug for Smallest<Var, TieBreaking> {
     }
 }
 
-impl<Var: Clone> Smallest<Var, InOrderTieBreaker<Var, i32>> {
+impl<Var: Clone> Smallest<Var, InOrderTieBreaker<Var, i64>> {
     pub fn new(variables: &[Var]) -> Self {
         if variables.is_empty() {
             warn!("The Smallest variable selector was not provided with any variables");
@@ -37,7 +37,7 @@ impl<Var: Clone> Smallest<Var, InOrderTieBreaker<Var, i32>> {
     }
 }
 
-impl<Var: Clone + 'static, TieBreaking: TieBreaker<Var, i32>> Smallest<Var, TieBreaking> {
+impl<Var: Clone + 'static, TieBreaking: TieBreaker<Var, i64>> Smallest<Var, TieBreaking> {
     pub fn with_tie_breaker(variables: &[Var], tie_breaker: TieBreaking) -> Self {
         pumpkin_assert_eq_simple!(
             tie_breaker.get_direction(),
@@ -61,7 +61,7 @@ impl<Var: Clone + 'static, TieBreaking: TieBreaker<Var, i32>> Smallest<Var, TieB
 
 impl<TieBreaking> VariableSelector<DomainId> for Smallest<DomainId, TieBreaking>
 where
-    TieBreaking: TieBreaker<DomainId, i32>,
+    TieBreaking: TieBreaker<DomainId, i64>,
 {
     fn select_variable(&mut self, context: &mut SelectionContext) -> Option<DomainId> {
         self.variables
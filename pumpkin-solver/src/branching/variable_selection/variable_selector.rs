@@ -41,7 +41,7 @@ pub trait VariableSelector<Var> {
     ///
     /// To receive information about this event, use [`BrancherEvent::UnassignInteger`] in
     /// [`Self::subscribe_to_events`]
-    fn on_unassign_integer(&mut self, _variable: DomainId, _value: i32) {}
+    fn on_unassign_integer(&mut self, _variable: DomainId, _value: i64) {}
 
     /// A function which is called when a [`Predicate`] appears in a conflict during conflict
     /// analysis.
@@ -50,6 +50,14 @@ pub trait VariableSelector<Var> {
     /// [`BrancherEvent::AppearanceInConflictPredicate`] in [`Self::subscribe_to_events`]
     fn on_appearance_in_conflict_predicate(&mut self, _predicate: Predicate) {}
 
+    /// A function which is called when a propagator reports a conflict, where `scope` contains
+    /// the [`DomainId`]s which the failing propagator is registered to. The scope is sorted and
+    /// does not contain duplicates.
+    ///
+    /// To receive information about this event, use [`BrancherEvent::PropagatorFailure`] in
+    /// [`Self::subscribe_to_events`]
+    fn on_propagator_failure(&mut self, _scope: &[DomainId]) {}
+
     /// This method returns whether a restart is *currently* pointless for the [`VariableSelector`].
     ///
     /// For example, if a [`VariableSelector`] is using a static strategy (e.g. [`Smallest`]) then a
@@ -1,22 +1,69 @@
-use super::binary_not_equals;
+use std::num::NonZero;
+
 use super::Constraint;
+use crate::propagators::AllDifferentBoundsPropagator;
+use crate::propagators::AllDifferentConsistency;
+use crate::propagators::AllDifferentDomainPropagator;
 use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::ConstraintOperationError;
+use crate::Solver;
 
 /// Creates the [`Constraint`] that enforces that all the given `variables` are distinct.
+///
+/// The constraint is propagated using a bounds-consistent propagator (see
+/// [`AllDifferentConsistency::Bounds`]); use [`all_different_with_consistency`] to select a
+/// different level of consistency.
 pub fn all_different<Var: IntegerVariable + 'static>(
     variables: impl Into<Box<[Var]>>,
 ) -> impl Constraint {
-    let variables: Box<[Var]> = variables.into();
-    let mut constraints = Vec::new();
+    all_different_with_consistency(variables, AllDifferentConsistency::default())
+}
 
-    for i in 0..variables.len() {
-        for j in i + 1..variables.len() {
-            constraints.push(binary_not_equals(
-                variables[i].clone(),
-                variables[j].clone(),
-            ));
+/// Creates the [`Constraint`] that enforces that all the given `variables` are distinct, where
+/// the propagator enforces the provided [`AllDifferentConsistency`].
+pub fn all_different_with_consistency<Var: IntegerVariable + 'static>(
+    variables: impl Into<Box<[Var]>>,
+    consistency: AllDifferentConsistency,
+) -> impl Constraint {
+    AllDifferentConstraint {
+        variables: variables.into(),
+        consistency,
+    }
+}
+
+struct AllDifferentConstraint<Var> {
+    variables: Box<[Var]>,
+    consistency: AllDifferentConsistency,
+}
+
+impl<Var: IntegerVariable + 'static> Constraint for AllDifferentConstraint<Var> {
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        match self.consistency {
+            AllDifferentConsistency::Bounds => {
+                AllDifferentBoundsPropagator::new(self.variables).post(solver, tag)
+            }
+            AllDifferentConsistency::Domain => {
+                AllDifferentDomainPropagator::new(self.variables).post(solver, tag)
+            }
         }
     }
 
-    constraints
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        match self.consistency {
+            AllDifferentConsistency::Bounds => AllDifferentBoundsPropagator::new(self.variables)
+                .implied_by(solver, reification_literal, tag),
+            AllDifferentConsistency::Domain => AllDifferentDomainPropagator::new(self.variables)
+                .implied_by(solver, reification_literal, tag),
+        }
+    }
 }
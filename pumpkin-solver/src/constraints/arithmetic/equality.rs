@@ -14,7 +14,7 @@ use crate::Solver;
 /// Its negation is [`not_equals`].
 pub fn equals<Var: IntegerVariable + Clone + 'static>(
     terms: impl Into<Box<[Var]>>,
-    rhs: i32,
+    rhs: i64,
 ) -> impl NegatableConstraint {
     EqualConstraint {
         terms: terms.into(),
@@ -37,7 +37,7 @@ pub fn binary_equals<Var: IntegerVariable + 'static>(
 /// Its negation is [`equals`].
 pub fn not_equals<Var: IntegerVariable + Clone + 'static>(
     terms: impl Into<Box<[Var]>>,
-    rhs: i32,
+    rhs: i64,
 ) -> impl NegatableConstraint {
     equals(terms, rhs).negation()
 }
@@ -54,7 +54,7 @@ pub fn binary_not_equals<Var: IntegerVariable + 'static>(
 
 struct EqualConstraint<Var> {
     terms: Box<[Var]>,
-    rhs: i32,
+    rhs: i64,
 }
 
 impl<Var> Constraint for EqualConstraint<Var>
@@ -117,7 +117,7 @@ where
 
 struct NotEqualConstraint<Var> {
     terms: Box<[Var]>,
-    rhs: i32,
+    rhs: i64,
 }
 
 impl<Var> Constraint for NotEqualConstraint<Var>
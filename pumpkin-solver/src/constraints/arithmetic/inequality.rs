@@ -12,7 +12,7 @@ use crate::Solver;
 /// Its negation is `\sum terms_i > rhs`
 pub fn less_than_or_equals<Var: IntegerVariable + 'static>(
     terms: impl Into<Box<[Var]>>,
-    rhs: i32,
+    rhs: i64,
 ) -> impl NegatableConstraint {
     Inequality {
         terms: terms.into(),
@@ -42,7 +42,7 @@ pub fn binary_less_than<Var: IntegerVariable + 'static>(
 
 struct Inequality<Var> {
     terms: Box<[Var]>,
-    rhs: i32,
+    rhs: i64,
 }
 
 impl<Var: IntegerVariable + 'static> Constraint for Inequality<Var> {
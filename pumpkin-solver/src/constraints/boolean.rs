@@ -12,9 +12,9 @@ use crate::Solver;
 
 /// Creates the [`Constraint`] `\sum weights_i * bools_i <= rhs`.
 pub fn boolean_less_than_or_equals(
-    weights: impl Into<Box<[i32]>>,
+    weights: impl Into<Box<[i64]>>,
     bools: impl Into<Box<[Literal]>>,
-    rhs: i32,
+    rhs: i64,
 ) -> impl Constraint {
     BooleanLessThanOrEqual {
         weights: weights.into(),
@@ -25,7 +25,7 @@ pub fn boolean_less_than_or_equals(
 
 /// Creates the [`Constraint`] `\sum weights_i * bools_i == rhs`.
 pub fn boolean_equals(
-    weights: impl Into<Box<[i32]>>,
+    weights: impl Into<Box<[i64]>>,
     bools: impl Into<Box<[Literal]>>,
     rhs: DomainId,
 ) -> impl Constraint {
@@ -37,9 +37,9 @@ pub fn boolean_equals(
 }
 
 struct BooleanLessThanOrEqual {
-    weights: Box<[i32]>,
+    weights: Box<[i64]>,
     bools: Box<[Literal]>,
-    rhs: i32,
+    rhs: i64,
 }
 
 impl Constraint for BooleanLessThanOrEqual {
@@ -76,7 +76,7 @@ impl BooleanLessThanOrEqual {
 }
 
 struct BooleanEqual {
-    weights: Box<[i32]>,
+    weights: Box<[i64]>,
     bools: Box<[Literal]>,
     rhs: DomainId,
 }
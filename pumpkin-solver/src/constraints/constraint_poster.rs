@@ -41,7 +41,16 @@ impl<ConstraintImpl: Constraint> ConstraintPoster<'_, ConstraintImpl> {
     /// This method returns a [`ConstraintOperationError`] if the addition of the [`Constraint`] led
     /// to a root-level conflict.
     pub fn post(mut self) -> Result<(), ConstraintOperationError> {
-        self.constraint.take().unwrap().post(self.solver, self.tag)
+        let constraint = self.constraint.take().unwrap();
+        let tag = self.tag;
+
+        match self.solver.innermost_scope() {
+            // Within a scope, the constraint is implied by the activation literal of that scope
+            Some(activation_literal) => self
+                .solver
+                .without_scopes(|solver| constraint.implied_by(solver, activation_literal, tag)),
+            None => constraint.post(self.solver, tag),
+        }
     }
 
     /// Add the half-reified version of the [`Constraint`] to the [`Solver`]; i.e. post the
@@ -53,10 +62,16 @@ impl<ConstraintImpl: Constraint> ConstraintPoster<'_, ConstraintImpl> {
         mut self,
         reification_literal: Literal,
     ) -> Result<(), ConstraintOperationError> {
-        self.constraint
-            .take()
-            .unwrap()
-            .implied_by(self.solver, reification_literal, self.tag)
+        let constraint = self.constraint.take().unwrap();
+        let tag = self.tag;
+
+        match self.solver.innermost_scope() {
+            Some(activation_literal) => self.solver.without_scopes(|solver| {
+                let literal = conjunction_literal(solver, activation_literal, reification_literal)?;
+                constraint.implied_by(solver, literal, tag)
+            }),
+            None => constraint.implied_by(self.solver, reification_literal, tag),
+        }
     }
 }
 
@@ -67,13 +82,52 @@ impl<ConstraintImpl: NegatableConstraint> ConstraintPoster<'_, ConstraintImpl> {
     /// This method returns a [`ConstraintOperationError`] if the addition of the [`Constraint`] led
     /// to a root-level conflict.
     pub fn reify(mut self, reification_literal: Literal) -> Result<(), ConstraintOperationError> {
-        self.constraint
-            .take()
-            .unwrap()
-            .reify(self.solver, reification_literal, self.tag)
+        let constraint = self.constraint.take().unwrap();
+        let tag = self.tag;
+
+        match self.solver.innermost_scope() {
+            // Within a scope, both directions of the reification are implied by the activation
+            // literal of that scope
+            Some(activation_literal) => self.solver.without_scopes(|solver| {
+                let negation = constraint.negation();
+
+                let literal = conjunction_literal(solver, activation_literal, reification_literal)?;
+                constraint.implied_by(solver, literal, tag)?;
+
+                let literal =
+                    conjunction_literal(solver, activation_literal, !reification_literal)?;
+                negation.implied_by(solver, literal, tag)
+            }),
+            None => constraint.reify(self.solver, reification_literal, tag),
+        }
     }
 }
 
+/// Creates a new literal `l` such that `l <-> (activation_literal /\ reification_literal)`.
+fn conjunction_literal(
+    solver: &mut Solver,
+    activation_literal: Literal,
+    reification_literal: Literal,
+) -> Result<Literal, ConstraintOperationError> {
+    let literal = solver.new_literal();
+
+    solver.add_clause([
+        (!activation_literal).get_true_predicate(),
+        (!reification_literal).get_true_predicate(),
+        literal.get_true_predicate(),
+    ])?;
+    solver.add_clause([
+        (!literal).get_true_predicate(),
+        activation_literal.get_true_predicate(),
+    ])?;
+    solver.add_clause([
+        (!literal).get_true_predicate(),
+        reification_literal.get_true_predicate(),
+    ])?;
+
+    Ok(literal)
+}
+
 impl<ConstraintImpl> Drop for ConstraintPoster<'_, ConstraintImpl> {
     fn drop(&mut self) {
         if self.constraint.is_some() {
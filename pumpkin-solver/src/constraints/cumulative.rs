@@ -5,11 +5,14 @@ use super::Constraint;
 use crate::options::CumulativePropagationMethod;
 use crate::propagators::ArgTask;
 use crate::propagators::CumulativeOptions;
+use crate::propagators::EnergeticReasoningPropagator;
+use crate::propagators::TimeTableEdgeFindingPropagator;
 use crate::propagators::TimeTableOverIntervalIncrementalPropagator;
 use crate::propagators::TimeTableOverIntervalPropagator;
 use crate::propagators::TimeTablePerPointIncrementalPropagator;
 use crate::propagators::TimeTablePerPointPropagator;
 use crate::pumpkin_assert_simple;
+use crate::variables::DomainId;
 use crate::variables::IntegerVariable;
 use crate::variables::Literal;
 use crate::ConstraintOperationError;
@@ -18,11 +21,19 @@ use crate::Solver;
 /// Creates the [Cumulative](https://sofdem.github.io/gccat/gccat/Ccumulative.html) [`Constraint`].
 ///
 /// This constraint ensures that at no point in time, the cumulative resource usage of the tasks
-/// exceeds `bound`.
+/// exceeds `resource_capacity`.
+///
+/// The durations, the resource requirements and the resource capacity can either be constants
+/// (i.e. [`i64`]s) or integer variables (see [`CumulativeArgument`]). Besides the start times, the
+/// time-table reasoning also propagates these variables: the lower-bound of the capacity is raised
+/// to the maximum height of the profile, and the upper-bounds of the resource requirements and the
+/// durations are lowered such that the tasks do not overflow the profile.
 ///
 /// The implementation uses a form of time-table reasoning (for an example of this type of
 /// reasoning, see \[1], note that it does **not** implement the specific algorithm in the paper
 /// but that the reasoning used is the same).
+/// Alternatively, time-table edge-finding or energetic reasoning can be used by selecting the
+/// corresponding [`CumulativePropagationMethod`] in the options.
 ///
 /// The length of `start_times`, `durations` and `resource_requirements` should be the same; if
 /// this is not the case then this method will panic.
@@ -73,7 +84,7 @@ use crate::Solver;
 ///
 /// // We check whether the result was feasible
 /// if let SatisfactionResult::Satisfiable(solution) = result {
-///     let horizon = durations.iter().sum::<i32>();
+///     let horizon = durations.iter().sum::<i64>();
 ///     let start_times = [start_0, start_1, start_2];
 ///
 ///     // Now we check whether the resource constraint is satisfied at each time-point t
@@ -91,7 +102,7 @@ use crate::Solver;
 ///                     None
 ///                 }
 ///             })
-///             .sum::<i32>();
+///             .sum::<i64>();
 ///         // Then we check whether the resource usage at the current time point is lower than
 ///         // the resource capacity
 ///         resource_usage_at_t <= resource_capacity
@@ -119,20 +130,23 @@ use crate::Solver;
 /// cumulative constraint’, in Principles and Practice of Constraint Programming: 21st
 /// International Conference, CP 2015, Cork, Ireland, August 31--September 4, 2015, Proceedings
 /// 21, 2015, pp. 149–157.
-pub fn cumulative<StartTimes, Durations, ResourceRequirements>(
+pub fn cumulative<StartTimes, Durations, ResourceRequirements, Capacity>(
     start_times: StartTimes,
     durations: Durations,
     resource_requirements: ResourceRequirements,
-    resource_capacity: i32,
+    resource_capacity: Capacity,
 ) -> impl Constraint
 where
     StartTimes: IntoIterator,
     StartTimes::Item: IntegerVariable + Debug + 'static,
     StartTimes::IntoIter: ExactSizeIterator,
-    Durations: IntoIterator<Item = i32>,
+    Durations: IntoIterator,
+    Durations::Item: CumulativeArgument,
     Durations::IntoIter: ExactSizeIterator,
-    ResourceRequirements: IntoIterator<Item = i32>,
+    ResourceRequirements: IntoIterator,
+    ResourceRequirements::Item: CumulativeArgument,
     ResourceRequirements::IntoIter: ExactSizeIterator,
+    Capacity: CumulativeArgument,
 {
     cumulative_with_options(
         start_times,
@@ -147,21 +161,24 @@ where
 /// with the provided [`CumulativeOptions`].
 ///
 /// See the documentation of [`cumulative`] for more information about the constraint.
-pub fn cumulative_with_options<StartTimes, Durations, ResourceRequirements>(
+pub fn cumulative_with_options<StartTimes, Durations, ResourceRequirements, Capacity>(
     start_times: StartTimes,
     durations: Durations,
     resource_requirements: ResourceRequirements,
-    resource_capacity: i32,
+    resource_capacity: Capacity,
     options: CumulativeOptions,
 ) -> impl Constraint
 where
     StartTimes: IntoIterator,
     StartTimes::Item: IntegerVariable + Debug + 'static,
     StartTimes::IntoIter: ExactSizeIterator,
-    Durations: IntoIterator<Item = i32>,
+    Durations: IntoIterator,
+    Durations::Item: CumulativeArgument,
     Durations::IntoIter: ExactSizeIterator,
-    ResourceRequirements: IntoIterator<Item = i32>,
+    ResourceRequirements: IntoIterator,
+    ResourceRequirements::Item: CumulativeArgument,
     ResourceRequirements::IntoIter: ExactSizeIterator,
+    Capacity: CumulativeArgument,
 {
     let start_times = start_times.into_iter();
     let durations = durations.into_iter();
@@ -172,8 +189,8 @@ where
         "The number of start variables, durations and resource requirements should be the same!"
     );
 
-    CumulativeConstraint::new(
-        &start_times
+    CumulativeArguments {
+        tasks: start_times
             .zip(durations)
             .zip(resource_requirements)
             .map(|((start_time, duration), resource_requirement)| ArgTask {
@@ -181,20 +198,119 @@ where
                 processing_time: duration,
                 resource_usage: resource_requirement,
             })
-            .collect::<Vec<_>>(),
+            .collect(),
         resource_capacity,
         options,
-    )
+    }
 }
 
-struct CumulativeConstraint<Var> {
-    tasks: Vec<ArgTask<Var>>,
-    resource_capacity: i32,
+/// A duration, a resource requirement or the resource capacity of the [`cumulative`], which is
+/// either a constant or an [`IntegerVariable`].
+///
+/// A constant is represented by a fixed [`DomainId`] which is created when the constraint is
+/// posted. This means that the propagators can reason over constants and variables in the same way,
+/// and that a conflict on a constant is reported like a conflict on any other variable.
+pub trait CumulativeArgument {
+    type Variable: IntegerVariable + Debug + 'static;
+
+    /// Get the variable which represents this argument, creating it in the `solver` if needed.
+    fn into_variable(self, solver: &mut Solver) -> Self::Variable;
+}
+
+impl CumulativeArgument for i64 {
+    type Variable = DomainId;
+
+    fn into_variable(self, solver: &mut Solver) -> DomainId {
+        // Constants are named by their value, in the same way as in the FlatZinc compiler.
+        solver.new_named_bounded_integer(self, self, self.to_string())
+    }
+}
+
+impl<Var: IntegerVariable + Debug + 'static> CumulativeArgument for Var {
+    type Variable = Var;
+
+    fn into_variable(self, _solver: &mut Solver) -> Var {
+        self
+    }
+}
+
+/// The arguments of the cumulative, which are turned into variables when the constraint is posted.
+struct CumulativeArguments<Var, P, R, C> {
+    tasks: Vec<ArgTask<Var, P, R>>,
+    resource_capacity: C,
     options: CumulativeOptions,
 }
 
-impl<Var: IntegerVariable + 'static> CumulativeConstraint<Var> {
-    fn new(tasks: &[ArgTask<Var>], resource_capacity: i32, options: CumulativeOptions) -> Self {
+impl<Var, P, R, C> CumulativeArguments<Var, P, R, C>
+where
+    Var: IntegerVariable + Debug + 'static,
+    P: CumulativeArgument,
+    R: CumulativeArgument,
+    C: CumulativeArgument,
+{
+    fn into_constraint(
+        self,
+        solver: &mut Solver,
+    ) -> CumulativeConstraint<Var, P::Variable, R::Variable, C::Variable> {
+        let tasks = self
+            .tasks
+            .into_iter()
+            .map(|task| ArgTask {
+                start_time: task.start_time,
+                processing_time: task.processing_time.into_variable(solver),
+                resource_usage: task.resource_usage.into_variable(solver),
+            })
+            .collect::<Vec<_>>();
+        let resource_capacity = self.resource_capacity.into_variable(solver);
+
+        CumulativeConstraint::new(&tasks, resource_capacity, self.options)
+    }
+}
+
+impl<Var, P, R, C> Constraint for CumulativeArguments<Var, P, R, C>
+where
+    Var: IntegerVariable + Debug + 'static,
+    P: CumulativeArgument,
+    R: CumulativeArgument,
+    C: CumulativeArgument,
+{
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        self.into_constraint(solver).post(solver, tag)
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        self.into_constraint(solver)
+            .implied_by(solver, reification_literal, tag)
+    }
+}
+
+struct CumulativeConstraint<Var, PVar, RVar, CVar> {
+    tasks: Vec<ArgTask<Var, PVar, RVar>>,
+    resource_capacity: CVar,
+    options: CumulativeOptions,
+}
+
+impl<Var, PVar, RVar, CVar> CumulativeConstraint<Var, PVar, RVar, CVar>
+where
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
+{
+    fn new(
+        tasks: &[ArgTask<Var, PVar, RVar>],
+        resource_capacity: CVar,
+        options: CumulativeOptions,
+    ) -> Self {
         Self {
             tasks: tasks.into(),
             resource_capacity,
@@ -203,7 +319,13 @@ impl<Var: IntegerVariable + 'static> CumulativeConstraint<Var> {
     }
 }
 
-impl<Var: IntegerVariable + 'static + Debug> Constraint for CumulativeConstraint<Var> {
+impl<Var, PVar, RVar, CVar> Constraint for CumulativeConstraint<Var, PVar, RVar, CVar>
+where
+    Var: IntegerVariable + 'static + Debug,
+    PVar: IntegerVariable + 'static + Debug,
+    RVar: IntegerVariable + 'static + Debug,
+    CVar: IntegerVariable + 'static + Debug,
+{
     fn post(
         self,
         solver: &mut Solver,
@@ -212,23 +334,23 @@ impl<Var: IntegerVariable + 'static + Debug> Constraint for CumulativeConstraint
         match self.options.propagation_method {
             CumulativePropagationMethod::TimeTablePerPoint => TimeTablePerPointPropagator::new(
                 &self.tasks,
-                self.resource_capacity,
+                self.resource_capacity.clone(),
                 self.options.propagator_options,
             )
             .post(solver, tag),
 
             CumulativePropagationMethod::TimeTablePerPointIncremental => {
-                TimeTablePerPointIncrementalPropagator::<Var, false>::new(
+                TimeTablePerPointIncrementalPropagator::<Var, false, PVar, RVar, CVar>::new(
                     &self.tasks,
-                    self.resource_capacity,
+                    self.resource_capacity.clone(),
                     self.options.propagator_options,
                 )
                 .post(solver, tag)
             }
             CumulativePropagationMethod::TimeTablePerPointIncrementalSynchronised => {
-                TimeTablePerPointIncrementalPropagator::<Var, true>::new(
+                TimeTablePerPointIncrementalPropagator::<Var, true, PVar, RVar, CVar>::new(
                     &self.tasks,
-                    self.resource_capacity,
+                    self.resource_capacity.clone(),
                     self.options.propagator_options,
                 )
                 .post(solver, tag)
@@ -236,27 +358,41 @@ impl<Var: IntegerVariable + 'static + Debug> Constraint for CumulativeConstraint
             CumulativePropagationMethod::TimeTableOverInterval => {
                 TimeTableOverIntervalPropagator::new(
                     &self.tasks,
-                    self.resource_capacity,
+                    self.resource_capacity.clone(),
                     self.options.propagator_options,
                 )
                 .post(solver, tag)
             }
             CumulativePropagationMethod::TimeTableOverIntervalIncremental => {
-                TimeTableOverIntervalIncrementalPropagator::<Var, false>::new(
+                TimeTableOverIntervalIncrementalPropagator::<Var, false, PVar, RVar, CVar>::new(
                     &self.tasks,
-                    self.resource_capacity,
+                    self.resource_capacity.clone(),
                     self.options.propagator_options,
                 )
                 .post(solver, tag)
             }
             CumulativePropagationMethod::TimeTableOverIntervalIncrementalSynchronised => {
-                TimeTableOverIntervalIncrementalPropagator::<Var, true>::new(
+                TimeTableOverIntervalIncrementalPropagator::<Var, true, PVar, RVar, CVar>::new(
                     &self.tasks,
-                    self.resource_capacity,
+                    self.resource_capacity.clone(),
                     self.options.propagator_options,
                 )
                 .post(solver, tag)
             }
+            CumulativePropagationMethod::TimeTableEdgeFinding => {
+                TimeTableEdgeFindingPropagator::new(
+                    &self.tasks,
+                    self.resource_capacity.clone(),
+                    self.options.propagator_options,
+                )
+                .post(solver, tag)
+            }
+            CumulativePropagationMethod::EnergeticReasoning => EnergeticReasoningPropagator::new(
+                &self.tasks,
+                self.resource_capacity.clone(),
+                self.options.propagator_options,
+            )
+            .post(solver, tag),
         }
     }
 
@@ -269,22 +405,22 @@ impl<Var: IntegerVariable + 'static + Debug> Constraint for CumulativeConstraint
         match self.options.propagation_method {
             CumulativePropagationMethod::TimeTablePerPoint => TimeTablePerPointPropagator::new(
                 &self.tasks,
-                self.resource_capacity,
+                self.resource_capacity.clone(),
                 self.options.propagator_options,
             )
             .implied_by(solver, reification_literal, tag),
             CumulativePropagationMethod::TimeTablePerPointIncremental => {
-                TimeTablePerPointIncrementalPropagator::<Var, false>::new(
+                TimeTablePerPointIncrementalPropagator::<Var, false, PVar, RVar, CVar>::new(
                     &self.tasks,
-                    self.resource_capacity,
+                    self.resource_capacity.clone(),
                     self.options.propagator_options,
                 )
                 .implied_by(solver, reification_literal, tag)
             }
             CumulativePropagationMethod::TimeTablePerPointIncrementalSynchronised => {
-                TimeTablePerPointIncrementalPropagator::<Var, true>::new(
+                TimeTablePerPointIncrementalPropagator::<Var, true, PVar, RVar, CVar>::new(
                     &self.tasks,
-                    self.resource_capacity,
+                    self.resource_capacity.clone(),
                     self.options.propagator_options,
                 )
                 .implied_by(solver, reification_literal, tag)
@@ -292,27 +428,41 @@ impl<Var: IntegerVariable + 'static + Debug> Constraint for CumulativeConstraint
             CumulativePropagationMethod::TimeTableOverInterval => {
                 TimeTableOverIntervalPropagator::new(
                     &self.tasks,
-                    self.resource_capacity,
+                    self.resource_capacity.clone(),
                     self.options.propagator_options,
                 )
                 .implied_by(solver, reification_literal, tag)
             }
             CumulativePropagationMethod::TimeTableOverIntervalIncremental => {
-                TimeTableOverIntervalIncrementalPropagator::<Var, false>::new(
+                TimeTableOverIntervalIncrementalPropagator::<Var, false, PVar, RVar, CVar>::new(
                     &self.tasks,
-                    self.resource_capacity,
+                    self.resource_capacity.clone(),
                     self.options.propagator_options,
                 )
                 .implied_by(solver, reification_literal, tag)
             }
             CumulativePropagationMethod::TimeTableOverIntervalIncrementalSynchronised => {
-                TimeTableOverIntervalIncrementalPropagator::<Var, true>::new(
+                TimeTableOverIntervalIncrementalPropagator::<Var, true, PVar, RVar, CVar>::new(
+                    &self.tasks,
+                    self.resource_capacity.clone(),
+                    self.options.propagator_options,
+                )
+                .implied_by(solver, reification_literal, tag)
+            }
+            CumulativePropagationMethod::TimeTableEdgeFinding => {
+                TimeTableEdgeFindingPropagator::new(
                     &self.tasks,
-                    self.resource_capacity,
+                    self.resource_capacity.clone(),
                     self.options.propagator_options,
                 )
                 .implied_by(solver, reification_literal, tag)
             }
+            CumulativePropagationMethod::EnergeticReasoning => EnergeticReasoningPropagator::new(
+                &self.tasks,
+                self.resource_capacity.clone(),
+                self.options.propagator_options,
+            )
+            .implied_by(solver, reification_literal, tag),
         }
     }
 }
@@ -0,0 +1,213 @@
+use std::fmt::Debug;
+use std::num::NonZero;
+
+use super::Constraint;
+use crate::propagators::ArgTaskDisj;
+use crate::propagators::DetectablePrecedencesPropagator;
+use crate::propagators::DisjunctivePropagatorOptions;
+use crate::propagators::EdgeFindingPropagator;
+use crate::propagators::NotFirstNotLastPropagator;
+use crate::propagators::OverloadCheckingPropagator;
+use crate::pumpkin_assert_simple;
+use crate::variables::IntegerVariable;
+use crate::variables::Literal;
+use crate::ConstraintOperationError;
+use crate::Solver;
+
+/// Creates the [Disjunctive](https://sofdem.github.io/gccat/gccat/Cdisjunctive.html)
+/// [`Constraint`].
+///
+/// This constraint ensures that no two tasks execute at the same time; i.e. for every pair of
+/// tasks `i` and `j`, either `s_i + d_i <= s_j` or `s_j + d_j <= s_i`. Note that this also
+/// applies to tasks with a duration of 0, which are not allowed to be scheduled during the
+/// execution of another task (this corresponds to `disjunctive_strict` in MiniZinc).
+///
+/// The implementation uses the following filtering rules (see \[1\] and \[2\]):
+/// - Overload checking, which detects whether a set of tasks cannot be scheduled before the latest
+///   completion time of the set.
+/// - Detectable precedences, which updates the bounds of a task based on the set of tasks which has
+///   been detected to precede (or succeed) it.
+/// - Edge-finding, which detects that a task has to be scheduled after (or before) a set of tasks.
+/// - Not-first/not-last, which detects that a task cannot be scheduled before (or after) all tasks
+///   in a set.
+///
+/// Each of these rules can be disabled using [`DisjunctivePropagatorOptions`].
+///
+/// The length of `start_times` and `durations` should be the same; if this is not the case then
+/// this method will panic.
+///
+/// It is possible to specify certain options for the disjunctive (such as the type of
+/// explanation) using [`disjunctive_with_options`].
+///
+/// # Example
+/// ```rust
+/// // We construct three tasks which all have to be executed on the same machine:
+/// // - Task 0: Start times: [0, 6], Processing time: 4
+/// // - Task 1: Start times: [0, 6], Processing time: 3
+/// // - Task 2: Start times: [3, 10], Processing time: 5
+/// # use pumpkin_solver::termination::Indefinite;
+/// # use pumpkin_solver::Solver;
+/// # use pumpkin_solver::results::SatisfactionResult;
+/// # use pumpkin_solver::constraints;
+/// # use pumpkin_solver::constraints::Constraint;
+/// # use crate::pumpkin_solver::results::ProblemSolution;
+/// let mut solver = Solver::default();
+///
+/// let start_0 = solver.new_bounded_integer(0, 6);
+/// let start_1 = solver.new_bounded_integer(0, 6);
+/// let start_2 = solver.new_bounded_integer(3, 10);
+///
+/// let start_times = [start_0, start_1, start_2];
+/// let durations = [4, 3, 5];
+///
+/// solver
+///     .add_constraint(constraints::disjunctive(start_times, durations))
+///     .post();
+///
+/// let mut termination = Indefinite;
+/// let mut brancher = solver.default_brancher();
+///
+/// let result = solver.satisfy(&mut brancher, &mut termination);
+///
+/// // We check whether the result was feasible
+/// match result {
+///     SatisfactionResult::Satisfiable(solution) => {
+///         // Now we check that no two tasks overlap
+///         for i in 0..start_times.len() {
+///             for j in i + 1..start_times.len() {
+///                 let start_i = solution.get_integer_value(start_times[i]);
+///                 let start_j = solution.get_integer_value(start_times[j]);
+///                 assert!(start_i + durations[i] <= start_j || start_j + durations[j] <= start_i);
+///             }
+///         }
+///     }
+///     SatisfactionResult::Unsatisfiable => panic!("The problem should be satisfiable"),
+///     SatisfactionResult::Unknown => panic!("The solver should not time out"),
+/// }
+/// ```
+///
+/// # Bibliography
+/// \[1\] H. Fahimi and C.-G. Quimper, ‘Linear-time filtering algorithms for the disjunctive
+/// constraint’, in Proceedings of the AAAI Conference on Artificial Intelligence, 2014.
+///
+/// \[2\] P. Vilím, ‘Global constraints in scheduling’, PhD thesis, Charles University in Prague,
+/// 2007.
+pub fn disjunctive<StartTimes, Durations>(
+    start_times: StartTimes,
+    durations: Durations,
+) -> impl Constraint
+where
+    StartTimes: IntoIterator,
+    StartTimes::Item: IntegerVariable + Debug + 'static,
+    StartTimes::IntoIter: ExactSizeIterator,
+    Durations: IntoIterator<Item = i64>,
+    Durations::IntoIter: ExactSizeIterator,
+{
+    disjunctive_with_options(
+        start_times,
+        durations,
+        DisjunctivePropagatorOptions::default(),
+    )
+}
+
+/// Creates the [Disjunctive](https://sofdem.github.io/gccat/gccat/Cdisjunctive.html) constraint
+/// with the provided [`DisjunctivePropagatorOptions`].
+///
+/// See the documentation of [`disjunctive`] for more information about the constraint.
+pub fn disjunctive_with_options<StartTimes, Durations>(
+    start_times: StartTimes,
+    durations: Durations,
+    options: DisjunctivePropagatorOptions,
+) -> impl Constraint
+where
+    StartTimes: IntoIterator,
+    StartTimes::Item: IntegerVariable + Debug + 'static,
+    StartTimes::IntoIter: ExactSizeIterator,
+    Durations: IntoIterator<Item = i64>,
+    Durations::IntoIter: ExactSizeIterator,
+{
+    let start_times = start_times.into_iter();
+    let durations = durations.into_iter();
+
+    pumpkin_assert_simple!(
+        start_times.len() == durations.len(),
+        "The number of start variables and durations should be the same!"
+    );
+
+    let tasks = start_times
+        .zip(durations)
+        .map(|(starting_time, duration)| {
+            pumpkin_assert_simple!(duration >= 0, "The durations should be non-negative!");
+            ArgTaskDisj {
+                starting_time,
+                duration,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    DisjunctiveConstraint { tasks, options }
+}
+
+struct DisjunctiveConstraint<Var> {
+    tasks: Vec<ArgTaskDisj<Var>>,
+    options: DisjunctivePropagatorOptions,
+}
+
+impl<Var: IntegerVariable + 'static + Debug> Constraint for DisjunctiveConstraint<Var> {
+    fn post(
+        self,
+        solver: &mut Solver,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        if self.options.overload_checking {
+            OverloadCheckingPropagator::new(&self.tasks, self.options).post(solver, tag)?;
+        }
+        if self.options.detectable_precedences {
+            DetectablePrecedencesPropagator::new(&self.tasks, self.options).post(solver, tag)?;
+        }
+        if self.options.edge_finding {
+            EdgeFindingPropagator::new(&self.tasks, self.options).post(solver, tag)?;
+        }
+        if self.options.not_first_not_last {
+            NotFirstNotLastPropagator::new(&self.tasks, self.options).post(solver, tag)?;
+        }
+        Ok(())
+    }
+
+    fn implied_by(
+        self,
+        solver: &mut Solver,
+        reification_literal: Literal,
+        tag: Option<NonZero<u32>>,
+    ) -> Result<(), ConstraintOperationError> {
+        if self.options.overload_checking {
+            OverloadCheckingPropagator::new(&self.tasks, self.options).implied_by(
+                solver,
+                reification_literal,
+                tag,
+            )?;
+        }
+        if self.options.detectable_precedences {
+            DetectablePrecedencesPropagator::new(&self.tasks, self.options).implied_by(
+                solver,
+                reification_literal,
+                tag,
+            )?;
+        }
+        if self.options.edge_finding {
+            EdgeFindingPropagator::new(&self.tasks, self.options).implied_by(
+                solver,
+                reification_literal,
+                tag,
+            )?;
+        }
+        if self.options.not_first_not_last {
+            NotFirstNotLastPropagator::new(&self.tasks, self.options).implied_by(
+                solver,
+                reification_literal,
+                tag,
+            )?;
+        }
+        Ok(())
+    }
+}
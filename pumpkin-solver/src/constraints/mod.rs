@@ -16,11 +16,11 @@
 //! solver.add_constraint(constraints::equals([a, b], 0)).post();
 //! ```
 //!
-//! # Note
-//! At the moment, the API for posting propagators is not yet publicly accessible as it is
-//! unstable. Consumers of the Pumpkin library can therefore only define constraints by
-//! decomposing them into the constraints that are predefined in the library. Once the
-//! propagator API is stabilized, it will become part of the public API.
+//! # Custom constraints
+//! Besides decomposing into the constraints that are predefined in the library, consumers of the
+//! Pumpkin library can define their own propagators by implementing [`Propagator`]. Every
+//! propagator is a [`Constraint`] and can thus be posted directly; see the
+//! [`crate::propagation`] module for an example.
 
 mod all_different;
 mod arithmetic;
@@ -28,7 +28,10 @@ mod boolean;
 mod clause;
 mod constraint_poster;
 mod cumulative;
+mod disjunctive;
 mod element;
+mod regular;
+mod table;
 
 use std::num::NonZero;
 
@@ -38,7 +41,10 @@ pub use boolean::*;
 pub use clause::*;
 pub use constraint_poster::*;
 pub use cumulative::*;
+pub use disjunctive::*;
 pub use element::*;
+pub use regular::*;
+pub use table::*;
 
 use crate::engine::propagation::Propagator;
 use crate::propagators::ReifiedPropagator;
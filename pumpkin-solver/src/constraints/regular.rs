@@ -0,0 +1,53 @@
+use super::Constraint;
+use crate::propagators::CostRegularPropagator;
+pub use crate::propagators::DeterministicFiniteAutomaton;
+use crate::propagators::RegularPropagator;
+use crate::variables::IntegerVariable;
+
+/// Creates the [Regular](https://sofdem.github.io/gccat/gccat/Cautomaton.html) [`Constraint`]
+/// which states that the sequence of values assigned to the `variables` is accepted by the
+/// `automaton`.
+///
+/// The constraint is propagated using the layered graph of the automaton, which enforces domain
+/// consistency.
+///
+/// # Example
+/// ```rust
+/// # use pumpkin_solver::constraints;
+/// # use pumpkin_solver::constraints::DeterministicFiniteAutomaton;
+/// # use pumpkin_solver::Solver;
+/// let mut solver = Solver::default();
+///
+/// let x = solver.new_bounded_integer(0, 1);
+/// let y = solver.new_bounded_integer(0, 1);
+/// let z = solver.new_bounded_integer(0, 1);
+///
+/// // The sequence does not contain two consecutive 1s
+/// let automaton =
+///     DeterministicFiniteAutomaton::new(2, 0, vec![0, 1], vec![(0, 0, 0), (0, 1, 1), (1, 0, 0)]);
+/// solver
+///     .add_constraint(constraints::regular([x, y, z], automaton))
+///     .post();
+/// ```
+pub fn regular<Var: IntegerVariable + 'static>(
+    variables: impl Into<Box<[Var]>>,
+    automaton: DeterministicFiniteAutomaton,
+) -> impl Constraint {
+    RegularPropagator::new(variables.into(), automaton)
+}
+
+/// Creates the cost-regular [`Constraint`] which states that the sequence of values assigned to
+/// the `variables` is accepted by the `automaton` and that `cost` is equal to the sum of the costs
+/// of the transitions which are taken.
+///
+/// The `transition_costs` should contain a cost for every transition of the `automaton` (in the
+/// order in which the transitions were provided to [`DeterministicFiniteAutomaton::new`]); if
+/// this is not the case then this method will panic.
+pub fn cost_regular<Var: IntegerVariable + 'static, CostVar: IntegerVariable + 'static>(
+    variables: impl Into<Box<[Var]>>,
+    automaton: DeterministicFiniteAutomaton,
+    transition_costs: Vec<i64>,
+    cost: CostVar,
+) -> impl Constraint {
+    CostRegularPropagator::new(variables.into(), automaton, transition_costs, cost)
+}
@@ -0,0 +1,46 @@
+use super::Constraint;
+use crate::propagators::NegativeTablePropagator;
+use crate::propagators::TablePropagator;
+use crate::variables::IntegerVariable;
+
+/// Creates the [Table](https://sofdem.github.io/gccat/gccat/Cin_relation.html) [`Constraint`]
+/// which states that the assignment to the `variables` is equal to one of the `tuples`.
+///
+/// Every tuple should contain a value for each of the `variables` (in the same order); if this is
+/// not the case then this method will panic.
+///
+/// The constraint is propagated using compact-table, which enforces domain consistency.
+///
+/// # Example
+/// ```rust
+/// # use pumpkin_solver::constraints;
+/// # use pumpkin_solver::Solver;
+/// let mut solver = Solver::default();
+///
+/// let x = solver.new_bounded_integer(0, 3);
+/// let y = solver.new_bounded_integer(0, 3);
+///
+/// // Either `x = 1` and `y = 2`, or `x = 3` and `y = 0`
+/// solver
+///     .add_constraint(constraints::table([x, y], vec![vec![1, 2], vec![3, 0]]))
+///     .post();
+/// ```
+pub fn table<Var: IntegerVariable + 'static>(
+    variables: impl Into<Box<[Var]>>,
+    tuples: Vec<Vec<i64>>,
+) -> impl Constraint {
+    TablePropagator::new(variables.into(), tuples)
+}
+
+/// Creates the negative [Table](https://sofdem.github.io/gccat/gccat/Cnot_in_relation.html)
+/// [`Constraint`] which states that the assignment to the `variables` is not equal to any of the
+/// `tuples`.
+///
+/// Every tuple should contain a value for each of the `variables` (in the same order); if this is
+/// not the case then this method will panic.
+pub fn negative_table<Var: IntegerVariable + 'static>(
+    variables: impl Into<Box<[Var]>>,
+    tuples: Vec<Vec<i64>>,
+) -> impl Constraint {
+    NegativeTablePropagator::new(variables.into(), tuples)
+}
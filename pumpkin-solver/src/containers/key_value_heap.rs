@@ -123,6 +123,24 @@ where
         }
     }
 
+    /// Sets the value of the element of 'key' to 'value', which can be both larger or smaller
+    /// than its current value.
+    ///
+    /// The worst-case time-complexity of this operation is O(logn)
+    pub(crate) fn set_value(&mut self, key: Key, value: Value) {
+        let position = self.map_key_to_position[key];
+        let is_increase = value >= self.values[position];
+        self.values[position] = value;
+        // Similar to 'increment', the position of the key is only restored if the key is present
+        if self.is_key_present(key) {
+            if is_increase {
+                self.sift_up(position);
+            } else {
+                self.sift_down(position);
+            }
+        }
+    }
+
     /// Restores the entry with key 'key' to the heap if the key is not present, otherwise does
     /// nothing. Its value is the previous value used before 'delete_key' was called.
     ///
@@ -343,6 +361,22 @@ mod test {
         assert_eq!(heap.pop_max().unwrap(), 2);
     }
 
+    #[test]
+    fn decreased_value_is_sifted_down() {
+        let mut heap: KeyValueHeap<usize, u32> = KeyValueHeap::default();
+
+        heap.grow(0, 7);
+        heap.grow(1, 5);
+        heap.grow(2, 6);
+
+        heap.set_value(0, 1);
+        heap.set_value(1, 8);
+
+        assert_eq!(heap.pop_max().unwrap(), 1);
+        assert_eq!(heap.pop_max().unwrap(), 2);
+        assert_eq!(heap.pop_max().unwrap(), 0);
+    }
+
     // Uses the heap to sort the input vectors, and compare with a sorted version of the vector.
     fn heap_sort_test_helper(numbers: Vec<usize>) {
         let mut sorted_numbers = numbers.clone();
@@ -99,6 +99,7 @@ impl ConflictAnalysisContext<'_> {
             } => {
                 let _ = self.proof_log.log_inference(
                     self.propagators.get_tag(propagator_id),
+                    self.propagators[propagator_id].inference_label(),
                     conflict_nogood.iter().copied(),
                     None,
                 );
@@ -214,23 +215,30 @@ impl ConflictAnalysisContext<'_> {
                 //
                 // It could be that the predicate is implied by another unit nogood
 
-                let step_id = unit_nogood_step_ids
-                    .get(&predicate)
-                    .or_else(|| {
-                        // It could be the case that we attempt to get the reason for the predicate
-                        // [x >= v] but that the corresponding unit nogood idea is the one for the
-                        // predicate [x == v]
-                        let domain_id = predicate.get_domain();
-                        let right_hand_side = predicate.get_right_hand_side();
-
-                        unit_nogood_step_ids.get(&predicate!(domain_id == right_hand_side))
-                    })
-                    .expect("Expected to be able to retrieve step id for unit nogood");
-                proof_log.add_propagation(*step_id);
+                let step_id = unit_nogood_step_ids.get(&predicate).or_else(|| {
+                    // It could be the case that we attempt to get the reason for the predicate
+                    // [x >= v] but that the corresponding unit nogood idea is the one for the
+                    // predicate [x == v]
+                    let domain_id = predicate.get_domain();
+                    let right_hand_side = predicate.get_right_hand_side();
+
+                    unit_nogood_step_ids.get(&predicate!(domain_id == right_hand_side))
+                });
+
+                // Unit nogoods which are added at the root (e.g. clauses added by the user) are
+                // only given a step id when inferences are logged
+                match step_id {
+                    Some(step_id) => proof_log.add_propagation(*step_id),
+                    None => pumpkin_assert_simple!(
+                        !proof_log.is_logging_inferences(),
+                        "Expected to be able to retrieve step id for unit nogood"
+                    ),
+                }
             } else {
                 // Otherwise we log the inference which was used to derive the nogood
                 let _ = proof_log.log_inference(
                     constraint_tag,
+                    propagators[propagator_id].inference_label(),
                     reason_buffer.as_ref().iter().copied(),
                     Some(predicate),
                 );
@@ -117,7 +117,7 @@ impl SemanticMinimiser {
         }
     }
 
-    fn grow(&mut self, lower_bound: i32, upper_bound: i32, holes: Vec<i32>) {
+    fn grow(&mut self, lower_bound: i64, upper_bound: i64, holes: Vec<i64>) {
         let mut initial_domain = SimpleIntegerDomain {
             lower_bound,
             upper_bound,
@@ -147,22 +147,22 @@ impl SemanticMinimiser {
 
 #[derive(Clone, Default, Debug)]
 struct SimpleIntegerDomain {
-    lower_bound: i32,
-    upper_bound: i32,
-    holes: HashSet<i32>,
+    lower_bound: i64,
+    upper_bound: i64,
+    holes: HashSet<i64>,
     inconsistent: bool,
 }
 
 impl SimpleIntegerDomain {
-    fn tighten_lower_bound(&mut self, lower_bound: i32) {
+    fn tighten_lower_bound(&mut self, lower_bound: i64) {
         self.lower_bound = cmp::max(self.lower_bound, lower_bound);
     }
 
-    fn tighten_upper_bound(&mut self, upper_bound: i32) {
+    fn tighten_upper_bound(&mut self, upper_bound: i64) {
         self.upper_bound = cmp::min(self.upper_bound, upper_bound);
     }
 
-    fn add_hole(&mut self, hole: i32) {
+    fn add_hole(&mut self, hole: i64) {
         // Add the hole if it is within the domain.
         // Note that we do not adjust bounds due to holes being at the border. This is taken care of
         // by other functions (propagate bounds based on holes).
@@ -171,7 +171,7 @@ impl SimpleIntegerDomain {
         }
     }
 
-    fn assign(&mut self, value: i32) {
+    fn assign(&mut self, value: i64) {
         // If the domains are inconsistent, or if the assigned value would make the domain
         // inconsistent, declare inconsistency and stop.
         if self.lower_bound > self.upper_bound
@@ -36,6 +36,7 @@ use crate::basic_types::PropositionalConjunction;
 use crate::basic_types::Random;
 use crate::basic_types::SolutionReference;
 use crate::basic_types::StoredConflictInfo;
+use crate::branching::branchers::default_brancher::BranchingHeuristic;
 use crate::branching::Brancher;
 use crate::branching::SelectionContext;
 use crate::engine::conflict_analysis::ConflictResolver as Resolver;
@@ -64,6 +65,8 @@ use crate::proof::FinalizingContext;
 use crate::proof::ProofLog;
 use crate::proof::RootExplanationContext;
 use crate::propagators::nogoods::LearningOptions;
+use crate::propagators::nogoods::NogoodExchange;
+use crate::propagators::nogoods::NogoodExchangeConnection;
 use crate::propagators::nogoods::NogoodPropagator;
 use crate::pumpkin_assert_advanced;
 use crate::pumpkin_assert_eq_simple;
@@ -74,6 +77,8 @@ use crate::statistics::statistic_logger::StatisticLogger;
 use crate::statistics::statistic_logging::should_log_statistics;
 use crate::statistics::Statistic;
 #[cfg(doc)]
+use crate::DefaultBrancher;
+#[cfg(doc)]
 use crate::Solver;
 
 /// A solver which attempts to find a solution to a Constraint Satisfaction Problem (CSP) using
@@ -150,6 +155,12 @@ pub struct ConstraintSatisfactionSolver {
     unit_nogood_step_ids: HashMap<Predicate, StepId>,
     /// The resolver which is used upon a conflict.
     conflict_resolver: Box<dyn Resolver>,
+    /// The connection to the exchange through which nogoods are shared with other solvers, if
+    /// any (see [`NogoodExchange`]).
+    nogood_exchange: Option<NogoodExchangeConnection>,
+    /// The propagator which reported the current conflict, if any; used to inform the brancher
+    /// about the failure (see [`Brancher::on_propagator_failure`]).
+    failed_propagator: Option<PropagatorId>,
 
     pub(crate) trailed_values: TrailedValues,
 }
@@ -205,6 +216,8 @@ pub struct SatisfactionSolverOptions {
     pub conflict_resolver: ConflictResolver,
     /// The options which influence the learning of the solver.
     pub learning_options: LearningOptions,
+    /// The heuristic used by the [`DefaultBrancher`] created by the [`Solver`].
+    pub branching_heuristic: BranchingHeuristic,
 }
 
 impl Default for SatisfactionSolverOptions {
@@ -216,6 +229,7 @@ impl Default for SatisfactionSolverOptions {
             proof_log: ProofLog::default(),
             conflict_resolver: ConflictResolver::default(),
             learning_options: LearningOptions::default(),
+            branching_heuristic: BranchingHeuristic::default(),
         }
     }
 }
@@ -339,6 +353,11 @@ impl ConstraintSatisfactionSolver {
         self.last_notified_cp_trail_index = self.assignments.num_trail_entries();
     }
 
+    /// Returns the number of propagators which are registered to the provided domain.
+    pub(crate) fn num_attached_propagators(&self, domain: DomainId) -> u32 {
+        self.watch_list_cp.degree(domain)
+    }
+
     /// This is a temporary accessor to help refactoring.
     pub fn get_solution_reference(&self) -> SolutionReference<'_> {
         SolutionReference::new(&self.assignments)
@@ -368,6 +387,7 @@ impl ConstraintSatisfactionSolver {
             } => {
                 let _ = self.internal_parameters.proof_log.log_inference(
                     self.propagators.get_tag(propagator_id),
+                    self.propagators[propagator_id].inference_label(),
                     conflict_nogood.iter().copied(),
                     None,
                 );
@@ -424,6 +444,8 @@ impl ConstraintSatisfactionSolver {
             },
             internal_parameters: solver_options,
             trailed_values: TrailedValues::default(),
+            nogood_exchange: None,
+            failed_propagator: None,
         };
 
         // As a convention, the assignments contain a dummy domain_id=0, which represents a 0-1
@@ -437,7 +459,13 @@ impl ConstraintSatisfactionSolver {
             .add_integer(dummy_id, "Dummy".to_owned());
 
         let _ = csp_solver.add_propagator(
-            NogoodPropagator::with_options(csp_solver.internal_parameters.learning_options),
+            NogoodPropagator::with_options(
+                csp_solver.internal_parameters.learning_options,
+                csp_solver
+                    .internal_parameters
+                    .proof_log
+                    .is_logging_deletions(),
+            ),
             None,
         );
 
@@ -479,6 +507,24 @@ impl ConstraintSatisfactionSolver {
         result
     }
 
+    /// Connects the solver to the provided [`NogoodExchange`]; the variables which have been
+    /// created up to this point are assumed to be shared with the other connected solvers.
+    ///
+    /// Imported nogoods cannot be justified in a proof, so the solver should not log a proof.
+    pub(crate) fn connect_to_nogood_exchange(&mut self, exchange: &NogoodExchange) {
+        pumpkin_assert_simple!(
+            !self.internal_parameters.proof_log.is_active(),
+            "Nogoods cannot be shared by a solver which logs a proof"
+        );
+        self.nogood_exchange = Some(exchange.connect(self.assignments.num_domains()));
+    }
+
+    /// Replaces the restart strategy of the solver by one which uses the provided
+    /// [`RestartOptions`].
+    pub(crate) fn set_restart_options(&mut self, restart_options: RestartOptions) {
+        self.restart_strategy = RestartStrategy::new(restart_options);
+    }
+
     pub fn get_state(&self) -> &CSPSolverState {
         &self.state
     }
@@ -487,6 +533,11 @@ impl ConstraintSatisfactionSolver {
         &mut self.internal_parameters.random_generator
     }
 
+    /// Returns the heuristic which should be used by the default brancher.
+    pub(crate) fn branching_heuristic(&self) -> BranchingHeuristic {
+        self.internal_parameters.branching_heuristic
+    }
+
     pub fn log_statistics(&self) {
         // We first check whether the statistics will/should be logged to prevent unnecessarily
         // going through all the propagators
@@ -530,8 +581,8 @@ impl ConstraintSatisfactionSolver {
     /// Create a new integer variable. Its domain will have the given lower and upper bounds.
     pub fn create_new_integer_variable(
         &mut self,
-        lower_bound: i32,
-        upper_bound: i32,
+        lower_bound: i64,
+        upper_bound: i64,
         name: Option<String>,
     ) -> DomainId {
         assert!(
@@ -552,7 +603,7 @@ impl ConstraintSatisfactionSolver {
     /// Creates an integer variable with a domain containing only the values in `values`
     pub fn create_new_integer_variable_sparse(
         &mut self,
-        values: Vec<i32>,
+        values: Vec<i64>,
         name: Option<String>,
     ) -> DomainId {
         let domain_id = self.assignments.create_new_integer_variable_sparse(values);
@@ -695,22 +746,22 @@ impl ConstraintSatisfactionSolver {
     }
 
     /// Get the lower bound for the given variable.
-    pub fn get_lower_bound(&self, variable: &impl IntegerVariable) -> i32 {
+    pub fn get_lower_bound(&self, variable: &impl IntegerVariable) -> i64 {
         variable.lower_bound(&self.assignments)
     }
 
     /// Get the upper bound for the given variable.
-    pub fn get_upper_bound(&self, variable: &impl IntegerVariable) -> i32 {
+    pub fn get_upper_bound(&self, variable: &impl IntegerVariable) -> i64 {
         variable.upper_bound(&self.assignments)
     }
 
     /// Determine whether `value` is in the domain of `variable`.
-    pub fn integer_variable_contains(&self, variable: &impl IntegerVariable, value: i32) -> bool {
+    pub fn integer_variable_contains(&self, variable: &impl IntegerVariable, value: i64) -> bool {
         variable.contains(&self.assignments, value)
     }
 
     /// Get the assigned integer for the given variable. If it is not assigned, `None` is returned.
-    pub fn get_assigned_integer_value(&self, variable: &impl IntegerVariable) -> Option<i32> {
+    pub fn get_assigned_integer_value(&self, variable: &impl IntegerVariable) -> Option<i64> {
         let lb = self.get_lower_bound(variable);
         let ub = self.get_upper_bound(variable);
 
@@ -721,7 +772,7 @@ impl ConstraintSatisfactionSolver {
         }
     }
 
-    pub fn restore_state_at_root(&mut self, brancher: &mut impl Brancher) {
+    pub fn restore_state_at_root(&mut self, brancher: &mut (impl Brancher + ?Sized)) {
         if self.assignments.get_decision_level() != 0 {
             ConstraintSatisfactionSolver::backtrack(
                 &mut self.assignments,
@@ -737,8 +788,51 @@ impl ConstraintSatisfactionSolver {
                 &mut self.trailed_values,
             );
             self.state.declare_ready();
+        } else if self.state.has_solution() || self.state.timeout() {
+            // The search can also stop at the root, in which case there is nothing to backtrack
+            self.state.declare_ready();
         }
     }
+
+    /// Posts the provided `predicates` at a new decision level and propagates them; if this does
+    /// not lead to a conflict, then `inspect` is called with the resulting domains. Afterwards,
+    /// the state of the solver is restored to the root.
+    ///
+    /// Returns [`None`] if the predicates lead to a conflict.
+    pub(crate) fn probe<R>(
+        &mut self,
+        predicates: &[Predicate],
+        brancher: &mut (impl Brancher + ?Sized),
+        inspect: impl FnOnce(&mut SelectionContext) -> R,
+    ) -> Option<R> {
+        pumpkin_assert_simple!(
+            self.get_decision_level() == 0,
+            "Probing should only take place at the root"
+        );
+        if self.state.is_inconsistent() {
+            return None;
+        }
+
+        self.declare_new_decision_level();
+
+        let is_consistent = predicates.iter().all(|&predicate| {
+            self.assignments.evaluate_predicate(predicate) != Some(false)
+                && self.assignments.post_predicate(predicate, None).is_ok()
+        });
+        if is_consistent {
+            self.propagate();
+        }
+
+        let result = (is_consistent && self.state.no_conflict()).then(|| {
+            inspect(&mut SelectionContext::new(
+                &self.assignments,
+                &mut self.internal_parameters.random_generator,
+            ))
+        });
+
+        self.restore_state_at_root(brancher);
+        result
+    }
 }
 
 // methods that serve as the main building blocks
@@ -758,6 +852,10 @@ impl ConstraintSatisfactionSolver {
         termination: &mut impl TerminationCondition,
         brancher: &mut impl Brancher,
     ) -> CSPSolverExecutionFlag {
+        if let Err(flag) = self.exchange_nogoods() {
+            return flag;
+        }
+
         loop {
             if termination.should_stop() {
                 self.state.declare_timeout();
@@ -776,6 +874,10 @@ impl ConstraintSatisfactionSolver {
                     && self.restart_strategy.should_restart()
                 {
                     self.restart_during_search(brancher);
+
+                    if let Err(flag) = self.exchange_nogoods() {
+                        return flag;
+                    }
                 }
 
                 let branching_result = self.make_next_decision(brancher);
@@ -798,14 +900,79 @@ impl ConstraintSatisfactionSolver {
                     return CSPSolverExecutionFlag::Infeasible;
                 }
 
+                self.notify_brancher_about_failure(brancher);
                 self.resolve_conflict_with_nogood(brancher);
 
                 brancher.on_conflict();
+                termination.on_conflict();
                 self.decay_nogood_activities();
             }
         }
     }
 
+    /// Informs the brancher about the propagator which reported the current conflict.
+    ///
+    /// The nogood propagator does not register to any domains, so for nogood conflicts the scope
+    /// consists of the domains in the conflicting nogood.
+    fn notify_brancher_about_failure(&mut self, brancher: &mut impl Brancher) {
+        let Some(propagator_id) = self.failed_propagator.take() else {
+            return;
+        };
+
+        let scope = self.watch_list_cp.scope(propagator_id);
+        if !scope.is_empty() {
+            brancher.on_propagator_failure(scope);
+            return;
+        }
+
+        let conflict_nogood = match self.state.get_conflict_info() {
+            StoredConflictInfo::Propagator {
+                conflict_nogood, ..
+            }
+            | StoredConflictInfo::EmptyDomain { conflict_nogood } => conflict_nogood,
+            StoredConflictInfo::RootLevelConflict(_) => return,
+        };
+        let mut scope = conflict_nogood
+            .iter()
+            .map(|predicate| predicate.get_domain())
+            .collect::<Vec<_>>();
+        scope.sort_unstable_by_key(|domain| domain.id);
+        scope.dedup();
+        brancher.on_propagator_failure(&scope);
+    }
+
+    /// Exports the nogoods which should be shared with the other solvers connected to the
+    /// [`NogoodExchange`] and imports the nogoods which they have shared.
+    ///
+    /// This only takes place at the root; if an imported nogood shows that the problem is
+    /// infeasible, then the solver is put in the infeasible state.
+    fn exchange_nogoods(&mut self) -> Result<(), CSPSolverExecutionFlag> {
+        if self.get_decision_level() != 0 {
+            return Ok(());
+        }
+        let Some(connection) = self.nogood_exchange.as_mut() else {
+            return Ok(());
+        };
+
+        self.solver_statistics
+            .learned_clause_statistics
+            .num_exported_nogoods += connection.num_pending_exports() as u64;
+        let imported_nogoods = connection.exchange();
+
+        for nogood in imported_nogoods {
+            self.solver_statistics
+                .learned_clause_statistics
+                .num_imported_nogoods += 1;
+
+            if self.add_nogood(nogood.into_vec()).is_err() {
+                self.state.declare_infeasible();
+                return Err(CSPSolverExecutionFlag::Infeasible);
+            }
+        }
+
+        Ok(())
+    }
+
     fn decay_nogood_activities(&mut self) {
         match self.propagators[Self::get_nogood_propagator_id()].downcast_mut::<NogoodPropagator>()
         {
@@ -923,15 +1090,20 @@ impl ConstraintSatisfactionSolver {
                 .average_backtrack_amount
                 .add_term((current_decision_level - learned_nogood.backjump_level) as u64);
 
+            let lbd = self.lbd_helper.compute_lbd(
+                &learned_nogood.predicates,
+                conflict_analysis_context.assignments,
+            );
             self.restart_strategy.notify_conflict(
-                self.lbd_helper.compute_lbd(
-                    &learned_nogood.predicates,
-                    conflict_analysis_context.assignments,
-                ),
+                lbd,
                 conflict_analysis_context
                     .assignments
                     .get_pruned_value_count(),
             );
+
+            if let Some(connection) = self.nogood_exchange.as_mut() {
+                connection.export_learned_nogood(&learned_nogood.predicates, lbd);
+            }
         }
 
         let result = self
@@ -1142,6 +1314,7 @@ impl ConstraintSatisfactionSolver {
 
         let _ = self.internal_parameters.proof_log.log_inference(
             constraint_tag,
+            self.propagators[propagator].inference_label(),
             empty_domain_reason.iter().copied(),
             Some(entry.predicate),
         );
@@ -1158,11 +1331,11 @@ impl ConstraintSatisfactionSolver {
     pub(crate) fn propagate(&mut self) {
         // Record the number of predicates on the trail for statistics purposes.
         let num_assigned_variables_old = self.assignments.num_trail_entries();
+        self.failed_propagator = None;
         // The initial domain events are due to the decision predicate.
         self.notify_propagators_about_domain_events();
         // Keep propagating until there are unprocessed propagators, or a conflict is detected.
         while let Some(propagator_id) = self.propagator_queue.pop() {
-            let tag = self.propagators.get_tag(propagator_id);
             let num_trail_entries_before = self.assignments.num_trail_entries();
 
             let propagation_status = {
@@ -1178,7 +1351,7 @@ impl ConstraintSatisfactionSolver {
             };
 
             if self.assignments.get_decision_level() == 0 {
-                self.log_root_propagation_to_proof(num_trail_entries_before, tag);
+                self.log_root_propagation_to_proof(num_trail_entries_before);
             }
 
             match propagation_status {
@@ -1186,30 +1359,34 @@ impl ConstraintSatisfactionSolver {
                     // Notify other propagators of the propagations and continue.
                     self.notify_propagators_about_domain_events();
                 }
-                Err(inconsistency) => match inconsistency {
-                    // A propagator did a change that resulted in an empty domain.
-                    Inconsistency::EmptyDomain => {
-                        self.prepare_for_conflict_resolution();
-                        break;
-                    }
-                    // A propagator-specific reason for the current conflict.
-                    Inconsistency::Conflict(conflict_nogood) => {
-                        pumpkin_assert_advanced!(DebugHelper::debug_reported_failure(
-                            &self.trailed_values,
-                            &self.assignments,
-                            &conflict_nogood,
-                            &self.propagators[propagator_id],
-                            propagator_id,
-                        ));
-
-                        let stored_conflict_info = StoredConflictInfo::Propagator {
-                            conflict_nogood,
-                            propagator_id,
-                        };
-                        self.state.declare_conflict(stored_conflict_info);
-                        break;
+                Err(inconsistency) => {
+                    self.failed_propagator = Some(propagator_id);
+
+                    match inconsistency {
+                        // A propagator did a change that resulted in an empty domain.
+                        Inconsistency::EmptyDomain => {
+                            self.prepare_for_conflict_resolution();
+                            break;
+                        }
+                        // A propagator-specific reason for the current conflict.
+                        Inconsistency::Conflict(conflict_nogood) => {
+                            pumpkin_assert_advanced!(DebugHelper::debug_reported_failure(
+                                &self.trailed_values,
+                                &self.assignments,
+                                &conflict_nogood,
+                                &self.propagators[propagator_id],
+                                propagator_id,
+                            ));
+
+                            let stored_conflict_info = StoredConflictInfo::Propagator {
+                                conflict_nogood,
+                                propagator_id,
+                            };
+                            self.state.declare_conflict(stored_conflict_info);
+                            break;
+                        }
                     }
-                },
+                }
             }
             pumpkin_assert_extreme!(
                 DebugHelper::debug_check_propagations(
@@ -1223,6 +1400,7 @@ impl ConstraintSatisfactionSolver {
                 "Checking the propagations performed by the propagator led to inconsistencies!"
             );
         }
+        self.log_deleted_nogoods_to_proof();
         // Record statistics.
         self.solver_statistics.engine_statistics.num_conflicts +=
             self.state.is_conflicting() as u64;
@@ -1240,17 +1418,32 @@ impl ConstraintSatisfactionSolver {
         );
     }
 
+    /// Logs the deletion of the nogoods which were removed by the nogood propagator during
+    /// propagation.
+    fn log_deleted_nogoods_to_proof(&mut self) {
+        if !self.internal_parameters.proof_log.is_logging_deletions() {
+            return;
+        }
+
+        let nogood_propagator = self.propagators[Self::get_nogood_propagator_id()]
+            .downcast_mut::<NogoodPropagator>()
+            .expect("Provided propagator should be the nogood propagator");
+
+        for nogood in nogood_propagator.drain_deleted_nogoods() {
+            let _ = self.internal_parameters.proof_log.log_deleted_clause(
+                nogood.iter().map(|&predicate| !predicate),
+                &self.variable_names,
+            );
+        }
+    }
+
     /// Introduces any root-level propagations to the proof by introducing them as
     /// nogoods.
     ///
     /// The inference `R -> l` is logged to the proof as follows:
     /// 1. Infernce `R /\ ~l -> false`
     /// 2. Nogood (clause) `l`
-    fn log_root_propagation_to_proof(
-        &mut self,
-        start_trail_index: usize,
-        tag: Option<NonZero<u32>>,
-    ) {
+    fn log_root_propagation_to_proof(&mut self, start_trail_index: usize) {
         pumpkin_assert_eq_simple!(self.get_decision_level(), 0);
 
         if !self.internal_parameters.proof_log.is_logging_inferences() {
@@ -1273,13 +1466,16 @@ impl ConstraintSatisfactionSolver {
             );
 
             let propagated = entry.predicate;
+            let propagator_id = self.reason_store.get_propagator(reason_ref);
 
             // The proof inference for the propagation `R -> l` is `R /\ ~l -> false`.
             let inference_premises = reason.iter().copied().chain(std::iter::once(!propagated));
-            let _ = self
-                .internal_parameters
-                .proof_log
-                .log_inference(tag, inference_premises, None);
+            let _ = self.internal_parameters.proof_log.log_inference(
+                self.propagators.get_tag(propagator_id),
+                self.propagators[propagator_id].inference_label(),
+                inference_premises,
+                None,
+            );
 
             // Since inference steps are only related to the nogood they directly precede,
             // facts derived at the root are also logged as nogoods so they can be used in the
@@ -1355,6 +1551,12 @@ impl ConstraintSatisfactionSolver {
              but this can easily be changed if there is a good reason."
         );
 
+        if let Some(connection) = self.nogood_exchange.as_mut() {
+            // The other solvers are not aware of this propagator, so the nogoods learned from now
+            // on cannot be shared
+            connection.stop_exporting();
+        }
+
         let new_propagator_id = self.propagators.alloc(Box::new(propagator_to_add), tag);
 
         let new_propagator = &mut self.propagators[new_propagator_id];
@@ -1403,7 +1605,12 @@ impl ConstraintSatisfactionSolver {
             Err(ConstraintOperationError::InfeasibleState)
         } else {
             match self.assignments.post_predicate(predicate, None) {
-                Ok(_) => Ok(()),
+                Ok(_) => {
+                    if let Some(connection) = self.nogood_exchange.as_mut() {
+                        connection.export_root_nogood(&[!predicate]);
+                    }
+                    Ok(())
+                }
                 Err(_) => Err(ConstraintOperationError::InfeasibleNogood),
             }
         }
@@ -1430,19 +1637,18 @@ impl ConstraintSatisfactionSolver {
 
         if addition_result.is_err() || self.state.is_conflicting() {
             self.prepare_for_conflict_resolution();
-            self.log_root_propagation_to_proof(num_trail_entries, None);
+            self.log_root_propagation_to_proof(num_trail_entries);
             self.complete_proof();
             return Err(ConstraintOperationError::InfeasibleNogood);
         }
 
-        self.log_root_propagation_to_proof(num_trail_entries, None);
+        self.log_root_propagation_to_proof(num_trail_entries);
 
         // temporary hack for the nogood propagator that does propagation from scratch
         self.propagator_queue.enqueue_propagator(PropagatorId(0), 0);
+        // The root-level propagations are logged to the proof by `propagate`.
         self.propagate();
 
-        self.log_root_propagation_to_proof(num_trail_entries, None);
-
         if self.state.is_infeasible() {
             self.prepare_for_conflict_resolution();
             self.complete_proof();
@@ -1498,6 +1704,15 @@ impl ConstraintSatisfactionSolver {
             "Clauses can only be added in the root"
         );
 
+        let clause = predicates.into_iter().collect::<Vec<_>>();
+
+        // Every input clause is recorded, so the ids of the clauses in the proof match their
+        // position in the input formula.
+        let input_clause_id = self
+            .internal_parameters
+            .proof_log
+            .log_input_clause(clause.iter().copied());
+
         if self.state.is_inconsistent() {
             return Err(ConstraintOperationError::InfeasiblePropagator);
         }
@@ -1507,15 +1722,21 @@ impl ConstraintSatisfactionSolver {
         // 5] /\ [x2 = 3] /\ [x3 > 5]`
 
         let mut are_all_falsified_at_root = true;
-        let predicates = predicates
-            .into_iter()
-            .map(|predicate| {
+        let predicates = clause
+            .iter()
+            .map(|&predicate| {
                 are_all_falsified_at_root &= self.assignments.is_predicate_falsified(predicate);
                 !predicate
             })
             .collect::<Vec<_>>();
 
         if predicates.is_empty() {
+            if let Some(input_clause_id) = input_clause_id {
+                self.internal_parameters
+                    .proof_log
+                    .add_propagation(input_clause_id);
+            }
+
             // This breaks the proof. If it occurs, we should fix up the proof logging.
             // The main issue is that nogoods are not tagged. In the proof that is problematic.
             self.state
@@ -1526,6 +1747,12 @@ impl ConstraintSatisfactionSolver {
         }
 
         if are_all_falsified_at_root {
+            if let Some(input_clause_id) = input_clause_id {
+                self.internal_parameters
+                    .proof_log
+                    .add_propagation(input_clause_id);
+            }
+
             finalize_proof(FinalizingContext {
                 conflict: predicates.into(),
                 propagators: &mut self.propagators,
@@ -1541,6 +1768,14 @@ impl ConstraintSatisfactionSolver {
             return Err(ConstraintOperationError::InfeasibleClause);
         }
 
+        if let Some(connection) = self.nogood_exchange.as_mut() {
+            connection.export_root_nogood(&predicates);
+        }
+
+        if let Some(input_clause_id) = input_clause_id {
+            self.log_strengthened_input_clause(input_clause_id, &clause);
+        }
+
         if let Err(constraint_operation_error) = self.add_nogood(predicates) {
             let _ = self.conclude_proof_unsat();
 
@@ -1553,6 +1788,50 @@ impl ConstraintSatisfactionSolver {
         Ok(())
     }
 
+    /// The nogood propagator removes the predicates which are falsified at the root from the
+    /// clauses it stores. In proofs which refer to clauses by their id, the stored clause must be
+    /// in the proof, so it is derived from the input clause here.
+    fn log_strengthened_input_clause(&mut self, input_clause_id: StepId, clause: &[Predicate]) {
+        let is_satisfied = clause
+            .iter()
+            .any(|&predicate| self.assignments.is_predicate_satisfied(predicate));
+        let is_strengthened = clause
+            .iter()
+            .any(|&predicate| self.assignments.is_predicate_falsified(predicate));
+
+        // A satisfied clause is not stored by the nogood propagator.
+        if is_satisfied || !is_strengthened {
+            return;
+        }
+
+        for &predicate in clause {
+            if self.assignments.is_predicate_falsified(predicate) {
+                explain_root_assignment(
+                    &mut RootExplanationContext {
+                        propagators: &mut self.propagators,
+                        proof_log: &mut self.internal_parameters.proof_log,
+                        unit_nogood_step_ids: &self.unit_nogood_step_ids,
+                        assignments: &self.assignments,
+                        reason_store: &mut self.reason_store,
+                    },
+                    !predicate,
+                );
+            }
+        }
+
+        self.internal_parameters
+            .proof_log
+            .add_propagation(input_clause_id);
+
+        let _ = self.internal_parameters.proof_log.log_learned_clause(
+            clause
+                .iter()
+                .copied()
+                .filter(|&predicate| !self.assignments.is_predicate_falsified(predicate)),
+            &self.variable_names,
+        );
+    }
+
     pub(crate) fn get_decision_level(&self) -> usize {
         self.assignments.get_decision_level()
     }
@@ -1690,13 +1969,20 @@ impl CSPSolverState {
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use super::ConstraintSatisfactionSolver;
     use super::CoreExtractionResult;
     use crate::basic_types::CSPSolverExecutionFlag;
+    use crate::branching::Brancher;
+    use crate::branching::BrancherEvent;
+    use crate::branching::SelectionContext;
     use crate::predicate;
     use crate::predicates::Predicate;
     use crate::propagators::linear_not_equal::LinearNotEqualPropagator;
     use crate::termination::Indefinite;
+    use crate::termination::TimeBudget;
+    use crate::variables::DomainId;
     use crate::variables::TransformableVariable;
     use crate::DefaultBrancher;
 
@@ -1951,4 +2237,140 @@ mod tests {
         let result = solver.add_propagator(propagator, None);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn propagator_watching_a_domain_twice_is_counted_once() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 10, None);
+
+        let result = solver.add_propagator(
+            LinearNotEqualPropagator::new([x.scaled(1), x.scaled(2)].into(), 5),
+            None,
+        );
+        assert!(result.is_ok());
+
+        assert_eq!(1, solver.num_attached_propagators(x));
+    }
+
+    #[test]
+    fn solver_can_be_restarted_after_stopping_at_the_root() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let _ = solver.create_new_integer_variable(1, 1, None);
+
+        let mut brancher = DefaultBrancher::default_over_all_variables(&solver.assignments);
+        let flag = solver.solve(&mut TimeBudget::starting_now(Duration::ZERO), &mut brancher);
+        assert_eq!(flag, CSPSolverExecutionFlag::Timeout);
+        assert_eq!(solver.get_decision_level(), 0);
+
+        solver.restore_state_at_root(&mut brancher);
+
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert_eq!(flag, CSPSolverExecutionFlag::Feasible);
+        assert_eq!(solver.get_decision_level(), 0);
+
+        solver.restore_state_at_root(&mut brancher);
+
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert_eq!(flag, CSPSolverExecutionFlag::Feasible);
+    }
+
+    #[test]
+    fn probe_inspects_propagated_domains_and_restores_the_root() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 10, None);
+        let y = solver.create_new_integer_variable(0, 10, None);
+
+        let result = solver.add_propagator(
+            LinearNotEqualPropagator::new([x.scaled(1), y.scaled(-1)].into(), 0),
+            None,
+        );
+        assert!(result.is_ok());
+
+        let mut brancher = DefaultBrancher::default_over_all_variables(&solver.assignments);
+        let bounds = solver.probe(
+            &[predicate!(x == 3), predicate!(y >= 3), predicate!(y <= 4)],
+            &mut brancher,
+            |context| (context.lower_bound(y), context.upper_bound(y)),
+        );
+        assert_eq!(bounds, Some((4, 4)));
+
+        assert_eq!(solver.get_decision_level(), 0);
+        assert_eq!(solver.get_lower_bound(&y), 0);
+        assert_eq!(solver.get_upper_bound(&y), 10);
+    }
+
+    #[test]
+    fn probe_with_conflicting_predicates_returns_none() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+        let x = solver.create_new_integer_variable(0, 10, None);
+        let y = solver.create_new_integer_variable(0, 10, None);
+
+        let result = solver.add_propagator(
+            LinearNotEqualPropagator::new([x.scaled(1), y.scaled(-1)].into(), 0),
+            None,
+        );
+        assert!(result.is_ok());
+
+        let mut brancher = DefaultBrancher::default_over_all_variables(&solver.assignments);
+        let result = solver.probe(
+            &[predicate!(x == 3), predicate!(y == 3)],
+            &mut brancher,
+            |_| (),
+        );
+        assert_eq!(result, None);
+
+        assert_eq!(solver.get_decision_level(), 0);
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert_eq!(flag, CSPSolverExecutionFlag::Feasible);
+    }
+
+    /// A brancher which records the scopes of the propagators which report a conflict.
+    struct FailureRecordingBrancher {
+        brancher: DefaultBrancher,
+        scopes: Vec<Vec<DomainId>>,
+    }
+
+    impl Brancher for FailureRecordingBrancher {
+        fn next_decision(&mut self, context: &mut SelectionContext) -> Option<Predicate> {
+            self.brancher.next_decision(context)
+        }
+
+        fn on_propagator_failure(&mut self, scope: &[DomainId]) {
+            self.scopes.push(scope.to_vec());
+        }
+
+        fn subscribe_to_events(&self) -> Vec<BrancherEvent> {
+            vec![BrancherEvent::PropagatorFailure]
+        }
+    }
+
+    #[test]
+    fn brancher_is_notified_about_failing_propagators() {
+        let mut solver = ConstraintSatisfactionSolver::default();
+
+        // Three pairwise different variables with only two values is infeasible, but this is
+        // not detected by propagation at the root
+        let variables = [0, 1, 2].map(|_| solver.create_new_integer_variable(0, 1, None));
+        for (index, &x) in variables.iter().enumerate() {
+            for &y in &variables[index + 1..] {
+                let result = solver.add_propagator(
+                    LinearNotEqualPropagator::new([x.scaled(1), y.scaled(-1)].into(), 0),
+                    None,
+                );
+                assert!(result.is_ok());
+            }
+        }
+
+        let mut brancher = FailureRecordingBrancher {
+            brancher: DefaultBrancher::default_over_all_variables(&solver.assignments),
+            scopes: vec![],
+        };
+        let flag = solver.solve(&mut Indefinite, &mut brancher);
+        assert_eq!(flag, CSPSolverExecutionFlag::Infeasible);
+
+        assert!(!brancher.scopes.is_empty());
+        assert!(brancher.scopes.iter().all(|scope| {
+            scope.len() == 2 && scope.iter().all(|domain| variables.contains(domain))
+        }));
+    }
 }
@@ -21,7 +21,9 @@ pub struct Assignments {
     backtrack_events: EventSink,
 
     /// The number of values that have been pruned from the domain.
-    pruned_values: u64,
+    ///
+    /// This is stored as a `u128` since a single bound update can prune close to `2^64` values.
+    pruned_values: u128,
 }
 
 impl Default for Assignments {
@@ -98,7 +100,7 @@ impl Assignments {
     // note that this is an internal method that does _not_ allocate additional information
     // necessary for the solver apart from the domain when creating a new integer variable, use
     // create_new_domain_id in the ConstraintSatisfactionSolver
-    pub(crate) fn grow(&mut self, lower_bound: i32, upper_bound: i32) -> DomainId {
+    pub(crate) fn grow(&mut self, lower_bound: i64, upper_bound: i64) -> DomainId {
         // This is necessary for the metric that maintains relative domain size. It is only updated
         // when values are removed at levels beyond the root, and then it becomes a tricky value to
         // update when a fresh domain needs to be considered.
@@ -136,7 +138,7 @@ impl Assignments {
 
         id
     }
-    pub fn create_new_integer_variable_sparse(&mut self, mut values: Vec<i32>) -> DomainId {
+    pub fn create_new_integer_variable_sparse(&mut self, mut values: Vec<i64>) -> DomainId {
         assert!(
             !values.is_empty(),
             "cannot create a variable with an empty domain"
@@ -260,7 +262,7 @@ impl Assignments {
 
 // methods for getting info about the domains
 impl Assignments {
-    pub(crate) fn get_lower_bound(&self, domain_id: DomainId) -> i32 {
+    pub(crate) fn get_lower_bound(&self, domain_id: DomainId) -> i64 {
         self.domains[domain_id].lower_bound()
     }
 
@@ -268,11 +270,11 @@ impl Assignments {
         &self,
         domain_id: DomainId,
         trail_position: usize,
-    ) -> i32 {
+    ) -> i64 {
         self.domains[domain_id].lower_bound_at_trail_position(trail_position)
     }
 
-    pub(crate) fn get_upper_bound(&self, domain_id: DomainId) -> i32 {
+    pub(crate) fn get_upper_bound(&self, domain_id: DomainId) -> i64 {
         self.domains[domain_id].upper_bound()
     }
 
@@ -280,26 +282,26 @@ impl Assignments {
         &self,
         domain_id: DomainId,
         trail_position: usize,
-    ) -> i32 {
+    ) -> i64 {
         self.domains[domain_id].upper_bound_at_trail_position(trail_position)
     }
 
-    pub(crate) fn get_initial_lower_bound(&self, domain_id: DomainId) -> i32 {
+    pub(crate) fn get_initial_lower_bound(&self, domain_id: DomainId) -> i64 {
         self.domains[domain_id].initial_lower_bound()
     }
 
-    pub(crate) fn get_initial_upper_bound(&self, domain_id: DomainId) -> i32 {
+    pub(crate) fn get_initial_upper_bound(&self, domain_id: DomainId) -> i64 {
         self.domains[domain_id].initial_upper_bound()
     }
 
-    pub(crate) fn get_initial_holes(&self, domain_id: DomainId) -> Vec<i32> {
+    pub(crate) fn get_initial_holes(&self, domain_id: DomainId) -> Vec<i64> {
         self.domains[domain_id].initial_holes.clone()
     }
 
     pub(crate) fn get_assigned_value<Var: IntegerVariable + 'static>(
         &self,
         var: &Var,
-    ) -> Option<i32> {
+    ) -> Option<i64> {
         self.is_domain_assigned(var).then(|| var.lower_bound(self))
     }
 
@@ -356,7 +358,7 @@ impl Assignments {
         predicates
     }
 
-    pub(crate) fn is_value_in_domain(&self, domain_id: DomainId, value: i32) -> bool {
+    pub(crate) fn is_value_in_domain(&self, domain_id: DomainId, value: i64) -> bool {
         let domain = &self.domains[domain_id];
         domain.contains(value)
     }
@@ -364,7 +366,7 @@ impl Assignments {
     pub(crate) fn is_value_in_domain_at_trail_position(
         &self,
         domain_id: DomainId,
-        value: i32,
+        value: i64,
         trail_position: usize,
     ) -> bool {
         self.domains[domain_id].contains_at_trail_position(value, trail_position)
@@ -408,7 +410,7 @@ impl Assignments {
     pub(crate) fn tighten_lower_bound(
         &mut self,
         domain_id: DomainId,
-        new_lower_bound: i32,
+        new_lower_bound: i64,
         reason: Option<ReasonRef>,
     ) -> Result<(), EmptyDomain> {
         // No need to do any changes if the new lower bound is weaker.
@@ -444,7 +446,7 @@ impl Assignments {
             &mut self.events,
         );
 
-        self.pruned_values += domain.lower_bound().abs_diff(old_lower_bound) as u64;
+        self.pruned_values += domain.lower_bound().abs_diff(old_lower_bound) as u128;
 
         domain.verify_consistency()
     }
@@ -452,7 +454,7 @@ impl Assignments {
     pub(crate) fn tighten_upper_bound(
         &mut self,
         domain_id: DomainId,
-        new_upper_bound: i32,
+        new_upper_bound: i64,
         reason: Option<ReasonRef>,
     ) -> Result<(), EmptyDomain> {
         // No need to do any changes if the new upper bound is weaker.
@@ -488,7 +490,7 @@ impl Assignments {
             &mut self.events,
         );
 
-        self.pruned_values += old_upper_bound.abs_diff(domain.upper_bound()) as u64;
+        self.pruned_values += old_upper_bound.abs_diff(domain.upper_bound()) as u128;
 
         domain.verify_consistency()
     }
@@ -496,7 +498,7 @@ impl Assignments {
     pub(crate) fn make_assignment(
         &mut self,
         domain_id: DomainId,
-        assigned_value: i32,
+        assigned_value: i64,
         reason: Option<ReasonRef>,
     ) -> Result<(), EmptyDomain> {
         // only tighten the lower bound if needed
@@ -515,7 +517,7 @@ impl Assignments {
     pub(crate) fn remove_value_from_domain(
         &mut self,
         domain_id: DomainId,
-        removed_value_from_domain: i32,
+        removed_value_from_domain: i64,
         reason: Option<ReasonRef>,
     ) -> Result<(), EmptyDomain> {
         // No need to do any changes if the value is not present anyway.
@@ -551,8 +553,8 @@ impl Assignments {
             &mut self.events,
         );
 
-        let changed_lower_bound = domain.lower_bound().abs_diff(old_lower_bound) as u64;
-        let changed_upper_bound = old_upper_bound.abs_diff(domain.upper_bound()) as u64;
+        let changed_lower_bound = domain.lower_bound().abs_diff(old_lower_bound) as u128;
+        let changed_upper_bound = old_upper_bound.abs_diff(domain.upper_bound()) as u128;
 
         if changed_lower_bound + changed_upper_bound > 0 {
             self.pruned_values += changed_upper_bound + changed_lower_bound;
@@ -673,7 +675,7 @@ impl Assignments {
         new_decision_level: usize,
         last_notified_trail_index: usize,
         is_watching_any_backtrack_events: bool,
-    ) -> Vec<(DomainId, i32)> {
+    ) -> Vec<(DomainId, i64)> {
         let mut unfixed_variables = Vec::new();
         let num_trail_entries_before_synchronisation = self.num_trail_entries();
 
@@ -696,8 +698,8 @@ impl Assignments {
 
             let trail_index = num_trail_entries_before_synchronisation - index - 1;
 
-            let add_on_upper_bound = entry.old_upper_bound.abs_diff(upper_bound_before) as u64;
-            let add_on_lower_bound = lower_bound_before.abs_diff(entry.old_lower_bound) as u64;
+            let add_on_upper_bound = entry.old_upper_bound.abs_diff(upper_bound_before) as u128;
+            let add_on_lower_bound = lower_bound_before.abs_diff(entry.old_lower_bound) as u128;
             self.pruned_values -= add_on_upper_bound + add_on_lower_bound;
 
             if let Predicate::NotEqual { .. } = entry.predicate {
@@ -747,8 +749,10 @@ impl Assignments {
     }
 
     /// Get the number of values pruned from all the domains.
+    ///
+    /// The count saturates at [`u64::MAX`].
     pub(crate) fn get_pruned_value_count(&self) -> u64 {
-        self.pruned_values
+        self.pruned_values.try_into().unwrap_or(u64::MAX)
     }
 }
 
@@ -776,8 +780,8 @@ pub(crate) struct ConstraintProgrammingTrailEntry {
     pub(crate) predicate: Predicate,
     /// Explicitly store the bound before the predicate was applied so that it is easier later on
     ///  to update the bounds when backtracking.
-    pub(crate) old_lower_bound: i32,
-    pub(crate) old_upper_bound: i32,
+    pub(crate) old_lower_bound: i64,
+    pub(crate) old_upper_bound: i64,
     /// Stores the a reference to the reason in the `ReasonStore`, only makes sense if a
     /// propagation  took place, e.g., does _not_ make sense in the case of a decision or if
     /// the update was due  to synchronisation from the propositional trail.
@@ -792,14 +796,14 @@ struct PairDecisionLevelTrailPosition {
 
 #[derive(Clone, Debug)]
 struct BoundUpdateInfo {
-    bound: i32,
+    bound: i64,
     decision_level: usize,
     trail_position: usize,
 }
 
 #[derive(Clone, Debug)]
 struct HoleUpdateInfo {
-    removed_value: i32,
+    removed_value: i64,
 
     triggered_lower_bound_update: bool,
     triggered_upper_bound_update: bool,
@@ -821,17 +825,17 @@ struct IntegerDomain {
     /// This is done to avoid going through 'hole_updates'.
     /// It maps a removed value with its decision level and trail position.
     /// In the future we could consider using direct hashing if the domain is small.
-    holes: HashMap<i32, PairDecisionLevelTrailPosition>,
+    holes: HashMap<i64, PairDecisionLevelTrailPosition>,
     // Records the trail entry at which all of the root bounds are true
     initial_bounds_below_trail: usize,
     /// The holes that exist in the input problem.
-    initial_holes: Vec<i32>,
+    initial_holes: Vec<i64>,
 }
 
 impl IntegerDomain {
     fn new(
-        lower_bound: i32,
-        upper_bound: i32,
+        lower_bound: i64,
+        upper_bound: i64,
         id: DomainId,
         initial_bounds_below_trail: usize,
     ) -> IntegerDomain {
@@ -860,7 +864,7 @@ impl IntegerDomain {
         }
     }
 
-    fn lower_bound(&self) -> i32 {
+    fn lower_bound(&self) -> i64 {
         // the last entry contains the current lower bound
         self.lower_bound_updates
             .last()
@@ -875,13 +879,13 @@ impl IntegerDomain {
             .decision_level
     }
 
-    fn initial_lower_bound(&self) -> i32 {
+    fn initial_lower_bound(&self) -> i64 {
         // the first entry is never removed,
         // and contains the bound that was assigned upon creation
         self.lower_bound_updates[0].bound
     }
 
-    fn lower_bound_at_trail_position(&self, trail_position: usize) -> i32 {
+    fn lower_bound_at_trail_position(&self, trail_position: usize) -> i64 {
         // for now a simple inefficient linear scan
         // in the future this should be done with binary search
         // possibly caching old queries, and
@@ -901,7 +905,7 @@ impl IntegerDomain {
             .bound
     }
 
-    fn upper_bound(&self) -> i32 {
+    fn upper_bound(&self) -> i64 {
         // the last entry contains the current upper bound
         self.upper_bound_updates
             .last()
@@ -916,13 +920,13 @@ impl IntegerDomain {
             .decision_level
     }
 
-    fn initial_upper_bound(&self) -> i32 {
+    fn initial_upper_bound(&self) -> i64 {
         // the first entry is never removed,
         // and contains the bound that was assigned upon creation
         self.upper_bound_updates[0].bound
     }
 
-    fn upper_bound_at_trail_position(&self, trail_position: usize) -> i32 {
+    fn upper_bound_at_trail_position(&self, trail_position: usize) -> i64 {
         // for now a simple inefficient linear scan
         // in the future this should be done with binary search
         // possibly caching old queries, and
@@ -950,13 +954,13 @@ impl IntegerDomain {
         IntegerDomainIterator::new(self)
     }
 
-    fn contains(&self, value: i32) -> bool {
+    fn contains(&self, value: i64) -> bool {
         self.lower_bound() <= value
             && value <= self.upper_bound()
             && !self.holes.contains_key(&value)
     }
 
-    fn contains_at_trail_position(&self, value: i32, trail_position: usize) -> bool {
+    fn contains_at_trail_position(&self, value: i64, trail_position: usize) -> bool {
         // If the value is out of bounds,
         // then we can safety say that the value is not in the domain.
         if self.lower_bound_at_trail_position(trail_position) > value
@@ -979,7 +983,7 @@ impl IntegerDomain {
 
     fn remove_value(
         &mut self,
-        removed_value: i32,
+        removed_value: i64,
         decision_level: usize,
         trail_position: usize,
         events: &mut EventSink,
@@ -1043,7 +1047,7 @@ impl IntegerDomain {
 
     fn set_upper_bound(
         &mut self,
-        new_upper_bound: i32,
+        new_upper_bound: i64,
         decision_level: usize,
         trail_position: usize,
         events: &mut EventSink,
@@ -1090,7 +1094,7 @@ impl IntegerDomain {
 
     fn set_lower_bound(
         &mut self,
-        new_lower_bound: i32,
+        new_lower_bound: i64,
         decision_level: usize,
         trail_position: usize,
         events: &mut EventSink,
@@ -1332,7 +1336,7 @@ impl IntegerDomain {
 #[derive(Debug)]
 pub(crate) struct IntegerDomainIterator<'a> {
     domain: &'a IntegerDomain,
-    current_value: i32,
+    current_value: i64,
 }
 
 impl IntegerDomainIterator<'_> {
@@ -1345,8 +1349,8 @@ impl IntegerDomainIterator<'_> {
 }
 
 impl Iterator for IntegerDomainIterator<'_> {
-    type Item = i32;
-    fn next(&mut self) -> Option<i32> {
+    type Item = i64;
+    fn next(&mut self) -> Option<i64> {
         // We would not expect to iterate through inconsistent domains,
         // although we support trying to do so. Not sure if this is good a idea?
         if self.domain.verify_consistency().is_err() {
@@ -2041,28 +2045,28 @@ mod tests {
         let _ = assignments.remove_value_from_domain(domain_id, 2, None);
         let _ = assignments.tighten_upper_bound(domain_id, 6, None);
 
-        let lb_predicate = |lower_bound: i32| -> Predicate {
+        let lb_predicate = |lower_bound: i64| -> Predicate {
             Predicate::LowerBound {
                 domain_id,
                 lower_bound,
             }
         };
 
-        let ub_predicate = |upper_bound: i32| -> Predicate {
+        let ub_predicate = |upper_bound: i64| -> Predicate {
             Predicate::UpperBound {
                 domain_id,
                 upper_bound,
             }
         };
 
-        let eq_predicate = |equality_constant: i32| -> Predicate {
+        let eq_predicate = |equality_constant: i64| -> Predicate {
             Predicate::Equal {
                 domain_id,
                 equality_constant,
             }
         };
 
-        let neq_predicate = |not_equal_constant: i32| -> Predicate {
+        let neq_predicate = |not_equal_constant: i64| -> Predicate {
             Predicate::NotEqual {
                 domain_id,
                 not_equal_constant,
@@ -4,33 +4,32 @@ use enumset::EnumSet;
 use crate::engine::IntDomainEvent;
 
 #[derive(Debug, Copy, Clone)]
-pub(crate) struct DomainEvents {
+pub struct DomainEvents {
     int_events: Option<EnumSet<IntDomainEvent>>,
 }
 
 impl DomainEvents {
     /// DomainEvents with both lower and upper bound tightening (but not other value removal).
-    pub(crate) const BOUNDS: DomainEvents = DomainEvents::create_with_int_events(enum_set!(
+    pub const BOUNDS: DomainEvents = DomainEvents::create_with_int_events(enum_set!(
         IntDomainEvent::LowerBound | IntDomainEvent::UpperBound
     ));
     // this is all options right now, but won't be once we add variables of other types
     /// DomainEvents with lower and upper bound tightening, assigning to a single value, and
     ///  single value removal.
-    pub(crate) const ANY_INT: DomainEvents = DomainEvents::create_with_int_events(enum_set!(
+    pub const ANY_INT: DomainEvents = DomainEvents::create_with_int_events(enum_set!(
         IntDomainEvent::Assign
             | IntDomainEvent::LowerBound
             | IntDomainEvent::UpperBound
             | IntDomainEvent::Removal
     ));
     /// DomainEvents with only lower bound tightening.
-    pub(crate) const LOWER_BOUND: DomainEvents =
+    pub const LOWER_BOUND: DomainEvents =
         DomainEvents::create_with_int_events(enum_set!(IntDomainEvent::LowerBound));
     /// DomainEvents with only upper bound tightening.
-    #[allow(unused, reason = "will be part of public API at some point")]
-    pub(crate) const UPPER_BOUND: DomainEvents =
+    pub const UPPER_BOUND: DomainEvents =
         DomainEvents::create_with_int_events(enum_set!(IntDomainEvent::UpperBound));
     /// DomainEvents with only assigning to a single value.
-    pub(crate) const ASSIGN: DomainEvents =
+    pub const ASSIGN: DomainEvents =
         DomainEvents::create_with_int_events(enum_set!(IntDomainEvent::Assign));
 }
 
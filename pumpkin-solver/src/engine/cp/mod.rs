@@ -6,15 +6,15 @@ pub(crate) mod propagation;
 mod propagator_queue;
 pub(crate) mod reason;
 pub(crate) mod test_solver;
-mod trailed;
+pub(crate) mod trailed;
 mod watch_list_cp;
 
 pub(crate) use assignments::Assignments;
-pub(crate) use assignments::EmptyDomain;
+pub use assignments::EmptyDomain;
 pub(crate) use event_sink::*;
 pub(crate) use propagator_queue::PropagatorQueue;
 pub(crate) use trailed::*;
-pub(crate) use watch_list_cp::IntDomainEvent;
+pub use watch_list_cp::IntDomainEvent;
 pub(crate) use watch_list_cp::WatchListCP;
 pub(crate) use watch_list_cp::Watchers;
 
@@ -4,13 +4,16 @@ use super::HasAssignments;
 use crate::basic_types::PredicateId;
 use crate::basic_types::PredicateIdGenerator;
 use crate::containers::KeyValueHeap;
+#[cfg(doc)]
+use crate::engine::propagation::Propagator;
 use crate::engine::Assignments;
 use crate::predicates::Predicate;
 
 /// The context that is available when lazily explaining propagations.
 ///
-/// See [`pumpkin_solver::engine::propagation::Propagator`] for more information.
-pub(crate) struct ExplanationContext<'a> {
+/// See [`Propagator::lazy_explanation`] for more information.
+#[derive(Debug)]
+pub struct ExplanationContext<'a> {
     assignments: &'a Assignments,
     current_nogood: CurrentNogood<'a>,
 }
@@ -37,8 +40,7 @@ impl<'a> ExplanationContext<'a> {
     /// The working nogood does not necessarily contain the predicate that is being explained.
     /// However, the explanation will be used to either resolve with the working nogood or minimize
     /// it some other way.
-    #[allow(unused, reason = "it will be part of the public API at some point")]
-    pub(crate) fn working_nogood(&self) -> impl Iterator<Item = Predicate> + '_ {
+    pub fn working_nogood(&self) -> impl Iterator<Item = Predicate> + '_ {
         self.current_nogood.iter()
     }
 }
@@ -56,6 +58,7 @@ static EMPTY_PREDICATE_IDS: LazyLock<PredicateIdGenerator> =
 
 static EMPTY_PREDICATES: [Predicate; 0] = [];
 
+#[derive(Debug)]
 pub(crate) struct CurrentNogood<'a> {
     heap: &'a KeyValueHeap<PredicateId, u32>,
     visited: &'a [Predicate],
@@ -12,7 +12,8 @@ use crate::engine::TrailedInteger;
 use crate::engine::TrailedValues;
 use crate::pumpkin_assert_simple;
 
-pub(crate) struct PropagationContextWithTrailedValues<'a> {
+#[derive(Debug)]
+pub struct PropagationContextWithTrailedValues<'a> {
     pub(crate) trailed_values: &'a mut TrailedValues,
     pub(crate) assignments: &'a Assignments,
 }
@@ -25,7 +26,7 @@ impl<'a> PropagationContextWithTrailedValues<'a> {
         }
     }
 
-    pub(crate) fn as_readonly(&self) -> PropagationContext<'_> {
+    pub fn as_readonly(&self) -> PropagationContext<'_> {
         PropagationContext {
             assignments: self.assignments,
         }
@@ -41,7 +42,7 @@ impl<'a> PropagationContextWithTrailedValues<'a> {
 /// Note that the [`PropagationContext`] is the only point of communication beween
 /// the propagations and the solver during propagation.
 #[derive(Clone, Copy, Debug)]
-pub(crate) struct PropagationContext<'a> {
+pub struct PropagationContext<'a> {
     pub assignments: &'a Assignments,
 }
 
@@ -52,7 +53,7 @@ impl<'a> PropagationContext<'a> {
 }
 
 #[derive(Debug)]
-pub(crate) struct PropagationContextMut<'a> {
+pub struct PropagationContextMut<'a> {
     pub(crate) trailed_values: &'a mut TrailedValues,
     pub(crate) assignments: &'a mut Assignments,
     pub(crate) reason_store: &'a mut ReasonStore,
@@ -109,32 +110,32 @@ impl<'a> PropagationContextMut<'a> {
         }
     }
 
-    pub(crate) fn as_trailed_readonly(&mut self) -> PropagationContextWithTrailedValues {
+    pub fn as_trailed_readonly(&mut self) -> PropagationContextWithTrailedValues {
         PropagationContextWithTrailedValues {
             trailed_values: self.trailed_values,
             assignments: self.assignments,
         }
     }
 
-    pub(crate) fn as_readonly(&self) -> PropagationContext<'_> {
+    pub fn as_readonly(&self) -> PropagationContext<'_> {
         PropagationContext {
             assignments: self.assignments,
         }
     }
 
-    pub(crate) fn get_decision_level(&self) -> usize {
+    pub fn get_decision_level(&self) -> usize {
         self.assignments.get_decision_level()
     }
 }
 
-/// A trait which defines common methods for retrieving the [`Assignments`] and
-/// [`AssignmentsPropositional`] from the structure which implements this trait.
+/// A trait which defines common methods for retrieving the assignments from the structure which
+/// implements this trait.
 pub trait HasAssignments {
-    /// Returns the stored [`Assignments`].
+    /// Returns the stored assignments.
     fn assignments(&self) -> &Assignments;
 }
 
-pub(crate) trait HasTrailedValues {
+pub trait HasTrailedValues {
     fn trailed_values(&self) -> &TrailedValues;
     fn trailed_values_mut(&mut self) -> &mut TrailedValues;
 }
@@ -181,7 +182,7 @@ mod private {
     }
 }
 
-pub(crate) trait ManipulateTrailedValues: HasTrailedValues {
+pub trait ManipulateTrailedValues: HasTrailedValues {
     fn new_trailed_integer(&mut self, initial_value: i64) -> TrailedInteger {
         self.trailed_values_mut().grow(initial_value)
     }
@@ -202,7 +203,7 @@ pub(crate) trait ManipulateTrailedValues: HasTrailedValues {
 
 impl<T: HasTrailedValues> ManipulateTrailedValues for T {}
 
-pub(crate) trait ReadDomains: HasAssignments {
+pub trait ReadDomains: HasAssignments {
     fn is_predicate_satisfied(&self, predicate: Predicate) -> bool {
         self.assignments()
             .evaluate_predicate(predicate)
@@ -232,7 +233,7 @@ pub(crate) trait ReadDomains: HasAssignments {
         self.lower_bound(var) == self.upper_bound(var)
     }
 
-    fn lower_bound<Var: IntegerVariable>(&self, var: &Var) -> i32 {
+    fn lower_bound<Var: IntegerVariable>(&self, var: &Var) -> i64 {
         var.lower_bound(self.assignments())
     }
 
@@ -240,11 +241,11 @@ pub(crate) trait ReadDomains: HasAssignments {
         &self,
         var: &Var,
         trail_position: usize,
-    ) -> i32 {
+    ) -> i64 {
         var.lower_bound_at_trail_position(self.assignments(), trail_position)
     }
 
-    fn upper_bound<Var: IntegerVariable>(&self, var: &Var) -> i32 {
+    fn upper_bound<Var: IntegerVariable>(&self, var: &Var) -> i64 {
         var.upper_bound(self.assignments())
     }
 
@@ -252,15 +253,15 @@ pub(crate) trait ReadDomains: HasAssignments {
         &self,
         var: &Var,
         trail_position: usize,
-    ) -> i32 {
+    ) -> i64 {
         var.upper_bound_at_trail_position(self.assignments(), trail_position)
     }
 
-    fn contains<Var: IntegerVariable>(&self, var: &Var, value: i32) -> bool {
+    fn contains<Var: IntegerVariable>(&self, var: &Var, value: i64) -> bool {
         var.contains(self.assignments(), value)
     }
 
-    fn iterate_domain<Var: IntegerVariable>(&self, var: &Var) -> impl Iterator<Item = i32> {
+    fn iterate_domain<Var: IntegerVariable>(&self, var: &Var) -> impl Iterator<Item = i64> {
         var.iterate_domain(self.assignments())
     }
 }
@@ -268,10 +269,10 @@ pub(crate) trait ReadDomains: HasAssignments {
 impl<T: HasAssignments> ReadDomains for T {}
 
 impl PropagationContextMut<'_> {
-    pub(crate) fn remove<Var: IntegerVariable, R: Into<Reason>>(
+    pub fn remove<Var: IntegerVariable, R: Into<Reason>>(
         &mut self,
         var: &Var,
-        value: i32,
+        value: i64,
         reason: R,
     ) -> Result<(), EmptyDomain> {
         if var.contains(self.assignments, value) {
@@ -282,10 +283,10 @@ impl PropagationContextMut<'_> {
         Ok(())
     }
 
-    pub(crate) fn set_upper_bound<Var: IntegerVariable, R: Into<Reason>>(
+    pub fn set_upper_bound<Var: IntegerVariable, R: Into<Reason>>(
         &mut self,
         var: &Var,
-        bound: i32,
+        bound: i64,
         reason: R,
     ) -> Result<(), EmptyDomain> {
         if bound < var.upper_bound(self.assignments) {
@@ -296,10 +297,10 @@ impl PropagationContextMut<'_> {
         Ok(())
     }
 
-    pub(crate) fn set_lower_bound<Var: IntegerVariable, R: Into<Reason>>(
+    pub fn set_lower_bound<Var: IntegerVariable, R: Into<Reason>>(
         &mut self,
         var: &Var,
-        bound: i32,
+        bound: i64,
         reason: R,
     ) -> Result<(), EmptyDomain> {
         if bound > var.lower_bound(self.assignments) {
@@ -311,11 +312,11 @@ impl PropagationContextMut<'_> {
         Ok(())
     }
 
-    pub(crate) fn evaluate_predicate(&self, predicate: Predicate) -> Option<bool> {
+    pub fn evaluate_predicate(&self, predicate: Predicate) -> Option<bool> {
         self.assignments.evaluate_predicate(predicate)
     }
 
-    pub(crate) fn post_predicate<R: Into<Reason>>(
+    pub fn post_predicate<R: Into<Reason>>(
         &mut self,
         predicate: Predicate,
         reason: R,
@@ -353,7 +354,7 @@ impl PropagationContextMut<'_> {
         }
     }
 
-    pub(crate) fn assign_literal<R: Into<Reason> + Clone>(
+    pub fn assign_literal<R: Into<Reason> + Clone>(
         &mut self,
         boolean: &Literal,
         truth_value: bool,
@@ -12,6 +12,12 @@ use crate::engine::Assignments;
 use crate::engine::TrailedValues;
 use crate::engine::WatchListCP;
 use crate::engine::Watchers;
+#[cfg(doc)]
+use crate::variables::AffineView;
+#[cfg(doc)]
+use crate::variables::DomainId;
+#[cfg(doc)]
+use crate::Solver;
 
 /// [`PropagatorInitialisationContext`] is used when [`Propagator`]s are initialised after creation.
 ///
@@ -19,7 +25,7 @@ use crate::engine::Watchers;
 /// Propagators use the [`PropagatorInitialisationContext`] to register to domain changes
 /// of variables and to retrieve the current bounds of variables.
 #[derive(Debug)]
-pub(crate) struct PropagatorInitialisationContext<'a> {
+pub struct PropagatorInitialisationContext<'a> {
     watch_list: &'a mut WatchListCP,
     pub(crate) trailed_values: &'a mut TrailedValues,
     propagator_id: PropagatorId,
@@ -45,14 +51,14 @@ impl PropagatorInitialisationContext<'_> {
         }
     }
 
-    pub(crate) fn as_trailed_readonly(&mut self) -> PropagationContextWithTrailedValues {
+    pub fn as_trailed_readonly(&mut self) -> PropagationContextWithTrailedValues {
         PropagationContextWithTrailedValues {
             trailed_values: self.trailed_values,
             assignments: self.assignments,
         }
     }
 
-    pub(crate) fn as_readonly(&self) -> PropagationContext {
+    pub fn as_readonly(&self) -> PropagationContext {
         PropagationContext::new(self.assignments)
     }
 
@@ -67,7 +73,7 @@ impl PropagatorInitialisationContext<'_> {
     ///
     /// Note that the [`LocalId`] is used to differentiate between [`DomainId`]s and
     /// [`AffineView`]s.
-    pub(crate) fn register<Var: IntegerVariable>(
+    pub fn register<Var: IntegerVariable>(
         &mut self,
         var: Var,
         domain_events: DomainEvents,
@@ -90,7 +96,7 @@ impl PropagatorInitialisationContext<'_> {
     }
 
     /// Subscribes the propagator to the given [`DomainEvents`] when they are undone during
-    /// backtracking. This method is complementary to [`PropagatorConstructorContext::register`],
+    /// backtracking. This method is complementary to [`PropagatorInitialisationContext::register`],
     /// the [`LocalId`]s provided to both of these method should be the same for the same variable.
     ///
     /// The domain events determine when [`Propagator::notify_backtrack()`] will be called on the
@@ -102,7 +108,7 @@ impl PropagatorInitialisationContext<'_> {
     ///
     /// Note that the [`LocalId`] is used to differentiate between [`DomainId`]s and
     /// [`AffineView`]s.
-    pub(crate) fn register_for_backtrack_events<Var: IntegerVariable>(
+    pub fn register_for_backtrack_events<Var: IntegerVariable>(
         &mut self,
         var: Var,
         domain_events: DomainEvents,
@@ -121,7 +127,7 @@ impl PropagatorInitialisationContext<'_> {
         var
     }
 
-    pub(crate) fn get_next_local_id(&self) -> LocalId {
+    pub fn get_next_local_id(&self) -> LocalId {
         self.next_local_id
     }
 }
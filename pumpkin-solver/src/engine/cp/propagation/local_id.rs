@@ -1,14 +1,14 @@
 /// A local id uniquely identifies a variable within a specific propagator. A local id can be
 /// thought of as the index of the variable in the propagator.
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub(crate) struct LocalId(u32);
+pub struct LocalId(u32);
 
 impl LocalId {
-    pub(crate) const fn from(value: u32) -> Self {
+    pub const fn from(value: u32) -> Self {
         LocalId(value)
     }
 
-    pub(crate) fn unpack(self) -> u32 {
+    pub fn unpack(self) -> u32 {
         self.0
     }
 }
@@ -84,14 +84,14 @@ pub(crate) mod propagator_var_id;
 pub(crate) mod store;
 
 pub(crate) use contexts::explanation_context::CurrentNogood;
-pub(crate) use contexts::explanation_context::ExplanationContext;
-pub(crate) use contexts::propagation_context::PropagationContext;
-pub(crate) use contexts::propagation_context::PropagationContextMut;
-pub(crate) use contexts::propagation_context::ReadDomains;
-pub(crate) use contexts::propagator_initialisation_context::PropagatorInitialisationContext;
-pub(crate) use local_id::LocalId;
-pub(crate) use propagator::EnqueueDecision;
-pub(crate) use propagator::Propagator;
+pub use contexts::explanation_context::ExplanationContext;
+pub use contexts::propagation_context::PropagationContext;
+pub use contexts::propagation_context::PropagationContextMut;
+pub use contexts::propagation_context::ReadDomains;
+pub use contexts::propagator_initialisation_context::PropagatorInitialisationContext;
+pub use local_id::LocalId;
+pub use propagator::EnqueueDecision;
+pub use propagator::Propagator;
 pub(crate) use propagator_id::PropagatorId;
 pub(crate) use propagator_var_id::PropagatorVarId;
 
@@ -14,7 +14,7 @@ use crate::create_statistics_struct;
 use crate::engine::opaque_domain_event::OpaqueDomainEvent;
 use crate::engine::propagation::local_id::LocalId;
 #[cfg(doc)]
-use crate::engine::ConstraintSatisfactionSolver;
+use crate::engine::reason::Reason;
 use crate::predicates::Predicate;
 use crate::predicates::PropositionalConjunction;
 #[cfg(doc)]
@@ -22,6 +22,8 @@ use crate::pumpkin_asserts::PUMPKIN_ASSERT_ADVANCED;
 #[cfg(doc)]
 use crate::pumpkin_asserts::PUMPKIN_ASSERT_EXTREME;
 use crate::statistics::statistic_logger::StatisticLogger;
+#[cfg(doc)]
+use crate::Solver;
 
 // We need to use this to cast from `Box<dyn Propagator>` to `NogoodPropagator`; rust inherently
 // does not allow downcasting from the trait definition to its concrete type.
@@ -35,17 +37,28 @@ impl_downcast!(Propagator);
 /// functions have default implementations. For initial development, the required functions are
 /// enough, but a more mature implementation considers all functions in most cases.
 ///
-/// See the [`crate::engine::cp::propagation`] documentation for more details.
-pub(crate) trait Propagator: Downcast {
+/// See the [`crate::propagation`] documentation for more details.
+pub trait Propagator: Downcast {
     /// Return the name of the propagator, this is a convenience method that is used for printing.
     fn name(&self) -> &str;
 
+    /// Return the label which identifies the filtering algorithm of the propagator.
+    ///
+    /// The label is attached to the inferences of the propagator in the proof log, so proof
+    /// processors can determine how an inference should be checked. Unlike [`Propagator::name`],
+    /// the label is part of the proof format and should therefore not change between versions.
+    ///
+    /// By default, the name of the propagator is used as its label.
+    fn inference_label(&self) -> &str {
+        self.name()
+    }
+
     /// A propagation method that is used to help debugging.
     ///
     /// This method propagates without relying on internal data structures, hence the immutable
     /// &self parameter. It is usually best to implement this propagation method in the simplest
     /// but correct way. When the assert level is set to [`PUMPKIN_ASSERT_ADVANCED`] or
-    /// [`PUMPKIN_ASSERT_EXTREME`] (see [`crate::pumpkin_asserts`]) this method will be called
+    /// [`PUMPKIN_ASSERT_EXTREME`] this method will be called
     /// to double check the reasons for failures and propagations that have been reported by
     /// this propagator.
     ///
@@ -53,8 +66,7 @@ pub(crate) trait Propagator: Downcast {
     /// the solver until no further propagations happen.
     fn debug_propagate_from_scratch(&self, context: PropagationContextMut) -> PropagationStatusCP;
 
-    /// Propagate method that will be called during search (e.g. in
-    /// [`ConstraintSatisfactionSolver::solve`]).
+    /// Propagate method that will be called during search (e.g. in [`Solver::satisfy`]).
     ///
     /// This method extends the current partial
     /// assignments with inferred domain changes found by the
@@ -118,7 +130,7 @@ pub(crate) trait Propagator: Downcast {
     ) {
     }
 
-    /// Called each time the [`ConstraintSatisfactionSolver`] backtracks, the propagator can then
+    /// Called each time the [`Solver`] backtracks, the propagator can then
     /// update its internal data structures given the new variable domains.
     ///
     /// By default this function does nothing.
@@ -136,8 +148,7 @@ pub(crate) trait Propagator: Downcast {
     }
 
     /// Initialises the propagator without performing propagation. This method is called only once
-    /// by the [`ConstraintSatisfactionSolver`] when the propagator is added using
-    /// [`ConstraintSatisfactionSolver::add_propagator`].
+    /// by the [`Solver`] when the propagator is added using [`Solver::add_propagator`].
     ///
     /// The method can be used to detect root-level inconsistencies and to register variables used
     /// for notifications (see [`Propagator::notify`]) by calling
@@ -184,7 +195,7 @@ pub(crate) trait Propagator: Downcast {
 
 /// Indicator of what to do when a propagator is notified.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub(crate) enum EnqueueDecision {
+pub enum EnqueueDecision {
     /// The propagator should be enqueued.
     Enqueue,
     /// The propagator should not be enqueued.
@@ -48,7 +48,7 @@ impl Debug for BoxedPropagator {
 }
 
 impl TestSolver {
-    pub(crate) fn new_variable(&mut self, lb: i32, ub: i32) -> DomainId {
+    pub(crate) fn new_variable(&mut self, lb: i64, ub: i64) -> DomainId {
         self.watch_list.grow();
         self.assignments_integer.grow(lb, ub)
     }
@@ -83,20 +83,20 @@ impl TestSolver {
         Ok(propagator)
     }
 
-    pub(crate) fn contains<Var: IntegerVariable>(&self, var: Var, value: i32) -> bool {
+    pub(crate) fn contains<Var: IntegerVariable>(&self, var: Var, value: i64) -> bool {
         var.contains(&self.assignments_integer, value)
     }
 
-    pub(crate) fn lower_bound(&self, var: DomainId) -> i32 {
+    pub(crate) fn lower_bound(&self, var: DomainId) -> i64 {
         self.assignments_integer.get_lower_bound(var)
     }
 
     pub(crate) fn increase_lower_bound_and_notify(
         &mut self,
         propagator: &mut BoxedPropagator,
-        id: i32,
+        id: i64,
         var: DomainId,
-        value: i32,
+        value: i64,
     ) -> EnqueueDecision {
         let result = self
             .assignments_integer
@@ -120,9 +120,9 @@ impl TestSolver {
     pub(crate) fn decrease_upper_bound_and_notify(
         &mut self,
         propagator: &mut BoxedPropagator,
-        id: i32,
+        id: i64,
         var: DomainId,
-        value: i32,
+        value: i64,
     ) -> EnqueueDecision {
         let result = self
             .assignments_integer
@@ -153,11 +153,11 @@ impl TestSolver {
             .is_literal_assigned_false(var)
     }
 
-    pub(crate) fn upper_bound(&self, var: DomainId) -> i32 {
+    pub(crate) fn upper_bound(&self, var: DomainId) -> i64 {
         self.assignments_integer.get_upper_bound(var)
     }
 
-    pub(crate) fn remove(&mut self, var: DomainId, value: i32) -> Result<(), EmptyDomain> {
+    pub(crate) fn remove(&mut self, var: DomainId, value: i64) -> Result<(), EmptyDomain> {
         self.assignments_integer
             .remove_value_from_domain(var, value, None)
     }
@@ -244,7 +244,7 @@ impl TestSolver {
             .expect("reason_ref should not be stale")
     }
 
-    pub(crate) fn assert_bounds(&self, var: DomainId, lb: i32, ub: i32) {
+    pub(crate) fn assert_bounds(&self, var: DomainId, lb: i64, ub: i64) {
         let actual_lb = self.lower_bound(var);
         let actual_ub = self.upper_bound(var);
 
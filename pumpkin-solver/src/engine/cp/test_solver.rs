@@ -55,7 +55,7 @@ impl Default for TestSolver {
 }
 
 impl TestSolver {
-    pub(crate) fn new_variable(&mut self, lb: i32, ub: i32) -> DomainId {
+    pub(crate) fn new_variable(&mut self, lb: i64, ub: i64) -> DomainId {
         self.watch_list.grow();
         self.assignments.grow(lb, ub)
     }
@@ -90,11 +90,11 @@ impl TestSolver {
         Ok(id)
     }
 
-    pub(crate) fn contains<Var: IntegerVariable>(&self, var: Var, value: i32) -> bool {
+    pub(crate) fn contains<Var: IntegerVariable>(&self, var: Var, value: i64) -> bool {
         var.contains(&self.assignments, value)
     }
 
-    pub(crate) fn lower_bound(&self, var: DomainId) -> i32 {
+    pub(crate) fn lower_bound(&self, var: DomainId) -> i64 {
         self.assignments.get_lower_bound(var)
     }
 
@@ -103,7 +103,7 @@ impl TestSolver {
         propagator: PropagatorId,
         local_id: u32,
         var: DomainId,
-        value: i32,
+        value: i64,
     ) -> EnqueueDecision {
         let result = self.assignments.tighten_lower_bound(var, value, None);
         assert!(result.is_ok(), "The provided value to `increase_lower_bound` caused an empty domain, generally the propagator should not be notified of this change!");
@@ -127,7 +127,7 @@ impl TestSolver {
         propagator: PropagatorId,
         local_id: u32,
         var: DomainId,
-        value: i32,
+        value: i64,
     ) -> EnqueueDecision {
         let result = self.assignments.tighten_upper_bound(var, value, None);
         assert!(result.is_ok(), "The provided value to `increase_lower_bound` caused an empty domain, generally the propagator should not be notified of this change!");
@@ -151,11 +151,11 @@ impl TestSolver {
             .is_some_and(|truth_value| !truth_value)
     }
 
-    pub(crate) fn upper_bound(&self, var: DomainId) -> i32 {
+    pub(crate) fn upper_bound(&self, var: DomainId) -> i64 {
         self.assignments.get_upper_bound(var)
     }
 
-    pub(crate) fn remove(&mut self, var: DomainId, value: i32) -> Result<(), EmptyDomain> {
+    pub(crate) fn remove(&mut self, var: DomainId, value: i64) -> Result<(), EmptyDomain> {
         self.assignments.remove_value_from_domain(var, value, None)
     }
 
@@ -267,7 +267,7 @@ impl TestSolver {
         self.get_reason_int(predicate)
     }
 
-    pub(crate) fn assert_bounds(&self, var: DomainId, lb: i32, ub: i32) {
+    pub(crate) fn assert_bounds(&self, var: DomainId, lb: i64, ub: i64) {
         let actual_lb = self.lower_bound(var);
         let actual_ub = self.upper_bound(var);
 
@@ -4,7 +4,7 @@ use crate::basic_types::Trail;
 use crate::containers::KeyedVec;
 
 #[derive(Default, Debug, Clone)]
-pub(crate) struct TrailedValues {
+pub struct TrailedValues {
     trail: Trail<TrailedChange>,
     values: KeyedVec<TrailedInteger, i64>,
 }
@@ -4,6 +4,8 @@ use enumset::EnumSet;
 use enumset::EnumSetType;
 
 use crate::containers::KeyedVec;
+use crate::containers::StorageKey;
+use crate::engine::propagation::PropagatorId;
 use crate::engine::propagation::PropagatorVarId;
 use crate::engine::variables::DomainId;
 
@@ -12,6 +14,10 @@ pub(crate) struct WatchListCP {
     watchers: KeyedVec<DomainId, WatcherCP>, /* contains propagator ids of propagators that
                                               * watch domain changes of the i-th integer
                                               * variable */
+    /// The domains which are watched by each propagator.
+    scopes: KeyedVec<PropagatorId, Vec<DomainId>>,
+    /// The number of propagators which watch each domain.
+    degrees: KeyedVec<DomainId, u32>,
     is_watching_anything: bool,
     is_watching_any_backtrack_events: bool,
 }
@@ -53,6 +59,21 @@ impl Display for IntDomainEvent {
 impl WatchListCP {
     pub(crate) fn grow(&mut self) {
         let _ = self.watchers.push(WatcherCP::default());
+        let _ = self.degrees.push(0);
+    }
+
+    /// Returns the domains which are watched by the provided propagator.
+    pub(crate) fn scope(&self, propagator: PropagatorId) -> &[DomainId] {
+        if propagator.index() < self.scopes.len() {
+            &self.scopes[propagator]
+        } else {
+            &[]
+        }
+    }
+
+    /// Returns the number of propagators which watch the provided domain.
+    pub(crate) fn degree(&self, domain: DomainId) -> u32 {
+        self.degrees[domain]
     }
 
     pub(crate) fn is_watching_any_backtrack_events(&self) -> bool {
@@ -100,6 +121,7 @@ impl<'a> Watchers<'a> {
 
     pub(crate) fn watch_all(&mut self, domain: DomainId, events: EnumSet<IntDomainEvent>) {
         self.watch_list.is_watching_anything = true;
+        self.add_to_scope(domain);
         let watcher = &mut self.watch_list.watchers[domain];
 
         for event in events {
@@ -116,6 +138,22 @@ impl<'a> Watchers<'a> {
         }
     }
 
+    /// Adds the domain to the (sorted) scope of the propagator, and adds the propagator to the
+    /// degree of the domain if it was not yet in the scope.
+    fn add_to_scope(&mut self, domain: DomainId) {
+        let propagator = self.propagator_var.propagator;
+        let scopes = &mut self.watch_list.scopes;
+        if scopes.len() <= propagator.index() {
+            scopes.resize(propagator.index() + 1, Vec::new());
+        }
+
+        let scope = &mut scopes[propagator];
+        if let Err(position) = scope.binary_search_by_key(&domain.id, |domain| domain.id) {
+            scope.insert(position, domain);
+            self.watch_list.degrees[domain] += 1;
+        }
+    }
+
     pub(crate) fn watch_all_backtrack(
         &mut self,
         domain: DomainId,
@@ -153,3 +191,42 @@ struct Watcher {
     assign_watchers: Vec<PropagatorVarId>,
     removal_watchers: Vec<PropagatorVarId>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::propagation::LocalId;
+
+    fn watchers(watch_list: &mut WatchListCP, propagator: u32, local_id: u32) -> Watchers<'_> {
+        Watchers::new(
+            PropagatorVarId {
+                propagator: PropagatorId(propagator),
+                variable: LocalId::from(local_id),
+            },
+            watch_list,
+        )
+    }
+
+    #[test]
+    fn scope_and_degree_count_every_domain_once() {
+        let mut watch_list = WatchListCP::default();
+        for _ in 0..3 {
+            watch_list.grow();
+        }
+        let [x, y, z] = [0, 1, 2].map(DomainId::new);
+
+        watchers(&mut watch_list, 0, 0).watch_all(z, IntDomainEvent::LowerBound.into());
+        watchers(&mut watch_list, 0, 1).watch_all(x, IntDomainEvent::UpperBound.into());
+        watchers(&mut watch_list, 0, 2).watch_all(z, IntDomainEvent::Assign.into());
+        watchers(&mut watch_list, 1, 0).watch_all(x, IntDomainEvent::Assign.into());
+
+        // A watch which is registered after the other propagator has registered its watches
+        watchers(&mut watch_list, 0, 3).watch_all(y, IntDomainEvent::Assign.into());
+
+        assert_eq!(watch_list.scope(PropagatorId(0)), &[x, y, z]);
+        assert_eq!(watch_list.scope(PropagatorId(1)), &[x]);
+        assert_eq!(watch_list.degree(x), 2);
+        assert_eq!(watch_list.degree(y), 1);
+        assert_eq!(watch_list.degree(z), 1);
+    }
+}
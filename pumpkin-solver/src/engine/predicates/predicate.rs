@@ -9,19 +9,19 @@ use crate::engine::variables::DomainId;
 pub enum Predicate {
     LowerBound {
         domain_id: DomainId,
-        lower_bound: i32,
+        lower_bound: i64,
     },
     UpperBound {
         domain_id: DomainId,
-        upper_bound: i32,
+        upper_bound: i64,
     },
     NotEqual {
         domain_id: DomainId,
-        not_equal_constant: i32,
+        not_equal_constant: i64,
     },
     Equal {
         domain_id: DomainId,
-        equality_constant: i32,
+        equality_constant: i64,
     },
 }
 
@@ -189,7 +189,7 @@ impl Predicate {
         }
     }
 
-    pub fn get_right_hand_side(&self) -> i32 {
+    pub fn get_right_hand_side(&self) -> i64 {
         match self {
             Predicate::LowerBound {
                 domain_id: _,
@@ -20,7 +20,7 @@ pub trait PredicateConstructor {
 }
 
 impl PredicateConstructor for DomainId {
-    type Value = i32;
+    type Value = i64;
 
     fn lower_bound_predicate(&self, bound: Self::Value) -> Predicate {
         Predicate::LowerBound {
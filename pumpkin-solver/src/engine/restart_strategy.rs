@@ -5,6 +5,7 @@ use crate::basic_types::moving_averages::MovingAverage;
 use crate::basic_types::moving_averages::WindowedMovingAverage;
 use crate::basic_types::sequence_generators::ConstantSequence;
 use crate::basic_types::sequence_generators::GeometricSequence;
+use crate::basic_types::sequence_generators::LinearSequence;
 use crate::basic_types::sequence_generators::LubySequence;
 use crate::basic_types::sequence_generators::SequenceGenerator;
 use crate::basic_types::sequence_generators::SequenceGeneratorType;
@@ -153,6 +154,7 @@ impl RestartStrategy {
                         "Using the geometric sequence for restarts, but the parameter restarts-geometric-coef is not defined.",
                     ),
                 )),
+                SequenceGeneratorType::Linear => Box::new(LinearSequence::new(options.base_interval as i64)),
                 SequenceGeneratorType::Luby => Box::new(LubySequence::new(options.base_interval as i64)),
             };
 
@@ -44,4 +44,8 @@ create_statistics_struct!(
         average_backtrack_amount: CumulativeMovingAverage<u64>,
         /// The average literal-block distance (LBD) metric for newly added learned nogoods
         average_lbd: CumulativeMovingAverage<u64>,
+        /// The number of nogoods which have been exported to other solvers
+        num_exported_nogoods: u64,
+        /// The number of nogoods which have been imported from other solvers
+        num_imported_nogoods: u64,
 });
@@ -0,0 +1,29 @@
+use super::TerminationCondition;
+
+/// A [`TerminationCondition`] which triggers when the specified number of conflicts has been
+/// encountered by the solver.
+#[derive(Clone, Copy, Debug)]
+pub struct ConflictBudget {
+    /// The number of conflicts which can still be encountered before
+    /// [`ConflictBudget::should_stop()`] becomes true.
+    remaining_conflicts: u64,
+}
+
+impl ConflictBudget {
+    /// Give the solver a budget of the provided number of conflicts.
+    pub fn new(budget: u64) -> ConflictBudget {
+        ConflictBudget {
+            remaining_conflicts: budget,
+        }
+    }
+}
+
+impl TerminationCondition for ConflictBudget {
+    fn should_stop(&mut self) -> bool {
+        self.remaining_conflicts == 0
+    }
+
+    fn on_conflict(&mut self) {
+        self.remaining_conflicts = self.remaining_conflicts.saturating_sub(1);
+    }
+}
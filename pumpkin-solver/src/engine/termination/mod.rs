@@ -4,8 +4,10 @@
 //! certain time budget to complete its search.
 
 pub(crate) mod combinator;
+pub(crate) mod conflict_budget;
 pub(crate) mod indefinite;
 pub(crate) mod os_signal;
+pub(crate) mod stop_signal;
 pub(crate) mod time_budget;
 
 /// The central trait that defines a termination condition. A termination condition determines when
@@ -13,6 +15,19 @@ pub(crate) mod time_budget;
 pub trait TerminationCondition {
     /// Returns `true` when the solver should stop, `false` otherwise.
     fn should_stop(&mut self) -> bool;
+
+    /// Called by the solver whenever it has encountered (and resolved) a conflict.
+    fn on_conflict(&mut self) {}
+}
+
+impl<T: TerminationCondition + ?Sized> TerminationCondition for &mut T {
+    fn should_stop(&mut self) -> bool {
+        (**self).should_stop()
+    }
+
+    fn on_conflict(&mut self) {
+        (**self).on_conflict()
+    }
 }
 
 impl<T: TerminationCondition> TerminationCondition for Option<T> {
@@ -22,4 +37,10 @@ impl<T: TerminationCondition> TerminationCondition for Option<T> {
             None => false,
         }
     }
+
+    fn on_conflict(&mut self) {
+        if let Some(t) = self {
+            t.on_conflict()
+        }
+    }
 }
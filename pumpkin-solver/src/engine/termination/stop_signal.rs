@@ -0,0 +1,25 @@
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use super::TerminationCondition;
+
+/// A [`TerminationCondition`] which triggers once [`StopSignal::stop`] has been called on it (or on
+/// any of its clones); this allows solvers in different threads to be stopped at once.
+#[derive(Clone, Debug, Default)]
+pub struct StopSignal {
+    is_stopped: Arc<AtomicBool>,
+}
+
+impl StopSignal {
+    /// Signals every clone of this termination condition to stop.
+    pub fn stop(&self) {
+        self.is_stopped.store(true, Ordering::Relaxed);
+    }
+}
+
+impl TerminationCondition for StopSignal {
+    fn should_stop(&mut self) -> bool {
+        self.is_stopped.load(Ordering::Relaxed)
+    }
+}
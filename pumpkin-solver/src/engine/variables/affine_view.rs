@@ -17,15 +17,20 @@ use crate::math::num_ext::NumExt;
 
 /// Models the constraint `y = ax + b`, by expressing the domain of `y` as a transformation of the
 /// domain of `x`.
+///
+/// The values in the domain of `y` are computed using `i128`s; if `ax + b` does not fit in an
+/// `i64` for a value in the domain of `x`, then it saturates to [`i64::MIN`] or [`i64::MAX`]
+/// rather than silently wrapping. Similarly, the scale and offset saturate when the view is
+/// transformed further (see [`TransformableVariable`]).
 #[derive(Clone, Copy, Hash, Eq, PartialEq)]
 pub struct AffineView<Inner> {
     inner: Inner,
-    scale: i32,
-    offset: i32,
+    scale: i64,
+    offset: i64,
 }
 
 impl<Inner> AffineView<Inner> {
-    pub fn new(inner: Inner, scale: i32, offset: i32) -> Self {
+    pub fn new(inner: Inner, scale: i64, offset: i64) -> Self {
         AffineView {
             inner,
             scale,
@@ -35,17 +40,35 @@ impl<Inner> AffineView<Inner> {
 
     /// Apply the inverse transformation of this view on a value, to go from the value in the domain
     /// of `self` to a value in the domain of `self.inner`.
-    fn invert(&self, value: i32, rounding: Rounding) -> i32 {
-        let inverted_translation = value - self.offset;
+    ///
+    /// The inverse is computed using `i128`s since the translation can overflow an `i64`; if the
+    /// inverted value does not fit in an `i64`, then it is clamped to the range of an `i64`.
+    fn invert(&self, value: i64, rounding: Rounding) -> i64 {
+        let inverted_translation = value as i128 - self.offset as i128;
+
+        let inverted = match rounding {
+            Rounding::Up => <i128 as NumExt>::div_ceil(inverted_translation, self.scale as i128),
+            Rounding::Down => <i128 as NumExt>::div_floor(inverted_translation, self.scale as i128),
+        };
+
+        inverted.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+
+    /// Returns the value in the domain of `self.inner` which is mapped to `value`, if it exists.
+    fn try_invert(&self, value: i64) -> Option<i64> {
+        let inverted_translation = value as i128 - self.offset as i128;
 
-        match rounding {
-            Rounding::Up => <i32 as NumExt>::div_ceil(inverted_translation, self.scale),
-            Rounding::Down => <i32 as NumExt>::div_floor(inverted_translation, self.scale),
+        if inverted_translation % self.scale as i128 == 0 {
+            i64::try_from(inverted_translation / self.scale as i128).ok()
+        } else {
+            None
         }
     }
 
-    fn map(&self, value: i32) -> i32 {
-        self.scale * value + self.offset
+    fn map(&self, value: i64) -> i64 {
+        let mapped = self.scale as i128 * value as i128 + self.offset as i128;
+
+        mapped.clamp(i64::MIN as i128, i64::MAX as i128) as i64
     }
 }
 
@@ -55,7 +78,7 @@ where
 {
     type AffineView = Self;
 
-    fn lower_bound(&self, assignment: &Assignments) -> i32 {
+    fn lower_bound(&self, assignment: &Assignments) -> i64 {
         if self.scale < 0 {
             self.map(self.inner.upper_bound(assignment))
         } else {
@@ -67,7 +90,7 @@ where
         &self,
         assignment: &Assignments,
         trail_position: usize,
-    ) -> i32 {
+    ) -> i64 {
         if self.scale < 0 {
             self.map(
                 self.inner
@@ -81,7 +104,7 @@ where
         }
     }
 
-    fn upper_bound(&self, assignment: &Assignments) -> i32 {
+    fn upper_bound(&self, assignment: &Assignments) -> i64 {
         if self.scale < 0 {
             self.map(self.inner.lower_bound(assignment))
         } else {
@@ -93,7 +116,7 @@ where
         &self,
         assignment: &Assignments,
         trail_position: usize,
-    ) -> i32 {
+    ) -> i64 {
         if self.scale < 0 {
             self.map(
                 self.inner
@@ -107,9 +130,8 @@ where
         }
     }
 
-    fn contains(&self, assignment: &Assignments, value: i32) -> bool {
-        if (value - self.offset) % self.scale == 0 {
-            let inverted = self.invert(value, Rounding::Up);
+    fn contains(&self, assignment: &Assignments, value: i64) -> bool {
+        if let Some(inverted) = self.try_invert(value) {
             self.inner.contains(assignment, inverted)
         } else {
             false
@@ -119,11 +141,10 @@ where
     fn contains_at_trail_position(
         &self,
         assignment: &Assignments,
-        value: i32,
+        value: i64,
         trail_position: usize,
     ) -> bool {
-        if (value - self.offset) % self.scale == 0 {
-            let inverted = self.invert(value, Rounding::Up);
+        if let Some(inverted) = self.try_invert(value) {
             self.inner
                 .contains_at_trail_position(assignment, inverted, trail_position)
         } else {
@@ -131,7 +152,7 @@ where
         }
     }
 
-    fn iterate_domain(&self, assignment: &Assignments) -> impl Iterator<Item = i32> {
+    fn iterate_domain(&self, assignment: &Assignments) -> impl Iterator<Item = i64> {
         self.inner
             .iterate_domain(assignment)
             .map(|value| self.map(value))
@@ -140,11 +161,10 @@ where
     fn remove(
         &self,
         assignment: &mut Assignments,
-        value: i32,
+        value: i64,
         reason: Option<ReasonRef>,
     ) -> Result<(), EmptyDomain> {
-        if (value - self.offset) % self.scale == 0 {
-            let inverted = self.invert(value, Rounding::Up);
+        if let Some(inverted) = self.try_invert(value) {
             self.inner.remove(assignment, inverted, reason)
         } else {
             Ok(())
@@ -154,7 +174,7 @@ where
     fn set_lower_bound(
         &self,
         assignment: &mut Assignments,
-        value: i32,
+        value: i64,
         reason: Option<ReasonRef>,
     ) -> Result<(), EmptyDomain> {
         if self.scale >= 0 {
@@ -169,7 +189,7 @@ where
     fn set_upper_bound(
         &self,
         assignment: &mut Assignments,
-        value: i32,
+        value: i64,
         reason: Option<ReasonRef>,
     ) -> Result<(), EmptyDomain> {
         if self.scale >= 0 {
@@ -214,22 +234,26 @@ where
             self.inner.unpack_event(event)
         }
     }
+
+    fn domain_id(&self) -> Option<DomainId> {
+        self.inner.domain_id()
+    }
 }
 
 impl<View> TransformableVariable<AffineView<View>> for AffineView<View>
 where
     View: IntegerVariable,
 {
-    fn scaled(&self, scale: i32) -> AffineView<View> {
+    fn scaled(&self, scale: i64) -> AffineView<View> {
         let mut result = self.clone();
-        result.scale *= scale;
-        result.offset *= scale;
+        result.scale = self.scale.saturating_mul(scale);
+        result.offset = self.offset.saturating_mul(scale);
         result
     }
 
-    fn offset(&self, offset: i32) -> AffineView<View> {
+    fn offset(&self, offset: i64) -> AffineView<View> {
         let mut result = self.clone();
-        result.offset += offset;
+        result.offset = self.offset.saturating_add(offset);
         result
     }
 }
@@ -245,7 +269,7 @@ impl<Var: std::fmt::Debug> std::fmt::Debug for AffineView<Var> {
         write!(f, "({:?})", self.inner)?;
 
         match self.offset.cmp(&0) {
-            Ordering::Less => write!(f, " - {}", -self.offset)?,
+            Ordering::Less => write!(f, " - {}", self.offset.unsigned_abs())?,
             Ordering::Equal => {}
             Ordering::Greater => write!(f, " + {}", self.offset)?,
         }
@@ -254,7 +278,7 @@ impl<Var: std::fmt::Debug> std::fmt::Debug for AffineView<Var> {
     }
 }
 
-impl<Var: PredicateConstructor<Value = i32>> PredicateConstructor for AffineView<Var> {
+impl<Var: PredicateConstructor<Value = i64>> PredicateConstructor for AffineView<Var> {
     type Value = Var::Value;
 
     fn lower_bound_predicate(&self, bound: Self::Value) -> Predicate {
@@ -278,8 +302,7 @@ impl<Var: PredicateConstructor<Value = i32>> PredicateConstructor for AffineView
     }
 
     fn equality_predicate(&self, bound: Self::Value) -> Predicate {
-        if (bound - self.offset) % self.scale == 0 {
-            let inverted_bound = self.invert(bound, Rounding::Up);
+        if let Some(inverted_bound) = self.try_invert(bound) {
             self.inner.equality_predicate(inverted_bound)
         } else {
             Predicate::trivially_false()
@@ -287,8 +310,7 @@ impl<Var: PredicateConstructor<Value = i32>> PredicateConstructor for AffineView
     }
 
     fn disequality_predicate(&self, bound: Self::Value) -> Predicate {
-        if (bound - self.offset) % self.scale == 0 {
-            let inverted_bound = self.invert(bound, Rounding::Up);
+        if let Some(inverted_bound) = self.try_invert(bound) {
             self.inner.disequality_predicate(inverted_bound)
         } else {
             Predicate::trivially_true()
@@ -332,6 +354,43 @@ mod tests {
         assert_eq!(10, scaled_view.offset);
     }
 
+    #[test]
+    fn scaling_an_affine_view_which_overflows_saturates() {
+        let view = AffineView::new(DomainId::new(0), i64::MAX / 2, -i64::MAX / 2);
+        let scaled_view = view.scaled(3);
+        assert_eq!(i64::MAX, scaled_view.scale);
+        assert_eq!(i64::MIN, scaled_view.offset);
+    }
+
+    #[test]
+    fn offsetting_an_affine_view_which_overflows_saturates() {
+        let view = AffineView::new(DomainId::new(0), 1, i64::MAX - 1);
+        let offset_view = view.offset(2);
+        assert_eq!(i64::MAX, offset_view.offset);
+    }
+
+    #[test]
+    fn mapping_a_bound_which_overflows_saturates() {
+        let mut assignments = Assignments::default();
+        let domain = assignments.grow(-(i64::MAX / 2) - 2, i64::MAX / 2 + 1);
+        let view = AffineView::new(domain, 2, 0);
+
+        assert_eq!(i64::MIN, view.lower_bound(&assignments));
+        assert_eq!(i64::MAX, view.upper_bound(&assignments));
+    }
+
+    #[test]
+    fn values_with_an_inverse_outside_of_the_inner_domain_are_not_contained() {
+        let mut assignments = Assignments::default();
+        let domain = assignments.grow(-10, 10);
+        let view = AffineView::new(domain, 1, -10);
+
+        assert!(!view.contains(&assignments, i64::MAX));
+        assert_eq!(Predicate::trivially_false(), predicate!(view == i64::MAX));
+        assert_eq!(Predicate::trivially_true(), predicate!(view != i64::MAX));
+        assert_eq!(predicate!(domain >= i64::MAX), predicate!(view >= i64::MAX));
+    }
+
     #[test]
     fn affine_view_obtaining_a_bound_should_round_optimistically_in_inner_domain() {
         let domain = DomainId::new(0);
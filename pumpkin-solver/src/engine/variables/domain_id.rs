@@ -27,7 +27,7 @@ impl DomainId {
 impl IntegerVariable for DomainId {
     type AffineView = AffineView<Self>;
 
-    fn lower_bound(&self, assignment: &Assignments) -> i32 {
+    fn lower_bound(&self, assignment: &Assignments) -> i64 {
         assignment.get_lower_bound(*self)
     }
 
@@ -35,11 +35,11 @@ impl IntegerVariable for DomainId {
         &self,
         assignment: &Assignments,
         trail_position: usize,
-    ) -> i32 {
+    ) -> i64 {
         assignment.get_lower_bound_at_trail_position(*self, trail_position)
     }
 
-    fn upper_bound(&self, assignment: &Assignments) -> i32 {
+    fn upper_bound(&self, assignment: &Assignments) -> i64 {
         assignment.get_upper_bound(*self)
     }
 
@@ -47,31 +47,31 @@ impl IntegerVariable for DomainId {
         &self,
         assignment: &Assignments,
         trail_position: usize,
-    ) -> i32 {
+    ) -> i64 {
         assignment.get_upper_bound_at_trail_position(*self, trail_position)
     }
 
-    fn contains(&self, assignment: &Assignments, value: i32) -> bool {
+    fn contains(&self, assignment: &Assignments, value: i64) -> bool {
         assignment.is_value_in_domain(*self, value)
     }
 
     fn contains_at_trail_position(
         &self,
         assignment: &Assignments,
-        value: i32,
+        value: i64,
         trail_position: usize,
     ) -> bool {
         assignment.is_value_in_domain_at_trail_position(*self, value, trail_position)
     }
 
-    fn iterate_domain(&self, assignment: &Assignments) -> impl Iterator<Item = i32> {
+    fn iterate_domain(&self, assignment: &Assignments) -> impl Iterator<Item = i64> {
         assignment.get_domain_iterator(*self)
     }
 
     fn remove(
         &self,
         assignment: &mut Assignments,
-        value: i32,
+        value: i64,
         reason: Option<ReasonRef>,
     ) -> Result<(), EmptyDomain> {
         assignment.remove_value_from_domain(*self, value, reason)
@@ -80,7 +80,7 @@ impl IntegerVariable for DomainId {
     fn set_lower_bound(
         &self,
         assignment: &mut Assignments,
-        value: i32,
+        value: i64,
         reason: Option<ReasonRef>,
     ) -> Result<(), EmptyDomain> {
         assignment.tighten_lower_bound(*self, value, reason)
@@ -89,7 +89,7 @@ impl IntegerVariable for DomainId {
     fn set_upper_bound(
         &self,
         assignment: &mut Assignments,
-        value: i32,
+        value: i64,
         reason: Option<ReasonRef>,
     ) -> Result<(), EmptyDomain> {
         assignment.tighten_upper_bound(*self, value, reason)
@@ -106,14 +106,18 @@ impl IntegerVariable for DomainId {
     fn unpack_event(&self, event: OpaqueDomainEvent) -> IntDomainEvent {
         event.unwrap()
     }
+
+    fn domain_id(&self) -> Option<DomainId> {
+        Some(*self)
+    }
 }
 
 impl TransformableVariable<AffineView<DomainId>> for DomainId {
-    fn scaled(&self, scale: i32) -> AffineView<DomainId> {
+    fn scaled(&self, scale: i64) -> AffineView<DomainId> {
         AffineView::new(*self, scale, 0)
     }
 
-    fn offset(&self, offset: i32) -> AffineView<DomainId> {
+    fn offset(&self, offset: i64) -> AffineView<DomainId> {
         AffineView::new(*self, 1, offset)
     }
 }
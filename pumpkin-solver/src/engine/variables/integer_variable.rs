@@ -4,6 +4,7 @@ use super::TransformableVariable;
 use crate::engine::opaque_domain_event::OpaqueDomainEvent;
 use crate::engine::predicates::predicate_constructor::PredicateConstructor;
 use crate::engine::reason::ReasonRef;
+use crate::engine::variables::DomainId;
 use crate::engine::Assignments;
 use crate::engine::EmptyDomain;
 use crate::engine::IntDomainEvent;
@@ -13,43 +14,43 @@ use crate::engine::Watchers;
 /// lower-bound ([`IntegerVariable::lower_bound`]) or adjusting the bounds
 /// ([`IntegerVariable::set_lower_bound`]).
 pub trait IntegerVariable:
-    Clone + PredicateConstructor<Value = i32> + TransformableVariable<Self::AffineView>
+    Clone + PredicateConstructor<Value = i64> + TransformableVariable<Self::AffineView>
 {
     type AffineView: IntegerVariable;
 
     /// Get the lower bound of the variable.
-    fn lower_bound(&self, assignment: &Assignments) -> i32;
+    fn lower_bound(&self, assignment: &Assignments) -> i64;
 
     /// Get the lower bound of the variable at the given trail position.
     fn lower_bound_at_trail_position(&self, assignment: &Assignments, trail_position: usize)
-        -> i32;
+        -> i64;
 
     /// Get the upper bound of the variable.
-    fn upper_bound(&self, assignment: &Assignments) -> i32;
+    fn upper_bound(&self, assignment: &Assignments) -> i64;
 
     /// Get the upper bound of the variable at the given trail position.
     fn upper_bound_at_trail_position(&self, assignment: &Assignments, trail_position: usize)
-        -> i32;
+        -> i64;
 
     /// Determine whether the value is in the domain of this variable.
-    fn contains(&self, assignment: &Assignments, value: i32) -> bool;
+    fn contains(&self, assignment: &Assignments, value: i64) -> bool;
 
     /// Determine whether the value is in the domain of this variable at the given trail position.
     fn contains_at_trail_position(
         &self,
         assignment: &Assignments,
-        value: i32,
+        value: i64,
         trail_position: usize,
     ) -> bool;
 
     /// Iterate over the values of the domain.
-    fn iterate_domain(&self, assignment: &Assignments) -> impl Iterator<Item = i32>;
+    fn iterate_domain(&self, assignment: &Assignments) -> impl Iterator<Item = i64>;
 
     /// Remove a value from the domain of this variable.
     fn remove(
         &self,
         assignment: &mut Assignments,
-        value: i32,
+        value: i64,
         reason: Option<ReasonRef>,
     ) -> Result<(), EmptyDomain>;
 
@@ -57,7 +58,7 @@ pub trait IntegerVariable:
     fn set_lower_bound(
         &self,
         assignment: &mut Assignments,
-        value: i32,
+        value: i64,
         reason: Option<ReasonRef>,
     ) -> Result<(), EmptyDomain>;
 
@@ -65,7 +66,7 @@ pub trait IntegerVariable:
     fn set_upper_bound(
         &self,
         assignment: &mut Assignments,
-        value: i32,
+        value: i64,
         reason: Option<ReasonRef>,
     ) -> Result<(), EmptyDomain>;
 
@@ -76,4 +77,8 @@ pub trait IntegerVariable:
 
     /// Decode a domain event for this variable.
     fn unpack_event(&self, event: OpaqueDomainEvent) -> IntDomainEvent;
+
+    /// Returns the domain of which this variable is a view, or [`None`] if the variable is a
+    /// constant.
+    fn domain_id(&self) -> Option<DomainId>;
 }
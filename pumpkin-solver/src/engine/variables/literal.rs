@@ -64,7 +64,7 @@ impl IntegerVariable for Literal {
     /// Literals that evaluate to true have a lower bound of 1.
     /// Literal that evaluate to false have a lower bound of 0.
     /// Unassigned literals have a lower bound of 0.
-    fn lower_bound(&self, assignment: &Assignments) -> i32 {
+    fn lower_bound(&self, assignment: &Assignments) -> i64 {
         self.integer_variable.lower_bound(assignment)
     }
 
@@ -72,7 +72,7 @@ impl IntegerVariable for Literal {
         &self,
         assignment: &Assignments,
         trail_position: usize,
-    ) -> i32 {
+    ) -> i64 {
         self.integer_variable
             .lower_bound_at_trail_position(assignment, trail_position)
     }
@@ -81,7 +81,7 @@ impl IntegerVariable for Literal {
     /// Literals that evaluate to true have an upper bound of 1.
     /// Literal that evaluate to false have a upper bound of 0.
     /// Unassigned literals have a upper bound of 1.
-    fn upper_bound(&self, assignment: &Assignments) -> i32 {
+    fn upper_bound(&self, assignment: &Assignments) -> i64 {
         self.integer_variable.upper_bound(assignment)
     }
 
@@ -89,7 +89,7 @@ impl IntegerVariable for Literal {
         &self,
         assignment: &Assignments,
         trail_position: usize,
-    ) -> i32 {
+    ) -> i64 {
         self.integer_variable
             .upper_bound_at_trail_position(assignment, trail_position)
     }
@@ -99,28 +99,28 @@ impl IntegerVariable for Literal {
     /// Literals that evaluate to true only contain value 1.
     /// Literals that evaluate to false only contain value 0.
     /// Unassigned literals contain both values 0 and 1.
-    fn contains(&self, assignment: &Assignments, value: i32) -> bool {
+    fn contains(&self, assignment: &Assignments, value: i64) -> bool {
         self.integer_variable.contains(assignment, value)
     }
 
     fn contains_at_trail_position(
         &self,
         assignment: &Assignments,
-        value: i32,
+        value: i64,
         trail_position: usize,
     ) -> bool {
         self.integer_variable
             .contains_at_trail_position(assignment, value, trail_position)
     }
 
-    fn iterate_domain(&self, assignment: &Assignments) -> impl Iterator<Item = i32> {
+    fn iterate_domain(&self, assignment: &Assignments) -> impl Iterator<Item = i64> {
         self.integer_variable.iterate_domain(assignment)
     }
 
     fn remove(
         &self,
         assignment: &mut Assignments,
-        value: i32,
+        value: i64,
         reason: Option<ReasonRef>,
     ) -> Result<(), EmptyDomain> {
         self.integer_variable.remove(assignment, value, reason)
@@ -129,7 +129,7 @@ impl IntegerVariable for Literal {
     fn set_lower_bound(
         &self,
         assignment: &mut Assignments,
-        value: i32,
+        value: i64,
         reason: Option<ReasonRef>,
     ) -> Result<(), EmptyDomain> {
         self.integer_variable
@@ -139,7 +139,7 @@ impl IntegerVariable for Literal {
     fn set_upper_bound(
         &self,
         assignment: &mut Assignments,
-        value: i32,
+        value: i64,
         reason: Option<ReasonRef>,
     ) -> Result<(), EmptyDomain> {
         self.integer_variable
@@ -154,13 +154,17 @@ impl IntegerVariable for Literal {
         self.integer_variable.unpack_event(event)
     }
 
+    fn domain_id(&self) -> Option<DomainId> {
+        self.integer_variable.domain_id()
+    }
+
     fn watch_all_backtrack(&self, watchers: &mut Watchers<'_>, events: EnumSet<IntDomainEvent>) {
         self.integer_variable.watch_all_backtrack(watchers, events)
     }
 }
 
 impl PredicateConstructor for Literal {
-    type Value = i32;
+    type Value = i64;
 
     fn lower_bound_predicate(&self, bound: Self::Value) -> Predicate {
         self.integer_variable.lower_bound_predicate(bound)
@@ -180,11 +184,11 @@ impl PredicateConstructor for Literal {
 }
 
 impl TransformableVariable<AffineView<Literal>> for Literal {
-    fn scaled(&self, scale: i32) -> AffineView<Literal> {
+    fn scaled(&self, scale: i64) -> AffineView<Literal> {
         AffineView::new(*self, scale, 0)
     }
 
-    fn offset(&self, offset: i32) -> AffineView<Literal> {
+    fn offset(&self, offset: i64) -> AffineView<Literal> {
         AffineView::new(*self, 1, offset)
     }
 }
@@ -9,6 +9,7 @@
 //!
 //! The solver currently supports integer variables and a number of (global) constraints:
 //! * [Cumulative global constraint][crate::constraints::cumulative].
+//! * [Disjunctive global constraint][crate::constraints::disjunctive].
 //! * [Element global constraint][crate::constraints::element].
 //! * Arithmetic constraints: [linear integer
 //!   (in)equalities][crate::constraints::less_than_or_equals], [integer
@@ -10,28 +10,37 @@ pub(crate) trait NumExt {
     fn div_floor(self, other: Self) -> Self;
 }
 
-impl NumExt for i32 {
-    fn div_ceil(self, other: Self) -> Self {
-        // TODO: The source is taken from the standard library nightly implementation of this
-        // function and div_floor. Once they are stabilized, these definitions can be removed.
-        // Tracking issue: https://github.com/rust-lang/rust/issues/88581
-        let d = self / other;
-        let r = self % other;
-        if (r > 0 && other > 0) || (r < 0 && other < 0) {
-            d + 1
-        } else {
-            d
-        }
-    }
+macro_rules! impl_num_ext {
+    ($($integer:ty),*) => {
+        $(
+            impl NumExt for $integer {
+                fn div_ceil(self, other: Self) -> Self {
+                    // TODO: The source is taken from the standard library nightly implementation
+                    // of this function and div_floor. Once they are stabilized, these definitions
+                    // can be removed.
+                    // Tracking issue: https://github.com/rust-lang/rust/issues/88581
+                    let d = self / other;
+                    let r = self % other;
+                    if (r > 0 && other > 0) || (r < 0 && other < 0) {
+                        d + 1
+                    } else {
+                        d
+                    }
+                }
 
-    fn div_floor(self, other: Self) -> Self {
-        // TODO: See todo in `div_ceil`.
-        let d = self / other;
-        let r = self % other;
-        if (r > 0 && other < 0) || (r < 0 && other > 0) {
-            d - 1
-        } else {
-            d
-        }
-    }
+                fn div_floor(self, other: Self) -> Self {
+                    // TODO: See todo in `div_ceil`.
+                    let d = self / other;
+                    let r = self % other;
+                    if (r > 0 && other < 0) || (r < 0 && other > 0) {
+                        d - 1
+                    } else {
+                        d
+                    }
+                }
+            }
+        )*
+    };
 }
+
+impl_num_ext!(i64, i128);
@@ -0,0 +1,176 @@
+use log::info;
+
+use super::solution_callback::SolutionCallback;
+use super::OptimisationProcedure;
+use crate::basic_types::CSPSolverExecutionFlag;
+use crate::branching::Brancher;
+use crate::optimisation::OptimisationDirection;
+use crate::predicate;
+use crate::results::OptimisationResult;
+use crate::results::Solution;
+use crate::results::SolutionReference;
+use crate::termination::TerminationCondition;
+use crate::variables::IntegerVariable;
+use crate::Solver;
+
+/// Implements a bisection (i.e. binary search) optimisation procedure over the objective.
+///
+/// After an initial solution has been found, the procedure keeps track of the proven lower-bound
+/// and the upper-bound (the objective value of the best-found solution) on the (minimised)
+/// objective. In every iteration, it solves under the assumption that the objective is at most the
+/// midpoint between these bounds; if a solution is found, then the upper-bound is tightened,
+/// otherwise the lower-bound is raised above the midpoint. The procedure terminates with an
+/// optimal solution once the bounds meet.
+///
+/// Every time one of the bounds changes, it is reported through
+/// [`SolutionCallback::on_bounds_callback`]. When the procedure terminates, the bound and the
+/// optimality gap are logged using [`Solver::log_bound_statistics`].
+#[derive(Debug, Clone, Copy)]
+pub struct Bisection<Var, Callback> {
+    direction: OptimisationDirection,
+    objective: Var,
+    solution_callback: Callback,
+}
+
+impl<Var, Callback> Bisection<Var, Callback> {
+    /// Create a new instance of [`Bisection`].
+    pub fn new(
+        direction: OptimisationDirection,
+        objective: Var,
+        solution_callback: Callback,
+    ) -> Self {
+        Self {
+            direction,
+            objective,
+            solution_callback,
+        }
+    }
+}
+
+impl<Var, Callback> Bisection<Var, Callback> {
+    /// Reports the provided bounds on the minimised objective in terms of the original objective.
+    fn report_bounds<B: Brancher>(&self, solver: &Solver, lower_bound: i64, upper_bound: i64)
+    where
+        Callback: SolutionCallback<B>,
+    {
+        let (lower_bound, upper_bound) = match self.direction {
+            OptimisationDirection::Minimise => (lower_bound, upper_bound),
+            OptimisationDirection::Maximise => (-upper_bound, -lower_bound),
+        };
+        info!("Bisection - The objective is in the range [{lower_bound}, {upper_bound}]");
+        self.solution_callback
+            .on_bounds_callback(solver, lower_bound, upper_bound);
+    }
+}
+
+impl<Var, B, Callback> OptimisationProcedure<B, Callback> for Bisection<Var, Callback>
+where
+    Var: IntegerVariable,
+    B: Brancher,
+    Callback: SolutionCallback<B>,
+{
+    fn optimise(
+        &mut self,
+        brancher: &mut B,
+        termination: &mut impl TerminationCondition,
+        solver: &mut Solver,
+    ) -> OptimisationResult {
+        let is_maximising = matches!(self.direction, OptimisationDirection::Maximise);
+        let objective = match self.direction {
+            OptimisationDirection::Maximise => self.objective.scaled(-1),
+            OptimisationDirection::Minimise => self.objective.scaled(1),
+        };
+        // If we are maximising then when we simply scale the variable by -1, however, this will
+        // lead to the printed objective value in the statistics to be multiplied by -1; this
+        // objective_multiplier ensures that the objective is correctly logged.
+        let objective_multiplier = if is_maximising { -1 } else { 1 };
+
+        // First we do a feasibility check
+        let feasibility_check = solver.solve_in_scope(&[], termination, brancher);
+        match feasibility_check {
+            CSPSolverExecutionFlag::Feasible => {}
+            CSPSolverExecutionFlag::Infeasible => {
+                // Reset the state whenever we return a result
+                solver.satisfaction_solver.restore_state_at_root(brancher);
+                solver.conclude_proof_unsat();
+                return OptimisationResult::Unsatisfiable;
+            }
+            CSPSolverExecutionFlag::Timeout => {
+                // Reset the state whenever we return a result
+                solver.satisfaction_solver.restore_state_at_root(brancher);
+                return OptimisationResult::Unknown;
+            }
+        }
+        let mut best_objective_value = Default::default();
+        let mut best_solution = Solution::default();
+
+        self.update_best_solution_and_process(
+            objective_multiplier,
+            &objective,
+            &mut best_objective_value,
+            &mut best_solution,
+            brancher,
+            solver,
+        );
+        solver.satisfaction_solver.restore_state_at_root(brancher);
+
+        let mut lower_bound = solver.lower_bound(&objective);
+        loop {
+            let upper_bound = best_objective_value * objective_multiplier;
+            self.report_bounds(solver, lower_bound, upper_bound);
+
+            if lower_bound >= upper_bound {
+                solver.log_bound_statistics(best_objective_value, best_objective_value);
+                solver.conclude_proof_optimal_with_bound(predicate![objective <= upper_bound]);
+                return OptimisationResult::Optimal(best_solution);
+            }
+
+            // We look for a solution which is strictly better than the best-found solution; the
+            // midpoint is computed such that it cannot overflow
+            let midpoint = lower_bound + (upper_bound - 1 - lower_bound) / 2;
+            let assumption = predicate!(objective <= midpoint);
+            info!("Bisection - Attempting to find solution with assumption {assumption}");
+
+            let solve_result = solver.solve_in_scope(&[assumption], termination, brancher);
+            match solve_result {
+                CSPSolverExecutionFlag::Feasible => {
+                    self.update_best_solution_and_process(
+                        objective_multiplier,
+                        &objective,
+                        &mut best_objective_value,
+                        &mut best_solution,
+                        brancher,
+                        solver,
+                    );
+                    solver.satisfaction_solver.restore_state_at_root(brancher);
+                }
+                CSPSolverExecutionFlag::Infeasible => {
+                    solver.satisfaction_solver.restore_state_at_root(brancher);
+                    // We add the (hard) constraint that the negated assumption should hold, which
+                    // can only fail if the best-found solution is optimal
+                    if solver.add_clause([!assumption]).is_err() {
+                        lower_bound = upper_bound;
+                        continue;
+                    }
+                    // Within a scope, this constraint does not tighten the bounds at the root, so
+                    // we keep track of the lower-bound ourselves
+                    lower_bound = solver.lower_bound(&objective).max(midpoint + 1);
+                }
+                CSPSolverExecutionFlag::Timeout => {
+                    // Reset the state whenever we return a result
+                    solver.satisfaction_solver.restore_state_at_root(brancher);
+                    solver.log_bound_statistics(
+                        best_objective_value,
+                        lower_bound * objective_multiplier,
+                    );
+                    return OptimisationResult::Satisfiable(best_solution);
+                }
+            }
+        }
+    }
+
+    fn on_solution_callback(&self, solver: &Solver, solution: SolutionReference, brancher: &B) {
+        self.solution_callback
+            .on_solution_callback(solver, solution, brancher)
+    }
+}
@@ -0,0 +1,270 @@
+use log::info;
+
+use super::solution_callback::SolutionCallback;
+use super::OptimisationProcedure;
+use crate::branching::Brancher;
+use crate::constraints;
+use crate::optimisation::OptimisationDirection;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::results::OptimisationResult;
+use crate::results::ProblemSolution;
+use crate::results::SatisfactionResult;
+use crate::results::SatisfactionResultUnderAssumptions;
+use crate::results::SolutionReference;
+use crate::termination::TerminationCondition;
+use crate::variables::AffineView;
+use crate::variables::DomainId;
+use crate::variables::IntegerVariable;
+use crate::variables::TransformableVariable;
+use crate::Solver;
+
+/// Implements a core-guided optimisation procedure based on OLL \[1\], adapted to integer
+/// variables as described in \[2\].
+///
+/// The procedure requires the objective to be provided as a weighted sum of variables; i.e. the
+/// objective variable should be equal to `sum w_i * x_i` (potentially with a constant offset). It
+/// solves under the assumptions that every term `x_i` takes its lower-bound; if this is
+/// unsatisfiable, then an unsatisfiable core is extracted which is used to increase the
+/// lower-bound on the objective. Every core is replaced by a new term `y` with the minimum weight
+/// of the terms in the core, where `y` is the sum of the increases of the terms in the core over
+/// their lower-bounds.
+///
+/// Once the assumptions are satisfiable, the found solution is optimal. Before the core-guided
+/// search starts, a single solution is found to determine whether the problem is satisfiable.
+///
+/// # Bibliography
+/// \[1\] A. Morgado, C. Dodaro, and J. Marques-Silva, ‘Core-guided MaxSAT with soft cardinality
+/// constraints’, in Principles and Practice of Constraint Programming: 20th International
+/// Conference, CP 2014, Lyon, France, September 8-12, 2014. Proceedings 20, 2014, pp. 564–573.
+///
+/// \[2\] G. Gange, J. Berg, E. Demirović, and P. J. Stuckey, ‘Core-guided and core-boosted
+/// search for CP’, in Integration of Constraint Programming, Artificial Intelligence, and
+/// Operations Research: 17th International Conference, CPAIOR 2020, Vienna, Austria, September
+/// 21--24, 2020, Proceedings 17, 2020, pp. 205–221.
+#[derive(Debug, Clone)]
+pub struct CoreGuided<Var, Callback> {
+    direction: OptimisationDirection,
+    objective: Var,
+    /// The terms of the (reformulated) objective which is minimised.
+    terms: Vec<ObjectiveTerm>,
+    solution_callback: Callback,
+}
+
+/// A term `weight * variable` of the objective which is minimised, where the weight is positive.
+#[derive(Debug, Clone, Copy)]
+struct ObjectiveTerm {
+    variable: AffineView<DomainId>,
+    weight: i64,
+    /// The lower-bound of the variable which has been established by the cores found so far.
+    lower_bound: i64,
+}
+
+impl<Var, Callback> CoreGuided<Var, Callback> {
+    /// Create a new instance of [`CoreGuided`], where the `objective` is equal to the weighted
+    /// sum of the provided `objective_terms` (up to a constant offset).
+    pub fn new<TermVar: TransformableVariable<AffineView<DomainId>>>(
+        direction: OptimisationDirection,
+        objective: Var,
+        objective_terms: impl IntoIterator<Item = (i64, TermVar)>,
+        solution_callback: Callback,
+    ) -> Self {
+        let terms = objective_terms
+            .into_iter()
+            .map(|(weight, variable)| match direction {
+                OptimisationDirection::Minimise => (weight, variable),
+                OptimisationDirection::Maximise => (-weight, variable),
+            })
+            .filter(|&(weight, _)| weight != 0)
+            .map(|(weight, variable)| ObjectiveTerm {
+                variable: variable.scaled(weight.signum()),
+                weight: weight.abs(),
+                lower_bound: i64::MIN,
+            })
+            .collect();
+
+        Self {
+            direction,
+            objective,
+            terms,
+            solution_callback,
+        }
+    }
+}
+
+impl<Var, Callback> CoreGuided<Var, Callback> {
+    /// Returns the assumptions that every term with a positive weight takes its lower-bound,
+    /// together with the index of the corresponding term.
+    fn create_assumptions(&mut self, solver: &Solver) -> Vec<(Predicate, usize)> {
+        self.terms
+            .iter_mut()
+            .enumerate()
+            .filter(|(_, term)| term.weight > 0)
+            .filter_map(|(index, term)| {
+                term.lower_bound = term.lower_bound.max(solver.lower_bound(&term.variable));
+                // If the term cannot increase anymore then it does not need to be assumed
+                (term.lower_bound < solver.upper_bound(&term.variable))
+                    .then(|| (predicate!(term.variable <= term.lower_bound), index))
+            })
+            .collect()
+    }
+
+    /// Returns the lower-bound on the (reformulated) objective.
+    fn lower_bound(&self) -> i128 {
+        self.terms
+            .iter()
+            .map(|term| term.weight as i128 * term.lower_bound as i128)
+            .sum()
+    }
+
+    /// Reformulates the objective based on the provided core, which consists of the indices of
+    /// the terms of which the assumptions form an unsatisfiable core.
+    ///
+    /// Returns `false` if the objective could not be reformulated because the sum of the
+    /// lower-bounds of the terms in the core does not fit in an `i64`.
+    fn process_core(&mut self, core: Vec<usize>, solver: &mut Solver) -> bool {
+        if let [index] = core[..] {
+            // The term cannot take its lower-bound, so we can simply increase its lower-bound
+            let term = &mut self.terms[index];
+            let _ = solver.add_clause([predicate!(term.variable >= term.lower_bound + 1)]);
+            term.lower_bound += 1;
+            return true;
+        }
+
+        let Some(sum_of_lower_bounds) = core.iter().try_fold(0_i64, |sum, &index| {
+            sum.checked_add(self.terms[index].lower_bound)
+        }) else {
+            return false;
+        };
+
+        let weight = core
+            .iter()
+            .map(|&index| self.terms[index].weight)
+            .min()
+            .expect("the core is not empty");
+
+        // The new variable is the sum of the increases of the terms in the core over their
+        // lower-bounds; since the core is unsatisfiable, this is at least 1
+        let maximum_increase = core
+            .iter()
+            .map(|&index| {
+                let term = &self.terms[index];
+                solver
+                    .upper_bound(&term.variable)
+                    .saturating_sub(term.lower_bound)
+            })
+            .fold(0_i64, |sum, increase| sum.saturating_add(increase));
+        let sum_of_increases = solver.new_bounded_integer(1, maximum_increase.max(1));
+
+        let sum_terms = core
+            .iter()
+            .map(|&index| self.terms[index].variable)
+            .chain(std::iter::once(sum_of_increases.scaled(-1)))
+            .collect::<Vec<_>>();
+        let _ = solver
+            .add_constraint(constraints::equals(sum_terms, sum_of_lower_bounds))
+            .post();
+
+        for &index in core.iter() {
+            self.terms[index].weight -= weight;
+        }
+        self.terms.push(ObjectiveTerm {
+            variable: sum_of_increases.scaled(1),
+            weight,
+            lower_bound: 1,
+        });
+
+        true
+    }
+}
+
+impl<Var, B, Callback> OptimisationProcedure<B, Callback> for CoreGuided<Var, Callback>
+where
+    Var: IntegerVariable + 'static,
+    B: Brancher,
+    Callback: SolutionCallback<B>,
+{
+    fn optimise(
+        &mut self,
+        brancher: &mut B,
+        termination: &mut impl TerminationCondition,
+        solver: &mut Solver,
+    ) -> OptimisationResult {
+        let objective = match self.direction {
+            OptimisationDirection::Maximise => self.objective.scaled(-1),
+            OptimisationDirection::Minimise => self.objective.scaled(1),
+        };
+
+        // First we do a feasibility check
+        let mut best_solution = match solver.satisfy(brancher, termination) {
+            SatisfactionResult::Satisfiable(solution) => solution,
+            SatisfactionResult::Unsatisfiable => return OptimisationResult::Unsatisfiable,
+            SatisfactionResult::Unknown => return OptimisationResult::Unknown,
+        };
+        self.on_solution_callback(solver, best_solution.as_reference(), brancher);
+
+        loop {
+            let assumptions = self.create_assumptions(solver);
+            info!(
+                "Core-Guided Search - Attempting to find solution with lower-bound {}",
+                self.lower_bound()
+            );
+
+            let predicates = assumptions
+                .iter()
+                .map(|&(predicate, _)| predicate)
+                .collect::<Vec<_>>();
+            let core = match solver.satisfy_under_assumptions(brancher, termination, &predicates) {
+                SatisfactionResultUnderAssumptions::Satisfiable(solution) => {
+                    best_solution = solution;
+                    None
+                }
+                SatisfactionResultUnderAssumptions::UnsatisfiableUnderAssumptions(
+                    mut unsatisfiable,
+                ) => Some(unsatisfiable.extract_core()),
+                SatisfactionResultUnderAssumptions::Unsatisfiable => {
+                    // The reformulation of the objective is implied by the cores, so this can only
+                    // occur if the solver derived infeasibility without involving the
+                    // assumptions; the best-found solution is still a solution, but it has not
+                    // been proven optimal
+                    return OptimisationResult::Satisfiable(best_solution);
+                }
+                SatisfactionResultUnderAssumptions::Unknown => {
+                    return OptimisationResult::Satisfiable(best_solution);
+                }
+            };
+
+            let Some(core) = core else {
+                self.on_solution_callback(solver, best_solution.as_reference(), brancher);
+
+                // We create a predicate specifying the best-found solution for the proof logging
+                let best_objective_value = best_solution.get_integer_value(objective.clone());
+                solver.conclude_proof_optimal_with_bound(predicate![
+                    objective <= best_objective_value
+                ]);
+
+                return OptimisationResult::Optimal(best_solution);
+            };
+
+            let core = assumptions
+                .iter()
+                .filter(|(predicate, _)| core.contains(predicate))
+                .map(|&(_, index)| index)
+                .collect::<Vec<_>>();
+            if core.is_empty() {
+                // The conflict does not depend on the assumptions, so no core can be used to
+                // reformulate the objective
+                return OptimisationResult::Satisfiable(best_solution);
+            }
+
+            if !self.process_core(core, solver) {
+                return OptimisationResult::Satisfiable(best_solution);
+            }
+        }
+    }
+
+    fn on_solution_callback(&self, solver: &Solver, solution: SolutionReference, brancher: &B) {
+        self.solution_callback
+            .on_solution_callback(solver, solution, brancher)
+    }
+}
@@ -0,0 +1,287 @@
+use log::info;
+
+use super::neighbourhoods::Neighbourhood;
+use super::neighbourhoods::NeighbourhoodContext;
+use super::solution_callback::SolutionCallback;
+use super::OptimisationProcedure;
+use crate::basic_types::CSPSolverExecutionFlag;
+use crate::basic_types::HashSet;
+use crate::branching::Brancher;
+use crate::optimisation::OptimisationDirection;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::pumpkin_assert_simple;
+use crate::results::OptimisationResult;
+use crate::results::ProblemSolution;
+use crate::results::Solution;
+use crate::results::SolutionReference;
+use crate::termination::Combinator;
+use crate::termination::ConflictBudget;
+use crate::termination::TerminationCondition;
+use crate::variables::DomainId;
+use crate::variables::IntegerVariable;
+use crate::Solver;
+
+/// The options for [`LargeNeighbourhoodSearch`].
+#[derive(Debug, Clone, Copy)]
+pub struct LargeNeighbourhoodSearchOptions {
+    /// The maximum number of conflicts which can be encountered while searching a single
+    /// neighbourhood.
+    pub conflict_limit: u64,
+    /// The fraction of the variables which is relaxed in the first neighbourhood; this fraction
+    /// is increased when a neighbourhood is exhausted, and it is decreased when the conflict
+    /// limit is reached.
+    pub initial_relaxation_ratio: f64,
+}
+
+impl Default for LargeNeighbourhoodSearchOptions {
+    fn default() -> Self {
+        Self {
+            conflict_limit: 1000,
+            initial_relaxation_ratio: 0.2,
+        }
+    }
+}
+
+/// Implements a large neighbourhood search (LNS) optimisation procedure \[1\].
+///
+/// After an initial solution has been found, the procedure repeatedly selects a set of relaxed
+/// variables using the provided [`Neighbourhood`]; the other variables are fixed to their values
+/// in the incumbent solution using assumptions, and the resulting neighbourhood is searched for a
+/// better solution with a limited number of conflicts (see
+/// [`LargeNeighbourhoodSearchOptions::conflict_limit`]). When a neighbourhood is exhausted
+/// without finding a better solution, the number of relaxed variables is increased; when the
+/// conflict limit is reached, it is decreased.
+///
+/// The procedure can only prove optimality when the search without any fixed variables is
+/// exhausted; in general, it is stopped by the provided [`TerminationCondition`].
+///
+/// # Bibliography
+/// \[1\] P. Shaw, ‘Using constraint programming and local search methods to solve vehicle routing
+/// problems’, in International Conference on Principles and Practice of Constraint Programming,
+/// 1998, pp. 417–431.
+#[derive(Debug, Clone)]
+pub struct LargeNeighbourhoodSearch<Var, N, Callback> {
+    direction: OptimisationDirection,
+    objective: Var,
+    /// The variables which are either relaxed or fixed to their values in the incumbent.
+    variables: Vec<DomainId>,
+    neighbourhood: N,
+    solution_callback: Callback,
+    options: LargeNeighbourhoodSearchOptions,
+}
+
+impl<Var, N, Callback> LargeNeighbourhoodSearch<Var, N, Callback> {
+    /// Create a new instance of [`LargeNeighbourhoodSearch`] with the default
+    /// [`LargeNeighbourhoodSearchOptions`], where the neighbourhoods are defined over the
+    /// provided `variables`.
+    pub fn new(
+        direction: OptimisationDirection,
+        objective: Var,
+        variables: impl IntoIterator<Item = DomainId>,
+        neighbourhood: N,
+        solution_callback: Callback,
+    ) -> Self {
+        Self::with_options(
+            direction,
+            objective,
+            variables,
+            neighbourhood,
+            solution_callback,
+            LargeNeighbourhoodSearchOptions::default(),
+        )
+    }
+
+    /// Create a new instance of [`LargeNeighbourhoodSearch`] with the provided
+    /// [`LargeNeighbourhoodSearchOptions`].
+    pub fn with_options(
+        direction: OptimisationDirection,
+        objective: Var,
+        variables: impl IntoIterator<Item = DomainId>,
+        neighbourhood: N,
+        solution_callback: Callback,
+        options: LargeNeighbourhoodSearchOptions,
+    ) -> Self {
+        pumpkin_assert_simple!(
+            options.initial_relaxation_ratio > 0.0 && options.initial_relaxation_ratio <= 1.0,
+            "The initial relaxation ratio should be in the range (0, 1]"
+        );
+        let mut unique_variables: HashSet<DomainId> = HashSet::default();
+        let variables = variables
+            .into_iter()
+            .filter(|&variable| unique_variables.insert(variable))
+            .collect();
+
+        Self {
+            direction,
+            objective,
+            variables,
+            neighbourhood,
+            solution_callback,
+            options,
+        }
+    }
+}
+
+impl<Var, N: Neighbourhood, Callback> LargeNeighbourhoodSearch<Var, N, Callback> {
+    /// Returns the assumptions which fix the variables which are not relaxed by the
+    /// neighbourhood to their values in the incumbent solution.
+    fn create_assumptions<B: Brancher>(
+        &mut self,
+        relaxation_ratio: f64,
+        incumbent: &Solution,
+        brancher: &mut B,
+        solver: &mut Solver,
+    ) -> Vec<Predicate> {
+        let num_relaxed = ((self.variables.len() as f64 * relaxation_ratio).ceil() as usize).max(1);
+        let relaxed = self
+            .neighbourhood
+            .relax(
+                &self.variables,
+                incumbent,
+                num_relaxed,
+                &mut NeighbourhoodContext::new(&mut solver.satisfaction_solver, brancher),
+            )
+            .into_iter()
+            .collect::<HashSet<_>>();
+
+        self.variables
+            .iter()
+            .filter(|variable| !relaxed.contains(variable))
+            .map(|&variable| predicate!(variable == incumbent.get_integer_value(variable)))
+            .collect()
+    }
+}
+
+impl<Var, N, Callback, B> OptimisationProcedure<B, Callback>
+    for LargeNeighbourhoodSearch<Var, N, Callback>
+where
+    Var: IntegerVariable,
+    N: Neighbourhood,
+    B: Brancher,
+    Callback: SolutionCallback<B>,
+{
+    fn optimise(
+        &mut self,
+        brancher: &mut B,
+        termination: &mut impl TerminationCondition,
+        solver: &mut Solver,
+    ) -> OptimisationResult {
+        let is_maximising = matches!(self.direction, OptimisationDirection::Maximise);
+        let objective = match self.direction {
+            OptimisationDirection::Maximise => self.objective.scaled(-1),
+            OptimisationDirection::Minimise => self.objective.scaled(1),
+        };
+        // If we are maximising then when we simply scale the variable by -1, however, this will
+        // lead to the printed objective value in the statistics to be multiplied by -1; this
+        // objective_multiplier ensures that the objective is correctly logged.
+        let objective_multiplier = if is_maximising { -1 } else { 1 };
+
+        let initial_solve = solver.solve_in_scope(&[], termination, brancher);
+        match initial_solve {
+            CSPSolverExecutionFlag::Feasible => {}
+            CSPSolverExecutionFlag::Infeasible => {
+                // Reset the state whenever we return a result
+                solver.satisfaction_solver.restore_state_at_root(brancher);
+                solver.conclude_proof_unsat();
+                return OptimisationResult::Unsatisfiable;
+            }
+            CSPSolverExecutionFlag::Timeout => {
+                // Reset the state whenever we return a result
+                solver.satisfaction_solver.restore_state_at_root(brancher);
+                return OptimisationResult::Unknown;
+            }
+        }
+        let mut best_objective_value = Default::default();
+        let mut best_solution = Solution::default();
+
+        self.update_best_solution_and_process(
+            objective_multiplier,
+            &objective,
+            &mut best_objective_value,
+            &mut best_solution,
+            brancher,
+            solver,
+        );
+
+        let mut relaxation_ratio = self.options.initial_relaxation_ratio;
+        let mut has_improved = true;
+
+        loop {
+            solver.satisfaction_solver.restore_state_at_root(brancher);
+
+            let best_value = best_objective_value * objective_multiplier;
+            if has_improved {
+                // We require every subsequent solution to be strictly better than the incumbent
+                if solver
+                    .add_clause([predicate!(objective <= best_value - 1)])
+                    .is_err()
+                {
+                    solver.conclude_proof_optimal_with_bound(predicate![objective <= best_value]);
+                    return OptimisationResult::Optimal(best_solution);
+                }
+                has_improved = false;
+            }
+
+            let assumptions =
+                self.create_assumptions(relaxation_ratio, &best_solution, brancher, solver);
+            info!(
+                "Large Neighbourhood Search - Searching neighbourhood with {} fixed variables",
+                assumptions.len()
+            );
+
+            let mut neighbourhood_termination = Combinator::new(
+                &mut *termination,
+                ConflictBudget::new(self.options.conflict_limit),
+            );
+            let solve_result =
+                solver.solve_in_scope(&assumptions, &mut neighbourhood_termination, brancher);
+            match solve_result {
+                CSPSolverExecutionFlag::Feasible => {
+                    self.update_best_solution_and_process(
+                        objective_multiplier,
+                        &objective,
+                        &mut best_objective_value,
+                        &mut best_solution,
+                        brancher,
+                        solver,
+                    );
+                    has_improved = true;
+                }
+                CSPSolverExecutionFlag::Infeasible => {
+                    // If no variables were fixed, then there is no better solution than the
+                    // incumbent
+                    let is_exhausted = assumptions.is_empty()
+                        || solver.satisfaction_solver.get_state().is_infeasible();
+
+                    // Reset the state whenever we return a result
+                    solver.satisfaction_solver.restore_state_at_root(brancher);
+                    if is_exhausted {
+                        solver
+                            .conclude_proof_optimal_with_bound(predicate![objective <= best_value]);
+                        return OptimisationResult::Optimal(best_solution);
+                    }
+
+                    // The neighbourhood did not contain a better solution, so we relax more
+                    // variables
+                    relaxation_ratio = (relaxation_ratio * 1.1).min(1.0);
+                }
+                CSPSolverExecutionFlag::Timeout => {
+                    // Reset the state whenever we return a result
+                    solver.satisfaction_solver.restore_state_at_root(brancher);
+                    if termination.should_stop() {
+                        return OptimisationResult::Satisfiable(best_solution);
+                    }
+
+                    // The conflict limit was reached, so we relax fewer variables
+                    relaxation_ratio *= 0.9;
+                }
+            }
+        }
+    }
+
+    fn on_solution_callback(&self, solver: &Solver, solution: SolutionReference, brancher: &B) {
+        self.solution_callback
+            .on_solution_callback(solver, solution, brancher)
+    }
+}
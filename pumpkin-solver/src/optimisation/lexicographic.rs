@@ -0,0 +1,138 @@
+use log::info;
+
+use super::solution_callback::SolutionCallback;
+use super::OptimisationProcedure;
+use crate::basic_types::CSPSolverExecutionFlag;
+use crate::branching::Brancher;
+use crate::optimisation::OptimisationDirection;
+use crate::predicate;
+use crate::results::OptimisationResult;
+use crate::results::ProblemSolution;
+use crate::results::Solution;
+use crate::results::SolutionReference;
+use crate::termination::TerminationCondition;
+use crate::variables::IntegerVariable;
+use crate::Solver;
+
+/// Implements a lexicographic optimisation procedure over multiple objectives.
+///
+/// The objectives are optimised one-by-one in the order in which they are provided; the first
+/// objective has the highest priority. Every objective is optimised in a linear SAT-UNSAT fashion,
+/// where every improving solution is found under the assumption that the objective is strictly
+/// better than the best-found solution. Once the optimum of an objective has been proven, the
+/// objective is frozen to its optimal value and the procedure moves on to the next objective.
+///
+/// Note that the constraints which freeze the objectives remain in the solver after the
+/// procedure has terminated; these can be retracted by optimising within a scope (see
+/// [`Solver::push`]).
+#[derive(Debug, Clone)]
+pub struct Lexicographic<Var, Callback> {
+    objectives: Vec<(OptimisationDirection, Var)>,
+    solution_callback: Callback,
+}
+
+impl<Var, Callback> Lexicographic<Var, Callback> {
+    /// Create a new instance of [`Lexicographic`], where the `objectives` are provided in order of
+    /// decreasing priority.
+    pub fn new(
+        objectives: impl IntoIterator<Item = (OptimisationDirection, Var)>,
+        solution_callback: Callback,
+    ) -> Self {
+        Self {
+            objectives: objectives.into_iter().collect(),
+            solution_callback,
+        }
+    }
+}
+
+impl<Var, B, Callback> OptimisationProcedure<B, Callback> for Lexicographic<Var, Callback>
+where
+    Var: IntegerVariable + 'static,
+    B: Brancher,
+    Callback: SolutionCallback<B>,
+{
+    fn optimise(
+        &mut self,
+        brancher: &mut B,
+        termination: &mut impl TerminationCondition,
+        solver: &mut Solver,
+    ) -> OptimisationResult {
+        // First we do a feasibility check
+        let feasibility_check = solver.solve_in_scope(&[], termination, brancher);
+        match feasibility_check {
+            CSPSolverExecutionFlag::Feasible => {}
+            CSPSolverExecutionFlag::Infeasible => {
+                // Reset the state whenever we return a result
+                solver.satisfaction_solver.restore_state_at_root(brancher);
+                solver.conclude_proof_unsat();
+                return OptimisationResult::Unsatisfiable;
+            }
+            CSPSolverExecutionFlag::Timeout => {
+                // Reset the state whenever we return a result
+                solver.satisfaction_solver.restore_state_at_root(brancher);
+                return OptimisationResult::Unknown;
+            }
+        }
+        let mut best_solution: Solution = solver.get_solution_reference().into();
+        self.internal_process_solution(&best_solution, brancher, solver);
+
+        // The objectives are all minimised
+        let objectives: Vec<Var::AffineView> = self
+            .objectives
+            .iter()
+            .map(|(direction, objective)| match direction {
+                OptimisationDirection::Maximise => objective.scaled(-1),
+                OptimisationDirection::Minimise => objective.scaled(1),
+            })
+            .collect();
+
+        for (index, objective) in objectives.iter().enumerate() {
+            loop {
+                solver.satisfaction_solver.restore_state_at_root(brancher);
+
+                let best_objective_value = best_solution.get_integer_value(objective.clone());
+                let assumption = predicate!(objective <= best_objective_value - 1);
+                info!(
+                    "Lexicographic Search - Attempting to improve objective {index} with assumption {assumption}"
+                );
+
+                let solve_result = solver.solve_in_scope(&[assumption], termination, brancher);
+                match solve_result {
+                    CSPSolverExecutionFlag::Feasible => {
+                        best_solution = solver.get_solution_reference().into();
+                        self.internal_process_solution(&best_solution, brancher, solver);
+                    }
+                    CSPSolverExecutionFlag::Infeasible => {
+                        solver.satisfaction_solver.restore_state_at_root(brancher);
+
+                        // The best-found solution is optimal with respect to this objective, so
+                        // we freeze the objective to its optimal value; if this fails, then no
+                        // other solution exists and the best-found solution is optimal
+                        if solver.add_clause([!assumption]).is_err()
+                            || solver
+                                .add_clause([predicate!(objective <= best_objective_value)])
+                                .is_err()
+                        {
+                            solver.satisfaction_solver.restore_state_at_root(brancher);
+                            return OptimisationResult::Optimal(best_solution);
+                        }
+                        break;
+                    }
+                    CSPSolverExecutionFlag::Timeout => {
+                        // Reset the state whenever we return a result
+                        solver.satisfaction_solver.restore_state_at_root(brancher);
+                        return OptimisationResult::Satisfiable(best_solution);
+                    }
+                }
+            }
+        }
+
+        solver.satisfaction_solver.restore_state_at_root(brancher);
+        OptimisationResult::Optimal(best_solution)
+    }
+
+    fn on_solution_callback(&self, solver: &Solver, solution: SolutionReference, brancher: &B) {
+        self.solution_callback
+            .on_solution_callback(solver, solution, brancher)
+    }
+}
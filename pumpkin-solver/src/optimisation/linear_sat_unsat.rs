@@ -46,9 +46,7 @@ impl<Var: IntegerVariable, Callback> LinearSatUnsat<Var, Callback> {
         best_objective_value: i64,
         solver: &mut Solver,
     ) -> Result<(), ConstraintOperationError> {
-        solver.satisfaction_solver.add_clause([predicate!(
-            objective_variable <= (best_objective_value - 1) as i32
-        )])
+        solver.add_clause([predicate!(objective_variable <= best_objective_value - 1)])
     }
 
     fn debug_bound_change(
@@ -58,10 +56,10 @@ impl<Var: IntegerVariable, Callback> LinearSatUnsat<Var, Callback> {
         solver: &Solver,
     ) {
         pumpkin_assert_simple!(
-            (solver
+            solver
                 .satisfaction_solver
                 .get_assigned_integer_value(objective_variable)
-                .expect("expected variable to be assigned") as i64)
+                .expect("expected variable to be assigned")
                 < best_objective_value,
             "{}",
             format!(
@@ -98,13 +96,13 @@ where
         // objective_multiplier ensures that the objective is correctly logged.
         let objective_multiplier = if is_maximising { -1 } else { 1 };
 
-        let initial_solve = solver.satisfaction_solver.solve(termination, brancher);
+        let initial_solve = solver.solve_in_scope(&[], termination, brancher);
         match initial_solve {
             CSPSolverExecutionFlag::Feasible => {}
             CSPSolverExecutionFlag::Infeasible => {
                 // Reset the state whenever we return a result
                 solver.satisfaction_solver.restore_state_at_root(brancher);
-                let _ = solver.satisfaction_solver.conclude_proof_unsat();
+                solver.conclude_proof_unsat();
                 return OptimisationResult::Unsatisfiable;
             }
             CSPSolverExecutionFlag::Timeout => {
@@ -129,33 +127,31 @@ where
             solver.satisfaction_solver.restore_state_at_root(brancher);
 
             let objective_bound_predicate = if is_maximising {
-                predicate![objective >= best_objective_value as i32 * objective_multiplier]
+                predicate![objective >= best_objective_value * objective_multiplier]
             } else {
-                predicate![objective <= best_objective_value as i32 * objective_multiplier]
+                predicate![objective <= best_objective_value * objective_multiplier]
             };
 
             if self
                 .strengthen(
                     &objective,
-                    best_objective_value * objective_multiplier as i64,
+                    best_objective_value * objective_multiplier,
                     solver,
                 )
                 .is_err()
             {
                 // Reset the state whenever we return a result
                 solver.satisfaction_solver.restore_state_at_root(brancher);
-                let _ = solver
-                    .satisfaction_solver
-                    .conclude_proof_optimal(objective_bound_predicate);
+                solver.conclude_proof_optimal_with_bound(objective_bound_predicate);
                 return OptimisationResult::Optimal(best_solution);
             }
 
-            let solve_result = solver.satisfaction_solver.solve(termination, brancher);
+            let solve_result = solver.solve_in_scope(&[], termination, brancher);
             match solve_result {
                 CSPSolverExecutionFlag::Feasible => {
                     self.debug_bound_change(
                         &objective,
-                        best_objective_value * objective_multiplier as i64,
+                        best_objective_value * objective_multiplier,
                         solver,
                     );
                     self.update_best_solution_and_process(
@@ -171,9 +167,7 @@ where
                     {
                         // Reset the state whenever we return a result
                         solver.satisfaction_solver.restore_state_at_root(brancher);
-                        let _ = solver
-                            .satisfaction_solver
-                            .conclude_proof_optimal(objective_bound_predicate);
+                        solver.conclude_proof_optimal_with_bound(objective_bound_predicate);
                         return OptimisationResult::Optimal(best_solution);
                     }
                 }
@@ -59,13 +59,13 @@ where
         let objective_multiplier = if is_maximising { -1 } else { 1 };
 
         // First we do a feasibility check
-        let feasibility_check = solver.satisfaction_solver.solve(termination, brancher);
+        let feasibility_check = solver.solve_in_scope(&[], termination, brancher);
         match feasibility_check {
             CSPSolverExecutionFlag::Feasible => {}
             CSPSolverExecutionFlag::Infeasible => {
                 // Reset the state whenever we return a result
                 solver.satisfaction_solver.restore_state_at_root(brancher);
-                let _ = solver.satisfaction_solver.conclude_proof_unsat();
+                solver.conclude_proof_unsat();
                 return OptimisationResult::Unsatisfiable;
             }
             CSPSolverExecutionFlag::Timeout => {
@@ -87,19 +87,16 @@ where
         );
         solver.satisfaction_solver.restore_state_at_root(brancher);
 
+        let mut lower_bound = solver.lower_bound(&objective);
         loop {
-            let assumption = predicate!(objective <= solver.lower_bound(&objective));
+            let assumption = predicate!(objective <= lower_bound);
 
             info!(
                 "Lower-Bounding Search - Attempting to find solution with assumption {assumption}"
             );
 
             // Solve under the assumption that the objective variable is lower than `lower-bound`
-            let solve_result = solver.satisfaction_solver.solve_under_assumptions(
-                &[assumption],
-                termination,
-                brancher,
-            );
+            let solve_result = solver.solve_in_scope(&[assumption], termination, brancher);
             match solve_result {
                 CSPSolverExecutionFlag::Feasible => {
                     self.update_best_solution_and_process(
@@ -117,13 +114,11 @@ where
                     // We create a predicate specifying the best-found solution for the proof
                     // logging
                     let objective_bound_predicate = if is_maximising {
-                        predicate![objective >= best_objective_value as i32 * objective_multiplier]
+                        predicate![objective >= best_objective_value * objective_multiplier]
                     } else {
-                        predicate![objective <= best_objective_value as i32 * objective_multiplier]
+                        predicate![objective <= best_objective_value * objective_multiplier]
                     };
-                    let _ = solver
-                        .satisfaction_solver
-                        .conclude_proof_optimal(objective_bound_predicate);
+                    solver.conclude_proof_optimal_with_bound(objective_bound_predicate);
 
                     return OptimisationResult::Optimal(best_solution);
                 }
@@ -132,6 +127,9 @@ where
                     // We add the (hard) constraint that the negated assumption should hold (i.e.,
                     // the solution should be at least as large as the found solution)
                     let _ = solver.add_clause([!assumption]);
+                    // Within a scope, this constraint does not tighten the bounds at the root, so
+                    // we keep track of the lower-bound ourselves
+                    lower_bound = solver.lower_bound(&objective).max(lower_bound + 1);
                 }
                 CSPSolverExecutionFlag::Timeout => {
                     // Reset the state whenever we return a result
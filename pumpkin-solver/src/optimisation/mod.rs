@@ -12,8 +12,14 @@ use crate::termination::TerminationCondition;
 use crate::variables::IntegerVariable;
 use crate::Solver;
 
+pub mod bisection;
+pub mod core_guided;
+pub mod large_neighbourhood_search;
+pub mod lexicographic;
 pub mod linear_sat_unsat;
 pub mod linear_unsat_sat;
+pub mod neighbourhoods;
+pub mod pareto;
 pub mod solution_callback;
 
 pub trait OptimisationProcedure<B: Brancher, Callback: SolutionCallback<B>> {
@@ -35,18 +41,18 @@ pub trait OptimisationProcedure<B: Brancher, Callback: SolutionCallback<B>> {
     /// - Calling the solution callback.
     fn update_best_solution_and_process(
         &self,
-        objective_multiplier: i32,
+        objective_multiplier: i64,
         objective_variable: &impl IntegerVariable,
         best_objective_value: &mut i64,
         best_solution: &mut Solution,
         brancher: &mut B,
         solver: &Solver,
     ) {
-        *best_objective_value = (objective_multiplier
+        *best_objective_value = objective_multiplier
             * solver
                 .satisfaction_solver
                 .get_assigned_integer_value(objective_variable)
-                .expect("expected variable to be assigned")) as i64;
+                .expect("expected variable to be assigned");
         *best_solution = solver.satisfaction_solver.get_solution_reference().into();
 
         self.internal_process_solution(best_solution, brancher, solver)
@@ -59,6 +65,29 @@ pub trait OptimisationProcedure<B: Brancher, Callback: SolutionCallback<B>> {
     }
 }
 
+/// This allows an [`OptimisationProcedure`] to be passed by reference to [`Solver::optimise`], such
+/// that it can be inspected afterwards (e.g. to retrieve the front found by
+/// [`pareto::Pareto`]).
+impl<P, B, Callback> OptimisationProcedure<B, Callback> for &mut P
+where
+    P: OptimisationProcedure<B, Callback>,
+    B: Brancher,
+    Callback: SolutionCallback<B>,
+{
+    fn optimise(
+        &mut self,
+        brancher: &mut B,
+        termination: &mut impl TerminationCondition,
+        solver: &mut Solver,
+    ) -> OptimisationResult {
+        (**self).optimise(brancher, termination, solver)
+    }
+
+    fn on_solution_callback(&self, solver: &Solver, solution: SolutionReference, brancher: &B) {
+        (**self).on_solution_callback(solver, solution, brancher)
+    }
+}
+
 /// The type of search which is performed by the solver.
 #[derive(Debug, Clone, Copy, ValueEnum, Default)]
 pub enum OptimisationStrategy {
@@ -69,6 +98,20 @@ pub enum OptimisationStrategy {
     /// Linear UNSAT-SAT - Starts with an unsatisfiable solution and tightens the bound on the
     /// objective variable until a SAT result is reached. Can be seen as lower-bounding search.
     LinearUnsatSat,
+    /// Core-Guided - Solves under the assumption that every term of the objective takes its
+    /// lower-bound, and uses the unsatisfiable cores to increase the lower-bound on the objective
+    /// until a SAT result is reached. Can be seen as lower-bounding search over the individual
+    /// terms of the objective.
+    CoreGuided,
+    /// Large Neighbourhood Search - Starts with a satisfiable solution and repeatedly searches
+    /// for a better solution in a neighbourhood of the incumbent solution, in which only a subset
+    /// of the variables is relaxed. Can be seen as upper-bounding search which only proves
+    /// optimality when no variables are fixed.
+    LargeNeighbourhoodSearch,
+    /// Bisection - Starts with a satisfiable solution and repeatedly attempts to find a solution
+    /// of which the objective value is at most the midpoint between the proven lower-bound and
+    /// the upper-bound. Can be seen as a combination of upper-bounding and lower-bounding search.
+    Bisection,
 }
 
 impl Display for OptimisationStrategy {
@@ -76,6 +119,11 @@ impl Display for OptimisationStrategy {
         match self {
             OptimisationStrategy::LinearSatUnsat => write!(f, "linear-sat-unsat"),
             OptimisationStrategy::LinearUnsatSat => write!(f, "linear-unsat-sat"),
+            OptimisationStrategy::CoreGuided => write!(f, "core-guided"),
+            OptimisationStrategy::LargeNeighbourhoodSearch => {
+                write!(f, "large-neighbourhood-search")
+            }
+            OptimisationStrategy::Bisection => write!(f, "bisection"),
         }
     }
 }
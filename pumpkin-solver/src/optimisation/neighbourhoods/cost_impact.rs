@@ -0,0 +1,109 @@
+use super::Neighbourhood;
+use super::NeighbourhoodContext;
+use crate::optimisation::OptimisationDirection;
+use crate::predicate;
+use crate::results::ProblemSolution;
+use crate::results::Solution;
+use crate::variables::DomainId;
+use crate::variables::IntegerVariable;
+
+/// A [`Neighbourhood`] which prefers to relax the variables which have a large impact on the
+/// objective.
+///
+/// The impact of a variable is the increase in the lower-bound of the (minimised) objective when
+/// the variable is fixed to its value in the incumbent solution; if fixing the variable leads to
+/// a conflict, then the impact is the size of the domain of the objective. The variables are
+/// relaxed randomly, where the probability of relaxing a variable is proportional to its impact
+/// (plus one, to ensure that every variable can be selected).
+///
+/// The impacts are only recomputed when the objective value of the incumbent solution changes.
+#[derive(Debug, Clone)]
+pub struct CostImpactNeighbourhood<Var> {
+    direction: OptimisationDirection,
+    objective: Var,
+    /// The objective value of the incumbent solution for which the impacts were computed.
+    incumbent_objective_value: Option<i64>,
+    /// The impact of each variable.
+    impacts: Vec<(DomainId, u64)>,
+}
+
+impl<Var: IntegerVariable + 'static> CostImpactNeighbourhood<Var> {
+    pub fn new(direction: OptimisationDirection, objective: Var) -> Self {
+        Self {
+            direction,
+            objective,
+            incumbent_objective_value: None,
+            impacts: Vec::new(),
+        }
+    }
+
+    /// Computes the impacts of the provided `variables` when fixed to their values in the
+    /// `incumbent`.
+    fn compute_impacts(
+        &mut self,
+        variables: &[DomainId],
+        incumbent: &Solution,
+        context: &mut NeighbourhoodContext,
+    ) {
+        let objective = match self.direction {
+            OptimisationDirection::Maximise => self.objective.scaled(-1),
+            OptimisationDirection::Minimise => self.objective.scaled(1),
+        };
+
+        let (lower_bound, upper_bound) = context
+            .probe(&[], |domains| {
+                (
+                    domains.lower_bound(objective.clone()),
+                    domains.upper_bound(objective.clone()),
+                )
+            })
+            .unwrap_or((0, 0));
+        let maximum_impact = upper_bound.abs_diff(lower_bound);
+
+        self.impacts = variables
+            .iter()
+            .map(|&variable| {
+                let value = incumbent.get_integer_value(variable);
+                let impact = context
+                    .probe(&[predicate!(variable == value)], |domains| {
+                        domains.lower_bound(objective.clone()).abs_diff(lower_bound)
+                    })
+                    .unwrap_or(maximum_impact);
+                (variable, impact)
+            })
+            .collect();
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Neighbourhood for CostImpactNeighbourhood<Var> {
+    fn relax(
+        &mut self,
+        variables: &[DomainId],
+        incumbent: &Solution,
+        num_relaxed: usize,
+        context: &mut NeighbourhoodContext,
+    ) -> Vec<DomainId> {
+        let incumbent_objective_value = incumbent.get_integer_value(self.objective.clone());
+        if self.incumbent_objective_value != Some(incumbent_objective_value)
+            || self.impacts.len() != variables.len()
+        {
+            self.compute_impacts(variables, incumbent, context);
+            self.incumbent_objective_value = Some(incumbent_objective_value);
+        }
+
+        let mut candidates = self.impacts.clone();
+        let mut relaxed = Vec::new();
+        while relaxed.len() < num_relaxed && !candidates.is_empty() {
+            let weights = candidates
+                .iter()
+                .map(|&(_, impact)| impact as f64 + 1.0)
+                .collect::<Vec<_>>();
+            let selected = context
+                .random()
+                .get_weighted_choice(&weights)
+                .expect("there are candidates");
+            relaxed.push(candidates.swap_remove(selected).0);
+        }
+        relaxed
+    }
+}
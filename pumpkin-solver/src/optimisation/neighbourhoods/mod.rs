@@ -0,0 +1,46 @@
+//! Provides the [`Neighbourhood`] trait which is used by
+//! [`LargeNeighbourhoodSearch`](super::large_neighbourhood_search::LargeNeighbourhoodSearch) to
+//! determine which variables are relaxed around the incumbent solution; all other variables are
+//! fixed to their values in the incumbent solution.
+//!
+//! Furthermore, it defines several implementations of the [`Neighbourhood`] trait, and the
+//! [`NeighbourhoodStrategy`] which can be used to select one of them.
+use std::fmt::Display;
+
+use clap::ValueEnum;
+
+mod cost_impact;
+mod neighbourhood;
+mod neighbourhood_context;
+mod propagation_guided;
+mod random;
+
+pub use cost_impact::*;
+pub use neighbourhood::Neighbourhood;
+pub use neighbourhood_context::NeighbourhoodContext;
+pub use propagation_guided::*;
+pub use random::*;
+
+/// The type of [`Neighbourhood`] which is used during large neighbourhood search.
+#[derive(Debug, Clone, Copy, ValueEnum, Default)]
+pub enum NeighbourhoodStrategy {
+    /// Relaxes a random subset of the variables (see [`RandomNeighbourhood`]).
+    #[default]
+    Random,
+    /// Fixes variables one-by-one, preferring the variables which were most affected by the
+    /// propagation of the previously fixed variables (see [`PropagationGuidedNeighbourhood`]).
+    PropagationGuided,
+    /// Relaxes the variables which have the largest impact on the lower-bound of the objective
+    /// when fixed to their value in the incumbent solution (see [`CostImpactNeighbourhood`]).
+    CostImpact,
+}
+
+impl Display for NeighbourhoodStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NeighbourhoodStrategy::Random => write!(f, "random"),
+            NeighbourhoodStrategy::PropagationGuided => write!(f, "propagation-guided"),
+            NeighbourhoodStrategy::CostImpact => write!(f, "cost-impact"),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use super::NeighbourhoodContext;
+#[cfg(doc)]
+use crate::optimisation::large_neighbourhood_search::LargeNeighbourhoodSearch;
+use crate::results::Solution;
+use crate::variables::DomainId;
+
+/// A trait containing the interface for neighbourhoods; a neighbourhood determines which
+/// variables are relaxed in an iteration of [`LargeNeighbourhoodSearch`]. The variables which are
+/// not relaxed are fixed to their values in the incumbent solution.
+pub trait Neighbourhood {
+    /// Returns the subset of the provided `variables` which should be relaxed around the
+    /// `incumbent` solution; the size of the returned subset should be close to `num_relaxed`.
+    ///
+    /// The [`NeighbourhoodContext`] can be used to determine the effect of fixing variables to
+    /// their values in the incumbent solution.
+    fn relax(
+        &mut self,
+        variables: &[DomainId],
+        incumbent: &Solution,
+        num_relaxed: usize,
+        context: &mut NeighbourhoodContext,
+    ) -> Vec<DomainId>;
+}
+
+impl<N: Neighbourhood + ?Sized> Neighbourhood for Box<N> {
+    fn relax(
+        &mut self,
+        variables: &[DomainId],
+        incumbent: &Solution,
+        num_relaxed: usize,
+        context: &mut NeighbourhoodContext,
+    ) -> Vec<DomainId> {
+        (**self).relax(variables, incumbent, num_relaxed, context)
+    }
+}
@@ -0,0 +1,51 @@
+use std::fmt::Debug;
+
+use crate::basic_types::Random;
+use crate::branching::Brancher;
+use crate::branching::SelectionContext;
+use crate::engine::ConstraintSatisfactionSolver;
+#[cfg(doc)]
+use crate::optimisation::neighbourhoods::Neighbourhood;
+use crate::predicates::Predicate;
+
+/// The context provided to a [`Neighbourhood`]; it allows access to methods from a [`Random`]
+/// generator and the probing of the effect of a set of [`Predicate`]s on the domains.
+pub struct NeighbourhoodContext<'a> {
+    solver: &'a mut ConstraintSatisfactionSolver,
+    brancher: &'a mut dyn Brancher,
+}
+
+impl Debug for NeighbourhoodContext<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NeighbourhoodContext")
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> NeighbourhoodContext<'a> {
+    pub(crate) fn new(
+        solver: &'a mut ConstraintSatisfactionSolver,
+        brancher: &'a mut dyn Brancher,
+    ) -> Self {
+        NeighbourhoodContext { solver, brancher }
+    }
+
+    /// Returns a random generator which can be used to generate random values (see [`Random`] for
+    /// more information).
+    pub fn random(&mut self) -> &mut dyn Random {
+        self.solver.get_random_generator()
+    }
+
+    /// Posts the provided `predicates` and propagates them; if this does not lead to a conflict,
+    /// then `inspect` is called with a [`SelectionContext`] which provides access to the
+    /// resulting domains. Afterwards, the predicates are retracted again.
+    ///
+    /// Returns [`None`] if the predicates lead to a conflict.
+    pub fn probe<R>(
+        &mut self,
+        predicates: &[Predicate],
+        inspect: impl FnOnce(&mut SelectionContext) -> R,
+    ) -> Option<R> {
+        self.solver.probe(predicates, self.brancher, inspect)
+    }
+}
@@ -0,0 +1,90 @@
+use super::Neighbourhood;
+use super::NeighbourhoodContext;
+use crate::predicate;
+use crate::results::ProblemSolution;
+use crate::results::Solution;
+use crate::variables::DomainId;
+
+/// A [`Neighbourhood`] which fixes the variables one-by-one to their values in the incumbent
+/// solution, based on propagation-guided LNS \[1\]. After fixing a variable, the fixed
+/// variables are propagated and the next variable to fix is the variable of which the domain was
+/// reduced the most by this propagation (or a random variable if no domain was reduced).
+///
+/// The variables which have not been fixed (either explicitly or by propagation) once the number
+/// of such variables does not exceed the requested number of relaxed variables are relaxed. If
+/// fixing a variable leads to a conflict, then the variables which are not yet fixed are relaxed.
+///
+/// # Bibliography
+/// \[1\] L. Perron, P. Shaw, and V. Furnon, ‘Propagation guided large neighborhood search’, in
+/// Principles and Practice of Constraint Programming–CP 2004: 10th International Conference, CP
+/// 2004, Toronto, Canada, September 27-October 1, 2004. Proceedings 10, 2004, pp. 468–481.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PropagationGuidedNeighbourhood;
+
+impl Neighbourhood for PropagationGuidedNeighbourhood {
+    fn relax(
+        &mut self,
+        variables: &[DomainId],
+        incumbent: &Solution,
+        num_relaxed: usize,
+        context: &mut NeighbourhoodContext,
+    ) -> Vec<DomainId> {
+        let Some(mut domain_sizes) = context.probe(&[], |domains| {
+            variables
+                .iter()
+                .map(|&variable| domains.get_size_of_domain(variable))
+                .collect::<Vec<_>>()
+        }) else {
+            return variables.to_vec();
+        };
+
+        let mut fixed_predicates = Vec::new();
+        let mut reductions = vec![0; variables.len()];
+
+        loop {
+            let unfixed = (0..variables.len())
+                .filter(|&index| domain_sizes[index] > 0)
+                .collect::<Vec<_>>();
+            if unfixed.len() <= num_relaxed {
+                return unfixed.into_iter().map(|index| variables[index]).collect();
+            }
+
+            // We select the variable which was affected most by the previous propagation, ties
+            // (including the case where no variable was affected) are broken randomly
+            let maximum_reduction = unfixed
+                .iter()
+                .map(|&index| reductions[index])
+                .max()
+                .expect("there are unfixed variables");
+            let candidates = unfixed
+                .iter()
+                .copied()
+                .filter(|&index| reductions[index] == maximum_reduction)
+                .collect::<Vec<_>>();
+            let selected = candidates[context
+                .random()
+                .generate_usize_in_range(0..candidates.len())];
+
+            let variable = variables[selected];
+            let value = incumbent.get_integer_value(variable);
+            fixed_predicates.push(predicate!(variable == value));
+
+            let Some(new_domain_sizes) = context.probe(&fixed_predicates, |domains| {
+                variables
+                    .iter()
+                    .map(|&variable| domains.get_size_of_domain(variable))
+                    .collect::<Vec<_>>()
+            }) else {
+                // Fixing the variable leads to a conflict, so we relax the remaining variables
+                return unfixed.into_iter().map(|index| variables[index]).collect();
+            };
+
+            reductions = domain_sizes
+                .iter()
+                .zip(new_domain_sizes.iter())
+                .map(|(old_size, new_size)| old_size - new_size)
+                .collect();
+            domain_sizes = new_domain_sizes;
+        }
+    }
+}
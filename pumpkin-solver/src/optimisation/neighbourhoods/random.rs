@@ -0,0 +1,31 @@
+use super::Neighbourhood;
+use super::NeighbourhoodContext;
+use crate::results::Solution;
+use crate::variables::DomainId;
+
+/// A [`Neighbourhood`] which relaxes a random subset of the variables, where every subset of the
+/// requested size is equally likely.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomNeighbourhood;
+
+impl Neighbourhood for RandomNeighbourhood {
+    fn relax(
+        &mut self,
+        variables: &[DomainId],
+        _incumbent: &Solution,
+        num_relaxed: usize,
+        context: &mut NeighbourhoodContext,
+    ) -> Vec<DomainId> {
+        // We perform a partial Fisher-Yates shuffle to select the relaxed variables
+        let mut relaxed = variables.to_vec();
+        let num_relaxed = num_relaxed.min(relaxed.len());
+        for index in 0..num_relaxed {
+            let selected = context
+                .random()
+                .generate_usize_in_range(index..relaxed.len());
+            relaxed.swap(index, selected);
+        }
+        relaxed.truncate(num_relaxed);
+        relaxed
+    }
+}
@@ -0,0 +1,203 @@
+use log::info;
+
+use super::solution_callback::SolutionCallback;
+use super::OptimisationProcedure;
+use crate::basic_types::CSPSolverExecutionFlag;
+use crate::branching::Brancher;
+use crate::optimisation::OptimisationDirection;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::results::OptimisationResult;
+use crate::results::ProblemSolution;
+use crate::results::Solution;
+use crate::results::SolutionReference;
+use crate::termination::TerminationCondition;
+use crate::variables::IntegerVariable;
+use crate::Solver;
+
+/// Implements an optimisation procedure which enumerates the Pareto front of multiple
+/// objectives; i.e. the set of solutions which are not dominated by any other solution.
+///
+/// Whenever a solution is found, it is repeatedly improved by searching for a solution which
+/// dominates it (i.e. a solution which is at least as good on every objective and strictly better
+/// on at least one objective) until no such solution exists. The resulting non-dominated solution
+/// is added to the front (see [`Pareto::front`]) and reported to the solution callback, after
+/// which a nogood is added which blocks the region of solutions dominated by it.
+///
+/// The procedure returns [`OptimisationResult::Optimal`] with the last solution of the front once
+/// the front is complete; if it is stopped by the [`TerminationCondition`] after finding a
+/// solution, then [`OptimisationResult::Satisfiable`] is returned with the last solution of the
+/// (partial) front, or with the (possibly dominated) solution which was being improved if the front
+/// is still empty.
+///
+/// Since the proof format has no conclusion for a Pareto front, a complete front is concluded in the
+/// proof as the unsatisfiability of the model together with the nogoods which block the regions
+/// dominated by the solutions in the front.
+///
+/// Note that the blocking nogoods remain in the solver after the procedure has terminated; these
+/// can be retracted by optimising within a scope (see [`Solver::push`]).
+#[derive(Debug, Clone)]
+pub struct Pareto<Var, Callback> {
+    objectives: Vec<(OptimisationDirection, Var)>,
+    solution_callback: Callback,
+    /// The non-dominated solutions which have been found.
+    front: Vec<Solution>,
+}
+
+impl<Var, Callback> Pareto<Var, Callback> {
+    /// Create a new instance of [`Pareto`] over the provided `objectives`.
+    pub fn new(
+        objectives: impl IntoIterator<Item = (OptimisationDirection, Var)>,
+        solution_callback: Callback,
+    ) -> Self {
+        Self {
+            objectives: objectives.into_iter().collect(),
+            solution_callback,
+            front: vec![],
+        }
+    }
+
+    /// Returns the non-dominated solutions which have been found, in the order in which they were
+    /// found.
+    pub fn front(&self) -> &[Solution] {
+        &self.front
+    }
+
+    /// Consumes the procedure and returns the non-dominated solutions which have been found.
+    pub fn into_front(self) -> Vec<Solution> {
+        self.front
+    }
+}
+
+impl<Var: IntegerVariable + 'static, Callback> Pareto<Var, Callback> {
+    /// Returns the (minimised) objectives.
+    fn minimised_objectives(&self) -> Vec<Var::AffineView> {
+        self.objectives
+            .iter()
+            .map(|(direction, objective)| match direction {
+                OptimisationDirection::Maximise => objective.scaled(-1),
+                OptimisationDirection::Minimise => objective.scaled(1),
+            })
+            .collect()
+    }
+
+    /// Returns the predicates which state that the objective value is strictly better than the
+    /// objective value of the provided `solution`, for each of the objectives.
+    fn improvements(objectives: &[Var::AffineView], solution: &Solution) -> Vec<Predicate> {
+        objectives
+            .iter()
+            .map(|objective| {
+                let value = solution.get_integer_value(objective.clone());
+                predicate!(objective <= value - 1)
+            })
+            .collect()
+    }
+}
+
+impl<Var, B, Callback> OptimisationProcedure<B, Callback> for Pareto<Var, Callback>
+where
+    Var: IntegerVariable + 'static,
+    B: Brancher,
+    Callback: SolutionCallback<B>,
+{
+    fn optimise(
+        &mut self,
+        brancher: &mut B,
+        termination: &mut impl TerminationCondition,
+        solver: &mut Solver,
+    ) -> OptimisationResult {
+        let objectives = self.minimised_objectives();
+
+        loop {
+            // Find a solution which is not dominated by the solutions in the front
+            let solve_result = solver.solve_in_scope(&[], termination, brancher);
+            let mut solution: Solution = match solve_result {
+                CSPSolverExecutionFlag::Feasible => solver.get_solution_reference().into(),
+                CSPSolverExecutionFlag::Infeasible => {
+                    // Reset the state whenever we return a result
+                    solver.satisfaction_solver.restore_state_at_root(brancher);
+                    solver.conclude_proof_unsat();
+                    return match self.front.last() {
+                        Some(solution) => OptimisationResult::Optimal(solution.clone()),
+                        None => OptimisationResult::Unsatisfiable,
+                    };
+                }
+                CSPSolverExecutionFlag::Timeout => {
+                    // Reset the state whenever we return a result
+                    solver.satisfaction_solver.restore_state_at_root(brancher);
+                    return match self.front.last() {
+                        Some(solution) => OptimisationResult::Satisfiable(solution.clone()),
+                        None => OptimisationResult::Unknown,
+                    };
+                }
+            };
+
+            // Improve the solution until it is not dominated by any other solution
+            solver.satisfaction_solver.restore_state_at_root(brancher);
+
+            // The activation literal enforces that the solution is strictly better on at least one
+            // of the objectives. A single literal is used for all improvements of this solution,
+            // since the clauses of the earlier improvements are implied by the assumptions of the
+            // later ones. The literal is named such that it can be referred to in the proof.
+            let activation_literal =
+                solver.new_named_literal(format!("pareto_activation_{}", self.front.len()));
+
+            loop {
+                let improvements = Self::improvements(&objectives, &solution);
+                let _ = solver.add_clause(
+                    std::iter::once(activation_literal.get_false_predicate())
+                        .chain(improvements.iter().copied()),
+                );
+
+                // And we assume that the solution is at least as good on all of the objectives
+                let assumptions = std::iter::once(activation_literal.get_true_predicate())
+                    .chain(objectives.iter().map(|objective| {
+                        let value = solution.get_integer_value(objective.clone());
+                        predicate!(objective <= value)
+                    }))
+                    .collect::<Vec<_>>();
+
+                let solve_result = solver.solve_in_scope(&assumptions, termination, brancher);
+                let is_dominated = matches!(solve_result, CSPSolverExecutionFlag::Feasible);
+                if is_dominated {
+                    solution = solver.get_solution_reference().into();
+                }
+                solver.satisfaction_solver.restore_state_at_root(brancher);
+
+                match solve_result {
+                    CSPSolverExecutionFlag::Feasible => {}
+                    CSPSolverExecutionFlag::Infeasible => break,
+                    CSPSolverExecutionFlag::Timeout => {
+                        let _ = solver.add_clause([activation_literal.get_false_predicate()]);
+                        let solution = self.front.last().cloned().unwrap_or(solution);
+                        return OptimisationResult::Satisfiable(solution);
+                    }
+                }
+            }
+
+            // Deactivate the clauses of the improvements
+            let _ = solver.add_clause([activation_literal.get_false_predicate()]);
+
+            info!(
+                "Pareto Search - Found non-dominated solution {} of the front",
+                self.front.len() + 1
+            );
+            self.internal_process_solution(&solution, brancher, solver);
+
+            // Block the solutions which are dominated by (or equivalent to) the found solution
+            let blocking_clause = Self::improvements(&objectives, &solution);
+            self.front.push(solution);
+            if solver.add_clause(blocking_clause).is_err() {
+                solver.conclude_proof_unsat();
+                return OptimisationResult::Optimal(
+                    self.front.last().expect("a solution was found").clone(),
+                );
+            }
+        }
+    }
+
+    fn on_solution_callback(&self, solver: &Solver, solution: SolutionReference, brancher: &B) {
+        self.solution_callback
+            .on_solution_callback(solver, solution, brancher)
+    }
+}
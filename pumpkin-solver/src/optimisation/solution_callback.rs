@@ -1,9 +1,17 @@
 use crate::branching::Brancher;
+#[cfg(doc)]
+use crate::optimisation::OptimisationProcedure;
 use crate::results::SolutionReference;
 use crate::Solver;
 
 pub trait SolutionCallback<B: Brancher> {
     fn on_solution_callback(&self, solver: &Solver, solution: SolutionReference, brancher: &B);
+
+    /// Called when an [`OptimisationProcedure`] has proven new bounds on the objective; i.e. the
+    /// objective value of an optimal solution is in the range `[lower_bound, upper_bound]`.
+    ///
+    /// Note that not every [`OptimisationProcedure`] reports its bounds.
+    fn on_bounds_callback(&self, _solver: &Solver, _lower_bound: i64, _upper_bound: i64) {}
 }
 
 impl<T: Fn(&Solver, SolutionReference, &B), B: Brancher> SolutionCallback<B> for T {
@@ -18,4 +26,10 @@ impl<T: SolutionCallback<B>, B: Brancher> SolutionCallback<B> for Option<T> {
             callback.on_solution_callback(solver, solution, brancher)
         }
     }
+
+    fn on_bounds_callback(&self, solver: &Solver, lower_bound: i64, upper_bound: i64) {
+        if let Some(callback) = self {
+            callback.on_bounds_callback(solver, lower_bound, upper_bound)
+        }
+    }
 }
@@ -2,20 +2,138 @@ use std::io::BufWriter;
 use std::io::Write;
 use std::num::NonZeroU64;
 
+use drcp_format::Format;
+use thiserror::Error;
+
+use crate::basic_types::HashMap;
+use crate::basic_types::HashSet;
+use crate::engine::variables::DomainId;
 use crate::engine::VariableNames;
 use crate::predicates::Predicate;
 
+/// A proof for a DIMACS problem, in either the DRAT or the LRAT format.
+///
+/// Both formats can be written as text or in their binary encoding. In the LRAT format, every
+/// clause is identified by an id, where the clauses of the input formula take the ids `1..=m`.
+/// The learned clauses are annotated with the ids of the clauses from which they are derived by
+/// unit propagation.
 #[derive(Debug)]
 pub(crate) struct DimacsProof<W: Write> {
     writer: BufWriter<W>,
+    format: Format,
+    next_input_clause_id: NonZeroU64,
     next_clause_id: NonZeroU64,
+    /// Whether the empty clause has been logged.
+    is_unsat: bool,
+    /// Whether a learned clause could not be logged, after which no more steps are written.
+    is_incomplete: bool,
+    /// The clauses which can be referred to as hints, if the proof is logged in the LRAT format.
+    lrat: Option<LratClauses>,
+}
+
+/// Errors which can occur when logging a learned clause to a [`DimacsProof`].
+#[derive(Debug, Error)]
+pub(crate) enum DimacsProofError {
+    #[error("failed to write the proof")]
+    Io(#[from] std::io::Error),
+
+    /// The recorded hints do not derive the learned clause by unit propagation. The clause is not
+    /// logged, and the proof is incomplete from this point onwards.
+    #[error("the hints of a learned clause do not lead to a conflict")]
+    InvalidHints,
+}
+
+/// The clauses of an LRAT proof, which are used to annotate learned clauses with hints.
+///
+/// The literals of a clause are stored as signed domain ids (see [`clause_key`]), which are sorted
+/// and do not contain duplicates. This allows the clauses to be found based on the predicates
+/// which are used during conflict analysis.
+#[derive(Debug, Default)]
+struct LratClauses {
+    /// The literals of the clauses which have not been deleted.
+    clauses: HashMap<NonZeroU64, Box<[i32]>>,
+    /// The ids of the clauses which have not been deleted, keyed by their literals.
+    ids: HashMap<Box<[i32]>, Vec<NonZeroU64>>,
+    /// The clauses used to derive the next learned clause, in reverse-application order.
+    hints: Vec<NonZeroU64>,
 }
 
 impl<W: Write> DimacsProof<W> {
-    pub(crate) fn new(writer: W) -> DimacsProof<W> {
+    pub(crate) fn new(writer: W, format: Format, log_hints: bool) -> DimacsProof<W> {
         DimacsProof {
             writer: BufWriter::new(writer),
+            format,
+            next_input_clause_id: NonZeroU64::new(1).unwrap(),
             next_clause_id: NonZeroU64::new(1).unwrap(),
+            is_unsat: false,
+            is_incomplete: false,
+            lrat: log_hints.then(LratClauses::default),
+        }
+    }
+
+    /// Whether the proof is logged in the LRAT format.
+    pub(crate) fn is_logging_hints(&self) -> bool {
+        self.lrat.is_some()
+    }
+
+    /// Derived clauses are numbered after the given number of input clauses.
+    pub(crate) fn set_num_input_clauses(&mut self, num_clauses: u64) {
+        self.next_clause_id = self
+            .next_clause_id
+            .max(NonZeroU64::MIN.saturating_add(num_clauses));
+    }
+
+    /// Record a clause of the input formula.
+    ///
+    /// Input clauses are not written to the proof, but in the LRAT format they are given an id so
+    /// they can be used as hints. In that case, the id is returned.
+    pub(crate) fn input_clause(
+        &mut self,
+        predicates: impl IntoIterator<Item = Predicate>,
+    ) -> Option<NonZeroU64> {
+        let lrat = self.lrat.as_mut()?;
+
+        let id = self.next_input_clause_id;
+        self.next_input_clause_id = next_id(id);
+        self.next_clause_id = self.next_clause_id.max(self.next_input_clause_id);
+
+        lrat.insert(id, clause_key(predicates));
+
+        Some(id)
+    }
+
+    /// Record that the clause `premises -> propagated` is used in the derivation of the next
+    /// learned clause.
+    ///
+    /// Only the LRAT format uses this information, as the hint of the learned clause.
+    pub(crate) fn inference(
+        &mut self,
+        premises: impl IntoIterator<Item = Predicate>,
+        propagated: Option<Predicate>,
+    ) {
+        let Some(lrat) = self.lrat.as_mut() else {
+            return;
+        };
+
+        let key = clause_key(
+            premises
+                .into_iter()
+                .map(|premise| !premise)
+                .chain(propagated),
+        );
+
+        // All propagations in a DIMACS problem are done by clauses. If the clause cannot be
+        // found, the derivation of the learned clause fails.
+        if let Some(&id) = lrat.ids.get(&key).and_then(|ids| ids.last()) {
+            lrat.hints.push(id);
+        }
+    }
+
+    /// Record that the clause with the given id is used in the derivation of the next learned
+    /// clause.
+    pub(crate) fn add_hint(&mut self, id: NonZeroU64) {
+        if let Some(lrat) = self.lrat.as_mut() {
+            lrat.hints.push(id);
         }
     }
 
@@ -23,55 +141,326 @@ impl<W: Write> DimacsProof<W> {
         &mut self,
         predicates: impl IntoIterator<Item = Predicate>,
         variable_names: &VariableNames,
-    ) -> std::io::Result<NonZeroU64> {
-        for predicate in predicates.into_iter() {
-            assert!(
-                predicate.get_right_hand_side() <= 1,
-                "in dimacs proofs all variables are Boolean (aka 0-1)"
-            );
-            assert!(
-                predicate.get_right_hand_side() >= 0,
-                "in dimacs proofs all variables are Boolean (aka 0-1)"
-            );
-
-            let variable_code = variable_names
-                .get_int_name(predicate.get_domain())
-                .expect("all variables are named in a DIMACS problem");
-            let variable_prefix = match predicate {
-                Predicate::LowerBound { lower_bound: 1, .. } => "",
-                Predicate::Equal {
-                    equality_constant: 1,
-                    ..
-                } => "",
-                Predicate::NotEqual {
-                    not_equal_constant: 0,
-                    ..
-                } => "",
-
-                Predicate::UpperBound { upper_bound: 0, .. } => "-",
-                Predicate::Equal {
-                    equality_constant: 0,
-                    ..
-                } => "-",
-                Predicate::NotEqual {
-                    not_equal_constant: 1,
-                    ..
-                } => "-",
-
-                other => panic!("Unexpected predicate {other:?} in learned clause for DIMACS"),
+    ) -> Result<NonZeroU64, DimacsProofError> {
+        let id = self.next_clause_id;
+        self.next_clause_id = next_id(id);
+
+        if self.is_incomplete {
+            return Ok(id);
+        }
+
+        let predicates = predicates.into_iter().collect::<Vec<_>>();
+
+        // An LRAT step without a valid derivation would make the whole proof invalid. Instead,
+        // the proof is cut off before the clause, and the hints are no longer recorded.
+        let lrat_step = match self.lrat.as_mut() {
+            Some(lrat) => {
+                let key = clause_key(predicates.iter().copied());
+                let Some(hints) = lrat.derivation(&key) else {
+                    self.is_incomplete = true;
+                    self.lrat = None;
+                    return Err(DimacsProofError::InvalidHints);
+                };
+
+                Some((lrat, key, hints))
+            }
+            None => None,
+        };
+
+        self.is_unsat |= predicates.is_empty();
+
+        let Some((lrat, key, hints)) = lrat_step else {
+            match self.format {
+                Format::Text => {
+                    self.write_text_literals(&predicates, variable_names)?;
+                    writeln!(self.writer, "0")?;
+                }
+                Format::Binary => {
+                    write!(self.writer, "a")?;
+                    self.write_binary_literals(&predicates, variable_names)?;
+                    write_binary_number(&mut self.writer, 0)?;
+                }
+            }
+
+            return Ok(id);
+        };
+
+        lrat.insert(id, key);
+
+        match self.format {
+            Format::Text => {
+                write!(self.writer, "{id} ")?;
+                self.write_text_literals(&predicates, variable_names)?;
+                write!(self.writer, "0")?;
+                for hint in hints {
+                    write!(self.writer, " {hint}")?;
+                }
+                writeln!(self.writer, " 0")?;
+            }
+            Format::Binary => {
+                write!(self.writer, "a")?;
+                write_binary_number(&mut self.writer, 2 * id.get())?;
+                self.write_binary_literals(&predicates, variable_names)?;
+                write_binary_number(&mut self.writer, 0)?;
+                for hint in hints {
+                    write_binary_number(&mut self.writer, 2 * hint.get())?;
+                }
+                write_binary_number(&mut self.writer, 0)?;
+            }
+        }
+
+        Ok(id)
+    }
+
+    pub(crate) fn deleted_clause(
+        &mut self,
+        predicates: impl IntoIterator<Item = Predicate>,
+        variable_names: &VariableNames,
+    ) -> std::io::Result<()> {
+        if self.is_incomplete {
+            return Ok(());
+        }
+
+        let predicates = predicates.into_iter().collect::<Vec<_>>();
+
+        let Some(lrat) = self.lrat.as_mut() else {
+            match self.format {
+                Format::Text => {
+                    write!(self.writer, "d ")?;
+                    self.write_text_literals(&predicates, variable_names)?;
+                    writeln!(self.writer, "0")?;
+                }
+                Format::Binary => {
+                    write!(self.writer, "d")?;
+                    self.write_binary_literals(&predicates, variable_names)?;
+                    write_binary_number(&mut self.writer, 0)?;
+                }
+            }
+
+            return Ok(());
+        };
+
+        let Some(id) = lrat.remove(&clause_key(predicates)) else {
+            return Ok(());
+        };
+
+        match self.format {
+            Format::Text => {
+                // Deletions are preceded by the id of the most recently added clause.
+                let last_id = self.next_clause_id.get() - 1;
+                writeln!(self.writer, "{last_id} d {id} 0")?;
+            }
+            Format::Binary => {
+                write!(self.writer, "d")?;
+                write_binary_number(&mut self.writer, 2 * id.get())?;
+                write_binary_number(&mut self.writer, 0)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Conclude the proof by logging the empty clause, unless it has already been logged or the
+    /// proof is incomplete.
+    pub(crate) fn unsat(mut self, variable_names: &VariableNames) -> Result<(), DimacsProofError> {
+        let result = if self.is_unsat {
+            Ok(())
+        } else {
+            self.learned_clause(std::iter::empty(), variable_names)
+                .map(|_| ())
+        };
+
+        self.writer.flush()?;
+        result
+    }
+
+    fn write_text_literals(
+        &mut self,
+        predicates: &[Predicate],
+        variable_names: &VariableNames,
+    ) -> std::io::Result<()> {
+        for &predicate in predicates {
+            let variable_code = variable_code(predicate.get_domain(), variable_names);
+            let variable_prefix = if is_positive_literal(predicate) {
+                ""
+            } else {
+                "-"
             };
 
             write!(self.writer, "{variable_prefix}{variable_code} ")?;
         }
 
-        writeln!(self.writer, "0")?;
+        Ok(())
+    }
 
-        let id = self.next_clause_id;
-        self.next_clause_id = self
-            .next_clause_id
-            .checked_add(1)
-            .expect("we are not adding u64::MAX clauses (hopefully)");
+    fn write_binary_literals(
+        &mut self,
+        predicates: &[Predicate],
+        variable_names: &VariableNames,
+    ) -> std::io::Result<()> {
+        for &predicate in predicates {
+            let variable_code = variable_code(predicate.get_domain(), variable_names);
+            let is_negative = !is_positive_literal(predicate) as u64;
 
-        Ok(id)
+            write_binary_number(&mut self.writer, 2 * variable_code + is_negative)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl LratClauses {
+    fn insert(&mut self, id: NonZeroU64, key: Box<[i32]>) {
+        self.ids.entry(key.clone()).or_default().push(id);
+        let _ = self.clauses.insert(id, key);
+    }
+
+    /// Removes the most recently added clause with the given literals, and returns its id.
+    fn remove(&mut self, key: &[i32]) -> Option<NonZeroU64> {
+        let ids = self.ids.get_mut(key)?;
+        let id = ids.pop()?;
+        if ids.is_empty() {
+            let _ = self.ids.remove(key);
+        }
+
+        let _ = self.clauses.remove(&id);
+        Some(id)
+    }
+
+    /// Take the recorded hints, and order them such that the clause `key` is derived by unit
+    /// propagation over the hints in that order.
+    ///
+    /// Conflict analysis may record clauses which turn out not to be needed, for example when the
+    /// recursive minimiser fails to remove a predicate. Since LRAT checkers require every hint to
+    /// be unit, such clauses are left out.
+    ///
+    /// Returns `None` if unit propagation over the hints does not lead to a conflict.
+    fn derivation(&mut self, key: &[i32]) -> Option<Vec<NonZeroU64>> {
+        // The literals which are true under the negation of the derived clause.
+        let mut assigned = key.iter().map(|&literal| -literal).collect::<HashSet<_>>();
+
+        let mut candidates = self.hints.drain(..).rev().collect::<Vec<_>>();
+        let mut derivation = vec![];
+
+        loop {
+            let mut is_propagating = false;
+            let mut not_unit = vec![];
+
+            for id in candidates {
+                let Some(clause) = self.clauses.get(&id) else {
+                    continue;
+                };
+
+                // A satisfied clause cannot be used, this includes clauses which already
+                // propagated.
+                if clause.iter().any(|literal| assigned.contains(literal)) {
+                    continue;
+                }
+
+                let mut unassigned = clause
+                    .iter()
+                    .filter(|&&literal| !assigned.contains(&-literal));
+
+                match (unassigned.next(), unassigned.next()) {
+                    (None, _) => {
+                        derivation.push(id);
+                        return Some(derivation);
+                    }
+                    (Some(&literal), None) => {
+                        let _ = assigned.insert(literal);
+                        derivation.push(id);
+                        is_propagating = true;
+                    }
+                    _ => not_unit.push(id),
+                }
+            }
+
+            candidates = not_unit;
+
+            if !is_propagating {
+                break;
+            }
+        }
+
+        // The hints do not lead to a conflict.
+        None
     }
 }
+
+/// The literals of a clause as signed domain ids, sorted and without duplicates.
+fn clause_key(predicates: impl IntoIterator<Item = Predicate>) -> Box<[i32]> {
+    let mut literals = predicates
+        .into_iter()
+        .map(|predicate| {
+            let code = predicate.get_domain().id as i32 + 1;
+            if is_positive_literal(predicate) {
+                code
+            } else {
+                -code
+            }
+        })
+        .collect::<Vec<_>>();
+
+    literals.sort_unstable();
+    literals.dedup();
+    literals.into()
+}
+
+fn is_positive_literal(predicate: Predicate) -> bool {
+    assert!(
+        predicate.get_right_hand_side() <= 1,
+        "in dimacs proofs all variables are Boolean (aka 0-1)"
+    );
+    assert!(
+        predicate.get_right_hand_side() >= 0,
+        "in dimacs proofs all variables are Boolean (aka 0-1)"
+    );
+
+    match predicate {
+        Predicate::LowerBound { lower_bound: 1, .. }
+        | Predicate::Equal {
+            equality_constant: 1,
+            ..
+        }
+        | Predicate::NotEqual {
+            not_equal_constant: 0,
+            ..
+        } => true,
+
+        Predicate::UpperBound { upper_bound: 0, .. }
+        | Predicate::Equal {
+            equality_constant: 0,
+            ..
+        }
+        | Predicate::NotEqual {
+            not_equal_constant: 1,
+            ..
+        } => false,
+
+        other => panic!("Unexpected predicate {other:?} in clause for DIMACS"),
+    }
+}
+
+fn variable_code(domain: DomainId, variable_names: &VariableNames) -> u64 {
+    variable_names
+        .get_int_name(domain)
+        .expect("all variables are named in a DIMACS problem")
+        .parse()
+        .expect("variables in a DIMACS problem are named by their code")
+}
+
+fn next_id(id: NonZeroU64) -> NonZeroU64 {
+    id.checked_add(1)
+        .expect("we are not adding u64::MAX clauses (hopefully)")
+}
+
+/// Write a number in the variable-length encoding of binary DRAT, i.e. seven bits at a time with
+/// the highest bit set on all but the last byte.
+fn write_binary_number(writer: &mut impl Write, mut number: u64) -> std::io::Result<()> {
+    while number > 127 {
+        writer.write_all(&[(number & 127) as u8 | 128])?;
+        number >>= 7;
+    }
+
+    writer.write_all(&[number as u8])
+}
@@ -97,8 +97,9 @@ pub(crate) fn explain_root_assignment(
         &mut reason,
     );
 
-    assert!(!reason.is_empty());
-
+    // The reason may be empty, for example when the predicate is implied by a unit nogood which
+    // was recorded for a stronger predicate. In that case the nogood step is already added as a
+    // propagation.
     for p in reason {
         explain_root_assignment(context, p);
     }
@@ -4,6 +4,8 @@
 //! Proof logging for CP is supported in the DRCP format. This format explicitly supports usage
 //! where the solver logs a proof scaffold which later processed into a full proof after search
 //! has completed.
+//!
+//! Proof logging for SAT is supported in the DRAT and LRAT formats.
 mod dimacs;
 mod finalizer;
 mod proof_literals;
@@ -17,9 +19,13 @@ use std::path::PathBuf;
 use drcp_format::writer::ProofWriter;
 pub use drcp_format::Format;
 pub(crate) use finalizer::*;
+use log::warn;
 
 use self::dimacs::DimacsProof;
+use self::dimacs::DimacsProofError;
 use self::proof_literals::ProofLiterals;
+#[cfg(doc)]
+use crate::engine::propagation::Propagator;
 use crate::engine::variable_names::VariableNames;
 use crate::predicates::Predicate;
 use crate::variables::Literal;
@@ -64,17 +70,54 @@ impl ProofLog {
     }
 
     /// Create a dimacs proof logger.
-    pub fn dimacs(file_path: &Path) -> std::io::Result<ProofLog> {
+    ///
+    /// If `log_hints` is true, the proof is logged in the LRAT format, which annotates every
+    /// learned clause with the clauses it is derived from. Otherwise, a DRAT proof is logged.
+    pub fn dimacs(file_path: &Path, format: Format, log_hints: bool) -> std::io::Result<ProofLog> {
         let file = File::create(file_path)?;
         Ok(ProofLog {
-            internal_proof: Some(ProofImpl::DimacsProof(DimacsProof::new(file))),
+            internal_proof: Some(ProofImpl::DimacsProof(DimacsProof::new(
+                file, format, log_hints,
+            ))),
         })
     }
 
+    /// Set the number of clauses in the input formula of a DIMACS proof.
+    ///
+    /// In the LRAT format, the input clauses are identified by their position in the formula. This
+    /// ensures the clauses which are derived while the formula is added to the solver are
+    /// numbered after the input clauses.
+    pub fn set_num_input_clauses(&mut self, num_clauses: u64) {
+        if let Some(ProofImpl::DimacsProof(writer)) = self.internal_proof.as_mut() {
+            writer.set_num_input_clauses(num_clauses);
+        }
+    }
+
+    /// Record a clause of the input formula.
+    ///
+    /// If the proof refers to clauses by their id, the id of the input clause is returned.
+    pub(crate) fn log_input_clause(
+        &mut self,
+        literals: impl IntoIterator<Item = Predicate>,
+    ) -> Option<NonZeroU64> {
+        let Some(ProofImpl::DimacsProof(writer)) = self.internal_proof.as_mut() else {
+            return None;
+        };
+
+        writer.input_clause(literals)
+    }
+
     /// Log an inference to the proof.
+    ///
+    /// The `inference_label` identifies the filtering algorithm which made the inference (see
+    /// [`Propagator::inference_label`]).
+    ///
+    /// In an LRAT proof, the clause which made the inference is recorded as a hint for the next
+    /// learned clause instead.
     pub(crate) fn log_inference(
         &mut self,
         constraint_tag: Option<NonZero<u32>>,
+        inference_label: &str,
         premises: impl IntoIterator<Item = Predicate>,
         propagated: Option<Predicate>,
     ) -> std::io::Result<NonZeroU64> {
@@ -85,11 +128,15 @@ impl ProofLog {
             ..
         }) = self.internal_proof.as_mut()
         else {
+            if let Some(ProofImpl::DimacsProof(writer)) = self.internal_proof.as_mut() {
+                writer.inference(premises, propagated);
+            }
+
             return Ok(DUMMY_STEP_ID);
         };
 
-        // TODO: Log the inference label.
-        let id = writer.log_inference(constraint_tag, None, premises, propagated)?;
+        let id =
+            writer.log_inference(constraint_tag, Some(inference_label), premises, propagated)?;
 
         if let Some(hints) = propagation_order_hint {
             hints.push(id);
@@ -103,15 +150,14 @@ impl ProofLog {
     /// Inferences are automatically added as a propagation hint when they are logged, this is
     /// therefore only necessary when nogoods are used in a propagation.
     pub(crate) fn add_propagation(&mut self, step_id: NonZeroU64) {
-        let Some(ProofImpl::CpProof {
-            propagation_order_hint: Some(ref mut hints),
-            ..
-        }) = self.internal_proof.as_mut()
-        else {
-            return;
-        };
-
-        hints.push(step_id);
+        match self.internal_proof.as_mut() {
+            Some(ProofImpl::CpProof {
+                propagation_order_hint: Some(ref mut hints),
+                ..
+            }) => hints.push(step_id),
+            Some(ProofImpl::DimacsProof(writer)) => writer.add_hint(step_id),
+            _ => {}
+        }
     }
 
     /// Log a learned clause to the proof.
@@ -142,12 +188,36 @@ impl ProofLog {
                 Ok(id)
             }
 
-            Some(ProofImpl::DimacsProof(writer)) => writer.learned_clause(literals, variable_names),
+            Some(ProofImpl::DimacsProof(writer)) => {
+                match writer.learned_clause(literals, variable_names) {
+                    Ok(id) => Ok(id),
+                    Err(DimacsProofError::Io(error)) => Err(error),
+                    Err(DimacsProofError::InvalidHints) => {
+                        warn_incomplete_lrat_proof();
+                        Ok(DUMMY_STEP_ID)
+                    }
+                }
+            }
 
             None => Ok(DUMMY_STEP_ID),
         }
     }
 
+    /// Log the deletion of a learned clause.
+    ///
+    /// Deletions are only logged to DIMACS proofs, since the solver does not keep track of the
+    /// steps which introduced its nogoods in a CP proof.
+    pub(crate) fn log_deleted_clause(
+        &mut self,
+        literals: impl IntoIterator<Item = Predicate>,
+        variable_names: &VariableNames,
+    ) -> std::io::Result<()> {
+        match &mut self.internal_proof {
+            Some(ProofImpl::DimacsProof(writer)) => writer.deleted_clause(literals, variable_names),
+            _ => Ok(()),
+        }
+    }
+
     pub(crate) fn unsat(self, variable_names: &VariableNames) -> std::io::Result<()> {
         match self.internal_proof {
             Some(ProofImpl::CpProof {
@@ -159,9 +229,14 @@ impl ProofLog {
                 let file = File::create(definitions_path)?;
                 literals.write(file, variable_names)
             }
-            Some(ProofImpl::DimacsProof(mut writer)) => writer
-                .learned_clause(std::iter::empty(), variable_names)
-                .map(|_| ()),
+            Some(ProofImpl::DimacsProof(writer)) => match writer.unsat(variable_names) {
+                Ok(()) => Ok(()),
+                Err(DimacsProofError::Io(error)) => Err(error),
+                Err(DimacsProofError::InvalidHints) => {
+                    warn_incomplete_lrat_proof();
+                    Ok(())
+                }
+            },
             None => Ok(()),
         }
     }
@@ -190,14 +265,25 @@ impl ProofLog {
         }
     }
 
+    /// Whether a proof is being logged.
+    pub(crate) fn is_active(&self) -> bool {
+        self.internal_proof.is_some()
+    }
+
+    /// Whether the deletion of learned clauses is logged, which is only the case for DIMACS
+    /// proofs.
+    pub(crate) fn is_logging_deletions(&self) -> bool {
+        matches!(self.internal_proof, Some(ProofImpl::DimacsProof(_)))
+    }
+
+    /// Whether the propagations used to derive nogoods are logged, either as inferences in a CP
+    /// proof or as hints in an LRAT proof.
     pub(crate) fn is_logging_inferences(&self) -> bool {
-        matches!(
-            self.internal_proof,
-            Some(ProofImpl::CpProof {
-                log_inferences: true,
-                ..
-            })
-        )
+        match &self.internal_proof {
+            Some(ProofImpl::CpProof { log_inferences, .. }) => *log_inferences,
+            Some(ProofImpl::DimacsProof(writer)) => writer.is_logging_hints(),
+            None => false,
+        }
     }
 
     pub(crate) fn reify_predicate(&mut self, literal: Literal, predicate: Predicate) {
@@ -221,3 +307,9 @@ enum ProofImpl {
     },
     DimacsProof(DimacsProof<File>),
 }
+
+/// Report that the hints of a learned clause could not be determined. The LRAT proof is cut off
+/// before that clause, so it will not contain the empty clause.
+fn warn_incomplete_lrat_proof() {
+    warn!("The LRAT proof is incomplete, as the hints of a learned clause do not derive it");
+}
@@ -110,7 +110,7 @@ fn predicate_to_atomic(
                 .get_int_name(domain_id)
                 .expect("integer domain is unnamed"),
             comparison: Comparison::LessThanEqual,
-            value: upper_bound.into(),
+            value: upper_bound,
         }),
         Predicate::Equal {
             domain_id,
@@ -120,7 +120,7 @@ fn predicate_to_atomic(
                 .get_int_name(domain_id)
                 .expect("integer domain is unnamed"),
             comparison: Comparison::Equal,
-            value: equality_constant.into(),
+            value: equality_constant,
         }),
 
         Predicate::NotEqual { .. } | Predicate::LowerBound { .. } => {
@@ -0,0 +1,605 @@
+use crate::basic_types::Inconsistency;
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// Bounds-consistent propagator for the [AllDifferent](https://sofdem.github.io/gccat/gccat/Calldifferent.html)
+/// constraint which detects Hall intervals using the algorithm of \[1\].
+///
+/// A Hall interval is an interval `[a, b]` for which there are `b - a + 1` variables with their
+/// domains contained in `[a, b]`; the bounds of the other variables are then adjusted such that
+/// they do not fall inside of this interval anymore. The propagation is explained by the bounds
+/// of the variables in the Hall interval (i.e. `[x >= a]` and `[x <= b]`) and the bound of the
+/// propagated variable which lies inside of the interval.
+///
+/// Additionally, the value of a fixed variable is removed from the domains of the other
+/// variables (explained by `[x == v]`), such that the propagator is at least as strong as the
+/// decomposition into binary not-equals constraints.
+///
+/// \[1\] A. López-Ortiz, C.-G. Quimper, J. Tromp, and P. Van Beek, ‘A fast and simple algorithm
+/// for bounds consistency of the alldifferent constraint’, in IJCAI, 2003, pp. 245–250.
+#[derive(Clone, Debug)]
+pub(crate) struct AllDifferentBoundsPropagator<Var> {
+    variables: Box<[Var]>,
+}
+
+impl<Var: IntegerVariable + 'static> AllDifferentBoundsPropagator<Var> {
+    pub(crate) fn new(variables: Box<[Var]>) -> Self {
+        AllDifferentBoundsPropagator { variables }
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for AllDifferentBoundsPropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.variables
+            .iter()
+            .cloned()
+            .enumerate()
+            .for_each(|(index, variable)| {
+                let _ =
+                    context.register(variable, DomainEvents::BOUNDS, LocalId::from(index as u32));
+            });
+
+        Ok(())
+    }
+
+    fn priority(&self) -> u32 {
+        2
+    }
+
+    fn name(&self) -> &str {
+        "AllDifferentBounds"
+    }
+
+    fn inference_label(&self) -> &str {
+        "all_different_bounds"
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        remove_fixed_values(&mut context, &self.variables)?;
+
+        let bounds = self
+            .variables
+            .iter()
+            .map(|variable| (context.lower_bound(variable), context.upper_bound(variable)))
+            .collect::<Vec<_>>();
+        if bounds.is_empty() {
+            return Ok(());
+        }
+
+        // Both passes reason over the bounds at the start of the propagation; the explanations
+        // therefore remain valid when the bounds are tightened by the other pass.
+        let sweep = HallIntervalSweep::new(&bounds);
+
+        let new_lower_bounds = sweep
+            .filter_lower_bounds()
+            .map_err(|end| explain_overload_ending_at(&self.variables, &bounds, end))?;
+        let new_upper_bounds = sweep
+            .filter_upper_bounds()
+            .map_err(|start| explain_overload_starting_at(&self.variables, &bounds, start))?;
+
+        for (index, variable) in self.variables.iter().enumerate() {
+            if let Some(new_lower_bound) = new_lower_bounds[index] {
+                let end = new_lower_bound - 1;
+                let reason = explain_hall_interval_ending_at(&self.variables, &bounds, index, end);
+                context.set_lower_bound(variable, new_lower_bound, reason)?;
+            }
+
+            if let Some(new_upper_bound) = new_upper_bounds[index] {
+                let start = new_upper_bound + 1;
+                let reason =
+                    explain_hall_interval_starting_at(&self.variables, &bounds, index, start);
+                context.set_upper_bound(variable, new_upper_bound, reason)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Removes the values of the fixed variables from the domains of the other variables.
+fn remove_fixed_values<Var: IntegerVariable + 'static>(
+    context: &mut PropagationContextMut,
+    variables: &[Var],
+) -> PropagationStatusCP {
+    for (index, fixed_variable) in variables.iter().enumerate() {
+        if !context.is_fixed(fixed_variable) {
+            continue;
+        }
+
+        let value = context.lower_bound(fixed_variable);
+        for (other_index, variable) in variables.iter().enumerate() {
+            if other_index != index {
+                context.remove(variable, value, conjunction!([fixed_variable == value]))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The sweep of \[1\] which computes the bounds-consistent bounds of the variables in a single
+/// pass over the variables sorted on their upper-bounds (for the lower-bounds) and a single pass
+/// over the variables sorted on their lower-bounds (for the upper-bounds).
+///
+/// The (unique) bounds of the variables are stored in `bounds`, where the upper-bounds are
+/// incremented by one such that every variable `x` covers the half-open range `[bounds[min_rank[x]],
+/// bounds[max_rank[x]])`. The first and last entry are sentinels. Hall intervals and the remaining
+/// capacity of the ranges are maintained using union-find like structures with path compression.
+///
+/// The values are stored as `i128`s since incrementing the upper-bounds (and adding the sentinels)
+/// can overflow an `i64`.
+struct HallIntervalSweep {
+    bounds: Vec<i128>,
+    sorted_on_lower_bound: Vec<usize>,
+    sorted_on_upper_bound: Vec<usize>,
+    min_rank: Vec<usize>,
+    max_rank: Vec<usize>,
+}
+
+impl HallIntervalSweep {
+    fn new(variable_bounds: &[(i64, i64)]) -> Self {
+        let number_of_variables = variable_bounds.len();
+
+        let mut sorted_on_lower_bound = (0..number_of_variables).collect::<Vec<_>>();
+        sorted_on_lower_bound.sort_by_key(|&index| variable_bounds[index].0);
+        let mut sorted_on_upper_bound = (0..number_of_variables).collect::<Vec<_>>();
+        sorted_on_upper_bound.sort_by_key(|&index| variable_bounds[index].1);
+
+        let lower_bound =
+            |position: usize| variable_bounds[sorted_on_lower_bound[position]].0 as i128;
+        let upper_bound =
+            |position: usize| variable_bounds[sorted_on_upper_bound[position]].1 as i128 + 1;
+
+        let mut min_rank = vec![0; number_of_variables];
+        let mut max_rank = vec![0; number_of_variables];
+
+        // We merge the sorted lower-bounds and (incremented) upper-bounds into the unique bounds
+        let mut bounds = vec![lower_bound(0) - 2];
+        let mut min_position = 0;
+        let mut max_position = 0;
+        loop {
+            if min_position < number_of_variables
+                && lower_bound(min_position) <= upper_bound(max_position)
+            {
+                let value = lower_bound(min_position);
+                if value != bounds[bounds.len() - 1] {
+                    bounds.push(value);
+                }
+                min_rank[sorted_on_lower_bound[min_position]] = bounds.len() - 1;
+                min_position += 1;
+            } else {
+                let value = upper_bound(max_position);
+                if value != bounds[bounds.len() - 1] {
+                    bounds.push(value);
+                }
+                max_rank[sorted_on_upper_bound[max_position]] = bounds.len() - 1;
+                max_position += 1;
+                if max_position == number_of_variables {
+                    break;
+                }
+            }
+        }
+        bounds.push(bounds[bounds.len() - 1] + 2);
+
+        HallIntervalSweep {
+            bounds,
+            sorted_on_lower_bound,
+            sorted_on_upper_bound,
+            min_rank,
+            max_rank,
+        }
+    }
+
+    /// The index of the last (non-sentinel) bound.
+    fn last_bound(&self) -> usize {
+        self.bounds.len() - 2
+    }
+
+    /// Computes the new lower-bound of every variable (or [`None`] if it does not change).
+    ///
+    /// Returns the upper-bound `b` of an interval `[a, b]` which contains the domains of more
+    /// than `b - a + 1` variables if there is no solution.
+    fn filter_lower_bounds(&self) -> Result<Vec<Option<i64>>, i64> {
+        let last_bound = self.last_bound();
+        let bounds = &self.bounds;
+
+        let mut tree = (0..=last_bound + 1)
+            .map(|index| index.saturating_sub(1))
+            .collect::<Vec<_>>();
+        let mut hall = tree.clone();
+        let mut capacity = (0..=last_bound + 1)
+            .map(|index| {
+                if index == 0 {
+                    0
+                } else {
+                    bounds[index] - bounds[index - 1]
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut new_lower_bounds = vec![None; self.min_rank.len()];
+        for &variable in self.sorted_on_upper_bound.iter() {
+            let x = self.min_rank[variable];
+            let y = self.max_rank[variable];
+
+            let mut z = path_max(&tree, x + 1);
+            let j = tree[z];
+            capacity[z] -= 1;
+            if capacity[z] == 0 {
+                tree[z] = z + 1;
+                z = path_max(&tree, z + 1);
+                tree[z] = j;
+            }
+            path_set(&mut tree, x + 1, z, z);
+
+            if capacity[z] < bounds[z] - bounds[y] {
+                return Err((bounds[y] - 1) as i64);
+            }
+
+            if hall[x] > x {
+                let w = path_max(&hall, hall[x]);
+                new_lower_bounds[variable] = Some(bounds[w] as i64);
+                path_set(&mut hall, x, w, w);
+            }
+
+            if capacity[z] == bounds[z] - bounds[y] {
+                let hall_start = hall[y];
+                path_set(&mut hall, hall_start, j - 1, y);
+                hall[y] = j - 1;
+            }
+        }
+
+        Ok(new_lower_bounds)
+    }
+
+    /// Computes the new upper-bound of every variable (or [`None`] if it does not change).
+    ///
+    /// Returns the lower-bound `a` of an interval `[a, b]` which contains the domains of more
+    /// than `b - a + 1` variables if there is no solution.
+    fn filter_upper_bounds(&self) -> Result<Vec<Option<i64>>, i64> {
+        let last_bound = self.last_bound();
+        let bounds = &self.bounds;
+
+        let mut tree = (0..=last_bound + 1)
+            .map(|index| (index + 1).min(last_bound + 1))
+            .collect::<Vec<_>>();
+        let mut hall = tree.clone();
+        let mut capacity = (0..=last_bound + 1)
+            .map(|index| {
+                if index > last_bound {
+                    0
+                } else {
+                    bounds[index + 1] - bounds[index]
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut new_upper_bounds = vec![None; self.max_rank.len()];
+        for &variable in self.sorted_on_lower_bound.iter().rev() {
+            let x = self.max_rank[variable];
+            let y = self.min_rank[variable];
+
+            let mut z = path_min(&tree, x - 1);
+            let j = tree[z];
+            capacity[z] -= 1;
+            if capacity[z] == 0 {
+                tree[z] = z - 1;
+                z = path_min(&tree, z - 1);
+                tree[z] = j;
+            }
+            path_set(&mut tree, x - 1, z, z);
+
+            if capacity[z] < bounds[y] - bounds[z] {
+                return Err(bounds[y] as i64);
+            }
+
+            if hall[x] < x {
+                let w = path_min(&hall, hall[x]);
+                new_upper_bounds[variable] = Some((bounds[w] - 1) as i64);
+                path_set(&mut hall, x, w, w);
+            }
+
+            if capacity[z] == bounds[y] - bounds[z] {
+                let hall_start = hall[y];
+                path_set(&mut hall, hall_start, j + 1, y);
+                hall[y] = j + 1;
+            }
+        }
+
+        Ok(new_upper_bounds)
+    }
+}
+
+/// Follows the pointers in `tree` from `index` for as long as they point to a larger index.
+fn path_max(tree: &[usize], mut index: usize) -> usize {
+    while tree[index] > index {
+        index = tree[index];
+    }
+    index
+}
+
+/// Follows the pointers in `tree` from `index` for as long as they point to a smaller index.
+fn path_min(tree: &[usize], mut index: usize) -> usize {
+    while tree[index] < index {
+        index = tree[index];
+    }
+    index
+}
+
+/// Sets all of the pointers on the path from `start` to `end` to `to` (i.e. path compression).
+fn path_set(tree: &mut [usize], start: usize, end: usize, to: usize) {
+    let mut current = start;
+    while current != end {
+        let next = tree[current];
+        tree[current] = to;
+        current = next;
+    }
+}
+
+/// The explanation for the variables in `contained_variables` having their domains contained in
+/// `[start, end]`.
+fn explain_contained<Var: IntegerVariable>(
+    variables: &[Var],
+    contained_variables: &[usize],
+    start: i64,
+    end: i64,
+) -> PropositionalConjunction {
+    contained_variables
+        .iter()
+        .flat_map(|&index| {
+            [
+                predicate!(variables[index] >= start),
+                predicate!(variables[index] <= end),
+            ]
+        })
+        .collect()
+}
+
+/// Returns the variables with their domains contained in an interval `[a, end]` for which there
+/// are at least `end - a + 1` of these variables (and `a <= max_start`), where `a` is as large as
+/// possible.
+fn find_interval_ending_at(
+    bounds: &[(i64, i64)],
+    end: i64,
+    max_start: i64,
+    is_overloaded: bool,
+) -> Option<(i64, Vec<usize>)> {
+    let mut contained_variables = (0..bounds.len())
+        .filter(|&index| bounds[index].1 <= end)
+        .collect::<Vec<_>>();
+    contained_variables.sort_by_key(|&index| std::cmp::Reverse(bounds[index].0));
+
+    let mut position = 0;
+    while position < contained_variables.len() {
+        let start = bounds[contained_variables[position]].0;
+        while position < contained_variables.len()
+            && bounds[contained_variables[position]].0 == start
+        {
+            position += 1;
+        }
+
+        let interval_size = end as i128 - start as i128 + 1;
+        let is_hall_interval = if is_overloaded {
+            position as i128 > interval_size
+        } else {
+            position as i128 >= interval_size
+        };
+        if start <= max_start && is_hall_interval {
+            contained_variables.truncate(position);
+            return Some((start, contained_variables));
+        }
+    }
+
+    None
+}
+
+/// The mirrored version of [`find_interval_ending_at`]; returns the variables with their domains
+/// contained in an interval `[start, b]` (with `b >= min_end`), where `b` is as small as possible.
+fn find_interval_starting_at(
+    bounds: &[(i64, i64)],
+    start: i64,
+    min_end: i64,
+    is_overloaded: bool,
+) -> Option<(i64, Vec<usize>)> {
+    let mirrored_bounds = bounds
+        .iter()
+        .map(|&(lower_bound, upper_bound)| (-upper_bound, -lower_bound))
+        .collect::<Vec<_>>();
+
+    find_interval_ending_at(&mirrored_bounds, -start, -min_end, is_overloaded)
+        .map(|(mirrored_end, contained_variables)| (-mirrored_end, contained_variables))
+}
+
+/// Explains why the lower-bound of the variable at `index` is increased to `end + 1`; i.e. there
+/// is a Hall interval `[a, end]` which contains its lower-bound.
+fn explain_hall_interval_ending_at<Var: IntegerVariable>(
+    variables: &[Var],
+    bounds: &[(i64, i64)],
+    index: usize,
+    end: i64,
+) -> PropositionalConjunction {
+    match find_interval_ending_at(bounds, end, bounds[index].0, false) {
+        Some((start, contained_variables)) => {
+            let mut reason = explain_contained(variables, &contained_variables, start, end);
+            reason.add(predicate!(variables[index] >= start));
+            reason
+        }
+        // The sweep only propagates based on Hall intervals, so this cannot happen; the bounds of
+        // all variables are nonetheless a valid explanation.
+        None => explain_all_bounds(variables, bounds),
+    }
+}
+
+/// Explains why the upper-bound of the variable at `index` is decreased to `start - 1`; i.e.
+/// there is a Hall interval `[start, b]` which contains its upper-bound.
+fn explain_hall_interval_starting_at<Var: IntegerVariable>(
+    variables: &[Var],
+    bounds: &[(i64, i64)],
+    index: usize,
+    start: i64,
+) -> PropositionalConjunction {
+    match find_interval_starting_at(bounds, start, bounds[index].1, false) {
+        Some((end, contained_variables)) => {
+            let mut reason = explain_contained(variables, &contained_variables, start, end);
+            reason.add(predicate!(variables[index] <= end));
+            reason
+        }
+        None => explain_all_bounds(variables, bounds),
+    }
+}
+
+/// Explains a conflict detected by the sweep over the variables sorted on their upper-bounds.
+fn explain_overload_ending_at<Var: IntegerVariable>(
+    variables: &[Var],
+    bounds: &[(i64, i64)],
+    end: i64,
+) -> Inconsistency {
+    match find_interval_ending_at(bounds, end, i64::MAX, true) {
+        Some((start, contained_variables)) => {
+            explain_contained(variables, &contained_variables, start, end).into()
+        }
+        None => explain_all_bounds(variables, bounds).into(),
+    }
+}
+
+/// Explains a conflict detected by the sweep over the variables sorted on their lower-bounds.
+fn explain_overload_starting_at<Var: IntegerVariable>(
+    variables: &[Var],
+    bounds: &[(i64, i64)],
+    start: i64,
+) -> Inconsistency {
+    match find_interval_starting_at(bounds, start, i64::MIN, true) {
+        Some((end, contained_variables)) => {
+            explain_contained(variables, &contained_variables, start, end).into()
+        }
+        None => explain_all_bounds(variables, bounds).into(),
+    }
+}
+
+fn explain_all_bounds<Var: IntegerVariable>(
+    variables: &[Var],
+    bounds: &[(i64, i64)],
+) -> PropositionalConjunction {
+    variables
+        .iter()
+        .zip(bounds)
+        .flat_map(|(variable, &(lower_bound, upper_bound))| {
+            [
+                predicate!(variable >= lower_bound),
+                predicate!(variable <= upper_bound),
+            ]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::test_solver::TestSolver;
+
+    #[test]
+    fn hall_interval_propagates_lower_bound() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 2);
+        let y = solver.new_variable(1, 2);
+        let z = solver.new_variable(1, 4);
+
+        let _ = solver
+            .new_propagator(AllDifferentBoundsPropagator::new([x, y, z].into()))
+            .expect("No conflict");
+
+        solver.assert_bounds(z, 3, 4);
+
+        let reason = solver.get_reason_int(predicate!(z >= 3));
+        assert_eq!(
+            conjunction!([x >= 1] & [x <= 2] & [y >= 1] & [y <= 2] & [z >= 1]),
+            reason
+        );
+    }
+
+    #[test]
+    fn hall_interval_propagates_upper_bound() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(3, 4);
+        let y = solver.new_variable(3, 4);
+        let z = solver.new_variable(1, 4);
+
+        let _ = solver
+            .new_propagator(AllDifferentBoundsPropagator::new([x, y, z].into()))
+            .expect("No conflict");
+
+        solver.assert_bounds(z, 1, 2);
+
+        let reason = solver.get_reason_int(predicate!(z <= 2));
+        assert_eq!(
+            conjunction!([x >= 3] & [x <= 4] & [y >= 3] & [y <= 4] & [z <= 4]),
+            reason
+        );
+    }
+
+    #[test]
+    fn propagation_creates_new_hall_interval() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 2);
+        let y = solver.new_variable(1, 2);
+        let z = solver.new_variable(2, 3);
+        let w = solver.new_variable(3, 5);
+
+        let _ = solver
+            .new_propagator(AllDifferentBoundsPropagator::new([x, y, z, w].into()))
+            .expect("No conflict");
+
+        solver.assert_bounds(z, 3, 3);
+        solver.assert_bounds(w, 4, 5);
+    }
+
+    #[test]
+    fn pigeonhole_is_detected() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 2);
+        let y = solver.new_variable(1, 2);
+        let z = solver.new_variable(1, 2);
+
+        let result = solver.new_propagator(AllDifferentBoundsPropagator::new([x, y, z].into()));
+
+        assert!(matches!(
+            result,
+            Err(Inconsistency::Conflict(explanation))
+                if explanation == conjunction!(
+                    [x >= 1] & [x <= 2] & [y >= 1] & [y <= 2] & [z >= 1] & [z <= 2]
+                )
+        ));
+    }
+
+    #[test]
+    fn value_of_fixed_variable_is_removed_from_other_domains() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(3, 3);
+        let y = solver.new_variable(1, 5);
+
+        let _ = solver
+            .new_propagator(AllDifferentBoundsPropagator::new([x, y].into()))
+            .expect("No conflict");
+
+        assert!(!solver.contains(y, 3));
+        solver.assert_bounds(y, 1, 5);
+
+        let reason = solver.get_reason_int(predicate!(y != 3));
+        assert_eq!(conjunction!([x == 3]), reason);
+    }
+}
@@ -0,0 +1,539 @@
+use crate::basic_types::HashMap;
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// Domain-consistent propagator for the [AllDifferent](https://sofdem.github.io/gccat/gccat/Calldifferent.html)
+/// constraint based on the matching-based algorithm of Régin \[1\].
+///
+/// The propagator finds a maximum matching between the variables and the values in their
+/// domains; if not every variable can be matched then a conflict is reported. Otherwise, a value
+/// `v` is removed from the domain of a variable `x` if the edge `(x, v)` cannot be part of any
+/// maximum matching.
+///
+/// The explanations are based on Hall sets \[2\]; if `v` is removed from the domain of `x` then
+/// there is a set of variables `H` (not containing `x`) which uses all values of the union of
+/// their domains `V_H`, where `v` is contained in `V_H`. The explanation then consists of the
+/// predicates which ensure that the domains of the variables in `H` are contained in `V_H`.
+///
+/// The matching of the previous propagation is stored and used as the starting point for finding
+/// a new maximum matching.
+///
+/// \[1\] J.-C. Régin, ‘A filtering algorithm for constraints of difference in CSPs’, in AAAI,
+/// 1994, pp. 362–367.
+///
+/// \[2\] N. Downing, T. Feydy, and P. J. Stuckey, ‘Explaining alldifferent’, in Proceedings of the
+/// Thirty-fifth Australasian Computer Science Conference, 2012, pp. 115–124.
+#[derive(Clone, Debug)]
+pub(crate) struct AllDifferentDomainPropagator<Var> {
+    variables: Box<[Var]>,
+    /// The value to which each variable was matched during the previous propagation.
+    previous_matching: Vec<Option<i64>>,
+}
+
+impl<Var: IntegerVariable + 'static> AllDifferentDomainPropagator<Var> {
+    pub(crate) fn new(variables: Box<[Var]>) -> Self {
+        let previous_matching = vec![None; variables.len()];
+        AllDifferentDomainPropagator {
+            variables,
+            previous_matching,
+        }
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for AllDifferentDomainPropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.variables
+            .iter()
+            .cloned()
+            .enumerate()
+            .for_each(|(index, variable)| {
+                let _ =
+                    context.register(variable, DomainEvents::ANY_INT, LocalId::from(index as u32));
+            });
+
+        Ok(())
+    }
+
+    fn priority(&self) -> u32 {
+        3
+    }
+
+    fn name(&self) -> &str {
+        "AllDifferentDomain"
+    }
+
+    fn inference_label(&self) -> &str {
+        "all_different_domain"
+    }
+
+    fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        propagate_domain_consistency(&mut context, &self.variables, &mut self.previous_matching)
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        propagate_domain_consistency(
+            &mut context,
+            &self.variables,
+            &mut vec![None; self.variables.len()],
+        )
+    }
+}
+
+/// The bipartite value graph of the variables and the values in their domains.
+///
+/// The variables are indexed by their position and the values are indexed by the order in which
+/// they were encountered.
+struct ValueGraph {
+    /// For every variable, the indices of the values in its domain.
+    domains: Vec<Vec<usize>>,
+    /// The value corresponding to every value index.
+    values: Vec<i64>,
+    /// The value index to which each variable is matched.
+    variable_to_value: Vec<Option<usize>>,
+    /// The variable to which each value index is matched.
+    value_to_variable: Vec<Option<usize>>,
+}
+
+impl ValueGraph {
+    fn new<Var: IntegerVariable + 'static>(
+        context: &PropagationContextMut,
+        variables: &[Var],
+    ) -> ValueGraph {
+        let mut value_indices: HashMap<i64, usize> = HashMap::default();
+        let mut values = Vec::new();
+        let domains = variables
+            .iter()
+            .map(|variable| {
+                context
+                    .iterate_domain(variable)
+                    .map(|value| {
+                        *value_indices.entry(value).or_insert_with(|| {
+                            values.push(value);
+                            values.len() - 1
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        ValueGraph {
+            variable_to_value: vec![None; domains.len()],
+            value_to_variable: vec![None; values.len()],
+            domains,
+            values,
+        }
+    }
+
+    fn match_pair(&mut self, variable: usize, value: usize) {
+        self.variable_to_value[variable] = Some(value);
+        self.value_to_variable[value] = Some(variable);
+    }
+
+    /// Attempts to find an augmenting path starting from the unmatched `variable`; the visited
+    /// variables are marked in `visited`.
+    ///
+    /// Returns whether an augmenting path was found (in which case the matching is updated).
+    fn augment(&mut self, variable: usize, visited: &mut [bool]) -> bool {
+        // The stack contains the variables on the current path and the position of the next value
+        // in their domain which should be considered
+        let mut stack = vec![(variable, 0)];
+        visited[variable] = true;
+
+        while let Some(&mut (current, ref mut position)) = stack.last_mut() {
+            if *position == self.domains[current].len() {
+                let _ = stack.pop();
+                continue;
+            }
+            let value = self.domains[current][*position];
+            *position += 1;
+
+            match self.value_to_variable[value] {
+                None => {
+                    // We have found a free value; we flip the matching along the path, where
+                    // every variable on the path takes the value which was last considered
+                    for &(path_variable, path_position) in stack.iter() {
+                        self.match_pair(
+                            path_variable,
+                            self.domains[path_variable][path_position - 1],
+                        );
+                    }
+                    return true;
+                }
+                Some(matched_variable) if !visited[matched_variable] => {
+                    visited[matched_variable] = true;
+                    stack.push((matched_variable, 0));
+                }
+                Some(_) => {}
+            }
+        }
+
+        false
+    }
+
+    /// Creates the predicates which ensure that the domains of the provided `variables` are
+    /// contained in the set of values `hall_values` (given as value indices).
+    ///
+    /// The bounds of every variable are lifted as long as the values remain in `hall_values`, and
+    /// the values within these bounds which are not in `hall_values` are excluded explicitly.
+    fn create_hall_set_explanation<Var: IntegerVariable + 'static>(
+        &self,
+        context: &PropagationContextMut,
+        variables: &[Var],
+        hall_variables: impl Iterator<Item = usize>,
+        hall_values: &[bool],
+    ) -> PropositionalConjunction {
+        let mut hall_values_set = self
+            .values
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| hall_values[index])
+            .map(|(_, &value)| value)
+            .collect::<Vec<_>>();
+        hall_values_set.sort();
+        let contains = |value: i64| hall_values_set.binary_search(&value).is_ok();
+
+        let mut explanation = PropositionalConjunction::default();
+        for index in hall_variables {
+            let variable = &variables[index];
+
+            let mut lower_bound = context.lower_bound(variable);
+            while contains(lower_bound - 1) {
+                lower_bound -= 1;
+            }
+            let mut upper_bound = context.upper_bound(variable);
+            while contains(upper_bound + 1) {
+                upper_bound += 1;
+            }
+
+            explanation.add(predicate!(variable >= lower_bound));
+            explanation.add(predicate!(variable <= upper_bound));
+            for value in lower_bound..=upper_bound {
+                if !contains(value) {
+                    explanation.add(predicate!(variable != value));
+                }
+            }
+        }
+
+        explanation
+    }
+}
+
+fn propagate_domain_consistency<Var: IntegerVariable + 'static>(
+    context: &mut PropagationContextMut,
+    variables: &[Var],
+    previous_matching: &mut [Option<i64>],
+) -> PropagationStatusCP {
+    let mut graph = ValueGraph::new(context, variables);
+
+    // We first restore the previous matching as far as it is still valid
+    for (variable, previous_value) in previous_matching.iter().enumerate() {
+        if let Some(value) = previous_value {
+            if let Some(&value_index) = graph.domains[variable]
+                .iter()
+                .find(|&&value_index| graph.values[value_index] == *value)
+            {
+                if graph.value_to_variable[value_index].is_none() {
+                    graph.match_pair(variable, value_index);
+                }
+            }
+        }
+    }
+
+    // Then we extend it to a maximum matching
+    for variable in 0..variables.len() {
+        if graph.variable_to_value[variable].is_some() {
+            continue;
+        }
+
+        let mut visited = vec![false; variables.len()];
+        if !graph.augment(variable, &mut visited) {
+            // The visited variables form a set of variables whose domains contain fewer values
+            // than there are variables
+            let mut hall_values = vec![false; graph.values.len()];
+            for (index, _) in visited.iter().enumerate().filter(|(_, &visited)| visited) {
+                for &value in graph.domains[index].iter() {
+                    hall_values[value] = true;
+                }
+            }
+
+            return Err(graph
+                .create_hall_set_explanation(
+                    context,
+                    variables,
+                    (0..variables.len()).filter(|&index| visited[index]),
+                    &hall_values,
+                )
+                .into());
+        }
+    }
+
+    for (variable, previous_value) in previous_matching.iter_mut().enumerate() {
+        *previous_value = graph.variable_to_value[variable].map(|value| graph.values[value]);
+    }
+
+    // We create the residual graph where the variables are the nodes `0..n` and the values are
+    // the nodes `n..n+m`; an edge from variable `x` to value `v` indicates that `x` can take
+    // value `v` but is not matched to it, while an edge from value `v` to variable `x` indicates
+    // that `x` is matched to `v`
+    let num_variables = variables.len();
+    let num_nodes = num_variables + graph.values.len();
+    let mut successors = vec![Vec::new(); num_nodes];
+    let mut predecessors = vec![Vec::new(); num_nodes];
+    for (variable, domain) in graph.domains.iter().enumerate() {
+        for &value in domain.iter() {
+            let (from, to) = if graph.variable_to_value[variable] == Some(value) {
+                (num_variables + value, variable)
+            } else {
+                (variable, num_variables + value)
+            };
+            successors[from].push(to);
+            predecessors[to].push(from);
+        }
+    }
+
+    // An edge which is not in the matching can be part of a maximum matching if it is part of an
+    // alternating path ending in a free value, or if it is part of an alternating cycle
+    let mut reaches_free_value = vec![false; num_nodes];
+    let mut stack = (0..graph.values.len())
+        .filter(|&value| graph.value_to_variable[value].is_none())
+        .map(|value| num_variables + value)
+        .collect::<Vec<_>>();
+    stack
+        .iter()
+        .for_each(|&node| reaches_free_value[node] = true);
+    while let Some(node) = stack.pop() {
+        for &predecessor in predecessors[node].iter() {
+            if !reaches_free_value[predecessor] {
+                reaches_free_value[predecessor] = true;
+                stack.push(predecessor);
+            }
+        }
+    }
+    let components = strongly_connected_components(&successors);
+
+    // The explanations are shared between all of the values which are matched to the same
+    // variable; we thus cache them
+    let mut explanations: HashMap<usize, PropositionalConjunction> = HashMap::default();
+    for (variable, domain) in graph.domains.iter().enumerate() {
+        for &value in domain.iter() {
+            let value_node = num_variables + value;
+            if graph.variable_to_value[variable] == Some(value)
+                || reaches_free_value[value_node]
+                || components[variable] == components[value_node]
+            {
+                continue;
+            }
+
+            let matched_variable = graph.value_to_variable[value]
+                .expect("A value which does not reach a free value should be matched");
+            let explanation = explanations
+                .entry(matched_variable)
+                .or_insert_with(|| {
+                    create_removal_explanation(context, variables, &graph, matched_variable)
+                })
+                .clone();
+
+            context.remove(&variables[variable], graph.values[value], explanation)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates the explanation for removing a value matched to `matched_variable` from the domain of
+/// another variable.
+///
+/// The Hall set consists of all variables which are reachable from `matched_variable` by
+/// repeatedly going to a value in the domain of a variable and then to the variable matched to
+/// that value.
+fn create_removal_explanation<Var: IntegerVariable + 'static>(
+    context: &PropagationContextMut,
+    variables: &[Var],
+    graph: &ValueGraph,
+    matched_variable: usize,
+) -> PropositionalConjunction {
+    let mut hall_variables = vec![false; variables.len()];
+    let mut hall_values = vec![false; graph.values.len()];
+
+    hall_variables[matched_variable] = true;
+    let mut stack = vec![matched_variable];
+    while let Some(variable) = stack.pop() {
+        for &value in graph.domains[variable].iter() {
+            if hall_values[value] {
+                continue;
+            }
+            hall_values[value] = true;
+
+            let other = graph.value_to_variable[value]
+                .expect("Every value reachable from a Hall set should be matched");
+            if !hall_variables[other] {
+                hall_variables[other] = true;
+                stack.push(other);
+            }
+        }
+    }
+
+    graph.create_hall_set_explanation(
+        context,
+        variables,
+        (0..variables.len()).filter(|&index| hall_variables[index]),
+        &hall_values,
+    )
+}
+
+/// Computes the strongly connected components of the graph given by the provided `successors`
+/// using (an iterative version of) Tarjan's algorithm; returns the component of every node.
+fn strongly_connected_components(successors: &[Vec<usize>]) -> Vec<usize> {
+    let num_nodes = successors.len();
+    let mut index = vec![usize::MAX; num_nodes];
+    let mut low_link = vec![0; num_nodes];
+    let mut on_stack = vec![false; num_nodes];
+    let mut component = vec![usize::MAX; num_nodes];
+
+    let mut next_index = 0;
+    let mut num_components = 0;
+    let mut tarjan_stack = Vec::new();
+
+    for root in 0..num_nodes {
+        if index[root] != usize::MAX {
+            continue;
+        }
+
+        // Every entry contains a node and the position of the next successor to consider
+        let mut call_stack = vec![(root, 0)];
+        index[root] = next_index;
+        low_link[root] = next_index;
+        next_index += 1;
+        tarjan_stack.push(root);
+        on_stack[root] = true;
+
+        while let Some(&mut (node, ref mut position)) = call_stack.last_mut() {
+            if *position < successors[node].len() {
+                let successor = successors[node][*position];
+                *position += 1;
+
+                if index[successor] == usize::MAX {
+                    index[successor] = next_index;
+                    low_link[successor] = next_index;
+                    next_index += 1;
+                    tarjan_stack.push(successor);
+                    on_stack[successor] = true;
+                    call_stack.push((successor, 0));
+                } else if on_stack[successor] {
+                    low_link[node] = low_link[node].min(index[successor]);
+                }
+                continue;
+            }
+
+            let _ = call_stack.pop();
+            if let Some(&(parent, _)) = call_stack.last() {
+                low_link[parent] = low_link[parent].min(low_link[node]);
+            }
+
+            if low_link[node] == index[node] {
+                loop {
+                    let member = tarjan_stack
+                        .pop()
+                        .expect("The root of a component should be on the stack");
+                    on_stack[member] = false;
+                    component[member] = num_components;
+                    if member == node {
+                        break;
+                    }
+                }
+                num_components += 1;
+            }
+        }
+    }
+
+    component
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::Inconsistency;
+    use crate::conjunction;
+    use crate::engine::test_solver::TestSolver;
+
+    #[test]
+    fn hall_set_removes_values() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 3);
+        let y = solver.new_variable(1, 3);
+        let z = solver.new_variable(1, 5);
+        solver.remove(x, 2).expect("Non-empty domain");
+        solver.remove(y, 2).expect("Non-empty domain");
+
+        let _ = solver
+            .new_propagator(AllDifferentDomainPropagator::new([x, y, z].into()))
+            .expect("No conflict");
+
+        // `x` and `y` take the values 1 and 3, the bounds propagator would not remove these values
+        // from `z`
+        assert!(!solver.contains(z, 1));
+        assert!(solver.contains(z, 2));
+        assert!(!solver.contains(z, 3));
+        solver.assert_bounds(z, 2, 5);
+
+        let reason = solver.get_reason_int(predicate!(z != 3));
+        assert_eq!(
+            conjunction!([x >= 1] & [x <= 3] & [x != 2] & [y >= 1] & [y <= 3] & [y != 2]),
+            reason
+        );
+    }
+
+    #[test]
+    fn values_on_alternating_cycles_are_kept() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 2);
+        let y = solver.new_variable(1, 2);
+        let z = solver.new_variable(2, 3);
+
+        let _ = solver
+            .new_propagator(AllDifferentDomainPropagator::new([x, y, z].into()))
+            .expect("No conflict");
+
+        solver.assert_bounds(x, 1, 2);
+        solver.assert_bounds(y, 1, 2);
+        solver.assert_bounds(z, 3, 3);
+    }
+
+    #[test]
+    fn pigeonhole_is_detected() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 3);
+        let y = solver.new_variable(1, 3);
+        let z = solver.new_variable(1, 3);
+        let w = solver.new_variable(0, 10);
+        for variable in [x, y, z] {
+            solver.remove(variable, 2).expect("Non-empty domain");
+        }
+
+        let result = solver.new_propagator(AllDifferentDomainPropagator::new([w, x, y, z].into()));
+
+        assert!(matches!(
+            result,
+            Err(Inconsistency::Conflict(explanation))
+                if explanation == conjunction!(
+                    [x >= 1] & [x <= 3] & [x != 2]
+                        & [y >= 1] & [y <= 3] & [y != 2]
+                        & [z >= 1] & [z <= 3] & [z != 2]
+                )
+        ));
+    }
+}
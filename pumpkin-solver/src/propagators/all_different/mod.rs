@@ -0,0 +1,35 @@
+//! Contains the propagators for the [AllDifferent](https://sofdem.github.io/gccat/gccat/Calldifferent.html)
+//! constraint, which ensures that all of the provided variables take a different value.
+//!
+//! # Theoretical
+//!
+//! Both propagators reason using so-called **Hall sets**; a Hall set is a set of variables `H`
+//! for which the union of their domains `V_H` contains exactly `|H|` values. Since every variable
+//! in `H` has to take a different value, all of the values in `V_H` are taken by the variables in
+//! `H` and they can be removed from the domains of the variables which are not in `H`. If the
+//! union of the domains of a set of variables contains fewer values than the number of variables
+//! then a conflict has been found.
+//!
+//! - The [`AllDifferentBoundsPropagator`] only considers the bounds of the variables, and thus only
+//!   detects Hall intervals (i.e. Hall sets where the domains are treated as intervals). It
+//!   enforces bounds consistency.
+//! - The [`AllDifferentDomainPropagator`] uses the matching-based algorithm of Régin \[1\] to
+//!   enforce domain consistency.
+//!
+//! The explanations of both propagators consist of the predicates which ensure that the variables
+//! in the Hall set can only take values from `V_H` (see \[2\]).
+//!
+//! # Bibliography
+//!
+//! \[1\] J.-C. Régin, ‘A filtering algorithm for constraints of difference in CSPs’, in AAAI,
+//! 1994, pp. 362–367.
+//!
+//! \[2\] N. Downing, T. Feydy, and P. J. Stuckey, ‘Explaining alldifferent’, in Proceedings of the
+//! Thirty-fifth Australasian Computer Science Conference, 2012, pp. 115–124.
+mod bounds_consistency;
+mod domain_consistency;
+mod options;
+
+pub(crate) use bounds_consistency::*;
+pub(crate) use domain_consistency::*;
+pub use options::AllDifferentConsistency;
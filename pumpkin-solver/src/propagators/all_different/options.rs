@@ -0,0 +1,24 @@
+use std::fmt::Display;
+
+use clap::ValueEnum;
+
+/// Determines the level of consistency which is enforced by the propagator of the
+/// [`all_different`][crate::constraints::all_different] constraint.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum AllDifferentConsistency {
+    /// The default; enforces bounds consistency by detecting Hall intervals, and removes the
+    /// values of fixed variables from the other domains.
+    #[default]
+    Bounds,
+    /// Enforces domain consistency using the matching-based algorithm of Régin.
+    Domain,
+}
+
+impl Display for AllDifferentConsistency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AllDifferentConsistency::Bounds => write!(f, "bounds"),
+            AllDifferentConsistency::Domain => write!(f, "domain"),
+        }
+    }
+}
@@ -49,6 +49,10 @@ impl<VA: IntegerVariable + 'static, VB: IntegerVariable + 'static> Propagator
         "IntAbs"
     }
 
+    fn inference_label(&self) -> &str {
+        "absolute_value"
+    }
+
     fn debug_propagate_from_scratch(
         &self,
         mut context: PropagationContextMut,
@@ -67,7 +71,7 @@ impl<VA: IntegerVariable + 'static, VB: IntegerVariable + 'static> Propagator
         let signed_lb = context.lower_bound(&self.signed);
         let signed_ub = context.upper_bound(&self.signed);
 
-        let signed_absolute_ub = i32::max(signed_lb.abs(), signed_ub.abs());
+        let signed_absolute_ub = i64::max(signed_lb.abs(), signed_ub.abs());
 
         context.set_upper_bound(
             &self.absolute,
@@ -51,6 +51,10 @@ where
         "Division"
     }
 
+    fn inference_label(&self) -> &str {
+        "division"
+    }
+
     fn initialise_at_root(
         &mut self,
         context: &mut PropagatorInitialisationContext,
@@ -213,7 +217,7 @@ fn propagate_positive_domains<VA: IntegerVariable, VB: IntegerVariable, VC: Inte
 
         let result = dividend / positive_divisor;
         let adjust = result * positive_divisor < dividend;
-        result + adjust as i32
+        result + adjust as i64
     };
 
     if denominator_min < new_min_denominator {
@@ -12,6 +12,8 @@ use crate::pumpkin_assert_simple;
 /// A propagator for maintaining the constraint `a * b = c`. The propagator
 /// (currently) only propagates the signs of the variables, the case where a, b, c >= 0, and detects
 /// a conflict if the variables are fixed.
+///
+/// Products which do not fit in an `i64` are detected; such a product cannot be equal to `c`.
 #[derive(Clone, Debug)]
 pub(crate) struct IntegerMultiplicationPropagator<VA, VB, VC> {
     a: VA,
@@ -60,6 +62,10 @@ where
         "IntTimes"
     }
 
+    fn inference_label(&self) -> &str {
+        "multiplication"
+    }
+
     fn debug_propagate_from_scratch(&self, context: PropagationContextMut) -> PropagationStatusCP {
         perform_propagation(context, &self.a, &self.b, &self.c)
     }
@@ -82,21 +88,27 @@ fn perform_propagation<VA: IntegerVariable, VB: IntegerVariable, VC: IntegerVari
     let c_max = context.upper_bound(c);
 
     if a_min >= 0 && b_min >= 0 {
-        let new_max_c = a_max * b_max;
-        let new_min_c = a_min * b_min;
-
-        // c is smaller than the maximum value that a * b can take
+        // c is smaller than the maximum value that a * b can take; if this product overflows,
+        // then it is larger than any value which c can take
         //
         // We need the lower-bounds in the explanation as well because the reasoning does not
         // hold in the case of a negative lower-bound
-        context.set_upper_bound(
-            c,
-            new_max_c,
-            conjunction!([a >= 0] & [a <= a_max] & [b >= 0] & [b <= b_max]),
-        )?;
-
-        // c is larger than the minimum value that a * b can take
-        context.set_lower_bound(c, new_min_c, conjunction!([a >= a_min] & [b >= b_min]))?;
+        if let Some(new_max_c) = a_max.checked_mul(b_max) {
+            context.set_upper_bound(
+                c,
+                new_max_c,
+                conjunction!([a >= 0] & [a <= a_max] & [b >= 0] & [b <= b_max]),
+            )?;
+        }
+
+        // c is larger than the minimum value that a * b can take; if this product overflows,
+        // then there is no value which c can take
+        match a_min.checked_mul(b_min) {
+            Some(new_min_c) => {
+                context.set_lower_bound(c, new_min_c, conjunction!([a >= a_min] & [b >= b_min]))?
+            }
+            None => return Err(conjunction!([a >= a_min] & [b >= b_min]).into()),
+        }
     }
 
     if b_min >= 0 && b_max >= 1 && c_min >= 1 {
@@ -143,7 +155,10 @@ fn perform_propagation<VA: IntegerVariable, VB: IntegerVariable, VC: IntegerVari
     if context.is_fixed(a)
         && context.is_fixed(b)
         && context.is_fixed(c)
-        && (context.lower_bound(a) * context.lower_bound(b)) != context.lower_bound(c)
+        && context
+            .lower_bound(a)
+            .checked_mul(context.lower_bound(b))
+            .is_none_or(|product| product != context.lower_bound(c))
     {
         // All variables are assigned but the resulting value is not correct, so we report a
         // conflict
@@ -266,7 +281,7 @@ fn propagate_signs<VA: IntegerVariable, VB: IntegerVariable, VC: IntegerVariable
 ///
 /// Assumes `numerator, denominator > 0`.
 #[inline]
-fn div_ceil_pos(numerator: i32, denominator: i32) -> i32 {
+fn div_ceil_pos(numerator: i64, denominator: i64) -> i64 {
     pumpkin_assert_simple!(numerator > 0 && denominator > 0, "Either the numerator {numerator} was non-positive or the denominator {denominator} was non-positive");
     numerator / denominator + (numerator % denominator).signum()
 }
@@ -274,6 +289,7 @@ fn div_ceil_pos(numerator: i32, denominator: i32) -> i32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::basic_types::Inconsistency;
     use crate::conjunction;
     use crate::engine::test_solver::TestSolver;
     use crate::predicate;
@@ -361,4 +377,35 @@ mod tests {
         let reason_ub = solver.get_reason_int(predicate![a <= 4]);
         assert_eq!(conjunction!([b >= 3] & [c >= 0] & [c <= 12]), reason_ub);
     }
+
+    #[test]
+    fn overflowing_maximum_product_does_not_propagate_upper_bound_c() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(1, i64::MAX);
+        let b = solver.new_variable(1, 2);
+        let c = solver.new_variable(0, i64::MAX);
+
+        let propagator = solver
+            .new_propagator(IntegerMultiplicationPropagator::new(a, b, c))
+            .expect("no empty domains");
+
+        solver.propagate(propagator).expect("no empty domains");
+
+        solver.assert_bounds(c, 1, i64::MAX);
+    }
+
+    #[test]
+    fn overflowing_minimum_product_is_conflict() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(i64::MAX / 2, i64::MAX);
+        let b = solver.new_variable(3, 4);
+        let c = solver.new_variable(0, i64::MAX);
+
+        let result = solver.new_propagator(IntegerMultiplicationPropagator::new(a, b, c));
+        assert!(matches!(
+            result,
+            Err(Inconsistency::Conflict(explanation))
+                if explanation == conjunction!([a >= i64::MAX / 2] & [b >= 3])
+        ));
+    }
 }
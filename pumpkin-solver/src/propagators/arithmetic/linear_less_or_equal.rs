@@ -19,13 +19,22 @@ use crate::predicate;
 use crate::pumpkin_assert_simple;
 
 /// Propagator for the constraint `\sum x_i <= c`.
+///
+/// The lower bound of the left-hand side is maintained incrementally; since the sum of the
+/// lower bounds does not necessarily fit in an `i64`, the propagator detects when the incremental
+/// sum overflows and falls back to computing the sum from scratch (using `i128`s) in that case.
 #[derive(Clone, Debug)]
 pub(crate) struct LinearLessOrEqualPropagator<Var> {
     x: Box<[Var]>,
-    c: i32,
+    c: i64,
 
     /// The lower bound of the sum of the left-hand side. This is incremental state.
+    ///
+    /// This value is only accurate if [`LinearLessOrEqualPropagator::has_overflowed`] is 0.
     lower_bound_left_hand_side: TrailedInteger,
+    /// Indicates whether the lower bound of the sum of the left-hand side could not be represented
+    /// by an `i64` (1) or not (0).
+    has_overflowed: TrailedInteger,
     /// The value at index `i` is the bound for `x[i]`.
     current_bounds: Box<[TrailedInteger]>,
 }
@@ -34,7 +43,7 @@ impl<Var> LinearLessOrEqualPropagator<Var>
 where
     Var: IntegerVariable,
 {
-    pub(crate) fn new(x: Box<[Var]>, c: i32) -> Self {
+    pub(crate) fn new(x: Box<[Var]>, c: i64) -> Self {
         let current_bounds = (0..x.len())
             .map(|_| TrailedInteger::default())
             .collect_vec()
@@ -45,6 +54,7 @@ where
             x,
             c,
             lower_bound_left_hand_side: TrailedInteger::default(),
+            has_overflowed: TrailedInteger::default(),
             current_bounds,
         }
     }
@@ -55,6 +65,25 @@ where
             .map(|var| predicate![var >= context.lower_bound(var)])
             .collect()
     }
+
+    /// Returns the lower bound of the left-hand side; the incrementally maintained value is used
+    /// unless it has overflowed, in which case it is recomputed.
+    fn lower_bound_left_hand_side(&self, context: &PropagationContextWithTrailedValues) -> i128 {
+        if context.value(self.has_overflowed) == 0 {
+            context.value(self.lower_bound_left_hand_side) as i128
+        } else {
+            compute_lower_bound_left_hand_side(context.as_readonly(), &self.x)
+        }
+    }
+}
+
+/// Computes the sum of the lower bounds of the provided variables; this cannot overflow an `i128`
+/// for any reasonable number of variables.
+fn compute_lower_bound_left_hand_side<Var: IntegerVariable>(
+    context: PropagationContext,
+    x: &[Var],
+) -> i128 {
+    x.iter().map(|var| context.lower_bound(var) as i128).sum()
 }
 
 impl<Var: 'static> Propagator for LinearLessOrEqualPropagator<Var>
@@ -65,17 +94,28 @@ where
         &mut self,
         context: &mut PropagatorInitialisationContext,
     ) -> Result<(), PropositionalConjunction> {
-        let mut lower_bound_left_hand_side = 0_i64;
         self.x.iter().enumerate().for_each(|(i, x_i)| {
             let _ = context.register(
                 x_i.clone(),
                 DomainEvents::LOWER_BOUND,
                 LocalId::from(i as u32),
             );
-            lower_bound_left_hand_side += context.lower_bound(x_i) as i64;
-            self.current_bounds[i] = context.new_trailed_integer(context.lower_bound(x_i) as i64);
+            self.current_bounds[i] = context.new_trailed_integer(context.lower_bound(x_i));
         });
-        self.lower_bound_left_hand_side = context.new_trailed_integer(lower_bound_left_hand_side);
+
+        let lower_bound_left_hand_side =
+            compute_lower_bound_left_hand_side(context.as_readonly(), &self.x);
+        match i64::try_from(lower_bound_left_hand_side) {
+            Ok(lower_bound_left_hand_side) => {
+                self.lower_bound_left_hand_side =
+                    context.new_trailed_integer(lower_bound_left_hand_side);
+                self.has_overflowed = context.new_trailed_integer(0);
+            }
+            Err(_) => {
+                self.lower_bound_left_hand_side = context.new_trailed_integer(0);
+                self.has_overflowed = context.new_trailed_integer(1);
+            }
+        }
 
         if let Some(conjunction) = self.detect_inconsistency(context.as_trailed_readonly()) {
             Err(conjunction)
@@ -88,7 +128,7 @@ where
         &self,
         context: PropagationContextWithTrailedValues,
     ) -> Option<PropositionalConjunction> {
-        if (self.c as i64) < context.value(self.lower_bound_left_hand_side) {
+        if (self.c as i128) < self.lower_bound_left_hand_side(&context) {
             Some(self.create_conflict_reason(context.as_readonly()))
         } else {
             None
@@ -105,16 +145,27 @@ where
         let x_i = &self.x[index];
 
         let old_bound = context.value(self.current_bounds[index]);
-        let new_bound = context.lower_bound(x_i) as i64;
+        let new_bound = context.lower_bound(x_i);
 
         pumpkin_assert_simple!(
             old_bound < new_bound,
             "propagator should only be triggered when lower bounds are tightened, old_bound={old_bound}, new_bound={new_bound}"
         );
 
-        context.add_assign(self.lower_bound_left_hand_side, new_bound - old_bound);
         context.assign(self.current_bounds[index], new_bound);
 
+        if context.value(self.has_overflowed) == 0 {
+            // The difference between the bounds is computed using `i128`s since it might not fit
+            // in an `i64`
+            let updated_lower_bound_left_hand_side = context.value(self.lower_bound_left_hand_side)
+                as i128
+                + (new_bound as i128 - old_bound as i128);
+            match i64::try_from(updated_lower_bound_left_hand_side) {
+                Ok(value) => context.assign(self.lower_bound_left_hand_side, value),
+                Err(_) => context.assign(self.has_overflowed, 1),
+            }
+        }
+
         EnqueueDecision::Enqueue
     }
 
@@ -126,117 +177,74 @@ where
         "LinearLeq"
     }
 
+    fn inference_label(&self) -> &str {
+        "linear_bound"
+    }
+
     fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
         if let Some(conjunction) = self.detect_inconsistency(context.as_trailed_readonly()) {
             return Err(conjunction.into());
         }
 
         let lower_bound_left_hand_side =
-            match TryInto::<i32>::try_into(context.value(self.lower_bound_left_hand_side)) {
-                Ok(bound) => bound,
-                Err(_) if context.value(self.lower_bound_left_hand_side).is_positive() => {
-                    // We cannot fit the `lower_bound_left_hand_side` into an i32 due to an
-                    // overflow (hence the check that the lower-bound on the left-hand side is
-                    // positive)
-                    //
-                    // This means that the lower-bounds of the current variables will always be
-                    // higher than the right-hand side (with a maximum value of i32). We thus
-                    // return a conflict
-                    return Err(self.create_conflict_reason(context.as_readonly()).into());
-                }
-                Err(_) => {
-                    // We cannot fit the `lower_bound_left_hand_side` into an i32 due to an
-                    // underflow
-                    //
-                    // This means that the constraint is always satisfied
-                    return Ok(());
-                }
-            };
-
-        for (i, x_i) in self.x.iter().enumerate() {
-            let bound = self.c - (lower_bound_left_hand_side - context.lower_bound(x_i));
-
-            if context.upper_bound(x_i) > bound {
-                let reason: PropositionalConjunction = self
-                    .x
-                    .iter()
-                    .enumerate()
-                    .filter_map(|(j, x_j)| {
-                        if j != i {
-                            Some(predicate![x_j >= context.lower_bound(x_j)])
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-
-                context.set_upper_bound(x_i, bound, reason)?;
-            }
-        }
-
-        Ok(())
+            self.lower_bound_left_hand_side(&context.as_trailed_readonly());
+        propagate_upper_bounds(&mut context, &self.x, self.c, lower_bound_left_hand_side)
     }
 
     fn debug_propagate_from_scratch(
         &self,
         mut context: PropagationContextMut,
     ) -> PropagationStatusCP {
-        let lower_bound_left_hand_side = self
-            .x
-            .iter()
-            .map(|var| context.lower_bound(var) as i64)
-            .sum::<i64>();
-
-        let lower_bound_left_hand_side = match TryInto::<i32>::try_into(lower_bound_left_hand_side)
-        {
-            Ok(bound) => bound,
-            Err(_) if context.value(self.lower_bound_left_hand_side).is_positive() => {
-                // We cannot fit the `lower_bound_left_hand_side` into an i32 due to an
-                // overflow (hence the check that the lower-bound on the left-hand side is
-                // positive)
-                //
-                // This means that the lower-bounds of the current variables will always be
-                // higher than the right-hand side (with a maximum value of i32). We thus
-                // return a conflict
-                return Err(self.create_conflict_reason(context.as_readonly()).into());
-            }
-            Err(_) => {
-                // We cannot fit the `lower_bound_left_hand_side` into an i32 due to an
-                // underflow
-                //
-                // This means that the constraint is always satisfied
-                return Ok(());
-            }
-        };
-
-        for (i, x_i) in self.x.iter().enumerate() {
-            let bound = self.c - (lower_bound_left_hand_side - context.lower_bound(x_i));
-
-            if context.upper_bound(x_i) > bound {
-                let reason: PropositionalConjunction = self
-                    .x
-                    .iter()
-                    .enumerate()
-                    .filter_map(|(j, x_j)| {
-                        if j != i {
-                            Some(predicate![x_j >= context.lower_bound(x_j)])
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-
-                context.set_upper_bound(x_i, bound, reason)?;
-            }
+        let lower_bound_left_hand_side =
+            compute_lower_bound_left_hand_side(context.as_readonly(), &self.x);
+        if (self.c as i128) < lower_bound_left_hand_side {
+            return Err(self.create_conflict_reason(context.as_readonly()).into());
         }
 
-        Ok(())
+        propagate_upper_bounds(&mut context, &self.x, self.c, lower_bound_left_hand_side)
     }
 }
 
+/// Propagates the upper bound of every variable based on the lower bounds of the other
+/// variables.
+///
+/// Assumes that the lower bound of the left-hand side does not exceed `c`; this ensures that the
+/// new upper bound of every variable is at least its lower bound and thus fits in an `i64`.
+fn propagate_upper_bounds<Var: IntegerVariable>(
+    context: &mut PropagationContextMut,
+    x: &[Var],
+    c: i64,
+    lower_bound_left_hand_side: i128,
+) -> PropagationStatusCP {
+    for (i, x_i) in x.iter().enumerate() {
+        let bound = c as i128 - (lower_bound_left_hand_side - context.lower_bound(x_i) as i128);
+
+        if (context.upper_bound(x_i) as i128) > bound {
+            let reason: PropositionalConjunction = x
+                .iter()
+                .enumerate()
+                .filter_map(|(j, x_j)| {
+                    if j != i {
+                        Some(predicate![x_j >= context.lower_bound(x_j)])
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            let bound = i64::try_from(bound)
+                .expect("the bound is at least the lower bound of the variable");
+            context.set_upper_bound(x_i, bound, reason)?;
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::basic_types::Inconsistency;
     use crate::conjunction;
     use crate::engine::test_solver::TestSolver;
 
@@ -277,23 +285,63 @@ mod tests {
     fn overflow_leads_to_conflict() {
         let mut solver = TestSolver::default();
 
-        let x = solver.new_variable(i32::MAX, i32::MAX);
+        let x = solver.new_variable(i64::MAX, i64::MAX);
         let y = solver.new_variable(1, 1);
 
         let _ = solver
-            .new_propagator(LinearLessOrEqualPropagator::new([x, y].into(), i32::MAX))
+            .new_propagator(LinearLessOrEqualPropagator::new([x, y].into(), i64::MAX))
             .expect_err("Expected overflow to be detected");
     }
 
+    #[test]
+    fn overflow_after_notification_leads_to_conflict() {
+        let mut solver = TestSolver::default();
+
+        let x = solver.new_variable(0, i64::MAX);
+        let y = solver.new_variable(0, i64::MAX);
+
+        let propagator = solver
+            .new_propagator(LinearLessOrEqualPropagator::new([x, y].into(), i64::MAX))
+            .expect("no empty domains");
+
+        let _ = solver.increase_lower_bound_and_notify(propagator, 0, x, i64::MAX - 1);
+        let _ = solver.increase_lower_bound_and_notify(propagator, 1, y, 2);
+
+        let result = solver.propagate(propagator);
+        assert!(matches!(
+            result,
+            Err(Inconsistency::Conflict(explanation))
+                if explanation == conjunction!([x >= i64::MAX - 1] & [y >= 2])
+        ));
+    }
+
+    #[test]
+    fn large_bounds_are_propagated_without_overflow() {
+        let mut solver = TestSolver::default();
+
+        let x = solver.new_variable(i64::MIN, 0);
+        let y = solver.new_variable(i64::MIN, i64::MAX);
+
+        let propagator = solver
+            .new_propagator(LinearLessOrEqualPropagator::new([x, y].into(), -10))
+            .expect("no empty domains");
+
+        let _ = solver.increase_lower_bound_and_notify(propagator, 0, x, -5);
+        solver.propagate(propagator).expect("non-empty domain");
+
+        solver.assert_bounds(y, i64::MIN, -5);
+        solver.assert_bounds(x, -5, 0);
+    }
+
     #[test]
     fn underflow_leads_to_no_propagation() {
         let mut solver = TestSolver::default();
 
-        let x = solver.new_variable(i32::MIN, i32::MIN);
+        let x = solver.new_variable(i64::MIN, i64::MIN);
         let y = solver.new_variable(-1, -1);
 
         let _ = solver
-            .new_propagator(LinearLessOrEqualPropagator::new([x, y].into(), i32::MIN))
+            .new_propagator(LinearLessOrEqualPropagator::new([x, y].into(), i64::MIN))
             .expect("Expected no error to be detected");
     }
 }
@@ -28,13 +28,14 @@ pub(crate) struct LinearNotEqualPropagator<Var> {
     /// The terms of the sum
     terms: Rc<[Var]>,
     /// The right-hand side of the sum
-    rhs: i32,
+    rhs: i64,
 
     /// The number of fixed terms; note that this constraint can only propagate when there is a
     /// single unfixed variable and can only detect conflicts if all variables are assigned
     number_of_fixed_terms: usize,
-    /// The sum of the values of the fixed terms
-    fixed_lhs: i32,
+    /// The sum of the values of the fixed terms; this is stored as an `i128` to prevent the sum
+    /// from overflowing
+    fixed_lhs: i128,
     /// Indicates whether the single unfixed variable has been updated; if this is the case then
     /// the propagator is not scheduled again
     unfixed_variable_has_been_updated: bool,
@@ -47,7 +48,7 @@ impl<Var> LinearNotEqualPropagator<Var>
 where
     Var: IntegerVariable + 'static,
 {
-    pub(crate) fn new(terms: Box<[Var]>, rhs: i32) -> Self {
+    pub(crate) fn new(terms: Box<[Var]>, rhs: i64) -> Self {
         LinearNotEqualPropagator {
             terms: terms.into(),
             rhs,
@@ -71,6 +72,10 @@ where
         "LinearNe"
     }
 
+    fn inference_label(&self) -> &str {
+        "linear_not_equal"
+    }
+
     fn notify(
         &mut self,
         context: PropagationContextWithTrailedValues,
@@ -80,7 +85,7 @@ where
         // If the updated term is fixed then we update the number of fixed variables
         self.number_of_fixed_terms += 1;
         // We update the value of the left-hand side with the value of the newly fixed variable
-        self.fixed_lhs += context.lower_bound(&self.terms[local_id.unpack() as usize]);
+        self.fixed_lhs += context.lower_bound(&self.terms[local_id.unpack() as usize]) as i128;
 
         // Either the number of fixed variables is the number of terms - 1 in which case we can
         // propagate if it has not been updated before; if it has been updated then we don't need to
@@ -92,7 +97,7 @@ where
         // - Either we can report a conflict
         // - Or the sum of the values of the left-hand side is inaccurate and we should recalculate
         let is_conflicting_or_outdated = self.number_of_fixed_terms == self.terms.len()
-            && (self.should_recalculate_lhs || self.fixed_lhs == self.rhs);
+            && (self.should_recalculate_lhs || self.fixed_lhs == self.rhs as i128);
         if can_propagate || is_conflicting_or_outdated {
             EnqueueDecision::Enqueue
         } else {
@@ -167,8 +172,10 @@ where
             pumpkin_assert_simple!(!self.should_recalculate_lhs);
 
             // The value which would cause a conflict if the current variable would be set equal to
-            // this
-            let value_to_remove = self.rhs - self.fixed_lhs;
+            // this; if it does not fit in an i64, then it cannot be in the domain of the variable
+            let Ok(value_to_remove) = i64::try_from(self.rhs as i128 - self.fixed_lhs) else {
+                return Ok(());
+            };
 
             // We find the value which is unfixed
             // We could make use of a sparse-set to determine this, if necessary
@@ -221,15 +228,17 @@ where
             .iter()
             .map(|var| {
                 if context.is_fixed(var) {
-                    context.lower_bound(var) as i64
+                    context.lower_bound(var) as i128
                 } else {
                     0
                 }
             })
-            .sum::<i64>();
+            .sum::<i128>();
 
         if num_fixed == self.terms.len() - 1 {
-            let value_to_remove = self.rhs as i64 - lhs;
+            let Ok(value_to_remove) = i64::try_from(self.rhs as i128 - lhs) else {
+                return Ok(());
+            };
 
             let unfixed_x_i = self
                 .terms
@@ -244,14 +253,8 @@ where
                 .filter(|&(i, _)| i != unfixed_x_i)
                 .map(|(_, x_i)| predicate![x_i == context.lower_bound(x_i)])
                 .collect::<PropositionalConjunction>();
-            context.remove(
-                &self.terms[unfixed_x_i],
-                value_to_remove
-                    .try_into()
-                    .expect("Expected to be able to fit i64 into i32"),
-                reason,
-            )?;
-        } else if num_fixed == self.terms.len() && lhs == self.rhs as i64 {
+            context.remove(&self.terms[unfixed_x_i], value_to_remove, reason)?;
+        } else if num_fixed == self.terms.len() && lhs == self.rhs as i128 {
             let failure_reason: PropositionalConjunction = self
                 .terms
                 .iter()
@@ -280,7 +283,7 @@ impl<Var: IntegerVariable + 'static> LinearNotEqualPropagator<Var> {
                 .fold((0, 0), |(fixed_lhs, number_of_fixed_terms), term| {
                     if context.is_fixed(term) {
                         (
-                            fixed_lhs + context.lower_bound(term),
+                            fixed_lhs + context.lower_bound(term) as i128,
                             number_of_fixed_terms + 1,
                         )
                     } else {
@@ -295,7 +298,7 @@ impl<Var: IntegerVariable + 'static> LinearNotEqualPropagator<Var> {
         context: PropagationContext,
     ) -> Result<(), PropositionalConjunction> {
         pumpkin_assert_simple!(!self.should_recalculate_lhs);
-        if self.number_of_fixed_terms == self.terms.len() && self.fixed_lhs == self.rhs {
+        if self.number_of_fixed_terms == self.terms.len() && self.fixed_lhs == self.rhs as i128 {
             let failure_reason: PropositionalConjunction = self
                 .terms
                 .iter()
@@ -319,12 +322,12 @@ impl<Var: IntegerVariable + 'static> LinearNotEqualPropagator<Var> {
         let number_of_fixed_terms_is_correct =
             self.number_of_fixed_terms == expected_number_of_fixed_terms;
 
-        let expected_fixed_lhs: i32 = self
+        let expected_fixed_lhs: i128 = self
             .terms
             .iter()
             .filter_map(|x_i| {
                 if context.is_fixed(x_i) {
-                    Some(context.lower_bound(x_i))
+                    Some(context.lower_bound(x_i) as i128)
                 } else {
                     None
                 }
@@ -56,6 +56,10 @@ impl<ElementVar: IntegerVariable + 'static, Rhs: IntegerVariable + 'static> Prop
         "Maximum"
     }
 
+    fn inference_label(&self) -> &str {
+        "maximum"
+    }
+
     fn debug_propagate_from_scratch(
         &self,
         mut context: PropagationContextMut,
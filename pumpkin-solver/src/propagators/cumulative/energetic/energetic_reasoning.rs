@@ -0,0 +1,278 @@
+use std::rc::Rc;
+
+use super::window_reasoning::propagate_based_on_energy;
+use super::window_reasoning::WindowEnergyType;
+use crate::basic_types::PropagationStatusCP;
+use crate::engine::opaque_domain_event::OpaqueDomainEvent;
+use crate::engine::propagation::contexts::PropagationContextWithTrailedValues;
+use crate::engine::propagation::EnqueueDecision;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::engine::IntDomainEvent;
+use crate::predicates::PropositionalConjunction;
+use crate::propagators::create_time_table_over_interval_from_scratch;
+use crate::propagators::cumulative::time_table::time_table_util::propagate_based_on_timetable;
+use crate::propagators::debug_propagate_from_scratch_time_table_interval;
+use crate::propagators::util::create_tasks;
+use crate::propagators::util::register_tasks;
+use crate::propagators::util::update_bounds_task;
+use crate::propagators::ArgTask;
+use crate::propagators::CumulativeParameters;
+use crate::propagators::CumulativePropagatorOptions;
+#[cfg(doc)]
+use crate::propagators::TimeTableEdgeFindingPropagator;
+use crate::propagators::UpdatableStructures;
+
+/// [`Propagator`] responsible for using energetic reasoning to propagate the [Cumulative](https://sofdem.github.io/gccat/gccat/Ccumulative.html) constraint.
+///
+/// Energetic reasoning considers windows `[a, b)` created from the earliest and latest start and
+/// completion times of the tasks; every task contributes the energy of its minimum intersection
+/// with the window (i.e. the minimum of the overlaps of its left-shifted and right-shifted
+/// schedules with the window). Before applying this reasoning, the propagator applies time-table
+/// reasoning using a time-table over intervals.
+///
+/// Note that the number of considered windows is quadratic in the number of tasks, and that the
+/// energy of every task is calculated for every window; this propagator is thus more expensive
+/// than the [`TimeTableEdgeFindingPropagator`] but it can also detect more conflicts and
+/// propagations.
+///
+/// See [`crate::propagators::cumulative::energetic`] for more information.
+///
+/// \[1\] P. Baptiste, C. Le Pape, and W. Nuijten, ‘Satisfiability tests and time‐bound
+/// adjustments for cumulative scheduling problems’, Annals of Operations research, vol. 92, pp.
+/// 305–333, 1999.
+#[derive(Debug)]
+pub(crate) struct EnergeticReasoningPropagator<Var, PVar, RVar, CVar> {
+    /// Stores the input parameters to the cumulative constraint
+    parameters: CumulativeParameters<Var, PVar, RVar, CVar>,
+    /// Stores structures which change during the search; used to store the bounds
+    updatable_structures: UpdatableStructures<Var, PVar, RVar>,
+}
+
+impl<
+        Var: IntegerVariable + 'static,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
+        CVar: IntegerVariable + 'static,
+    > EnergeticReasoningPropagator<Var, PVar, RVar, CVar>
+{
+    pub(crate) fn new(
+        arg_tasks: &[ArgTask<Var, PVar, RVar>],
+        capacity: CVar,
+        cumulative_options: CumulativePropagatorOptions,
+    ) -> EnergeticReasoningPropagator<Var, PVar, RVar, CVar> {
+        let tasks = create_tasks(arg_tasks);
+        let parameters = CumulativeParameters::new(tasks, capacity, cumulative_options);
+        let updatable_structures = UpdatableStructures::new(&parameters);
+
+        EnergeticReasoningPropagator {
+            parameters,
+            updatable_structures,
+        }
+    }
+}
+
+impl<
+        Var: IntegerVariable + 'static,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
+        CVar: IntegerVariable + 'static,
+    > Propagator for EnergeticReasoningPropagator<Var, PVar, RVar, CVar>
+{
+    fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        let time_table =
+            create_time_table_over_interval_from_scratch(context.as_readonly(), &self.parameters)?;
+        propagate_based_on_timetable(
+            &mut context,
+            time_table.iter(),
+            &self.parameters,
+            &mut self.updatable_structures,
+        )?;
+
+        propagate_based_on_energy(&mut context, &self.parameters, WindowEnergyType::Energetic)
+    }
+
+    fn synchronise(&mut self, context: PropagationContext) {
+        self.updatable_structures
+            .reset_all_bounds_and_remove_fixed(context, &self.parameters);
+    }
+
+    fn notify(
+        &mut self,
+        context: PropagationContextWithTrailedValues,
+        local_id: LocalId,
+        event: OpaqueDomainEvent,
+    ) -> EnqueueDecision {
+        if local_id.unpack() as usize >= self.parameters.tasks.len() {
+            // The processing time or resource usage of a task or the capacity has been updated
+            return EnqueueDecision::Enqueue;
+        }
+
+        let updated_task = Rc::clone(&self.parameters.tasks[local_id.unpack() as usize]);
+        update_bounds_task(
+            context.as_readonly(),
+            self.updatable_structures.get_stored_bounds_mut(),
+            &updated_task,
+        );
+
+        if matches!(
+            updated_task.start_variable.unpack_event(event),
+            IntDomainEvent::Assign
+        ) {
+            self.updatable_structures.fix_task(&updated_task)
+        }
+
+        // Any change in the bounds of a task can change the energy which it requires in a window
+        EnqueueDecision::Enqueue
+    }
+
+    fn priority(&self) -> u32 {
+        3
+    }
+
+    fn name(&self) -> &str {
+        "CumulativeEnergeticReasoning"
+    }
+
+    fn inference_label(&self) -> &str {
+        "energetic_reasoning"
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.updatable_structures
+            .initialise_bounds_and_remove_fixed(context.as_readonly(), &self.parameters);
+        register_tasks(
+            &self.parameters.tasks,
+            &self.parameters.capacity,
+            context,
+            false,
+        );
+
+        Ok(())
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        debug_propagate_from_scratch_time_table_interval(
+            &mut context,
+            &self.parameters,
+            &self.updatable_structures,
+        )?;
+
+        propagate_based_on_energy(&mut context, &self.parameters, WindowEnergyType::Energetic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::Inconsistency;
+    use crate::conjunction;
+    use crate::engine::test_solver::TestSolver;
+    use crate::options::CumulativeExplanationType;
+    use crate::predicate;
+    use crate::propagators::ArgTask;
+    use crate::propagators::CumulativePropagatorOptions;
+    use crate::propagators::EnergeticReasoningPropagator;
+
+    #[test]
+    fn propagator_propagates_lower_bound_without_mandatory_parts() {
+        let mut solver = TestSolver::default();
+        let s1 = solver.new_variable(1, 4);
+        let s2 = solver.new_variable(1, 4);
+        let s3 = solver.new_variable(2, 11);
+
+        let propagator = EnergeticReasoningPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s3,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ],
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions::default(),
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
+
+        // The tasks `s1` and `s2` have to be executed in [1, 6) which leaves 1 time unit for `s3`;
+        // note that the explanation only requires `s3` to start at 1 at the earliest
+        assert_eq!(solver.lower_bound(s3), 5);
+        assert_eq!(solver.upper_bound(s3), 11);
+
+        let reason = solver.get_reason_int(predicate!(s3 >= 5));
+        assert_eq!(
+            conjunction!([s1 >= 1] & [s1 <= 4] & [s2 >= 1] & [s2 <= 4] & [s3 >= 1]),
+            reason
+        );
+    }
+
+    #[test]
+    fn propagator_detects_conflict_with_variable_capacity() {
+        let mut solver = TestSolver::default();
+        let s1 = solver.new_variable(0, 2);
+        let s2 = solver.new_variable(0, 2);
+        let s3 = solver.new_variable(0, 2);
+        // The capacity is tightened, so its bound is not implied by the initial domain
+        let capacity = solver.new_variable(0, 2);
+        let _ = solver.remove(capacity, 2);
+
+        let propagator = EnergeticReasoningPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s3,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ],
+            capacity,
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let result = solver.new_propagator(propagator);
+
+        assert!(matches!(
+            result,
+            Err(Inconsistency::Conflict(explanation))
+                if explanation == conjunction!(
+                    [s1 >= 0]
+                        & [s1 <= 2]
+                        & [s2 >= 0]
+                        & [s2 <= 2]
+                        & [s3 >= 0]
+                        & [s3 <= 2]
+                        & [capacity <= 1]
+                )
+        ));
+    }
+}
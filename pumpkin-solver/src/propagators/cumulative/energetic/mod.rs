@@ -0,0 +1,66 @@
+//! Contains the propagators which use energy-based reasoning for propagating the
+//! [Cumulative](https://sofdem.github.io/gccat/gccat/Ccumulative.html) constraint.
+//!
+//! # Theoretical
+//!
+//! The **energy** of a [`Task`] *i* is the area `p_i * r_i` which it occupies on the resource.
+//! Energy-based propagators consider time windows `[a, b)` and determine (for every [`Task`]) a
+//! lower-bound on the energy which it has to consume within this window given its current bounds.
+//! If the sum of these energies exceeds the energy available in the window (i.e. `C * (b - a)`)
+//! then a conflict has been found.
+//!
+//! Otherwise, the remaining energy (the *slack*) of the window can be used to propagate the
+//! bounds of a [`Task`] *i*; if the energy which *i* consumes within the window when it is
+//! scheduled at its earliest start time exceeds the slack (when disregarding its own contribution)
+//! then it can only use `q = floor(slack / r_i)` time units of the window, meaning that it can
+//! start at `b - q` at the earliest. The same reasoning is applied to the latest completion time
+//! of *i* when it is scheduled at its latest start time.
+//!
+//! The propagators in this module differ in the energy which they assign to a [`Task`] in a
+//! window and in the windows which are considered:
+//! - [`TimeTableEdgeFindingPropagator`] uses time-table edge-finding, where the [`Task`]s which are
+//!   entirely contained in the window contribute their full energy and the other [`Task`]s only
+//!   contribute the energy of their mandatory part overlapping with the window. The windows start
+//!   at an earliest start time and end at a latest completion time.
+//! - [`EnergeticReasoningPropagator`] uses energetic reasoning, where each [`Task`] contributes its
+//!   minimum intersection with the window (i.e. the minimum overlap of its left-shifted and
+//!   right-shifted schedules with the window). The windows are created from the earliest and latest
+//!   start and completion times of the [`Task`]s.
+//!
+//! Both of the propagators first apply time-table reasoning (using the time-table created by the
+//! [`TimeTableOverIntervalPropagator`]) before applying their energy-based reasoning.
+//!
+//! # Explanations
+//!
+//! The explanations are determined by the [`CumulativeExplanationType`]; the naive explanation
+//! uses the current bounds of all [`Task`]s which contribute energy to the window. Otherwise, the
+//! bounds are lifted such that every [`Task`] *j* is only required to consume the energy which was
+//! used in the reasoning; if *j* was assigned `v` time units then its start time is explained by
+//! `[s_j >= a + v - p_j]` and `[s_j <= b - v]`.
+//!
+//! # Bibliography
+//!
+//! \[1\] P. Vilím, ‘Timetable edge finding filtering algorithm for discrete cumulative
+//! resources’, in International Conference on AI and OR Techniques in Constraint Programming for
+//! Combinatorial Optimization Problems, 2011, pp. 230–245.
+//!
+//! \[2\] A. Schutt, T. Feydy, and P. J. Stuckey, ‘Explaining time-table-edge-finding propagation
+//! for the cumulative resource constraint’, in International Conference on AI and OR Techniques
+//! in Constraint Programming for Combinatorial Optimization Problems, 2013, pp. 234–250.
+//!
+//! \[3\] P. Baptiste, C. Le Pape, and W. Nuijten, ‘Satisfiability tests and time‐bound
+//! adjustments for cumulative scheduling problems’, Annals of Operations research, vol. 92, pp.
+//! 305–333, 1999.
+mod energetic_reasoning;
+mod time_table_edge_finding;
+mod window_reasoning;
+
+pub(crate) use energetic_reasoning::*;
+pub(crate) use time_table_edge_finding::*;
+
+#[cfg(doc)]
+use crate::propagators::CumulativeExplanationType;
+#[cfg(doc)]
+use crate::propagators::Task;
+#[cfg(doc)]
+use crate::propagators::TimeTableOverIntervalPropagator;
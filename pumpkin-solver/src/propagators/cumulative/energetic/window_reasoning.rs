@@ -0,0 +1,415 @@
+//! Contains the energy-based reasoning over time windows which is shared by the
+//! [`TimeTableEdgeFindingPropagator`] and the [`EnergeticReasoningPropagator`].
+use std::cmp::max;
+use std::cmp::min;
+
+use crate::basic_types::PropagationStatusCP;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::ReadDomains;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::predicates::PropositionalConjunction;
+use crate::propagators::cumulative::time_table::explanations::create_capacity_predicate;
+use crate::propagators::cumulative::time_table::explanations::create_task_data_predicates;
+use crate::propagators::CumulativeExplanationType;
+use crate::propagators::CumulativeParameters;
+#[cfg(doc)]
+use crate::propagators::EnergeticReasoningPropagator;
+use crate::propagators::Task;
+#[cfg(doc)]
+use crate::propagators::TimeTableEdgeFindingPropagator;
+use crate::pumpkin_assert_simple;
+use crate::variables::IntegerVariable;
+
+/// Determines how the energy which a [`Task`] has to consume in a time window is calculated, and
+/// which time windows are considered.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum WindowEnergyType {
+    /// The energy used by time-table edge-finding (see [`TimeTableEdgeFindingPropagator`]).
+    TimeTableEdgeFinding,
+    /// The energy used by energetic reasoning (see [`EnergeticReasoningPropagator`]).
+    Energetic,
+}
+
+/// The bounds of a [`Task`] which are used to determine the energy that it has to consume in a
+/// time window.
+#[derive(Debug, Clone, Copy)]
+struct TaskBounds {
+    earliest_start: i64,
+    latest_start: i64,
+    processing_time: i64,
+    resource_usage: i64,
+}
+
+impl TaskBounds {
+    fn new<Var, PVar, RVar, Context>(context: Context, task: &Task<Var, PVar, RVar>) -> Self
+    where
+        Var: IntegerVariable + 'static,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
+        Context: ReadDomains,
+    {
+        TaskBounds {
+            earliest_start: context.lower_bound(&task.start_variable),
+            latest_start: context.upper_bound(&task.start_variable),
+            processing_time: context.lower_bound(&task.processing_time),
+            resource_usage: context.lower_bound(&task.resource_usage),
+        }
+    }
+
+    fn earliest_completion(&self) -> i64 {
+        self.earliest_start + self.processing_time
+    }
+
+    fn latest_completion(&self) -> i64 {
+        self.latest_start + self.processing_time
+    }
+
+    /// Returns whether the [`Task`] is guaranteed to consume energy.
+    fn has_energy(&self) -> bool {
+        self.processing_time > 0 && self.resource_usage > 0
+    }
+
+    /// The number of time units in `[start, end)` used by the [`Task`] if it starts at
+    /// `start_time`.
+    fn overlap_when_starting_at(&self, start_time: i64, start: i64, end: i64) -> i64 {
+        max(
+            0,
+            min(end, start_time + self.processing_time) - max(start, start_time),
+        )
+    }
+}
+
+impl WindowEnergyType {
+    /// Returns the windows `[a, b)` (with `a < b`) which are considered for the provided `bounds`.
+    fn candidate_windows(&self, bounds: &[TaskBounds]) -> Vec<(i64, i64)> {
+        let tasks_with_energy = bounds.iter().filter(|bounds| bounds.has_energy());
+
+        let (mut starts, mut ends): (Vec<i64>, Vec<i64>) = match self {
+            WindowEnergyType::TimeTableEdgeFinding => tasks_with_energy
+                .map(|bounds| (bounds.earliest_start, bounds.latest_completion()))
+                .unzip(),
+            WindowEnergyType::Energetic => {
+                let mut starts = Vec::new();
+                let mut ends = Vec::new();
+                for bounds in tasks_with_energy {
+                    starts.extend([
+                        bounds.earliest_start,
+                        bounds.latest_start,
+                        bounds.earliest_completion(),
+                    ]);
+                    ends.extend([
+                        bounds.latest_completion(),
+                        bounds.earliest_completion(),
+                        bounds.latest_start,
+                    ]);
+                }
+                (starts, ends)
+            }
+        };
+        starts.sort();
+        starts.dedup();
+        ends.sort();
+        ends.dedup();
+
+        starts
+            .iter()
+            .flat_map(|&start| {
+                ends.iter()
+                    .filter(move |&&end| start < end)
+                    .map(move |&end| (start, end))
+            })
+            .collect()
+    }
+
+    /// Returns the number of time units which the [`Task`] with the provided `bounds` has to be
+    /// executed in the window `[start, end)`.
+    fn required_overlap(&self, bounds: &TaskBounds, start: i64, end: i64) -> i64 {
+        if !bounds.has_energy() {
+            return 0;
+        }
+
+        match self {
+            WindowEnergyType::TimeTableEdgeFinding => {
+                if start <= bounds.earliest_start && bounds.latest_completion() <= end {
+                    // The task is fully contained in the window
+                    bounds.processing_time
+                } else {
+                    // Otherwise, only the mandatory part in the window is taken into account
+                    max(
+                        0,
+                        min(end, bounds.earliest_completion()) - max(start, bounds.latest_start),
+                    )
+                }
+            }
+            WindowEnergyType::Energetic => max(
+                0,
+                min(
+                    min(end - start, bounds.processing_time),
+                    min(
+                        bounds.earliest_completion() - start,
+                        end - bounds.latest_start,
+                    ),
+                ),
+            ),
+        }
+    }
+}
+
+/// Goes over the candidate windows of the `energy_type` and checks whether the energy required by
+/// the tasks exceeds the energy which is available in the window; if this is not the case then it
+/// uses the slack of the window to propagate the bounds of the tasks.
+///
+/// Note that this method is not idempotent; the propagations in one window can lead to
+/// propagations in windows which have already been considered.
+pub(crate) fn propagate_based_on_energy<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
+>(
+    context: &mut PropagationContextMut,
+    parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
+    energy_type: WindowEnergyType,
+) -> PropagationStatusCP {
+    let capacity = context.upper_bound(&parameters.capacity) as i128;
+    let mut bounds = parameters
+        .tasks
+        .iter()
+        .map(|task| TaskBounds::new(context.as_readonly(), task))
+        .collect::<Vec<_>>();
+
+    for (start, end) in energy_type.candidate_windows(&bounds) {
+        let overlaps = bounds
+            .iter()
+            .map(|task_bounds| energy_type.required_overlap(task_bounds, start, end))
+            .collect::<Vec<_>>();
+        let required_energy = overlaps
+            .iter()
+            .zip(bounds.iter())
+            .map(|(&overlap, task_bounds)| overlap as i128 * task_bounds.resource_usage as i128)
+            .sum::<i128>();
+        if required_energy == 0 {
+            continue;
+        }
+
+        let available_energy = capacity * (end as i128 - start as i128);
+        if required_energy > available_energy {
+            return Err(create_energy_conflict_explanation(
+                context,
+                parameters,
+                &bounds,
+                &overlaps,
+                (start, end),
+                available_energy,
+            )
+            .into());
+        }
+
+        for task in parameters.tasks.iter() {
+            let index = task.id.unpack() as usize;
+            if !bounds[index].has_energy() || context.is_fixed(&task.start_variable) {
+                continue;
+            }
+            let resource_usage = bounds[index].resource_usage as i128;
+
+            // The energy which is left in the window when disregarding the contribution of the
+            // current task
+            let slack =
+                available_energy - required_energy + overlaps[index] as i128 * resource_usage;
+            pumpkin_assert_simple!(slack >= 0);
+            // The number of time units of the window which the task can use without overflowing
+            // the capacity; note that this value is only used if it is smaller than the number of
+            // time units in the window, meaning that it fits in an `i64`
+            let usable_time_units = slack / resource_usage;
+
+            let left_shifted_overlap =
+                bounds[index].overlap_when_starting_at(bounds[index].earliest_start, start, end);
+            if left_shifted_overlap as i128 > usable_time_units {
+                let usable_time_units = usable_time_units as i64;
+                let explanation = create_energy_propagation_explanation(
+                    context,
+                    parameters,
+                    &bounds,
+                    &overlaps,
+                    (start, end),
+                    task,
+                    predicate!(
+                        task.start_variable
+                            >= lifted_bound(
+                                parameters.options.explanation_type,
+                                bounds[index].earliest_start,
+                                start + usable_time_units + 1 - bounds[index].processing_time,
+                            )
+                    ),
+                );
+                let new_lower_bound = end - usable_time_units;
+                context.set_lower_bound(&task.start_variable, new_lower_bound, explanation)?;
+                bounds[index].earliest_start = new_lower_bound;
+            }
+
+            let right_shifted_overlap =
+                bounds[index].overlap_when_starting_at(bounds[index].latest_start, start, end);
+            if right_shifted_overlap as i128 > usable_time_units {
+                let usable_time_units = usable_time_units as i64;
+                let explanation = create_energy_propagation_explanation(
+                    context,
+                    parameters,
+                    &bounds,
+                    &overlaps,
+                    (start, end),
+                    task,
+                    predicate!(
+                        task.start_variable
+                            <= lifted_bound(
+                                parameters.options.explanation_type,
+                                bounds[index].latest_start,
+                                end - usable_time_units - 1,
+                            )
+                    ),
+                );
+                let new_upper_bound = start + usable_time_units - bounds[index].processing_time;
+                context.set_upper_bound(&task.start_variable, new_upper_bound, explanation)?;
+                bounds[index].latest_start = new_upper_bound;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the `current_bound` when using the [`CumulativeExplanationType::Naive`] explanation
+/// and the `lifted_bound` otherwise.
+fn lifted_bound(
+    explanation_type: CumulativeExplanationType,
+    current_bound: i64,
+    lifted_bound: i64,
+) -> i64 {
+    match explanation_type {
+        CumulativeExplanationType::Naive => current_bound,
+        CumulativeExplanationType::BigStep | CumulativeExplanationType::Pointwise => lifted_bound,
+    }
+}
+
+/// Adds the predicates to the `explanation` which ensure that the `task` (with the provided
+/// `bounds`) executes for at least `overlap` time units in the window `[start, end)`.
+fn add_energy_predicates<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    Context: ReadDomains + Copy,
+>(
+    explanation: &mut Vec<Predicate>,
+    context: Context,
+    explanation_type: CumulativeExplanationType,
+    task: &Task<Var, PVar, RVar>,
+    bounds: &TaskBounds,
+    overlap: i64,
+    (start, end): (i64, i64),
+) {
+    explanation.push(predicate!(
+        task.start_variable
+            >= lifted_bound(
+                explanation_type,
+                bounds.earliest_start,
+                start + overlap - bounds.processing_time,
+            )
+    ));
+    explanation.push(predicate!(
+        task.start_variable <= lifted_bound(explanation_type, bounds.latest_start, end - overlap)
+    ));
+    explanation.extend(create_task_data_predicates(context, task));
+}
+
+/// Creates the explanation for the window `[start, end)` requiring more energy than the
+/// `available_energy`; only the tasks which are required to exceed the available energy are
+/// included in the explanation.
+fn create_energy_conflict_explanation<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
+>(
+    context: &PropagationContextMut,
+    parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
+    bounds: &[TaskBounds],
+    overlaps: &[i64],
+    window: (i64, i64),
+    available_energy: i128,
+) -> PropositionalConjunction {
+    let mut explanation = Vec::new();
+    let mut required_energy = 0;
+    for task in parameters.tasks.iter() {
+        let index = task.id.unpack() as usize;
+        if overlaps[index] == 0 {
+            continue;
+        }
+
+        add_energy_predicates(
+            &mut explanation,
+            context.as_readonly(),
+            parameters.options.explanation_type,
+            task,
+            &bounds[index],
+            overlaps[index],
+            window,
+        );
+        required_energy += overlaps[index] as i128 * bounds[index].resource_usage as i128;
+        if required_energy > available_energy {
+            break;
+        }
+    }
+    explanation.extend(create_capacity_predicate(
+        context.as_readonly(),
+        &parameters.capacity,
+    ));
+
+    explanation.into()
+}
+
+/// Creates the explanation for the propagation of the `propagating_task` based on the energy
+/// required by the other tasks in the window `[start, end)`; the `propagating_task_predicate`
+/// explains the position of the `propagating_task` which causes it to overlap with the window.
+fn create_energy_propagation_explanation<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
+>(
+    context: &PropagationContextMut,
+    parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
+    bounds: &[TaskBounds],
+    overlaps: &[i64],
+    window: (i64, i64),
+    propagating_task: &Task<Var, PVar, RVar>,
+    propagating_task_predicate: Predicate,
+) -> PropositionalConjunction {
+    let mut explanation = Vec::new();
+    for task in parameters.tasks.iter() {
+        let index = task.id.unpack() as usize;
+        if overlaps[index] == 0 || task.id == propagating_task.id {
+            continue;
+        }
+
+        add_energy_predicates(
+            &mut explanation,
+            context.as_readonly(),
+            parameters.options.explanation_type,
+            task,
+            &bounds[index],
+            overlaps[index],
+            window,
+        );
+    }
+    explanation.push(propagating_task_predicate);
+    explanation.extend(create_task_data_predicates(
+        context.as_readonly(),
+        propagating_task,
+    ));
+    explanation.extend(create_capacity_predicate(
+        context.as_readonly(),
+        &parameters.capacity,
+    ));
+
+    explanation.into()
+}
@@ -1,6 +1,7 @@
 //! Contains the propagators for the [Cumulative](https://sofdem.github.io/gccat/gccat/Ccumulative.html)
-//! constraint, currently it contains solely time-tabling propagators (see
-//! [`crate::propagators::cumulative::time_table`] for an explanation).
+//! constraint, currently it contains time-tabling propagators (see
+//! [`crate::propagators::cumulative::time_table`] for an explanation) and energy-based propagators
+//! (see [`crate::propagators::cumulative::energetic`] for an explanation).
 //!
 //! # Theoretical
 //!
@@ -9,7 +10,12 @@
 //! - A variable `s_i` representing the start time of the [`Task`]
 //! - The duration of the [`Task`] `p_i` (which is the same for all resources) which cannot be
 //!   interruped
-//! - The constant resource usage `r_i` of the [`Task`] (which can differ for different resources)
+//! - The resource usage `r_i` of the [`Task`] (which can differ for different resources)
+//!
+//! Both the duration and the resource usage of a [`Task`] (as well as the capacity of the
+//! resource) can be either constants or variables; in the latter case, the propagators reason
+//! using `lb(p_i)`, `lb(r_i)` and the upper-bound of the capacity (and these bounds are included
+//! in the explanations).
 //!
 //! Oftentimes the following notation is used to denote certain significant time points:
 //! - `EST_i` - The earliest starting time, equal to `lb(s_i)`
@@ -71,7 +77,7 @@
 //!
 //! // We check whether the result was feasible
 //! if let SatisfactionResult::Satisfiable(solution) = result {
-//!     let horizon = durations.iter().sum::<i32>();
+//!     let horizon = durations.iter().sum::<i64>();
 //!     let start_times = [start_0, start_1, start_2];
 //!
 //!     // Now we check whether the resource constraint is satisfied at each time-point t
@@ -89,7 +95,7 @@
 //!                     None
 //!                 }
 //!             })
-//!             .sum::<i32>();
+//!             .sum::<i64>();
 //!         // Then we check whether the resource usage at the current time point is lower than
 //!         // the resource capacity
 //!         resource_usage_at_t <= resource_capacity
@@ -114,6 +120,8 @@
 mod time_table;
 pub use time_table::CumulativeExplanationType;
 pub(crate) use time_table::*;
+mod energetic;
+pub(crate) use energetic::*;
 mod options;
 pub use options::*;
 
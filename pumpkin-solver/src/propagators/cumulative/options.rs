@@ -21,9 +21,9 @@ pub(crate) struct CumulativePropagatorOptions {
 
 #[derive(Debug, Copy, Clone, Default)]
 pub struct CumulativeOptions {
-    /// The propagation method which is used for the cumulative constraints; currently all of them
-    /// are variations of time-tabling. The default is incremental time-tabling reasoning over
-    /// intervals.
+    /// The propagation method which is used for the cumulative constraints; these are either
+    /// variations of time-tabling or energy-based reasoning (which is combined with time-tabling).
+    /// The default is incremental time-tabling reasoning over intervals.
     pub(crate) propagation_method: CumulativePropagationMethod,
     /// The options which are passed to the propagator itself
     pub(crate) propagator_options: CumulativePropagatorOptions,
@@ -58,6 +58,8 @@ pub enum CumulativePropagationMethod {
     #[default]
     TimeTableOverIntervalIncremental,
     TimeTableOverIntervalIncrementalSynchronised,
+    TimeTableEdgeFinding,
+    EnergeticReasoning,
 }
 
 impl Display for CumulativePropagationMethod {
@@ -79,6 +81,10 @@ impl Display for CumulativePropagationMethod {
             CumulativePropagationMethod::TimeTableOverIntervalIncrementalSynchronised => {
                 write!(f, "time-table-over-interval-incremental-synchronised")
             }
+            CumulativePropagationMethod::TimeTableEdgeFinding => {
+                write!(f, "time-table-edge-finding")
+            }
+            CumulativePropagationMethod::EnergeticReasoning => write!(f, "energetic-reasoning"),
         }
     }
 }
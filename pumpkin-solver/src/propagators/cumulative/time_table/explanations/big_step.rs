@@ -6,14 +6,20 @@ use crate::engine::propagation::ReadDomains;
 use crate::predicate;
 use crate::predicates::Predicate;
 use crate::predicates::PropositionalConjunction;
+use crate::propagators::cumulative::time_table::explanations::create_task_data_predicates;
 use crate::propagators::ResourceProfile;
 use crate::propagators::Task;
 use crate::variables::IntegerVariable;
 
 /// Creates the propagation explanation using the big-step approach (see
 /// [`CumulativeExplanationType::BigStep`])
-pub(crate) fn create_big_step_propagation_explanation<Var: IntegerVariable + 'static>(
-    profile: &ResourceProfile<Var>,
+pub(crate) fn create_big_step_propagation_explanation<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
+    profile: &ResourceProfile<Var, PVar, RVar>,
+    context: PropagationContext,
 ) -> PropositionalConjunction {
     profile
         .profile_tasks
@@ -21,18 +27,27 @@ pub(crate) fn create_big_step_propagation_explanation<Var: IntegerVariable + 'st
         .flat_map(|profile_task| {
             [
                 predicate!(
-                    profile_task.start_variable >= profile.end - profile_task.processing_time + 1
+                    profile_task.start_variable
+                        >= profile.end - context.lower_bound(&profile_task.processing_time) + 1
                 ),
                 predicate!(profile_task.start_variable <= profile.start),
             ]
+            .into_iter()
+            .chain(create_task_data_predicates(context, profile_task))
         })
         .collect()
 }
 
 /// Creates the conflict explanation using the big-step approach (see
 /// [`CumulativeExplanationType::BigStep`])
-pub(crate) fn create_big_step_conflict_explanation<Var: IntegerVariable + 'static>(
-    conflict_profile: &ResourceProfile<Var>,
+pub(crate) fn create_big_step_conflict_explanation<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    Context: ReadDomains + Copy,
+>(
+    conflict_profile: &ResourceProfile<Var, PVar, RVar>,
+    context: Context,
 ) -> PropositionalConjunction {
     conflict_profile
         .profile_tasks
@@ -41,31 +56,42 @@ pub(crate) fn create_big_step_conflict_explanation<Var: IntegerVariable + 'stati
             [
                 predicate!(
                     profile_task.start_variable
-                        >= conflict_profile.end - profile_task.processing_time + 1
+                        >= conflict_profile.end
+                            - context.lower_bound(&profile_task.processing_time)
+                            + 1
                 ),
                 predicate!(profile_task.start_variable <= conflict_profile.start),
             ]
+            .into_iter()
+            .chain(create_task_data_predicates(context, profile_task))
         })
         .collect()
 }
 
-pub(crate) fn create_big_step_predicate_propagating_task_lower_bound_propagation<Var>(
-    task: &Rc<Task<Var>>,
-    profile: &ResourceProfile<Var>,
+pub(crate) fn create_big_step_predicate_propagating_task_lower_bound_propagation<Var, PVar, RVar>(
+    task: &Rc<Task<Var, PVar, RVar>>,
+    profile: &ResourceProfile<Var, PVar, RVar>,
+    context: PropagationContext,
 ) -> Predicate
 where
     Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
 {
-    predicate!(task.start_variable >= profile.start + 1 - task.processing_time)
+    predicate!(
+        task.start_variable >= profile.start + 1 - context.lower_bound(&task.processing_time)
+    )
 }
 
-pub(crate) fn create_big_step_predicate_propagating_task_upper_bound_propagation<Var>(
-    task: &Rc<Task<Var>>,
-    profile: &ResourceProfile<Var>,
+pub(crate) fn create_big_step_predicate_propagating_task_upper_bound_propagation<Var, PVar, RVar>(
+    task: &Rc<Task<Var, PVar, RVar>>,
+    profile: &ResourceProfile<Var, PVar, RVar>,
     context: PropagationContext,
 ) -> Predicate
 where
     Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
 {
     predicate!(task.start_variable <= max(context.upper_bound(&task.start_variable), profile.end))
 }
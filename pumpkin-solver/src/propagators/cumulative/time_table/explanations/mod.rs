@@ -13,6 +13,8 @@ use pointwise::create_pointwise_predicate_propagating_task_lower_bound_propagati
 use pointwise::create_pointwise_predicate_propagating_task_upper_bound_propagation;
 
 use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::ReadDomains;
+use crate::predicate;
 use crate::predicates::Predicate;
 use crate::predicates::PropositionalConjunction;
 use crate::propagators::ResourceProfile;
@@ -71,34 +73,48 @@ impl Display for CumulativeExplanationType {
 /// Creates the lower-bound [`Predicate`] of the `propagating_task` based on the `explanation_type`.
 pub(crate) fn create_predicate_propagating_task_lower_bound_propagation<
     Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
 >(
     explanation_type: CumulativeExplanationType,
     context: PropagationContext,
-    task: &Rc<Task<Var>>,
-    profile: &ResourceProfile<Var>,
-    time_point: Option<i32>,
+    task: &Rc<Task<Var, PVar, RVar>>,
+    profile: &ResourceProfile<Var, PVar, RVar>,
+    time_point: Option<i64>,
 ) -> Predicate {
     match explanation_type {
         CumulativeExplanationType::Naive => {
             create_naive_predicate_propagating_task_lower_bound_propagation(context, task)
         }
         CumulativeExplanationType::BigStep => {
-            create_big_step_predicate_propagating_task_lower_bound_propagation(task, profile)
+            create_big_step_predicate_propagating_task_lower_bound_propagation(
+                task, profile, context,
+            )
         }
         CumulativeExplanationType::Pointwise => {
-            create_pointwise_predicate_propagating_task_lower_bound_propagation(task, time_point)
+            create_pointwise_predicate_propagating_task_lower_bound_propagation(
+                task, time_point, context,
+            )
         }
     }
 }
 
-/// Adds the lower-bound predicate of the propagating task to the provided `explanation`.
-pub(crate) fn add_propagating_task_predicate_lower_bound<Var: IntegerVariable + 'static>(
+/// Adds the lower-bound predicate of the propagating task to the provided `explanation` together
+/// with the predicates on its processing time and resource usage (see
+/// [`create_task_data_predicates`]) and the provided `capacity_predicate` (see
+/// [`create_capacity_predicate`]).
+pub(crate) fn add_propagating_task_predicate_lower_bound<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
     mut explanation: PropositionalConjunction,
     explanation_type: CumulativeExplanationType,
     context: PropagationContext,
-    task: &Rc<Task<Var>>,
-    profile: &ResourceProfile<Var>,
-    time_point: Option<i32>,
+    task: &Rc<Task<Var, PVar, RVar>>,
+    profile: &ResourceProfile<Var, PVar, RVar>,
+    time_point: Option<i64>,
+    capacity_predicate: Option<Predicate>,
 ) -> PropositionalConjunction {
     explanation.add(create_predicate_propagating_task_lower_bound_propagation(
         explanation_type,
@@ -107,18 +123,22 @@ pub(crate) fn add_propagating_task_predicate_lower_bound<Var: IntegerVariable +
         profile,
         time_point,
     ));
+    explanation.extend(create_task_data_predicates(context, task));
+    explanation.extend(capacity_predicate);
     explanation
 }
 
 /// Creates the upper-bound [`Predicate`] of the `propagating_task` based on the `explanation_type`.
 pub(crate) fn create_predicate_propagating_task_upper_bound_propagation<
     Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
 >(
     explanation_type: CumulativeExplanationType,
     context: PropagationContext,
-    task: &Rc<Task<Var>>,
-    profile: &ResourceProfile<Var>,
-    time_point: Option<i32>,
+    task: &Rc<Task<Var, PVar, RVar>>,
+    profile: &ResourceProfile<Var, PVar, RVar>,
+    time_point: Option<i64>,
 ) -> Predicate {
     match explanation_type {
         CumulativeExplanationType::Naive => {
@@ -135,14 +155,22 @@ pub(crate) fn create_predicate_propagating_task_upper_bound_propagation<
     }
 }
 
-/// Adds the upper-bound predicate of the propagating task to the provided `explanation`.
-pub(crate) fn add_propagating_task_predicate_upper_bound<Var: IntegerVariable + 'static>(
+/// Adds the upper-bound predicate of the propagating task to the provided `explanation` together
+/// with the predicates on its processing time and resource usage (see
+/// [`create_task_data_predicates`]) and the provided `capacity_predicate` (see
+/// [`create_capacity_predicate`]).
+pub(crate) fn add_propagating_task_predicate_upper_bound<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
     mut explanation: PropositionalConjunction,
     explanation_type: CumulativeExplanationType,
     context: PropagationContext,
-    task: &Rc<Task<Var>>,
-    profile: &ResourceProfile<Var>,
-    time_point: Option<i32>,
+    task: &Rc<Task<Var, PVar, RVar>>,
+    profile: &ResourceProfile<Var, PVar, RVar>,
+    time_point: Option<i64>,
+    capacity_predicate: Option<Predicate>,
 ) -> PropositionalConjunction {
     explanation.add(create_predicate_propagating_task_upper_bound_propagation(
         explanation_type,
@@ -151,5 +179,76 @@ pub(crate) fn add_propagating_task_predicate_upper_bound<Var: IntegerVariable +
         profile,
         time_point,
     ));
+    explanation.extend(create_task_data_predicates(context, task));
+    explanation.extend(capacity_predicate);
     explanation
 }
+
+/// Creates the predicates which explain the processing time and the resource usage of the provided
+/// `task`; i.e. `[p >= lb(p)]` and `[r >= lb(r)]`.
+///
+/// Predicates which hold in the initial domains (e.g. if the processing time is a constant) are not
+/// returned.
+pub(crate) fn create_task_data_predicates<
+    Var,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    Context: ReadDomains + Copy,
+>(
+    context: Context,
+    task: &Task<Var, PVar, RVar>,
+) -> impl Iterator<Item = Predicate> {
+    create_processing_time_predicate(context, task)
+        .into_iter()
+        .chain(create_resource_usage_predicate(context, task))
+}
+
+/// Creates the predicate which explains the processing time of the provided `task`; i.e. `[p >=
+/// lb(p)]`.
+///
+/// If the predicate holds in the initial domain then [`None`] is returned.
+pub(crate) fn create_processing_time_predicate<
+    Var,
+    PVar: IntegerVariable + 'static,
+    RVar,
+    Context: ReadDomains,
+>(
+    context: Context,
+    task: &Task<Var, PVar, RVar>,
+) -> Option<Predicate> {
+    Some(predicate!(
+        task.processing_time >= context.lower_bound(&task.processing_time)
+    ))
+    .filter(|&predicate| !context.assignments().is_initial_bound(predicate))
+}
+
+/// Creates the predicate which explains the resource usage of the provided `task`; i.e. `[r >=
+/// lb(r)]`.
+///
+/// If the predicate holds in the initial domain then [`None`] is returned.
+pub(crate) fn create_resource_usage_predicate<
+    Var,
+    PVar,
+    RVar: IntegerVariable + 'static,
+    Context: ReadDomains,
+>(
+    context: Context,
+    task: &Task<Var, PVar, RVar>,
+) -> Option<Predicate> {
+    Some(predicate!(
+        task.resource_usage >= context.lower_bound(&task.resource_usage)
+    ))
+    .filter(|&predicate| !context.assignments().is_initial_bound(predicate))
+}
+
+/// Creates the predicate which explains the capacity of the resource; i.e. `[c <= ub(c)]`.
+///
+/// If the predicate holds in the initial domain (e.g. if the capacity is a constant) then [`None`] is
+/// returned.
+pub(crate) fn create_capacity_predicate<CVar: IntegerVariable + 'static, Context: ReadDomains>(
+    context: Context,
+    capacity: &CVar,
+) -> Option<Predicate> {
+    Some(predicate!(capacity <= context.upper_bound(capacity)))
+        .filter(|&predicate| !context.assignments().is_initial_bound(predicate))
+}
@@ -5,14 +5,19 @@ use crate::engine::propagation::ReadDomains;
 use crate::predicate;
 use crate::predicates::Predicate;
 use crate::predicates::PropositionalConjunction;
+use crate::propagators::cumulative::time_table::explanations::create_task_data_predicates;
 use crate::propagators::ResourceProfile;
 use crate::propagators::Task;
 use crate::variables::IntegerVariable;
 
 /// Creates the propagation explanation using the naive approach (see
 /// [`CumulativeExplanationType::Naive`])
-pub(crate) fn create_naive_propagation_explanation<Var: IntegerVariable + 'static>(
-    profile: &ResourceProfile<Var>,
+pub(crate) fn create_naive_propagation_explanation<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
+    profile: &ResourceProfile<Var, PVar, RVar>,
     context: PropagationContext,
 ) -> PropositionalConjunction {
     profile
@@ -29,18 +34,22 @@ pub(crate) fn create_naive_propagation_explanation<Var: IntegerVariable + 'stati
                         <= context.upper_bound(&profile_task.start_variable)
                 ),
             ]
+            .into_iter()
+            .chain(create_task_data_predicates(context, profile_task))
         })
         .collect()
 }
 
 /// Creates the conflict explanation using the naive approach (see
 /// [`CumulativeExplanationType::Naive`])
-pub(crate) fn create_naive_conflict_explanation<Var, Context: ReadDomains + Copy>(
-    conflict_profile: &ResourceProfile<Var>,
+pub(crate) fn create_naive_conflict_explanation<Var, PVar, RVar, Context: ReadDomains + Copy>(
+    conflict_profile: &ResourceProfile<Var, PVar, RVar>,
     context: Context,
 ) -> PropositionalConjunction
 where
     Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
 {
     conflict_profile
         .profile_tasks
@@ -56,26 +65,32 @@ where
                         <= context.upper_bound(&profile_task.start_variable)
                 ),
             ]
+            .into_iter()
+            .chain(create_task_data_predicates(context, profile_task))
         })
         .collect()
 }
 
-pub(crate) fn create_naive_predicate_propagating_task_lower_bound_propagation<Var>(
+pub(crate) fn create_naive_predicate_propagating_task_lower_bound_propagation<Var, PVar, RVar>(
     context: PropagationContext,
-    task: &Rc<Task<Var>>,
+    task: &Rc<Task<Var, PVar, RVar>>,
 ) -> Predicate
 where
     Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
 {
     predicate!(task.start_variable >= context.lower_bound(&task.start_variable))
 }
 
-pub(crate) fn create_naive_predicate_propagating_task_upper_bound_propagation<Var>(
+pub(crate) fn create_naive_predicate_propagating_task_upper_bound_propagation<Var, PVar, RVar>(
     context: PropagationContext,
-    task: &Rc<Task<Var>>,
+    task: &Rc<Task<Var, PVar, RVar>>,
 ) -> Predicate
 where
     Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
 {
     predicate!(task.start_variable <= context.upper_bound(&task.start_variable))
 }
@@ -1,6 +1,7 @@
 use std::rc::Rc;
 
 use crate::engine::propagation::contexts::propagation_context::HasAssignments;
+use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::ReadDomains;
 use crate::engine::EmptyDomain;
@@ -10,17 +11,24 @@ use crate::predicates::Predicate;
 use crate::predicates::PropositionalConjunction;
 use crate::propagators::cumulative::time_table::explanations::add_propagating_task_predicate_lower_bound;
 use crate::propagators::cumulative::time_table::explanations::add_propagating_task_predicate_upper_bound;
+use crate::propagators::cumulative::time_table::explanations::create_task_data_predicates;
 use crate::propagators::ResourceProfile;
 use crate::propagators::Task;
 use crate::pumpkin_assert_extreme;
 use crate::pumpkin_assert_simple;
 use crate::variables::IntegerVariable;
 
-pub(crate) fn propagate_lower_bounds_with_pointwise_explanations<Var: IntegerVariable + 'static>(
+pub(crate) fn propagate_lower_bounds_with_pointwise_explanations<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
     context: &mut PropagationContextMut,
-    profiles: &[&ResourceProfile<Var>],
-    propagating_task: &Rc<Task<Var>>,
+    profiles: &[&ResourceProfile<Var, PVar, RVar>],
+    propagating_task: &Rc<Task<Var, PVar, RVar>>,
+    capacity_predicate: Option<Predicate>,
 ) -> Result<(), EmptyDomain> {
+    let processing_time = context.lower_bound(&propagating_task.processing_time);
     // The time points should follow the following properties (based on `Improving
     // scheduling by learning - Andreas Schutt`):
     // 1. `t_0 = lb(s)`
@@ -40,10 +48,9 @@ pub(crate) fn propagate_lower_bounds_with_pointwise_explanations<Var: IntegerVar
     // We take as `t_1` the minimum of the first profile end and the earliest
     // completion time - 1 (this - 1 is necessary since the explanation uses the
     // predicate `[s >= t_l + 1 - p]`, and this predicate holds only if the -1 is added)
-    let mut time_point = profiles[current_profile_index].end.min(
-        context.lower_bound(&propagating_task.start_variable) + propagating_task.processing_time
-            - 1,
-    );
+    let mut time_point = profiles[current_profile_index]
+        .end
+        .min(context.lower_bound(&propagating_task.start_variable) + processing_time - 1);
     let mut should_exit = false;
 
     loop {
@@ -60,12 +67,14 @@ pub(crate) fn propagate_lower_bounds_with_pointwise_explanations<Var: IntegerVar
                 create_pointwise_propagation_explanation(
                     time_point,
                     profiles[current_profile_index],
+                    context.as_readonly(),
                 ),
                 CumulativeExplanationType::Pointwise,
                 context.as_readonly(),
                 propagating_task,
                 profiles[current_profile_index],
                 Some(time_point),
+                capacity_predicate,
             );
             pumpkin_assert_extreme!(
                 explanation
@@ -85,7 +94,7 @@ pub(crate) fn propagate_lower_bounds_with_pointwise_explanations<Var: IntegerVar
         }
 
         // We place the time-point as far as possible
-        time_point += propagating_task.processing_time;
+        time_point += processing_time;
 
         // Then we update the index of the current profile if appropriate
         if time_point > profiles[current_profile_index].end {
@@ -124,11 +133,17 @@ pub(crate) fn propagate_lower_bounds_with_pointwise_explanations<Var: IntegerVar
     }
     Ok(())
 }
-pub(crate) fn propagate_upper_bounds_with_pointwise_explanations<Var: IntegerVariable + 'static>(
+pub(crate) fn propagate_upper_bounds_with_pointwise_explanations<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
     context: &mut PropagationContextMut,
-    profiles: &[&ResourceProfile<Var>],
-    propagating_task: &Rc<Task<Var>>,
+    profiles: &[&ResourceProfile<Var, PVar, RVar>],
+    propagating_task: &Rc<Task<Var, PVar, RVar>>,
+    capacity_predicate: Option<Predicate>,
 ) -> Result<(), EmptyDomain> {
+    let processing_time = context.lower_bound(&propagating_task.processing_time);
     // The time points should follow the following properties (based on `Improving
     // scheduling by learning - Andreas Schutt`):
     // 1. `t_0 = ub(s) + p`
@@ -161,19 +176,19 @@ pub(crate) fn propagate_upper_bounds_with_pointwise_explanations<Var: IntegerVar
                         profiles[current_profile_index].end
                     );
 
-        if time_point - propagating_task.processing_time
-            < context.upper_bound(&propagating_task.start_variable)
-        {
+        if time_point - processing_time < context.upper_bound(&propagating_task.start_variable) {
             let explanation = add_propagating_task_predicate_upper_bound(
                 create_pointwise_propagation_explanation(
                     time_point,
                     profiles[current_profile_index],
+                    context.as_readonly(),
                 ),
                 CumulativeExplanationType::Pointwise,
                 context.as_readonly(),
                 propagating_task,
                 profiles[current_profile_index],
                 Some(time_point),
+                capacity_predicate,
             );
             pumpkin_assert_extreme!(
                 explanation
@@ -183,7 +198,7 @@ pub(crate) fn propagate_upper_bounds_with_pointwise_explanations<Var: IntegerVar
             );
             context.set_upper_bound(
                 &propagating_task.start_variable,
-                time_point - propagating_task.processing_time,
+                time_point - processing_time,
                 explanation,
             )?;
         }
@@ -192,7 +207,7 @@ pub(crate) fn propagate_upper_bounds_with_pointwise_explanations<Var: IntegerVar
             break;
         }
 
-        time_point -= propagating_task.processing_time;
+        time_point -= processing_time;
 
         // Then we update the index of the current profile if appropriate
         if time_point < profiles[current_profile_index].start {
@@ -229,9 +244,14 @@ pub(crate) fn propagate_upper_bounds_with_pointwise_explanations<Var: IntegerVar
 
 /// Creates the propagation explanation using the point-wise approach (see
 /// [`CumulativeExplanationType::PointWise`])
-pub(crate) fn create_pointwise_propagation_explanation<Var: IntegerVariable + 'static>(
-    time_point: i32,
-    profile: &ResourceProfile<Var>,
+pub(crate) fn create_pointwise_propagation_explanation<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
+    time_point: i64,
+    profile: &ResourceProfile<Var, PVar, RVar>,
+    context: PropagationContext,
 ) -> PropositionalConjunction {
     profile
         .profile_tasks
@@ -239,18 +259,27 @@ pub(crate) fn create_pointwise_propagation_explanation<Var: IntegerVariable + 's
         .flat_map(move |profile_task| {
             [
                 predicate!(
-                    profile_task.start_variable >= time_point + 1 - profile_task.processing_time
+                    profile_task.start_variable
+                        >= time_point + 1 - context.lower_bound(&profile_task.processing_time)
                 ),
                 predicate!(profile_task.start_variable <= time_point),
             ]
+            .into_iter()
+            .chain(create_task_data_predicates(context, profile_task))
         })
         .collect()
 }
 
 /// Creates the conflict explanation using the point-wise approach (see
 /// [`CumulativeExplanationType::PointWise`])
-pub(crate) fn create_pointwise_conflict_explanation<Var: IntegerVariable + 'static>(
-    conflict_profile: &ResourceProfile<Var>,
+pub(crate) fn create_pointwise_conflict_explanation<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    Context: ReadDomains + Copy,
+>(
+    conflict_profile: &ResourceProfile<Var, PVar, RVar>,
+    context: Context,
 ) -> PropositionalConjunction {
     // As stated in improving scheduling by learning, we choose the middle point; this
     // could potentially be improved
@@ -265,36 +294,44 @@ pub(crate) fn create_pointwise_conflict_explanation<Var: IntegerVariable + 'stat
         .flat_map(|profile_task| {
             [
                 predicate!(
-                    profile_task.start_variable >= middle_point + 1 - profile_task.processing_time
+                    profile_task.start_variable
+                        >= middle_point + 1 - context.lower_bound(&profile_task.processing_time)
                 ),
                 predicate!(profile_task.start_variable <= middle_point),
             ]
+            .into_iter()
+            .chain(create_task_data_predicates(context, profile_task))
         })
         .collect()
 }
 
-pub(crate) fn create_pointwise_predicate_propagating_task_lower_bound_propagation<Var>(
-    task: &Rc<Task<Var>>,
-    time_point: Option<i32>,
+pub(crate) fn create_pointwise_predicate_propagating_task_lower_bound_propagation<Var, PVar, RVar>(
+    task: &Rc<Task<Var, PVar, RVar>>,
+    time_point: Option<i64>,
+    context: PropagationContext,
 ) -> Predicate
 where
     Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
 {
     predicate!(
         task.start_variable
             >= time_point
                 .expect("Expected time-point to be provided to pointwise explanation creation")
                 + 1
-                - task.processing_time
+                - context.lower_bound(&task.processing_time)
     )
 }
 
-pub(crate) fn create_pointwise_predicate_propagating_task_upper_bound_propagation<Var>(
-    task: &Rc<Task<Var>>,
-    time_point: Option<i32>,
+pub(crate) fn create_pointwise_predicate_propagating_task_upper_bound_propagation<Var, PVar, RVar>(
+    task: &Rc<Task<Var, PVar, RVar>>,
+    time_point: Option<i64>,
 ) -> Predicate
 where
     Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
 {
     predicate!(
         task.start_variable
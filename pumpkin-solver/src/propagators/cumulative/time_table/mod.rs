@@ -69,13 +69,13 @@
 //! Conference, CP 2015, Cork, Ireland, August 31--September 4, 2015, Proceedings 21, 2015, pp.
 //! 149–157.
 
-mod explanations;
+pub(crate) mod explanations;
 mod over_interval_incremental_propagator;
 mod per_point_incremental_propagator;
 mod propagation_handler;
 mod time_table_over_interval;
 mod time_table_per_point;
-mod time_table_util;
+pub(crate) mod time_table_util;
 pub use explanations::CumulativeExplanationType;
 pub(crate) use over_interval_incremental_propagator::*;
 pub(crate) use per_point_incremental_propagator::*;
@@ -3,6 +3,8 @@
 use std::ops::Range;
 use std::rc::Rc;
 
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::ReadDomains;
 use crate::propagators::cumulative::time_table::over_interval_incremental_propagator::checks;
 use crate::propagators::OverIntervalTimeTableType;
 use crate::propagators::ResourceProfile;
@@ -16,14 +18,17 @@ use crate::variables::IntegerVariable;
 /// profiles and adds them to the `time-table` at the correct position.
 pub(crate) fn insert_profiles_overlapping_with_added_mandatory_part<
     Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
 >(
-    time_table: &mut OverIntervalTimeTableType<Var>,
+    context: PropagationContext,
+    time_table: &mut OverIntervalTimeTableType<Var, PVar, RVar>,
     start_index: usize,
     end_index: usize,
-    update_range: &Range<i32>,
-    updated_task: &Rc<Task<Var>>,
-    capacity: i32,
-) -> Result<(), ResourceProfile<Var>> {
+    update_range: &Range<i64>,
+    updated_task: &Rc<Task<Var, PVar, RVar>>,
+    capacity: i64,
+) -> Result<(), ResourceProfile<Var, PVar, RVar>> {
     let mut to_add = Vec::new();
 
     // We keep track of whether a conflict has been found
@@ -39,6 +44,7 @@ pub(crate) fn insert_profiles_overlapping_with_added_mandatory_part<
         // Check whether there is a new profile before the first overlapping
         // profile
         checks::new_profile_before_first_profile(
+            context,
             current_index,
             start_index,
             update_range,
@@ -50,6 +56,7 @@ pub(crate) fn insert_profiles_overlapping_with_added_mandatory_part<
         // Check whether there is a new profile between the current profile
         // and the previous profile (beginning of profile remains unchanged)
         checks::new_profile_between_profiles(
+            context,
             time_table,
             current_index,
             start_index,
@@ -72,6 +79,7 @@ pub(crate) fn insert_profiles_overlapping_with_added_mandatory_part<
         //
         // The addition of the mandatory part can lead to an overflow
         let result = checks::overlap_updated_profile(
+            context,
             update_range,
             profile,
             &mut to_add,
@@ -93,6 +101,7 @@ pub(crate) fn insert_profiles_overlapping_with_added_mandatory_part<
         // Check whether there is a new profile before the last overlapping
         // profile
         checks::new_part_after_last_profile(
+            context,
             current_index,
             end_index,
             update_range,
@@ -115,11 +124,16 @@ pub(crate) fn insert_profiles_overlapping_with_added_mandatory_part<
 /// The new mandatory part added by `updated_task` (spanning `update_range`) does not overlap
 /// with any existing profile. This method inserts it at the position of `index_to_insert`
 /// in the `time-table`.
-pub(crate) fn insert_profile_new_mandatory_part<Var: IntegerVariable + 'static>(
-    time_table: &mut OverIntervalTimeTableType<Var>,
+pub(crate) fn insert_profile_new_mandatory_part<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
+    context: PropagationContext,
+    time_table: &mut OverIntervalTimeTableType<Var, PVar, RVar>,
     index_to_insert: usize,
-    update_range: &Range<i32>,
-    updated_task: &Rc<Task<Var>>,
+    update_range: &Range<i64>,
+    updated_task: &Rc<Task<Var, PVar, RVar>>,
 ) {
     pumpkin_assert_moderate!(
         index_to_insert <= time_table.len()
@@ -135,7 +149,7 @@ pub(crate) fn insert_profile_new_mandatory_part<Var: IntegerVariable + 'static>(
             start: update_range.start,
             end: update_range.end - 1,
             profile_tasks: vec![Rc::clone(updated_task)],
-            height: updated_task.resource_usage,
+            height: context.lower_bound(&updated_task.resource_usage),
         },
     );
 }
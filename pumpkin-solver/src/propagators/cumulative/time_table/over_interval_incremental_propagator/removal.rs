@@ -5,6 +5,8 @@ use std::cmp::min;
 use std::ops::Range;
 use std::rc::Rc;
 
+use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::ReadDomains;
 use crate::propagators::OverIntervalTimeTableType;
 use crate::propagators::ResourceProfile;
 use crate::propagators::Task;
@@ -15,12 +17,15 @@ use crate::variables::IntegerVariable;
 /// profiles and adds them to the `time-table` at the correct position.
 pub(crate) fn reduce_profiles_overlapping_with_added_mandatory_part<
     Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
 >(
-    time_table: &mut OverIntervalTimeTableType<Var>,
+    context: PropagationContext,
+    time_table: &mut OverIntervalTimeTableType<Var, PVar, RVar>,
     start_index: usize,
     end_index: usize,
-    update_range: &Range<i32>,
-    updated_task: &Rc<Task<Var>>,
+    update_range: &Range<i64>,
+    updated_task: &Rc<Task<Var, PVar, RVar>>,
 ) {
     let mut to_add = vec![];
 
@@ -37,7 +42,7 @@ pub(crate) fn reduce_profiles_overlapping_with_added_mandatory_part<
 
         // Then we need to add the updated profile due to the overlap between `profile` and
         // `updated_task`
-        overlap_updated_profile(update_range, profile, &mut to_add, updated_task);
+        overlap_updated_profile(context, update_range, profile, &mut to_add, updated_task);
 
         // We need to check whether the last overlapping profile was split
         if index == end_index {
@@ -51,12 +56,17 @@ pub(crate) fn reduce_profiles_overlapping_with_added_mandatory_part<
 }
 
 /// Returns the provided `profile` with the provided `updated_task` removed.
-fn remove_task_from_profile<Var: IntegerVariable + 'static>(
-    updated_task: &Rc<Task<Var>>,
-    start: i32,
-    end: i32,
-    profile: &ResourceProfile<Var>,
-) -> ResourceProfile<Var> {
+fn remove_task_from_profile<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
+    context: PropagationContext,
+    updated_task: &Rc<Task<Var, PVar, RVar>>,
+    start: i64,
+    end: i64,
+    profile: &ResourceProfile<Var, PVar, RVar>,
+) -> ResourceProfile<Var, PVar, RVar> {
     let mut updated_profile_tasks = profile.profile_tasks.clone();
     let _ = updated_profile_tasks.swap_remove(
         updated_profile_tasks
@@ -69,16 +79,20 @@ fn remove_task_from_profile<Var: IntegerVariable + 'static>(
         start,
         end,
         profile_tasks: updated_profile_tasks,
-        height: profile.height - updated_task.resource_usage,
+        height: profile.height - context.lower_bound(&updated_task.resource_usage),
     }
 }
 
 /// If there is a partial overlap, this method creates a profile consisting of the original
 /// profile before the overlap.
-pub(crate) fn split_first_profile<Var: IntegerVariable + 'static>(
-    to_add: &mut Vec<ResourceProfile<Var>>,
-    update_range: &Range<i32>,
-    first_profile: &ResourceProfile<Var>,
+pub(crate) fn split_first_profile<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
+    to_add: &mut Vec<ResourceProfile<Var, PVar, RVar>>,
+    update_range: &Range<i64>,
+    first_profile: &ResourceProfile<Var, PVar, RVar>,
 ) {
     if update_range.start > first_profile.start {
         to_add.push(ResourceProfile {
@@ -90,10 +104,14 @@ pub(crate) fn split_first_profile<Var: IntegerVariable + 'static>(
     }
 }
 
-pub(crate) fn split_last_profile<Var: IntegerVariable + 'static>(
-    to_add: &mut Vec<ResourceProfile<Var>>,
-    update_range: &Range<i32>,
-    last_profile: &ResourceProfile<Var>,
+pub(crate) fn split_last_profile<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
+    to_add: &mut Vec<ResourceProfile<Var, PVar, RVar>>,
+    update_range: &Range<i64>,
+    last_profile: &ResourceProfile<Var, PVar, RVar>,
 ) {
     if last_profile.end >= update_range.end {
         // We are splitting the current profile into one or more parts
@@ -111,13 +129,18 @@ pub(crate) fn split_last_profile<Var: IntegerVariable + 'static>(
 }
 
 /// This method creates a new profile based on the overlap with the provided `profile`.
-pub(crate) fn overlap_updated_profile<Var: IntegerVariable + 'static>(
-    update_range: &Range<i32>,
-    profile: &ResourceProfile<Var>,
-    to_add: &mut Vec<ResourceProfile<Var>>,
-    updated_task: &Rc<Task<Var>>,
+pub(crate) fn overlap_updated_profile<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
+    context: PropagationContext,
+    update_range: &Range<i64>,
+    profile: &ResourceProfile<Var, PVar, RVar>,
+    to_add: &mut Vec<ResourceProfile<Var, PVar, RVar>>,
+    updated_task: &Rc<Task<Var, PVar, RVar>>,
 ) {
-    if profile.height - updated_task.resource_usage == 0 {
+    if profile.height - context.lower_bound(&updated_task.resource_usage) == 0 {
         // If the removal of this task results in an empty profile then we simply do not add it
         return;
     }
@@ -147,6 +170,7 @@ pub(crate) fn overlap_updated_profile<Var: IntegerVariable + 'static>(
         // We thus create a new profile consisting of the combination of
         // the previous profile and the updated task under consideration
         to_add.push(remove_task_from_profile(
+            context,
             updated_task,
             new_profile_lower_bound,
             new_profile_upper_bound,
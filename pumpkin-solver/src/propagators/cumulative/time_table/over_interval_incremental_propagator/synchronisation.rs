@@ -19,17 +19,23 @@ use crate::variables::IntegerVariable;
 /// [`TimeTableOverIntervalPropagator`]; this is the first conflicting profile in terms of start
 /// time, however, the returned profile should be merged with adjacent profiles to create the
 /// returned conflict profile.
-pub(crate) fn find_synchronised_conflict<Var: IntegerVariable + 'static>(
-    time_table: &mut OverIntervalTimeTableType<Var>,
-    parameters: &CumulativeParameters<Var>,
-) -> Option<ResourceProfile<Var>> {
+pub(crate) fn find_synchronised_conflict<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
+>(
+    time_table: &mut OverIntervalTimeTableType<Var, PVar, RVar>,
+    context: PropagationContext,
+    parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
+) -> Option<ResourceProfile<Var, PVar, RVar>> {
     if time_table.is_empty() {
         return None;
     }
 
     let first_conflict_profile_index = time_table
         .iter()
-        .position(|profile| profile.height > parameters.capacity);
+        .position(|profile| profile.height > context.upper_bound(&parameters.capacity));
     if let Some(mut first_conflict_profile_index) = first_conflict_profile_index {
         let mut new_profile = time_table[first_conflict_profile_index].clone();
 
@@ -54,10 +60,13 @@ pub(crate) fn find_synchronised_conflict<Var: IntegerVariable + 'static>(
 /// [`TimeTableOverIntervalPropagator`].
 pub(crate) fn check_synchronisation_conflict_explanation_over_interval<
     Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
 >(
     synchronised_conflict_explanation: &PropagationStatusCP,
     context: PropagationContext,
-    parameters: &CumulativeParameters<Var>,
+    parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
 ) -> bool {
     let error_from_scratch = create_time_table_over_interval_from_scratch(context, parameters);
     if let Err(explanation_scratch) = error_from_scratch {
@@ -77,10 +86,15 @@ pub(crate) fn check_synchronisation_conflict_explanation_over_interval<
 /// by [`TimeTableOverIntervalPropagator`]), this function calculates the error which would have
 /// been reported by [`TimeTableOverIntervalPropagator`] by finding the tasks which should be
 /// included in the profile and sorting them in the same order.
-pub(crate) fn create_synchronised_conflict_explanation<Var: IntegerVariable + 'static>(
+pub(crate) fn create_synchronised_conflict_explanation<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
+>(
     context: PropagationContext,
-    conflicting_profile: &mut ResourceProfile<Var>,
-    parameters: &CumulativeParameters<Var>,
+    conflicting_profile: &mut ResourceProfile<Var, PVar, RVar>,
+    parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
 ) -> PropagationStatusCP {
     // If we need to synchronise then we need to find the conflict profile which
     // would have been found by the non-incremental propagator; we thus first sort based on
@@ -96,9 +110,10 @@ pub(crate) fn create_synchronised_conflict_explanation<Var: IntegerVariable + 's
     let mut new_profile = Vec::new();
 
     // Now we find the tasks in the profile which together overflow the resource
-    while resource_usage <= parameters.capacity {
+    let capacity = context.upper_bound(&parameters.capacity);
+    while resource_usage <= capacity {
         let task = &conflicting_profile.profile_tasks[index];
-        resource_usage += task.resource_usage;
+        resource_usage += context.lower_bound(&task.resource_usage);
         new_profile.push(Rc::clone(task));
         index += 1;
     }
@@ -112,6 +127,7 @@ pub(crate) fn create_synchronised_conflict_explanation<Var: IntegerVariable + 's
             height: resource_usage,
         },
         parameters.options.explanation_type,
+        &parameters.capacity,
     )
     .into())
 }
@@ -120,8 +136,12 @@ pub(crate) fn create_synchronised_conflict_explanation<Var: IntegerVariable + 's
 /// 1. Adjacent profiles are merged which have been split due to the incremental updates
 /// 2. Each profile is sorted such that it corresponds to the order in which
 ///    [`TimeTableOverIntervalPropagator`] would have found them
-pub(crate) fn synchronise_time_table<Var: IntegerVariable + 'static>(
-    time_table: &mut OverIntervalTimeTableType<Var>,
+pub(crate) fn synchronise_time_table<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
+    time_table: &mut OverIntervalTimeTableType<Var, PVar, RVar>,
     context: PropagationContext,
 ) {
     if !time_table.is_empty() {
@@ -138,8 +158,12 @@ pub(crate) fn synchronise_time_table<Var: IntegerVariable + 'static>(
 
 /// Sorts the provided `profile` on non-decreasing order of upper-bound while tie-breaking in
 /// non-decreasing order of ID
-fn sort_profile_based_on_upper_bound_and_id<Var: IntegerVariable + 'static>(
-    profile: &mut ResourceProfile<Var>,
+fn sort_profile_based_on_upper_bound_and_id<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
+    profile: &mut ResourceProfile<Var, PVar, RVar>,
     context: PropagationContext,
 ) {
     profile.profile_tasks.sort_by(|a, b| {
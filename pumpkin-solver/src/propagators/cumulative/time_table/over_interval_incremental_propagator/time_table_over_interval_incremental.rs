@@ -13,6 +13,7 @@ use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
 use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::propagation::ReadDomains;
 use crate::engine::variables::IntegerVariable;
 use crate::engine::IntDomainEvent;
 use crate::predicates::PropositionalConjunction;
@@ -68,16 +69,22 @@ use crate::pumpkin_assert_simple;
 /// \[1\] A. Schutt, Improving scheduling by learning. University of Melbourne, Department of
 /// Computer Science and Software Engineering, 2011.
 #[derive(Clone, Debug)]
-pub(crate) struct TimeTableOverIntervalIncrementalPropagator<Var, const SYNCHRONISE: bool> {
+pub(crate) struct TimeTableOverIntervalIncrementalPropagator<
+    Var,
+    const SYNCHRONISE: bool,
+    PVar,
+    RVar,
+    CVar,
+> {
     /// The key `t` (representing a time-point) holds the mandatory resource consumption of
     /// [`Task`]s at that time (stored in a [`ResourceProfile`]); the [`ResourceProfile`]s are
     /// sorted based on start time and they are assumed to be non-overlapping
-    time_table: OverIntervalTimeTableType<Var>,
+    time_table: OverIntervalTimeTableType<Var, PVar, RVar>,
     /// Stores the input parameters to the cumulative constraint
-    parameters: CumulativeParameters<Var>,
+    parameters: CumulativeParameters<Var, PVar, RVar, CVar>,
     /// Stores structures which change during the search; either to store bounds or when applying
     /// incrementality
-    updatable_structures: UpdatableStructures<Var>,
+    updatable_structures: UpdatableStructures<Var, PVar, RVar>,
     /// Stores whether the propagator found a conflict in the previous call
     ///
     /// This is stored to deal with the case where the same conflict can be created via two
@@ -91,14 +98,19 @@ pub(crate) struct TimeTableOverIntervalIncrementalPropagator<Var, const SYNCHRON
     is_time_table_outdated: bool,
 }
 
-impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool>
-    TimeTableOverIntervalIncrementalPropagator<Var, SYNCHRONISE>
+impl<
+        Var: IntegerVariable + 'static,
+        const SYNCHRONISE: bool,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
+        CVar: IntegerVariable + 'static,
+    > TimeTableOverIntervalIncrementalPropagator<Var, SYNCHRONISE, PVar, RVar, CVar>
 {
     pub(crate) fn new(
-        arg_tasks: &[ArgTask<Var>],
-        capacity: i32,
+        arg_tasks: &[ArgTask<Var, PVar, RVar>],
+        capacity: CVar,
         cumulative_options: CumulativePropagatorOptions,
-    ) -> TimeTableOverIntervalIncrementalPropagator<Var, SYNCHRONISE> {
+    ) -> TimeTableOverIntervalIncrementalPropagator<Var, SYNCHRONISE, PVar, RVar, CVar> {
         let tasks = create_tasks(arg_tasks);
         let parameters = CumulativeParameters::new(tasks, capacity, cumulative_options);
         let updatable_structures = UpdatableStructures::new(&parameters);
@@ -118,7 +130,7 @@ impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool>
         &mut self,
         context: PropagationContext,
         mandatory_part_adjustments: &MandatoryPartAdjustments,
-        task: &Rc<Task<Var>>,
+        task: &Rc<Task<Var, PVar, RVar>>,
     ) -> PropagationStatusCP {
         let mut conflict = None;
         // We consider both of the possible update ranges
@@ -129,12 +141,13 @@ impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool>
             match determine_profiles_to_update(&self.time_table, &update_range) {
                 Ok((start_index, end_index)) => {
                     let result = insertion::insert_profiles_overlapping_with_added_mandatory_part(
+                        context,
                         &mut self.time_table,
                         start_index,
                         end_index,
                         &update_range,
                         task,
-                        self.parameters.capacity,
+                        context.upper_bound(&self.parameters.capacity),
                     );
                     if let Err(conflict_tasks) = result {
                         if conflict.is_none() {
@@ -142,12 +155,14 @@ impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool>
                                 context,
                                 &conflict_tasks,
                                 self.parameters.options.explanation_type,
+                                &self.parameters.capacity,
                             )
                             .into()));
                         }
                     }
                 }
                 Err(index_to_insert) => insertion::insert_profile_new_mandatory_part(
+                    context,
                     &mut self.time_table,
                     index_to_insert,
                     &update_range,
@@ -165,8 +180,9 @@ impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool>
     /// Removes the removed parts in the provided [`MandatoryPartAdjustments`] from the time-table
     fn remove_from_time_table(
         &mut self,
+        context: PropagationContext,
         mandatory_part_adjustments: &MandatoryPartAdjustments,
-        task: &Rc<Task<Var>>,
+        task: &Rc<Task<Var, PVar, RVar>>,
     ) {
         // We consider both of the possible update ranges
         // Note that the upper update range is first considered to avoid any issues with the
@@ -176,6 +192,7 @@ impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool>
             match determine_profiles_to_update(&self.time_table, &update_range) {
                 Ok((start_index, end_index)) => {
                     removal::reduce_profiles_overlapping_with_added_mandatory_part(
+                        context,
                         &mut self.time_table,
                         start_index,
                         end_index,
@@ -221,13 +238,18 @@ impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool>
             let element = self.updatable_structures.get_update_for_task(&updated_task);
 
             // We get the adjustments based on the stored updated
-            let mandatory_part_adjustments = element.get_mandatory_part_adjustments();
+            let mandatory_part_adjustments =
+                element.get_mandatory_part_adjustments(context.as_readonly());
 
             // Then we first remove from the time-table (if necessary)
             //
             // This order ensures that there is less of a chance of incorrect overflows being
             // reported
-            self.remove_from_time_table(&mandatory_part_adjustments, &updated_task);
+            self.remove_from_time_table(
+                context.as_readonly(),
+                &mandatory_part_adjustments,
+                &updated_task,
+            );
 
             // Then we add to the time-table (if necessary)
             //
@@ -253,8 +275,11 @@ impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool>
             if SYNCHRONISE {
                 // If we are synchronising then we need to search for the conflict which would have
                 // been found by the non-incremental propagator
-                let conflicting_profile =
-                    find_synchronised_conflict(&mut self.time_table, &self.parameters);
+                let conflicting_profile = find_synchronised_conflict(
+                    &mut self.time_table,
+                    context.as_readonly(),
+                    &self.parameters,
+                );
                 // Now we need to find the same explanation as would have been found by
                 // the non-incremental propagator
                 if let Some(mut conflicting_profile) = conflicting_profile {
@@ -279,10 +304,9 @@ impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool>
                 self.found_previous_conflict = false;
             } else {
                 // We linearly scan the profiles and find the first one which exceeds the capacity
-                let conflicting_profile = self
-                    .time_table
-                    .iter_mut()
-                    .find(|profile| profile.height > self.parameters.capacity);
+                let conflicting_profile = self.time_table.iter_mut().find(|profile| {
+                    profile.height > context.upper_bound(&self.parameters.capacity)
+                });
 
                 // If we have found such a conflict then we return it
                 if let Some(conflicting_profile) = conflicting_profile {
@@ -301,6 +325,7 @@ impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool>
                         context.as_readonly(),
                         conflicting_profile,
                         self.parameters.options.explanation_type,
+                        &self.parameters.capacity,
                     )
                     .into());
                 }
@@ -321,13 +346,19 @@ impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool>
         pumpkin_assert_extreme!(self
             .time_table
             .iter()
-            .all(|profile| profile.height <= self.parameters.capacity));
+            .all(|profile| profile.height <= context.upper_bound(&self.parameters.capacity)));
         Ok(())
     }
 }
 
-impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool> Propagator
-    for TimeTableOverIntervalIncrementalPropagator<Var, SYNCHRONISE>
+impl<
+        Var: IntegerVariable + 'static,
+        const SYNCHRONISE: bool,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
+        CVar: IntegerVariable + 'static,
+    > Propagator
+    for TimeTableOverIntervalIncrementalPropagator<Var, SYNCHRONISE, PVar, RVar, CVar>
 {
     fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
         pumpkin_assert_advanced!(
@@ -342,7 +373,7 @@ impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool> Propagator
         self.update_time_table(&mut context)?;
 
         pumpkin_assert_extreme!(
-            debug::time_tables_are_the_same_interval::<Var, SYNCHRONISE>(
+            debug::time_tables_are_the_same_interval::<Var, PVar, RVar, CVar, SYNCHRONISE>(
                 context.as_readonly(),
                 &self.time_table,
                 &self.parameters,
@@ -368,6 +399,14 @@ impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool> Propagator
         local_id: LocalId,
         event: OpaqueDomainEvent,
     ) -> EnqueueDecision {
+        if local_id.unpack() as usize >= self.parameters.tasks.len() {
+            // The processing time or resource usage of a task or the capacity has been updated;
+            // we recalculate the time-table from scratch since the mandatory parts of the tasks
+            // could have changed without any of the bounds of the start variables changing
+            self.is_time_table_outdated = true;
+            return EnqueueDecision::Enqueue;
+        }
+
         let updated_task = Rc::clone(&self.parameters.tasks[local_id.unpack() as usize]);
         // Note that we do not take into account the fact that the time-table could be outdated
         // here; the time-table can only become outdated due to backtracking which means that if the
@@ -412,6 +451,13 @@ impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool> Propagator
     ) {
         pumpkin_assert_simple!(self.parameters.options.incremental_backtracking);
 
+        if local_id.unpack() as usize >= self.parameters.tasks.len() {
+            // The processing time or resource usage of a task or the capacity has been
+            // backtracked; the time-table is recalculated from scratch
+            self.is_time_table_outdated = true;
+            return;
+        }
+
         let updated_task = Rc::clone(&self.parameters.tasks[local_id.unpack() as usize]);
 
         backtrack_update(context, &mut self.updatable_structures, &updated_task);
@@ -456,6 +502,10 @@ impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool> Propagator
         "CumulativeTimeTableOverIntervalIncremental"
     }
 
+    fn inference_label(&self) -> &str {
+        "time_table"
+    }
+
     fn initialise_at_root(
         &mut self,
         context: &mut PropagatorInitialisationContext,
@@ -464,6 +514,7 @@ impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool> Propagator
         // enabled
         register_tasks(
             &self.parameters.tasks,
+            &self.parameters.capacity,
             context,
             self.parameters.options.incremental_backtracking,
         );
@@ -500,9 +551,13 @@ impl<Var: IntegerVariable + 'static, const SYNCHRONISE: bool> Propagator
 /// if there are no overlapping profiles
 ///
 /// Note that the lower-bound of the range is inclusive and the upper-bound is exclusive
-fn determine_profiles_to_update<Var: IntegerVariable + 'static>(
-    time_table: &OverIntervalTimeTableType<Var>,
-    update_range: &Range<i32>,
+fn determine_profiles_to_update<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
+    time_table: &OverIntervalTimeTableType<Var, PVar, RVar>,
+    update_range: &Range<i64>,
 ) -> Result<(usize, usize), usize> {
     let overlapping_profile = find_overlapping_profile(time_table, update_range);
 
@@ -569,9 +624,13 @@ fn determine_profiles_to_update<Var: IntegerVariable + 'static>(
 /// [Ok] containing the index of the overlapping profile. If no such element could be found,
 /// it returns [Err] containing the index at which the element should be inserted to
 /// preserve the ordering
-fn find_overlapping_profile<Var: IntegerVariable + 'static>(
-    time_table: &OverIntervalTimeTableType<Var>,
-    update_range: &Range<i32>,
+fn find_overlapping_profile<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
+    time_table: &OverIntervalTimeTableType<Var, PVar, RVar>,
+    update_range: &Range<i64>,
 ) -> Result<usize, usize> {
     time_table.binary_search_by(|profile| {
         if has_overlap_with_interval(
@@ -608,28 +667,31 @@ mod tests {
         let s1 = solver.new_variable(1, 1);
         let s2 = solver.new_variable(1, 8);
 
-        let _ = solver
-            .new_propagator(
-                TimeTableOverIntervalIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: s1,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s2,
-                            processing_time: 3,
-                            resource_usage: 1,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    1,
-                    CumulativePropagatorOptions::default(),
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTableOverIntervalIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions::default(),
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 5);
         assert_eq!(solver.upper_bound(s2), 8);
         assert_eq!(solver.lower_bound(s1), 1);
@@ -642,30 +704,34 @@ mod tests {
         let s1 = solver.new_variable(1, 1);
         let s2 = solver.new_variable(1, 1);
 
-        let result = solver.new_propagator(TimeTableOverIntervalIncrementalPropagator::<
+        let propagator = TimeTableOverIntervalIncrementalPropagator::<
             DomainId,
             false,
+            DomainId,
+            DomainId,
+            DomainId,
         >::new(
             &[
                 ArgTask {
                     start_time: s1,
-                    processing_time: 4,
-                    resource_usage: 1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
                 },
                 ArgTask {
                     start_time: s2,
-                    processing_time: 4,
-                    resource_usage: 1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
                 },
             ]
             .into_iter()
             .collect::<Vec<_>>(),
-            1,
+            solver.new_variable(1, 1),
             CumulativePropagatorOptions {
                 explanation_type: CumulativeExplanationType::Naive,
                 ..Default::default()
             },
-        ));
+        );
+        let result = solver.new_propagator(propagator);
 
         assert!(matches!(result, Err(Inconsistency::Conflict(_))));
         assert!(match result {
@@ -693,28 +759,31 @@ mod tests {
         let s1 = solver.new_variable(0, 6);
         let s2 = solver.new_variable(0, 6);
 
-        let _ = solver
-            .new_propagator(
-                TimeTableOverIntervalIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: s1,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s2,
-                            processing_time: 3,
-                            resource_usage: 1,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    1,
-                    CumulativePropagatorOptions::default(),
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTableOverIntervalIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions::default(),
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 0);
         assert_eq!(solver.upper_bound(s2), 6);
         assert_eq!(solver.lower_bound(s1), 0);
@@ -731,48 +800,51 @@ mod tests {
         let b = solver.new_variable(2, 3);
         let a = solver.new_variable(0, 1);
 
-        let _ = solver
-            .new_propagator(
-                TimeTableOverIntervalIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: a,
-                            processing_time: 2,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: b,
-                            processing_time: 6,
-                            resource_usage: 2,
-                        },
-                        ArgTask {
-                            start_time: c,
-                            processing_time: 2,
-                            resource_usage: 4,
-                        },
-                        ArgTask {
-                            start_time: d,
-                            processing_time: 2,
-                            resource_usage: 2,
-                        },
-                        ArgTask {
-                            start_time: e,
-                            processing_time: 5,
-                            resource_usage: 2,
-                        },
-                        ArgTask {
-                            start_time: f,
-                            processing_time: 6,
-                            resource_usage: 2,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    5,
-                    CumulativePropagatorOptions::default(),
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTableOverIntervalIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: a,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: b,
+                    processing_time: solver.new_variable(6, 6),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: c,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(4, 4),
+                },
+                ArgTask {
+                    start_time: d,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: e,
+                    processing_time: solver.new_variable(5, 5),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: f,
+                    processing_time: solver.new_variable(6, 6),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(5, 5),
+            CumulativePropagatorOptions::default(),
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(f), 10);
     }
 
@@ -782,28 +854,31 @@ mod tests {
         let s1 = solver.new_variable(0, 6);
         let s2 = solver.new_variable(6, 10);
 
-        let propagator = solver
-            .new_propagator(
-                TimeTableOverIntervalIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: s1,
-                            processing_time: 2,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s2,
-                            processing_time: 3,
-                            resource_usage: 1,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    1,
-                    CumulativePropagatorOptions::default(),
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTableOverIntervalIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions::default(),
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 6);
         assert_eq!(solver.upper_bound(s2), 10);
         assert_eq!(solver.lower_bound(s1), 0);
@@ -828,31 +903,34 @@ mod tests {
         let s1 = solver.new_variable(6, 6);
         let s2 = solver.new_variable(1, 8);
 
-        let _ = solver
-            .new_propagator(
-                TimeTableOverIntervalIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: s1,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s2,
-                            processing_time: 3,
-                            resource_usage: 1,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    1,
-                    CumulativePropagatorOptions {
-                        explanation_type: CumulativeExplanationType::Naive,
-                        ..Default::default()
-                    },
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTableOverIntervalIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 1);
         assert_eq!(solver.upper_bound(s2), 3);
         assert_eq!(solver.lower_bound(s1), 6);
@@ -872,48 +950,51 @@ mod tests {
         let b = solver.new_variable(2, 3);
         let a = solver.new_variable(0, 1);
 
-        let propagator = solver
-            .new_propagator(
-                TimeTableOverIntervalIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: a,
-                            processing_time: 2,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: b,
-                            processing_time: 6,
-                            resource_usage: 2,
-                        },
-                        ArgTask {
-                            start_time: c,
-                            processing_time: 2,
-                            resource_usage: 4,
-                        },
-                        ArgTask {
-                            start_time: d,
-                            processing_time: 2,
-                            resource_usage: 2,
-                        },
-                        ArgTask {
-                            start_time: e,
-                            processing_time: 4,
-                            resource_usage: 2,
-                        },
-                        ArgTask {
-                            start_time: f,
-                            processing_time: 6,
-                            resource_usage: 2,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    5,
-                    CumulativePropagatorOptions::default(),
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTableOverIntervalIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: a,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: b,
+                    processing_time: solver.new_variable(6, 6),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: c,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(4, 4),
+                },
+                ArgTask {
+                    start_time: d,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: e,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: f,
+                    processing_time: solver.new_variable(6, 6),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(5, 5),
+            CumulativePropagatorOptions::default(),
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(a), 0);
         assert_eq!(solver.upper_bound(a), 1);
         assert_eq!(solver.lower_bound(b), 2);
@@ -927,7 +1008,7 @@ mod tests {
         assert_eq!(solver.lower_bound(f), 0);
         assert_eq!(solver.upper_bound(f), 14);
 
-        let notification_status = solver.increase_lower_bound_and_notify(propagator, 3, e, 3);
+        let notification_status = solver.increase_lower_bound_and_notify(propagator, 4, e, 3);
         assert!(match notification_status {
             EnqueueDecision::Enqueue => true,
             EnqueueDecision::Skip => false,
@@ -948,53 +1029,56 @@ mod tests {
         let b1 = solver.new_variable(3, 3);
         let a = solver.new_variable(0, 1);
 
-        let propagator = solver
-            .new_propagator(
-                TimeTableOverIntervalIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: a,
-                            processing_time: 2,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: b1,
-                            processing_time: 2,
-                            resource_usage: 2,
-                        },
-                        ArgTask {
-                            start_time: b2,
-                            processing_time: 3,
-                            resource_usage: 2,
-                        },
-                        ArgTask {
-                            start_time: c,
-                            processing_time: 2,
-                            resource_usage: 4,
-                        },
-                        ArgTask {
-                            start_time: d,
-                            processing_time: 2,
-                            resource_usage: 2,
-                        },
-                        ArgTask {
-                            start_time: e,
-                            processing_time: 4,
-                            resource_usage: 2,
-                        },
-                        ArgTask {
-                            start_time: f,
-                            processing_time: 6,
-                            resource_usage: 2,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    5,
-                    CumulativePropagatorOptions::default(),
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTableOverIntervalIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: a,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: b1,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: b2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: c,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(4, 4),
+                },
+                ArgTask {
+                    start_time: d,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: e,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: f,
+                    processing_time: solver.new_variable(6, 6),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(5, 5),
+            CumulativePropagatorOptions::default(),
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(a), 0);
         assert_eq!(solver.upper_bound(a), 1);
         assert_eq!(solver.lower_bound(c), 8);
@@ -1006,7 +1090,7 @@ mod tests {
         assert_eq!(solver.lower_bound(f), 0);
         assert_eq!(solver.upper_bound(f), 14);
 
-        let notification_status = solver.increase_lower_bound_and_notify(propagator, 4, e, 3);
+        let notification_status = solver.increase_lower_bound_and_notify(propagator, 5, e, 3);
         assert!(match notification_status {
             EnqueueDecision::Enqueue => true,
             EnqueueDecision::Skip => false,
@@ -1022,31 +1106,34 @@ mod tests {
         let s1 = solver.new_variable(1, 1);
         let s2 = solver.new_variable(1, 8);
 
-        let _ = solver
-            .new_propagator(
-                TimeTableOverIntervalIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: s1,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s2,
-                            processing_time: 3,
-                            resource_usage: 1,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    1,
-                    CumulativePropagatorOptions {
-                        explanation_type: CumulativeExplanationType::Naive,
-                        ..Default::default()
-                    },
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTableOverIntervalIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 5);
         assert_eq!(solver.upper_bound(s2), 8);
         assert_eq!(solver.lower_bound(s1), 1);
@@ -1063,36 +1150,39 @@ mod tests {
         let s2 = solver.new_variable(5, 5);
         let s3 = solver.new_variable(1, 15);
 
-        let _ = solver
-            .new_propagator(
-                TimeTableOverIntervalIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: s1,
-                            processing_time: 2,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s2,
-                            processing_time: 2,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s3,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    1,
-                    CumulativePropagatorOptions {
-                        explanation_type: CumulativeExplanationType::Naive,
-                        ..Default::default()
-                    },
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTableOverIntervalIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s3,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s3), 7);
         assert_eq!(solver.upper_bound(s3), 15);
         assert_eq!(solver.lower_bound(s2), 5);
@@ -1110,32 +1200,35 @@ mod tests {
         let s1 = solver.new_variable(4, 4);
         let s2 = solver.new_variable(0, 8);
 
-        let _ = solver
-            .new_propagator(
-                TimeTableOverIntervalIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: s1,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s2,
-                            processing_time: 3,
-                            resource_usage: 1,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    1,
-                    CumulativePropagatorOptions {
-                        explanation_type: CumulativeExplanationType::Naive,
-                        allow_holes_in_domain: true,
-                        ..Default::default()
-                    },
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTableOverIntervalIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                allow_holes_in_domain: true,
+                ..Default::default()
+            },
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 0);
         assert_eq!(solver.upper_bound(s2), 8);
         assert_eq!(solver.lower_bound(s1), 4);
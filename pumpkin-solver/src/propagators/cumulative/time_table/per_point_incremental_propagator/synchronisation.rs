@@ -3,6 +3,7 @@ use std::rc::Rc;
 use crate::basic_types::Inconsistency;
 use crate::basic_types::PropagationStatusCP;
 use crate::engine::propagation::PropagationContext;
+use crate::engine::propagation::ReadDomains;
 use crate::propagators::create_time_table_per_point_from_scratch;
 use crate::propagators::cumulative::time_table::propagation_handler::create_conflict_explanation;
 use crate::propagators::CumulativeParameters;
@@ -17,10 +18,13 @@ use crate::variables::IntegerVariable;
 /// [`TimeTablePerPointPropagator`].
 pub(crate) fn check_synchronisation_conflict_explanation_per_point<
     Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
 >(
     synchronised_conflict_explanation: &PropagationStatusCP,
     context: PropagationContext,
-    parameters: &CumulativeParameters<Var>,
+    parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
 ) -> bool {
     let error_from_scratch = create_time_table_per_point_from_scratch(context, parameters);
     if let Err(explanation_scratch) = error_from_scratch {
@@ -39,16 +43,23 @@ pub(crate) fn check_synchronisation_conflict_explanation_per_point<
 /// Finds the conflicting profile which would have been found by the
 /// [`TimeTablePerPointPropagator`]; this is the conflicting profile which has the minimum maximum
 /// ID in set of the first `n` profile tasks (when sorted on ID) which overflow the capacity
-pub(crate) fn find_synchronised_conflict<Var: IntegerVariable + 'static>(
-    time_table: &mut PerPointTimeTableType<Var>,
-    parameters: &CumulativeParameters<Var>,
+pub(crate) fn find_synchronised_conflict<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
+>(
+    time_table: &mut PerPointTimeTableType<Var, PVar, RVar>,
+    context: PropagationContext,
+    parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
 ) -> Option<u32> {
+    let capacity = context.upper_bound(&parameters.capacity);
     let mut profile_time_point = None;
     let mut minimum_maximum_id = u32::MAX;
 
     // We go over every profile
     for (time_point, profile) in time_table.iter_mut() {
-        if profile.height <= parameters.capacity {
+        if profile.height <= capacity {
             // If the profile cannot overflow the resource capacity then we move onto the next
             // profile
             continue;
@@ -58,10 +69,14 @@ pub(crate) fn find_synchronised_conflict<Var: IntegerVariable + 'static>(
         // is overflown and we get the last element in this set (which has the one with the maximum
         // ID since the profile is sorted in the method based on ID)
         let mut new_height = 0;
-        let conflicting_tasks =
-            get_minimum_set_of_tasks_which_overflow_capacity(profile, parameters, &mut new_height);
+        let conflicting_tasks = get_minimum_set_of_tasks_which_overflow_capacity(
+            profile,
+            context,
+            parameters,
+            &mut new_height,
+        );
         if let Some(task_with_maximum_id) = conflicting_tasks.last() {
-            pumpkin_assert_moderate!(new_height > parameters.capacity);
+            pumpkin_assert_moderate!(new_height > capacity);
             if task_with_maximum_id.id.unpack() < minimum_maximum_id {
                 minimum_maximum_id = task_with_maximum_id.id.unpack();
                 profile_time_point = Some(*time_point);
@@ -76,25 +91,34 @@ pub(crate) fn find_synchronised_conflict<Var: IntegerVariable + 'static>(
 ///
 /// The sum of the heights of the tasks is stored in the provided `output_height`; note that this
 /// means that the iterator should be consumed before reading the `output_height`
-fn get_minimum_set_of_tasks_which_overflow_capacity<'a, Var: IntegerVariable + 'static>(
-    profile: &'a mut ResourceProfile<Var>,
-    parameters: &'a CumulativeParameters<Var>,
-    output_height: &'a mut i32,
-) -> impl Iterator<Item = Rc<Task<Var>>> + 'a {
+fn get_minimum_set_of_tasks_which_overflow_capacity<
+    'a,
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
+>(
+    profile: &'a mut ResourceProfile<Var, PVar, RVar>,
+    context: PropagationContext<'a>,
+    parameters: &'a CumulativeParameters<Var, PVar, RVar, CVar>,
+    output_height: &'a mut i64,
+) -> impl Iterator<Item = Rc<Task<Var, PVar, RVar>>> + 'a {
+    let capacity = context.upper_bound(&parameters.capacity);
+
     // First we sort the profile based on the ID
     sort_profile_based_on_id(profile);
 
     // We go over each task and take while the resource usage is less than or equal to the
     // capacity
-    let calculate_tasks = |resource_usage: &'a mut i32| {
+    let calculate_tasks = |resource_usage: &'a mut i64| {
         profile
             .profile_tasks
             .iter()
             .take_while(move |task| {
-                if *resource_usage > parameters.capacity {
+                if *resource_usage > capacity {
                     return false;
                 }
-                *resource_usage += task.resource_usage;
+                *resource_usage += context.lower_bound(&task.resource_usage);
                 true
             })
             .cloned()
@@ -106,10 +130,15 @@ fn get_minimum_set_of_tasks_which_overflow_capacity<'a, Var: IntegerVariable + '
 /// by [`TimeTablePerPointPropagator`]), this function calculates the error which would have been
 /// reported by [`TimeTablePerPointPropagator`] by finding the tasks which should be included in the
 /// profile and sorting them in the same order.
-pub(crate) fn create_synchronised_conflict_explanation<Var: IntegerVariable + 'static>(
+pub(crate) fn create_synchronised_conflict_explanation<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
+>(
     context: PropagationContext,
-    conflicting_profile: &mut ResourceProfile<Var>,
-    parameters: &CumulativeParameters<Var>,
+    conflicting_profile: &mut ResourceProfile<Var, PVar, RVar>,
+    parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
 ) -> PropagationStatusCP {
     // Store because we are mutably borrowing the conflicting profile
     let new_profile_start = conflicting_profile.start;
@@ -121,6 +150,7 @@ pub(crate) fn create_synchronised_conflict_explanation<Var: IntegerVariable + 's
     // we thus sort on the IDs and take the first `n` tasks which lead to an overflow
     let new_profile = get_minimum_set_of_tasks_which_overflow_capacity(
         conflicting_profile,
+        context,
         parameters,
         &mut new_height,
     )
@@ -135,6 +165,7 @@ pub(crate) fn create_synchronised_conflict_explanation<Var: IntegerVariable + 's
             height: new_height,
         },
         parameters.options.explanation_type,
+        &parameters.capacity,
     )
     .into())
 }
@@ -142,14 +173,25 @@ pub(crate) fn create_synchronised_conflict_explanation<Var: IntegerVariable + 's
 /// Synchronises the time-table; one action is performed:
 /// 1. Each profile is sorted such that it corresponds to the order in which
 ///    [`TimeTableOverIntervalPropagator`] would have found them
-pub(crate) fn synchronise_time_table<'a, Var: IntegerVariable + 'static>(
-    time_table: impl Iterator<Item = &'a mut ResourceProfile<Var>>,
+pub(crate) fn synchronise_time_table<
+    'a,
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
+    time_table: impl Iterator<Item = &'a mut ResourceProfile<Var, PVar, RVar>>,
 ) {
     time_table.for_each(|profile| sort_profile_based_on_id(profile))
 }
 
 /// Sorts the provided `profile` on non-decreasing order of ID
-fn sort_profile_based_on_id<Var: IntegerVariable + 'static>(profile: &mut ResourceProfile<Var>) {
+fn sort_profile_based_on_id<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
+    profile: &mut ResourceProfile<Var, PVar, RVar>,
+) {
     profile
         .profile_tasks
         .sort_by(|a, b| a.id.unpack().cmp(&b.id.unpack()));
@@ -161,6 +203,7 @@ mod tests {
 
     use super::find_synchronised_conflict;
     use crate::engine::propagation::LocalId;
+    use crate::engine::propagation::PropagationContext;
     use crate::engine::test_solver::TestSolver;
     use crate::propagators::CumulativeParameters;
     use crate::propagators::CumulativePropagatorOptions;
@@ -179,26 +222,27 @@ mod tests {
         let tasks = vec![
             Task {
                 start_variable: x0,
-                processing_time: 2,
-                resource_usage: 2,
+                processing_time: solver.new_variable(2, 2),
+                resource_usage: solver.new_variable(2, 2),
                 id: LocalId::from(0),
             },
             Task {
                 start_variable: x1,
-                processing_time: 2,
-                resource_usage: 2,
+                processing_time: solver.new_variable(2, 2),
+                resource_usage: solver.new_variable(2, 2),
                 id: LocalId::from(1),
             },
             Task {
                 start_variable: x2,
-                processing_time: 2,
-                resource_usage: 1,
+                processing_time: solver.new_variable(2, 2),
+                resource_usage: solver.new_variable(1, 1),
                 id: LocalId::from(2),
             },
         ];
 
+        let capacity = solver.new_variable(1, 1);
         let parameters =
-            CumulativeParameters::new(tasks, 1, CumulativePropagatorOptions::default());
+            CumulativeParameters::new(tasks, capacity, CumulativePropagatorOptions::default());
 
         let mut time_table = PerPointTimeTableType::default();
         let _ = time_table.insert(
@@ -223,7 +267,11 @@ mod tests {
             },
         );
 
-        let result = find_synchronised_conflict(&mut time_table, &parameters);
+        let result = find_synchronised_conflict(
+            &mut time_table,
+            PropagationContext::new(&solver.assignments),
+            &parameters,
+        );
         assert!(matches!(result, Some(4)));
     }
 }
@@ -12,6 +12,7 @@ use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
 use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::propagation::ReadDomains;
 use crate::engine::variables::IntegerVariable;
 use crate::engine::IntDomainEvent;
 use crate::predicates::PropositionalConjunction;
@@ -64,16 +65,22 @@ use crate::pumpkin_assert_extreme;
 /// Computer Science and Software Engineering, 2011.
 #[derive(Debug)]
 
-pub(crate) struct TimeTablePerPointIncrementalPropagator<Var, const SYNCHRONISE: bool> {
+pub(crate) struct TimeTablePerPointIncrementalPropagator<
+    Var,
+    const SYNCHRONISE: bool,
+    PVar,
+    RVar,
+    CVar,
+> {
     /// The key `t` (representing a time-point) holds the mandatory resource consumption of
     /// [`Task`]s at that time (stored in a [`ResourceProfile`]); the [`ResourceProfile`]s are
     /// sorted based on start time and they are assumed to be non-overlapping
-    time_table: PerPointTimeTableType<Var>,
+    time_table: PerPointTimeTableType<Var, PVar, RVar>,
     /// Stores the input parameters to the cumulative constraint
-    parameters: CumulativeParameters<Var>,
+    parameters: CumulativeParameters<Var, PVar, RVar, CVar>,
     /// Stores structures which change during the search; either to store bounds or when applying
     /// incrementality
-    updatable_structures: UpdatableStructures<Var>,
+    updatable_structures: UpdatableStructures<Var, PVar, RVar>,
     /// Stores whether the propagator found a conflict in the previous call
     ///
     /// This is stored to deal with the case where the same conflict can be created via two
@@ -87,14 +94,19 @@ pub(crate) struct TimeTablePerPointIncrementalPropagator<Var, const SYNCHRONISE:
     is_time_table_outdated: bool,
 }
 
-impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool>
-    TimeTablePerPointIncrementalPropagator<Var, SYNCHRONISE>
+impl<
+        Var: IntegerVariable + 'static + Debug,
+        const SYNCHRONISE: bool,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
+        CVar: IntegerVariable + 'static,
+    > TimeTablePerPointIncrementalPropagator<Var, SYNCHRONISE, PVar, RVar, CVar>
 {
     pub(crate) fn new(
-        arg_tasks: &[ArgTask<Var>],
-        capacity: i32,
+        arg_tasks: &[ArgTask<Var, PVar, RVar>],
+        capacity: CVar,
         cumulative_options: CumulativePropagatorOptions,
-    ) -> TimeTablePerPointIncrementalPropagator<Var, SYNCHRONISE> {
+    ) -> TimeTablePerPointIncrementalPropagator<Var, SYNCHRONISE, PVar, RVar, CVar> {
         let tasks = create_tasks(arg_tasks);
         let parameters = CumulativeParameters::new(tasks, capacity, cumulative_options);
         let updatable_structures = UpdatableStructures::new(&parameters);
@@ -113,7 +125,7 @@ impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool>
         &mut self,
         context: PropagationContext,
         mandatory_part_adjustments: &MandatoryPartAdjustments,
-        task: &Rc<Task<Var>>,
+        task: &Rc<Task<Var, PVar, RVar>>,
     ) -> PropagationStatusCP {
         // Go over all of the updated tasks and calculate the added mandatory part (we know
         // that for each of these tasks, a mandatory part exists, otherwise it would not
@@ -127,20 +139,23 @@ impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool>
                         "Attempted to insert mandatory part where it already exists at time point {time_point} for task {} in time-table per time-point propagator\n", task.id.unpack() as usize);
 
             // Add the updated profile to the ResourceProfile at time t
-            let current_profile: &mut ResourceProfile<Var> = self
+            let current_profile: &mut ResourceProfile<Var, PVar, RVar> = self
                 .time_table
                 .entry(time_point as u32)
                 .or_insert(ResourceProfile::default(time_point));
 
-            current_profile.height += task.resource_usage;
+            current_profile.height += context.lower_bound(&task.resource_usage);
             current_profile.profile_tasks.push(Rc::clone(task));
 
-            if current_profile.height > self.parameters.capacity && conflict.is_none() {
+            if current_profile.height > context.upper_bound(&self.parameters.capacity)
+                && conflict.is_none()
+            {
                 // The newly introduced mandatory part(s) caused an overflow of the resource
                 conflict = Some(Err(create_conflict_explanation(
                     context,
                     current_profile,
                     self.parameters.options.explanation_type,
+                    &self.parameters.capacity,
                 )
                 .into()));
             }
@@ -157,9 +172,11 @@ impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool>
     /// Removes the removed parts in the provided [`MandatoryPartAdjustments`] from the time-table
     fn remove_from_time_table(
         &mut self,
+        context: PropagationContext,
         mandatory_part_adjustments: &MandatoryPartAdjustments,
-        task: &Rc<Task<Var>>,
+        task: &Rc<Task<Var, PVar, RVar>>,
     ) {
+        let resource_usage = context.lower_bound(&task.resource_usage);
         for time_point in mandatory_part_adjustments.get_removed_parts().flatten() {
             pumpkin_assert_extreme!(
                         self.time_table.contains_key(&(time_point as u32)) && self.time_table.get(&(time_point as u32)).unwrap().profile_tasks.iter().any(|profile_task| profile_task.id.unpack() as usize == task.id.unpack() as usize) ,
@@ -171,7 +188,7 @@ impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool>
                     .entry(time_point as u32)
                     .and_modify(|profile| {
                         // We remove the resource usage of the task from the height of the profile
-                        profile.height -= task.resource_usage;
+                        profile.height -= resource_usage;
 
                         // If the height of the profile is not equal to 0 then we remove the task
                         // from the profile tasks
@@ -224,13 +241,18 @@ impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool>
             let element = self.updatable_structures.get_update_for_task(&updated_task);
 
             // We get the adjustments based on the stored updated
-            let mandatory_part_adjustments = element.get_mandatory_part_adjustments();
+            let mandatory_part_adjustments =
+                element.get_mandatory_part_adjustments(context.as_readonly());
 
             // Then we first remove from the time-table (if necessary)
             //
             // This order ensures that there is less of a chance of incorrect overflows being
             // reported
-            self.remove_from_time_table(&mandatory_part_adjustments, &updated_task);
+            self.remove_from_time_table(
+                context.as_readonly(),
+                &mandatory_part_adjustments,
+                &updated_task,
+            );
 
             // Then we add to the time-table (if necessary)
             //
@@ -256,8 +278,11 @@ impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool>
             if SYNCHRONISE {
                 // If we are synchronising then we need to search for the conflict which would have
                 // been found by the non-incremental propagator
-                let synchronised_conflict =
-                    find_synchronised_conflict(&mut self.time_table, &self.parameters);
+                let synchronised_conflict = find_synchronised_conflict(
+                    &mut self.time_table,
+                    context.as_readonly(),
+                    &self.parameters,
+                );
 
                 // After finding the profile which would have been found by the non-incremental
                 // propagator, we also need to find the profile explanation which would have been
@@ -293,10 +318,9 @@ impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool>
                 self.found_previous_conflict = false;
             } else {
                 // We linearly scan the profiles and find the first one which exceeds the capacity
-                let conflicting_profile = self
-                    .time_table
-                    .values_mut()
-                    .find(|profile| profile.height > self.parameters.capacity);
+                let conflicting_profile = self.time_table.values_mut().find(|profile| {
+                    profile.height > context.upper_bound(&self.parameters.capacity)
+                });
 
                 // If we have found such a conflict then we return it
                 if let Some(conflicting_profile) = conflicting_profile {
@@ -315,6 +339,7 @@ impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool>
                         context.as_readonly(),
                         conflicting_profile,
                         self.parameters.options.explanation_type,
+                        &self.parameters.capacity,
                     )
                     .into());
                 }
@@ -335,13 +360,18 @@ impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool>
         pumpkin_assert_extreme!(self
             .time_table
             .values()
-            .all(|profile| profile.height <= self.parameters.capacity));
+            .all(|profile| profile.height <= context.upper_bound(&self.parameters.capacity)));
         Ok(())
     }
 }
 
-impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool> Propagator
-    for TimeTablePerPointIncrementalPropagator<Var, SYNCHRONISE>
+impl<
+        Var: IntegerVariable + 'static + Debug,
+        const SYNCHRONISE: bool,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
+        CVar: IntegerVariable + 'static,
+    > Propagator for TimeTablePerPointIncrementalPropagator<Var, SYNCHRONISE, PVar, RVar, CVar>
 {
     fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
         pumpkin_assert_advanced!(
@@ -356,10 +386,14 @@ impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool> Propagator
         // We update the time-table based on the stored updates
         self.update_time_table(&mut context)?;
 
-        pumpkin_assert_extreme!(debug::time_tables_are_the_same_point::<Var, SYNCHRONISE>(
-            context.as_readonly(),
-            &self.time_table,
-            &self.parameters
+        pumpkin_assert_extreme!(debug::time_tables_are_the_same_point::<
+            Var,
+            PVar,
+            RVar,
+            CVar,
+            SYNCHRONISE,
+        >(
+            context.as_readonly(), &self.time_table, &self.parameters
         ));
 
         // We pass the entirety of the table to check due to the fact that the propagation of the
@@ -380,6 +414,14 @@ impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool> Propagator
         local_id: LocalId,
         event: OpaqueDomainEvent,
     ) -> EnqueueDecision {
+        if local_id.unpack() as usize >= self.parameters.tasks.len() {
+            // The processing time or resource usage of a task or the capacity has been updated;
+            // we recalculate the time-table from scratch since the mandatory parts of the tasks
+            // could have changed without any of the bounds of the start variables changing
+            self.is_time_table_outdated = true;
+            return EnqueueDecision::Enqueue;
+        }
+
         let updated_task = Rc::clone(&self.parameters.tasks[local_id.unpack() as usize]);
         // Note that we do not take into account the fact that the time-table could be outdated
         // here; the time-table can only become outdated due to backtracking which means that if the
@@ -422,6 +464,13 @@ impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool> Propagator
         local_id: LocalId,
         event: OpaqueDomainEvent,
     ) {
+        if local_id.unpack() as usize >= self.parameters.tasks.len() {
+            // The processing time or resource usage of a task or the capacity has been
+            // backtracked; the time-table is recalculated from scratch
+            self.is_time_table_outdated = true;
+            return;
+        }
+
         let updated_task = Rc::clone(&self.parameters.tasks[local_id.unpack() as usize]);
 
         backtrack_update(context, &mut self.updatable_structures, &updated_task);
@@ -466,11 +515,20 @@ impl<Var: IntegerVariable + 'static + Debug, const SYNCHRONISE: bool> Propagator
         "CumulativeTimeTablePerPointIncremental"
     }
 
+    fn inference_label(&self) -> &str {
+        "time_table"
+    }
+
     fn initialise_at_root(
         &mut self,
         context: &mut PropagatorInitialisationContext,
     ) -> Result<(), PropositionalConjunction> {
-        register_tasks(&self.parameters.tasks, context, true);
+        register_tasks(
+            &self.parameters.tasks,
+            &self.parameters.capacity,
+            context,
+            true,
+        );
         self.updatable_structures
             .reset_all_bounds_and_remove_fixed(context.as_readonly(), &self.parameters);
 
@@ -513,11 +571,14 @@ mod debug {
     ///        the same!
     pub(crate) fn time_tables_are_the_same_point<
         Var: IntegerVariable + 'static,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
+        CVar: IntegerVariable + 'static,
         const SYNCHRONISE: bool,
     >(
         context: PropagationContext,
-        time_table: &PerPointTimeTableType<Var>,
-        parameters: &CumulativeParameters<Var>,
+        time_table: &PerPointTimeTableType<Var, PVar, RVar>,
+        parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
     ) -> bool {
         let time_table_scratch = create_time_table_per_point_from_scratch(context, parameters)
             .expect("Expected no error");
@@ -581,28 +642,31 @@ mod tests {
         let s1 = solver.new_variable(1, 1);
         let s2 = solver.new_variable(1, 8);
 
-        let _ = solver
-            .new_propagator(
-                TimeTablePerPointIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: s1,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s2,
-                            processing_time: 3,
-                            resource_usage: 1,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    1,
-                    CumulativePropagatorOptions::default(),
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTablePerPointIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions::default(),
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 5);
         assert_eq!(solver.upper_bound(s2), 8);
         assert_eq!(solver.lower_bound(s1), 1);
@@ -615,29 +679,34 @@ mod tests {
         let s1 = solver.new_variable(1, 1);
         let s2 = solver.new_variable(1, 1);
 
-        let result = solver.new_propagator(
-            TimeTablePerPointIncrementalPropagator::<DomainId, false>::new(
-                &[
-                    ArgTask {
-                        start_time: s1,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                1,
-                CumulativePropagatorOptions {
-                    explanation_type: CumulativeExplanationType::Naive,
-                    ..Default::default()
+        let propagator = TimeTablePerPointIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
                 },
-            ),
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
         );
+        let result = solver.new_propagator(propagator);
         assert!(match result {
             Err(Inconsistency::Conflict(x)) => {
                 let expected = [
@@ -661,28 +730,31 @@ mod tests {
         let s1 = solver.new_variable(0, 6);
         let s2 = solver.new_variable(0, 6);
 
-        let _ = solver
-            .new_propagator(
-                TimeTablePerPointIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: s1,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s2,
-                            processing_time: 3,
-                            resource_usage: 1,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    1,
-                    CumulativePropagatorOptions::default(),
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTablePerPointIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions::default(),
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 0);
         assert_eq!(solver.upper_bound(s2), 6);
         assert_eq!(solver.lower_bound(s1), 0);
@@ -699,48 +771,51 @@ mod tests {
         let b = solver.new_variable(2, 3);
         let a = solver.new_variable(0, 1);
 
-        let _ = solver
-            .new_propagator(
-                TimeTablePerPointIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: a,
-                            processing_time: 2,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: b,
-                            processing_time: 6,
-                            resource_usage: 2,
-                        },
-                        ArgTask {
-                            start_time: c,
-                            processing_time: 2,
-                            resource_usage: 4,
-                        },
-                        ArgTask {
-                            start_time: d,
-                            processing_time: 2,
-                            resource_usage: 2,
-                        },
-                        ArgTask {
-                            start_time: e,
-                            processing_time: 5,
-                            resource_usage: 2,
-                        },
-                        ArgTask {
-                            start_time: f,
-                            processing_time: 6,
-                            resource_usage: 2,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    5,
-                    CumulativePropagatorOptions::default(),
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTablePerPointIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: a,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: b,
+                    processing_time: solver.new_variable(6, 6),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: c,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(4, 4),
+                },
+                ArgTask {
+                    start_time: d,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: e,
+                    processing_time: solver.new_variable(5, 5),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: f,
+                    processing_time: solver.new_variable(6, 6),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(5, 5),
+            CumulativePropagatorOptions::default(),
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(f), 10);
     }
 
@@ -750,28 +825,31 @@ mod tests {
         let s1 = solver.new_variable(0, 6);
         let s2 = solver.new_variable(6, 10);
 
-        let propagator = solver
-            .new_propagator(
-                TimeTablePerPointIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: s1,
-                            processing_time: 2,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s2,
-                            processing_time: 3,
-                            resource_usage: 1,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    1,
-                    CumulativePropagatorOptions::default(),
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTablePerPointIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions::default(),
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 6);
         assert_eq!(solver.upper_bound(s2), 10);
         assert_eq!(solver.lower_bound(s1), 0);
@@ -796,31 +874,34 @@ mod tests {
         let s1 = solver.new_variable(6, 6);
         let s2 = solver.new_variable(1, 8);
 
-        let propagator = solver
-            .new_propagator(
-                TimeTablePerPointIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: s1,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s2,
-                            processing_time: 3,
-                            resource_usage: 1,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    1,
-                    CumulativePropagatorOptions {
-                        explanation_type: CumulativeExplanationType::Naive,
-                        ..Default::default()
-                    },
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTablePerPointIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
         let result = solver.propagate_until_fixed_point(propagator);
         assert!(result.is_ok());
         assert_eq!(solver.lower_bound(s2), 1);
@@ -842,48 +923,51 @@ mod tests {
         let b = solver.new_variable(2, 3);
         let a = solver.new_variable(0, 1);
 
-        let propagator = solver
-            .new_propagator(
-                TimeTablePerPointIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: a,
-                            processing_time: 2,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: b,
-                            processing_time: 6,
-                            resource_usage: 2,
-                        },
-                        ArgTask {
-                            start_time: c,
-                            processing_time: 2,
-                            resource_usage: 4,
-                        },
-                        ArgTask {
-                            start_time: d,
-                            processing_time: 2,
-                            resource_usage: 2,
-                        },
-                        ArgTask {
-                            start_time: e,
-                            processing_time: 4,
-                            resource_usage: 2,
-                        },
-                        ArgTask {
-                            start_time: f,
-                            processing_time: 6,
-                            resource_usage: 2,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    5,
-                    CumulativePropagatorOptions::default(),
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTablePerPointIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: a,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: b,
+                    processing_time: solver.new_variable(6, 6),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: c,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(4, 4),
+                },
+                ArgTask {
+                    start_time: d,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: e,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: f,
+                    processing_time: solver.new_variable(6, 6),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(5, 5),
+            CumulativePropagatorOptions::default(),
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(a), 0);
         assert_eq!(solver.upper_bound(a), 1);
         assert_eq!(solver.lower_bound(b), 2);
@@ -897,7 +981,7 @@ mod tests {
         assert_eq!(solver.lower_bound(f), 0);
         assert_eq!(solver.upper_bound(f), 14);
 
-        let notification_status = solver.increase_lower_bound_and_notify(propagator, 3, e, 3);
+        let notification_status = solver.increase_lower_bound_and_notify(propagator, 4, e, 3);
         assert!(match notification_status {
             EnqueueDecision::Enqueue => true,
             EnqueueDecision::Skip => false,
@@ -918,53 +1002,56 @@ mod tests {
         let b1 = solver.new_variable(3, 3);
         let a = solver.new_variable(0, 1);
 
-        let propagator = solver
-            .new_propagator(
-                TimeTablePerPointIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: a,
-                            processing_time: 2,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: b1,
-                            processing_time: 2,
-                            resource_usage: 2,
-                        },
-                        ArgTask {
-                            start_time: b2,
-                            processing_time: 3,
-                            resource_usage: 2,
-                        },
-                        ArgTask {
-                            start_time: c,
-                            processing_time: 2,
-                            resource_usage: 4,
-                        },
-                        ArgTask {
-                            start_time: d,
-                            processing_time: 2,
-                            resource_usage: 2,
-                        },
-                        ArgTask {
-                            start_time: e,
-                            processing_time: 4,
-                            resource_usage: 2,
-                        },
-                        ArgTask {
-                            start_time: f,
-                            processing_time: 6,
-                            resource_usage: 2,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    5,
-                    CumulativePropagatorOptions::default(),
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTablePerPointIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: a,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: b1,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: b2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: c,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(4, 4),
+                },
+                ArgTask {
+                    start_time: d,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: e,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: f,
+                    processing_time: solver.new_variable(6, 6),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(5, 5),
+            CumulativePropagatorOptions::default(),
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(a), 0);
         assert_eq!(solver.upper_bound(a), 1);
         assert_eq!(solver.lower_bound(c), 8);
@@ -976,7 +1063,7 @@ mod tests {
         assert_eq!(solver.lower_bound(f), 0);
         assert_eq!(solver.upper_bound(f), 14);
 
-        let notification_status = solver.increase_lower_bound_and_notify(propagator, 4, e, 3);
+        let notification_status = solver.increase_lower_bound_and_notify(propagator, 5, e, 3);
         assert!(match notification_status {
             EnqueueDecision::Enqueue => true,
             EnqueueDecision::Skip => false,
@@ -986,37 +1073,86 @@ mod tests {
         assert_eq!(solver.lower_bound(f), 10);
     }
 
+    #[test]
+    fn propagator_recalculates_time_table_after_resource_usage_update() {
+        let mut solver = TestSolver::default();
+        let s1 = solver.new_variable(1, 1);
+        let s2 = solver.new_variable(1, 8);
+        let r1 = solver.new_variable(0, 1);
+        let r2 = solver.new_variable(1, 1);
+
+        let propagator = TimeTablePerPointIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: r1,
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: r2,
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions::default(),
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
+        // The first task does not have a mandatory part since it could have a resource usage of 0
+        assert_eq!(solver.lower_bound(s2), 1);
+
+        // The resource usages are registered with the local ids `2n..3n`
+        let notification_status = solver.increase_lower_bound_and_notify(propagator, 4, r1, 1);
+        assert!(matches!(notification_status, EnqueueDecision::Enqueue));
+
+        let result = solver.propagate(propagator);
+        assert!(result.is_ok());
+        assert_eq!(solver.lower_bound(s2), 5);
+        assert_eq!(solver.upper_bound(s2), 8);
+    }
+
     #[test]
     fn propagator_propagates_from_profile_reason() {
         let mut solver = TestSolver::default();
         let s1 = solver.new_variable(1, 1);
         let s2 = solver.new_variable(1, 8);
 
-        let _ = solver
-            .new_propagator(
-                TimeTablePerPointIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: s1,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s2,
-                            processing_time: 3,
-                            resource_usage: 1,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    1,
-                    CumulativePropagatorOptions {
-                        explanation_type: CumulativeExplanationType::Naive,
-                        ..Default::default()
-                    },
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTablePerPointIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 5);
         assert_eq!(solver.upper_bound(s2), 8);
         assert_eq!(solver.lower_bound(s1), 1);
@@ -1043,36 +1179,39 @@ mod tests {
         let s2 = solver.new_variable(5, 5);
         let s3 = solver.new_variable(1, 15);
 
-        let _ = solver
-            .new_propagator(
-                TimeTablePerPointIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: s1,
-                            processing_time: 2,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s2,
-                            processing_time: 2,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s3,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    1,
-                    CumulativePropagatorOptions {
-                        explanation_type: CumulativeExplanationType::Naive,
-                        ..Default::default()
-                    },
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTablePerPointIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s3,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s3), 7);
         assert_eq!(solver.upper_bound(s3), 15);
         assert_eq!(solver.lower_bound(s2), 5);
@@ -1097,32 +1236,35 @@ mod tests {
         let s1 = solver.new_variable(4, 4);
         let s2 = solver.new_variable(0, 8);
 
-        let _ = solver
-            .new_propagator(
-                TimeTablePerPointIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: s1,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s2,
-                            processing_time: 3,
-                            resource_usage: 1,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    1,
-                    CumulativePropagatorOptions {
-                        explanation_type: CumulativeExplanationType::Naive,
-                        allow_holes_in_domain: true,
-                        ..Default::default()
-                    },
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTablePerPointIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                allow_holes_in_domain: true,
+                ..Default::default()
+            },
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 0);
         assert_eq!(solver.upper_bound(s2), 8);
         assert_eq!(solver.lower_bound(s1), 4);
@@ -1141,33 +1283,34 @@ mod tests {
         let s1_scratch = solver_scratch.new_variable(5, 5);
         let s2_scratch = solver_scratch.new_variable(1, 10);
         let s3_scratch = solver_scratch.new_variable(1, 10);
+        let propagator_scratch = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1_scratch,
+                    processing_time: solver_scratch.new_variable(2, 2),
+                    resource_usage: solver_scratch.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2_scratch,
+                    processing_time: solver_scratch.new_variable(4, 4),
+                    resource_usage: solver_scratch.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s3_scratch,
+                    processing_time: solver_scratch.new_variable(4, 4),
+                    resource_usage: solver_scratch.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver_scratch.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
         let propagator_scratch = solver_scratch
-            .new_propagator(TimeTablePerPointPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: s1_scratch,
-                        processing_time: 2,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2_scratch,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s3_scratch,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                1,
-                CumulativePropagatorOptions {
-                    explanation_type: CumulativeExplanationType::Naive,
-                    ..Default::default()
-                },
-            ))
+            .new_propagator(propagator_scratch)
             .expect("No conflict");
         let _ =
             solver_scratch.increase_lower_bound_and_notify(propagator_scratch, 1, s2_scratch, 7);
@@ -1179,36 +1322,39 @@ mod tests {
         let s1 = solver.new_variable(5, 5);
         let s2 = solver.new_variable(1, 10);
         let s3 = solver.new_variable(1, 10);
-        let propagator = solver
-            .new_propagator(
-                TimeTablePerPointIncrementalPropagator::<DomainId, true>::new(
-                    &[
-                        ArgTask {
-                            start_time: s1,
-                            processing_time: 2,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s2,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s3,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    1,
-                    CumulativePropagatorOptions {
-                        explanation_type: CumulativeExplanationType::Naive,
-                        ..Default::default()
-                    },
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTablePerPointIncrementalPropagator::<
+            DomainId,
+            true,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s3,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
         let _ = solver.increase_lower_bound_and_notify(propagator, 2, s3, 7);
         let _ = solver.increase_lower_bound_and_notify(propagator, 1, s2, 7);
         let result = solver.propagate(propagator);
@@ -1238,33 +1384,34 @@ mod tests {
         let s1_scratch = solver_scratch.new_variable(5, 5);
         let s2_scratch = solver_scratch.new_variable(1, 10);
         let s3_scratch = solver_scratch.new_variable(1, 10);
+        let propagator_scratch = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1_scratch,
+                    processing_time: solver_scratch.new_variable(2, 2),
+                    resource_usage: solver_scratch.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2_scratch,
+                    processing_time: solver_scratch.new_variable(4, 4),
+                    resource_usage: solver_scratch.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s3_scratch,
+                    processing_time: solver_scratch.new_variable(4, 4),
+                    resource_usage: solver_scratch.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver_scratch.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
         let propagator_scratch = solver_scratch
-            .new_propagator(TimeTablePerPointPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: s1_scratch,
-                        processing_time: 2,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2_scratch,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s3_scratch,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                1,
-                CumulativePropagatorOptions {
-                    explanation_type: CumulativeExplanationType::Naive,
-                    ..Default::default()
-                },
-            ))
+            .new_propagator(propagator_scratch)
             .expect("No conflict");
         let _ =
             solver_scratch.increase_lower_bound_and_notify(propagator_scratch, 2, s3_scratch, 7);
@@ -1275,36 +1422,39 @@ mod tests {
         let s1 = solver.new_variable(5, 5);
         let s2 = solver.new_variable(1, 10);
         let s3 = solver.new_variable(1, 10);
-        let propagator = solver
-            .new_propagator(
-                TimeTablePerPointIncrementalPropagator::<DomainId, true>::new(
-                    &[
-                        ArgTask {
-                            start_time: s1,
-                            processing_time: 2,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s2,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s3,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    1,
-                    CumulativePropagatorOptions {
-                        explanation_type: CumulativeExplanationType::Naive,
-                        ..Default::default()
-                    },
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTablePerPointIncrementalPropagator::<
+            DomainId,
+            true,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s3,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
         let _ = solver.increase_lower_bound_and_notify(propagator, 2, s3, 7);
         let _ = solver.increase_lower_bound_and_notify(propagator, 1, s2, 7);
         let result = solver.propagate(propagator);
@@ -1332,33 +1482,34 @@ mod tests {
         let s1_scratch = solver_scratch.new_variable(5, 5);
         let s2_scratch = solver_scratch.new_variable(1, 10);
         let s3_scratch = solver_scratch.new_variable(1, 10);
+        let propagator_scratch = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1_scratch,
+                    processing_time: solver_scratch.new_variable(2, 2),
+                    resource_usage: solver_scratch.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2_scratch,
+                    processing_time: solver_scratch.new_variable(4, 4),
+                    resource_usage: solver_scratch.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s3_scratch,
+                    processing_time: solver_scratch.new_variable(4, 4),
+                    resource_usage: solver_scratch.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver_scratch.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
         let propagator_scratch = solver_scratch
-            .new_propagator(TimeTablePerPointPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: s1_scratch,
-                        processing_time: 2,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2_scratch,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s3_scratch,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                1,
-                CumulativePropagatorOptions {
-                    explanation_type: CumulativeExplanationType::Naive,
-                    ..Default::default()
-                },
-            ))
+            .new_propagator(propagator_scratch)
             .expect("No conflict");
         let _ =
             solver_scratch.increase_lower_bound_and_notify(propagator_scratch, 2, s3_scratch, 7);
@@ -1369,36 +1520,39 @@ mod tests {
         let s1 = solver.new_variable(5, 5);
         let s2 = solver.new_variable(1, 10);
         let s3 = solver.new_variable(1, 10);
-        let propagator = solver
-            .new_propagator(
-                TimeTablePerPointIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: s1,
-                            processing_time: 2,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s2,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s3,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    1,
-                    CumulativePropagatorOptions {
-                        explanation_type: CumulativeExplanationType::Naive,
-                        ..Default::default()
-                    },
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTablePerPointIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s3,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
         let _ = solver.increase_lower_bound_and_notify(propagator, 2, s3, 7);
         let _ = solver.increase_lower_bound_and_notify(propagator, 1, s2, 7);
         let result = solver.propagate(propagator);
@@ -1424,33 +1578,34 @@ mod tests {
         let s1_scratch = solver_scratch.new_variable(1, 6);
         let s2_scratch = solver_scratch.new_variable(1, 6);
         let s3_scratch = solver_scratch.new_variable(5, 11);
+        let propagator_scratch = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1_scratch,
+                    processing_time: solver_scratch.new_variable(2, 2),
+                    resource_usage: solver_scratch.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2_scratch,
+                    processing_time: solver_scratch.new_variable(4, 4),
+                    resource_usage: solver_scratch.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s3_scratch,
+                    processing_time: solver_scratch.new_variable(4, 4),
+                    resource_usage: solver_scratch.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver_scratch.new_variable(2, 2),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
         let propagator_scratch = solver_scratch
-            .new_propagator(TimeTablePerPointPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: s1_scratch,
-                        processing_time: 2,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2_scratch,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s3_scratch,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                2,
-                CumulativePropagatorOptions {
-                    explanation_type: CumulativeExplanationType::Naive,
-                    ..Default::default()
-                },
-            ))
+            .new_propagator(propagator_scratch)
             .expect("No conflict");
         let _ =
             solver_scratch.increase_lower_bound_and_notify(propagator_scratch, 1, s2_scratch, 5);
@@ -1461,36 +1616,39 @@ mod tests {
         let s1 = solver.new_variable(1, 6);
         let s2 = solver.new_variable(1, 6);
         let s3 = solver.new_variable(5, 11);
-        let propagator = solver
-            .new_propagator(
-                TimeTablePerPointIncrementalPropagator::<DomainId, true>::new(
-                    &[
-                        ArgTask {
-                            start_time: s1,
-                            processing_time: 2,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s2,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s3,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    2,
-                    CumulativePropagatorOptions {
-                        explanation_type: CumulativeExplanationType::Naive,
-                        ..Default::default()
-                    },
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTablePerPointIncrementalPropagator::<
+            DomainId,
+            true,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s3,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(2, 2),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
         let _ = solver.increase_lower_bound_and_notify(propagator, 1, s2, 5);
         let result = solver.propagate(propagator);
         assert!(result.is_ok());
@@ -1514,33 +1672,34 @@ mod tests {
         let s1_scratch = solver_scratch.new_variable(1, 6);
         let s2_scratch = solver_scratch.new_variable(1, 6);
         let s3_scratch = solver_scratch.new_variable(5, 11);
+        let propagator_scratch = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1_scratch,
+                    processing_time: solver_scratch.new_variable(2, 2),
+                    resource_usage: solver_scratch.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2_scratch,
+                    processing_time: solver_scratch.new_variable(4, 4),
+                    resource_usage: solver_scratch.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s3_scratch,
+                    processing_time: solver_scratch.new_variable(4, 4),
+                    resource_usage: solver_scratch.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver_scratch.new_variable(2, 2),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
         let propagator_scratch = solver_scratch
-            .new_propagator(TimeTablePerPointPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: s1_scratch,
-                        processing_time: 2,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2_scratch,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s3_scratch,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                2,
-                CumulativePropagatorOptions {
-                    explanation_type: CumulativeExplanationType::Naive,
-                    ..Default::default()
-                },
-            ))
+            .new_propagator(propagator_scratch)
             .expect("No conflict");
         let _ =
             solver_scratch.increase_lower_bound_and_notify(propagator_scratch, 1, s2_scratch, 5);
@@ -1550,36 +1709,39 @@ mod tests {
         let s1 = solver.new_variable(1, 6);
         let s2 = solver.new_variable(1, 6);
         let s3 = solver.new_variable(5, 11);
-        let propagator = solver
-            .new_propagator(
-                TimeTablePerPointIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: s1,
-                            processing_time: 2,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s2,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s3,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    2,
-                    CumulativePropagatorOptions {
-                        explanation_type: CumulativeExplanationType::Naive,
-                        ..Default::default()
-                    },
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTablePerPointIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s3,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(2, 2),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
         let _ = solver.increase_lower_bound_and_notify(propagator, 1, s2, 5);
         let result = solver.propagate(propagator);
         assert!(result.is_ok());
@@ -1608,33 +1770,34 @@ mod tests {
         let s1_scratch = solver_scratch.new_variable(1, 5);
         let s2_scratch = solver_scratch.new_variable(1, 5);
 
+        let propagator_scratch = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s0_scratch,
+                    processing_time: solver_scratch.new_variable(4, 4),
+                    resource_usage: solver_scratch.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s1_scratch,
+                    processing_time: solver_scratch.new_variable(1, 1),
+                    resource_usage: solver_scratch.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2_scratch,
+                    processing_time: solver_scratch.new_variable(1, 1),
+                    resource_usage: solver_scratch.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver_scratch.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
         let propagator_scratch = solver_scratch
-            .new_propagator(TimeTablePerPointPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: s0_scratch,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s1_scratch,
-                        processing_time: 1,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2_scratch,
-                        processing_time: 1,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                1,
-                CumulativePropagatorOptions {
-                    explanation_type: CumulativeExplanationType::Naive,
-                    ..Default::default()
-                },
-            ))
+            .new_propagator(propagator_scratch)
             .expect("No conflict");
         let _ =
             solver_scratch.increase_lower_bound_and_notify(propagator_scratch, 2, s2_scratch, 5);
@@ -1650,36 +1813,39 @@ mod tests {
         let s1 = solver.new_variable(1, 5);
         let s2 = solver.new_variable(1, 5);
 
-        let propagator = solver
-            .new_propagator(
-                TimeTablePerPointIncrementalPropagator::<DomainId, false>::new(
-                    &[
-                        ArgTask {
-                            start_time: s0,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s1,
-                            processing_time: 1,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s2,
-                            processing_time: 1,
-                            resource_usage: 1,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    1,
-                    CumulativePropagatorOptions {
-                        explanation_type: CumulativeExplanationType::Naive,
-                        ..Default::default()
-                    },
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTablePerPointIncrementalPropagator::<
+            DomainId,
+            false,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s0,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(1, 1),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(1, 1),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
         let _ = solver.increase_lower_bound_and_notify(propagator, 2, s2, 5);
         let _ = solver.increase_lower_bound_and_notify(propagator, 1, s1, 5);
         let _ = solver.increase_lower_bound_and_notify(propagator, 0, s0, 5);
@@ -1715,33 +1881,34 @@ mod tests {
         let s1_scratch = solver_scratch.new_variable(1, 5);
         let s2_scratch = solver_scratch.new_variable(1, 5);
 
+        let propagator_scratch = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s0_scratch,
+                    processing_time: solver_scratch.new_variable(4, 4),
+                    resource_usage: solver_scratch.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s1_scratch,
+                    processing_time: solver_scratch.new_variable(1, 1),
+                    resource_usage: solver_scratch.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2_scratch,
+                    processing_time: solver_scratch.new_variable(1, 1),
+                    resource_usage: solver_scratch.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver_scratch.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
         let propagator_scratch = solver_scratch
-            .new_propagator(TimeTablePerPointPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: s0_scratch,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s1_scratch,
-                        processing_time: 1,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2_scratch,
-                        processing_time: 1,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                1,
-                CumulativePropagatorOptions {
-                    explanation_type: CumulativeExplanationType::Naive,
-                    ..Default::default()
-                },
-            ))
+            .new_propagator(propagator_scratch)
             .expect("No conflict");
         let _ =
             solver_scratch.increase_lower_bound_and_notify(propagator_scratch, 2, s2_scratch, 5);
@@ -1757,36 +1924,39 @@ mod tests {
         let s1 = solver.new_variable(1, 5);
         let s2 = solver.new_variable(1, 5);
 
-        let propagator = solver
-            .new_propagator(
-                TimeTablePerPointIncrementalPropagator::<DomainId, true>::new(
-                    &[
-                        ArgTask {
-                            start_time: s0,
-                            processing_time: 4,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s1,
-                            processing_time: 1,
-                            resource_usage: 1,
-                        },
-                        ArgTask {
-                            start_time: s2,
-                            processing_time: 1,
-                            resource_usage: 1,
-                        },
-                    ]
-                    .into_iter()
-                    .collect::<Vec<_>>(),
-                    1,
-                    CumulativePropagatorOptions {
-                        explanation_type: CumulativeExplanationType::Naive,
-                        ..Default::default()
-                    },
-                ),
-            )
-            .expect("No conflict");
+        let propagator = TimeTablePerPointIncrementalPropagator::<
+            DomainId,
+            true,
+            DomainId,
+            DomainId,
+            DomainId,
+        >::new(
+            &[
+                ArgTask {
+                    start_time: s0,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(1, 1),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(1, 1),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
         let _ = solver.increase_lower_bound_and_notify(propagator, 2, s2, 5);
         let _ = solver.increase_lower_bound_and_notify(propagator, 1, s1, 5);
         let _ = solver.increase_lower_bound_and_notify(propagator, 0, s0, 5);
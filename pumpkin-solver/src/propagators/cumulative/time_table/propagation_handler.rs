@@ -7,8 +7,10 @@ use super::explanations::add_propagating_task_predicate_lower_bound;
 use super::explanations::add_propagating_task_predicate_upper_bound;
 use super::explanations::big_step::create_big_step_conflict_explanation;
 use super::explanations::big_step::create_big_step_propagation_explanation;
-use super::explanations::create_predicate_propagating_task_lower_bound_propagation;
-use super::explanations::create_predicate_propagating_task_upper_bound_propagation;
+use super::explanations::create_capacity_predicate;
+use super::explanations::create_processing_time_predicate;
+use super::explanations::create_resource_usage_predicate;
+use super::explanations::create_task_data_predicates;
 use super::explanations::naive::create_naive_conflict_explanation;
 use super::explanations::naive::create_naive_propagation_explanation;
 use super::explanations::pointwise::create_pointwise_conflict_explanation;
@@ -19,6 +21,8 @@ use crate::engine::propagation::PropagationContext;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::ReadDomains;
 use crate::engine::EmptyDomain;
+use crate::predicate;
+use crate::predicates::Predicate;
 use crate::predicates::PropositionalConjunction;
 use crate::propagators::cumulative::time_table::explanations::pointwise;
 use crate::propagators::ResourceProfile;
@@ -36,6 +40,9 @@ pub(crate) struct CumulativePropagationHandler {
     /// explanation and re-use it. Note that this will only be used for
     /// [`CumulativeExplanationType::Naive`] and [`CumulativeExplanationType::BigStep`].
     stored_profile_explanation: OnceCell<Rc<PropositionalConjunction>>,
+    /// The predicate explaining the capacity of the resource (see [`create_capacity_predicate`]);
+    /// this is [`None`] if the capacity is a constant.
+    capacity_predicate: Option<Predicate>,
 }
 
 fn check_explanation(explanation: &PropositionalConjunction, context: PropagationContext) -> bool {
@@ -49,23 +56,29 @@ fn check_explanation(explanation: &PropositionalConjunction, context: Propagatio
 }
 
 impl CumulativePropagationHandler {
-    pub(crate) fn new(explanation_type: CumulativeExplanationType) -> Self {
+    pub(crate) fn new(
+        explanation_type: CumulativeExplanationType,
+        capacity_predicate: Option<Predicate>,
+    ) -> Self {
         Self {
             explanation_type,
             stored_profile_explanation: OnceCell::new(),
+            capacity_predicate,
         }
     }
 
     /// Propagates the lower-bound of the `propagating_task` to not conflict with all of the
     /// `profiles` anymore.
-    pub(crate) fn propagate_chain_of_lower_bounds_with_explanations<Var>(
+    pub(crate) fn propagate_chain_of_lower_bounds_with_explanations<Var, PVar, RVar>(
         &mut self,
         context: &mut PropagationContextMut,
-        profiles: &[&ResourceProfile<Var>],
-        propagating_task: &Rc<Task<Var>>,
+        profiles: &[&ResourceProfile<Var, PVar, RVar>],
+        propagating_task: &Rc<Task<Var, PVar, RVar>>,
     ) -> Result<(), EmptyDomain>
     where
         Var: IntegerVariable + 'static,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
     {
         pumpkin_assert_simple!(!profiles.is_empty());
         match self.explanation_type {
@@ -78,7 +91,7 @@ impl CumulativePropagationHandler {
                             create_naive_propagation_explanation(profile, context.as_readonly())
                         }
                         CumulativeExplanationType::BigStep => {
-                            create_big_step_propagation_explanation(profile)
+                            create_big_step_propagation_explanation(profile, context.as_readonly())
                         }
                         CumulativeExplanationType::Pointwise => {
                             unreachable!("At the moment, we do not store the profile explanation for the pointwise explanation since it consists of multiple explanations")
@@ -96,6 +109,7 @@ impl CumulativePropagationHandler {
                     propagating_task,
                     profiles[0],
                     None,
+                    self.capacity_predicate,
                 );
 
                 pumpkin_assert_extreme!(check_explanation(
@@ -113,6 +127,7 @@ impl CumulativePropagationHandler {
                     context,
                     profiles,
                     propagating_task,
+                    self.capacity_predicate,
                 )
             }
         }
@@ -120,14 +135,16 @@ impl CumulativePropagationHandler {
 
     /// Propagates the upper-bound of the `propagating_task` to not conflict with all of the
     /// `profiles` anymore.
-    pub(crate) fn propagate_chain_of_upper_bounds_with_explanations<Var>(
+    pub(crate) fn propagate_chain_of_upper_bounds_with_explanations<Var, PVar, RVar>(
         &mut self,
         context: &mut PropagationContextMut,
-        profiles: &[&ResourceProfile<Var>],
-        propagating_task: &Rc<Task<Var>>,
+        profiles: &[&ResourceProfile<Var, PVar, RVar>],
+        propagating_task: &Rc<Task<Var, PVar, RVar>>,
     ) -> Result<(), EmptyDomain>
     where
         Var: IntegerVariable + 'static,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
     {
         pumpkin_assert_simple!(!profiles.is_empty());
 
@@ -141,7 +158,7 @@ impl CumulativePropagationHandler {
                             create_naive_propagation_explanation(profile, context.as_readonly())
                         }
                         CumulativeExplanationType::BigStep => {
-                            create_big_step_propagation_explanation(profile)
+                            create_big_step_propagation_explanation(profile, context.as_readonly())
                         }
                         CumulativeExplanationType::Pointwise => {
                             unreachable!("At the moment, we do not store the profile explanation for the pointwise explanation since it consists of multiple explanations")
@@ -159,6 +176,7 @@ impl CumulativePropagationHandler {
                     propagating_task,
                     profiles[profiles.len() - 1],
                     None,
+                    self.capacity_predicate,
                 );
                 pumpkin_assert_extreme!(check_explanation(
                     &full_explanation,
@@ -166,7 +184,7 @@ impl CumulativePropagationHandler {
                 ));
                 context.set_upper_bound(
                     &propagating_task.start_variable,
-                    profiles[0].start - propagating_task.processing_time,
+                    profiles[0].start - context.lower_bound(&propagating_task.processing_time),
                     full_explanation,
                 )
             }
@@ -175,20 +193,23 @@ impl CumulativePropagationHandler {
                     context,
                     profiles,
                     propagating_task,
+                    self.capacity_predicate,
                 )
             }
         }
     }
 
     /// Propagates the lower-bound of the `propagating_task` to not conflict with `profile` anymore.
-    pub(crate) fn propagate_lower_bound_with_explanations<Var>(
+    pub(crate) fn propagate_lower_bound_with_explanations<Var, PVar, RVar>(
         &mut self,
         context: &mut PropagationContextMut,
-        profile: &ResourceProfile<Var>,
-        propagating_task: &Rc<Task<Var>>,
+        profile: &ResourceProfile<Var, PVar, RVar>,
+        propagating_task: &Rc<Task<Var, PVar, RVar>>,
     ) -> Result<(), EmptyDomain>
     where
         Var: IntegerVariable + 'static,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
     {
         pumpkin_assert_advanced!(
             context.lower_bound(&propagating_task.start_variable) < profile.end + 1
@@ -201,18 +222,17 @@ impl CumulativePropagationHandler {
                 // `create_predicate_propagating_task_lower_bound_propagation` both use the
                 // explanation type to create the explanations.
                 let explanation = self.get_stored_profile_explanation_or_init(context, profile);
-                let lower_bound_predicate_propagating_task =
-                    create_predicate_propagating_task_lower_bound_propagation(
-                        self.explanation_type,
-                        context.as_readonly(),
-                        propagating_task,
-                        profile,
-                        None,
-                    );
                 pumpkin_assert_extreme!(check_explanation(&explanation, context.as_readonly()));
 
-                let mut reason = (*explanation).clone();
-                reason.add(lower_bound_predicate_propagating_task);
+                let reason = add_propagating_task_predicate_lower_bound(
+                    (*explanation).clone(),
+                    self.explanation_type,
+                    context.as_readonly(),
+                    propagating_task,
+                    profile,
+                    None,
+                    self.capacity_predicate,
+                );
                 context.set_lower_bound(&propagating_task.start_variable, profile.end + 1, reason)
             }
             CumulativeExplanationType::Pointwise => {
@@ -220,24 +240,27 @@ impl CumulativePropagationHandler {
                     context,
                     &[profile],
                     propagating_task,
+                    self.capacity_predicate,
                 )
             }
         }
     }
 
     /// Propagates the upper-bound of the `propagating_task` to not conflict with `profile` anymore.
-    pub(crate) fn propagate_upper_bound_with_explanations<Var>(
+    pub(crate) fn propagate_upper_bound_with_explanations<Var, PVar, RVar>(
         &mut self,
         context: &mut PropagationContextMut,
-        profile: &ResourceProfile<Var>,
-        propagating_task: &Rc<Task<Var>>,
+        profile: &ResourceProfile<Var, PVar, RVar>,
+        propagating_task: &Rc<Task<Var, PVar, RVar>>,
     ) -> Result<(), EmptyDomain>
     where
         Var: IntegerVariable + 'static,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
     {
         pumpkin_assert_advanced!(
             context.upper_bound(&propagating_task.start_variable)
-                > profile.start - propagating_task.processing_time
+                > profile.start - context.lower_bound(&propagating_task.processing_time)
         );
 
         match self.explanation_type {
@@ -247,21 +270,20 @@ impl CumulativePropagationHandler {
                 // `create_predicate_propagating_task_upper_bound_propagation` both use the
                 // explanation type to create the explanations.
                 let explanation = self.get_stored_profile_explanation_or_init(context, profile);
-                let upper_bound_predicate_propagating_task =
-                    create_predicate_propagating_task_upper_bound_propagation(
-                        self.explanation_type,
-                        context.as_readonly(),
-                        propagating_task,
-                        profile,
-                        None,
-                    );
                 pumpkin_assert_extreme!(check_explanation(&explanation, context.as_readonly()));
 
-                let mut reason = (*explanation).clone();
-                reason.add(upper_bound_predicate_propagating_task);
+                let reason = add_propagating_task_predicate_upper_bound(
+                    (*explanation).clone(),
+                    self.explanation_type,
+                    context.as_readonly(),
+                    propagating_task,
+                    profile,
+                    None,
+                    self.capacity_predicate,
+                );
                 context.set_upper_bound(
                     &propagating_task.start_variable,
-                    profile.start - propagating_task.processing_time,
+                    profile.start - context.lower_bound(&propagating_task.processing_time),
                     reason,
                 )
             }
@@ -270,22 +292,28 @@ impl CumulativePropagationHandler {
                     context,
                     &[profile],
                     propagating_task,
+                    self.capacity_predicate,
                 )
             }
         }
     }
 
     /// Propagates a hole in the domain; note that this explanation does not contain any of the
-    /// bounds of `propagating_task`.
-    pub(crate) fn propagate_holes_in_domain<Var>(
+    /// bounds of `propagating_task` (but it does contain the predicates on its processing time and
+    /// resource usage, see [`create_task_data_predicates`]).
+    pub(crate) fn propagate_holes_in_domain<Var, PVar, RVar>(
         &mut self,
         context: &mut PropagationContextMut,
-        profile: &ResourceProfile<Var>,
-        propagating_task: &Rc<Task<Var>>,
+        profile: &ResourceProfile<Var, PVar, RVar>,
+        propagating_task: &Rc<Task<Var, PVar, RVar>>,
     ) -> Result<(), EmptyDomain>
     where
         Var: IntegerVariable + 'static,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
     {
+        let processing_time = context.lower_bound(&propagating_task.processing_time);
+
         // We go through all of the time-points which cause `task` to overlap
         // with the resource profile
 
@@ -297,7 +325,7 @@ impl CumulativePropagationHandler {
         //   time-point in which case we simply start from the lower-bound of the task.
         let lower_bound_removed_time_points = max(
             context.lower_bound(&propagating_task.start_variable),
-            profile.start - propagating_task.processing_time + 1,
+            profile.start - processing_time + 1,
         );
 
         // There are also two options for determine the highest value to remove
@@ -323,11 +351,13 @@ impl CumulativePropagationHandler {
                     // explanation type to create the explanations.
                     let explanation = self.get_stored_profile_explanation_or_init(context, profile);
                     pumpkin_assert_extreme!(check_explanation(&explanation, context.as_readonly()));
-                    context.remove(
-                        &propagating_task.start_variable,
-                        time_point,
-                        (*explanation).clone(),
-                    )?;
+                    let mut reason = (*explanation).clone();
+                    reason.extend(create_task_data_predicates(
+                        context.as_readonly(),
+                        propagating_task,
+                    ));
+                    reason.extend(self.capacity_predicate);
+                    context.remove(&propagating_task.start_variable, time_point, reason)?;
                 }
                 CumulativeExplanationType::Pointwise => {
                     // We split into two cases when determining the explanation of the profile
@@ -343,18 +373,24 @@ impl CumulativePropagationHandler {
                     //   together with the propagating task would overflow the capacity)
                     let corresponding_profile_explanation_point = if time_point < profile.start {
                         min(
-                            time_point + propagating_task.processing_time - 1,
+                            time_point + processing_time - 1,
                             (profile.end - profile.start) / 2 + profile.start,
                         )
                     } else {
                         time_point
                     };
 
-                    let explanation = create_pointwise_propagation_explanation(
+                    let mut explanation = create_pointwise_propagation_explanation(
                         corresponding_profile_explanation_point,
                         profile,
+                        context.as_readonly(),
                     );
                     pumpkin_assert_extreme!(check_explanation(&explanation, context.as_readonly()));
+                    explanation.extend(create_task_data_predicates(
+                        context.as_readonly(),
+                        propagating_task,
+                    ));
+                    explanation.extend(self.capacity_predicate);
                     context.remove(&propagating_task.start_variable, time_point, explanation)?;
                 }
             }
@@ -363,6 +399,162 @@ impl CumulativePropagationHandler {
         Ok(())
     }
 
+    /// Propagates the lower-bound of the `capacity` to the height of `profile`; the explanation is
+    /// that the tasks in `profile` overlap (see [`create_profile_explanation`]).
+    pub(crate) fn propagate_capacity_lower_bound<Var, PVar, RVar, CVar>(
+        &mut self,
+        context: &mut PropagationContextMut,
+        profile: &ResourceProfile<Var, PVar, RVar>,
+        capacity: &CVar,
+    ) -> Result<(), EmptyDomain>
+    where
+        Var: IntegerVariable + 'static,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
+        CVar: IntegerVariable + 'static,
+    {
+        let explanation =
+            create_profile_explanation(context.as_readonly(), profile, self.explanation_type);
+        pumpkin_assert_extreme!(check_explanation(&explanation, context.as_readonly()));
+        context.set_lower_bound(capacity, profile.height, explanation)
+    }
+
+    /// Propagates the upper-bound of the resource usage of `propagating_task` (which is part of
+    /// `profile`) such that it does not overflow the capacity together with the other tasks in
+    /// `profile`.
+    pub(crate) fn propagate_resource_usage_upper_bound<Var, PVar, RVar>(
+        &mut self,
+        context: &mut PropagationContextMut,
+        profile: &ResourceProfile<Var, PVar, RVar>,
+        propagating_task: &Rc<Task<Var, PVar, RVar>>,
+        capacity: i64,
+    ) -> Result<(), EmptyDomain>
+    where
+        Var: IntegerVariable + 'static,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
+    {
+        pumpkin_assert_advanced!(profile
+            .profile_tasks
+            .iter()
+            .any(|profile_task| profile_task.id == propagating_task.id));
+
+        let resource_usage = context.lower_bound(&propagating_task.resource_usage);
+
+        // The explanation consists of the other tasks in the profile and of the predicates which
+        // cause the mandatory part of the propagating task to overlap with them; note that we
+        // do not use the resource usage of the propagating task itself
+        let other_tasks = ResourceProfile {
+            start: profile.start,
+            end: profile.end,
+            profile_tasks: profile
+                .profile_tasks
+                .iter()
+                .filter(|profile_task| profile_task.id != propagating_task.id)
+                .cloned()
+                .collect(),
+            height: profile.height - resource_usage,
+        };
+        let mut explanation =
+            create_profile_explanation(context.as_readonly(), &other_tasks, self.explanation_type);
+
+        let processing_time = context.lower_bound(&propagating_task.processing_time);
+        let (earliest_start, latest_start) = match self.explanation_type {
+            CumulativeExplanationType::Naive => (
+                context.lower_bound(&propagating_task.start_variable),
+                context.upper_bound(&propagating_task.start_variable),
+            ),
+            CumulativeExplanationType::BigStep => {
+                (profile.end - processing_time + 1, profile.start)
+            }
+            CumulativeExplanationType::Pointwise => {
+                // This is the time-point which is used by the conflict explanation of the other
+                // tasks (see [`create_pointwise_conflict_explanation`])
+                let middle_point = (profile.end - profile.start) / 2 + profile.start;
+                (middle_point - processing_time + 1, middle_point)
+            }
+        };
+        explanation.add(predicate!(
+            propagating_task.start_variable >= earliest_start
+        ));
+        explanation.add(predicate!(propagating_task.start_variable <= latest_start));
+        explanation.extend(create_processing_time_predicate(
+            context.as_readonly(),
+            propagating_task,
+        ));
+        explanation.extend(self.capacity_predicate);
+
+        pumpkin_assert_extreme!(check_explanation(&explanation, context.as_readonly()));
+        context.set_upper_bound(
+            &propagating_task.resource_usage,
+            capacity - other_tasks.height,
+            explanation,
+        )
+    }
+
+    /// Propagates the upper-bound of the processing time of `propagating_task` such that it ends
+    /// before the start of `profile`.
+    ///
+    /// It is assumed that the `propagating_task` cannot start after the start of `profile` and that
+    /// it would overflow the capacity together with `profile`.
+    pub(crate) fn propagate_processing_time_upper_bound<Var, PVar, RVar>(
+        &mut self,
+        context: &mut PropagationContextMut,
+        profile: &ResourceProfile<Var, PVar, RVar>,
+        propagating_task: &Rc<Task<Var, PVar, RVar>>,
+    ) -> Result<(), EmptyDomain>
+    where
+        Var: IntegerVariable + 'static,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
+    {
+        pumpkin_assert_advanced!(
+            context.upper_bound(&propagating_task.start_variable) <= profile.start
+        );
+
+        // The explanation is that the profile contains the start of the profile and that the
+        // propagating task would run at that time-point if it did not end before it
+        let mut explanation = match self.explanation_type {
+            CumulativeExplanationType::Naive => {
+                create_naive_propagation_explanation(profile, context.as_readonly())
+            }
+            CumulativeExplanationType::BigStep => {
+                create_big_step_propagation_explanation(profile, context.as_readonly())
+            }
+            CumulativeExplanationType::Pointwise => create_pointwise_propagation_explanation(
+                profile.start,
+                profile,
+                context.as_readonly(),
+            ),
+        };
+
+        let earliest_start = context.lower_bound(&propagating_task.start_variable);
+        let latest_start = match self.explanation_type {
+            CumulativeExplanationType::Naive => {
+                context.upper_bound(&propagating_task.start_variable)
+            }
+            CumulativeExplanationType::BigStep | CumulativeExplanationType::Pointwise => {
+                profile.start
+            }
+        };
+        explanation.add(predicate!(
+            propagating_task.start_variable >= earliest_start
+        ));
+        explanation.add(predicate!(propagating_task.start_variable <= latest_start));
+        explanation.extend(create_resource_usage_predicate(
+            context.as_readonly(),
+            propagating_task,
+        ));
+        explanation.extend(self.capacity_predicate);
+
+        pumpkin_assert_extreme!(check_explanation(&explanation, context.as_readonly()));
+        context.set_upper_bound(
+            &propagating_task.processing_time,
+            profile.start - earliest_start,
+            explanation,
+        )
+    }
+
     /// Signifies that we are moving to another profile and we cannot re-use the cached explanation
     /// of [`CumulativePropagationHandler::stored_profile_explanation`].
     pub(crate) fn next_profile(&mut self) {
@@ -370,13 +562,15 @@ impl CumulativePropagationHandler {
     }
 
     /// Either we get the stored stored profile explanation or we initialize it.
-    fn get_stored_profile_explanation_or_init<Var>(
+    fn get_stored_profile_explanation_or_init<Var, PVar, RVar>(
         &mut self,
         context: &mut PropagationContextMut,
-        profile: &ResourceProfile<Var>,
+        profile: &ResourceProfile<Var, PVar, RVar>,
     ) -> Rc<PropositionalConjunction>
     where
         Var: IntegerVariable + 'static,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
     {
         Rc::clone(self.stored_profile_explanation.get_or_init(|| {
             Rc::new(
@@ -385,7 +579,7 @@ impl CumulativePropagationHandler {
                         create_naive_propagation_explanation(profile, context.as_readonly())
                     },
                     CumulativeExplanationType::BigStep => {
-                        create_big_step_propagation_explanation(profile)
+                        create_big_step_propagation_explanation(profile, context.as_readonly())
                     },
                     CumulativeExplanationType::Pointwise => {
                         unreachable!("At the moment, we do not store the profile explanation for the pointwise explanation since it consists of multiple explanations")
@@ -397,24 +591,44 @@ impl CumulativePropagationHandler {
 }
 
 /// Creates an explanation of the conflict caused by `conflict_profile` based on the provided
-/// `explanation_type`.
-pub(crate) fn create_conflict_explanation<Var, Context: ReadDomains + Copy>(
+/// `explanation_type`; the explanation also contains the upper-bound of the `capacity` (see
+/// [`create_capacity_predicate`]).
+pub(crate) fn create_conflict_explanation<Var, PVar, RVar, CVar, Context: ReadDomains + Copy>(
+    context: Context,
+    conflict_profile: &ResourceProfile<Var, PVar, RVar>,
+    explanation_type: CumulativeExplanationType,
+    capacity: &CVar,
+) -> PropositionalConjunction
+where
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
+{
+    let mut explanation = create_profile_explanation(context, conflict_profile, explanation_type);
+    explanation.extend(create_capacity_predicate(context, capacity));
+    explanation
+}
+
+/// Creates an explanation of the tasks in `profile` overlapping (i.e. that their cumulative resource
+/// usage is at least the height of `profile`) based on the provided `explanation_type`.
+fn create_profile_explanation<Var, PVar, RVar, Context: ReadDomains + Copy>(
     context: Context,
-    conflict_profile: &ResourceProfile<Var>,
+    profile: &ResourceProfile<Var, PVar, RVar>,
     explanation_type: CumulativeExplanationType,
 ) -> PropositionalConjunction
 where
     Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
 {
     match explanation_type {
-        CumulativeExplanationType::Naive => {
-            create_naive_conflict_explanation(conflict_profile, context)
-        }
+        CumulativeExplanationType::Naive => create_naive_conflict_explanation(profile, context),
         CumulativeExplanationType::BigStep => {
-            create_big_step_conflict_explanation(conflict_profile)
+            create_big_step_conflict_explanation(profile, context)
         }
         CumulativeExplanationType::Pointwise => {
-            create_pointwise_conflict_explanation(conflict_profile)
+            create_pointwise_conflict_explanation(profile, context)
         }
     }
 }
@@ -452,7 +666,7 @@ pub(crate) mod test_propagation_handler {
 
     impl TestPropagationHandler {
         pub(crate) fn new(explanation_type: CumulativeExplanationType) -> Self {
-            let propagation_handler = CumulativePropagationHandler::new(explanation_type);
+            let propagation_handler = CumulativePropagationHandler::new(explanation_type, None);
 
             let reason_store = ReasonStore::default();
             let assignments = Assignments::default();
@@ -470,8 +684,8 @@ pub(crate) mod test_propagation_handler {
 
             let profile_task = Task {
                 start_variable: y,
-                processing_time: 4,
-                resource_usage: 1,
+                processing_time: self.assignments.grow(4, 4),
+                resource_usage: self.assignments.grow(1, 1),
                 id: LocalId::from(1),
             };
 
@@ -482,10 +696,12 @@ pub(crate) mod test_propagation_handler {
                 height: 1,
             };
 
+            let capacity = self.assignments.grow(1, 1);
             let reason = create_conflict_explanation(
                 PropagationContext::new(&self.assignments),
                 &profile,
                 self.propagation_handler.explanation_type,
+                &capacity,
             );
 
             (reason, y)
@@ -499,15 +715,15 @@ pub(crate) mod test_propagation_handler {
 
             let propagating_task = Task {
                 start_variable: x,
-                processing_time: 6,
-                resource_usage: 1,
+                processing_time: self.assignments.grow(6, 6),
+                resource_usage: self.assignments.grow(1, 1),
                 id: LocalId::from(0),
             };
 
             let profile_task = Task {
                 start_variable: y,
-                processing_time: 4,
-                resource_usage: 1,
+                processing_time: self.assignments.grow(4, 4),
+                resource_usage: self.assignments.grow(1, 1),
                 id: LocalId::from(1),
             };
 
@@ -548,15 +764,15 @@ pub(crate) mod test_propagation_handler {
 
             let propagating_task = Task {
                 start_variable: x,
-                processing_time: 6,
-                resource_usage: 1,
+                processing_time: self.assignments.grow(6, 6),
+                resource_usage: self.assignments.grow(1, 1),
                 id: LocalId::from(0),
             };
 
             let profile_task_y = Task {
                 start_variable: y,
-                processing_time: 4,
-                resource_usage: 1,
+                processing_time: self.assignments.grow(4, 4),
+                resource_usage: self.assignments.grow(1, 1),
                 id: LocalId::from(1),
             };
             let profile_y = ResourceProfile {
@@ -568,8 +784,8 @@ pub(crate) mod test_propagation_handler {
 
             let profile_task_z = Task {
                 start_variable: z,
-                processing_time: 7,
-                resource_usage: 1,
+                processing_time: self.assignments.grow(7, 7),
+                resource_usage: self.assignments.grow(1, 1),
                 id: LocalId::from(2),
             };
             let profile_z = ResourceProfile {
@@ -608,15 +824,15 @@ pub(crate) mod test_propagation_handler {
 
             let propagating_task = Task {
                 start_variable: x,
-                processing_time: 6,
-                resource_usage: 1,
+                processing_time: self.assignments.grow(6, 6),
+                resource_usage: self.assignments.grow(1, 1),
                 id: LocalId::from(0),
             };
 
             let profile_task = Task {
                 start_variable: y,
-                processing_time: 4,
-                resource_usage: 1,
+                processing_time: self.assignments.grow(4, 4),
+                resource_usage: self.assignments.grow(1, 1),
                 id: LocalId::from(1),
             };
 
@@ -657,15 +873,15 @@ pub(crate) mod test_propagation_handler {
 
             let propagating_task = Task {
                 start_variable: x,
-                processing_time: 6,
-                resource_usage: 1,
+                processing_time: self.assignments.grow(6, 6),
+                resource_usage: self.assignments.grow(1, 1),
                 id: LocalId::from(0),
             };
 
             let profile_task_y = Task {
                 start_variable: y,
-                processing_time: 4,
-                resource_usage: 1,
+                processing_time: self.assignments.grow(4, 4),
+                resource_usage: self.assignments.grow(1, 1),
                 id: LocalId::from(1),
             };
             let profile_y = ResourceProfile {
@@ -677,8 +893,8 @@ pub(crate) mod test_propagation_handler {
 
             let profile_task_z = Task {
                 start_variable: z,
-                processing_time: 6,
-                resource_usage: 1,
+                processing_time: self.assignments.grow(6, 6),
+                resource_usage: self.assignments.grow(1, 1),
                 id: LocalId::from(2),
             };
             let profile_z = ResourceProfile {
@@ -17,6 +17,7 @@ use crate::engine::IntDomainEvent;
 use crate::predicates::PropositionalConjunction;
 use crate::propagators::cumulative::time_table::propagation_handler::create_conflict_explanation;
 use crate::propagators::util::create_tasks;
+use crate::propagators::util::mandatory_processing_time;
 use crate::propagators::util::register_tasks;
 use crate::propagators::util::update_bounds_task;
 use crate::propagators::ArgTask;
@@ -33,14 +34,14 @@ use crate::pumpkin_assert_simple;
 
 /// An event storing the start and end of mandatory parts used for creating the time-table
 #[derive(Debug)]
-pub(crate) struct Event<Var> {
+pub(crate) struct Event<Var, PVar, RVar> {
     /// The time-point at which the [`Event`] took place
-    time_stamp: i32,
+    time_stamp: i64,
     /// Change in resource usage at [time_stamp][Event::time_stamp], positive if it is the start of
     /// a mandatory part and negative otherwise
-    change_in_resource_usage: i32,
+    change_in_resource_usage: i64,
     /// The [`Task`] which has caused the event to take place
-    task: Rc<Task<Var>>,
+    task: Rc<Task<Var, PVar, RVar>>,
 }
 
 /// [`Propagator`] responsible for using time-table reasoning to propagate the [Cumulative](https://sofdem.github.io/gccat/gccat/Ccumulative.html) constraint
@@ -55,27 +56,33 @@ pub(crate) struct Event<Var> {
 /// Computer Science and Software Engineering, 2011.
 #[derive(Debug)]
 
-pub(crate) struct TimeTableOverIntervalPropagator<Var> {
+pub(crate) struct TimeTableOverIntervalPropagator<Var, PVar, RVar, CVar> {
     /// Stores whether the time-table is empty
     is_time_table_empty: bool,
     /// Stores the input parameters to the cumulative constraint
-    parameters: CumulativeParameters<Var>,
+    parameters: CumulativeParameters<Var, PVar, RVar, CVar>,
     /// Stores structures which change during the search; used to store the bounds
-    updatable_structures: UpdatableStructures<Var>,
+    updatable_structures: UpdatableStructures<Var, PVar, RVar>,
 }
 
 /// The type of the time-table used by propagators which use time-table reasoning over intervals.
 ///
 /// The [ResourceProfile]s are sorted based on start time and they are non-overlapping; each entry
 /// in the [`Vec`] represents the mandatory resource usage across an interval.
-pub(crate) type OverIntervalTimeTableType<Var> = Vec<ResourceProfile<Var>>;
-
-impl<Var: IntegerVariable + 'static> TimeTableOverIntervalPropagator<Var> {
+pub(crate) type OverIntervalTimeTableType<Var, PVar, RVar> = Vec<ResourceProfile<Var, PVar, RVar>>;
+
+impl<
+        Var: IntegerVariable + 'static,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
+        CVar: IntegerVariable + 'static,
+    > TimeTableOverIntervalPropagator<Var, PVar, RVar, CVar>
+{
     pub(crate) fn new(
-        arg_tasks: &[ArgTask<Var>],
-        capacity: i32,
+        arg_tasks: &[ArgTask<Var, PVar, RVar>],
+        capacity: CVar,
         cumulative_options: CumulativePropagatorOptions,
-    ) -> TimeTableOverIntervalPropagator<Var> {
+    ) -> TimeTableOverIntervalPropagator<Var, PVar, RVar, CVar> {
         let tasks = create_tasks(arg_tasks);
         let parameters = CumulativeParameters::new(tasks, capacity, cumulative_options);
         let updatable_structures = UpdatableStructures::new(&parameters);
@@ -88,7 +95,13 @@ impl<Var: IntegerVariable + 'static> TimeTableOverIntervalPropagator<Var> {
     }
 }
 
-impl<Var: IntegerVariable + 'static> Propagator for TimeTableOverIntervalPropagator<Var> {
+impl<
+        Var: IntegerVariable + 'static,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
+        CVar: IntegerVariable + 'static,
+    > Propagator for TimeTableOverIntervalPropagator<Var, PVar, RVar, CVar>
+{
     fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
         let time_table =
             create_time_table_over_interval_from_scratch(context.as_readonly(), &self.parameters)?;
@@ -114,6 +127,12 @@ impl<Var: IntegerVariable + 'static> Propagator for TimeTableOverIntervalPropaga
         local_id: LocalId,
         event: OpaqueDomainEvent,
     ) -> EnqueueDecision {
+        if local_id.unpack() as usize >= self.parameters.tasks.len() {
+            // The processing time or resource usage of a task or the capacity has been updated;
+            // the time-table is recalculated from scratch anyways
+            return EnqueueDecision::Enqueue;
+        }
+
         let updated_task = Rc::clone(&self.parameters.tasks[local_id.unpack() as usize]);
         // Note that it could be the case that `is_time_table_empty` is inaccurate here since it
         // wasn't updated in `synchronise`; however, `synchronise` will only remove profiles
@@ -152,13 +171,22 @@ impl<Var: IntegerVariable + 'static> Propagator for TimeTableOverIntervalPropaga
         "CumulativeTimeTableOverInterval"
     }
 
+    fn inference_label(&self) -> &str {
+        "time_table"
+    }
+
     fn initialise_at_root(
         &mut self,
         context: &mut PropagatorInitialisationContext,
     ) -> Result<(), PropositionalConjunction> {
         self.updatable_structures
             .initialise_bounds_and_remove_fixed(context.as_readonly(), &self.parameters);
-        register_tasks(&self.parameters.tasks, context, false);
+        register_tasks(
+            &self.parameters.tasks,
+            &self.parameters.capacity,
+            context,
+            false,
+        );
 
         Ok(())
     }
@@ -187,11 +215,14 @@ impl<Var: IntegerVariable + 'static> Propagator for TimeTableOverIntervalPropaga
 /// conflict in the form of an [`Inconsistency`].
 pub(crate) fn create_time_table_over_interval_from_scratch<
     Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
     Context: ReadDomains + Copy,
 >(
     context: Context,
-    parameters: &CumulativeParameters<Var>,
-) -> Result<OverIntervalTimeTableType<Var>, PropositionalConjunction> {
+    parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
+) -> Result<OverIntervalTimeTableType<Var, PVar, RVar>, PropositionalConjunction> {
     // First we create a list of all the events (i.e. start and ends of mandatory parts)
     let events = create_events(context, parameters);
 
@@ -206,17 +237,25 @@ pub(crate) fn create_time_table_over_interval_from_scratch<
 /// is resolved by placing the events which signify the ends of mandatory parts first (if the
 /// tie is between events of the same type then the tie-breaking is done on the id in
 /// non-decreasing order).
-fn create_events<Var: IntegerVariable + 'static, Context: ReadDomains + Copy>(
+fn create_events<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
+    Context: ReadDomains + Copy,
+>(
     context: Context,
-    parameters: &CumulativeParameters<Var>,
-) -> Vec<Event<Var>> {
+    parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
+) -> Vec<Event<Var, PVar, RVar>> {
     // First we create a list of events with which we will create the time-table
-    let mut events: Vec<Event<Var>> = Vec::new();
+    let mut events: Vec<Event<Var, PVar, RVar>> = Vec::new();
     // Then we go over every task
     for task in parameters.tasks.iter() {
         let upper_bound = context.upper_bound(&task.start_variable);
         let lower_bound = context.lower_bound(&task.start_variable);
-        if upper_bound < lower_bound + task.processing_time {
+        let processing_time = mandatory_processing_time(context, task);
+        let resource_usage = context.lower_bound(&task.resource_usage);
+        if upper_bound < lower_bound + processing_time {
             // The task has a mandatory part, we need to add the appropriate events to the
             // events list
 
@@ -224,15 +263,15 @@ fn create_events<Var: IntegerVariable + 'static, Context: ReadDomains + Copy>(
             // resource usage)
             events.push(Event {
                 time_stamp: upper_bound,
-                change_in_resource_usage: task.resource_usage,
+                change_in_resource_usage: resource_usage,
                 task: Rc::clone(task),
             });
 
             // Then we create an event for the end of a mandatory part (with negative resource
             // usage)
             events.push(Event {
-                time_stamp: lower_bound + task.processing_time,
-                change_in_resource_usage: -task.resource_usage,
+                time_stamp: lower_bound + processing_time,
+                change_in_resource_usage: -resource_usage,
                 task: Rc::clone(task),
             });
         }
@@ -266,11 +305,17 @@ fn create_events<Var: IntegerVariable + 'static, Context: ReadDomains + Copy>(
 /// Creates a time-table based on the provided `events` (which are assumed to be sorted
 /// chronologically, with tie-breaking performed in such a way that the ends of mandatory parts
 /// are before the starts of mandatory parts).
-fn create_time_table_from_events<Var: IntegerVariable + 'static, Context: ReadDomains + Copy>(
-    events: Vec<Event<Var>>,
+fn create_time_table_from_events<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
+    Context: ReadDomains + Copy,
+>(
+    events: Vec<Event<Var, PVar, RVar>>,
     context: Context,
-    parameters: &CumulativeParameters<Var>,
-) -> Result<OverIntervalTimeTableType<Var>, PropositionalConjunction> {
+    parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
+) -> Result<OverIntervalTimeTableType<Var, PVar, RVar>, PropositionalConjunction> {
     pumpkin_assert_extreme!(
         events.is_empty()
             || (0..events.len() - 1)
@@ -286,14 +331,14 @@ fn create_time_table_from_events<Var: IntegerVariable + 'static, Context: ReadDo
         "Events were not ordered in such a way that the ends of mandatory parts occurred first"
     );
 
-    let mut time_table: OverIntervalTimeTableType<Var> = Default::default();
+    let mut time_table: OverIntervalTimeTableType<Var, PVar, RVar> = Default::default();
     // The tasks which are contributing to the current profile under consideration
-    let mut current_profile_tasks: Vec<Rc<Task<Var>>> = Vec::new();
+    let mut current_profile_tasks: Vec<Rc<Task<Var, PVar, RVar>>> = Vec::new();
     // The cumulative resource usage of the tasks which are contributing to the current profile
     // under consideration
-    let mut current_resource_usage: i32 = 0;
+    let mut current_resource_usage: i64 = 0;
     // The beginning of the current interval under consideration
-    let mut start_of_interval: i32 = -1;
+    let mut start_of_interval: i64 = -1;
     // Determines whether a conflict has occurred
     let mut is_conflicting = false;
 
@@ -320,7 +365,7 @@ fn create_time_table_from_events<Var: IntegerVariable + 'static, Context: ReadDo
 
             // We have first traversed all of the ends of mandatory parts, meaning that any
             // overflow will persist after processing all events at this time-point
-            if current_resource_usage > parameters.capacity {
+            if current_resource_usage > context.upper_bound(&parameters.capacity) {
                 is_conflicting = true;
             }
 
@@ -340,6 +385,7 @@ fn create_time_table_from_events<Var: IntegerVariable + 'static, Context: ReadDo
                         context,
                         &new_profile,
                         parameters.options.explanation_type,
+                        &parameters.capacity,
                     ));
                 } else {
                     // We end the current profile, creating a profile from [start_of_interval,
@@ -396,10 +442,14 @@ fn create_time_table_from_events<Var: IntegerVariable + 'static, Context: ReadDo
     Ok(time_table)
 }
 
-fn check_starting_new_profile_invariants<Var: IntegerVariable + 'static>(
-    event: &Event<Var>,
-    current_resource_usage: i32,
-    current_profile_tasks: &[Rc<Task<Var>>],
+fn check_starting_new_profile_invariants<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
+    event: &Event<Var, PVar, RVar>,
+    current_resource_usage: i64,
+    current_profile_tasks: &[Rc<Task<Var, PVar, RVar>>],
 ) -> bool {
     if event.change_in_resource_usage <= 0 {
         eprintln!("The resource usage of an event which causes a new profile to be started should never be negative")
@@ -415,10 +465,15 @@ fn check_starting_new_profile_invariants<Var: IntegerVariable + 'static>(
         && current_profile_tasks.is_empty()
 }
 
-pub(crate) fn debug_propagate_from_scratch_time_table_interval<Var: IntegerVariable + 'static>(
+pub(crate) fn debug_propagate_from_scratch_time_table_interval<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
+>(
     context: &mut PropagationContextMut,
-    parameters: &CumulativeParameters<Var>,
-    updatable_structures: &UpdatableStructures<Var>,
+    parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
+    updatable_structures: &UpdatableStructures<Var, PVar, RVar>,
 ) -> PropagationStatusCP {
     // We first create a time-table over interval and return an error if there was
     // an overflow of the resource capacity while building the time-table
@@ -452,26 +507,25 @@ mod tests {
         let s1 = solver.new_variable(1, 1);
         let s2 = solver.new_variable(1, 8);
 
-        let _ = solver
-            .new_propagator(TimeTableOverIntervalPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: s1,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2,
-                        processing_time: 3,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                1,
-                CumulativePropagatorOptions::default(),
-            ))
-            .expect("No conflict");
+        let propagator = TimeTableOverIntervalPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions::default(),
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 5);
         assert_eq!(solver.upper_bound(s2), 8);
         assert_eq!(solver.lower_bound(s1), 1);
@@ -484,27 +538,28 @@ mod tests {
         let s1 = solver.new_variable(1, 1);
         let s2 = solver.new_variable(1, 1);
 
-        let result = solver.new_propagator(TimeTableOverIntervalPropagator::new(
+        let propagator = TimeTableOverIntervalPropagator::new(
             &[
                 ArgTask {
                     start_time: s1,
-                    processing_time: 4,
-                    resource_usage: 1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
                 },
                 ArgTask {
                     start_time: s2,
-                    processing_time: 4,
-                    resource_usage: 1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
                 },
             ]
             .into_iter()
             .collect::<Vec<_>>(),
-            1,
+            solver.new_variable(1, 1),
             CumulativePropagatorOptions {
                 explanation_type: CumulativeExplanationType::Naive,
                 ..Default::default()
             },
-        ));
+        );
+        let result = solver.new_propagator(propagator);
 
         assert!(match result {
             Err(e) => {
@@ -534,26 +589,25 @@ mod tests {
         let s1 = solver.new_variable(0, 6);
         let s2 = solver.new_variable(0, 6);
 
-        let _ = solver
-            .new_propagator(TimeTableOverIntervalPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: s1,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2,
-                        processing_time: 3,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                1,
-                CumulativePropagatorOptions::default(),
-            ))
-            .expect("No conflict");
+        let propagator = TimeTableOverIntervalPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions::default(),
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 0);
         assert_eq!(solver.upper_bound(s2), 6);
         assert_eq!(solver.lower_bound(s1), 0);
@@ -570,46 +624,45 @@ mod tests {
         let b = solver.new_variable(2, 3);
         let a = solver.new_variable(0, 1);
 
-        let _ = solver
-            .new_propagator(TimeTableOverIntervalPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: a,
-                        processing_time: 2,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: b,
-                        processing_time: 6,
-                        resource_usage: 2,
-                    },
-                    ArgTask {
-                        start_time: c,
-                        processing_time: 2,
-                        resource_usage: 4,
-                    },
-                    ArgTask {
-                        start_time: d,
-                        processing_time: 2,
-                        resource_usage: 2,
-                    },
-                    ArgTask {
-                        start_time: e,
-                        processing_time: 5,
-                        resource_usage: 2,
-                    },
-                    ArgTask {
-                        start_time: f,
-                        processing_time: 6,
-                        resource_usage: 2,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                5,
-                CumulativePropagatorOptions::default(),
-            ))
-            .expect("No conflict");
+        let propagator = TimeTableOverIntervalPropagator::new(
+            &[
+                ArgTask {
+                    start_time: a,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: b,
+                    processing_time: solver.new_variable(6, 6),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: c,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(4, 4),
+                },
+                ArgTask {
+                    start_time: d,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: e,
+                    processing_time: solver.new_variable(5, 5),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: f,
+                    processing_time: solver.new_variable(6, 6),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(5, 5),
+            CumulativePropagatorOptions::default(),
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(f), 10);
     }
 
@@ -619,26 +672,25 @@ mod tests {
         let s1 = solver.new_variable(0, 6);
         let s2 = solver.new_variable(6, 10);
 
-        let propagator = solver
-            .new_propagator(TimeTableOverIntervalPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: s1,
-                        processing_time: 2,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2,
-                        processing_time: 3,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                1,
-                CumulativePropagatorOptions::default(),
-            ))
-            .expect("No conflict");
+        let propagator = TimeTableOverIntervalPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions::default(),
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 6);
         assert_eq!(solver.upper_bound(s2), 10);
         assert_eq!(solver.lower_bound(s1), 0);
@@ -663,29 +715,28 @@ mod tests {
         let s1 = solver.new_variable(6, 6);
         let s2 = solver.new_variable(1, 8);
 
-        let _ = solver
-            .new_propagator(TimeTableOverIntervalPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: s1,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2,
-                        processing_time: 3,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                1,
-                CumulativePropagatorOptions {
-                    explanation_type: CumulativeExplanationType::Naive,
-                    ..Default::default()
-                },
-            ))
-            .expect("No conflict");
+        let propagator = TimeTableOverIntervalPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 1);
         assert_eq!(solver.upper_bound(s2), 3);
         assert_eq!(solver.lower_bound(s1), 6);
@@ -705,46 +756,45 @@ mod tests {
         let b = solver.new_variable(2, 3);
         let a = solver.new_variable(0, 1);
 
-        let propagator = solver
-            .new_propagator(TimeTableOverIntervalPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: a,
-                        processing_time: 2,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: b,
-                        processing_time: 6,
-                        resource_usage: 2,
-                    },
-                    ArgTask {
-                        start_time: c,
-                        processing_time: 2,
-                        resource_usage: 4,
-                    },
-                    ArgTask {
-                        start_time: d,
-                        processing_time: 2,
-                        resource_usage: 2,
-                    },
-                    ArgTask {
-                        start_time: e,
-                        processing_time: 4,
-                        resource_usage: 2,
-                    },
-                    ArgTask {
-                        start_time: f,
-                        processing_time: 6,
-                        resource_usage: 2,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                5,
-                CumulativePropagatorOptions::default(),
-            ))
-            .expect("No conflict");
+        let propagator = TimeTableOverIntervalPropagator::new(
+            &[
+                ArgTask {
+                    start_time: a,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: b,
+                    processing_time: solver.new_variable(6, 6),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: c,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(4, 4),
+                },
+                ArgTask {
+                    start_time: d,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: e,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: f,
+                    processing_time: solver.new_variable(6, 6),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(5, 5),
+            CumulativePropagatorOptions::default(),
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(a), 0);
         assert_eq!(solver.upper_bound(a), 1);
         assert_eq!(solver.lower_bound(b), 2);
@@ -758,7 +808,7 @@ mod tests {
         assert_eq!(solver.lower_bound(f), 0);
         assert_eq!(solver.upper_bound(f), 14);
 
-        let notification_status = solver.increase_lower_bound_and_notify(propagator, 3, e, 3);
+        let notification_status = solver.increase_lower_bound_and_notify(propagator, 4, e, 3);
         assert!(match notification_status {
             EnqueueDecision::Enqueue => true,
             EnqueueDecision::Skip => false,
@@ -779,51 +829,50 @@ mod tests {
         let b1 = solver.new_variable(3, 3);
         let a = solver.new_variable(0, 1);
 
-        let propagator = solver
-            .new_propagator(TimeTableOverIntervalPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: a,
-                        processing_time: 2,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: b1,
-                        processing_time: 2,
-                        resource_usage: 2,
-                    },
-                    ArgTask {
-                        start_time: b2,
-                        processing_time: 3,
-                        resource_usage: 2,
-                    },
-                    ArgTask {
-                        start_time: c,
-                        processing_time: 2,
-                        resource_usage: 4,
-                    },
-                    ArgTask {
-                        start_time: d,
-                        processing_time: 2,
-                        resource_usage: 2,
-                    },
-                    ArgTask {
-                        start_time: e,
-                        processing_time: 4,
-                        resource_usage: 2,
-                    },
-                    ArgTask {
-                        start_time: f,
-                        processing_time: 6,
-                        resource_usage: 2,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                5,
-                CumulativePropagatorOptions::default(),
-            ))
-            .expect("No conflict");
+        let propagator = TimeTableOverIntervalPropagator::new(
+            &[
+                ArgTask {
+                    start_time: a,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: b1,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: b2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: c,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(4, 4),
+                },
+                ArgTask {
+                    start_time: d,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: e,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: f,
+                    processing_time: solver.new_variable(6, 6),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(5, 5),
+            CumulativePropagatorOptions::default(),
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(a), 0);
         assert_eq!(solver.upper_bound(a), 1);
         assert_eq!(solver.lower_bound(c), 8);
@@ -835,7 +884,7 @@ mod tests {
         assert_eq!(solver.lower_bound(f), 0);
         assert_eq!(solver.upper_bound(f), 14);
 
-        let notification_status = solver.increase_lower_bound_and_notify(propagator, 4, e, 3);
+        let notification_status = solver.increase_lower_bound_and_notify(propagator, 5, e, 3);
         assert!(match notification_status {
             EnqueueDecision::Enqueue => true,
             EnqueueDecision::Skip => false,
@@ -851,29 +900,28 @@ mod tests {
         let s1 = solver.new_variable(1, 1);
         let s2 = solver.new_variable(1, 8);
 
-        let _ = solver
-            .new_propagator(TimeTableOverIntervalPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: s1,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2,
-                        processing_time: 3,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                1,
-                CumulativePropagatorOptions {
-                    explanation_type: CumulativeExplanationType::Naive,
-                    ..Default::default()
-                },
-            ))
-            .expect("No conflict");
+        let propagator = TimeTableOverIntervalPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 5);
         assert_eq!(solver.upper_bound(s2), 8);
         assert_eq!(solver.lower_bound(s1), 1);
@@ -890,34 +938,33 @@ mod tests {
         let s2 = solver.new_variable(5, 5);
         let s3 = solver.new_variable(1, 15);
 
-        let _ = solver
-            .new_propagator(TimeTableOverIntervalPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: s1,
-                        processing_time: 2,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2,
-                        processing_time: 2,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s3,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                1,
-                CumulativePropagatorOptions {
-                    explanation_type: CumulativeExplanationType::Naive,
-                    ..Default::default()
-                },
-            ))
-            .expect("No conflict");
+        let propagator = TimeTableOverIntervalPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s3,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s3), 7);
         assert_eq!(solver.upper_bound(s3), 15);
         assert_eq!(solver.lower_bound(s2), 5);
@@ -935,30 +982,29 @@ mod tests {
         let s1 = solver.new_variable(4, 4);
         let s2 = solver.new_variable(0, 8);
 
-        let _ = solver
-            .new_propagator(TimeTableOverIntervalPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: s1,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2,
-                        processing_time: 3,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                1,
-                CumulativePropagatorOptions {
-                    explanation_type: CumulativeExplanationType::Naive,
-                    allow_holes_in_domain: true,
-                    ..Default::default()
-                },
-            ))
-            .expect("No conflict");
+        let propagator = TimeTableOverIntervalPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                allow_holes_in_domain: true,
+                ..Default::default()
+            },
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 0);
         assert_eq!(solver.upper_bound(s2), 8);
         assert_eq!(solver.lower_bound(s1), 4);
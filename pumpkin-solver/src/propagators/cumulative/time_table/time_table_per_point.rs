@@ -22,6 +22,7 @@ use crate::engine::IntDomainEvent;
 use crate::predicates::PropositionalConjunction;
 use crate::propagators::cumulative::time_table::propagation_handler::create_conflict_explanation;
 use crate::propagators::util::create_tasks;
+use crate::propagators::util::mandatory_processing_time;
 use crate::propagators::util::register_tasks;
 use crate::propagators::util::update_bounds_task;
 use crate::propagators::ArgTask;
@@ -45,13 +46,13 @@ use crate::pumpkin_assert_extreme;
 /// Computer Science and Software Engineering, 2011.
 #[derive(Debug)]
 
-pub(crate) struct TimeTablePerPointPropagator<Var> {
+pub(crate) struct TimeTablePerPointPropagator<Var, PVar, RVar, CVar> {
     /// Stores whether the time-table is empty
     is_time_table_empty: bool,
     /// Stores the input parameters to the cumulative constraint
-    parameters: CumulativeParameters<Var>,
+    parameters: CumulativeParameters<Var, PVar, RVar, CVar>,
     /// Stores structures which change during the search; used to store the bounds
-    updatable_structures: UpdatableStructures<Var>,
+    updatable_structures: UpdatableStructures<Var, PVar, RVar>,
 }
 
 /// The type of the time-table used by propagators which use time-table reasoning per time-point;
@@ -61,14 +62,21 @@ pub(crate) struct TimeTablePerPointPropagator<Var> {
 /// The key t (representing a time-point) holds the mandatory resource consumption of tasks at
 /// that time (stored in a [`ResourceProfile`]); the [ResourceProfile]s are sorted based on
 /// start time and they are non-overlapping
-pub(crate) type PerPointTimeTableType<Var> = BTreeMap<u32, ResourceProfile<Var>>;
+pub(crate) type PerPointTimeTableType<Var, PVar, RVar> =
+    BTreeMap<u32, ResourceProfile<Var, PVar, RVar>>;
 
-impl<Var: IntegerVariable + 'static> TimeTablePerPointPropagator<Var> {
+impl<
+        Var: IntegerVariable + 'static,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
+        CVar: IntegerVariable + 'static,
+    > TimeTablePerPointPropagator<Var, PVar, RVar, CVar>
+{
     pub(crate) fn new(
-        arg_tasks: &[ArgTask<Var>],
-        capacity: i32,
+        arg_tasks: &[ArgTask<Var, PVar, RVar>],
+        capacity: CVar,
         cumulative_options: CumulativePropagatorOptions,
-    ) -> TimeTablePerPointPropagator<Var> {
+    ) -> TimeTablePerPointPropagator<Var, PVar, RVar, CVar> {
         let tasks = create_tasks(arg_tasks);
         let parameters = CumulativeParameters::new(tasks, capacity, cumulative_options);
         let updatable_structures = UpdatableStructures::new(&parameters);
@@ -81,7 +89,13 @@ impl<Var: IntegerVariable + 'static> TimeTablePerPointPropagator<Var> {
     }
 }
 
-impl<Var: IntegerVariable + 'static> Propagator for TimeTablePerPointPropagator<Var> {
+impl<
+        Var: IntegerVariable + 'static,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
+        CVar: IntegerVariable + 'static,
+    > Propagator for TimeTablePerPointPropagator<Var, PVar, RVar, CVar>
+{
     fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
         let time_table =
             create_time_table_per_point_from_scratch(context.as_readonly(), &self.parameters)?;
@@ -107,6 +121,12 @@ impl<Var: IntegerVariable + 'static> Propagator for TimeTablePerPointPropagator<
         local_id: LocalId,
         event: OpaqueDomainEvent,
     ) -> EnqueueDecision {
+        if local_id.unpack() as usize >= self.parameters.tasks.len() {
+            // The processing time or resource usage of a task or the capacity has been updated;
+            // the time-table is recalculated from scratch anyways
+            return EnqueueDecision::Enqueue;
+        }
+
         let updated_task = Rc::clone(&self.parameters.tasks[local_id.unpack() as usize]);
         // Note that it could be the case that `is_time_table_empty` is inaccurate here since it
         // wasn't updated in `synchronise`; however, `synchronise` will only remove profiles
@@ -147,13 +167,22 @@ impl<Var: IntegerVariable + 'static> Propagator for TimeTablePerPointPropagator<
         "CumulativeTimeTablePerPoint"
     }
 
+    fn inference_label(&self) -> &str {
+        "time_table"
+    }
+
     fn initialise_at_root(
         &mut self,
         context: &mut PropagatorInitialisationContext,
     ) -> Result<(), PropositionalConjunction> {
         self.updatable_structures
             .initialise_bounds_and_remove_fixed(context.as_readonly(), &self.parameters);
-        register_tasks(&self.parameters.tasks, context, false);
+        register_tasks(
+            &self.parameters.tasks,
+            &self.parameters.capacity,
+            context,
+            false,
+        );
 
         Ok(())
     }
@@ -180,36 +209,41 @@ impl<Var: IntegerVariable + 'static> Propagator for TimeTablePerPointPropagator<
 /// conflict in the form of an [`Inconsistency`].
 pub(crate) fn create_time_table_per_point_from_scratch<
     Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
     Context: ReadDomains + Copy,
 >(
     context: Context,
-    parameters: &CumulativeParameters<Var>,
-) -> Result<PerPointTimeTableType<Var>, PropositionalConjunction> {
-    let mut time_table: PerPointTimeTableType<Var> = PerPointTimeTableType::new();
+    parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
+) -> Result<PerPointTimeTableType<Var, PVar, RVar>, PropositionalConjunction> {
+    let mut time_table: PerPointTimeTableType<Var, PVar, RVar> = PerPointTimeTableType::new();
     // First we go over all tasks and determine their mandatory parts
     for task in parameters.tasks.iter() {
         let upper_bound = context.upper_bound(&task.start_variable);
         let lower_bound = context.lower_bound(&task.start_variable);
+        let processing_time = mandatory_processing_time(context, task);
 
-        if upper_bound < lower_bound + task.processing_time {
+        if upper_bound < lower_bound + processing_time {
             // There is a mandatory part
-            for i in upper_bound..(lower_bound + task.processing_time) {
+            for i in upper_bound..(lower_bound + processing_time) {
                 // For every time-point of the mandatory part,
                 //  add the resource usage of the current task to the ResourceProfile and add it
                 // to the profile tasks of the resource
-                let current_profile: &mut ResourceProfile<Var> = time_table
+                let current_profile: &mut ResourceProfile<Var, PVar, RVar> = time_table
                     .entry(i as u32)
                     .or_insert(ResourceProfile::default(i));
-                current_profile.height += task.resource_usage;
+                current_profile.height += context.lower_bound(&task.resource_usage);
                 current_profile.profile_tasks.push(Rc::clone(task));
 
-                if current_profile.height > parameters.capacity {
+                if current_profile.height > context.upper_bound(&parameters.capacity) {
                     // The addition of the current task to the resource profile has caused an
                     // overflow
                     return Err(create_conflict_explanation(
                         context,
                         current_profile,
                         parameters.options.explanation_type,
+                        &parameters.capacity,
                     ));
                 }
             }
@@ -224,10 +258,15 @@ pub(crate) fn create_time_table_per_point_from_scratch<
     Ok(time_table)
 }
 
-pub(crate) fn debug_propagate_from_scratch_time_table_point<Var: IntegerVariable + 'static>(
+pub(crate) fn debug_propagate_from_scratch_time_table_point<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
+>(
     context: &mut PropagationContextMut,
-    parameters: &CumulativeParameters<Var>,
-    updatable_structures: &UpdatableStructures<Var>,
+    parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
+    updatable_structures: &UpdatableStructures<Var, PVar, RVar>,
 ) -> PropagationStatusCP {
     // We first create a time-table per point and return an error if there was
     // an overflow of the resource capacity while building the time-table
@@ -260,26 +299,25 @@ mod tests {
         let s1 = solver.new_variable(1, 1);
         let s2 = solver.new_variable(1, 8);
 
-        let _ = solver
-            .new_propagator(TimeTablePerPointPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: s1,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2,
-                        processing_time: 3,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                1,
-                CumulativePropagatorOptions::default(),
-            ))
-            .expect("No conflict");
+        let propagator = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions::default(),
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 5);
         assert_eq!(solver.upper_bound(s2), 8);
         assert_eq!(solver.lower_bound(s1), 1);
@@ -292,27 +330,28 @@ mod tests {
         let s1 = solver.new_variable(1, 1);
         let s2 = solver.new_variable(1, 1);
 
-        let result = solver.new_propagator(TimeTablePerPointPropagator::new(
+        let propagator = TimeTablePerPointPropagator::new(
             &[
                 ArgTask {
                     start_time: s1,
-                    processing_time: 4,
-                    resource_usage: 1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
                 },
                 ArgTask {
                     start_time: s2,
-                    processing_time: 4,
-                    resource_usage: 1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
                 },
             ]
             .into_iter()
             .collect::<Vec<_>>(),
-            1,
+            solver.new_variable(1, 1),
             CumulativePropagatorOptions {
                 explanation_type: CumulativeExplanationType::Naive,
                 ..Default::default()
             },
-        ));
+        );
+        let result = solver.new_propagator(propagator);
         assert!(match result {
             Err(e) => match e {
                 Inconsistency::EmptyDomain => false,
@@ -340,26 +379,25 @@ mod tests {
         let s1 = solver.new_variable(0, 6);
         let s2 = solver.new_variable(0, 6);
 
-        let _ = solver
-            .new_propagator(TimeTablePerPointPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: s1,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2,
-                        processing_time: 3,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                1,
-                CumulativePropagatorOptions::default(),
-            ))
-            .expect("No conflict");
+        let propagator = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions::default(),
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 0);
         assert_eq!(solver.upper_bound(s2), 6);
         assert_eq!(solver.lower_bound(s1), 0);
@@ -376,46 +414,45 @@ mod tests {
         let b = solver.new_variable(2, 3);
         let a = solver.new_variable(0, 1);
 
-        let _ = solver
-            .new_propagator(TimeTablePerPointPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: a,
-                        processing_time: 2,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: b,
-                        processing_time: 6,
-                        resource_usage: 2,
-                    },
-                    ArgTask {
-                        start_time: c,
-                        processing_time: 2,
-                        resource_usage: 4,
-                    },
-                    ArgTask {
-                        start_time: d,
-                        processing_time: 2,
-                        resource_usage: 2,
-                    },
-                    ArgTask {
-                        start_time: e,
-                        processing_time: 5,
-                        resource_usage: 2,
-                    },
-                    ArgTask {
-                        start_time: f,
-                        processing_time: 6,
-                        resource_usage: 2,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                5,
-                CumulativePropagatorOptions::default(),
-            ))
-            .expect("No conflict");
+        let propagator = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: a,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: b,
+                    processing_time: solver.new_variable(6, 6),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: c,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(4, 4),
+                },
+                ArgTask {
+                    start_time: d,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: e,
+                    processing_time: solver.new_variable(5, 5),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: f,
+                    processing_time: solver.new_variable(6, 6),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(5, 5),
+            CumulativePropagatorOptions::default(),
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(f), 10);
     }
 
@@ -425,26 +462,25 @@ mod tests {
         let s1 = solver.new_variable(0, 6);
         let s2 = solver.new_variable(6, 10);
 
-        let propagator = solver
-            .new_propagator(TimeTablePerPointPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: s1,
-                        processing_time: 2,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2,
-                        processing_time: 3,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                1,
-                CumulativePropagatorOptions::default(),
-            ))
-            .expect("No conflict");
+        let propagator = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions::default(),
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 6);
         assert_eq!(solver.upper_bound(s2), 10);
         assert_eq!(solver.lower_bound(s1), 0);
@@ -469,29 +505,28 @@ mod tests {
         let s1 = solver.new_variable(6, 6);
         let s2 = solver.new_variable(1, 8);
 
-        let propagator = solver
-            .new_propagator(TimeTablePerPointPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: s1,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2,
-                        processing_time: 3,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                1,
-                CumulativePropagatorOptions {
-                    explanation_type: CumulativeExplanationType::Naive,
-                    ..Default::default()
-                },
-            ))
-            .expect("No conflict");
+        let propagator = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
         let result = solver.propagate_until_fixed_point(propagator);
         assert!(result.is_ok());
         assert_eq!(solver.lower_bound(s2), 1);
@@ -513,46 +548,45 @@ mod tests {
         let b = solver.new_variable(2, 3);
         let a = solver.new_variable(0, 1);
 
-        let propagator = solver
-            .new_propagator(TimeTablePerPointPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: a,
-                        processing_time: 2,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: b,
-                        processing_time: 6,
-                        resource_usage: 2,
-                    },
-                    ArgTask {
-                        start_time: c,
-                        processing_time: 2,
-                        resource_usage: 4,
-                    },
-                    ArgTask {
-                        start_time: d,
-                        processing_time: 2,
-                        resource_usage: 2,
-                    },
-                    ArgTask {
-                        start_time: e,
-                        processing_time: 4,
-                        resource_usage: 2,
-                    },
-                    ArgTask {
-                        start_time: f,
-                        processing_time: 6,
-                        resource_usage: 2,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                5,
-                CumulativePropagatorOptions::default(),
-            ))
-            .expect("No conflict");
+        let propagator = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: a,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: b,
+                    processing_time: solver.new_variable(6, 6),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: c,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(4, 4),
+                },
+                ArgTask {
+                    start_time: d,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: e,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: f,
+                    processing_time: solver.new_variable(6, 6),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(5, 5),
+            CumulativePropagatorOptions::default(),
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(a), 0);
         assert_eq!(solver.upper_bound(a), 1);
         assert_eq!(solver.lower_bound(b), 2);
@@ -566,7 +600,7 @@ mod tests {
         assert_eq!(solver.lower_bound(f), 0);
         assert_eq!(solver.upper_bound(f), 14);
 
-        let notification_status = solver.increase_lower_bound_and_notify(propagator, 3, e, 3);
+        let notification_status = solver.increase_lower_bound_and_notify(propagator, 4, e, 3);
         assert!(match notification_status {
             EnqueueDecision::Enqueue => true,
             EnqueueDecision::Skip => false,
@@ -587,51 +621,50 @@ mod tests {
         let b1 = solver.new_variable(3, 3);
         let a = solver.new_variable(0, 1);
 
-        let propagator = solver
-            .new_propagator(TimeTablePerPointPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: a,
-                        processing_time: 2,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: b1,
-                        processing_time: 2,
-                        resource_usage: 2,
-                    },
-                    ArgTask {
-                        start_time: b2,
-                        processing_time: 3,
-                        resource_usage: 2,
-                    },
-                    ArgTask {
-                        start_time: c,
-                        processing_time: 2,
-                        resource_usage: 4,
-                    },
-                    ArgTask {
-                        start_time: d,
-                        processing_time: 2,
-                        resource_usage: 2,
-                    },
-                    ArgTask {
-                        start_time: e,
-                        processing_time: 4,
-                        resource_usage: 2,
-                    },
-                    ArgTask {
-                        start_time: f,
-                        processing_time: 6,
-                        resource_usage: 2,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                5,
-                CumulativePropagatorOptions::default(),
-            ))
-            .expect("No conflict");
+        let propagator = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: a,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: b1,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: b2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: c,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(4, 4),
+                },
+                ArgTask {
+                    start_time: d,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: e,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+                ArgTask {
+                    start_time: f,
+                    processing_time: solver.new_variable(6, 6),
+                    resource_usage: solver.new_variable(2, 2),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(5, 5),
+            CumulativePropagatorOptions::default(),
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(a), 0);
         assert_eq!(solver.upper_bound(a), 1);
         assert_eq!(solver.lower_bound(c), 8);
@@ -643,7 +676,7 @@ mod tests {
         assert_eq!(solver.lower_bound(f), 0);
         assert_eq!(solver.upper_bound(f), 14);
 
-        let notification_status = solver.increase_lower_bound_and_notify(propagator, 4, e, 3);
+        let notification_status = solver.increase_lower_bound_and_notify(propagator, 5, e, 3);
         assert!(match notification_status {
             EnqueueDecision::Enqueue => true,
             EnqueueDecision::Skip => false,
@@ -659,29 +692,28 @@ mod tests {
         let s1 = solver.new_variable(1, 1);
         let s2 = solver.new_variable(1, 8);
 
-        let _ = solver
-            .new_propagator(TimeTablePerPointPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: s1,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2,
-                        processing_time: 3,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                1,
-                CumulativePropagatorOptions {
-                    explanation_type: CumulativeExplanationType::Naive,
-                    ..Default::default()
-                },
-            ))
-            .expect("No conflict");
+        let propagator = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 5);
         assert_eq!(solver.upper_bound(s2), 8);
         assert_eq!(solver.lower_bound(s1), 1);
@@ -708,34 +740,33 @@ mod tests {
         let s2 = solver.new_variable(5, 5);
         let s3 = solver.new_variable(1, 15);
 
-        let _ = solver
-            .new_propagator(TimeTablePerPointPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: s1,
-                        processing_time: 2,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2,
-                        processing_time: 2,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s3,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                1,
-                CumulativePropagatorOptions {
-                    explanation_type: CumulativeExplanationType::Naive,
-                    ..Default::default()
-                },
-            ))
-            .expect("No conflict");
+        let propagator = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s3,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s3), 7);
         assert_eq!(solver.upper_bound(s3), 15);
         assert_eq!(solver.lower_bound(s2), 5);
@@ -760,30 +791,29 @@ mod tests {
         let s1 = solver.new_variable(4, 4);
         let s2 = solver.new_variable(0, 8);
 
-        let _ = solver
-            .new_propagator(TimeTablePerPointPropagator::new(
-                &[
-                    ArgTask {
-                        start_time: s1,
-                        processing_time: 4,
-                        resource_usage: 1,
-                    },
-                    ArgTask {
-                        start_time: s2,
-                        processing_time: 3,
-                        resource_usage: 1,
-                    },
-                ]
-                .into_iter()
-                .collect::<Vec<_>>(),
-                1,
-                CumulativePropagatorOptions {
-                    explanation_type: CumulativeExplanationType::Naive,
-                    allow_holes_in_domain: true,
-                    ..Default::default()
-                },
-            ))
-            .expect("No conflict");
+        let propagator = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(3, 3),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                allow_holes_in_domain: true,
+                ..Default::default()
+            },
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
         assert_eq!(solver.lower_bound(s2), 0);
         assert_eq!(solver.upper_bound(s2), 8);
         assert_eq!(solver.lower_bound(s1), 4);
@@ -795,4 +825,254 @@ mod tests {
             assert_eq!(conjunction!([s1 <= 4] & [s1 >= 4]), reason);
         }
     }
+
+    #[test]
+    fn propagator_propagates_with_variable_processing_times_and_resource_usages() {
+        let mut solver = TestSolver::default();
+        let s1 = solver.new_variable(1, 1);
+        let s2 = solver.new_variable(1, 8);
+        let p1 = solver.new_variable(3, 6);
+        let p2 = solver.new_variable(2, 5);
+        let r1 = solver.new_variable(0, 2);
+        let r2 = solver.new_variable(1, 1);
+
+        // The bounds are tightened, so they are not implied by the initial domains
+        let _ = solver.remove(p1, 3);
+        let _ = solver.remove(p2, 2);
+        let _ = solver.remove(r1, 0);
+
+        let propagator = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: p1,
+                    resource_usage: r1,
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: p2,
+                    resource_usage: r2,
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
+        assert_eq!(solver.lower_bound(s2), 5);
+        assert_eq!(solver.upper_bound(s2), 8);
+        assert_eq!(solver.lower_bound(p1), 4);
+        assert_eq!(solver.lower_bound(r1), 1);
+
+        let reason = solver.get_reason_int(predicate!(s2 >= 5));
+        assert_eq!(
+            conjunction!([s2 >= 4] & [p2 >= 3] & [s1 >= 1] & [s1 <= 1] & [p1 >= 4] & [r1 >= 1]),
+            reason
+        );
+    }
+
+    #[test]
+    fn propagator_detects_conflict_with_variable_capacity() {
+        let mut solver = TestSolver::default();
+        let s1 = solver.new_variable(1, 1);
+        let s2 = solver.new_variable(1, 5);
+        let capacity = solver.new_variable(1, 2);
+
+        let propagator = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            capacity,
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let propagator = solver.new_propagator(propagator).expect("No conflict");
+
+        // The capacity is registered with the local id directly after those of the resource usages
+        let notification_status =
+            solver.decrease_upper_bound_and_notify(propagator, 6, capacity, 1);
+        assert!(matches!(notification_status, EnqueueDecision::Enqueue));
+        let notification_status = solver.decrease_upper_bound_and_notify(propagator, 1, s2, 1);
+        assert!(matches!(notification_status, EnqueueDecision::Enqueue));
+
+        let result = solver.propagate(propagator);
+        assert!(matches!(
+            result,
+            Err(Inconsistency::Conflict(conflict)) if conflict == conjunction!(
+                [s1 >= 1] & [s1 <= 1] & [s2 >= 1] & [s2 <= 1] & [capacity <= 1]
+            )
+        ));
+    }
+
+    #[test]
+    fn propagator_propagates_lower_bound_capacity() {
+        let mut solver = TestSolver::default();
+        let s1 = solver.new_variable(1, 1);
+        let s2 = solver.new_variable(2, 2);
+        let capacity = solver.new_variable(0, 3);
+
+        let propagator = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            capacity,
+            CumulativePropagatorOptions::default(),
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
+        assert_eq!(solver.lower_bound(capacity), 2);
+        assert_eq!(solver.upper_bound(capacity), 3);
+
+        let reason = solver.get_reason_int(predicate!(capacity >= 2));
+        assert_eq!(
+            conjunction!([s1 >= 1] & [s1 <= 4] & [s2 >= 1] & [s2 <= 4]),
+            reason
+        );
+    }
+
+    #[test]
+    fn propagator_propagates_upper_bound_resource_usage() {
+        let mut solver = TestSolver::default();
+        let s1 = solver.new_variable(1, 1);
+        let s2 = solver.new_variable(2, 2);
+        let r1 = solver.new_variable(1, 1);
+        let r2 = solver.new_variable(0, 3);
+        let capacity = solver.new_variable(1, 3);
+
+        // The bounds are tightened, so they are not implied by the initial domains
+        let _ = solver.remove(r2, 0);
+        let _ = solver.remove(capacity, 3);
+
+        let propagator = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: r1,
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: solver.new_variable(4, 4),
+                    resource_usage: r2,
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            capacity,
+            CumulativePropagatorOptions::default(),
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
+        assert_eq!(solver.lower_bound(r2), 1);
+        assert_eq!(solver.upper_bound(r2), 1);
+
+        // The resource usage of the propagated task itself is not part of the reason
+        let reason = solver.get_reason_int(predicate!(r2 <= 1));
+        assert_eq!(
+            conjunction!([s1 >= -1] & [s1 <= 2] & [s2 >= -1] & [s2 <= 2] & [capacity <= 2]),
+            reason
+        );
+    }
+
+    #[test]
+    fn propagator_propagates_upper_bound_processing_time() {
+        let mut solver = TestSolver::default();
+        let s1 = solver.new_variable(5, 5);
+        let s2 = solver.new_variable(1, 3);
+        let p2 = solver.new_variable(1, 8);
+
+        let propagator = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: p2,
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions::default(),
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
+        assert_eq!(solver.lower_bound(s2), 1);
+        assert_eq!(solver.upper_bound(s2), 3);
+        assert_eq!(solver.lower_bound(p2), 1);
+        assert_eq!(solver.upper_bound(p2), 4);
+
+        let reason = solver.get_reason_int(predicate!(p2 <= 4));
+        assert_eq!(
+            conjunction!([s1 >= 4] & [s1 <= 5] & [s2 >= 1] & [s2 <= 5]),
+            reason
+        );
+    }
+
+    #[test]
+    fn propagator_propagates_upper_bound_processing_time_naive() {
+        let mut solver = TestSolver::default();
+        let s1 = solver.new_variable(5, 5);
+        let s2 = solver.new_variable(1, 3);
+        let p2 = solver.new_variable(1, 8);
+
+        let propagator = TimeTablePerPointPropagator::new(
+            &[
+                ArgTask {
+                    start_time: s1,
+                    processing_time: solver.new_variable(2, 2),
+                    resource_usage: solver.new_variable(1, 1),
+                },
+                ArgTask {
+                    start_time: s2,
+                    processing_time: p2,
+                    resource_usage: solver.new_variable(1, 1),
+                },
+            ]
+            .into_iter()
+            .collect::<Vec<_>>(),
+            solver.new_variable(1, 1),
+            CumulativePropagatorOptions {
+                explanation_type: CumulativeExplanationType::Naive,
+                ..Default::default()
+            },
+        );
+        let _ = solver.new_propagator(propagator).expect("No conflict");
+        assert_eq!(solver.upper_bound(p2), 4);
+
+        let reason = solver.get_reason_int(predicate!(p2 <= 4));
+        assert_eq!(
+            conjunction!([s1 >= 5] & [s1 <= 5] & [s2 >= 1] & [s2 <= 3]),
+            reason
+        );
+    }
 }
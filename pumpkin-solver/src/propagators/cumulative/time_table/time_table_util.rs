@@ -13,7 +13,9 @@ use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
 use crate::engine::propagation::ReadDomains;
 use crate::engine::variables::IntegerVariable;
+use crate::propagators::cumulative::time_table::explanations::create_capacity_predicate;
 use crate::propagators::cumulative::time_table::propagation_handler::CumulativePropagationHandler;
+use crate::propagators::util::mandatory_processing_time;
 use crate::propagators::CumulativeParameters;
 use crate::propagators::ResourceProfile;
 use crate::propagators::Task;
@@ -25,7 +27,7 @@ use crate::pumpkin_assert_moderate;
 /// The result of [`should_enqueue`], contains the [`EnqueueDecision`] whether the propagator should
 /// currently be enqueued and potentially the updated [`Task`] (in the form of a
 /// [`UpdatedTaskInfo`]) if the mandatory part of this [`Task`] has changed.
-pub(crate) struct ShouldEnqueueResult<Var> {
+pub(crate) struct ShouldEnqueueResult<Var, PVar, RVar> {
     /// Whether the propagator which called this method should be enqueued
     pub(crate) decision: EnqueueDecision,
     /// If the mandatory part of the task passed to [`should_enqueue`] has changed then this field
@@ -33,20 +35,25 @@ pub(crate) struct ShouldEnqueueResult<Var> {
     ///
     /// In general, non-incremental propagators will not make use of this field since they will
     /// propagate from scratch anyways.
-    pub(crate) update: Option<UpdatedTaskInfo<Var>>,
+    pub(crate) update: Option<UpdatedTaskInfo<Var, PVar, RVar>>,
 }
 
 /// Determines whether a time-table propagator should enqueue and returns a structure containing the
 /// [`EnqueueDecision`] and the info of the task with the extended mandatory part (or [`None`] if no
 /// such task exists). This method should be called in the
 /// [`ConstraintProgrammingPropagator::notify`] method.
-pub(crate) fn should_enqueue<Var: IntegerVariable + 'static>(
-    parameters: &CumulativeParameters<Var>,
-    updatable_structures: &UpdatableStructures<Var>,
-    updated_task: &Rc<Task<Var>>,
+pub(crate) fn should_enqueue<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
+>(
+    parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
+    updatable_structures: &UpdatableStructures<Var, PVar, RVar>,
+    updated_task: &Rc<Task<Var, PVar, RVar>>,
     context: PropagationContext,
     empty_time_table: bool,
-) -> ShouldEnqueueResult<Var> {
+) -> ShouldEnqueueResult<Var, PVar, RVar> {
     pumpkin_assert_extreme!(
         context.lower_bound(&updated_task.start_variable) > updatable_structures.get_stored_lower_bound(updated_task)
             || updatable_structures.get_stored_upper_bound(updated_task)
@@ -104,42 +111,55 @@ pub(crate) fn should_enqueue<Var: IntegerVariable + 'static>(
     result
 }
 
-pub(crate) fn has_mandatory_part<Var: IntegerVariable + 'static>(
+pub(crate) fn has_mandatory_part<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
     context: PropagationContext,
-    task: &Rc<Task<Var>>,
+    task: &Rc<Task<Var, PVar, RVar>>,
 ) -> bool {
     context.upper_bound(&task.start_variable)
-        < context.lower_bound(&task.start_variable) + task.processing_time
+        < context.lower_bound(&task.start_variable) + mandatory_processing_time(context, task)
 }
 
 /// Checks whether a specific task (indicated by id) has a mandatory part which overlaps with the
 /// interval [start, end]
-pub(crate) fn has_mandatory_part_in_interval<Var: IntegerVariable + 'static>(
+pub(crate) fn has_mandatory_part_in_interval<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
     context: PropagationContext,
-    task: &Rc<Task<Var>>,
-    start: i32,
-    end: i32,
+    task: &Rc<Task<Var, PVar, RVar>>,
+    start: i64,
+    end: i64,
 ) -> bool {
     let (lower_bound, upper_bound) = (
         context.lower_bound(&task.start_variable),
         context.upper_bound(&task.start_variable),
     );
+    let processing_time = mandatory_processing_time(context, task);
     // There exists a mandatory part
-    (upper_bound < (lower_bound + task.processing_time))
-        && has_overlap_with_interval(upper_bound, lower_bound + task.processing_time, start, end)
+    (upper_bound < (lower_bound + processing_time))
+        && has_overlap_with_interval(upper_bound, lower_bound + processing_time, start, end)
     // Determine whether the mandatory part overlaps with the provided bounds
 }
 
 /// Checks whether the lower and upper bound of a task overlap with the provided interval
-pub(crate) fn task_has_overlap_with_interval<Var: IntegerVariable + 'static>(
+pub(crate) fn task_has_overlap_with_interval<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
     context: PropagationContext,
-    task: &Rc<Task<Var>>,
-    start: i32,
-    end: i32,
+    task: &Rc<Task<Var, PVar, RVar>>,
+    start: i64,
+    end: i64,
 ) -> bool {
     let (lower_bound, upper_bound) = (
         context.lower_bound(&task.start_variable),
-        context.upper_bound(&task.start_variable) + task.processing_time,
+        context.upper_bound(&task.start_variable) + context.lower_bound(&task.processing_time),
     ); // The release time of the task and the deadline
     has_overlap_with_interval(lower_bound, upper_bound, start, end)
 }
@@ -147,10 +167,10 @@ pub(crate) fn task_has_overlap_with_interval<Var: IntegerVariable + 'static>(
 /// Determines whether the interval \[lower_bound, upper_bound\) overlaps with the interval \[start,
 /// end\]
 pub(crate) fn has_overlap_with_interval(
-    lower_bound: i32,
-    upper_bound: i32,
-    start: i32,
-    end: i32,
+    lower_bound: i64,
+    upper_bound: i64,
+    start: i64,
+    end: i64,
 ) -> bool {
     start < upper_bound && lower_bound <= end
 }
@@ -159,8 +179,13 @@ pub(crate) fn has_overlap_with_interval(
 /// based on start time and that the profiles are maximal (i.e. the [`ResourceProfile::start`] and
 /// [`ResourceProfile::end`] cannot be increased or decreased, respectively). It returns true if
 /// both of these invariants hold and false otherwise.
-fn debug_check_whether_profiles_are_maximal_and_sorted<'a, Var: IntegerVariable + 'static>(
-    time_table: impl Iterator<Item = &'a ResourceProfile<Var>> + Clone,
+fn debug_check_whether_profiles_are_maximal_and_sorted<
+    'a,
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
+    time_table: impl Iterator<Item = &'a ResourceProfile<Var, PVar, RVar>> + Clone,
 ) -> bool {
     let collected_time_table = time_table.clone().collect::<Vec<_>>();
     let sorted_profiles = collected_time_table.is_empty()
@@ -198,11 +223,17 @@ fn debug_check_whether_profiles_are_maximal_and_sorted<'a, Var: IntegerVariable
 /// sorted in increasing order in terms of [`ResourceProfile::start`] and that the
 /// [`ResourceProfile`] is maximal (i.e. the [`ResourceProfile::start`] and [`ResourceProfile::end`]
 /// cannot be increased or decreased, respectively).
-pub(crate) fn propagate_based_on_timetable<'a, Var: IntegerVariable + 'static>(
+pub(crate) fn propagate_based_on_timetable<
+    'a,
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
+>(
     context: &mut PropagationContextMut,
-    time_table: impl Iterator<Item = &'a ResourceProfile<Var>> + Clone,
-    parameters: &CumulativeParameters<Var>,
-    updatable_structures: &mut UpdatableStructures<Var>,
+    time_table: impl Iterator<Item = &'a ResourceProfile<Var, PVar, RVar>> + Clone,
+    parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
+    updatable_structures: &mut UpdatableStructures<Var, PVar, RVar>,
 ) -> PropagationStatusCP {
     pumpkin_assert_extreme!(
         debug_check_whether_profiles_are_maximal_and_sorted(time_table.clone()),
@@ -222,6 +253,8 @@ pub(crate) fn propagate_based_on_timetable<'a, Var: IntegerVariable + 'static>(
         "All of the fixed tasks should be fixed at this point"
     );
 
+    propagate_capacity_and_task_data(context, time_table.clone(), parameters)?;
+
     if parameters.options.generate_sequence {
         propagate_sequence_of_profiles(context, time_table, updatable_structures, parameters)?;
     } else {
@@ -231,6 +264,86 @@ pub(crate) fn propagate_based_on_timetable<'a, Var: IntegerVariable + 'static>(
     Ok(())
 }
 
+/// Propagates the capacity, the resource usages and the processing times based on the provided
+/// time-table:
+/// - The lower-bound of the capacity is at least the height of the highest profile.
+/// - The resource usage of a task which is part of a profile is at most the upper-bound of the
+///   capacity minus the resource usage of the other tasks in that profile.
+/// - The processing time of a task is at most the distance between its earliest start time and the
+///   start of the first profile after its latest start time which it would overflow.
+///
+/// Note that these propagations do not change the mandatory parts of the tasks; the provided
+/// time-table thus remains valid.
+fn propagate_capacity_and_task_data<
+    'a,
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
+>(
+    context: &mut PropagationContextMut,
+    time_table: impl Iterator<Item = &'a ResourceProfile<Var, PVar, RVar>> + Clone,
+    parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
+) -> PropagationStatusCP {
+    // We create the structure responsible for propagations and explanations
+    let mut propagation_handler = CumulativePropagationHandler::new(
+        parameters.options.explanation_type,
+        create_capacity_predicate(context.as_readonly(), &parameters.capacity),
+    );
+
+    if let Some(highest_profile) = time_table.clone().max_by_key(|profile| profile.height) {
+        if highest_profile.height > context.lower_bound(&parameters.capacity) {
+            propagation_handler.propagate_capacity_lower_bound(
+                context,
+                highest_profile,
+                &parameters.capacity,
+            )?;
+        }
+    }
+
+    let capacity = context.upper_bound(&parameters.capacity);
+
+    for profile in time_table.clone() {
+        for task in profile.profile_tasks.iter() {
+            // The other tasks in the profile leave `capacity - (profile.height - lb(r))` of the
+            // resource for the task
+            if context.upper_bound(&task.resource_usage)
+                > capacity - profile.height + context.lower_bound(&task.resource_usage)
+            {
+                propagation_handler
+                    .propagate_resource_usage_upper_bound(context, profile, task, capacity)?;
+            }
+        }
+    }
+
+    for task in parameters.tasks.iter() {
+        if context.is_fixed(&task.processing_time) {
+            continue;
+        }
+
+        let earliest_start = context.lower_bound(&task.start_variable);
+        let latest_start = context.upper_bound(&task.start_variable);
+
+        // We find the first profile after the latest start time of the task which the task would
+        // overflow; note that a profile which starts after the end of the mandatory part of the
+        // task does not contain the task
+        let overflowing_profile = time_table.clone().find(|profile| {
+            profile.start >= latest_start
+                && profile.start >= earliest_start + context.lower_bound(&task.processing_time)
+                && profile.height + context.lower_bound(&task.resource_usage) > capacity
+        });
+
+        if let Some(profile) = overflowing_profile {
+            if earliest_start + context.upper_bound(&task.processing_time) > profile.start {
+                propagation_handler
+                    .propagate_processing_time_upper_bound(context, profile, task)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// For each profile in chronological order, this method goes through the tasks and checks whether
 /// the profile can propagate the domain of the task.
 ///
@@ -240,15 +353,23 @@ pub(crate) fn propagate_based_on_timetable<'a, Var: IntegerVariable + 'static>(
 ///
 /// This type of propagation is likely to be less beneficial for the explanation
 /// [`CumulativeExplanationType::Pointwise`].
-fn propagate_single_profiles<'a, Var: IntegerVariable + 'static>(
+fn propagate_single_profiles<
+    'a,
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
+>(
     context: &mut PropagationContextMut,
-    time_table: impl Iterator<Item = &'a ResourceProfile<Var>> + Clone,
-    updatable_structures: &mut UpdatableStructures<Var>,
-    parameters: &CumulativeParameters<Var>,
+    time_table: impl Iterator<Item = &'a ResourceProfile<Var, PVar, RVar>> + Clone,
+    updatable_structures: &mut UpdatableStructures<Var, PVar, RVar>,
+    parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
 ) -> PropagationStatusCP {
     // We create the structure responsible for propagations and explanations
-    let mut propagation_handler =
-        CumulativePropagationHandler::new(parameters.options.explanation_type);
+    let mut propagation_handler = CumulativePropagationHandler::new(
+        parameters.options.explanation_type,
+        create_capacity_predicate(context.as_readonly(), &parameters.capacity),
+    );
 
     // Then we go over all of the profiles in the time-table
     'profile_loop: for profile in time_table {
@@ -274,7 +395,10 @@ fn propagate_single_profiles<'a, Var: IntegerVariable + 'static>(
                 }
                 continue;
             }
-            if profile.start > context.upper_bound(&task.start_variable) + task.processing_time {
+            if profile.start
+                > context.upper_bound(&task.start_variable)
+                    + context.lower_bound(&task.processing_time)
+            {
                 // The start of the current profile is necessarily after the latest
                 // completion time of the task under consideration The profiles are
                 // sorted by start time (and non-overlapping) so we can remove the task from
@@ -323,15 +447,23 @@ fn propagate_single_profiles<'a, Var: IntegerVariable + 'static>(
 ///
 /// Especially in the case of [`CumulativeExplanationType::Pointwise`] this is likely to be
 /// beneficial.
-fn propagate_sequence_of_profiles<'a, Var: IntegerVariable + 'static>(
+fn propagate_sequence_of_profiles<
+    'a,
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
+>(
     context: &mut PropagationContextMut,
-    time_table: impl Iterator<Item = &'a ResourceProfile<Var>> + Clone,
-    updatable_structures: &UpdatableStructures<Var>,
-    parameters: &CumulativeParameters<Var>,
+    time_table: impl Iterator<Item = &'a ResourceProfile<Var, PVar, RVar>> + Clone,
+    updatable_structures: &UpdatableStructures<Var, PVar, RVar>,
+    parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
 ) -> PropagationStatusCP {
     // We create the structure responsible for propagations and explanations
-    let mut propagation_handler =
-        CumulativePropagationHandler::new(parameters.options.explanation_type);
+    let mut propagation_handler = CumulativePropagationHandler::new(
+        parameters.options.explanation_type,
+        create_capacity_predicate(context.as_readonly(), &parameters.capacity),
+    );
 
     // We collect the time-table since we will need to index into it
     let time_table = time_table.collect::<Vec<_>>();
@@ -348,7 +480,10 @@ fn propagate_sequence_of_profiles<'a, Var: IntegerVariable + 'static>(
         'profile_loop: while profile_index < time_table.len() {
             let profile = time_table[profile_index];
 
-            if profile.start > context.upper_bound(&task.start_variable) + task.processing_time {
+            if profile.start
+                > context.upper_bound(&task.start_variable)
+                    + context.lower_bound(&task.processing_time)
+            {
                 // The profiles are sorted, if we cannot update using this one then we cannot update
                 // using the subsequent profiles, we can break from the loop
                 break 'profile_loop;
@@ -373,7 +508,7 @@ fn propagate_sequence_of_profiles<'a, Var: IntegerVariable + 'static>(
                 context.as_readonly(),
                 task,
                 profile,
-                parameters.capacity,
+                context.upper_bound(&parameters.capacity),
             ) {
                 // We find the index (non-inclusive) of the last profile in the chain of lower-bound
                 // propagations
@@ -382,7 +517,7 @@ fn propagate_sequence_of_profiles<'a, Var: IntegerVariable + 'static>(
                     &time_table,
                     context.as_readonly(),
                     task,
-                    parameters.capacity,
+                    context.upper_bound(&parameters.capacity),
                 );
 
                 // Then we provide the propagation handler with the chain of profiles and propagate
@@ -402,7 +537,7 @@ fn propagate_sequence_of_profiles<'a, Var: IntegerVariable + 'static>(
                 context.as_readonly(),
                 task,
                 profile,
-                parameters.capacity,
+                context.upper_bound(&parameters.capacity),
             ) {
                 // We find the index (inclusive) of the last profile in the chain of upper-bound
                 // propagations (note that the index of this last profile in the chain is `<=
@@ -412,7 +547,7 @@ fn propagate_sequence_of_profiles<'a, Var: IntegerVariable + 'static>(
                     &time_table,
                     context.as_readonly(),
                     task,
-                    parameters.capacity,
+                    context.upper_bound(&parameters.capacity),
                 );
                 // Then we provide the propagation handler with the chain of profiles and propagate
                 // all of them
@@ -446,17 +581,22 @@ fn propagate_sequence_of_profiles<'a, Var: IntegerVariable + 'static>(
 
 /// Returns the index of the profile which cannot propagate the lower-bound of the provided task any
 /// further based on the propagation of the upper-bound due to `time_table[profile_index]`.
-fn find_index_last_profile_which_propagates_lower_bound<Var: IntegerVariable + 'static>(
+fn find_index_last_profile_which_propagates_lower_bound<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
     profile_index: usize,
-    time_table: &[&ResourceProfile<Var>],
+    time_table: &[&ResourceProfile<Var, PVar, RVar>],
     context: PropagationContext,
-    task: &Rc<Task<Var>>,
-    capacity: i32,
+    task: &Rc<Task<Var, PVar, RVar>>,
+    capacity: i64,
 ) -> usize {
     let mut last_index = profile_index + 1;
     while last_index < time_table.len() {
         let next_profile = time_table[last_index];
-        if next_profile.start - time_table[last_index - 1].end >= task.processing_time
+        if next_profile.start - time_table[last_index - 1].end
+            >= context.lower_bound(&task.processing_time)
             || !overflows_capacity_and_is_not_part_of_profile(context, task, next_profile, capacity)
         {
             break;
@@ -468,12 +608,16 @@ fn find_index_last_profile_which_propagates_lower_bound<Var: IntegerVariable + '
 
 /// Returns the index of the last profile which could propagate the upper-bound of the task based on
 /// the propagation of the upper-bound due to `time_table[profile_index]`.
-fn find_index_last_profile_which_propagates_upper_bound<Var: IntegerVariable + 'static>(
+fn find_index_last_profile_which_propagates_upper_bound<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
     profile_index: usize,
-    time_table: &[&ResourceProfile<Var>],
+    time_table: &[&ResourceProfile<Var, PVar, RVar>],
     context: PropagationContext,
-    task: &Rc<Task<Var>>,
-    capacity: i32,
+    task: &Rc<Task<Var, PVar, RVar>>,
+    capacity: i64,
 ) -> usize {
     if profile_index == 0 {
         return 0;
@@ -481,7 +625,8 @@ fn find_index_last_profile_which_propagates_upper_bound<Var: IntegerVariable + '
     let mut first_index = profile_index - 1;
     loop {
         let previous_profile = time_table[first_index];
-        if time_table[first_index + 1].start - previous_profile.end >= task.processing_time
+        if time_table[first_index + 1].start - previous_profile.end
+            >= context.lower_bound(&task.processing_time)
             || !overflows_capacity_and_is_not_part_of_profile(
                 context,
                 task,
@@ -510,17 +655,22 @@ fn find_index_last_profile_which_propagates_upper_bound<Var: IntegerVariable + '
 ///
 /// Note: It is assumed that task.resource_usage + height > capacity (i.e. the task has the
 /// potential to overflow the capacity in combination with the profile)
-fn lower_bound_can_be_propagated_by_profile<Var: IntegerVariable + 'static>(
+fn lower_bound_can_be_propagated_by_profile<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
     context: PropagationContext,
-    task: &Rc<Task<Var>>,
-    profile: &ResourceProfile<Var>,
-    capacity: i32,
+    task: &Rc<Task<Var, PVar, RVar>>,
+    profile: &ResourceProfile<Var, PVar, RVar>,
+    capacity: i64,
 ) -> bool {
     pumpkin_assert_moderate!(
-        profile.height + task.resource_usage > capacity
+        profile.height + context.lower_bound(&task.resource_usage) > capacity
             && task_has_overlap_with_interval(context, task, profile.start, profile.end)
     , "It is checked whether a task can be propagated while the invariants do not hold - The task should overflow the capacity with the profile");
-    (context.lower_bound(&task.start_variable) + task.processing_time) > profile.start
+    (context.lower_bound(&task.start_variable) + context.lower_bound(&task.processing_time))
+        > profile.start
         && context.lower_bound(&task.start_variable) <= profile.end
 }
 
@@ -530,16 +680,21 @@ fn lower_bound_can_be_propagated_by_profile<Var: IntegerVariable + 'static>(
 ///       [`ResourceProfile`]
 ///     * ub(s) <= end, i.e. the latest start time is before the end of the [`ResourceProfile`]
 /// Note: It is assumed that the task is known to overflow the [`ResourceProfile`]
-fn upper_bound_can_be_propagated_by_profile<Var: IntegerVariable + 'static>(
+fn upper_bound_can_be_propagated_by_profile<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
     context: PropagationContext,
-    task: &Rc<Task<Var>>,
-    profile: &ResourceProfile<Var>,
-    capacity: i32,
+    task: &Rc<Task<Var, PVar, RVar>>,
+    profile: &ResourceProfile<Var, PVar, RVar>,
+    capacity: i64,
 ) -> bool {
     pumpkin_assert_moderate!(
-        profile.height + task.resource_usage > capacity
+        profile.height + context.lower_bound(&task.resource_usage) > capacity
     , "It is checked whether a task can be propagated while the invariants do not hold - The task should overflow the capacity with the profile");
-    (context.upper_bound(&task.start_variable) + task.processing_time) > profile.start
+    (context.upper_bound(&task.start_variable) + context.lower_bound(&task.processing_time))
+        > profile.start
         && context.upper_bound(&task.start_variable) <= profile.end
 }
 
@@ -550,11 +705,15 @@ fn upper_bound_can_be_propagated_by_profile<Var: IntegerVariable + 'static>(
 ///
 /// If the first condition is true, the second false and the third true then this method returns
 /// true (otherwise it returns false)
-fn can_be_updated_by_profile<Var: IntegerVariable + 'static>(
+fn can_be_updated_by_profile<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
     context: PropagationContext,
-    task: &Rc<Task<Var>>,
-    profile: &ResourceProfile<Var>,
-    capacity: i32,
+    task: &Rc<Task<Var, PVar, RVar>>,
+    profile: &ResourceProfile<Var, PVar, RVar>,
+    capacity: i64,
 ) -> bool {
     overflows_capacity_and_is_not_part_of_profile(context, task, profile, capacity)
         && task_has_overlap_with_interval(context, task, profile.start, profile.end)
@@ -566,13 +725,17 @@ fn can_be_updated_by_profile<Var: IntegerVariable + 'static>(
 ///
 /// If the first condition is true, and the second false then this method returns
 /// true (otherwise it returns false)
-fn overflows_capacity_and_is_not_part_of_profile<Var: IntegerVariable + 'static>(
+fn overflows_capacity_and_is_not_part_of_profile<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
     context: PropagationContext,
-    task: &Rc<Task<Var>>,
-    profile: &ResourceProfile<Var>,
-    capacity: i32,
+    task: &Rc<Task<Var, PVar, RVar>>,
+    profile: &ResourceProfile<Var, PVar, RVar>,
+    capacity: i64,
 ) -> bool {
-    profile.height + task.resource_usage > capacity
+    profile.height + context.lower_bound(&task.resource_usage) > capacity
         && !has_mandatory_part_in_interval(context, task, profile.start, profile.end)
 }
 
@@ -589,13 +752,23 @@ enum CanUpdate {
 ///
 /// Note that this method can only find [`Inconsistency::EmptyDomain`] conflicts which means that we
 /// handle that error in the parent function
-fn find_possible_updates<Var: IntegerVariable + 'static>(
+fn find_possible_updates<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
+>(
     context: &mut PropagationContextMut,
-    task: &Rc<Task<Var>>,
-    profile: &ResourceProfile<Var>,
-    parameters: &CumulativeParameters<Var>,
+    task: &Rc<Task<Var, PVar, RVar>>,
+    profile: &ResourceProfile<Var, PVar, RVar>,
+    parameters: &CumulativeParameters<Var, PVar, RVar, CVar>,
 ) -> Vec<CanUpdate> {
-    if !can_be_updated_by_profile(context.as_readonly(), task, profile, parameters.capacity) {
+    if !can_be_updated_by_profile(
+        context.as_readonly(),
+        task,
+        profile,
+        context.upper_bound(&parameters.capacity),
+    ) {
         // If the task cannot be updated by the profile then we simply return the empty list
         vec![]
     } else {
@@ -606,7 +779,7 @@ fn find_possible_updates<Var: IntegerVariable + 'static>(
             context.as_readonly(),
             task,
             profile,
-            parameters.capacity,
+            context.upper_bound(&parameters.capacity),
         ) {
             // The lower-bound of the task can be updated by the profile
             result.push(CanUpdate::LowerBound)
@@ -615,7 +788,7 @@ fn find_possible_updates<Var: IntegerVariable + 'static>(
             context.as_readonly(),
             task,
             profile,
-            parameters.capacity,
+            context.upper_bound(&parameters.capacity),
         ) {
             // The upper-bound of the task can be updated by the profile
             result.push(CanUpdate::UpperBound)
@@ -628,10 +801,14 @@ fn find_possible_updates<Var: IntegerVariable + 'static>(
     }
 }
 
-pub(crate) fn insert_update<Var: IntegerVariable + 'static>(
-    updated_task: &Rc<Task<Var>>,
-    updatable_structures: &mut UpdatableStructures<Var>,
-    potential_update: Option<UpdatedTaskInfo<Var>>,
+pub(crate) fn insert_update<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
+    updated_task: &Rc<Task<Var, PVar, RVar>>,
+    updatable_structures: &mut UpdatableStructures<Var, PVar, RVar>,
+    potential_update: Option<UpdatedTaskInfo<Var, PVar, RVar>>,
 ) {
     if let Some(update) = potential_update {
         updatable_structures.task_has_been_updated(updated_task);
@@ -639,10 +816,14 @@ pub(crate) fn insert_update<Var: IntegerVariable + 'static>(
     }
 }
 
-pub(crate) fn backtrack_update<Var: IntegerVariable + 'static>(
+pub(crate) fn backtrack_update<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
     context: PropagationContext,
-    updatable_structures: &mut UpdatableStructures<Var>,
-    updated_task: &Rc<Task<Var>>,
+    updatable_structures: &mut UpdatableStructures<Var, PVar, RVar>,
+    updated_task: &Rc<Task<Var, PVar, RVar>>,
 ) {
     // Stores whether the stored lower-bound is equal to the current lower-bound
     let lower_bound_equal_to_stored = updatable_structures.get_stored_lower_bound(updated_task)
@@ -655,7 +836,8 @@ pub(crate) fn backtrack_update<Var: IntegerVariable + 'static>(
     // Stores whether the stored bounds did not include a mandatory part
     let previously_did_not_have_mandatory_part = updatable_structures
         .get_stored_upper_bound(updated_task)
-        >= updatable_structures.get_stored_lower_bound(updated_task) + updated_task.processing_time;
+        >= updatable_structures.get_stored_lower_bound(updated_task)
+            + mandatory_processing_time(context, updated_task);
 
     // If the stored bounds are already the same or the previous stored bounds did not include a
     // mandatory part (which means that this task will also not have mandatory part after
@@ -707,8 +889,8 @@ mod tests {
                 end: 6,
                 profile_tasks: vec![Rc::new(Task {
                     start_variable: y,
-                    processing_time: 2,
-                    resource_usage: 1,
+                    processing_time: assignments.grow(2, 2),
+                    resource_usage: assignments.grow(1, 1),
                     id: LocalId::from(1),
                 })],
                 height: 1,
@@ -718,24 +900,26 @@ mod tests {
                 end: 8,
                 profile_tasks: vec![Rc::new(Task {
                     start_variable: z,
-                    processing_time: 1,
-                    resource_usage: 1,
+                    processing_time: assignments.grow(1, 1),
+                    resource_usage: assignments.grow(1, 1),
                     id: LocalId::from(2),
                 })],
                 height: 1,
             },
         ];
 
+        let propagating_task = Rc::new(Task {
+            start_variable: x,
+            processing_time: assignments.grow(6, 6),
+            resource_usage: assignments.grow(1, 1),
+            id: LocalId::from(0),
+        });
+
         let last_index = find_index_last_profile_which_propagates_lower_bound(
             0,
             &time_table,
             PropagationContext::new(&assignments),
-            &Rc::new(Task {
-                start_variable: x,
-                processing_time: 6,
-                resource_usage: 1,
-                id: LocalId::from(0),
-            }),
+            &propagating_task,
             1,
         );
         assert_eq!(last_index, 2);
@@ -755,8 +939,8 @@ mod tests {
                 end: 6,
                 profile_tasks: vec![Rc::new(Task {
                     start_variable: y,
-                    processing_time: 2,
-                    resource_usage: 1,
+                    processing_time: assignments.grow(2, 2),
+                    resource_usage: assignments.grow(1, 1),
                     id: LocalId::from(1),
                 })],
                 height: 1,
@@ -766,24 +950,26 @@ mod tests {
                 end: 8,
                 profile_tasks: vec![Rc::new(Task {
                     start_variable: z,
-                    processing_time: 1,
-                    resource_usage: 1,
+                    processing_time: assignments.grow(1, 1),
+                    resource_usage: assignments.grow(1, 1),
                     id: LocalId::from(2),
                 })],
                 height: 1,
             },
         ];
 
+        let propagating_task = Rc::new(Task {
+            start_variable: x,
+            processing_time: assignments.grow(6, 6),
+            resource_usage: assignments.grow(1, 1),
+            id: LocalId::from(0),
+        });
+
         let last_index = find_index_last_profile_which_propagates_upper_bound(
             1,
             &time_table,
             PropagationContext::new(&assignments),
-            &Rc::new(Task {
-                start_variable: x,
-                processing_time: 6,
-                resource_usage: 1,
-                id: LocalId::from(0),
-            }),
+            &propagating_task,
             1,
         );
         assert_eq!(last_index, 0);
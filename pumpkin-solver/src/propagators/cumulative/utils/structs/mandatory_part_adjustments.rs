@@ -2,6 +2,9 @@ use std::cmp::Ordering;
 use std::ops::Range;
 
 use super::UpdatedTaskInfo;
+use crate::engine::propagation::PropagationContext;
+use crate::propagators::util::mandatory_processing_time;
+use crate::variables::IntegerVariable;
 
 /// Represents adjustments to a mandatory part due to bound changes.
 ///
@@ -10,13 +13,13 @@ use super::UpdatedTaskInfo;
 /// [`MandatoryPartAdjustments::removed_parts`].
 pub(crate) struct MandatoryPartAdjustments {
     /// The additions to the mandatory part
-    added_parts: Vec<Range<i32>>,
+    added_parts: Vec<Range<i64>>,
     /// The removals from the mandatory part
-    removed_parts: Vec<Range<i32>>,
+    removed_parts: Vec<Range<i64>>,
 }
 
 impl MandatoryPartAdjustments {
-    fn new(added_parts: Vec<Range<i32>>, removed_parts: Vec<Range<i32>>) -> Self {
+    fn new(added_parts: Vec<Range<i64>>, removed_parts: Vec<Range<i64>>) -> Self {
         Self {
             added_parts,
             removed_parts,
@@ -25,7 +28,7 @@ impl MandatoryPartAdjustments {
 
     /// Returns an iterator over the removed ranges of the mandatory part; only returns non-empty
     /// intervals.
-    pub(crate) fn get_removed_parts(&self) -> impl Iterator<Item = Range<i32>> + '_ {
+    pub(crate) fn get_removed_parts(&self) -> impl Iterator<Item = Range<i64>> + '_ {
         self.removed_parts
             .iter()
             .filter(|range| !range.is_empty())
@@ -34,7 +37,7 @@ impl MandatoryPartAdjustments {
 
     /// Returns an iterator over the added ranges of the mandatory part; only returns non-mepty
     /// intervals.
-    pub(crate) fn get_added_parts(&self) -> impl Iterator<Item = Range<i32>> + '_ {
+    pub(crate) fn get_added_parts(&self) -> impl Iterator<Item = Range<i64>> + '_ {
         self.added_parts
             .iter()
             .filter(|range| !range.is_empty())
@@ -51,7 +54,7 @@ impl MandatoryPartAdjustments {
     }
 
     /// Creates a [`MandatoryPartAdjustments`] containing a single added part.
-    fn from_added_part(added_part: Range<i32>) -> Self {
+    fn from_added_part(added_part: Range<i64>) -> Self {
         Self {
             added_parts: vec![added_part],
             removed_parts: vec![],
@@ -59,7 +62,7 @@ impl MandatoryPartAdjustments {
     }
 
     /// Creates a [`MandatoryPartAdjustments`] containing a single removed part.
-    fn from_removed_part(removed_part: Range<i32>) -> Self {
+    fn from_removed_part(removed_part: Range<i64>) -> Self {
         Self {
             added_parts: vec![],
             removed_parts: vec![removed_part],
@@ -68,7 +71,7 @@ impl MandatoryPartAdjustments {
 
     /// Creates a [`MandatoryPartAdjustments`] containing a single added part and a single removed
     /// part.
-    fn from_added_and_removed_part(added_part: Range<i32>, removed_part: Range<i32>) -> Self {
+    fn from_added_and_removed_part(added_part: Range<i64>, removed_part: Range<i64>) -> Self {
         Self {
             added_parts: vec![added_part],
             removed_parts: vec![removed_part],
@@ -76,16 +79,24 @@ impl MandatoryPartAdjustments {
     }
 }
 
-impl<Var> UpdatedTaskInfo<Var> {
+impl<Var, PVar: IntegerVariable + 'static, RVar: IntegerVariable + 'static>
+    UpdatedTaskInfo<Var, PVar, RVar>
+{
     /// Returns the adjustments which need to be made to the time-table in the form of a
     /// [`MandatoryPartAdjustments`].
-    pub(crate) fn get_mandatory_part_adjustments(&self) -> MandatoryPartAdjustments {
+    ///
+    /// Note that it is assumed that the processing time and resource usage of the task have not
+    /// changed since the old mandatory part was added to the time-table.
+    pub(crate) fn get_mandatory_part_adjustments(
+        &self,
+        context: PropagationContext,
+    ) -> MandatoryPartAdjustments {
+        let processing_time = mandatory_processing_time(context, &self.task);
+
         // We get the previous mandatory part
-        let previous_mandatory_part =
-            self.old_upper_bound..self.old_lower_bound + self.task.processing_time;
+        let previous_mandatory_part = self.old_upper_bound..self.old_lower_bound + processing_time;
         // We also get the new mandatory part
-        let new_mandatory_part =
-            self.new_upper_bound..self.new_lower_bound + self.task.processing_time;
+        let new_mandatory_part = self.new_upper_bound..self.new_lower_bound + processing_time;
 
         if previous_mandatory_part.is_empty() && new_mandatory_part.is_empty() {
             // If both are empty then no adjustments should be made
@@ -9,24 +9,31 @@ use crate::variables::IntegerVariable;
 /// - The capacity of the resource
 /// - The options for propagating the cumulative constraint
 #[derive(Debug, Clone)]
-pub(crate) struct CumulativeParameters<Var> {
+pub(crate) struct CumulativeParameters<Var, PVar, RVar, CVar> {
     /// The Set of [`Task`]s; for each [`Task`], the [`Task::id`] is assumed to correspond to its
     /// index in this [`Vec`]; this is stored as a [`Box`] of [`Rc`]'s to accomodate the
     /// sharing of the tasks
-    pub(crate) tasks: Box<[Rc<Task<Var>>]>,
+    pub(crate) tasks: Box<[Rc<Task<Var, PVar, RVar>>]>,
     /// The capacity of the resource (i.e. how much resource consumption can be maximally
-    /// accomodated at each time point)
-    pub(crate) capacity: i32,
+    /// accomodated at each time point); note that only the upper-bound of the capacity is used
+    /// for propagation
+    pub(crate) capacity: CVar,
     /// The [`CumulativeOptions`] which influence the behaviour of the cumulative propagator(s).
     pub(crate) options: CumulativePropagatorOptions,
 }
 
-impl<Var: IntegerVariable + 'static> CumulativeParameters<Var> {
+impl<
+        Var: IntegerVariable + 'static,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
+        CVar: IntegerVariable + 'static,
+    > CumulativeParameters<Var, PVar, RVar, CVar>
+{
     pub(crate) fn new(
-        tasks: Vec<Task<Var>>,
-        capacity: i32,
+        tasks: Vec<Task<Var, PVar, RVar>>,
+        capacity: CVar,
         options: CumulativePropagatorOptions,
-    ) -> CumulativeParameters<Var> {
+    ) -> CumulativeParameters<Var, PVar, RVar, CVar> {
         let tasks = tasks
             .into_iter()
             .map(Rc::new)
@@ -8,19 +8,19 @@ use crate::variables::IntegerVariable;
 /// A [`ResourceProfile`] represents a rectangle where the height is the cumulative mandatory
 /// resource usage of the [`profile tasks`][ResourceProfile::profile_tasks]
 #[derive(Clone)]
-pub(crate) struct ResourceProfile<Var> {
+pub(crate) struct ResourceProfile<Var, PVar, RVar> {
     /// The start time of the [`ResourceProfile`] (inclusive)
-    pub(crate) start: i32,
+    pub(crate) start: i64,
     /// The end time of the [`ResourceProfile`] (inclusive)
-    pub(crate) end: i32,
+    pub(crate) end: i64,
     /// The IDs of the tasks which are part of the profile
-    pub(crate) profile_tasks: Vec<Rc<Task<Var>>>,
+    pub(crate) profile_tasks: Vec<Rc<Task<Var, PVar, RVar>>>,
     /// The amount of cumulative resource usage of all [`profile
     /// tasks`][ResourceProfile::profile_tasks] (i.e. the height of the rectangle)
-    pub(crate) height: i32,
+    pub(crate) height: i64,
 }
 
-impl<Var> Debug for ResourceProfile<Var> {
+impl<Var, PVar, RVar> Debug for ResourceProfile<Var, PVar, RVar> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ResourceProfile")
             .field("start", &self.start)
@@ -30,8 +30,13 @@ impl<Var> Debug for ResourceProfile<Var> {
     }
 }
 
-impl<Var: IntegerVariable + 'static> ResourceProfile<Var> {
-    pub(crate) fn default(time: i32) -> ResourceProfile<Var> {
+impl<
+        Var: IntegerVariable + 'static,
+        PVar: IntegerVariable + 'static,
+        RVar: IntegerVariable + 'static,
+    > ResourceProfile<Var, PVar, RVar>
+{
+    pub(crate) fn default(time: i64) -> ResourceProfile<Var, PVar, RVar> {
         ResourceProfile {
             start: time,
             end: time,
@@ -18,41 +18,48 @@ use crate::propagators::Task;
 /// Based on the [`ArgTask`]s which are passed, it creates and returns [`Task`]s which have been
 /// registered for [`DomainEvents`].
 ///
-/// It sorts [`Task`]s on non-decreasing resource usage and removes [`Task`]s with resource usage 0.
-pub(crate) fn create_tasks<Var: IntegerVariable + 'static>(
-    arg_tasks: &[ArgTask<Var>],
-) -> Vec<Task<Var>> {
-    // We order the tasks by non-decreasing resource usage, this allows certain optimizations
-    let mut ordered_tasks = arg_tasks.to_vec();
-    ordered_tasks.sort_by(|a, b| b.resource_usage.cmp(&a.resource_usage));
-
-    let mut id = 0;
-    ordered_tasks
+/// Note that the processing times and resource usages are not known until the propagator is
+/// initialised; [`Task`]s which (at some point) have a resource usage of 0 are thus not removed
+/// but are simply never considered to have a mandatory part (see [`mandatory_processing_time`]).
+pub(crate) fn create_tasks<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
+    arg_tasks: &[ArgTask<Var, PVar, RVar>],
+) -> Vec<Task<Var, PVar, RVar>> {
+    arg_tasks
         .iter()
-        .filter_map(|x| {
-            // We only add tasks which have a non-zero resource usage
-            if x.resource_usage > 0 {
-                let return_value = Some(Task {
-                    start_variable: x.start_time.clone(),
-                    processing_time: x.processing_time,
-                    resource_usage: x.resource_usage,
-                    id: LocalId::from(id),
-                });
-
-                id += 1;
-                return_value
-            } else {
-                None
-            }
+        .enumerate()
+        .map(|(id, x)| Task {
+            start_variable: x.start_time.clone(),
+            processing_time: x.processing_time.clone(),
+            resource_usage: x.resource_usage.clone(),
+            id: LocalId::from(id as u32),
         })
-        .collect::<Vec<Task<Var>>>()
+        .collect::<Vec<Task<Var, PVar, RVar>>>()
 }
 
-pub(crate) fn register_tasks<Var: IntegerVariable + 'static>(
-    tasks: &[Rc<Task<Var>>],
+/// Registers the variables of the provided [`Task`]s and the `capacity` for [`DomainEvents`].
+///
+/// The start variables are registered with the [`LocalId`] of their [`Task`] (i.e. `0..n`), the
+/// processing times with the [`LocalId`]s `n..2n`, the resource usages with the [`LocalId`]s
+/// `2n..3n` and the capacity with the [`LocalId`] `3n`. Note that only the changes to the
+/// processing times and resource usages which can lead to propagations (i.e. increases of the
+/// lower-bounds) are registered and similarly for the capacity (i.e. decreases of the
+/// upper-bound).
+pub(crate) fn register_tasks<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    CVar: IntegerVariable + 'static,
+>(
+    tasks: &[Rc<Task<Var, PVar, RVar>>],
+    capacity: &CVar,
     context: &mut PropagatorInitialisationContext<'_>,
     register_backtrack: bool,
 ) {
+    let num_tasks = tasks.len() as u32;
     tasks.iter().for_each(|task| {
         let _ = context.register(
             task.start_variable.clone(),
@@ -61,6 +68,16 @@ pub(crate) fn register_tasks<Var: IntegerVariable + 'static>(
             )),
             task.id,
         );
+        let _ = context.register(
+            task.processing_time.clone(),
+            DomainEvents::LOWER_BOUND,
+            LocalId::from(num_tasks + task.id.unpack()),
+        );
+        let _ = context.register(
+            task.resource_usage.clone(),
+            DomainEvents::LOWER_BOUND,
+            LocalId::from(2 * num_tasks + task.id.unpack()),
+        );
         if register_backtrack {
             let _ = context.register_for_backtrack_events(
                 task.start_variable.clone(),
@@ -71,16 +88,65 @@ pub(crate) fn register_tasks<Var: IntegerVariable + 'static>(
                 )),
                 task.id,
             );
+            let _ = context.register_for_backtrack_events(
+                task.processing_time.clone(),
+                DomainEvents::LOWER_BOUND,
+                LocalId::from(num_tasks + task.id.unpack()),
+            );
+            let _ = context.register_for_backtrack_events(
+                task.resource_usage.clone(),
+                DomainEvents::LOWER_BOUND,
+                LocalId::from(2 * num_tasks + task.id.unpack()),
+            );
         }
     });
+
+    let _ = context.register(
+        capacity.clone(),
+        DomainEvents::UPPER_BOUND,
+        LocalId::from(3 * num_tasks),
+    );
+    if register_backtrack {
+        let _ = context.register_for_backtrack_events(
+            capacity.clone(),
+            DomainEvents::UPPER_BOUND,
+            LocalId::from(3 * num_tasks),
+        );
+    }
+}
+
+/// Returns the processing time of the provided [`Task`] which is used for determining its mandatory
+/// part (i.e. the mandatory part is `[ub(s), lb(s) + mandatory_processing_time)`).
+///
+/// This is the lower-bound of the processing time of the [`Task`]; however, if the resource usage
+/// of the [`Task`] can be 0 then it does not contribute to any profile and 0 is returned (which
+/// means that the [`Task`] has no mandatory part).
+pub(crate) fn mandatory_processing_time<
+    Var,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+    Context: ReadDomains,
+>(
+    context: Context,
+    task: &Task<Var, PVar, RVar>,
+) -> i64 {
+    if context.lower_bound(&task.resource_usage) > 0 {
+        context.lower_bound(&task.processing_time)
+    } else {
+        0
+    }
 }
 
 /// Updates the bounds of the provided [`Task`] to those stored in
 /// `context`.
-pub(crate) fn update_bounds_task<Var: IntegerVariable + 'static>(
+pub(crate) fn update_bounds_task<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
     context: PropagationContext,
-    bounds: &mut [(i32, i32)],
-    task: &Rc<Task<Var>>,
+    bounds: &mut [(i64, i64)],
+    task: &Rc<Task<Var, PVar, RVar>>,
 ) {
     bounds[task.id.unpack() as usize] = (
         context.lower_bound(&task.start_variable),
@@ -89,10 +155,14 @@ pub(crate) fn update_bounds_task<Var: IntegerVariable + 'static>(
 }
 
 /// Determines whether the stored bounds are equal when propagation occurs
-pub(crate) fn check_bounds_equal_at_propagation<Var: IntegerVariable + 'static>(
+pub(crate) fn check_bounds_equal_at_propagation<
+    Var: IntegerVariable + 'static,
+    PVar: IntegerVariable + 'static,
+    RVar: IntegerVariable + 'static,
+>(
     context: PropagationContext,
-    tasks: &[Rc<Task<Var>>],
-    bounds: &[(i32, i32)],
+    tasks: &[Rc<Task<Var, PVar, RVar>>],
+    bounds: &[(i64, i64)],
 ) -> bool {
     tasks.iter().all(|current| {
         bounds[current.id.unpack() as usize]
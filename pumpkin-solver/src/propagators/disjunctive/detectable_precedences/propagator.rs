@@ -1,147 +1,165 @@
-use std::collections::HashMap;
-use std::rc::Rc;
-
-use enumset::enum_set;
-
 use crate::basic_types::Inconsistency;
 use crate::basic_types::PropagationStatusCP;
-use crate::engine::opaque_domain_event::OpaqueDomainEvent;
-use crate::engine::propagation::contexts::PropagationContextWithTrailedValues;
-use crate::engine::propagation::EnqueueDecision;
-use crate::engine::propagation::LocalId;
-use crate::engine::propagation::PropagationContext;
+use crate::basic_types::PropositionalConjunction;
 use crate::engine::propagation::PropagationContextMut;
 use crate::engine::propagation::Propagator;
 use crate::engine::propagation::PropagatorInitialisationContext;
 use crate::engine::DomainEvents;
-use crate::engine::IntDomainEvent;
-use crate::predicate;
-use crate::predicates::PropositionalConjunction;
+use crate::predicates::Predicate;
+use crate::propagators::disjunctive::create_overlap_conflict_explanation;
+use crate::propagators::disjunctive::create_precedence_explanation;
 use crate::propagators::disjunctive::ArgTaskDisj;
-use crate::propagators::disjunctive::RevTimeline;
+use crate::propagators::disjunctive::Direction;
+use crate::propagators::disjunctive::DisjunctivePropagatorOptions;
+use crate::propagators::disjunctive::TaskBounds;
 use crate::propagators::disjunctive::TaskDisj;
 use crate::propagators::disjunctive::Timeline;
 use crate::variables::IntegerVariable;
 
+/// Propagator for the disjunctive constraint using the detectable precedences rule; it is based
+/// on the timeline-based algorithm described in \[1\].
+///
+/// A task `j` is detected to precede task `i` if `lst_j < ect_i`; in that case `i` cannot start
+/// before `j`. For every task `i`, the propagator determines the set of tasks Ω which are detected
+/// to precede it and then updates the earliest starting time of `i` to the earliest completion
+/// time of Ω. The latest starting times are updated symmetrically.
+///
+/// # Bibliography
+/// \[1\] H. Fahimi and C.-G. Quimper, ‘Linear-time filtering algorithms for the disjunctive
+/// constraint’, in Proceedings of the AAAI Conference on Artificial Intelligence, 2014.
 #[derive(Clone, Debug)]
 pub(crate) struct DetectablePrecedencesPropagator<Var> {
-    tasks: Rc<[TaskDisj<Var>]>,
+    tasks: Box<[TaskDisj<Var>]>,
+    options: DisjunctivePropagatorOptions,
 }
 
 impl<Var> DetectablePrecedencesPropagator<Var>
 where
     Var: IntegerVariable + 'static,
 {
-    pub(crate) fn new(tasks: Rc<[ArgTaskDisj<Var>]>) -> Self {
+    pub(crate) fn new(tasks: &[ArgTaskDisj<Var>], options: DisjunctivePropagatorOptions) -> Self {
         DetectablePrecedencesPropagator {
-            tasks: tasks
-                .iter()
-                .enumerate()
-                .map(|(i, task)| TaskDisj {
-                    starting_time: task.starting_time.clone(),
-                    duration: task.duration,
-                    deadline: task.deadline,
-                    local_id: LocalId::from(i as u32),
-                })
-                .collect(),
+            tasks: TaskDisj::from_args(tasks),
+            options,
         }
     }
 
+    /// Applies the detectable precedences rule in the provided [`Direction`].
+    fn propagate_detectable_precedences(
+        &self,
+        context: &mut PropagationContextMut,
+        direction: Direction,
+    ) -> PropagationStatusCP {
+        let bounds = direction.task_bounds(&self.tasks, context);
+
+        let mut tasks_by_ect = (0..bounds.len()).collect::<Vec<_>>();
+        tasks_by_ect.sort_by_key(|&task| bounds[task].ect());
+        let mut tasks_by_lst = (0..bounds.len()).collect::<Vec<_>>();
+        tasks_by_lst.sort_by_key(|&task| bounds[task].lst);
+
+        let mut timeline = Timeline::new(&bounds);
+        let mut scheduled_tasks = vec![];
+        // A task which has a mandatory part and which has been detected to precede a task; it is
+        // only scheduled once its own bound has been computed.
+        let mut blocking_task: Option<usize> = None;
+        let mut postponed_tasks = vec![];
+        let mut updates = vec![];
 
-    fn propagate_upper_bound(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP{
-        let assignments = context.assignments.clone();
-        let reason = self
-            .tasks
-            .iter()
-            .flat_map(|task| {
-                vec![
-                    predicate![task.starting_time >= TaskDisj::get_est(task, &assignments)],
-                    predicate![task.starting_time <= TaskDisj::get_lst(task, &assignments)],
-                ]
-            })
-            .collect::<PropositionalConjunction>();
-        let mut timeline = RevTimeline::new(self.tasks.clone(), &assignments);
-        let mut i_lst = self.tasks.iter().cloned().collect::<Vec<TaskDisj<Var>>>();
-        i_lst.sort_by(|a, b| {
-            let a_lst = TaskDisj::get_lst(a, &assignments);
-            let b_lst = TaskDisj::get_lst(b, &assignments);
-            b_lst.cmp(&a_lst)
-        });
-        let mut i_ect = self.tasks.iter().cloned().collect::<Vec<TaskDisj<Var>>>();
-        i_ect.sort_by(|a, b| {
-            let a_ect = TaskDisj::get_ect(a, &assignments);
-            let b_ect = TaskDisj::get_ect(b, &assignments);
-            b_ect.cmp(&a_ect)
-        });
         let mut j = 0;
-        let mut k = i_ect[0].clone();
-        let mut ect_k = TaskDisj::get_ect(&k, &assignments);
-        let mut lst_k = TaskDisj::get_lst(&k, &assignments);
-        let mut blocking_task: Option<TaskDisj<Var>> = None;
-        let mut postponed_tasks: Vec<TaskDisj<Var>> = vec![];
-        let mut propagations: HashMap<LocalId, (i32, PropositionalConjunction)> = HashMap::new();
-        for i in i_lst.iter() {
-            let lst_i = TaskDisj::get_lst(i, &assignments);
-            while j < i_lst.len() && ect_k > lst_i {
-                if lst_k >= ect_k {
-                    timeline.schedule_task(&Rc::new(k.clone()));
+        for &i in tasks_by_ect.iter() {
+            while j < tasks_by_lst.len() && bounds[tasks_by_lst[j]].lst < bounds[i].ect() {
+                let k = tasks_by_lst[j];
+                if bounds[k].lst >= bounds[k].ect() {
+                    timeline.schedule_task(k, bounds[k].duration);
+                    scheduled_tasks.push(k);
+                } else if let Some(blocking_task) = blocking_task {
+                    // Both tasks have a mandatory part and these mandatory parts overlap
+                    return Err(Inconsistency::Conflict(
+                        create_overlap_conflict_explanation(
+                            &self.tasks,
+                            &bounds,
+                            direction,
+                            blocking_task,
+                            k,
+                            self.options.explanation_type,
+                        ),
+                    ));
                 } else {
-                    if matches!(blocking_task, Some(_)) {
-                        return Err(Inconsistency::Conflict(reason));
-                    }
-                    blocking_task = Some(k.clone());
+                    blocking_task = Some(k);
                 }
                 j += 1;
-                k = i_ect[j].clone();
-                ect_k = TaskDisj::get_ect(&k, &assignments);
-                lst_k = TaskDisj::get_lst(&k, &assignments);
             }
-            if matches!(blocking_task, None) {
-                let lst_timeline = timeline.latest_starting_time();
-                if !propagations.contains_key(&i.local_id)
-                    || lst_timeline - i.duration < propagations.get(&i.local_id).unwrap().0
-                {
-                    let _ = propagations.insert(i.local_id, (lst_timeline - i.duration, reason.clone()));
-                }
-            } else {
-                let Some(ref x) = blocking_task else {
-                    panic!("This should not happen");
-                };
-                if i.local_id == x.local_id {
-                    let mut lst_timeline = timeline.latest_starting_time();
-                    if !propagations.contains_key(&i.local_id)
-                        || lst_timeline - i.duration < propagations.get(&i.local_id).unwrap().0
-                    {
-                        let _ = propagations.insert(i.local_id, (lst_timeline - i.duration, reason.clone()));
-                    }
-                    timeline.schedule_task(&Rc::new(i.clone()));
+
+            match blocking_task {
+                None => updates.extend(self.create_update(
+                    &bounds,
+                    direction,
+                    &timeline,
+                    &scheduled_tasks,
+                    i,
+                )),
+                Some(blocking) if blocking == i => {
+                    updates.extend(self.create_update(
+                        &bounds,
+                        direction,
+                        &timeline,
+                        &scheduled_tasks,
+                        i,
+                    ));
+
+                    timeline.schedule_task(i, bounds[i].duration);
+                    scheduled_tasks.push(i);
                     blocking_task = None;
-                    lst_timeline = timeline.latest_starting_time();
-                    for z in postponed_tasks.iter() {
-                        if !propagations.contains_key(&z.local_id)
-                            || lst_timeline - z.duration < propagations.get(&z.local_id).unwrap().0
-                        {
-                            let _ = propagations.insert(z.local_id, (lst_timeline - z.duration, reason.clone()));
-                        }
+
+                    for postponed in postponed_tasks.drain(..) {
+                        updates.extend(self.create_update(
+                            &bounds,
+                            direction,
+                            &timeline,
+                            &scheduled_tasks,
+                            postponed,
+                        ));
                     }
-                    postponed_tasks.clear();
-                } else {
-                    postponed_tasks.push(i.clone());
                 }
+                Some(_) => postponed_tasks.push(i),
             }
         }
-        for (local_id, (lst, reason)) in propagations.iter() {
-            if *lst >= TaskDisj::get_lst(&self.tasks[local_id.unpack() as usize], &assignments) {
-                continue;
-            }
-            let _ = context.set_upper_bound(
-                &self.tasks[local_id.unpack() as usize].starting_time.clone(),
-                *lst,
-                reason.clone(),
-            );
+
+        for (predicate, reason) in updates {
+            context.post_predicate(predicate, reason)?;
         }
+
         Ok(())
     }
+
+    /// Creates the update of the (possibly mirrored) earliest starting time of `task` to the
+    /// earliest completion time of the scheduled tasks, if this improves its bound.
+    fn create_update(
+        &self,
+        bounds: &[TaskBounds],
+        direction: Direction,
+        timeline: &Timeline,
+        scheduled_tasks: &[usize],
+        task: usize,
+    ) -> Option<(Predicate, PropositionalConjunction)> {
+        let earliest_completion_time = timeline.earliest_completion_time()?;
+        if earliest_completion_time <= bounds[task].est {
+            return None;
+        }
+
+        let reason = create_precedence_explanation(
+            &self.tasks,
+            bounds,
+            direction,
+            task,
+            scheduled_tasks,
+            self.options.explanation_type,
+        );
+        Some((
+            direction.lower_bound_predicate(&self.tasks[task], earliest_completion_time),
+            reason,
+        ))
+    }
 }
 
 impl<Var> Propagator for DetectablePrecedencesPropagator<Var>
@@ -151,25 +169,13 @@ where
     fn priority(&self) -> u32 {
         3
     }
-    fn name(&self) -> &str {
-        "DisDetectablePrecedences"
-    }
 
-    fn notify(
-        &mut self,
-        context: PropagationContextWithTrailedValues,
-        local_id: LocalId,
-        _event: OpaqueDomainEvent,
-    ) -> EnqueueDecision {
-        EnqueueDecision::Enqueue
+    fn name(&self) -> &str {
+        "DisjunctiveDetectablePrecedences"
     }
 
-    fn notify_backtrack(
-        &mut self,
-        _context: PropagationContext,
-        local_id: LocalId,
-        event: OpaqueDomainEvent,
-    ) {
+    fn inference_label(&self) -> &str {
+        "detectable_precedences"
     }
 
     fn initialise_at_root(
@@ -179,175 +185,70 @@ where
         self.tasks.iter().for_each(|task| {
             let _ = context.register(
                 task.starting_time.clone(),
-                DomainEvents::ASSIGN,
-                task.local_id,
-            );
-            let _ = context.register_for_backtrack_events(
-                task.starting_time.clone(),
-                DomainEvents::create_with_int_events(enum_set!(
-                    IntDomainEvent::Assign | IntDomainEvent::Removal
-                )),
+                DomainEvents::BOUNDS,
                 task.local_id,
             );
         });
         Ok(())
     }
 
-    fn debug_propagate_from_scratch(&self, context: PropagationContextMut) -> PropagationStatusCP {
-        let assignments = context.assignments;
-        for task in self.tasks.iter() {
-            let ect = TaskDisj::get_ect(task, &assignments);
-            let lst = TaskDisj::get_lst(task, &assignments);
-            if ect > task.deadline {
-                let reason: PropositionalConjunction =
-                    predicate![task.starting_time >= lst - 1].into();
-                return Err(Inconsistency::Conflict(reason));
-            }
-        }
-
-        Ok(())
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        self.propagate_detectable_precedences(&mut context, Direction::Forward)?;
+        self.propagate_detectable_precedences(&mut context, Direction::Backward)
     }
-
-    fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
-        // self.debug_propagate_from_scratch(context)
-        let assignments = context.assignments.clone();
-        let reason = self
-            .tasks
-            .iter()
-            .flat_map(|task| {
-                vec![
-                    predicate![task.starting_time >= TaskDisj::get_est(task, &assignments)],
-                    predicate![task.starting_time <= TaskDisj::get_lst(task, &assignments)],
-                ]
-            })
-            .collect::<PropositionalConjunction>();
-        let mut timeline = Timeline::new(self.tasks.clone(), &assignments);
-        let mut i_lst = self.tasks.iter().cloned().collect::<Vec<TaskDisj<Var>>>();
-        i_lst.sort_by(|a, b| {
-            let a_lst = TaskDisj::get_lst(a, &assignments);
-            let b_lst = TaskDisj::get_lst(b, &assignments);
-            a_lst.cmp(&b_lst)
-        });
-        let mut i_ect = self.tasks.iter().cloned().collect::<Vec<TaskDisj<Var>>>();
-        i_ect.sort_by(|a, b| {
-            let a_ect = TaskDisj::get_ect(a, &assignments);
-            let b_ect = TaskDisj::get_ect(b, &assignments);
-            a_ect.cmp(&b_ect)
-        });
-        let mut j = 0;
-        let mut k = i_lst[0].clone();
-        let mut ect_k = TaskDisj::get_ect(&k, &assignments);
-        let mut lst_k = TaskDisj::get_lst(&k, &assignments);
-        let mut blocking_task: Option<TaskDisj<Var>> = None;
-        let mut postponed_tasks: Vec<TaskDisj<Var>> = vec![];
-        let mut propagations: HashMap<LocalId, (i32, PropositionalConjunction)> = HashMap::new();
-        for i in i_ect.iter() {
-            let ect_i = TaskDisj::get_ect(i, &assignments);
-            while j < i_lst.len() && lst_k < ect_i {
-                if lst_k >= ect_k {
-                    timeline.schedule_task(&Rc::new(k.clone()));
-                } else {
-                    if matches!(blocking_task, Some(_)) {
-                        return Err(Inconsistency::Conflict(reason));
-                    }
-                    blocking_task = Some(k.clone());
-                }
-                j += 1;
-                k = i_lst[j].clone();
-                ect_k = TaskDisj::get_ect(&k, &assignments);
-                lst_k = TaskDisj::get_lst(&k, &assignments);
-            }
-            if matches!(blocking_task, None) {
-                let ect_timeline = timeline.earliest_completion_time();
-                if !propagations.contains_key(&i.local_id)
-                    || ect_timeline > propagations.get(&i.local_id).unwrap().0
-                {
-                    let _ = propagations.insert(i.local_id, (ect_timeline, reason.clone()));
-                }
-            } else {
-                let Some(ref x) = blocking_task else {
-                    panic!("This should not happen");
-                };
-                if i.local_id == x.local_id {
-                    let mut ect_timeline = timeline.earliest_completion_time();
-                    if !propagations.contains_key(&i.local_id)
-                        || ect_timeline > propagations.get(&i.local_id).unwrap().0
-                    {
-                        let _ = propagations.insert(i.local_id, (ect_timeline, reason.clone()));
-                    }
-                    timeline.schedule_task(&Rc::new(i.clone()));
-                    blocking_task = None;
-                    ect_timeline = timeline.earliest_completion_time();
-                    for z in postponed_tasks.iter() {
-                        if !propagations.contains_key(&z.local_id)
-                            || ect_timeline > propagations.get(&z.local_id).unwrap().0
-                        {
-                            let _ = propagations.insert(z.local_id, (ect_timeline, reason.clone()));
-                        }
-                    }
-                    postponed_tasks.clear();
-                } else {
-                    postponed_tasks.push(i.clone());
-                }
-            }
-        }
-        for (local_id, (ect, reason)) in propagations.iter() {
-            let _ = context.set_lower_bound(
-                &self.tasks[local_id.unpack() as usize].starting_time.clone(),
-                *ect,
-                reason.clone(),
-            );
-        }
-       self.propagate_upper_bound(context)
-       
-    }
-
-
-    
 }
 
 #[cfg(test)]
 mod tests {
-    use std::rc::Rc;
-
     use crate::basic_types::Inconsistency;
-    use crate::engine::propagation::PropagationContextMut;
-    use crate::engine::propagation::Propagator;
+    use crate::conjunction;
     use crate::engine::test_solver::TestSolver;
+    use crate::options::DisjunctiveExplanationType;
+    use crate::predicate;
     use crate::propagators::disjunctive::ArgTaskDisj;
     use crate::propagators::disjunctive::DetectablePrecedencesPropagator;
+    use crate::propagators::disjunctive::DisjunctivePropagatorOptions;
 
     #[test]
     fn test_debug_propagate() {
         let mut solver = TestSolver::default();
         let x = solver.new_variable(5, 8);
 
-        let propagator = solver
-            .new_propagator(DetectablePrecedencesPropagator::new(Rc::new([ArgTaskDisj {
-                starting_time: x.clone(),
-                duration: 2,
-                deadline: 10,
-            }])))
-            .expect("fail");
-        let result = solver.propagate(propagator);
-        assert!(result.is_ok());
+        let _ = solver
+            .new_propagator(DetectablePrecedencesPropagator::new(
+                &[ArgTaskDisj {
+                    starting_time: x,
+                    duration: 2,
+                }],
+                DisjunctivePropagatorOptions::default(),
+            ))
+            .expect("No conflict");
+        solver.assert_bounds(x, 5, 8);
     }
+
     #[test]
     fn test_debug_propagate_fail() {
         let mut solver = TestSolver::default();
-        let x = solver.new_variable(3, 8);
+        let x = solver.new_variable(3, 4);
+        let y = solver.new_variable(4, 5);
 
-        let propagator = solver
-            .new_propagator(DetectablePrecedencesPropagator::new(Rc::new([ArgTaskDisj {
-                starting_time: x.clone(),
-                duration: 2,
-                deadline: 6,
-            }])))
-            .expect("fail");
-        let _ = solver.remove(x, 3);
-        let _ = solver.remove(x, 4);
-        let result = solver.propagate(propagator);
-        // assert!(matches!(result, Err(_)));
+        let result = solver.new_propagator(DetectablePrecedencesPropagator::new(
+            &[
+                ArgTaskDisj {
+                    starting_time: x,
+                    duration: 3,
+                },
+                ArgTaskDisj {
+                    starting_time: y,
+                    duration: 3,
+                },
+            ],
+            DisjunctivePropagatorOptions::default(),
+        ));
+        assert!(matches!(result, Err(Inconsistency::Conflict(_))));
     }
 
     #[test]
@@ -361,29 +262,28 @@ mod tests {
             ArgTaskDisj {
                 starting_time: w,
                 duration: 4,
-                deadline: 19,
             },
             ArgTaskDisj {
                 starting_time: x,
                 duration: 9,
-                deadline: 22,
             },
             ArgTaskDisj {
                 starting_time: y,
                 duration: 7,
-                deadline: 30,
             },
             ArgTaskDisj {
                 starting_time: z,
                 duration: 6,
-                deadline: 20,
             },
         ];
         assert!(solver.lower_bound(y) == 9);
         assert!(solver.lower_bound(z) == 12);
-        let propagator = solver
-            .new_propagator(DetectablePrecedencesPropagator::new(Rc::new(tasks)))
-            .expect("fail");
+        let _ = solver
+            .new_propagator(DetectablePrecedencesPropagator::new(
+                &tasks,
+                DisjunctivePropagatorOptions::default(),
+            ))
+            .expect("No conflict");
         assert!(solver.lower_bound(x) == 2);
         assert!(solver.lower_bound(y) == 19);
         assert!(solver.lower_bound(z) == 13);
@@ -392,41 +292,139 @@ mod tests {
     }
 
     #[test]
-    fn test_prop_example(){
+    fn test_prop_example() {
         let mut solver = TestSolver::default();
         let w = solver.new_variable(0, 4);
-        let x = solver.new_variable(3,5);
+        let x = solver.new_variable(3, 5);
         let y = solver.new_variable(7, 10);
         let z = solver.new_variable(4, 18);
         let tasks = [
             ArgTaskDisj {
                 starting_time: w,
                 duration: 2,
-                deadline: 6,
             },
             ArgTaskDisj {
                 starting_time: x,
                 duration: 5,
-                deadline: 10,
             },
             ArgTaskDisj {
                 starting_time: y,
                 duration: 5,
-                deadline: 15,
             },
             ArgTaskDisj {
                 starting_time: z,
                 duration: 2,
-                deadline: 20,
             },
         ];
-        let propagator = solver
-            .new_propagator(DetectablePrecedencesPropagator::new(Rc::new(tasks)))
-            .expect("fail");
+        let _ = solver
+            .new_propagator(DetectablePrecedencesPropagator::new(
+                &tasks,
+                DisjunctivePropagatorOptions::default(),
+            ))
+            .expect("No conflict");
         assert!(solver.lower_bound(w) == 0);
         assert!(solver.lower_bound(x) == 3);
         assert!(solver.lower_bound(y) == 8);
         assert!(solver.lower_bound(z) == 8);
+    }
+
+    #[test]
+    fn test_no_propagation_without_precedences() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 10);
+        let y = solver.new_variable(0, 10);
+
+        let _ = solver
+            .new_propagator(DetectablePrecedencesPropagator::new(
+                &[
+                    ArgTaskDisj {
+                        starting_time: x,
+                        duration: 2,
+                    },
+                    ArgTaskDisj {
+                        starting_time: y,
+                        duration: 3,
+                    },
+                ],
+                DisjunctivePropagatorOptions::default(),
+            ))
+            .expect("No conflict");
+        solver.assert_bounds(x, 0, 10);
+        solver.assert_bounds(y, 0, 10);
+    }
+
+    #[test]
+    fn test_explanation_naive() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 1);
+        let y = solver.new_variable(0, 10);
+        let z = solver.new_variable(0, 20);
+
+        let _ = solver
+            .new_propagator(DetectablePrecedencesPropagator::new(
+                &[
+                    ArgTaskDisj {
+                        starting_time: x,
+                        duration: 1,
+                    },
+                    ArgTaskDisj {
+                        starting_time: y,
+                        duration: 3,
+                    },
+                    ArgTaskDisj {
+                        starting_time: z,
+                        duration: 2,
+                    },
+                ],
+                DisjunctivePropagatorOptions {
+                    explanation_type: DisjunctiveExplanationType::Naive,
+                    ..Default::default()
+                },
+            ))
+            .expect("No conflict");
+        solver.assert_bounds(y, 1, 10);
+
+        let reason = solver.get_reason_int(predicate![y >= 1]);
+        assert_eq!(
+            reason,
+            conjunction!([x >= 0] & [x <= 1] & [y >= 0] & [y <= 10] & [z >= 0] & [z <= 20])
+        );
+    }
+
+    #[test]
+    fn test_explanation_last_cluster() {
+        let mut solver = TestSolver::default();
+        let w = solver.new_variable(0, 1);
+        let x = solver.new_variable(6, 7);
+        let y = solver.new_variable(4, 20);
+
+        let _ = solver
+            .new_propagator(DetectablePrecedencesPropagator::new(
+                &[
+                    ArgTaskDisj {
+                        starting_time: w,
+                        duration: 2,
+                    },
+                    ArgTaskDisj {
+                        starting_time: x,
+                        duration: 3,
+                    },
+                    ArgTaskDisj {
+                        starting_time: y,
+                        duration: 5,
+                    },
+                ],
+                DisjunctivePropagatorOptions {
+                    explanation_type: DisjunctiveExplanationType::LastCluster,
+                    ..Default::default()
+                },
+            ))
+            .expect("No conflict");
+        solver.assert_bounds(y, 9, 20);
 
+        // Only task `x` is required to explain the new bound of `y`; the upper-bound of `x` is
+        // lifted to the latest starting time for which `x` is still detected to precede `y`
+        let reason = solver.get_reason_int(predicate![y >= 9]);
+        assert_eq!(reason, conjunction!([x >= 6] & [x <= 8] & [y >= 4]));
     }
 }
@@ -0,0 +1,2 @@
+mod propagator;
+pub(crate) use propagator::*;
@@ -0,0 +1,245 @@
+use crate::basic_types::Inconsistency;
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::DomainEvents;
+use crate::propagators::disjunctive::create_edge_finding_explanation;
+use crate::propagators::disjunctive::create_overload_explanation;
+use crate::propagators::disjunctive::ArgTaskDisj;
+use crate::propagators::disjunctive::Direction;
+use crate::propagators::disjunctive::DisjunctivePropagatorOptions;
+use crate::propagators::disjunctive::TaskDisj;
+use crate::propagators::disjunctive::ThetaLambdaTree;
+use crate::variables::IntegerVariable;
+
+/// Propagator for the disjunctive constraint using the edge-finding rule; it is based on the
+/// algorithm using Θ-Λ-trees described in \[1\].
+///
+/// For a set of tasks Θ and a task `i ∉ Θ`, if `ect(Θ ∪ {i}) > lct(Θ)` then `i` has to be
+/// scheduled after all tasks in Θ; the earliest starting time of `i` is then updated to the
+/// earliest completion time of Θ. The latest starting times are updated symmetrically.
+///
+/// # Bibliography
+/// \[1\] P. Vilím, ‘Global constraints in scheduling’, PhD thesis, Charles University in Prague,
+/// 2007.
+#[derive(Clone, Debug)]
+pub(crate) struct EdgeFindingPropagator<Var> {
+    tasks: Box<[TaskDisj<Var>]>,
+    options: DisjunctivePropagatorOptions,
+}
+
+impl<Var> EdgeFindingPropagator<Var>
+where
+    Var: IntegerVariable + 'static,
+{
+    pub(crate) fn new(tasks: &[ArgTaskDisj<Var>], options: DisjunctivePropagatorOptions) -> Self {
+        EdgeFindingPropagator {
+            tasks: TaskDisj::from_args(tasks),
+            options,
+        }
+    }
+
+    /// Applies the edge-finding rule in the provided [`Direction`].
+    fn propagate_edge_finding(
+        &self,
+        context: &mut PropagationContextMut,
+        direction: Direction,
+    ) -> PropagationStatusCP {
+        let bounds = direction.task_bounds(&self.tasks, context);
+
+        let mut tasks_by_lct = (0..bounds.len()).collect::<Vec<_>>();
+        tasks_by_lct.sort_by_key(|&task| bounds[task].lct());
+
+        let mut tree = ThetaLambdaTree::new(&bounds);
+        tasks_by_lct
+            .iter()
+            .for_each(|&task| tree.add_to_theta(task));
+
+        let mut updates = vec![];
+        // Θ always consists of the first `theta_size` tasks sorted by latest completion time
+        for theta_size in (2..=tasks_by_lct.len()).rev() {
+            let theta = &tasks_by_lct[..theta_size];
+            let latest_completion_time = bounds[theta[theta_size - 1]].lct();
+
+            if tree
+                .earliest_completion_time()
+                .is_some_and(|earliest_completion_time| {
+                    earliest_completion_time > latest_completion_time
+                })
+            {
+                return Err(Inconsistency::Conflict(create_overload_explanation(
+                    &self.tasks,
+                    &bounds,
+                    direction,
+                    theta,
+                    self.options.explanation_type,
+                )));
+            }
+
+            // Move the task with the largest latest completion time from Θ to Λ
+            tree.add_to_lambda(theta[theta_size - 1]);
+            let theta = &tasks_by_lct[..theta_size - 1];
+            let latest_completion_time = bounds[theta[theta_size - 2]].lct();
+
+            while let Some((_, Some(propagated))) =
+                tree.earliest_completion_time_gray()
+                    .filter(|&(earliest_completion_time, _)| {
+                        earliest_completion_time > latest_completion_time
+                    })
+            {
+                let earliest_completion_time = tree
+                    .earliest_completion_time()
+                    .expect("Expected Θ to be non-empty");
+
+                if earliest_completion_time > bounds[propagated].est {
+                    let reason = create_edge_finding_explanation(
+                        &self.tasks,
+                        &bounds,
+                        direction,
+                        propagated,
+                        theta,
+                        self.options.explanation_type,
+                    );
+                    updates.push((
+                        direction.lower_bound_predicate(
+                            &self.tasks[propagated],
+                            earliest_completion_time,
+                        ),
+                        reason,
+                    ));
+                }
+
+                tree.remove(propagated);
+            }
+        }
+
+        for (predicate, reason) in updates {
+            context.post_predicate(predicate, reason)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<Var> Propagator for EdgeFindingPropagator<Var>
+where
+    Var: IntegerVariable + 'static,
+{
+    fn priority(&self) -> u32 {
+        3
+    }
+
+    fn name(&self) -> &str {
+        "DisjunctiveEdgeFinding"
+    }
+
+    fn inference_label(&self) -> &str {
+        "edge_finding"
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.tasks.iter().for_each(|task| {
+            let _ = context.register(
+                task.starting_time.clone(),
+                DomainEvents::BOUNDS,
+                task.local_id,
+            );
+        });
+        Ok(())
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        self.propagate_edge_finding(&mut context, Direction::Forward)?;
+        self.propagate_edge_finding(&mut context, Direction::Backward)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::conjunction;
+    use crate::engine::test_solver::TestSolver;
+    use crate::options::DisjunctiveExplanationType;
+    use crate::predicate;
+    use crate::propagators::disjunctive::ArgTaskDisj;
+    use crate::propagators::disjunctive::DisjunctivePropagatorOptions;
+    use crate::propagators::disjunctive::EdgeFindingPropagator;
+
+    #[test]
+    fn task_is_scheduled_after_set() {
+        // Task z cannot be scheduled before (or in between) x and y since then the three tasks
+        // would have to be executed in [0, 11); hence z has to be scheduled after both of them
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 6);
+        let y = solver.new_variable(1, 5);
+        let z = solver.new_variable(2, 15);
+
+        let _ = solver
+            .new_propagator(EdgeFindingPropagator::new(
+                &[
+                    ArgTaskDisj {
+                        starting_time: x,
+                        duration: 5,
+                    },
+                    ArgTaskDisj {
+                        starting_time: y,
+                        duration: 6,
+                    },
+                    ArgTaskDisj {
+                        starting_time: z,
+                        duration: 4,
+                    },
+                ],
+                DisjunctivePropagatorOptions::default(),
+            ))
+            .expect("No conflict");
+
+        solver.assert_bounds(z, 11, 15);
+    }
+
+    #[test]
+    fn explanation_last_cluster() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 6);
+        let y = solver.new_variable(1, 5);
+        let z = solver.new_variable(2, 15);
+
+        let _ = solver
+            .new_propagator(EdgeFindingPropagator::new(
+                &[
+                    ArgTaskDisj {
+                        starting_time: x,
+                        duration: 5,
+                    },
+                    ArgTaskDisj {
+                        starting_time: y,
+                        duration: 6,
+                    },
+                    ArgTaskDisj {
+                        starting_time: z,
+                        duration: 4,
+                    },
+                ],
+                DisjunctivePropagatorOptions {
+                    explanation_type: DisjunctiveExplanationType::LastCluster,
+                    ..Default::default()
+                },
+            ))
+            .expect("No conflict");
+
+        // The tasks x, y and z cannot be executed in [0, 15); the upper-bounds of x and y are
+        // lifted accordingly
+        let reason = solver.get_reason_int(predicate![z >= 11]);
+        assert_eq!(
+            reason,
+            conjunction!([x >= 0] & [x <= 9] & [y >= 0] & [y <= 8] & [z >= 0])
+        );
+    }
+}
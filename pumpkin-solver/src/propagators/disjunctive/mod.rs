@@ -1,4 +1,13 @@
 pub(crate) use detectable_precedences::*;
+pub(crate) use edge_finding::*;
+pub(crate) use not_first_not_last::*;
+pub(crate) use overload_checking::*;
 mod detectable_precedences;
+mod edge_finding;
+mod not_first_not_last;
+mod overload_checking;
 mod utils;
+pub use utils::DisjunctiveExplanationType;
+pub use utils::DisjunctiveOptionsError;
+pub use utils::DisjunctivePropagatorOptions;
 pub(crate) use utils::*;
@@ -0,0 +1,233 @@
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::DomainEvents;
+use crate::propagators::disjunctive::create_not_last_explanation;
+use crate::propagators::disjunctive::last_cluster;
+use crate::propagators::disjunctive::latest_starting_time;
+use crate::propagators::disjunctive::ArgTaskDisj;
+use crate::propagators::disjunctive::Direction;
+use crate::propagators::disjunctive::DisjunctivePropagatorOptions;
+use crate::propagators::disjunctive::TaskDisj;
+use crate::propagators::disjunctive::ThetaLambdaTree;
+use crate::variables::IntegerVariable;
+
+/// Propagator for the disjunctive constraint using the not-first/not-last rules; it is based on
+/// the algorithm using Θ-trees described in \[1\].
+///
+/// For a set of tasks Ω and a task `i ∉ Ω`, if `ect(Ω) > lst_i` then `i` cannot be scheduled
+/// after all tasks in Ω (i.e. `i` is not last); the latest starting time of `i` is then updated
+/// such that it ends before the latest starting time of one of the tasks in Ω. The not-first rule
+/// updates the earliest starting times symmetrically.
+///
+/// # Bibliography
+/// \[1\] P. Vilím, ‘Global constraints in scheduling’, PhD thesis, Charles University in Prague,
+/// 2007.
+#[derive(Clone, Debug)]
+pub(crate) struct NotFirstNotLastPropagator<Var> {
+    tasks: Box<[TaskDisj<Var>]>,
+    options: DisjunctivePropagatorOptions,
+}
+
+impl<Var> NotFirstNotLastPropagator<Var>
+where
+    Var: IntegerVariable + 'static,
+{
+    pub(crate) fn new(tasks: &[ArgTaskDisj<Var>], options: DisjunctivePropagatorOptions) -> Self {
+        NotFirstNotLastPropagator {
+            tasks: TaskDisj::from_args(tasks),
+            options,
+        }
+    }
+
+    /// Applies the not-last rule in the provided [`Direction`]; in the [`Direction::Backward`]
+    /// this corresponds to the not-first rule.
+    fn propagate_not_last(
+        &self,
+        context: &mut PropagationContextMut,
+        direction: Direction,
+    ) -> PropagationStatusCP {
+        let bounds = direction.task_bounds(&self.tasks, context);
+
+        let mut tasks_by_lct = (0..bounds.len()).collect::<Vec<_>>();
+        tasks_by_lct.sort_by_key(|&task| bounds[task].lct());
+        let mut tasks_by_lst = (0..bounds.len()).collect::<Vec<_>>();
+        tasks_by_lst.sort_by_key(|&task| bounds[task].lst);
+
+        let mut tree = ThetaLambdaTree::new(&bounds);
+        // Θ consists of the first `theta_size` tasks sorted by latest starting time
+        let mut theta_size = 0;
+        let mut in_theta = vec![false; bounds.len()];
+
+        let mut updates = vec![];
+        for &i in tasks_by_lct.iter() {
+            while theta_size < tasks_by_lst.len()
+                && bounds[i].lct() > bounds[tasks_by_lst[theta_size]].lst
+            {
+                let task = tasks_by_lst[theta_size];
+                tree.add_to_theta(task);
+                in_theta[task] = true;
+                theta_size += 1;
+            }
+
+            // The rule is applied to Ω = Θ \ {i}
+            if in_theta[i] {
+                tree.remove(i);
+            }
+
+            if tree
+                .earliest_completion_time()
+                .is_some_and(|earliest_completion_time| earliest_completion_time > bounds[i].lst)
+            {
+                let omega = tasks_by_lst[..theta_size]
+                    .iter()
+                    .copied()
+                    .filter(|&task| task != i)
+                    .collect::<Vec<_>>();
+                let cluster = last_cluster(&bounds, &omega);
+                let latest_start = latest_starting_time(&bounds, &cluster) - bounds[i].duration;
+
+                if latest_start < bounds[i].lst {
+                    let reason = create_not_last_explanation(
+                        &self.tasks,
+                        &bounds,
+                        direction,
+                        i,
+                        &omega,
+                        self.options.explanation_type,
+                    );
+                    updates.push((
+                        direction.upper_bound_predicate(&self.tasks[i], latest_start),
+                        reason,
+                    ));
+                }
+            }
+
+            if in_theta[i] {
+                tree.add_to_theta(i);
+            }
+        }
+
+        for (predicate, reason) in updates {
+            context.post_predicate(predicate, reason)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<Var> Propagator for NotFirstNotLastPropagator<Var>
+where
+    Var: IntegerVariable + 'static,
+{
+    fn priority(&self) -> u32 {
+        3
+    }
+
+    fn name(&self) -> &str {
+        "DisjunctiveNotFirstNotLast"
+    }
+
+    fn inference_label(&self) -> &str {
+        "not_first_not_last"
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.tasks.iter().for_each(|task| {
+            let _ = context.register(
+                task.starting_time.clone(),
+                DomainEvents::BOUNDS,
+                task.local_id,
+            );
+        });
+        Ok(())
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        self.propagate_not_last(&mut context, Direction::Forward)?;
+        self.propagate_not_last(&mut context, Direction::Backward)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::conjunction;
+    use crate::engine::test_solver::TestSolver;
+    use crate::options::DisjunctiveExplanationType;
+    use crate::predicate;
+    use crate::propagators::disjunctive::ArgTaskDisj;
+    use crate::propagators::disjunctive::DisjunctivePropagatorOptions;
+    use crate::propagators::disjunctive::NotFirstNotLastPropagator;
+
+    #[test]
+    fn task_is_not_last() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 7);
+        let y = solver.new_variable(6, 8);
+
+        let _ = solver
+            .new_propagator(NotFirstNotLastPropagator::new(
+                &[
+                    ArgTaskDisj {
+                        starting_time: x,
+                        duration: 5,
+                    },
+                    ArgTaskDisj {
+                        starting_time: y,
+                        duration: 3,
+                    },
+                ],
+                DisjunctivePropagatorOptions {
+                    explanation_type: DisjunctiveExplanationType::LastCluster,
+                    ..Default::default()
+                },
+            ))
+            .expect("No conflict");
+
+        solver.assert_bounds(x, 0, 3);
+        solver.assert_bounds(y, 6, 8);
+
+        let reason = solver.get_reason_int(predicate![x <= 3]);
+        assert_eq!(reason, conjunction!([y >= 6] & [y <= 8] & [x <= 8]));
+    }
+
+    #[test]
+    fn task_is_not_first() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 10);
+        let y = solver.new_variable(0, 2);
+
+        let _ = solver
+            .new_propagator(NotFirstNotLastPropagator::new(
+                &[
+                    ArgTaskDisj {
+                        starting_time: x,
+                        duration: 5,
+                    },
+                    ArgTaskDisj {
+                        starting_time: y,
+                        duration: 3,
+                    },
+                ],
+                DisjunctivePropagatorOptions {
+                    explanation_type: DisjunctiveExplanationType::LastCluster,
+                    ..Default::default()
+                },
+            ))
+            .expect("No conflict");
+
+        solver.assert_bounds(x, 3, 10);
+        solver.assert_bounds(y, 0, 2);
+
+        let reason = solver.get_reason_int(predicate![x >= 3]);
+        assert_eq!(reason, conjunction!([y <= 2] & [y >= 0] & [x >= -2]));
+    }
+}
@@ -0,0 +1,186 @@
+use crate::basic_types::Inconsistency;
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::DomainEvents;
+use crate::propagators::disjunctive::create_overload_explanation;
+use crate::propagators::disjunctive::ArgTaskDisj;
+use crate::propagators::disjunctive::Direction;
+use crate::propagators::disjunctive::DisjunctivePropagatorOptions;
+use crate::propagators::disjunctive::TaskDisj;
+use crate::propagators::disjunctive::Timeline;
+use crate::variables::IntegerVariable;
+
+/// Propagator for the disjunctive constraint which performs overload checking using the
+/// timeline-based algorithm described in \[1\].
+///
+/// A set of tasks Ω is overloaded if it cannot be completed before its latest completion time;
+/// i.e. if `ect(Ω) > lct(Ω)`. This propagator only detects conflicts, it does not update any
+/// bounds.
+///
+/// # Bibliography
+/// \[1\] H. Fahimi and C.-G. Quimper, ‘Linear-time filtering algorithms for the disjunctive
+/// constraint’, in Proceedings of the AAAI Conference on Artificial Intelligence, 2014.
+#[derive(Clone, Debug)]
+pub(crate) struct OverloadCheckingPropagator<Var> {
+    tasks: Box<[TaskDisj<Var>]>,
+    options: DisjunctivePropagatorOptions,
+}
+
+impl<Var> OverloadCheckingPropagator<Var>
+where
+    Var: IntegerVariable + 'static,
+{
+    pub(crate) fn new(tasks: &[ArgTaskDisj<Var>], options: DisjunctivePropagatorOptions) -> Self {
+        OverloadCheckingPropagator {
+            tasks: TaskDisj::from_args(tasks),
+            options,
+        }
+    }
+}
+
+impl<Var> Propagator for OverloadCheckingPropagator<Var>
+where
+    Var: IntegerVariable + 'static,
+{
+    fn priority(&self) -> u32 {
+        3
+    }
+
+    fn name(&self) -> &str {
+        "DisjunctiveOverloadChecking"
+    }
+
+    fn inference_label(&self) -> &str {
+        "overload_checking"
+    }
+
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.tasks.iter().for_each(|task| {
+            let _ = context.register(
+                task.starting_time.clone(),
+                DomainEvents::BOUNDS,
+                task.local_id,
+            );
+        });
+        Ok(())
+    }
+
+    fn debug_propagate_from_scratch(&self, context: PropagationContextMut) -> PropagationStatusCP {
+        // Checking for overload in a single direction suffices to detect all overloaded sets
+        let direction = Direction::Forward;
+        let bounds = direction.task_bounds(&self.tasks, &context);
+
+        let mut tasks_by_lct = (0..bounds.len()).collect::<Vec<_>>();
+        tasks_by_lct.sort_by_key(|&task| bounds[task].lct());
+
+        let mut timeline = Timeline::new(&bounds);
+        for (index, &task) in tasks_by_lct.iter().enumerate() {
+            timeline.schedule_task(task, bounds[task].duration);
+
+            if timeline
+                .earliest_completion_time()
+                .is_some_and(|earliest_completion_time| {
+                    earliest_completion_time > bounds[task].lct()
+                })
+            {
+                return Err(Inconsistency::Conflict(create_overload_explanation(
+                    &self.tasks,
+                    &bounds,
+                    direction,
+                    &tasks_by_lct[..=index],
+                    self.options.explanation_type,
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::Inconsistency;
+    use crate::conjunction;
+    use crate::engine::test_solver::TestSolver;
+    use crate::options::DisjunctiveExplanationType;
+    use crate::propagators::disjunctive::ArgTaskDisj;
+    use crate::propagators::disjunctive::DisjunctivePropagatorOptions;
+    use crate::propagators::disjunctive::OverloadCheckingPropagator;
+
+    #[test]
+    fn no_conflict_without_overload() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 6);
+        let y = solver.new_variable(0, 6);
+        let z = solver.new_variable(0, 6);
+
+        let result = solver.new_propagator(OverloadCheckingPropagator::new(
+            &[
+                ArgTaskDisj {
+                    starting_time: x,
+                    duration: 3,
+                },
+                ArgTaskDisj {
+                    starting_time: y,
+                    duration: 3,
+                },
+                ArgTaskDisj {
+                    starting_time: z,
+                    duration: 3,
+                },
+            ],
+            DisjunctivePropagatorOptions::default(),
+        ));
+        assert!(result.is_ok());
+        solver.assert_bounds(x, 0, 6);
+    }
+
+    #[test]
+    fn conflict_is_explained_by_last_cluster() {
+        let mut solver = TestSolver::default();
+        let w = solver.new_variable(0, 20);
+        let x = solver.new_variable(2, 4);
+        let y = solver.new_variable(3, 5);
+        let z = solver.new_variable(4, 6);
+
+        let result = solver.new_propagator(OverloadCheckingPropagator::new(
+            &[
+                ArgTaskDisj {
+                    starting_time: w,
+                    duration: 2,
+                },
+                ArgTaskDisj {
+                    starting_time: x,
+                    duration: 3,
+                },
+                ArgTaskDisj {
+                    starting_time: y,
+                    duration: 3,
+                },
+                ArgTaskDisj {
+                    starting_time: z,
+                    duration: 3,
+                },
+            ],
+            DisjunctivePropagatorOptions {
+                explanation_type: DisjunctiveExplanationType::LastCluster,
+                ..Default::default()
+            },
+        ));
+
+        // The tasks x, y and z require 9 time units but have to be executed in [2, 9)
+        let Err(Inconsistency::Conflict(reason)) = result else {
+            panic!("Expected a conflict to be detected");
+        };
+        assert_eq!(
+            reason,
+            conjunction!([x >= 2] & [x <= 7] & [y >= 2] & [y <= 7] & [z >= 2] & [z <= 7])
+        );
+    }
+}
@@ -0,0 +1,363 @@
+use std::cmp::Reverse;
+
+use super::DisjunctiveExplanationType;
+use super::TaskBounds;
+use super::TaskDisj;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::ReadDomains;
+use crate::predicate;
+use crate::predicates::Predicate;
+use crate::predicates::PropositionalConjunction;
+use crate::variables::IntegerVariable;
+
+/// The direction in which the disjunctive propagators reason over the tasks.
+///
+/// All reasoning is done over earliest starting times; when updating latest starting times, the
+/// bounds of the tasks are mirrored (see [`TaskBounds::mirrored`]) and the resulting bounds and
+/// explanations are translated back to the original tasks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Direction {
+    /// Reasoning over the earliest starting times of the tasks
+    Forward,
+    /// Reasoning over the latest starting times of the tasks
+    Backward,
+}
+
+impl Direction {
+    /// Returns the (possibly mirrored) bounds of all of the provided tasks.
+    pub(crate) fn task_bounds<Var: IntegerVariable>(
+        self,
+        tasks: &[TaskDisj<Var>],
+        context: &PropagationContextMut,
+    ) -> Vec<TaskBounds> {
+        tasks
+            .iter()
+            .map(|task| {
+                let bounds = TaskBounds {
+                    est: context.lower_bound(&task.starting_time),
+                    lst: context.upper_bound(&task.starting_time),
+                    duration: task.duration,
+                };
+                match self {
+                    Direction::Forward => bounds,
+                    Direction::Backward => bounds.mirrored(),
+                }
+            })
+            .collect()
+    }
+
+    /// Creates the predicate `[s >= value]` where `s` is the (possibly mirrored) starting time of
+    /// the provided task.
+    pub(crate) fn lower_bound_predicate<Var: IntegerVariable>(
+        self,
+        task: &TaskDisj<Var>,
+        value: i64,
+    ) -> Predicate {
+        match self {
+            Direction::Forward => predicate![task.starting_time >= value],
+            Direction::Backward => predicate![task.starting_time <= -value - task.duration],
+        }
+    }
+
+    /// Creates the predicate `[s <= value]` where `s` is the (possibly mirrored) starting time of
+    /// the provided task.
+    pub(crate) fn upper_bound_predicate<Var: IntegerVariable>(
+        self,
+        task: &TaskDisj<Var>,
+        value: i64,
+    ) -> Predicate {
+        match self {
+            Direction::Forward => predicate![task.starting_time <= value],
+            Direction::Backward => predicate![task.starting_time >= -value - task.duration],
+        }
+    }
+}
+
+/// Creates the explanation for increasing the (possibly mirrored) earliest starting time of
+/// `propagated` to the earliest completion time of `omega`, a set of tasks which all precede
+/// `propagated`.
+///
+/// The `bounds` should be the bounds of the tasks in the provided [`Direction`].
+pub(crate) fn create_precedence_explanation<Var: IntegerVariable>(
+    tasks: &[TaskDisj<Var>],
+    bounds: &[TaskBounds],
+    direction: Direction,
+    propagated: usize,
+    omega: &[usize],
+    explanation_type: DisjunctiveExplanationType,
+) -> PropositionalConjunction {
+    match explanation_type {
+        DisjunctiveExplanationType::Naive => create_naive_explanation(tasks, bounds, direction),
+        DisjunctiveExplanationType::PrevScheduledTasks => {
+            current_bounds(tasks, bounds, direction, omega.iter().copied())
+                .chain(std::iter::once(direction.lower_bound_predicate(
+                    &tasks[propagated],
+                    bounds[propagated].est,
+                )))
+                .collect()
+        }
+        DisjunctiveExplanationType::LastCluster => {
+            let cluster = last_cluster(bounds, omega);
+            let cluster_est = earliest_starting_time(bounds, &cluster);
+            // The precedence of a task `k` with the propagated task `i` is detected as long as
+            // `lst_k < ect_i`
+            let latest_preceding_start = bounds[propagated].ect() - 1;
+
+            cluster
+                .iter()
+                .flat_map(|&task| {
+                    [
+                        direction.lower_bound_predicate(&tasks[task], cluster_est),
+                        direction.upper_bound_predicate(&tasks[task], latest_preceding_start),
+                    ]
+                })
+                .chain(std::iter::once(direction.lower_bound_predicate(
+                    &tasks[propagated],
+                    bounds[propagated].est,
+                )))
+                .collect()
+        }
+    }
+}
+
+/// Creates the explanation for the conflict which occurs when the two provided tasks are both
+/// required to execute at the same time (i.e. their mandatory parts overlap).
+pub(crate) fn create_overlap_conflict_explanation<Var: IntegerVariable>(
+    tasks: &[TaskDisj<Var>],
+    bounds: &[TaskBounds],
+    direction: Direction,
+    first: usize,
+    second: usize,
+    explanation_type: DisjunctiveExplanationType,
+) -> PropositionalConjunction {
+    match explanation_type {
+        DisjunctiveExplanationType::Naive => create_naive_explanation(tasks, bounds, direction),
+        DisjunctiveExplanationType::PrevScheduledTasks
+        | DisjunctiveExplanationType::LastCluster => {
+            current_bounds(tasks, bounds, direction, [first, second].into_iter()).collect()
+        }
+    }
+}
+
+/// Creates the explanation for the conflict which occurs when the tasks in `theta` cannot be
+/// scheduled before the latest completion time of `theta`; i.e. when `ect(Θ) > lct(Θ)`.
+pub(crate) fn create_overload_explanation<Var: IntegerVariable>(
+    tasks: &[TaskDisj<Var>],
+    bounds: &[TaskBounds],
+    direction: Direction,
+    theta: &[usize],
+    explanation_type: DisjunctiveExplanationType,
+) -> PropositionalConjunction {
+    match explanation_type {
+        DisjunctiveExplanationType::Naive => create_naive_explanation(tasks, bounds, direction),
+        DisjunctiveExplanationType::PrevScheduledTasks => {
+            current_bounds(tasks, bounds, direction, theta.iter().copied()).collect()
+        }
+        DisjunctiveExplanationType::LastCluster => {
+            // The tasks in the cluster cannot be scheduled in any interval which is shorter than
+            // their total processing time
+            let cluster = last_cluster(bounds, theta);
+            let cluster_est = earliest_starting_time(bounds, &cluster);
+            let latest_completion_time = cluster_est + processing_time(bounds, &cluster) - 1;
+
+            cluster
+                .iter()
+                .flat_map(|&task| {
+                    [
+                        direction.lower_bound_predicate(&tasks[task], cluster_est),
+                        direction.upper_bound_predicate(
+                            &tasks[task],
+                            latest_completion_time - bounds[task].duration,
+                        ),
+                    ]
+                })
+                .collect()
+        }
+    }
+}
+
+/// Creates the explanation for the edge-finding rule which increases the (possibly mirrored)
+/// earliest starting time of `propagated` to the earliest completion time of `theta`.
+///
+/// The rule is applied when the earliest completion time of `Θ ∪ {i}` is larger than the latest
+/// completion time of Θ (where `i` is the propagated task); in that case, `i` has to be scheduled
+/// after all tasks in Θ.
+pub(crate) fn create_edge_finding_explanation<Var: IntegerVariable>(
+    tasks: &[TaskDisj<Var>],
+    bounds: &[TaskBounds],
+    direction: Direction,
+    propagated: usize,
+    theta: &[usize],
+    explanation_type: DisjunctiveExplanationType,
+) -> PropositionalConjunction {
+    match explanation_type {
+        DisjunctiveExplanationType::Naive => create_naive_explanation(tasks, bounds, direction),
+        DisjunctiveExplanationType::PrevScheduledTasks => {
+            current_bounds(tasks, bounds, direction, theta.iter().copied())
+                .chain(std::iter::once(direction.lower_bound_predicate(
+                    &tasks[propagated],
+                    bounds[propagated].est,
+                )))
+                .collect()
+        }
+        DisjunctiveExplanationType::LastCluster => {
+            // The set of tasks which, together with the propagated task, cannot be completed
+            // before `latest_completion_time`; this implies that the propagated task cannot
+            // precede any task which completes before `latest_completion_time`.
+            let mut detecting_tasks = theta.to_vec();
+            detecting_tasks.push(propagated);
+            let detecting_cluster = last_cluster(bounds, &detecting_tasks);
+            let detecting_est = earliest_starting_time(bounds, &detecting_cluster);
+            let latest_completion_time =
+                detecting_est + processing_time(bounds, &detecting_cluster) - 1;
+
+            // The set of tasks which determines the new bound of the propagated task
+            let cluster = last_cluster(bounds, theta);
+            let cluster_est = earliest_starting_time(bounds, &cluster);
+
+            let mut lower_bounds = vec![None; tasks.len()];
+            let mut upper_bounds = vec![None; tasks.len()];
+            for &task in detecting_cluster.iter() {
+                lower_bounds[task] = Some(detecting_est);
+                if task != propagated {
+                    upper_bounds[task] = Some(latest_completion_time - bounds[task].duration);
+                }
+            }
+            for &task in cluster.iter() {
+                lower_bounds[task] = lower_bounds[task].max(Some(cluster_est));
+                upper_bounds[task] = Some(latest_completion_time - bounds[task].duration);
+            }
+
+            lower_bounds
+                .iter()
+                .enumerate()
+                .filter_map(|(task, bound)| {
+                    bound.map(|bound| direction.lower_bound_predicate(&tasks[task], bound))
+                })
+                .chain(upper_bounds.iter().enumerate().filter_map(|(task, bound)| {
+                    bound.map(|bound| direction.upper_bound_predicate(&tasks[task], bound))
+                }))
+                .collect()
+        }
+    }
+}
+
+/// Creates the explanation for the not-last rule which decreases the (possibly mirrored) latest
+/// starting time of `propagated` to `max_{k ∈ Ω'} lst_k - p_i`, where Ω' is the last cluster of
+/// `omega`.
+///
+/// The rule is applied when the earliest completion time of Ω is larger than the latest starting
+/// time of the propagated task; in that case, the propagated task cannot be scheduled after all
+/// tasks in Ω.
+pub(crate) fn create_not_last_explanation<Var: IntegerVariable>(
+    tasks: &[TaskDisj<Var>],
+    bounds: &[TaskBounds],
+    direction: Direction,
+    propagated: usize,
+    omega: &[usize],
+    explanation_type: DisjunctiveExplanationType,
+) -> PropositionalConjunction {
+    match explanation_type {
+        DisjunctiveExplanationType::Naive => create_naive_explanation(tasks, bounds, direction),
+        DisjunctiveExplanationType::PrevScheduledTasks => {
+            current_bounds(tasks, bounds, direction, omega.iter().copied())
+                .chain(std::iter::once(direction.upper_bound_predicate(
+                    &tasks[propagated],
+                    bounds[propagated].lst,
+                )))
+                .collect()
+        }
+        DisjunctiveExplanationType::LastCluster => {
+            let cluster = last_cluster(bounds, omega);
+            let cluster_est = earliest_starting_time(bounds, &cluster);
+            let cluster_ect = cluster_est + processing_time(bounds, &cluster);
+            let latest_start = latest_starting_time(bounds, &cluster);
+
+            cluster
+                .iter()
+                .flat_map(|&task| {
+                    [
+                        direction.lower_bound_predicate(&tasks[task], cluster_est),
+                        direction.upper_bound_predicate(&tasks[task], latest_start),
+                    ]
+                })
+                .chain(std::iter::once(
+                    direction.upper_bound_predicate(&tasks[propagated], cluster_ect - 1),
+                ))
+                .collect()
+        }
+    }
+}
+
+/// Creates an explanation consisting of the current bounds of all tasks.
+fn create_naive_explanation<Var: IntegerVariable>(
+    tasks: &[TaskDisj<Var>],
+    bounds: &[TaskBounds],
+    direction: Direction,
+) -> PropositionalConjunction {
+    current_bounds(tasks, bounds, direction, 0..tasks.len()).collect()
+}
+
+/// Returns the predicates describing the current bounds of the provided tasks.
+fn current_bounds<'a, Var: IntegerVariable>(
+    tasks: &'a [TaskDisj<Var>],
+    bounds: &'a [TaskBounds],
+    direction: Direction,
+    selected_tasks: impl Iterator<Item = usize> + 'a,
+) -> impl Iterator<Item = Predicate> + 'a {
+    selected_tasks.flat_map(move |task| {
+        [
+            direction.lower_bound_predicate(&tasks[task], bounds[task].est),
+            direction.upper_bound_predicate(&tasks[task], bounds[task].lst),
+        ]
+    })
+}
+
+/// Returns the smallest subset Ω' of `omega` such that `est(Ω') + p(Ω')` is equal to the earliest
+/// completion time of `omega`.
+pub(crate) fn last_cluster(bounds: &[TaskBounds], omega: &[usize]) -> Vec<usize> {
+    let mut sorted = omega.to_vec();
+    sorted.sort_by_key(|&task| Reverse(bounds[task].est));
+
+    let mut processing_time = 0;
+    let mut best_completion_time = i64::MIN;
+    let mut cluster_size = 0;
+    for (index, &task) in sorted.iter().enumerate() {
+        processing_time += bounds[task].duration;
+
+        // Only consider the completion time once all tasks with the same earliest starting time
+        // have been added
+        let is_last_with_est =
+            index + 1 == sorted.len() || bounds[sorted[index + 1]].est != bounds[task].est;
+        if is_last_with_est && bounds[task].est + processing_time > best_completion_time {
+            best_completion_time = bounds[task].est + processing_time;
+            cluster_size = index + 1;
+        }
+    }
+
+    sorted.truncate(cluster_size);
+    sorted
+}
+
+/// Returns the earliest starting time of the provided (non-empty) set of tasks.
+fn earliest_starting_time(bounds: &[TaskBounds], tasks: &[usize]) -> i64 {
+    tasks
+        .iter()
+        .map(|&task| bounds[task].est)
+        .min()
+        .expect("Expected the set of tasks to be non-empty")
+}
+
+/// Returns the latest starting time of any task in the provided (non-empty) set of tasks.
+pub(crate) fn latest_starting_time(bounds: &[TaskBounds], tasks: &[usize]) -> i64 {
+    tasks
+        .iter()
+        .map(|&task| bounds[task].lst)
+        .max()
+        .expect("Expected the set of tasks to be non-empty")
+}
+
+/// Returns the total processing time of the provided set of tasks.
+fn processing_time(bounds: &[TaskBounds], tasks: &[usize]) -> i64 {
+    tasks.iter().map(|&task| bounds[task].duration).sum()
+}
@@ -1,8 +1,14 @@
+mod explanations;
+pub(crate) use explanations::*;
 mod task;
 pub(crate) use task::*;
+mod theta_lambda_tree;
+pub(crate) use theta_lambda_tree::*;
 mod timeline;
 pub(crate) use timeline::*;
 mod union_find;
 pub(crate) use union_find::*;
 mod options;
-pub(crate) use options::*;
\ No newline at end of file
+pub use options::DisjunctiveExplanationType;
+pub use options::DisjunctiveOptionsError;
+pub use options::DisjunctivePropagatorOptions;
@@ -1,16 +1,138 @@
+use std::fmt::Display;
 
+use clap::ValueEnum;
+use thiserror::Error;
 
+/// The options which can be passed to the disjunctive constraint.
+///
+/// Besides the type of explanation, these options determine which filtering rules are used to
+/// propagate the disjunctive; every enabled rule is posted as a separate propagator. By default,
+/// all rules are enabled.
+#[derive(Clone, Debug, Copy)]
+pub struct DisjunctivePropagatorOptions {
+    /// The type of explanation which is used by the disjunctive to explain propagations and
+    /// conflicts.
+    pub(crate) explanation_type: DisjunctiveExplanationType,
+    /// Determines whether overload checking is used to detect conflicts.
+    pub(crate) overload_checking: bool,
+    /// Determines whether the detectable precedences rule is used.
+    pub(crate) detectable_precedences: bool,
+    /// Determines whether the edge-finding rule is used.
+    pub(crate) edge_finding: bool,
+    /// Determines whether the not-first/not-last rules are used.
+    pub(crate) not_first_not_last: bool,
+}
 
-#[derive(Clone, Debug, Default, Copy)]
-pub (crate) struct DisjunctivePropagatorOptions {
-    pub (crate) explanation_type: DisjunctiveExplanationType
+impl Default for DisjunctivePropagatorOptions {
+    fn default() -> Self {
+        Self {
+            explanation_type: DisjunctiveExplanationType::default(),
+            overload_checking: true,
+            detectable_precedences: true,
+            edge_finding: true,
+            not_first_not_last: true,
+        }
+    }
 }
 
+impl DisjunctivePropagatorOptions {
+    /// Creates the options for the disjunctive with the provided explanation type; all filtering
+    /// rules are enabled.
+    pub fn new(explanation_type: DisjunctiveExplanationType) -> Self {
+        Self {
+            explanation_type,
+            ..Default::default()
+        }
+    }
 
-#[derive(Debug, Clone, Copy, Default)]
-pub (crate) enum DisjunctiveExplanationType {
-    #[default]
+    /// Determines whether overload checking is used to detect conflicts.
+    pub fn with_overload_checking(mut self, overload_checking: bool) -> Self {
+        self.overload_checking = overload_checking;
+        self
+    }
+
+    /// Determines whether the detectable precedences rule is used.
+    ///
+    /// Returns an error if this disables the last remaining filtering rule (see
+    /// [`DisjunctiveOptionsError::NoFilteringRule`]).
+    pub fn with_detectable_precedences(
+        mut self,
+        detectable_precedences: bool,
+    ) -> Result<Self, DisjunctiveOptionsError> {
+        self.detectable_precedences = detectable_precedences;
+        self.check_filtering_rules()
+    }
+
+    /// Determines whether the edge-finding rule is used.
+    ///
+    /// Returns an error if this disables the last remaining filtering rule (see
+    /// [`DisjunctiveOptionsError::NoFilteringRule`]).
+    pub fn with_edge_finding(
+        mut self,
+        edge_finding: bool,
+    ) -> Result<Self, DisjunctiveOptionsError> {
+        self.edge_finding = edge_finding;
+        self.check_filtering_rules()
+    }
+
+    /// Determines whether the not-first/not-last rules are used.
+    ///
+    /// Returns an error if this disables the last remaining filtering rule (see
+    /// [`DisjunctiveOptionsError::NoFilteringRule`]).
+    pub fn with_not_first_not_last(
+        mut self,
+        not_first_not_last: bool,
+    ) -> Result<Self, DisjunctiveOptionsError> {
+        self.not_first_not_last = not_first_not_last;
+        self.check_filtering_rules()
+    }
+
+    fn check_filtering_rules(self) -> Result<Self, DisjunctiveOptionsError> {
+        if self.detectable_precedences || self.edge_finding || self.not_first_not_last {
+            Ok(self)
+        } else {
+            Err(DisjunctiveOptionsError::NoFilteringRule)
+        }
+    }
+}
+
+/// The error which is returned when the [`DisjunctivePropagatorOptions`] would be invalid.
+#[derive(Debug, Clone, Copy, Error)]
+pub enum DisjunctiveOptionsError {
+    /// At least one of detectable precedences, edge-finding or not-first/not-last should be
+    /// enabled; overload checking on its own does not detect that a task with a duration of 0 is
+    /// scheduled during the execution of another task.
+    #[error("At least one of detectable precedences, edge-finding or not-first/not-last should be enabled for the disjunctive")]
+    NoFilteringRule,
+}
+
+/// Determines what type of explanation is used by the disjunctive.
+///
+/// Every filtering rule reasons over a set of tasks Ω; e.g. when the detectable precedences rule
+/// increases the earliest starting time of a task `i`, Ω is the set of tasks which has been
+/// detected to precede `i` and the new bound is the earliest completion time of Ω. The explanation
+/// types differ in which part of Ω (and its bounds) is used to explain a propagation or conflict.
+/// The explanations for updating the latest starting times are symmetric.
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum DisjunctiveExplanationType {
+    /// Uses the current bounds of all tasks in the disjunctive.
     Naive,
+    /// Uses the current bounds of the tasks in Ω and the bound of the propagated task.
     PrevScheduledTasks,
+    /// The default; only uses the subset Ω' ⊆ Ω which determines the earliest completion time of
+    /// Ω (i.e. the last cluster of tasks in the schedule of Ω). The bounds of these tasks are
+    /// lifted: the lower-bounds are weakened to the earliest starting time of Ω' and the
+    /// upper-bounds are weakened to the weakest bound for which the rule still applies.
+    #[default]
     LastCluster,
-}
\ No newline at end of file
+}
+
+impl Display for DisjunctiveExplanationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DisjunctiveExplanationType::Naive => write!(f, "naive"),
+            DisjunctiveExplanationType::PrevScheduledTasks => write!(f, "prev-scheduled-tasks"),
+            DisjunctiveExplanationType::LastCluster => write!(f, "last-cluster"),
+        }
+    }
+}
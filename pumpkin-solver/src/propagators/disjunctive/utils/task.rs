@@ -1,52 +1,71 @@
-use std::fmt::Debug;
-use std::rc::Rc;
-
 use crate::engine::propagation::LocalId;
-use crate::engine::Assignments;
 use crate::variables::IntegerVariable;
 
-#[derive(Clone)]
+/// A task of the disjunctive as it is stored in the propagator.
+#[derive(Clone, Debug)]
 pub(crate) struct TaskDisj<Var> {
     pub(crate) starting_time: Var,
-    pub(crate) duration: i32,
-    pub(crate) deadline: i32,
+    pub(crate) duration: i64,
     pub(crate) local_id: LocalId,
 }
 
-impl<Var: IntegerVariable + 'static> TaskDisj<Var> {
-    pub(crate) fn get_id(task: &Rc<TaskDisj<Var>>) -> usize {
-        task.local_id.unpack() as usize
+impl<Var: IntegerVariable> TaskDisj<Var> {
+    /// Creates the tasks which are stored by a propagator; the [`LocalId`] of every task is its
+    /// index.
+    pub(crate) fn from_args(tasks: &[ArgTaskDisj<Var>]) -> Box<[TaskDisj<Var>]> {
+        tasks
+            .iter()
+            .enumerate()
+            .map(|(index, task)| TaskDisj {
+                starting_time: task.starting_time.clone(),
+                duration: task.duration,
+                local_id: LocalId::from(index as u32),
+            })
+            .collect()
     }
+}
 
-    pub(crate) fn get_ect(task: &TaskDisj<Var>, assignments: &Assignments) -> i32 {
-        task.starting_time.lower_bound(assignments) + task.duration
-    }
+/// A task which is passed to the disjunctive propagators upon construction.
+#[derive(Clone, Debug)]
+pub(crate) struct ArgTaskDisj<Var> {
+    pub(crate) starting_time: Var,
+    pub(crate) duration: i64,
+}
 
-    pub(crate) fn get_lst(task: &TaskDisj<Var>, assignments: &Assignments) -> i32 {
-        task.starting_time.upper_bound(assignments)
-    }
+/// A snapshot of the bounds of a task at the moment of propagation.
+///
+/// The propagation algorithms only reason over earliest start times; the latest start times are
+/// updated by running the same algorithms over the mirrored bounds (see [`TaskBounds::mirrored`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct TaskBounds {
+    /// The earliest starting time of the task
+    pub(crate) est: i64,
+    /// The latest starting time of the task
+    pub(crate) lst: i64,
+    pub(crate) duration: i64,
+}
 
-    pub(crate) fn get_est(task: &TaskDisj<Var>, assignments: &Assignments) -> i32 {
-        task.starting_time.lower_bound(assignments)
+impl TaskBounds {
+    /// The earliest completion time of the task
+    pub(crate) fn ect(&self) -> i64 {
+        self.est + self.duration
     }
 
-    pub(crate) fn get_lct(task: &TaskDisj<Var>, assignments: &Assignments) -> i32 {
-        task.starting_time.upper_bound(assignments) + task.duration
+    /// The latest completion time of the task
+    pub(crate) fn lct(&self) -> i64 {
+        self.lst + self.duration
     }
-}
 
-impl<Var> Debug for TaskDisj<Var> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Task")
-            .field("duration", &self.duration)
-            .field("deadline", &self.deadline)
-            .field("local_id", &self.local_id)
-            .finish()
+    /// Returns the bounds of the task when time is reversed; i.e. the bounds of `s' = -s - d`
+    /// where `s` is the starting time and `d` is the duration of the task.
+    ///
+    /// Increasing the earliest starting time of the mirrored task corresponds to decreasing the
+    /// latest starting time of the original task.
+    pub(crate) fn mirrored(&self) -> TaskBounds {
+        TaskBounds {
+            est: -self.lct(),
+            lst: -self.ect(),
+            duration: self.duration,
+        }
     }
 }
-
-pub(crate) struct ArgTaskDisj<Var> {
-    pub(crate) starting_time: Var,
-    pub(crate) duration: i32,
-    pub(crate) deadline: i32,
-}
@@ -0,0 +1,256 @@
+use std::cmp::max;
+
+use super::TaskBounds;
+
+/// The value used to represent the earliest completion time of an empty set of tasks.
+const EMPTY_COMPLETION_TIME: i128 = i128::MIN / 4;
+
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    /// The total processing time of the tasks in Θ
+    sum_processing_time: i128,
+    /// The earliest completion time of the tasks in Θ
+    earliest_completion_time: i128,
+    /// The total processing time of the tasks in Θ when at most one task from Λ is added
+    sum_processing_time_gray: i128,
+    /// The earliest completion time of the tasks in Θ when at most one task from Λ is added
+    earliest_completion_time_gray: i128,
+    /// The task from Λ which is responsible for `sum_processing_time_gray`
+    responsible_processing_time: Option<usize>,
+    /// The task from Λ which is responsible for `earliest_completion_time_gray`
+    responsible_completion_time: Option<usize>,
+}
+
+impl Node {
+    const EMPTY: Node = Node {
+        sum_processing_time: 0,
+        earliest_completion_time: EMPTY_COMPLETION_TIME,
+        sum_processing_time_gray: 0,
+        earliest_completion_time_gray: EMPTY_COMPLETION_TIME,
+        responsible_processing_time: None,
+        responsible_completion_time: None,
+    };
+
+    fn combine(left: &Node, right: &Node) -> Node {
+        let sum_processing_time = left.sum_processing_time + right.sum_processing_time;
+        let earliest_completion_time = max(
+            right.earliest_completion_time,
+            left.earliest_completion_time + right.sum_processing_time,
+        );
+
+        let (sum_processing_time_gray, responsible_processing_time) =
+            if left.sum_processing_time_gray + right.sum_processing_time
+                >= left.sum_processing_time + right.sum_processing_time_gray
+            {
+                (
+                    left.sum_processing_time_gray + right.sum_processing_time,
+                    left.responsible_processing_time,
+                )
+            } else {
+                (
+                    left.sum_processing_time + right.sum_processing_time_gray,
+                    right.responsible_processing_time,
+                )
+            };
+
+        let mut earliest_completion_time_gray = right.earliest_completion_time_gray;
+        let mut responsible_completion_time = right.responsible_completion_time;
+        if left.earliest_completion_time + right.sum_processing_time_gray
+            > earliest_completion_time_gray
+        {
+            earliest_completion_time_gray =
+                left.earliest_completion_time + right.sum_processing_time_gray;
+            responsible_completion_time = right.responsible_processing_time;
+        }
+        if left.earliest_completion_time_gray + right.sum_processing_time
+            > earliest_completion_time_gray
+        {
+            earliest_completion_time_gray =
+                left.earliest_completion_time_gray + right.sum_processing_time;
+            responsible_completion_time = left.responsible_completion_time;
+        }
+
+        Node {
+            sum_processing_time,
+            earliest_completion_time,
+            sum_processing_time_gray,
+            earliest_completion_time_gray,
+            responsible_processing_time,
+            responsible_completion_time,
+        }
+    }
+}
+
+/// The Θ-Λ-tree data structure from \[1\]; it maintains two disjoint sets of tasks Θ and Λ and
+/// allows computing the earliest completion time of Θ (i.e. `max_{Ω ⊆ Θ} est(Ω) + p(Ω)`) as well
+/// as the earliest completion time of Θ when at most one task from Λ is added to it.
+///
+/// Tasks are identified by their index in the slice of [`TaskBounds`] which is provided upon
+/// construction; initially, both Θ and Λ are empty.
+///
+/// # Bibliography
+/// \[1\] P. Vilím, ‘Global constraints in scheduling’, PhD thesis, Charles University in Prague,
+/// 2007.
+#[derive(Debug)]
+pub(crate) struct ThetaLambdaTree {
+    nodes: Vec<Node>,
+    /// The index of the leaf of every task; the leaves are sorted by earliest starting time.
+    leaves: Vec<usize>,
+    bounds: Vec<TaskBounds>,
+}
+
+impl ThetaLambdaTree {
+    pub(crate) fn new(bounds: &[TaskBounds]) -> Self {
+        let number_of_leaves = bounds.len().max(1).next_power_of_two();
+
+        let mut tasks_by_est = (0..bounds.len()).collect::<Vec<_>>();
+        tasks_by_est.sort_by_key(|&task| bounds[task].est);
+
+        let mut leaves = vec![0; bounds.len()];
+        for (position, &task) in tasks_by_est.iter().enumerate() {
+            leaves[task] = number_of_leaves + position;
+        }
+
+        ThetaLambdaTree {
+            nodes: vec![Node::EMPTY; 2 * number_of_leaves],
+            leaves,
+            bounds: bounds.to_vec(),
+        }
+    }
+
+    /// Adds the task to Θ (removing it from Λ if it is contained in it).
+    pub(crate) fn add_to_theta(&mut self, task: usize) {
+        let bounds = self.bounds[task];
+        let earliest_completion_time = bounds.est as i128 + bounds.duration as i128;
+        self.update_leaf(
+            task,
+            Node {
+                sum_processing_time: bounds.duration as i128,
+                earliest_completion_time,
+                sum_processing_time_gray: bounds.duration as i128,
+                earliest_completion_time_gray: earliest_completion_time,
+                responsible_processing_time: None,
+                responsible_completion_time: None,
+            },
+        );
+    }
+
+    /// Adds the task to Λ (removing it from Θ if it is contained in it).
+    pub(crate) fn add_to_lambda(&mut self, task: usize) {
+        let bounds = self.bounds[task];
+        self.update_leaf(
+            task,
+            Node {
+                sum_processing_time: 0,
+                earliest_completion_time: EMPTY_COMPLETION_TIME,
+                sum_processing_time_gray: bounds.duration as i128,
+                earliest_completion_time_gray: bounds.est as i128 + bounds.duration as i128,
+                responsible_processing_time: Some(task),
+                responsible_completion_time: Some(task),
+            },
+        );
+    }
+
+    /// Removes the task from both Θ and Λ.
+    pub(crate) fn remove(&mut self, task: usize) {
+        self.update_leaf(task, Node::EMPTY);
+    }
+
+    /// Returns the earliest completion time of Θ, or [`None`] if Θ is empty.
+    pub(crate) fn earliest_completion_time(&self) -> Option<i64> {
+        Self::to_time(self.nodes[1].earliest_completion_time)
+    }
+
+    /// Returns the earliest completion time of Θ when at most one task from Λ is added to it
+    /// together with the task from Λ which is responsible for it (if any); returns [`None`] if
+    /// both Θ and Λ are empty.
+    pub(crate) fn earliest_completion_time_gray(&self) -> Option<(i64, Option<usize>)> {
+        Self::to_time(self.nodes[1].earliest_completion_time_gray)
+            .map(|time| (time, self.nodes[1].responsible_completion_time))
+    }
+
+    /// Converts the value of a node to a time-point; the values in the tree are stored as `i128`
+    /// to prevent overflows, and time-points which do not fit in an `i64` are saturated.
+    fn to_time(value: i128) -> Option<i64> {
+        (value > EMPTY_COMPLETION_TIME / 2).then(|| value.try_into().unwrap_or(i64::MAX))
+    }
+
+    fn update_leaf(&mut self, task: usize, node: Node) {
+        let mut index = self.leaves[task];
+        self.nodes[index] = node;
+
+        while index > 1 {
+            index /= 2;
+            self.nodes[index] = Node::combine(&self.nodes[2 * index], &self.nodes[2 * index + 1]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ThetaLambdaTree;
+    use crate::propagators::disjunctive::TaskBounds;
+
+    fn tasks() -> [TaskBounds; 4] {
+        [
+            TaskBounds {
+                est: 0,
+                lst: 15,
+                duration: 4,
+            },
+            TaskBounds {
+                est: 2,
+                lst: 13,
+                duration: 9,
+            },
+            TaskBounds {
+                est: 9,
+                lst: 23,
+                duration: 7,
+            },
+            TaskBounds {
+                est: 12,
+                lst: 14,
+                duration: 6,
+            },
+        ]
+    }
+
+    #[test]
+    fn earliest_completion_time_of_theta() {
+        let tasks = tasks();
+        let mut tree = ThetaLambdaTree::new(&tasks);
+        assert_eq!(tree.earliest_completion_time(), None);
+
+        tree.add_to_theta(1);
+        assert_eq!(tree.earliest_completion_time(), Some(11));
+        tree.add_to_theta(0);
+        assert_eq!(tree.earliest_completion_time(), Some(13));
+        tree.add_to_theta(3);
+        assert_eq!(tree.earliest_completion_time(), Some(19));
+
+        tree.remove(0);
+        assert_eq!(tree.earliest_completion_time(), Some(18));
+    }
+
+    #[test]
+    fn earliest_completion_time_with_gray_task() {
+        let tasks = tasks();
+        let mut tree = ThetaLambdaTree::new(&tasks);
+        tree.add_to_theta(0);
+        tree.add_to_theta(3);
+        tree.add_to_lambda(1);
+        tree.add_to_lambda(2);
+
+        assert_eq!(tree.earliest_completion_time(), Some(18));
+        // Adding task 1 to Θ leads to an earliest completion time of 0 + 4 + 9 + 6 = 19, while
+        // adding task 2 leads to an earliest completion time of 9 + 7 + 6 = 22
+        assert_eq!(tree.earliest_completion_time_gray(), Some((22, Some(2))));
+
+        tree.remove(2);
+        assert_eq!(tree.earliest_completion_time_gray(), Some((19, Some(1))));
+
+        tree.add_to_theta(1);
+        assert_eq!(tree.earliest_completion_time_gray(), Some((19, None)));
+    }
+}
@@ -1,166 +1,68 @@
 use std::cmp::max;
 use std::cmp::min;
-use std::collections::HashMap;
-use std::fmt::Debug;
-use std::rc::Rc;
 
-use super::TaskDisj;
+use super::TaskBounds;
 use super::UnionFind;
-use crate::{engine::Assignments, variables::IntegerVariable};
 
+/// The timeline data structure from \[1\] which allows computing the earliest completion time of
+/// a set of tasks Ω (i.e. `max_{Ω' ⊆ Ω} est(Ω') + p(Ω')`) while incrementally adding tasks to Ω.
+///
+/// Tasks are identified by their index in the slice of [`TaskBounds`] which is provided upon
+/// construction.
+///
+/// # Bibliography
+/// \[1\] H. Fahimi and C.-G. Quimper, ‘Linear-time filtering algorithms for the disjunctive
+/// constraint’, in Proceedings of the AAAI Conference on Artificial Intelligence, 2014.
+#[derive(Debug)]
 pub(crate) struct Timeline {
-    pub(crate) t: Vec<i32>,
-    pub(crate) c: Vec<i32>,
-    pub(crate) m: Vec<i32>,
-    pub(crate) e: i32,
-    pub(crate) s: UnionFind,
+    /// The distinct earliest starting times in increasing order, followed by a time-point after
+    /// which all tasks are guaranteed to be finished.
+    t: Vec<i64>,
+    /// The remaining capacity in the interval `[t[k], t[k + 1])`.
+    c: Vec<i64>,
+    /// Maps every task to the index of its earliest starting time in `t`.
+    m: Vec<i32>,
+    /// The index of the latest interval which has been (partially) filled, -1 if no task has been
+    /// scheduled yet.
+    e: i32,
+    /// Points every interval to the first interval at or after it which has capacity left.
+    s: UnionFind,
 }
 
 impl Timeline {
-    pub(crate) fn new<Var: IntegerVariable + 'static>(
-        tasks: Rc<[TaskDisj<Var>]>,
-        assignments: &Assignments,
-    ) -> Self {
-        let mut tasks_est = tasks.iter().cloned().collect::<Vec<TaskDisj<Var>>>();
-        tasks_est.sort_by(|a, b| TaskDisj::get_est(a, assignments).cmp(&TaskDisj::get_est(b, assignments)));
+    pub(crate) fn new(tasks: &[TaskBounds]) -> Self {
+        let mut tasks_est = (0..tasks.len()).collect::<Vec<_>>();
+        tasks_est.sort_by_key(|&task| tasks[task].est);
 
-        let mut t = vec![];
-        let mut c = vec![];
-        let mut m: Vec<i32> = vec![0; tasks_est.len()];
+        let mut t: Vec<i64> = vec![];
+        let mut m: Vec<i32> = vec![0; tasks.len()];
 
-        for task in tasks_est.iter() {
-            let est = TaskDisj::get_est(task, assignments);
-            if t.len() == 0 || t[t.len() - 1] != est {
+        for &task in tasks_est.iter() {
+            let est = tasks[task].est;
+            if t.last() != Some(&est) {
                 t.push(est);
             }
-            m[TaskDisj::get_id(&Rc::new(task.clone()))] = (t.len() - 1) as i32;
+            m[task] = (t.len() - 1) as i32;
         }
-        let highest_lct = tasks
-            .iter()
-            .map(|task| TaskDisj::get_lct(task, assignments))
-            .max()
-            .unwrap();
-        t.push(highest_lct + tasks.iter().map(|task| task.duration).sum::<i32>());
-        for k in 0..t.len() - 1 {
-            c.push(t[k + 1] - t[k]);
-        }
-        let n = t.len();
-        Timeline {
-            t: t,
-            c: c,
-            m: m,
-            e: -1,
-            s: UnionFind::new(n as i32),
-        }
-    }
-
-    pub(crate) fn schedule_task<Var: IntegerVariable + 'static>(
-        &mut self,
-        task: &Rc<TaskDisj<Var>>,
-    ) -> () {
-        let mut rho = task.duration;
-        let mut k = self.s.find(self.m[TaskDisj::get_id(task)]) as usize;
-
-        while rho > 0 {
-            let delta = min(self.c[k], rho);
-            rho -= delta;
-            self.c[k] -= delta;
-            if self.c[k] == 0 {
-                let _ = self.s.union(k as i32, (k + 1) as i32);
-                k = self.s.find(k as i32) as usize;
-            }
-        }
-        self.e = max(self.e, k as i32);
-    }
-
-    pub(crate) fn earliest_completion_time(&self) -> i32 {
-        if self.e == -1 {
-            return 0;
-        }
-        self.t[(self.e + 1) as usize] - self.c[self.e as usize]
-    }
-
-    fn print_uf(&mut self) {
-        let mut uf_map: HashMap<i32, Vec<i32>> = HashMap::new();
-        for i in 0..self.s.size() {
-            let root = self.s.find(i);
-            uf_map.entry(root).or_insert_with(Vec::new).push(i);
-        }
-        let mut vals = uf_map.values().cloned().collect::<Vec<Vec<i32>>>();
-        vals.iter_mut().for_each(|i| {
-            i.sort();
-        });
-        vals.sort();
-        println!("{:?}", vals);
-    }
-}
-
-impl Debug for Timeline {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Timeline")
-            .field("t", &self.t)
-            .field("c", &self.c)
-            .field("m", &self.m)
-            .field("e", &self.e)
-            .finish()
-    }
-}
-
-pub(crate) struct RevTimeline {
-    pub(crate) t: Vec<i32>,
-    pub(crate) c: Vec<i32>,
-    pub(crate) m: Vec<i32>,
-    pub(crate) e: i32,
-    pub(crate) s: UnionFind,
-}
 
-impl RevTimeline {
-    pub(crate) fn new<Var: IntegerVariable + 'static>(
-        tasks: Rc<[TaskDisj<Var>]>,
-        assignments: &Assignments,
-    ) -> Self {
-        //let mut tasks_est = tasks.iter().cloned().collect::<Vec<TaskDisj<Var>>>();
-        //tasks_est.sort_by(|a, b| TaskDisj::get_est(a, assignments).cmp(&TaskDisj::get_est(b, assignments)));
-        let mut tasks_lct = tasks.iter().cloned().collect::<Vec<TaskDisj<Var>>>();
-        tasks_lct.sort_by(|a, b| TaskDisj::get_lct(b, assignments).cmp(&TaskDisj::get_lct(a, assignments)));
-        let mut t = vec![];
-        let mut c = vec![];
-        let mut m: Vec<i32> = vec![0; tasks_lct.len()];
+        let highest_lct = tasks.iter().map(TaskBounds::lct).max().unwrap_or_default();
+        t.push(highest_lct + tasks.iter().map(|task| task.duration).sum::<i64>());
 
-        for task in tasks_lct.iter() {
-            //let est = TaskDisj::get_est(task, assignments);
-            let lct = TaskDisj::get_lct(task, assignments);
-            if t.len() == 0 || t[t.len() - 1] != lct {
-                t.push(lct);
-            }
-            m[TaskDisj::get_id(&Rc::new(task.clone()))] = (t.len() - 1) as i32;
-        }
-        /*let highest_lct = tasks
-            .iter()
-            .map(|task| TaskDisj::get_lct(task, assignments))
-            .max()
-            .unwrap();*/
-        let lowest_est = tasks.iter().map(|task| TaskDisj::get_est(task, assignments)).min().unwrap();
-        t.push(lowest_est - tasks.iter().map(|task| task.duration).sum::<i32>());
-        for k in 0..t.len() - 1 {
-            c.push(t[k] - t[k+1]);
-        }
+        let c = t.windows(2).map(|window| window[1] - window[0]).collect();
         let n = t.len();
-        RevTimeline {
-            t: t,
-            c: c,
-            m: m,
+        Timeline {
+            t,
+            c,
+            m,
             e: -1,
             s: UnionFind::new(n as i32),
         }
     }
 
-    pub(crate) fn schedule_task<Var: IntegerVariable + 'static>(
-        &mut self,
-        task: &Rc<TaskDisj<Var>>,
-    ) -> () {
-        let mut rho = task.duration;
-        let mut k = self.s.find(self.m[TaskDisj::get_id(task)]) as usize;
+    /// Adds the task with the provided index (and duration) to the set of scheduled tasks.
+    pub(crate) fn schedule_task(&mut self, task: usize, duration: i64) {
+        let mut rho = duration;
+        let mut k = self.s.find(self.m[task]) as usize;
 
         while rho > 0 {
             let delta = min(self.c[k], rho);
@@ -174,136 +76,82 @@ impl RevTimeline {
         self.e = max(self.e, k as i32);
     }
 
-    pub(crate) fn latest_starting_time(&self) -> i32 {
+    /// Returns the earliest completion time of the scheduled tasks, or [`None`] if no task has
+    /// been scheduled.
+    pub(crate) fn earliest_completion_time(&self) -> Option<i64> {
         if self.e == -1 {
-            return self.t[0];
-        }
-        self.t[(self.e + 1) as usize] + self.c[self.e as usize]
-    }
-
-    fn print_uf(&mut self) {
-        let mut uf_map: HashMap<i32, Vec<i32>> = HashMap::new();
-        for i in 0..self.s.size() {
-            let root = self.s.find(i);
-            uf_map.entry(root).or_insert_with(Vec::new).push(i);
+            return None;
         }
-        let mut vals = uf_map.values().cloned().collect::<Vec<Vec<i32>>>();
-        vals.iter_mut().for_each(|i| {
-            i.sort();
-        });
-        vals.sort();
-        println!("{:?}", vals);
-    }
-}
-
-impl Debug for RevTimeline {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Timeline")
-            .field("t", &self.t)
-            .field("c", &self.c)
-            .field("m", &self.m)
-            .field("e", &self.e)
-            .finish()
+        Some(self.t[(self.e + 1) as usize] - self.c[self.e as usize])
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use core::time;
-    use std::rc::Rc;
-
     use super::Timeline;
-    use crate::engine::propagation::LocalId;
-    use crate::engine::test_solver::TestSolver;
-    use crate::propagators::disjunctive::TaskDisj;
+    use crate::propagators::disjunctive::TaskBounds;
 
     #[test]
     fn test_constructor() {
-        let mut solver = TestSolver::default();
-        let x = solver.new_variable(4, 10);
-        let y = solver.new_variable(1, 4);
-        let z = solver.new_variable(5, 6);
         let tasks = [
-            TaskDisj {
-                starting_time: x,
+            TaskBounds {
+                est: 4,
+                lst: 10,
                 duration: 5,
-                deadline: 15,
-                local_id: LocalId::from(0),
             },
-            TaskDisj {
-                starting_time: y,
+            TaskBounds {
+                est: 1,
+                lst: 4,
                 duration: 6,
-                deadline: 10,
-                local_id: LocalId::from(1),
             },
-            TaskDisj {
-                starting_time: z,
-                deadline: 8,
+            TaskBounds {
+                est: 5,
+                lst: 6,
                 duration: 2,
-                local_id: LocalId::from(2),
             },
         ];
-        let mut timeline = Timeline::new(Rc::new(tasks.clone()), &solver.assignments);
-        println!("{:?}", timeline);
-        timeline.print_uf();
-        timeline.schedule_task(&Rc::new(tasks[0].clone()));
-        println!("{:?}", timeline);
-        timeline.print_uf();
-        assert!(timeline.earliest_completion_time() == 9);
-        timeline.schedule_task(&Rc::new(tasks[1].clone()));
-        assert!(timeline.earliest_completion_time() == 12);
-        timeline.schedule_task(&Rc::new(tasks[2].clone()));
-        assert!(timeline.earliest_completion_time() == 14);
+        let mut timeline = Timeline::new(&tasks);
+        assert_eq!(timeline.earliest_completion_time(), None);
+        timeline.schedule_task(0, tasks[0].duration);
+        assert_eq!(timeline.earliest_completion_time(), Some(9));
+        timeline.schedule_task(1, tasks[1].duration);
+        assert_eq!(timeline.earliest_completion_time(), Some(12));
+        timeline.schedule_task(2, tasks[2].duration);
+        assert_eq!(timeline.earliest_completion_time(), Some(14));
     }
 
     #[test]
     fn test_rev() {
-        let mut solver = TestSolver::default();
-        let w = solver.new_variable(0, 15);
-        let x = solver.new_variable(2, 13);
-        let y = solver.new_variable(9, 23);
-        let z = solver.new_variable(12, 14);
         let tasks = [
-            TaskDisj {
-                starting_time: w,
+            TaskBounds {
+                est: 0,
+                lst: 15,
                 duration: 4,
-                deadline: 19,
-                local_id: LocalId::from(0),
             },
-            TaskDisj {
-                starting_time: x,
+            TaskBounds {
+                est: 2,
+                lst: 13,
                 duration: 9,
-                deadline: 22,
-                local_id: LocalId::from(1),
             },
-            TaskDisj {
-                starting_time: y,
+            TaskBounds {
+                est: 9,
+                lst: 23,
                 duration: 7,
-                deadline: 30,
-                local_id: LocalId::from(2),
             },
-            TaskDisj {
-                starting_time: z,
-                deadline: 20,
+            TaskBounds {
+                est: 12,
+                lst: 14,
                 duration: 6,
-                local_id: LocalId::from(3),
             },
-        ];
-        let mut timeline = super::RevTimeline::new(Rc::new(tasks.clone()), &solver.assignments);
-        println!("{:?}", timeline);
-        timeline.print_uf();
-
-        timeline.schedule_task(&Rc::new(tasks[2].clone()));
-        println!("{:?}", timeline);
-        timeline.print_uf();
-        assert!(timeline.latest_starting_time() == 23);
-        timeline.schedule_task(&Rc::new(tasks[1].clone()));
-        println!("{:?}", timeline);
-        timeline.print_uf();
-        assert!(timeline.latest_starting_time() == 13);
-       // timeline.schedule_task(&Rc::new(tasks[0].clone()));
-        //println!("{:?}", timeline);
-        //timeline.print_uf();
-        //assert!(timeline.earliest_completion_time() == -1);
+        ]
+        .map(|task| task.mirrored());
+        let mut timeline = Timeline::new(&tasks);
+
+        // The earliest completion time of the mirrored tasks is the negated latest starting time
+        // of the original tasks
+        timeline.schedule_task(2, tasks[2].duration);
+        assert_eq!(timeline.earliest_completion_time(), Some(-23));
+        timeline.schedule_task(1, tasks[1].duration);
+        assert_eq!(timeline.earliest_completion_time(), Some(-13));
     }
 }
@@ -59,6 +59,10 @@ where
         "Element"
     }
 
+    fn inference_label(&self) -> &str {
+        "element"
+    }
+
     fn debug_propagate_from_scratch(
         &self,
         mut context: PropagationContextMut,
@@ -96,18 +100,10 @@ where
     fn lazy_explanation(&mut self, code: u64, context: ExplanationContext) -> &[Predicate] {
         let payload = RightHandSideReason::from_bits(code);
 
-        self.rhs_reason_buffer.clear();
-        self.rhs_reason_buffer
-            .extend(self.array.iter().enumerate().map(|(idx, variable)| {
-                if context.contains(&self.index, idx as i32) {
-                    match payload.bound() {
-                        Bound::Lower => predicate![variable >= payload.value()],
-                        Bound::Upper => predicate![variable <= payload.value()],
-                    }
-                } else {
-                    predicate![self.index != idx as i32]
-                }
-            }));
+        let mut rhs_reason_buffer = std::mem::take(&mut self.rhs_reason_buffer);
+        rhs_reason_buffer.clear();
+        rhs_reason_buffer.extend(self.rhs_reason(&context, payload.bound(), payload.value()));
+        self.rhs_reason_buffer = rhs_reason_buffer;
 
         &self.rhs_reason_buffer
     }
@@ -125,7 +121,7 @@ where
         context: &mut PropagationContextMut<'_>,
     ) -> PropagationStatusCP {
         context.set_lower_bound(&self.index, 0, conjunction!())?;
-        context.set_upper_bound(&self.index, self.array.len() as i32 - 1, conjunction!())?;
+        context.set_upper_bound(&self.index, self.array.len() as i64 - 1, conjunction!())?;
         Ok(())
     }
 
@@ -139,36 +135,62 @@ where
             .array
             .iter()
             .enumerate()
-            .filter(|(idx, _)| context.contains(&self.index, *idx as i32))
-            .fold((i32::MAX, i32::MIN), |(rhs_lb, rhs_ub), (_, element)| {
+            .filter(|(idx, _)| context.contains(&self.index, *idx as i64))
+            .fold((i64::MAX, i64::MIN), |(rhs_lb, rhs_ub), (_, element)| {
                 (
-                    i32::min(rhs_lb, context.lower_bound(element)),
-                    i32::max(rhs_ub, context.upper_bound(element)),
+                    i64::min(rhs_lb, context.lower_bound(element)),
+                    i64::max(rhs_ub, context.upper_bound(element)),
                 )
             });
 
-        context.set_lower_bound(
-            &self.rhs,
-            rhs_lb,
-            Reason::DynamicLazy(
-                RightHandSideReason::new()
-                    .with_bound(Bound::Lower)
-                    .with_value(rhs_lb)
-                    .into_bits(),
-            ),
-        )?;
-        context.set_upper_bound(
-            &self.rhs,
-            rhs_ub,
+        let lower_bound_reason = self.create_rhs_reason(context, Bound::Lower, rhs_lb);
+        context.set_lower_bound(&self.rhs, rhs_lb, lower_bound_reason)?;
+        let upper_bound_reason = self.create_rhs_reason(context, Bound::Upper, rhs_ub);
+        context.set_upper_bound(&self.rhs, rhs_ub, upper_bound_reason)?;
+
+        Ok(())
+    }
+
+    /// Creates the reason for setting the `bound` of the right-hand side to `value`; this reason
+    /// is lazy, unless `value` does not fit in the payload of a [`RightHandSideReason`].
+    fn create_rhs_reason(
+        &self,
+        context: &PropagationContextMut<'_>,
+        bound: Bound,
+        value: i64,
+    ) -> Reason {
+        if RightHandSideReason::can_store_value(value) {
             Reason::DynamicLazy(
                 RightHandSideReason::new()
-                    .with_bound(Bound::Upper)
-                    .with_value(rhs_ub)
+                    .with_bound(bound)
+                    .with_value(value)
                     .into_bits(),
-            ),
-        )?;
+            )
+        } else {
+            self.rhs_reason(context, bound, value)
+                .collect::<PropositionalConjunction>()
+                .into()
+        }
+    }
 
-        Ok(())
+    /// The predicates which explain setting the `bound` of the right-hand side to `value`: every
+    /// element which can still be selected by the index has the same bound.
+    fn rhs_reason<'a>(
+        &'a self,
+        context: &'a impl ReadDomains,
+        bound: Bound,
+        value: i64,
+    ) -> impl Iterator<Item = Predicate> + 'a {
+        self.array.iter().enumerate().map(move |(idx, variable)| {
+            if context.contains(&self.index, idx as i64) {
+                match bound {
+                    Bound::Lower => predicate![variable >= value],
+                    Bound::Upper => predicate![variable <= value],
+                }
+            } else {
+                predicate![self.index != idx as i64]
+            }
+        })
     }
 
     /// Go through the array. For every element for which the domain does not intersect with the
@@ -209,7 +231,7 @@ where
     fn propagate_equality(
         &self,
         context: &mut PropagationContextMut<'_>,
-        index: i32,
+        index: i64,
     ) -> PropagationStatusCP {
         let rhs_lb = context.lower_bound(&self.rhs);
         let rhs_ub = context.upper_bound(&self.rhs);
@@ -251,9 +273,17 @@ impl Bound {
 
 #[bitfield(u64)]
 struct RightHandSideReason {
-    #[bits(32, from = Bound::from_bits)]
+    #[bits(1, from = Bound::from_bits)]
     bound: Bound,
-    value: i32,
+    #[bits(63)]
+    value: i64,
+}
+
+impl RightHandSideReason {
+    /// Whether `value` fits in the 63 bits which are available for it in the payload.
+    fn can_store_value(value: i64) -> bool {
+        (i64::MIN >> 1..=i64::MAX >> 1).contains(&value)
+    }
 }
 
 #[cfg(test)]
@@ -396,4 +426,38 @@ mod tests {
             conjunction!([x_0 <= 15] & [x_2 <= 15] & [x_3 <= 15] & [index != 1])
         );
     }
+
+    #[test]
+    fn bounds_of_rhs_which_do_not_fit_in_a_lazy_reason_are_explained() {
+        let mut solver = TestSolver::default();
+
+        let x_0 = solver.new_variable(i64::MAX - 30, i64::MAX - 25);
+        let x_1 = solver.new_variable(0, 10);
+        let x_2 = solver.new_variable(i64::MAX - 20, i64::MAX - 10);
+
+        let index = solver.new_variable(0, 2);
+        solver.remove(index, 1).expect("Value can be removed");
+
+        let rhs = solver.new_variable(0, i64::MAX);
+
+        let _ = solver
+            .new_propagator(ElementPropagator::new(
+                vec![x_0, x_1, x_2].into(),
+                index,
+                rhs,
+            ))
+            .expect("no empty domains");
+
+        solver.assert_bounds(rhs, i64::MAX - 30, i64::MAX - 10);
+
+        assert_eq!(
+            solver.get_reason_int(predicate![rhs >= i64::MAX - 30]),
+            conjunction!([x_0 >= i64::MAX - 30] & [x_2 >= i64::MAX - 30] & [index != 1])
+        );
+
+        assert_eq!(
+            solver.get_reason_int(predicate![rhs <= i64::MAX - 10]),
+            conjunction!([x_0 <= i64::MAX - 10] & [x_2 <= i64::MAX - 10] & [index != 1])
+        );
+    }
 }
@@ -2,15 +2,27 @@
 //!
 //! See the [`crate::engine::cp::propagation`] for info on propagators.
 
+mod all_different;
 pub(crate) mod arithmetic;
 mod cumulative;
 mod disjunctive;
 pub(crate) mod element;
 pub(crate) mod nogoods;
+mod regular;
 mod reified_propagator;
+mod table;
+pub use all_different::AllDifferentConsistency;
+pub(crate) use all_different::*;
 pub(crate) use arithmetic::*;
 pub use cumulative::CumulativeExplanationType;
 pub use cumulative::CumulativeOptions;
 pub use cumulative::CumulativePropagationMethod;
 pub(crate) use cumulative::*;
+pub use disjunctive::DisjunctiveExplanationType;
+pub use disjunctive::DisjunctiveOptionsError;
+pub use disjunctive::DisjunctivePropagatorOptions;
+pub(crate) use disjunctive::*;
+pub use regular::DeterministicFiniteAutomaton;
+pub(crate) use regular::*;
 pub(crate) use reified_propagator::*;
+pub(crate) use table::*;
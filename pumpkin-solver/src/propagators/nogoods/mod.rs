@@ -1,11 +1,15 @@
 mod learning_options;
 mod nogood;
+mod nogood_exchange;
 mod nogood_id;
 mod nogood_propagator;
 mod nogood_watching;
 
 pub use learning_options::*;
 pub(crate) use nogood::*;
+pub use nogood_exchange::NogoodExchange;
+pub(crate) use nogood_exchange::NogoodExchangeConnection;
+pub use nogood_exchange::NogoodSharingOptions;
 pub(crate) use nogood_id::*;
 pub(crate) use nogood_propagator::*;
 pub(crate) use nogood_watching::*;
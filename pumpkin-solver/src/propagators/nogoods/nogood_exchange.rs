@@ -0,0 +1,275 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::engine::predicates::predicate::Predicate;
+#[cfg(doc)]
+use crate::Solver;
+
+/// Options which determine which learned nogoods are exported to a [`NogoodExchange`].
+#[derive(Debug, Clone, Copy)]
+pub struct NogoodSharingOptions {
+    /// Only learned nogoods with a literal-block distance (LBD) of at most this value are
+    /// exported.
+    pub max_lbd: u32,
+    /// Only learned nogoods consisting of at most this number of predicates are exported.
+    pub max_length: usize,
+}
+
+impl Default for NogoodSharingOptions {
+    fn default() -> Self {
+        Self {
+            max_lbd: 2,
+            max_length: 8,
+        }
+    }
+}
+
+/// Allows solvers which solve the same problem in different threads to exchange nogoods (see
+/// [`Solver::share_nogoods`]).
+///
+/// Every connected solver exports its short learned nogoods with a low LBD (see
+/// [`NogoodSharingOptions`]), together with the clauses which are added to it after it has been
+/// connected (e.g. the clauses which strengthen the bound on the objective during optimisation).
+/// The nogoods of the other solvers are imported at the root, i.e. when solving starts and upon
+/// every restart.
+///
+/// Nogoods are exchanged in terms of [`DomainId`](crate::variables::DomainId)s, which means that
+/// the connected solvers should have created exactly the same variables at the moment that they
+/// are connected; this is the case if every solver is constructed from the same model. Nogoods
+/// over variables which are created after connecting are not exported. Once a propagator is
+/// added to a solver after it has been connected, or a clause over such variables, that solver
+/// stops exporting altogether, since its nogoods could depend on constraints which are not known
+/// to the other solvers.
+///
+/// Note that imported nogoods are not justified in the proof, so the exchange should not be used
+/// in combination with proof logging.
+#[derive(Debug, Clone, Default)]
+pub struct NogoodExchange {
+    shared: Arc<Mutex<SharedNogoods>>,
+    options: NogoodSharingOptions,
+}
+
+impl NogoodExchange {
+    pub fn new(options: NogoodSharingOptions) -> Self {
+        Self {
+            shared: Arc::default(),
+            options,
+        }
+    }
+
+    /// Connects a solver which has created `num_shared_domains` domains to the exchange.
+    pub(crate) fn connect(&self, num_shared_domains: u32) -> NogoodExchangeConnection {
+        let mut shared = self.lock();
+        let id = shared.num_imported.len();
+        let num_removed = shared.num_removed;
+        shared.num_imported.push(num_removed);
+
+        NogoodExchangeConnection {
+            exchange: self.clone(),
+            id,
+            num_shared_domains,
+            is_exporting: true,
+            exported: vec![],
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, SharedNogoods> {
+        self.shared
+            .lock()
+            .expect("a solver panicked while exchanging nogoods")
+    }
+}
+
+#[derive(Debug, Default)]
+struct SharedNogoods {
+    /// The nogoods which have not yet been imported by every connected solver.
+    nogoods: VecDeque<SharedNogood>,
+    /// The number of nogoods which have been removed from the front of `nogoods` since they have
+    /// been imported by every connected solver.
+    num_removed: usize,
+    /// For every connected solver, the number of nogoods which it has imported (including the
+    /// nogoods which it has exported itself).
+    num_imported: Vec<usize>,
+}
+
+#[derive(Debug)]
+struct SharedNogood {
+    /// The id of the connection through which the nogood was exported.
+    source: usize,
+    predicates: Box<[Predicate]>,
+}
+
+/// The connection of a single solver to a [`NogoodExchange`].
+///
+/// Exported nogoods are buffered until the next exchange, to avoid locking the exchange upon
+/// every conflict.
+#[derive(Debug)]
+pub(crate) struct NogoodExchangeConnection {
+    exchange: NogoodExchange,
+    id: usize,
+    /// Only nogoods over the domains with an id lower than this value are shared.
+    num_shared_domains: u32,
+    is_exporting: bool,
+    /// The nogoods which are exported during the next exchange.
+    exported: Vec<Box<[Predicate]>>,
+}
+
+impl NogoodExchangeConnection {
+    /// Exports the provided learned nogood if it is short enough and its LBD is low enough.
+    pub(crate) fn export_learned_nogood(&mut self, nogood: &[Predicate], lbd: u32) {
+        let options = self.exchange.options;
+        if lbd <= options.max_lbd && nogood.len() <= options.max_length && self.is_shared(nogood) {
+            self.export(nogood);
+        }
+    }
+
+    /// Exports a nogood which has been added to the solver at the root; if it cannot be shared,
+    /// then the solver stops exporting.
+    pub(crate) fn export_root_nogood(&mut self, nogood: &[Predicate]) {
+        if self.is_shared(nogood) {
+            self.export(nogood);
+        } else {
+            self.stop_exporting();
+        }
+    }
+
+    /// Prevents any further nogoods from being exported; this is required once the solver
+    /// contains constraints which are not known to the other connected solvers.
+    pub(crate) fn stop_exporting(&mut self) {
+        self.is_exporting = false;
+    }
+
+    /// Returns the number of nogoods which are waiting to be exported.
+    pub(crate) fn num_pending_exports(&self) -> usize {
+        self.exported.len()
+    }
+
+    /// Publishes the exported nogoods and returns the nogoods which have been exported by the
+    /// other solvers since the previous exchange.
+    pub(crate) fn exchange(&mut self) -> Vec<Box<[Predicate]>> {
+        let mut guard = self.exchange.lock();
+        let shared = &mut *guard;
+
+        let first_unseen = shared.num_imported[self.id] - shared.num_removed;
+        let imported = shared
+            .nogoods
+            .iter()
+            .skip(first_unseen)
+            .filter(|nogood| nogood.source != self.id)
+            .map(|nogood| nogood.predicates.clone())
+            .collect();
+
+        shared
+            .nogoods
+            .extend(self.exported.drain(..).map(|predicates| SharedNogood {
+                source: self.id,
+                predicates,
+            }));
+        shared.num_imported[self.id] = shared.num_removed + shared.nogoods.len();
+
+        let num_imported_by_all = shared
+            .num_imported
+            .iter()
+            .copied()
+            .min()
+            .expect("this solver is connected");
+        while shared.num_removed < num_imported_by_all {
+            let _ = shared.nogoods.pop_front();
+            shared.num_removed += 1;
+        }
+
+        imported
+    }
+
+    fn export(&mut self, nogood: &[Predicate]) {
+        if self.is_exporting {
+            self.exported.push(nogood.into());
+        }
+    }
+
+    /// Returns whether all of the predicates in the nogood are over domains which are known to
+    /// every connected solver.
+    fn is_shared(&self, nogood: &[Predicate]) -> bool {
+        nogood
+            .iter()
+            .all(|predicate| predicate.get_domain().id < self.num_shared_domains)
+    }
+}
+
+impl Drop for NogoodExchangeConnection {
+    fn drop(&mut self) {
+        // A disconnected solver does not import any nogoods anymore, so the shared nogoods do not
+        // need to be kept around for it
+        if let Ok(mut shared) = self.exchange.shared.lock() {
+            shared.num_imported[self.id] = usize::MAX;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::predicate;
+    use crate::variables::DomainId;
+
+    #[test]
+    fn nogoods_are_imported_by_other_solvers() {
+        let exchange = NogoodExchange::default();
+        let mut first = exchange.connect(3);
+        let mut second = exchange.connect(3);
+
+        let x = DomainId::new(1);
+        let nogood = [predicate!(x >= 5)];
+        first.export_root_nogood(&nogood);
+
+        assert!(first.exchange().is_empty());
+        assert_eq!(second.exchange(), vec![Box::from(nogood)]);
+        assert!(second.exchange().is_empty());
+    }
+
+    #[test]
+    fn disconnected_solvers_do_not_retain_nogoods() {
+        let exchange = NogoodExchange::default();
+        let mut first = exchange.connect(2);
+        let second = exchange.connect(2);
+        drop(second);
+
+        let x = DomainId::new(1);
+        first.export_root_nogood(&[predicate!(x >= 1)]);
+        let _ = first.exchange();
+
+        assert!(exchange.lock().nogoods.is_empty());
+    }
+
+    #[test]
+    fn nogoods_over_unshared_domains_stop_exporting() {
+        let exchange = NogoodExchange::default();
+        let mut first = exchange.connect(2);
+        let mut second = exchange.connect(2);
+
+        let x = DomainId::new(1);
+        let y = DomainId::new(2);
+        first.export_root_nogood(&[predicate!(y >= 1)]);
+        first.export_root_nogood(&[predicate!(x >= 1)]);
+
+        let _ = first.exchange();
+        assert!(second.exchange().is_empty());
+    }
+
+    #[test]
+    fn learned_nogoods_with_high_lbd_are_not_exported() {
+        let exchange = NogoodExchange::new(NogoodSharingOptions {
+            max_lbd: 2,
+            max_length: 8,
+        });
+        let mut first = exchange.connect(2);
+
+        let x = DomainId::new(1);
+        first.export_learned_nogood(&[predicate!(x >= 1), predicate!(x <= 5)], 3);
+        assert_eq!(first.num_pending_exports(), 0);
+
+        first.export_learned_nogood(&[predicate!(x >= 1), predicate!(x <= 5)], 2);
+        assert_eq!(first.num_pending_exports(), 1);
+    }
+}
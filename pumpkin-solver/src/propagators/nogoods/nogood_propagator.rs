@@ -57,6 +57,12 @@ pub(crate) struct NogoodPropagator {
     learned_nogood_ids: LearnedNogoodIds,
     /// Ids which have been deleted and can now be re-used
     delete_ids: Vec<NogoodId>,
+    /// Whether the deleted nogoods are kept in `deleted_nogoods`, which is only needed when
+    /// deletions are logged to the proof.
+    is_recording_deletions: bool,
+    /// The nogoods which have been deleted since the last call to
+    /// [`NogoodPropagator::drain_deleted_nogoods`], so their deletion can be logged to the proof.
+    deleted_nogoods: Vec<PropositionalConjunction>,
     /// The trail index is used to determine the domains of the variables since last time.
     last_index_on_trail: usize,
     /// Watch lists for the nogood propagator.
@@ -82,9 +88,10 @@ struct LearnedNogoodIds {
 }
 
 impl NogoodPropagator {
-    pub(crate) fn with_options(parameters: LearningOptions) -> Self {
+    pub(crate) fn with_options(parameters: LearningOptions, is_recording_deletions: bool) -> Self {
         Self {
             parameters,
+            is_recording_deletions,
             ..Default::default()
         }
     }
@@ -131,6 +138,10 @@ impl Propagator for NogoodPropagator {
         "NogoodPropagator"
     }
 
+    fn inference_label(&self) -> &str {
+        "nogood"
+    }
+
     fn priority(&self) -> u32 {
         0
     }
@@ -506,7 +517,7 @@ impl Propagator for NogoodPropagator {
                             // should not be moved from this list, but instead only its right hand
                             // side should be changed to reflect the new watcher. The variable
                             // 'kept_watcher_new_rhs' holds info about this new rhs if appropriate.
-                            let mut kept_watcher_new_rhs: Option<i32> = None;
+                            let mut kept_watcher_new_rhs: Option<i64> = None;
                             // Start from index 2 since we are skipping watched predicates.
                             for i in 2..nogood.len() {
                                 // Find a predicate that is either false or unassigned,
@@ -995,12 +1006,19 @@ impl NogoodPropagator {
         // Then we pre-process the nogood such that (among others) it does not contain duplicates
         Self::preprocess_nogood(&mut nogood, context);
 
+        // A nogood which contains a falsified predicate can never be violated, so it does not have
+        // to be stored.
+        if nogood[0] == Predicate::trivially_false() {
+            return Ok(());
+        }
+
         // Unit nogoods are added as root assignments rather than as nogoods.
         if nogood.len() == 1 {
-            // Get the reason for the propagation. Note that preprocessing removes literals from
-            // `nogood` that are still present in `input_nogood`, so this does not necessarily
-            // result in an empty reason.
-            input_nogood.retain(|&p| p != nogood[0]);
+            // Get the reason for the propagation, which are the predicates that preprocessing
+            // removed because they are satisfied at the root. The remaining predicate may have
+            // been rewritten by the semantic minimiser, so it cannot be removed by comparing it
+            // to the predicates in `input_nogood`.
+            input_nogood.retain(|&p| context.is_predicate_satisfied(p));
 
             // Post the negated predicate at the root to respect the nogood.
             context.post_predicate(!nogood[0], PropositionalConjunction::from(input_nogood))?;
@@ -1192,6 +1210,10 @@ impl NogoodPropagator {
             // simpler, since other nogood ids remain unchanged.
             self.nogoods[id].is_deleted = true;
             self.delete_ids.push(id);
+            if self.is_recording_deletions {
+                self.deleted_nogoods
+                    .push(self.nogoods[id].predicates.clone());
+            }
 
             num_clauses_to_remove -= 1;
         }
@@ -1234,6 +1256,13 @@ impl NogoodPropagator {
             });
     }
 
+    /// Returns the nogoods which have been deleted since the last call to this method.
+    pub(crate) fn drain_deleted_nogoods(
+        &mut self,
+    ) -> impl Iterator<Item = PropositionalConjunction> + '_ {
+        self.deleted_nogoods.drain(..)
+    }
+
     /// Decays the activity bump increment by
     /// [`LearningOptions::self.parameters.activity_decay_factor`].
     pub(crate) fn decay_nogood_activities(&mut self) {
@@ -1539,4 +1568,39 @@ mod tests {
         let result = solver.propagate_until_fixed_point(propagator);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn unit_nogood_after_preprocessing_is_explained_by_root_predicates() {
+        let mut solver = TestSolver::default();
+        let a = solver.new_variable(1, 3);
+        let b = solver.new_variable(1, 3);
+
+        let propagator = solver
+            .new_propagator(NogoodPropagator::default())
+            .expect("no empty domains");
+
+        let _ = solver.increase_lower_bound_and_notify(propagator, a.id, a, 2);
+
+        // The semantic minimiser rewrites [b == 1] to [b <= 1], which should not end up in the
+        // reason for the propagation.
+        let nogood = conjunction!([a >= 2] & [b == 1]);
+        {
+            let mut context = PropagationContextMut::new(
+                &mut solver.trailed_values,
+                &mut solver.assignments,
+                &mut solver.reason_store,
+                &mut solver.semantic_minimiser,
+                propagator,
+            );
+
+            downcast_to_nogood_propagator(propagator, &mut solver.propagator_store)
+                .add_nogood(nogood.into(), &mut context)
+                .expect("");
+        }
+
+        assert_eq!(solver.lower_bound(b), 2);
+
+        let reason = solver.get_reason_int(predicate!(b >= 2));
+        assert_eq!(conjunction!([a >= 2]), reason);
+    }
 }
@@ -14,7 +14,7 @@ pub(crate) struct NogoodWatchList {
 }
 
 impl NogoodWatchList {
-    fn find_and_remove_watcher(watch_list: &mut Vec<NogoodWatcher>, id: NogoodId, value: i32) {
+    fn find_and_remove_watcher(watch_list: &mut Vec<NogoodWatcher>, id: NogoodId, value: i64) {
         let position = watch_list
             .iter()
             .position(|w| w.right_hand_side == value && w.nogood_id == id)
@@ -28,7 +28,7 @@ impl NogoodWatchList {
         self.lower_bound.iter()
     }
 
-    pub(crate) fn remove_lower_bound_watcher(&mut self, nogood_id: NogoodId, value: i32) {
+    pub(crate) fn remove_lower_bound_watcher(&mut self, nogood_id: NogoodId, value: i64) {
         NogoodWatchList::find_and_remove_watcher(&mut self.lower_bound, nogood_id, value);
     }
 
@@ -48,7 +48,7 @@ impl NogoodWatchList {
         &self.lower_bound[index]
     }
 
-    pub(crate) fn add_lower_bound_watcher(&mut self, nogood_id: NogoodId, right_hand_side: i32) {
+    pub(crate) fn add_lower_bound_watcher(&mut self, nogood_id: NogoodId, right_hand_side: i64) {
         self.lower_bound.push(NogoodWatcher {
             right_hand_side,
             nogood_id,
@@ -65,7 +65,7 @@ impl NogoodWatchList {
         self.upper_bound.iter()
     }
 
-    pub(crate) fn remove_upper_bound_watcher(&mut self, nogood_id: NogoodId, value: i32) {
+    pub(crate) fn remove_upper_bound_watcher(&mut self, nogood_id: NogoodId, value: i64) {
         NogoodWatchList::find_and_remove_watcher(&mut self.upper_bound, nogood_id, value);
     }
 
@@ -85,7 +85,7 @@ impl NogoodWatchList {
         &self.upper_bound[index]
     }
 
-    pub(crate) fn add_upper_bound_watcher(&mut self, nogood_id: NogoodId, right_hand_side: i32) {
+    pub(crate) fn add_upper_bound_watcher(&mut self, nogood_id: NogoodId, right_hand_side: i64) {
         self.upper_bound.push(NogoodWatcher {
             right_hand_side,
             nogood_id,
@@ -102,7 +102,7 @@ impl NogoodWatchList {
         self.hole.iter()
     }
 
-    pub(crate) fn remove_inequality_watcher(&mut self, nogood_id: NogoodId, value: i32) {
+    pub(crate) fn remove_inequality_watcher(&mut self, nogood_id: NogoodId, value: i64) {
         NogoodWatchList::find_and_remove_watcher(&mut self.hole, nogood_id, value);
     }
 
@@ -122,7 +122,7 @@ impl NogoodWatchList {
         &self.hole[index]
     }
 
-    pub(crate) fn add_inequality_watcher(&mut self, nogood_id: NogoodId, right_hand_side: i32) {
+    pub(crate) fn add_inequality_watcher(&mut self, nogood_id: NogoodId, right_hand_side: i64) {
         self.hole.push(NogoodWatcher {
             right_hand_side,
             nogood_id,
@@ -136,7 +136,7 @@ impl NogoodWatchList {
     pub(crate) fn set_right_hand_side_of_inequality_watcher_at_index(
         &mut self,
         index: usize,
-        new_rhs: i32,
+        new_rhs: i64,
     ) {
         self.hole[index].right_hand_side = new_rhs;
     }
@@ -147,7 +147,7 @@ impl NogoodWatchList {
         self.equals.iter()
     }
 
-    pub(crate) fn remove_equality_watcher(&mut self, nogood_id: NogoodId, value: i32) {
+    pub(crate) fn remove_equality_watcher(&mut self, nogood_id: NogoodId, value: i64) {
         NogoodWatchList::find_and_remove_watcher(&mut self.equals, nogood_id, value);
     }
 
@@ -167,7 +167,7 @@ impl NogoodWatchList {
         &self.equals[index]
     }
 
-    pub(crate) fn add_equality_watcher(&mut self, nogood_id: NogoodId, right_hand_side: i32) {
+    pub(crate) fn add_equality_watcher(&mut self, nogood_id: NogoodId, right_hand_side: i64) {
         self.equals.push(NogoodWatcher {
             right_hand_side,
             nogood_id,
@@ -185,6 +185,6 @@ pub(crate) struct NogoodWatcher {
     /// This field represents the right-hand side of the predicate present in the nogood.
     ///
     /// It is used as an indicator to whether the nogood should be inspected.
-    pub(crate) right_hand_side: i32,
+    pub(crate) right_hand_side: i64,
     pub(crate) nogood_id: NogoodId,
 }
@@ -0,0 +1,138 @@
+use crate::basic_types::HashMap;
+
+/// A deterministic finite automaton which is used to define the
+/// [Regular](https://sofdem.github.io/gccat/gccat/Cautomaton.html) constraint (see
+/// [`crate::constraints::regular`]).
+///
+/// The states of the automaton are numbered `0, ..., num_states - 1`; a transition `(from, value,
+/// to)` indicates that the automaton moves from state `from` to state `to` when reading `value`.
+/// If there is no transition for a state and a value, then the automaton rejects the sequence.
+///
+/// # Example
+/// ```rust
+/// # use pumpkin_solver::constraints::DeterministicFiniteAutomaton;
+/// // Accepts the sequences over {0, 1} which do not contain two consecutive 1s
+/// let automaton =
+///     DeterministicFiniteAutomaton::new(2, 0, vec![0, 1], vec![(0, 0, 0), (0, 1, 1), (1, 0, 0)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DeterministicFiniteAutomaton {
+    initial_state: usize,
+    is_accepting: Box<[bool]>,
+    /// For every state, the transitions which leave it.
+    outgoing: Box<[Vec<Transition>]>,
+    /// For every state, the transitions which enter it.
+    incoming: Box<[Vec<Transition>]>,
+    /// For every value, the transitions which read it.
+    transitions_with_value: HashMap<i64, Vec<Transition>>,
+    num_transitions: usize,
+}
+
+/// A transition of a [`DeterministicFiniteAutomaton`], where `index` is the position of the
+/// transition in the transitions which were provided to [`DeterministicFiniteAutomaton::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Transition {
+    pub(crate) from: usize,
+    pub(crate) value: i64,
+    pub(crate) to: usize,
+    pub(crate) index: usize,
+}
+
+impl DeterministicFiniteAutomaton {
+    /// Creates a new [`DeterministicFiniteAutomaton`] with `num_states` states which starts in
+    /// `initial_state` and accepts a sequence if it ends in one of the `accepting_states`.
+    ///
+    /// Every transition is given as `(from, value, to)`; this method panics if a state is out of
+    /// range or if there are multiple transitions for the same state and value.
+    pub fn new(
+        num_states: usize,
+        initial_state: usize,
+        accepting_states: Vec<usize>,
+        transitions: Vec<(usize, i64, usize)>,
+    ) -> Self {
+        assert!(
+            initial_state < num_states,
+            "The initial state {initial_state} is not one of the {num_states} states"
+        );
+
+        let mut is_accepting = vec![false; num_states];
+        for state in accepting_states {
+            assert!(
+                state < num_states,
+                "The accepting state {state} is not one of the {num_states} states"
+            );
+            is_accepting[state] = true;
+        }
+
+        let mut outgoing = vec![Vec::new(); num_states];
+        let mut incoming = vec![Vec::new(); num_states];
+        let mut transitions_with_value: HashMap<i64, Vec<Transition>> = HashMap::default();
+        let num_transitions = transitions.len();
+
+        for (index, (from, value, to)) in transitions.into_iter().enumerate() {
+            assert!(
+                from < num_states && to < num_states,
+                "The transition ({from}, {value}, {to}) is not between two of the {num_states} states"
+            );
+            assert!(
+                outgoing[from]
+                    .iter()
+                    .all(|transition: &Transition| transition.value != value),
+                "There are multiple transitions from state {from} with value {value}"
+            );
+
+            let transition = Transition {
+                from,
+                value,
+                to,
+                index,
+            };
+            outgoing[from].push(transition);
+            incoming[to].push(transition);
+            transitions_with_value
+                .entry(value)
+                .or_default()
+                .push(transition);
+        }
+
+        DeterministicFiniteAutomaton {
+            initial_state,
+            is_accepting: is_accepting.into(),
+            outgoing: outgoing.into(),
+            incoming: incoming.into(),
+            transitions_with_value,
+            num_transitions,
+        }
+    }
+
+    pub(crate) fn num_states(&self) -> usize {
+        self.is_accepting.len()
+    }
+
+    pub(crate) fn num_transitions(&self) -> usize {
+        self.num_transitions
+    }
+
+    pub(crate) fn initial_state(&self) -> usize {
+        self.initial_state
+    }
+
+    pub(crate) fn is_accepting(&self, state: usize) -> bool {
+        self.is_accepting[state]
+    }
+
+    pub(crate) fn outgoing(&self, state: usize) -> &[Transition] {
+        &self.outgoing[state]
+    }
+
+    pub(crate) fn incoming(&self, state: usize) -> &[Transition] {
+        &self.incoming[state]
+    }
+
+    pub(crate) fn transitions_with_value(&self, value: i64) -> &[Transition] {
+        self.transitions_with_value
+            .get(&value)
+            .map(|transitions| transitions.as_slice())
+            .unwrap_or_default()
+    }
+}
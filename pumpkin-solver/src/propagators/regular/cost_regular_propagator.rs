@@ -0,0 +1,505 @@
+use super::automaton::Transition;
+use super::layered_graph::ExcludedValues;
+use super::layered_graph::LayeredGraph;
+use super::regular_propagator::filter_regular;
+use super::DeterministicFiniteAutomaton;
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// Propagator for the cost-regular constraint, which enforces the
+/// [Regular](https://sofdem.github.io/gccat/gccat/Cautomaton.html) constraint and bounds the cost
+/// of the transitions which are taken (see [`crate::propagators::regular`] for more information).
+#[derive(Clone, Debug)]
+pub(crate) struct CostRegularPropagator<Var, CostVar> {
+    variables: Box<[Var]>,
+    automaton: DeterministicFiniteAutomaton,
+    /// The cost of every transition of the automaton, in the order in which the transitions were
+    /// provided to [`DeterministicFiniteAutomaton::new`].
+    transition_costs: Box<[i64]>,
+    cost: CostVar,
+    /// The costs of the paths when every value is in the domain of every variable, which are used
+    /// to explain the inferences (see [`CostRegularPropagator::explain_dead_transitions`]).
+    relaxed_path_costs: PathCosts,
+}
+
+impl<Var, CostVar> CostRegularPropagator<Var, CostVar>
+where
+    Var: IntegerVariable + 'static,
+    CostVar: IntegerVariable + 'static,
+{
+    pub(crate) fn new(
+        variables: Box<[Var]>,
+        automaton: DeterministicFiniteAutomaton,
+        transition_costs: Vec<i64>,
+        cost: CostVar,
+    ) -> Self {
+        assert_eq!(
+            transition_costs.len(),
+            automaton.num_transitions(),
+            "Expected a cost for every transition of the automaton"
+        );
+
+        let relaxed_path_costs = PathCosts::relaxed(variables.len(), &automaton, &transition_costs);
+
+        CostRegularPropagator {
+            variables,
+            automaton,
+            transition_costs: transition_costs.into(),
+            cost,
+            relaxed_path_costs,
+        }
+    }
+
+    /// Creates the explanation for why no path from the initial state to an accepting state has a
+    /// cost which contradicts an inference, where `contradicts(min, max)` returns whether a path
+    /// with a cost in `[min, max]` could do so.
+    ///
+    /// Such a path has to use a dead transition whose value is not in the domain of its variable
+    /// (since the paths over the current domains all have a valid cost); the explanation thus
+    /// consists of the excluded values of the dead transitions which lie on a path over the
+    /// relaxed layered graph which could contradict the inference. The variable at `skipped_layer`
+    /// is not part of the explanation.
+    fn explain_dead_transitions(
+        &self,
+        context: &impl ReadDomains,
+        skipped_layer: Option<usize>,
+        contradicts: impl Fn(i64, i64) -> bool,
+    ) -> PropositionalConjunction {
+        let mut explanation = ExcludedValues::new(self.variables.len());
+
+        for (layer, variable) in self.variables.iter().enumerate() {
+            if skipped_layer == Some(layer) {
+                continue;
+            }
+            for state in 0..self.automaton.num_states() {
+                for transition in self.automaton.outgoing(state) {
+                    if context.contains(variable, transition.value) {
+                        continue;
+                    }
+                    let is_relevant = self
+                        .relaxed_path_costs
+                        .through(layer, transition, &self.transition_costs)
+                        .is_some_and(|(min, max)| contradicts(min, max));
+                    if is_relevant {
+                        explanation.add(layer, transition.value);
+                    }
+                }
+            }
+        }
+
+        explanation.into_conjunction(context, &self.variables)
+    }
+}
+
+impl<Var, CostVar> Propagator for CostRegularPropagator<Var, CostVar>
+where
+    Var: IntegerVariable + 'static,
+    CostVar: IntegerVariable + 'static,
+{
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.variables
+            .iter()
+            .cloned()
+            .enumerate()
+            .for_each(|(index, variable)| {
+                let _ =
+                    context.register(variable, DomainEvents::ANY_INT, LocalId::from(index as u32));
+            });
+        let _ = context.register(
+            self.cost.clone(),
+            DomainEvents::BOUNDS,
+            LocalId::from(self.variables.len() as u32),
+        );
+
+        Ok(())
+    }
+
+    fn priority(&self) -> u32 {
+        3
+    }
+
+    fn name(&self) -> &str {
+        "CostRegular"
+    }
+
+    fn inference_label(&self) -> &str {
+        "cost_regular"
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        let graph = filter_regular(&mut context, &self.variables, &self.automaton)?;
+        let path_costs = PathCosts::new(
+            &context,
+            &self.variables,
+            &self.automaton,
+            &self.transition_costs,
+            &graph,
+        );
+
+        let initial_state = self.automaton.initial_state();
+        let min_cost = path_costs.backward_min[0][initial_state];
+        let max_cost = path_costs.backward_max[0][initial_state];
+        if min_cost > context.lower_bound(&self.cost) {
+            let reason = self.explain_dead_transitions(&context, None, |min, _| min < min_cost);
+            context.set_lower_bound(&self.cost, min_cost, reason)?;
+        }
+        if max_cost < context.upper_bound(&self.cost) {
+            let reason = self.explain_dead_transitions(&context, None, |_, max| max > max_cost);
+            context.set_upper_bound(&self.cost, max_cost, reason)?;
+        }
+
+        let lower_bound = context.lower_bound(&self.cost);
+        let upper_bound = context.upper_bound(&self.cost);
+        for (layer, variable) in self.variables.iter().enumerate() {
+            let unsupported_values = context
+                .iterate_domain(variable)
+                .filter_map(|value| {
+                    let (min_cost, max_cost) = self
+                        .automaton
+                        .transitions_with_value(value)
+                        .iter()
+                        .filter(|transition| graph.is_transition_alive(layer, transition))
+                        .filter_map(|transition| {
+                            path_costs.through(layer, transition, &self.transition_costs)
+                        })
+                        .fold((i64::MAX, i64::MIN), |(min, max), (lower, upper)| {
+                            (min.min(lower), max.max(upper))
+                        });
+
+                    if min_cost > upper_bound {
+                        Some((value, true))
+                    } else if max_cost < lower_bound {
+                        Some((value, false))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            for (value, exceeds_upper_bound) in unsupported_values {
+                // The paths through the value only depend on the domains of the other variables
+                let reason = if exceeds_upper_bound {
+                    let mut reason =
+                        self.explain_dead_transitions(&context, Some(layer), |min, _| {
+                            min <= upper_bound
+                        });
+                    reason.add(predicate!(self.cost <= upper_bound));
+                    reason
+                } else {
+                    let mut reason =
+                        self.explain_dead_transitions(&context, Some(layer), |_, max| {
+                            max >= lower_bound
+                        });
+                    reason.add(predicate!(self.cost >= lower_bound));
+                    reason
+                };
+                context.remove(variable, value, reason)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The minimum and maximum cost of the paths in a [`LayeredGraph`] from the initial state to every
+/// state (forward) and from every state to an accepting state (backward).
+///
+/// The costs are only valid for the states which lie on a path from the initial state to an
+/// accepting state.
+#[derive(Clone, Debug)]
+struct PathCosts {
+    forward_min: Vec<Box<[i64]>>,
+    forward_max: Vec<Box<[i64]>>,
+    backward_min: Vec<Box<[i64]>>,
+    backward_max: Vec<Box<[i64]>>,
+}
+
+impl PathCosts {
+    /// Computes the costs of the paths in the provided [`LayeredGraph`] over the current domains.
+    fn new<Var: IntegerVariable>(
+        context: &impl ReadDomains,
+        variables: &[Var],
+        automaton: &DeterministicFiniteAutomaton,
+        transition_costs: &[i64],
+        graph: &LayeredGraph,
+    ) -> Self {
+        PathCosts::compute(
+            variables.len(),
+            automaton,
+            transition_costs,
+            |layer, state| graph.is_alive(layer, state),
+            |layer, transition| {
+                graph.is_transition_alive(layer, transition)
+                    && context.contains(&variables[layer], transition.value)
+            },
+        )
+    }
+
+    /// Computes the costs of the paths in the layered graph in which every value is in the domain
+    /// of every variable; these costs thus do not depend on the current domains.
+    fn relaxed(
+        num_variables: usize,
+        automaton: &DeterministicFiniteAutomaton,
+        transition_costs: &[i64],
+    ) -> Self {
+        PathCosts::compute(
+            num_variables,
+            automaton,
+            transition_costs,
+            |layer, state| layer < num_variables || automaton.is_accepting(state),
+            |_, _| true,
+        )
+    }
+
+    fn compute(
+        num_variables: usize,
+        automaton: &DeterministicFiniteAutomaton,
+        transition_costs: &[i64],
+        is_alive: impl Fn(usize, usize) -> bool,
+        is_alive_transition: impl Fn(usize, &Transition) -> bool,
+    ) -> Self {
+        let num_states = automaton.num_states();
+        let num_layers = num_variables + 1;
+
+        let mut forward_min = vec![vec![i64::MAX; num_states].into_boxed_slice(); num_layers];
+        let mut forward_max = vec![vec![i64::MIN; num_states].into_boxed_slice(); num_layers];
+        forward_min[0][automaton.initial_state()] = 0;
+        forward_max[0][automaton.initial_state()] = 0;
+
+        let mut backward_min = vec![vec![i64::MAX; num_states].into_boxed_slice(); num_layers];
+        let mut backward_max = vec![vec![i64::MIN; num_states].into_boxed_slice(); num_layers];
+        for state in 0..num_states {
+            if is_alive(num_variables, state) {
+                backward_min[num_variables][state] = 0;
+                backward_max[num_variables][state] = 0;
+            }
+        }
+
+        for layer in 0..num_variables {
+            for state in (0..num_states).filter(|&state| is_alive(layer, state)) {
+                if forward_min[layer][state] == i64::MAX {
+                    // The state cannot be reached from the initial state
+                    continue;
+                }
+                for transition in automaton
+                    .outgoing(state)
+                    .iter()
+                    .filter(|transition| is_alive_transition(layer, transition))
+                {
+                    let cost = transition_costs[transition.index];
+                    forward_min[layer + 1][transition.to] =
+                        forward_min[layer + 1][transition.to].min(forward_min[layer][state] + cost);
+                    forward_max[layer + 1][transition.to] =
+                        forward_max[layer + 1][transition.to].max(forward_max[layer][state] + cost);
+                }
+            }
+        }
+
+        for layer in (0..num_variables).rev() {
+            for state in (0..num_states).filter(|&state| is_alive(layer, state)) {
+                for transition in automaton
+                    .outgoing(state)
+                    .iter()
+                    .filter(|transition| is_alive_transition(layer, transition))
+                {
+                    if backward_min[layer + 1][transition.to] == i64::MAX {
+                        // No accepting state can be reached from the state which it leads to
+                        continue;
+                    }
+                    let cost = transition_costs[transition.index];
+                    backward_min[layer][state] = backward_min[layer][state]
+                        .min(cost + backward_min[layer + 1][transition.to]);
+                    backward_max[layer][state] = backward_max[layer][state]
+                        .max(cost + backward_max[layer + 1][transition.to]);
+                }
+            }
+        }
+
+        PathCosts {
+            forward_min,
+            forward_max,
+            backward_min,
+            backward_max,
+        }
+    }
+
+    /// Returns the minimum and maximum cost of the paths which use the transition from the
+    /// provided layer, or [`None`] if there is no such path.
+    fn through(
+        &self,
+        layer: usize,
+        transition: &Transition,
+        transition_costs: &[i64],
+    ) -> Option<(i64, i64)> {
+        if self.forward_min[layer][transition.from] == i64::MAX
+            || self.backward_min[layer + 1][transition.to] == i64::MAX
+        {
+            return None;
+        }
+
+        let cost = transition_costs[transition.index];
+        Some((
+            self.forward_min[layer][transition.from]
+                + cost
+                + self.backward_min[layer + 1][transition.to],
+            self.forward_max[layer][transition.from]
+                + cost
+                + self.backward_max[layer + 1][transition.to],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::Inconsistency;
+    use crate::conjunction;
+    use crate::engine::test_solver::TestSolver;
+
+    /// Accepts all sequences over {0, 1}, where reading a 1 after a 1 costs 3 and every other
+    /// transition costs 1.
+    fn consecutive_ones_are_expensive() -> (DeterministicFiniteAutomaton, Vec<i64>) {
+        (
+            DeterministicFiniteAutomaton::new(
+                2,
+                0,
+                vec![0, 1],
+                vec![(0, 0, 0), (0, 1, 1), (1, 0, 0), (1, 1, 1)],
+            ),
+            vec![1, 1, 1, 3],
+        )
+    }
+
+    #[test]
+    fn cost_is_bounded_by_paths() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 1);
+        let y = solver.new_variable(0, 1);
+        let cost = solver.new_variable(0, 10);
+
+        let (automaton, costs) = consecutive_ones_are_expensive();
+        let _ = solver
+            .new_propagator(CostRegularPropagator::new(
+                [x, y].into(),
+                automaton,
+                costs,
+                cost,
+            ))
+            .expect("No conflict");
+
+        solver.assert_bounds(cost, 2, 4);
+    }
+
+    #[test]
+    fn expensive_values_are_removed() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 1);
+        let y = solver.new_variable(0, 1);
+        let cost = solver.new_variable(0, 3);
+
+        let (automaton, costs) = consecutive_ones_are_expensive();
+        let _ = solver
+            .new_propagator(CostRegularPropagator::new(
+                [x, y].into(),
+                automaton,
+                costs,
+                cost,
+            ))
+            .expect("No conflict");
+
+        solver.assert_bounds(y, 0, 0);
+        let reason = solver.get_reason_int(predicate!(y != 1));
+        assert_eq!(conjunction!([x >= 1] & [cost <= 3]), reason);
+    }
+
+    #[test]
+    fn cost_is_explained_by_dead_transitions() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 1);
+        let y = solver.new_variable(0, 1);
+        let cost = solver.new_variable(0, 10);
+
+        let (automaton, costs) = consecutive_ones_are_expensive();
+        let propagator = solver
+            .new_propagator(CostRegularPropagator::new(
+                [x, y].into(),
+                automaton,
+                costs,
+                cost,
+            ))
+            .expect("No conflict");
+
+        solver.remove(y, 1).expect("Non-empty domain");
+        let result = solver.propagate_until_fixed_point(propagator);
+        assert!(result.is_ok());
+
+        // Only reading a 1 after a 1 could lead to a more expensive path, so the domain of `x` is
+        // not part of the explanation
+        solver.assert_bounds(cost, 2, 2);
+        let reason = solver.get_reason_int(predicate!(cost <= 2));
+        assert_eq!(conjunction!([y <= 0]), reason);
+    }
+
+    #[test]
+    fn explanations_do_not_overflow_at_the_domain_limit() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(i64::MIN, i64::MIN + 1);
+        let cost = solver.new_variable(0, 10);
+
+        // Reading the limit itself is free and reading the other value costs 1
+        let automaton = DeterministicFiniteAutomaton::new(
+            1,
+            0,
+            vec![0],
+            vec![(0, i64::MIN, 0), (0, i64::MIN + 1, 0)],
+        );
+        let propagator = solver
+            .new_propagator(CostRegularPropagator::new(
+                [x].into(),
+                automaton,
+                vec![0, 1],
+                cost,
+            ))
+            .expect("No conflict");
+
+        solver.remove(x, i64::MIN).expect("Non-empty domain");
+        let result = solver.propagate_until_fixed_point(propagator);
+        assert!(result.is_ok());
+
+        solver.assert_bounds(cost, 1, 1);
+        let reason = solver.get_reason_int(predicate!(cost >= 1));
+        assert_eq!(conjunction!([x >= i64::MIN + 1]), reason);
+    }
+
+    #[test]
+    fn conflict_when_no_path_has_valid_cost() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 1);
+        let y = solver.new_variable(1, 1);
+        let cost = solver.new_variable(0, 3);
+
+        let (automaton, costs) = consecutive_ones_are_expensive();
+        let result = solver.new_propagator(CostRegularPropagator::new(
+            [x, y].into(),
+            automaton,
+            costs,
+            cost,
+        ));
+
+        assert!(matches!(result, Err(Inconsistency::EmptyDomain)));
+    }
+}
@@ -0,0 +1,226 @@
+use super::automaton::Transition;
+use super::DeterministicFiniteAutomaton;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+use crate::pumpkin_assert_simple;
+
+/// The layered graph of a [`DeterministicFiniteAutomaton`] over a sequence of variables, where
+/// layer `i` contains the states in which the automaton can be before reading the variable at
+/// index `i` (see [`crate::propagators::regular`]).
+#[derive(Clone, Debug)]
+pub(super) struct LayeredGraph {
+    /// Whether the state in a layer can be reached from the initial state in the first layer.
+    reachable: Vec<Box<[bool]>>,
+    /// Whether an accepting state in the last layer can be reached from the state in a layer.
+    co_reachable: Vec<Box<[bool]>>,
+}
+
+impl LayeredGraph {
+    /// Creates the [`LayeredGraph`] based on the current domains of the `variables`.
+    pub(super) fn new<Var: IntegerVariable>(
+        context: &impl ReadDomains,
+        variables: &[Var],
+        automaton: &DeterministicFiniteAutomaton,
+    ) -> Self {
+        let num_states = automaton.num_states();
+
+        let mut reachable = vec![vec![false; num_states].into_boxed_slice(); variables.len() + 1];
+        reachable[0][automaton.initial_state()] = true;
+        for (layer, variable) in variables.iter().enumerate() {
+            for state in 0..num_states {
+                if !reachable[layer][state] {
+                    continue;
+                }
+                for transition in automaton.outgoing(state) {
+                    if context.contains(variable, transition.value) {
+                        reachable[layer + 1][transition.to] = true;
+                    }
+                }
+            }
+        }
+
+        let mut co_reachable =
+            vec![vec![false; num_states].into_boxed_slice(); variables.len() + 1];
+        for (state, is_co_reachable) in co_reachable[variables.len()].iter_mut().enumerate() {
+            *is_co_reachable = automaton.is_accepting(state);
+        }
+        for (layer, variable) in variables.iter().enumerate().rev() {
+            for state in 0..num_states {
+                co_reachable[layer][state] = automaton.outgoing(state).iter().any(|transition| {
+                    co_reachable[layer + 1][transition.to]
+                        && context.contains(variable, transition.value)
+                });
+            }
+        }
+
+        LayeredGraph {
+            reachable,
+            co_reachable,
+        }
+    }
+
+    /// Returns whether there is an accepted sequence of values in the current domains.
+    pub(super) fn has_accepted_sequence(&self, automaton: &DeterministicFiniteAutomaton) -> bool {
+        self.co_reachable[0][automaton.initial_state()]
+    }
+
+    /// Returns whether the state in the layer lies on a path from the initial state in the first
+    /// layer to an accepting state in the last layer.
+    pub(super) fn is_alive(&self, layer: usize, state: usize) -> bool {
+        self.reachable[layer][state] && self.co_reachable[layer][state]
+    }
+
+    /// Returns whether the transition from the provided layer lies on a path from the initial
+    /// state in the first layer to an accepting state in the last layer (assuming that its value is
+    /// in the domain of the corresponding variable).
+    pub(super) fn is_transition_alive(&self, layer: usize, transition: &Transition) -> bool {
+        self.reachable[layer][transition.from] && self.co_reachable[layer + 1][transition.to]
+    }
+}
+
+/// Creates explanations for why transitions (or states) of a [`LayeredGraph`] do not lie on a path
+/// from the initial state to an accepting state.
+///
+/// A state which cannot be reached from the initial state is explained by showing that, for every
+/// incoming transition, either the value is not in the domain of the corresponding variable or
+/// the state which it comes from cannot be reached; the reasoning for states from which no
+/// accepting state can be reached is symmetric.
+pub(super) struct LayeredGraphExplainer<'a, Var> {
+    variables: &'a [Var],
+    automaton: &'a DeterministicFiniteAutomaton,
+    graph: &'a LayeredGraph,
+    /// The states in every layer which should be explained to be unreachable.
+    unreachable: Vec<Box<[bool]>>,
+    /// The states in every layer which should be explained to not reach an accepting state.
+    not_co_reachable: Vec<Box<[bool]>>,
+}
+
+impl<'a, Var: IntegerVariable> LayeredGraphExplainer<'a, Var> {
+    pub(super) fn new(
+        variables: &'a [Var],
+        automaton: &'a DeterministicFiniteAutomaton,
+        graph: &'a LayeredGraph,
+    ) -> Self {
+        let states = vec![false; automaton.num_states()].into_boxed_slice();
+        LayeredGraphExplainer {
+            variables,
+            automaton,
+            graph,
+            unreachable: vec![states.clone(); variables.len() + 1],
+            not_co_reachable: vec![states; variables.len() + 1],
+        }
+    }
+
+    /// Adds the transition from the provided layer which should be explained to not lie on a
+    /// path from the initial state to an accepting state.
+    pub(super) fn add_dead_transition(&mut self, layer: usize, transition: &Transition) {
+        if !self.graph.reachable[layer][transition.from] {
+            self.unreachable[layer][transition.from] = true;
+        } else {
+            pumpkin_assert_simple!(!self.graph.co_reachable[layer + 1][transition.to]);
+            self.not_co_reachable[layer + 1][transition.to] = true;
+        }
+    }
+
+    /// Adds the state in the provided layer which should be explained to not reach an accepting
+    /// state.
+    pub(super) fn add_not_co_reachable(&mut self, layer: usize, state: usize) {
+        pumpkin_assert_simple!(!self.graph.co_reachable[layer][state]);
+        self.not_co_reachable[layer][state] = true;
+    }
+
+    /// Creates the explanation for all of the added transitions and states.
+    pub(super) fn explain(mut self, context: &impl ReadDomains) -> PropositionalConjunction {
+        let mut explanation = ExcludedValues::new(self.variables.len());
+
+        for layer in (1..self.unreachable.len()).rev() {
+            let variable = &self.variables[layer - 1];
+            for state in 0..self.automaton.num_states() {
+                if !self.unreachable[layer][state] {
+                    continue;
+                }
+                for transition in self.automaton.incoming(state) {
+                    if self.unreachable[layer - 1][transition.from] {
+                        continue;
+                    }
+                    if !context.contains(variable, transition.value) {
+                        explanation.add(layer - 1, transition.value);
+                    } else {
+                        pumpkin_assert_simple!(!self.graph.reachable[layer - 1][transition.from]);
+                        self.unreachable[layer - 1][transition.from] = true;
+                    }
+                }
+            }
+        }
+
+        for layer in 0..self.variables.len() {
+            let variable = &self.variables[layer];
+            for state in 0..self.automaton.num_states() {
+                if !self.not_co_reachable[layer][state] {
+                    continue;
+                }
+                for transition in self.automaton.outgoing(state) {
+                    if self.not_co_reachable[layer + 1][transition.to] {
+                        continue;
+                    }
+                    if !context.contains(variable, transition.value) {
+                        explanation.add(layer, transition.value);
+                    } else {
+                        pumpkin_assert_simple!(!self.graph.co_reachable[layer + 1][transition.to]);
+                        self.not_co_reachable[layer + 1][transition.to] = true;
+                    }
+                }
+            }
+        }
+
+        explanation.into_conjunction(context, self.variables)
+    }
+}
+
+/// The values which are not in the domains of the variables; the bounds of the variables are used
+/// to explain the values outside of the bounds.
+pub(super) struct ExcludedValues {
+    excluded: Vec<Vec<i64>>,
+}
+
+impl ExcludedValues {
+    pub(super) fn new(num_variables: usize) -> Self {
+        ExcludedValues {
+            excluded: vec![vec![]; num_variables],
+        }
+    }
+
+    /// Adds the value which is not in the domain of the variable at the provided index.
+    pub(super) fn add(&mut self, index: usize, value: i64) {
+        if !self.excluded[index].contains(&value) {
+            self.excluded[index].push(value);
+        }
+    }
+
+    pub(super) fn into_conjunction<Var: IntegerVariable>(
+        self,
+        context: &impl ReadDomains,
+        variables: &[Var],
+    ) -> PropositionalConjunction {
+        let mut conjunction = PropositionalConjunction::default();
+        for (variable, excluded) in variables.iter().zip(self.excluded) {
+            let lower_bound = context.lower_bound(variable);
+            let upper_bound = context.upper_bound(variable);
+
+            if excluded.iter().any(|&value| value < lower_bound) {
+                conjunction.add(predicate!(variable >= lower_bound));
+            }
+            if excluded.iter().any(|&value| value > upper_bound) {
+                conjunction.add(predicate!(variable <= upper_bound));
+            }
+            for value in excluded {
+                if lower_bound <= value && value <= upper_bound {
+                    conjunction.add(predicate!(variable != value));
+                }
+            }
+        }
+        conjunction
+    }
+}
@@ -0,0 +1,46 @@
+//! Contains the propagators for the [Regular](https://sofdem.github.io/gccat/gccat/Cautomaton.html)
+//! constraint and its cost-regular counterpart.
+//!
+//! # Theoretical
+//!
+//! A regular constraint over the variables `x_1, ..., x_n` is defined by a deterministic finite
+//! automaton; it states that the sequence of values assigned to the variables should be accepted
+//! by the automaton. The cost-regular constraint additionally associates a cost with every
+//! transition of the automaton and states that a variable `c` is equal to the sum of the costs of
+//! the transitions which are taken.
+//!
+//! Both propagators are based on the layered graph of \[1\]; the graph contains a layer for every
+//! variable (and one final layer), where every layer contains a node for every state of the
+//! automaton. For every value `v` in the domain of `x_i`, there is an edge from state `q` in layer
+//! `i` to state `q'` in layer `i + 1` if the automaton has a transition from `q` to `q'` when
+//! reading `v`. A sequence of values is accepted if and only if it corresponds to a path from the
+//! initial state in the first layer to an accepting state in the last layer.
+//!
+//! - For the [`RegularPropagator`], a value `v` is removed from the domain of `x_i` if there is no
+//!   such path which uses an edge for `v` in layer `i`, which enforces domain consistency. The
+//!   explanation for this removal states why every edge for `v` is not on such a path; for a state
+//!   which cannot be reached from the initial state, this is explained by the edges which enter it
+//!   (and symmetrically for states from which no accepting state can be reached).
+//! - For the [`CostRegularPropagator`], the bounds of `c` are set to the minimum and maximum cost
+//!   of the paths, and a value `v` is removed from the domain of `x_i` if all of the paths which
+//!   use an edge for `v` in layer `i` have a cost outside of the bounds of `c` \[2\]. A path with
+//!   a cost outside of these bounds has to use an edge whose value has been removed; the
+//!   explanation consists of the removed values of the edges which lie on such a path in the layered
+//!   graph in which every value of the automaton is in the domains.
+//!
+//! # Bibliography
+//!
+//! \[1\] G. Pesant, ‘A regular language membership constraint for finite sequences of variables’,
+//! in Principles and Practice of Constraint Programming: 10th International Conference, CP 2004,
+//! 2004, pp. 482–495.
+//!
+//! \[2\] S. Demassey, G. Pesant, and L.-M. Rousseau, ‘A cost-regular based hybrid column
+//! generation approach’, Constraints, vol. 11, no. 4, pp. 315–333, 2006.
+mod automaton;
+mod cost_regular_propagator;
+mod layered_graph;
+mod regular_propagator;
+
+pub use automaton::DeterministicFiniteAutomaton;
+pub(crate) use cost_regular_propagator::*;
+pub(crate) use regular_propagator::*;
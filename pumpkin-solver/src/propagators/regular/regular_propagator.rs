@@ -0,0 +1,175 @@
+use super::layered_graph::LayeredGraph;
+use super::layered_graph::LayeredGraphExplainer;
+use super::DeterministicFiniteAutomaton;
+use crate::basic_types::Inconsistency;
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+
+/// Propagator for the [Regular](https://sofdem.github.io/gccat/gccat/Cautomaton.html) constraint,
+/// which enforces domain consistency using the layered graph of the automaton (see
+/// [`crate::propagators::regular`] for more information).
+#[derive(Clone, Debug)]
+pub(crate) struct RegularPropagator<Var> {
+    variables: Box<[Var]>,
+    automaton: DeterministicFiniteAutomaton,
+}
+
+impl<Var: IntegerVariable + 'static> RegularPropagator<Var> {
+    pub(crate) fn new(variables: Box<[Var]>, automaton: DeterministicFiniteAutomaton) -> Self {
+        RegularPropagator {
+            variables,
+            automaton,
+        }
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for RegularPropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.variables
+            .iter()
+            .cloned()
+            .enumerate()
+            .for_each(|(index, variable)| {
+                let _ =
+                    context.register(variable, DomainEvents::ANY_INT, LocalId::from(index as u32));
+            });
+
+        Ok(())
+    }
+
+    fn priority(&self) -> u32 {
+        3
+    }
+
+    fn name(&self) -> &str {
+        "Regular"
+    }
+
+    fn inference_label(&self) -> &str {
+        "regular"
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        filter_regular(&mut context, &self.variables, &self.automaton).map(|_| ())
+    }
+}
+
+/// Removes the values which do not lie on a path from the initial state to an accepting state in
+/// the layered graph, or reports a conflict if there is no such path.
+///
+/// Returns the layered graph which was used for the filtering.
+pub(super) fn filter_regular<Var: IntegerVariable + 'static>(
+    context: &mut PropagationContextMut,
+    variables: &[Var],
+    automaton: &DeterministicFiniteAutomaton,
+) -> Result<LayeredGraph, Inconsistency> {
+    let graph = LayeredGraph::new(context, variables, automaton);
+
+    if !graph.has_accepted_sequence(automaton) {
+        let mut explainer = LayeredGraphExplainer::new(variables, automaton, &graph);
+        explainer.add_not_co_reachable(0, automaton.initial_state());
+        return Err(explainer.explain(context).into());
+    }
+
+    for (layer, variable) in variables.iter().enumerate() {
+        let unsupported_values = context
+            .iterate_domain(variable)
+            .filter(|&value| {
+                automaton
+                    .transitions_with_value(value)
+                    .iter()
+                    .all(|transition| !graph.is_transition_alive(layer, transition))
+            })
+            .collect::<Vec<_>>();
+
+        for value in unsupported_values {
+            let mut explainer = LayeredGraphExplainer::new(variables, automaton, &graph);
+            for transition in automaton.transitions_with_value(value) {
+                explainer.add_dead_transition(layer, transition);
+            }
+            let reason = explainer.explain(context);
+            context.remove(variable, value, reason)?;
+        }
+    }
+
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conjunction;
+    use crate::engine::test_solver::TestSolver;
+    use crate::predicate;
+
+    /// Accepts the sequences over {0, 1} which do not contain two consecutive 1s.
+    fn no_consecutive_ones() -> DeterministicFiniteAutomaton {
+        DeterministicFiniteAutomaton::new(2, 0, vec![0, 1], vec![(0, 0, 0), (0, 1, 1), (1, 0, 0)])
+    }
+
+    #[test]
+    fn unsupported_values_are_removed() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 2);
+        let y = solver.new_variable(1, 1);
+        let z = solver.new_variable(0, 1);
+
+        let _ = solver
+            .new_propagator(RegularPropagator::new(
+                [x, y, z].into(),
+                no_consecutive_ones(),
+            ))
+            .expect("No conflict");
+
+        solver.assert_bounds(x, 0, 0);
+        solver.assert_bounds(z, 0, 0);
+    }
+
+    #[test]
+    fn removal_is_explained_by_layered_graph() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 1);
+        let y = solver.new_variable(0, 1);
+
+        let propagator = solver
+            .new_propagator(RegularPropagator::new([x, y].into(), no_consecutive_ones()))
+            .expect("No conflict");
+
+        solver.remove(x, 0).expect("Non-empty domain");
+        let result = solver.propagate_until_fixed_point(propagator);
+        assert!(result.is_ok());
+
+        solver.assert_bounds(y, 0, 0);
+        let reason = solver.get_reason_int(predicate!(y != 1));
+        assert_eq!(conjunction!([x >= 1]), reason);
+    }
+
+    #[test]
+    fn conflict_is_explained_by_layered_graph() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 1);
+        let y = solver.new_variable(1, 2);
+
+        let result =
+            solver.new_propagator(RegularPropagator::new([x, y].into(), no_consecutive_ones()));
+
+        assert!(matches!(
+            result,
+            Err(Inconsistency::Conflict(explanation))
+                if explanation == conjunction!([x >= 1] & [y >= 1])
+        ));
+    }
+}
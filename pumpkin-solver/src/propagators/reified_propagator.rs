@@ -139,6 +139,10 @@ impl<WrappedPropagator: Propagator> Propagator for ReifiedPropagator<WrappedProp
         &self.name
     }
 
+    fn inference_label(&self) -> &str {
+        self.propagator.inference_label()
+    }
+
     fn debug_propagate_from_scratch(
         &self,
         mut context: PropagationContextMut,
@@ -0,0 +1,38 @@
+//! Contains the propagators for the (positive) [Table](https://sofdem.github.io/gccat/gccat/Cin_relation.html)
+//! constraint and its negative counterpart.
+//!
+//! # Theoretical
+//!
+//! A table constraint over the variables `x_1, ..., x_n` is defined by a set of tuples `T`; the
+//! positive table constraint states that the assignment to the variables should be one of the
+//! tuples in `T` while the negative table constraint states that the assignment should not be one
+//! of the tuples in `T`.
+//!
+//! Both propagators are based on compact-table \[1\]; they maintain the set of tuples which are
+//! still *valid* (i.e. all of the values in the tuple are contained in the domains of the
+//! corresponding variables) as a bitset which is stored using trailed values. For every variable
+//! `x_i` and value `v`, a bitset containing the tuples `t` for which `t[i] = v` is created upon
+//! construction, which allows the set of valid tuples to be efficiently updated.
+//!
+//! - For the [`TablePropagator`], a value `v` is removed from the domain of `x_i` if there is no
+//!   valid tuple `t` such that `t[i] = v`. The explanation for this removal states for every tuple
+//!   `t` with `t[i] = v` why it is not valid (i.e. which of its values is not contained in the
+//!   domain of one of the other variables).
+//! - For the [`NegativeTablePropagator`], a value `v` is removed from the domain of `x_i` if all of
+//!   the possible assignments to the other variables (combined with `x_i = v`) are contained in the
+//!   table \[2\]. This is explained by the current domains of the other variables.
+//!
+//! # Bibliography
+//!
+//! \[1\] J. Demeulenaere et al., ‘Compact-table: Efficiently filtering table constraints with
+//! reversible sparse bit-sets’, in Principles and Practice of Constraint Programming: 22nd
+//! International Conference, CP 2016, 2016, pp. 207–223.
+//!
+//! \[2\] H. Verhaeghe, C. Lecoutre, and P. Schaus, ‘Extending compact-table to negative and short
+//! tables’, in Proceedings of the AAAI Conference on Artificial Intelligence, 2017.
+mod negative_table;
+mod positive_table;
+mod supports;
+
+pub(crate) use negative_table::*;
+pub(crate) use positive_table::*;
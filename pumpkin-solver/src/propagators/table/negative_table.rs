@@ -0,0 +1,252 @@
+use super::supports::intersection_size;
+use super::supports::num_tuples_in;
+use super::supports::TableSupports;
+use super::supports::ValidTuples;
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::opaque_domain_event::OpaqueDomainEvent;
+use crate::engine::propagation::contexts::PropagationContextWithTrailedValues;
+use crate::engine::propagation::EnqueueDecision;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::predicate;
+
+/// Propagator for the negative [Table](https://sofdem.github.io/gccat/gccat/Cnot_in_relation.html)
+/// constraint, which enforces domain consistency using compact-table (see
+/// [`crate::propagators::table`] for more information).
+#[derive(Clone, Debug)]
+pub(crate) struct NegativeTablePropagator<Var> {
+    variables: Box<[Var]>,
+    supports: TableSupports,
+    valid_tuples: ValidTuples,
+}
+
+impl<Var: IntegerVariable + 'static> NegativeTablePropagator<Var> {
+    pub(crate) fn new(variables: Box<[Var]>, tuples: Vec<Vec<i64>>) -> Self {
+        let supports = TableSupports::new(variables.len(), tuples);
+        let valid_tuples = ValidTuples::new(variables.len());
+        NegativeTablePropagator {
+            variables,
+            supports,
+            valid_tuples,
+        }
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for NegativeTablePropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.variables
+            .iter()
+            .cloned()
+            .enumerate()
+            .for_each(|(index, variable)| {
+                let _ =
+                    context.register(variable, DomainEvents::ANY_INT, LocalId::from(index as u32));
+            });
+
+        self.valid_tuples.initialise(context, &self.supports);
+
+        Ok(())
+    }
+
+    fn notify(
+        &mut self,
+        _context: PropagationContextWithTrailedValues,
+        local_id: LocalId,
+        _event: OpaqueDomainEvent,
+    ) -> EnqueueDecision {
+        self.valid_tuples.mark_changed(local_id.unpack() as usize);
+        EnqueueDecision::Enqueue
+    }
+
+    fn priority(&self) -> u32 {
+        3
+    }
+
+    fn name(&self) -> &str {
+        "NegativeTable"
+    }
+
+    fn inference_label(&self) -> &str {
+        "negative_table"
+    }
+
+    fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        let valid_tuples = self
+            .valid_tuples
+            .update(&mut context, &self.variables, &self.supports);
+        filter_negative_table(&mut context, &self.variables, &self.supports, &valid_tuples)
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        let valid_tuples = self
+            .supports
+            .compute_valid_tuples(&context, &self.variables);
+        filter_negative_table(&mut context, &self.variables, &self.supports, &valid_tuples)
+    }
+}
+
+/// Removes a value `v` from the domain of `x_i` if every assignment to the other variables
+/// (together with `x_i = v`) corresponds to a valid tuple, or reports a conflict if every
+/// assignment to the variables corresponds to a valid tuple.
+///
+/// Note that this relies on the tuples being unique.
+fn filter_negative_table<Var: IntegerVariable + 'static>(
+    context: &mut PropagationContextMut,
+    variables: &[Var],
+    supports: &TableSupports,
+    valid_tuples: &[u64],
+) -> PropagationStatusCP {
+    let num_tuples = supports.num_tuples() as u64;
+    // The domain sizes are capped at one more than the number of tuples, since any larger domain
+    // cannot lead to propagation
+    let domain_sizes = variables
+        .iter()
+        .map(|variable| {
+            context
+                .iterate_domain(variable)
+                .take(num_tuples as usize + 1)
+                .count() as u64
+        })
+        .collect::<Vec<_>>();
+    let product = |ignored_index: Option<usize>| {
+        domain_sizes
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| Some(index) != ignored_index)
+            .fold(1_u64, |product, (_, &size)| {
+                product.saturating_mul(size).min(num_tuples + 1)
+            })
+    };
+
+    let num_valid_tuples = num_tuples_in(valid_tuples) as u64;
+    if product(None) == num_valid_tuples {
+        return Err(explain_domains(context, variables, None).into());
+    }
+
+    for (index, variable) in variables.iter().enumerate() {
+        let num_assignments = product(Some(index));
+        if num_assignments > num_tuples {
+            continue;
+        }
+
+        let forbidden_values = supports
+            .supports_of(index)
+            .filter(|&(value, support)| {
+                context.contains(variable, value)
+                    && intersection_size(support, valid_tuples) as u64 == num_assignments
+            })
+            .map(|(value, _)| value)
+            .collect::<Vec<_>>();
+
+        for value in forbidden_values {
+            let reason = explain_domains(context, variables, Some(index));
+            context.remove(variable, value, reason)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates the explanation which describes the current domains of the variables (except for the
+/// variable at `ignored_index`).
+fn explain_domains<Var: IntegerVariable + 'static>(
+    context: &PropagationContextMut,
+    variables: &[Var],
+    ignored_index: Option<usize>,
+) -> PropositionalConjunction {
+    let mut explanation = PropositionalConjunction::default();
+    for (index, variable) in variables.iter().enumerate() {
+        if Some(index) == ignored_index {
+            continue;
+        }
+
+        let lower_bound = context.lower_bound(variable);
+        let upper_bound = context.upper_bound(variable);
+        explanation.add(predicate!(variable >= lower_bound));
+        explanation.add(predicate!(variable <= upper_bound));
+        for value in lower_bound..=upper_bound {
+            if !context.contains(variable, value) {
+                explanation.add(predicate!(variable != value));
+            }
+        }
+    }
+    explanation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::Inconsistency;
+    use crate::conjunction;
+    use crate::engine::test_solver::TestSolver;
+
+    #[test]
+    fn fixed_variables_remove_forbidden_value() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 1);
+        let y = solver.new_variable(2, 2);
+        let z = solver.new_variable(1, 3);
+
+        let _ = solver
+            .new_propagator(NegativeTablePropagator::new(
+                [x, y, z].into(),
+                vec![vec![1, 2, 3], vec![1, 1, 1]],
+            ))
+            .expect("No conflict");
+
+        solver.assert_bounds(z, 1, 2);
+
+        let reason = solver.get_reason_int(predicate!(z != 3));
+        assert_eq!(
+            conjunction!([x >= 1] & [x <= 1] & [y >= 2] & [y <= 2]),
+            reason
+        );
+    }
+
+    #[test]
+    fn all_assignments_forbidden_removes_value() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 2);
+        let y = solver.new_variable(1, 2);
+
+        let _ = solver
+            .new_propagator(NegativeTablePropagator::new(
+                [x, y].into(),
+                vec![vec![1, 1], vec![1, 2]],
+            ))
+            .expect("No conflict");
+
+        solver.assert_bounds(x, 2, 2);
+        solver.assert_bounds(y, 1, 2);
+    }
+
+    #[test]
+    fn forbidden_assignment_is_conflict() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 1);
+        let y = solver.new_variable(2, 2);
+
+        let result = solver.new_propagator(NegativeTablePropagator::new(
+            [x, y].into(),
+            vec![vec![1, 2]],
+        ));
+
+        assert!(matches!(
+            result,
+            Err(Inconsistency::Conflict(explanation))
+                if explanation == conjunction!([x >= 1] & [x <= 1] & [y >= 2] & [y <= 2])
+        ));
+    }
+}
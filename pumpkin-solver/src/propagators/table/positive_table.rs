@@ -0,0 +1,334 @@
+use super::supports::intersects;
+use super::supports::iterate_tuples;
+use super::supports::TableSupports;
+use super::supports::ValidTuples;
+use crate::basic_types::PropagationStatusCP;
+use crate::basic_types::PropositionalConjunction;
+use crate::conjunction;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::domain_events::DomainEvents;
+use crate::engine::opaque_domain_event::OpaqueDomainEvent;
+use crate::engine::propagation::contexts::ManipulateTrailedValues;
+use crate::engine::propagation::contexts::PropagationContextWithTrailedValues;
+use crate::engine::propagation::EnqueueDecision;
+use crate::engine::propagation::LocalId;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::Propagator;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::engine::TrailedInteger;
+use crate::predicate;
+use crate::pumpkin_assert_simple;
+
+/// Propagator for the (positive) [Table](https://sofdem.github.io/gccat/gccat/Cin_relation.html)
+/// constraint, which enforces domain consistency using compact-table (see
+/// [`crate::propagators::table`] for more information).
+#[derive(Clone, Debug)]
+pub(crate) struct TablePropagator<Var> {
+    variables: Box<[Var]>,
+    supports: TableSupports,
+    valid_tuples: ValidTuples,
+    /// Indicates whether the values which do not occur in any tuple have been removed from the
+    /// domains of the variables (which only needs to happen once).
+    has_removed_unsupported_values: TrailedInteger,
+}
+
+impl<Var: IntegerVariable + 'static> TablePropagator<Var> {
+    pub(crate) fn new(variables: Box<[Var]>, tuples: Vec<Vec<i64>>) -> Self {
+        let supports = TableSupports::new(variables.len(), tuples);
+        let valid_tuples = ValidTuples::new(variables.len());
+        TablePropagator {
+            variables,
+            supports,
+            valid_tuples,
+            has_removed_unsupported_values: TrailedInteger::default(),
+        }
+    }
+}
+
+impl<Var: IntegerVariable + 'static> Propagator for TablePropagator<Var> {
+    fn initialise_at_root(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+    ) -> Result<(), PropositionalConjunction> {
+        self.variables
+            .iter()
+            .cloned()
+            .enumerate()
+            .for_each(|(index, variable)| {
+                let _ =
+                    context.register(variable, DomainEvents::ANY_INT, LocalId::from(index as u32));
+            });
+
+        self.valid_tuples.initialise(context, &self.supports);
+        self.has_removed_unsupported_values = context.new_trailed_integer(0);
+
+        Ok(())
+    }
+
+    fn notify(
+        &mut self,
+        _context: PropagationContextWithTrailedValues,
+        local_id: LocalId,
+        _event: OpaqueDomainEvent,
+    ) -> EnqueueDecision {
+        self.valid_tuples.mark_changed(local_id.unpack() as usize);
+        EnqueueDecision::Enqueue
+    }
+
+    fn priority(&self) -> u32 {
+        3
+    }
+
+    fn name(&self) -> &str {
+        "Table"
+    }
+
+    fn inference_label(&self) -> &str {
+        "table"
+    }
+
+    fn propagate(&mut self, mut context: PropagationContextMut) -> PropagationStatusCP {
+        if context.value(self.has_removed_unsupported_values) == 0 {
+            remove_unsupported_values(&mut context, &self.variables, &self.supports)?;
+            context.assign(self.has_removed_unsupported_values, 1);
+        }
+
+        let valid_tuples = self
+            .valid_tuples
+            .update(&mut context, &self.variables, &self.supports);
+        filter_table(&mut context, &self.variables, &self.supports, &valid_tuples)
+    }
+
+    fn debug_propagate_from_scratch(
+        &self,
+        mut context: PropagationContextMut,
+    ) -> PropagationStatusCP {
+        remove_unsupported_values(&mut context, &self.variables, &self.supports)?;
+
+        let valid_tuples = self
+            .supports
+            .compute_valid_tuples(&context, &self.variables);
+        filter_table(&mut context, &self.variables, &self.supports, &valid_tuples)
+    }
+}
+
+/// Removes the values which do not occur in any of the tuples from the domains of the variables.
+fn remove_unsupported_values<Var: IntegerVariable + 'static>(
+    context: &mut PropagationContextMut,
+    variables: &[Var],
+    supports: &TableSupports,
+) -> PropagationStatusCP {
+    for (index, variable) in variables.iter().enumerate() {
+        let (Some(min_value), Some(max_value)) = (
+            supports.supports_of(index).map(|(value, _)| value).min(),
+            supports.supports_of(index).map(|(value, _)| value).max(),
+        ) else {
+            // There are no tuples at all, which means that the constraint cannot be satisfied
+            return Err(conjunction!().into());
+        };
+
+        context.set_lower_bound(variable, min_value, conjunction!())?;
+        context.set_upper_bound(variable, max_value, conjunction!())?;
+
+        let unsupported_values = context
+            .iterate_domain(variable)
+            .filter(|&value| !supports.contains_value(index, value))
+            .collect::<Vec<_>>();
+        for value in unsupported_values {
+            context.remove(variable, value, conjunction!())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Removes the values which are not supported by any valid tuple, or reports a conflict if there
+/// are no valid tuples.
+fn filter_table<Var: IntegerVariable + 'static>(
+    context: &mut PropagationContextMut,
+    variables: &[Var],
+    supports: &TableSupports,
+    valid_tuples: &[u64],
+) -> PropagationStatusCP {
+    if valid_tuples.iter().all(|&word| word == 0) {
+        return Err(explain_invalid_tuples(
+            context,
+            variables,
+            supports,
+            0..supports.num_tuples(),
+            None,
+        )
+        .into());
+    }
+
+    for (index, variable) in variables.iter().enumerate() {
+        let unsupported_values = supports
+            .supports_of(index)
+            .filter(|&(value, support)| {
+                context.contains(variable, value) && !intersects(support, valid_tuples)
+            })
+            .map(|(value, support)| (value, support.to_vec()))
+            .collect::<Vec<_>>();
+
+        for (value, support) in unsupported_values {
+            let reason = explain_invalid_tuples(
+                context,
+                variables,
+                supports,
+                iterate_tuples(&support),
+                Some(index),
+            );
+            context.remove(variable, value, reason)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates an explanation for why none of the provided tuples are valid, where the variable at
+/// `ignored_index` (if any) is not used in the explanation.
+///
+/// For every tuple, a predicate is added which shows that one of its values is not in the domain
+/// of the corresponding variable; the bounds of the variables are preferred over disequalities,
+/// and predicates which were already added are reused where possible.
+fn explain_invalid_tuples<Var: IntegerVariable + 'static>(
+    context: &PropagationContextMut,
+    variables: &[Var],
+    supports: &TableSupports,
+    tuple_indices: impl Iterator<Item = usize>,
+    ignored_index: Option<usize>,
+) -> PropositionalConjunction {
+    let bounds = variables
+        .iter()
+        .map(|variable| (context.lower_bound(variable), context.upper_bound(variable)))
+        .collect::<Vec<_>>();
+    let mut uses_lower_bound = vec![false; variables.len()];
+    let mut uses_upper_bound = vec![false; variables.len()];
+    let mut excluded_values = vec![Vec::new(); variables.len()];
+
+    let candidates = (0..variables.len())
+        .filter(|&index| Some(index) != ignored_index)
+        .collect::<Vec<_>>();
+
+    for tuple_index in tuple_indices {
+        let tuple = supports.tuple(tuple_index);
+
+        let is_explained = candidates.iter().any(|&index| {
+            (uses_lower_bound[index] && tuple[index] < bounds[index].0)
+                || (uses_upper_bound[index] && tuple[index] > bounds[index].1)
+                || excluded_values[index].contains(&tuple[index])
+        });
+        if is_explained {
+            continue;
+        }
+
+        if let Some(&index) = candidates
+            .iter()
+            .find(|&&index| tuple[index] < bounds[index].0)
+        {
+            uses_lower_bound[index] = true;
+        } else if let Some(&index) = candidates
+            .iter()
+            .find(|&&index| tuple[index] > bounds[index].1)
+        {
+            uses_upper_bound[index] = true;
+        } else {
+            let index = candidates
+                .iter()
+                .find(|&&index| !context.contains(&variables[index], tuple[index]));
+            pumpkin_assert_simple!(
+                index.is_some(),
+                "A tuple which is being explained should not be valid"
+            );
+            if let Some(&index) = index {
+                excluded_values[index].push(tuple[index]);
+            }
+        }
+    }
+
+    let mut explanation = PropositionalConjunction::default();
+    for (index, variable) in variables.iter().enumerate() {
+        if uses_lower_bound[index] {
+            explanation.add(predicate!(variable >= bounds[index].0));
+        }
+        if uses_upper_bound[index] {
+            explanation.add(predicate!(variable <= bounds[index].1));
+        }
+        for &value in excluded_values[index].iter() {
+            explanation.add(predicate!(variable != value));
+        }
+    }
+    explanation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::Inconsistency;
+    use crate::engine::test_solver::TestSolver;
+
+    #[test]
+    fn unsupported_values_are_removed() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(0, 5);
+        let y = solver.new_variable(0, 5);
+
+        let _ = solver
+            .new_propagator(TablePropagator::new(
+                [x, y].into(),
+                vec![vec![1, 2], vec![3, 2], vec![3, 4]],
+            ))
+            .expect("No conflict");
+
+        solver.assert_bounds(x, 1, 3);
+        assert!(!solver.contains(x, 2));
+        solver.assert_bounds(y, 2, 4);
+        assert!(!solver.contains(y, 3));
+    }
+
+    #[test]
+    fn removal_is_explained_by_invalid_tuples() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 3);
+        let y = solver.new_variable(1, 3);
+        let z = solver.new_variable(1, 3);
+
+        let propagator = solver
+            .new_propagator(TablePropagator::new(
+                [x, y, z].into(),
+                vec![vec![1, 1, 1], vec![2, 3, 2], vec![3, 3, 3], vec![3, 2, 1]],
+            ))
+            .expect("No conflict");
+
+        solver.remove(y, 3).expect("Non-empty domain");
+        let result = solver.propagate_until_fixed_point(propagator);
+        assert!(result.is_ok());
+
+        // The only remaining tuples are (1, 1, 1) and (3, 2, 1)
+        assert!(!solver.contains(x, 2));
+        solver.assert_bounds(z, 1, 1);
+
+        let reason = solver.get_reason_int(predicate!(x != 2));
+        assert_eq!(conjunction!([y <= 2]), reason);
+    }
+
+    #[test]
+    fn conflict_is_explained_by_invalid_tuples() {
+        let mut solver = TestSolver::default();
+        let x = solver.new_variable(1, 3);
+        let y = solver.new_variable(2, 3);
+        solver.remove(x, 2).expect("Non-empty domain");
+
+        let result = solver.new_propagator(TablePropagator::new(
+            [x, y].into(),
+            vec![vec![1, 1], vec![2, 2], vec![2, 3]],
+        ));
+
+        assert!(matches!(
+            result,
+            Err(Inconsistency::Conflict(explanation))
+                if explanation == conjunction!([x <= 1] & [y >= 2])
+        ));
+    }
+}
@@ -0,0 +1,221 @@
+use crate::basic_types::HashMap;
+use crate::engine::cp::propagation::ReadDomains;
+use crate::engine::propagation::contexts::ManipulateTrailedValues;
+use crate::engine::propagation::PropagationContextMut;
+use crate::engine::propagation::PropagatorInitialisationContext;
+use crate::engine::variables::IntegerVariable;
+use crate::engine::TrailedInteger;
+
+const WORD_SIZE: usize = u64::BITS as usize;
+
+/// The tuples of a table together with, for every variable and value, the bitset of the tuples
+/// which assign that value to the variable.
+#[derive(Clone, Debug)]
+pub(super) struct TableSupports {
+    tuples: Box<[Box<[i64]>]>,
+    /// For every variable, maps the values which occur in the tuples to the bitset of tuples in
+    /// which the variable takes that value.
+    supports: Box<[HashMap<i64, Box<[u64]>>]>,
+    num_words: usize,
+}
+
+impl TableSupports {
+    /// Creates the supports for the provided `tuples`; duplicate tuples are removed.
+    pub(super) fn new(num_variables: usize, tuples: Vec<Vec<i64>>) -> Self {
+        let mut tuples = tuples;
+        tuples.sort();
+        tuples.dedup();
+
+        let num_words = tuples.len().div_ceil(WORD_SIZE);
+        let mut supports: Box<[HashMap<i64, Box<[u64]>>]> =
+            vec![HashMap::default(); num_variables].into();
+        for (tuple_index, tuple) in tuples.iter().enumerate() {
+            assert_eq!(
+                tuple.len(),
+                num_variables,
+                "The length of every tuple should be the same as the number of variables"
+            );
+            for (variable_index, &value) in tuple.iter().enumerate() {
+                let support = supports[variable_index]
+                    .entry(value)
+                    .or_insert_with(|| vec![0; num_words].into());
+                support[tuple_index / WORD_SIZE] |= 1 << (tuple_index % WORD_SIZE);
+            }
+        }
+
+        TableSupports {
+            tuples: tuples.into_iter().map(Vec::into_boxed_slice).collect(),
+            supports,
+            num_words,
+        }
+    }
+
+    pub(super) fn num_tuples(&self) -> usize {
+        self.tuples.len()
+    }
+
+    pub(super) fn tuple(&self, tuple_index: usize) -> &[i64] {
+        &self.tuples[tuple_index]
+    }
+
+    /// Returns the values which occur in the tuples for the variable at `variable_index` together
+    /// with their supports.
+    pub(super) fn supports_of(&self, variable_index: usize) -> impl Iterator<Item = (i64, &[u64])> {
+        self.supports[variable_index]
+            .iter()
+            .map(|(&value, support)| (value, support.as_ref()))
+    }
+
+    pub(super) fn contains_value(&self, variable_index: usize, value: i64) -> bool {
+        self.supports[variable_index].contains_key(&value)
+    }
+
+    /// Returns the bitset of tuples for which all of the values are in the domains of the
+    /// corresponding variables.
+    pub(super) fn compute_valid_tuples<Var: IntegerVariable>(
+        &self,
+        context: &impl ReadDomains,
+        variables: &[Var],
+    ) -> Vec<u64> {
+        let mut valid_tuples = self.all_tuples();
+        for (variable_index, variable) in variables.iter().enumerate() {
+            let mask = self.compute_mask(context, variable_index, variable);
+            valid_tuples
+                .iter_mut()
+                .zip(mask)
+                .for_each(|(word, mask_word)| *word &= mask_word);
+        }
+        valid_tuples
+    }
+
+    /// Returns the bitset of tuples for which the value of the variable at `variable_index` is in
+    /// its domain.
+    fn compute_mask<Var: IntegerVariable>(
+        &self,
+        context: &impl ReadDomains,
+        variable_index: usize,
+        variable: &Var,
+    ) -> Vec<u64> {
+        let mut mask = vec![0; self.num_words];
+        self.supports_of(variable_index)
+            .filter(|&(value, _)| context.contains(variable, value))
+            .for_each(|(_, support)| {
+                mask.iter_mut()
+                    .zip(support)
+                    .for_each(|(word, support_word)| *word |= support_word)
+            });
+        mask
+    }
+
+    /// Returns the bitset containing all of the tuples.
+    fn all_tuples(&self) -> Vec<u64> {
+        let mut words = vec![u64::MAX; self.num_words];
+        let remainder = self.tuples.len() % WORD_SIZE;
+        if remainder != 0 {
+            words[self.num_words - 1] = (1 << remainder) - 1;
+        }
+        words
+    }
+}
+
+/// Returns the indices of the tuples which are contained in the provided bitset.
+pub(super) fn iterate_tuples(bitset: &[u64]) -> impl Iterator<Item = usize> + '_ {
+    bitset.iter().enumerate().flat_map(|(word_index, &word)| {
+        (0..WORD_SIZE)
+            .filter(move |bit| word & (1 << bit) != 0)
+            .map(move |bit| word_index * WORD_SIZE + bit)
+    })
+}
+
+/// Returns whether the two provided bitsets have a tuple in common.
+pub(super) fn intersects(lhs: &[u64], rhs: &[u64]) -> bool {
+    lhs.iter().zip(rhs).any(|(lhs, rhs)| lhs & rhs != 0)
+}
+
+/// Returns the number of tuples which are contained in the provided bitset.
+pub(super) fn num_tuples_in(bitset: &[u64]) -> usize {
+    bitset.iter().map(|word| word.count_ones() as usize).sum()
+}
+
+/// Returns the number of tuples which are contained in both of the provided bitsets.
+pub(super) fn intersection_size(lhs: &[u64], rhs: &[u64]) -> usize {
+    lhs.iter()
+        .zip(rhs)
+        .map(|(lhs, rhs)| (lhs & rhs).count_ones() as usize)
+        .sum()
+}
+
+/// The (reversible) bitset of tuples which are still valid; i.e. all of the values of the tuple
+/// are contained in the domains of the corresponding variables.
+///
+/// The bitset is stored using trailed values, and it is only updated for the variables of which
+/// the domain has changed since the last update.
+#[derive(Clone, Debug)]
+pub(super) struct ValidTuples {
+    words: Box<[TrailedInteger]>,
+    /// Whether the domain of the variable at each index has changed since the last update.
+    has_changed: Box<[bool]>,
+}
+
+impl ValidTuples {
+    pub(super) fn new(num_variables: usize) -> Self {
+        ValidTuples {
+            words: Box::default(),
+            has_changed: vec![true; num_variables].into(),
+        }
+    }
+
+    pub(super) fn initialise(
+        &mut self,
+        context: &mut PropagatorInitialisationContext,
+        supports: &TableSupports,
+    ) {
+        self.words = supports
+            .all_tuples()
+            .into_iter()
+            .map(|word| context.new_trailed_integer(word as i64))
+            .collect();
+        self.has_changed
+            .iter_mut()
+            .for_each(|changed| *changed = true);
+    }
+
+    pub(super) fn mark_changed(&mut self, variable_index: usize) {
+        self.has_changed[variable_index] = true;
+    }
+
+    /// Removes the tuples which are not valid anymore due to the changed domains and returns the
+    /// bitset of valid tuples.
+    pub(super) fn update<Var: IntegerVariable>(
+        &mut self,
+        context: &mut PropagationContextMut,
+        variables: &[Var],
+        supports: &TableSupports,
+    ) -> Vec<u64> {
+        let mut valid_tuples = self
+            .words
+            .iter()
+            .map(|&word| context.value(word) as u64)
+            .collect::<Vec<_>>();
+
+        for (variable_index, variable) in variables.iter().enumerate() {
+            if !self.has_changed[variable_index] {
+                continue;
+            }
+            self.has_changed[variable_index] = false;
+
+            let mask = supports.compute_mask(context, variable_index, variable);
+            valid_tuples
+                .iter_mut()
+                .zip(mask)
+                .for_each(|(word, mask_word)| *word &= mask_word);
+        }
+
+        self.words
+            .iter()
+            .zip(valid_tuples.iter())
+            .for_each(|(&trailed_word, &word)| context.assign(trailed_word, word as i64));
+
+        valid_tuples
+    }
+}
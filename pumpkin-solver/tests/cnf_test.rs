@@ -1,5 +1,6 @@
 #![cfg(test)] // workaround for https://github.com/rust-lang/rust-clippy/issues/11024
 
+use std::collections::HashMap;
 use std::process::Command;
 use std::process::Output;
 
@@ -7,6 +8,7 @@ mod helpers;
 
 use helpers::run_solution_checker;
 use helpers::run_solver;
+use helpers::run_solver_with_options;
 use helpers::verify_proof;
 use helpers::Checker;
 use helpers::CheckerOutput;
@@ -107,6 +109,40 @@ test_cnf_instance!(unit5);
 test_cnf_instance!(unit6);
 test_cnf_instance!(unit7);
 
+macro_rules! test_cnf_proof_formats {
+    ($name:ident) => {
+        mod $name {
+            use super::*;
+
+            #[test]
+            fn binary_drat() {
+                run_cnf_binary_drat_test(stringify!($name));
+            }
+
+            #[test]
+            fn text_lrat() {
+                run_cnf_lrat_test(stringify!($name), "text");
+            }
+
+            #[test]
+            fn binary_lrat() {
+                run_cnf_lrat_test(stringify!($name), "binary");
+            }
+        }
+    };
+}
+
+mod proof_formats {
+    use super::*;
+
+    test_cnf_proof_formats!(add16);
+    test_cnf_proof_formats!(full1);
+    test_cnf_proof_formats!(ph6);
+    test_cnf_proof_formats!(prime65537);
+    test_cnf_proof_formats!(trivially_false);
+    test_cnf_proof_formats!(unit4);
+}
+
 struct CnfChecker;
 
 impl Checker for CnfChecker {
@@ -135,11 +171,265 @@ impl Checker for CnfChecker {
 }
 
 fn run_cnf_test(instance_name: &str) {
-    let instance_path = format!(
+    let files = run_solver(cnf_instance_path(instance_name), true);
+
+    run_solution_checker(files, CnfChecker);
+}
+
+/// The options which make the solver delete learned clauses often, so the deletions in the proof
+/// are checked as well.
+fn proof_format_options(proof_format: &str, proof_type: &str) -> Vec<String> {
+    [
+        "--proof-format",
+        proof_format,
+        "--proof-type",
+        proof_type,
+        "--learning-max-num-clauses",
+        "100",
+    ]
+    .into_iter()
+    .map(str::to_owned)
+    .collect()
+}
+
+fn cnf_instance_path(instance_name: &str) -> String {
+    format!(
         "{}/tests/cnf/{instance_name}.cnf",
         env!("CARGO_MANIFEST_DIR")
+    )
+}
+
+fn run_cnf_binary_drat_test(instance_name: &str) {
+    let files = run_solver_with_options(
+        cnf_instance_path(instance_name),
+        true,
+        proof_format_options("binary", "full"),
+        Some("binary-drat"),
     );
-    let files = run_solver(instance_path, true);
 
     run_solution_checker(files, CnfChecker);
 }
+
+fn run_cnf_lrat_test(instance_name: &str, proof_format: &str) {
+    let files = run_solver_with_options(
+        cnf_instance_path(instance_name),
+        true,
+        proof_format_options(proof_format, "with-hints"),
+        Some(&format!("{proof_format}-lrat")),
+    );
+
+    let instance = std::fs::read_to_string(&files.instance_file).expect("Failed to read instance");
+    let proof = std::fs::read(&files.proof_file).expect("Failed to read proof");
+    let steps = match proof_format {
+        "text" => parse_text_lrat(std::str::from_utf8(&proof).expect("Proof is not UTF-8")),
+        "binary" => parse_binary_lrat(&proof),
+        _ => unreachable!("unknown proof format {proof_format}"),
+    };
+
+    // The hints are checked here, after which the lemmas and deletions form a DRAT proof which is
+    // checked by drat-trim.
+    let drat = check_lrat(&instance, &steps);
+    std::fs::write(&files.proof_file, drat).expect("Failed to write proof");
+
+    run_solution_checker(files, CnfChecker);
+}
+
+/// A step in an LRAT proof.
+enum LratStep {
+    Add {
+        id: u64,
+        literals: Vec<i64>,
+        hints: Vec<i64>,
+    },
+    Delete(Vec<u64>),
+}
+
+fn parse_text_lrat(proof: &str) -> Vec<LratStep> {
+    proof
+        .lines()
+        .map(|line| {
+            let tokens = line.split_whitespace().collect::<Vec<_>>();
+
+            if tokens[1] == "d" {
+                let ids = tokens[2..tokens.len() - 1]
+                    .iter()
+                    .map(|id| id.parse().expect("Invalid clause id"))
+                    .collect();
+                return LratStep::Delete(ids);
+            }
+
+            let numbers = tokens[1..]
+                .iter()
+                .map(|token| token.parse().expect("Invalid number"))
+                .collect::<Vec<i64>>();
+            let separator = numbers.iter().position(|&number| number == 0).unwrap();
+
+            LratStep::Add {
+                id: tokens[0].parse().expect("Invalid clause id"),
+                literals: numbers[..separator].to_vec(),
+                hints: numbers[separator + 1..numbers.len() - 1].to_vec(),
+            }
+        })
+        .collect()
+}
+
+fn parse_binary_lrat(proof: &[u8]) -> Vec<LratStep> {
+    let mut bytes = proof.iter().copied();
+    let mut steps = vec![];
+
+    while let Some(kind) = bytes.next() {
+        match kind {
+            b'a' => {
+                let id = read_binary_number(&mut bytes) / 2;
+                let literals = read_binary_list(&mut bytes);
+                let hints = read_binary_list(&mut bytes);
+                steps.push(LratStep::Add {
+                    id,
+                    literals,
+                    hints,
+                });
+            }
+            b'd' => steps.push(LratStep::Delete(
+                read_binary_list(&mut bytes)
+                    .into_iter()
+                    .map(|id| id as u64)
+                    .collect(),
+            )),
+            _ => panic!("unexpected step {kind}"),
+        }
+    }
+
+    steps
+}
+
+/// Reads a number in the variable-length encoding of binary DRAT.
+fn read_binary_number(bytes: &mut impl Iterator<Item = u8>) -> u64 {
+    let mut number = 0;
+    let mut shift = 0;
+
+    loop {
+        let byte = bytes.next().expect("Unexpected end of proof");
+        number |= u64::from(byte & 127) << shift;
+        shift += 7;
+
+        if byte & 128 == 0 {
+            return number;
+        }
+    }
+}
+
+/// Reads a list of signed numbers, terminated by 0, where a number `n` is encoded as `2 * |n|` if
+/// it is positive and `2 * |n| + 1` if it is negative.
+fn read_binary_list(bytes: &mut impl Iterator<Item = u8>) -> Vec<i64> {
+    std::iter::from_fn(|| Some(read_binary_number(bytes)))
+        .take_while(|&number| number != 0)
+        .map(|number| {
+            let magnitude = (number / 2) as i64;
+            if number % 2 == 0 {
+                magnitude
+            } else {
+                -magnitude
+            }
+        })
+        .collect()
+}
+
+/// Checks that every clause in the LRAT proof is derived by unit propagation over its hints, in the
+/// order in which they are given, and that the proof derives the empty clause. The proof is
+/// returned in the DRAT format, with the clause ids and hints removed.
+fn check_lrat(instance: &str, steps: &[LratStep]) -> String {
+    let mut clauses = HashMap::new();
+    let mut clause = vec![];
+    for literal in instance
+        .lines()
+        .filter(|line| !line.starts_with('c') && !line.starts_with('p'))
+        .flat_map(str::split_whitespace)
+        .map(|literal| literal.parse::<i64>().expect("Invalid literal"))
+    {
+        if literal == 0 {
+            let id = clauses.len() as u64 + 1;
+            let _ = clauses.insert(id, std::mem::take(&mut clause));
+        } else {
+            clause.push(literal);
+        }
+    }
+
+    let mut drat = String::new();
+    let mut has_empty_clause = false;
+
+    for step in steps {
+        match step {
+            LratStep::Add {
+                id,
+                literals,
+                hints,
+            } => {
+                assert!(
+                    is_derived_by_hints(&clauses, literals, hints),
+                    "the hints {hints:?} of clause {id} do not derive it"
+                );
+                assert!(
+                    clauses.insert(*id, literals.clone()).is_none(),
+                    "clause {id} is added twice"
+                );
+
+                has_empty_clause |= literals.is_empty();
+                drat.push_str(&format!("{} 0\n", join_literals(literals)));
+            }
+            LratStep::Delete(ids) => {
+                for id in ids {
+                    let literals = clauses
+                        .remove(id)
+                        .unwrap_or_else(|| panic!("deleted unknown clause {id}"));
+                    drat.push_str(&format!("d {} 0\n", join_literals(&literals)));
+                }
+            }
+        }
+    }
+
+    assert!(
+        has_empty_clause,
+        "the proof does not derive the empty clause"
+    );
+
+    drat
+}
+
+/// Whether assigning the negation of `literals` and then propagating the hints one after the
+/// other leads to a conflict, where every hint has to be unit or conflicting when it is used.
+fn is_derived_by_hints(clauses: &HashMap<u64, Vec<i64>>, literals: &[i64], hints: &[i64]) -> bool {
+    let mut assigned = literals.iter().map(|&literal| -literal).collect::<Vec<_>>();
+
+    for &hint in hints {
+        let Some(hint_clause) = u64::try_from(hint).ok().and_then(|id| clauses.get(&id)) else {
+            return false;
+        };
+
+        let mut unassigned = vec![];
+        for &literal in hint_clause {
+            if assigned.contains(&literal) {
+                return false;
+            }
+
+            if !assigned.contains(&-literal) {
+                unassigned.push(literal);
+            }
+        }
+
+        match unassigned[..] {
+            [] => return true,
+            [literal] => assigned.push(literal),
+            _ => return false,
+        }
+    }
+
+    false
+}
+
+fn join_literals(literals: &[i64]) -> String {
+    literals
+        .iter()
+        .map(i64::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
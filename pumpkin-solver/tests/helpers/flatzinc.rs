@@ -11,9 +11,9 @@ use regex::Regex;
     reason = "this is what a FlatZinc value can be, so we have no choice"
 )]
 pub(crate) enum Value {
-    Int(i32),
+    Int(i64),
     Bool(bool),
-    IntArray(Vec<i32>),
+    IntArray(Vec<i64>),
     BoolArray(Vec<bool>),
 }
 
@@ -21,7 +21,7 @@ impl FromStr for Value {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        s.parse::<i32>()
+        s.parse::<i64>()
             .map(Value::Int)
             .or_else(|_| {
                 s.parse::<bool>()
@@ -51,7 +51,7 @@ fn create_array_from_string(s: &str) -> Result<Value, ArrayError> {
                 .unwrap()
                 .as_str()
                 .split(", ")
-                .map(|integer| integer.parse::<i32>().unwrap())
+                .map(|integer| integer.parse::<i64>().unwrap())
                 .collect::<Vec<_>>(),
         ));
     }
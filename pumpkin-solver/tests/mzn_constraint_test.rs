@@ -66,12 +66,48 @@ mzn_test!(bool_lin_eq);
 mzn_test!(bool_lin_le);
 mzn_test!(bool_clause);
 
+mzn_test!(cumulative_var);
+mzn_test!(
+    cumulative_var_time_table_per_point_incremental,
+    "cumulative_var",
+    vec![
+        "--cumulative-propagation-method".to_owned(),
+        "time-table-per-point-incremental".to_owned()
+    ]
+);
+mzn_test!(
+    cumulative_var_time_table_over_interval_incremental,
+    "cumulative_var",
+    vec![
+        "--cumulative-propagation-method".to_owned(),
+        "time-table-over-interval-incremental".to_owned()
+    ]
+);
+mzn_test!(
+    cumulative_var_time_table_edge_finding,
+    "cumulative_var",
+    vec![
+        "--cumulative-propagation-method".to_owned(),
+        "time-table-edge-finding".to_owned()
+    ]
+);
+mzn_test!(
+    cumulative_var_energetic_reasoning,
+    "cumulative_var",
+    vec![
+        "--cumulative-propagation-method".to_owned(),
+        "energetic-reasoning".to_owned()
+    ]
+);
+
 cumulative!(time_table_per_point);
 cumulative!(time_table_per_point_incremental);
 cumulative!(time_table_per_point_incremental_synchronised);
 cumulative!(time_table_over_interval);
 cumulative!(time_table_over_interval_incremental);
 cumulative!(time_table_over_interval_incremental_synchronised);
+cumulative!(time_table_edge_finding);
+cumulative!(energetic_reasoning);
 
 cumulative_synchronised!(
     time_table_per_point,
@@ -83,3 +119,62 @@ cumulative_synchronised!(
 );
 
 mzn_test!(all_different);
+mzn_test!(
+    all_different_domain,
+    "all_different",
+    vec![
+        "--all-different-consistency".to_owned(),
+        "domain".to_owned()
+    ]
+);
+
+mzn_test!(table_int);
+mzn_test!(table_bool);
+
+mzn_test!(regular);
+mzn_test!(cost_regular);
+
+mzn_test!(disjunctive);
+mzn_test!(
+    disjunctive_naive,
+    "disjunctive",
+    vec![
+        "--disjunctive-explanation-type".to_owned(),
+        "naive".to_owned()
+    ]
+);
+mzn_test!(
+    disjunctive_prev_scheduled_tasks,
+    "disjunctive",
+    vec![
+        "--disjunctive-explanation-type".to_owned(),
+        "prev-scheduled-tasks".to_owned()
+    ]
+);
+mzn_test!(
+    disjunctive_only_detectable_precedences,
+    "disjunctive",
+    vec![
+        "--disjunctive-no-overload-checking".to_owned(),
+        "--disjunctive-no-edge-finding".to_owned(),
+        "--disjunctive-no-not-first-not-last".to_owned()
+    ]
+);
+mzn_test!(
+    disjunctive_only_edge_finding,
+    "disjunctive",
+    vec![
+        "--disjunctive-no-overload-checking".to_owned(),
+        "--disjunctive-no-detectable-precedences".to_owned(),
+        "--disjunctive-no-not-first-not-last".to_owned()
+    ]
+);
+mzn_test!(
+    disjunctive_only_not_first_not_last,
+    "disjunctive",
+    vec![
+        "--disjunctive-no-overload-checking".to_owned(),
+        "--disjunctive-no-detectable-precedences".to_owned(),
+        "--disjunctive-no-edge-finding".to_owned()
+    ]
+);
@@ -2,14 +2,27 @@
 
 mod helpers;
 
+use std::path::PathBuf;
+
+use drcp_checker::Format;
 use helpers::run_mzn_test;
+use helpers::run_mzn_test_with_options;
 use helpers::TestType;
 
 macro_rules! mzn_infeasible_test {
     ($name:ident) => {
-        #[test]
-        fn $name() {
-            run_mzn_infeasible_test(stringify!($name), "mzn_infeasible");
+        mod $name {
+            use super::*;
+
+            #[test]
+            fn scaffold_proof() {
+                run_mzn_infeasible_test(stringify!($name), "mzn_infeasible");
+            }
+
+            #[test]
+            fn full_proof() {
+                run_mzn_infeasible_test_with_full_proof(stringify!($name), "mzn_infeasible");
+            }
         }
     };
 }
@@ -18,3 +31,47 @@ mzn_infeasible_test!(prop_stress);
 pub fn run_mzn_infeasible_test(instance_name: &str, folder_name: &str) {
     let _ = run_mzn_test::<false>(instance_name, folder_name, TestType::Unsatisfiable);
 }
+
+pub fn run_mzn_infeasible_test_with_full_proof(instance_name: &str, folder_name: &str) {
+    let _ = run_mzn_test_with_options::<false>(
+        instance_name,
+        folder_name,
+        TestType::Unsatisfiable,
+        vec!["--proof-type".to_owned(), "full".to_owned()],
+        "full",
+    );
+
+    let instance_path = PathBuf::from(format!(
+        "{}/tests/{folder_name}/{instance_name}",
+        env!("CARGO_MANIFEST_DIR")
+    ));
+    let model_path = instance_path.with_extension("fzn");
+    let proof_path = instance_path.with_extension("full.proof");
+    let lits_path = instance_path.with_extension("full.lits");
+
+    // Every inference should be validated against the model by the proof checker.
+    let checked_proof =
+        drcp_checker::check_proof_files(&model_path, &proof_path, &lits_path, Format::Text, false)
+            .expect("Failed to check proof");
+    assert_eq!(0, checked_proof.statistics.num_unchecked_inferences());
+
+    let proof = std::fs::read_to_string(proof_path).expect("Failed to read proof");
+
+    // Every inference should identify the filtering algorithm which made it.
+    let mut inferences = proof
+        .lines()
+        .filter(|line| line.starts_with("i "))
+        .peekable();
+    assert!(
+        inferences.peek().is_some(),
+        "expected the proof to contain inferences"
+    );
+    for inference in inferences {
+        assert!(
+            inference.contains(" l:"),
+            "inference without a label: {inference}"
+        );
+    }
+
+    assert_eq!(Some("c UNSAT"), proof.lines().last());
+}
@@ -3,6 +3,7 @@
 mod helpers;
 
 use helpers::run_mzn_test;
+use helpers::run_solver_with_options;
 use helpers::TestType;
 
 macro_rules! mzn_optimization_test {
@@ -18,3 +19,87 @@ macro_rules! mzn_optimization_test {
 
 mzn_optimization_test!(constant_objective);
 mzn_optimization_test!(unfixed_objective);
+
+#[test]
+fn unfixed_objective_with_multiple_threads() {
+    let instance_path = format!(
+        "{}/tests/mzn_optimization/unfixed_objective.fzn",
+        env!("CARGO_MANIFEST_DIR")
+    );
+
+    let files = run_solver_with_options(
+        instance_path,
+        false,
+        ["-p".to_owned(), "4".to_owned()],
+        Some("parallel"),
+    );
+
+    let output = std::fs::read_to_string(files.log_file).expect("Failed to read solver output");
+    assert!(output.contains("objective = 3;"));
+    assert!(output.ends_with("==========\n"));
+}
+
+#[test]
+fn unfixed_objective_with_chb() {
+    let instance_path = format!(
+        "{}/tests/mzn_optimization/unfixed_objective.fzn",
+        env!("CARGO_MANIFEST_DIR")
+    );
+
+    let files = run_solver_with_options(
+        instance_path,
+        false,
+        [
+            "-f".to_owned(),
+            "--branching-heuristic".to_owned(),
+            "chb".to_owned(),
+        ],
+        Some("chb"),
+    );
+
+    let output = std::fs::read_to_string(files.log_file).expect("Failed to read solver output");
+    assert!(output.contains("objective = 3;"));
+    assert!(output.ends_with("==========\n"));
+}
+
+#[test]
+fn unfixed_objective_with_lrb() {
+    let instance_path = format!(
+        "{}/tests/mzn_optimization/unfixed_objective.fzn",
+        env!("CARGO_MANIFEST_DIR")
+    );
+
+    let files = run_solver_with_options(
+        instance_path,
+        false,
+        [
+            "-f".to_owned(),
+            "--branching-heuristic".to_owned(),
+            "lrb".to_owned(),
+        ],
+        Some("lrb"),
+    );
+
+    let output = std::fs::read_to_string(files.log_file).expect("Failed to read solver output");
+    assert!(output.contains("objective = 3;"));
+    assert!(output.ends_with("==========\n"));
+}
+
+#[test]
+fn unfixed_objective_without_solution_guided_search() {
+    let instance_path = format!(
+        "{}/tests/mzn_optimization/unfixed_objective.fzn",
+        env!("CARGO_MANIFEST_DIR")
+    );
+
+    let files = run_solver_with_options(
+        instance_path,
+        false,
+        ["--no-solution-guided-search".to_owned()],
+        Some("no-solution-guided"),
+    );
+
+    let output = std::fs::read_to_string(files.log_file).expect("Failed to read solver output");
+    assert!(output.contains("objective = 3;"));
+    assert!(output.ends_with("==========\n"));
+}
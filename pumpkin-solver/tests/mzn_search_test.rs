@@ -37,3 +37,8 @@ mzn_search_ordered!(search_over_bools_no_propagators);
 mzn_search_ordered!(seq_search_1);
 mzn_search_unordered!(search_with_constants_in_search);
 mzn_search_unordered!(search_annotation_does_not_fix_all_variables);
+mzn_search_ordered!(warm_start_over_ints);
+mzn_search_ordered!(warm_start_array_over_bools);
+mzn_search_ordered!(priority_search_smallest);
+mzn_search_unordered!(restart_linear_with_int_search);
+mzn_search_unordered!(restart_luby_with_int_search);
@@ -1,14 +1,36 @@
 #![cfg(test)] // workaround for https://github.com/rust-lang/rust-clippy/issues/11024
 
+use std::cell::RefCell;
 use std::num::NonZero;
 use std::path::PathBuf;
 
+use drcp_format::reader::ProofReader;
+use drcp_format::steps::Conclusion;
+use drcp_format::steps::Step;
 use pumpkin_solver::constraints;
+use pumpkin_solver::optimisation::bisection::Bisection;
+use pumpkin_solver::optimisation::core_guided::CoreGuided;
+use pumpkin_solver::optimisation::large_neighbourhood_search::LargeNeighbourhoodSearch;
+use pumpkin_solver::optimisation::lexicographic::Lexicographic;
+use pumpkin_solver::optimisation::linear_sat_unsat::LinearSatUnsat;
+use pumpkin_solver::optimisation::linear_unsat_sat::LinearUnsatSat;
+use pumpkin_solver::optimisation::neighbourhoods::CostImpactNeighbourhood;
+use pumpkin_solver::optimisation::neighbourhoods::PropagationGuidedNeighbourhood;
+use pumpkin_solver::optimisation::neighbourhoods::RandomNeighbourhood;
+use pumpkin_solver::optimisation::pareto::Pareto;
+use pumpkin_solver::optimisation::solution_callback::SolutionCallback;
+use pumpkin_solver::optimisation::OptimisationDirection;
 use pumpkin_solver::options::SolverOptions;
 use pumpkin_solver::predicate;
 use pumpkin_solver::proof::ProofLog;
+use pumpkin_solver::results::OptimisationResult;
+use pumpkin_solver::results::ProblemSolution;
 use pumpkin_solver::results::SatisfactionResult;
+use pumpkin_solver::results::SatisfactionResultUnderAssumptions;
+use pumpkin_solver::results::SolutionReference;
 use pumpkin_solver::termination::Indefinite;
+use pumpkin_solver::variables::TransformableVariable;
+use pumpkin_solver::DefaultBrancher;
 use pumpkin_solver::Solver;
 
 #[test]
@@ -73,3 +95,643 @@ fn proof_with_equality_unit_nogood_step() {
     let result = solver.satisfy(&mut brancher, &mut Indefinite);
     assert!(matches!(result, SatisfactionResult::Unsatisfiable));
 }
+
+#[test]
+fn binary_proof_can_be_read() {
+    let proof_path =
+        std::env::temp_dir().join(format!("pumpkin_binary_proof_{}.drcp", std::process::id()));
+    let mut solver = Solver::with_options(SolverOptions {
+        proof_log: ProofLog::cp(&proof_path, drcp_format::Format::Binary, true, true)
+            .expect("created proof"),
+        ..Default::default()
+    });
+
+    let x1 = solver.new_named_bounded_integer(1, 2, "x1");
+    let x2 = solver.new_named_bounded_integer(1, 1, "x2");
+    solver
+        .add_constraint(constraints::binary_not_equals(x1, x2))
+        .with_tag(NonZero::new(1).unwrap())
+        .post()
+        .expect("no conflict");
+
+    let _ = solver
+        .add_constraint(constraints::less_than_or_equals([x1], 1))
+        .with_tag(NonZero::new(2).unwrap())
+        .post()
+        .expect_err("conflict");
+
+    let mut brancher = solver.default_brancher();
+    let result = solver.satisfy(&mut brancher, &mut Indefinite);
+    assert!(matches!(result, SatisfactionResult::Unsatisfiable));
+    solver.conclude_proof_unsat();
+
+    let proof = std::fs::File::open(&proof_path).expect("proof exists");
+    let mut reader =
+        ProofReader::with_format(drcp_format::Format::Binary, proof, std::convert::identity);
+
+    let mut last_step = None;
+    while let Some(step) = reader.next_step().expect("valid binary proof step") {
+        last_step = Some(matches!(step, Step::Conclusion(Conclusion::Unsatisfiable)));
+    }
+    assert_eq!(Some(true), last_step);
+}
+
+#[test]
+fn constraints_of_popped_scopes_are_retracted() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+    let y = solver.new_bounded_integer(0, 5);
+    solver
+        .add_constraint(constraints::binary_not_equals(x, y))
+        .post()
+        .expect("no conflict");
+
+    let mut brancher = solver.default_brancher();
+
+    solver.push();
+    solver
+        .add_constraint(constraints::equals([x], 2))
+        .post()
+        .expect("no conflict");
+
+    solver.push();
+    let reification_literal = solver.new_literal();
+    solver
+        .add_constraint(constraints::equals([y], 2))
+        .reify(reification_literal)
+        .expect("no conflict");
+    solver
+        .add_clause([reification_literal.get_true_predicate()])
+        .expect("no conflict");
+    assert_eq!(solver.num_scopes(), 2);
+
+    let result = solver.satisfy(&mut brancher, &mut Indefinite);
+    assert!(matches!(result, SatisfactionResult::Unsatisfiable));
+
+    solver.pop();
+    let result = solver.satisfy(&mut brancher, &mut Indefinite);
+    let SatisfactionResult::Satisfiable(solution) = result else {
+        panic!("expected a solution");
+    };
+    assert_eq!(solution.get_integer_value(x), 2);
+    assert_ne!(solution.get_integer_value(y), 2);
+
+    solver.pop();
+    solver
+        .add_constraint(constraints::equals([x], 4))
+        .post()
+        .expect("no conflict");
+    let result = solver.satisfy(&mut brancher, &mut Indefinite);
+    assert!(matches!(result, SatisfactionResult::Satisfiable(_)));
+}
+
+#[test]
+fn optimisation_within_scope() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 10);
+    let y = solver.new_bounded_integer(0, 10);
+    solver
+        .add_constraint(constraints::less_than_or_equals(
+            [x.scaled(-1), y.scaled(1)],
+            -3,
+        ))
+        .post()
+        .expect("no conflict");
+
+    let mut brancher = solver.default_brancher();
+    let callback: fn(&Solver, SolutionReference, &DefaultBrancher) = |_, _, _| {};
+
+    solver.push();
+    solver
+        .add_constraint(constraints::less_than_or_equals([y.scaled(-1)], -4))
+        .post()
+        .expect("no conflict");
+    let result = solver.optimise(
+        &mut brancher,
+        &mut Indefinite,
+        LinearUnsatSat::new(OptimisationDirection::Minimise, x, callback),
+    );
+    let OptimisationResult::Optimal(solution) = result else {
+        panic!("expected an optimal solution");
+    };
+    assert_eq!(solution.get_integer_value(x), 7);
+    solver.pop();
+
+    let result = solver.optimise(
+        &mut brancher,
+        &mut Indefinite,
+        LinearSatUnsat::new(OptimisationDirection::Minimise, x, callback),
+    );
+    let OptimisationResult::Optimal(solution) = result else {
+        panic!("expected an optimal solution");
+    };
+    assert_eq!(solution.get_integer_value(x), 3);
+}
+
+#[test]
+fn core_does_not_contain_scope_literals() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+
+    let mut brancher = solver.default_brancher();
+
+    solver.push();
+    solver
+        .add_constraint(constraints::less_than_or_equals([x], 2))
+        .post()
+        .expect("no conflict");
+
+    let result =
+        solver.satisfy_under_assumptions(&mut brancher, &mut Indefinite, &[predicate!(x >= 4)]);
+    let SatisfactionResultUnderAssumptions::UnsatisfiableUnderAssumptions(mut unsatisfiable) =
+        result
+    else {
+        panic!("expected the assumptions to be unsatisfiable");
+    };
+    assert_eq!(
+        unsatisfiable.extract_core(),
+        vec![predicate!(x >= 4)].into()
+    );
+}
+
+#[test]
+fn core_guided_optimises_weighted_objective() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+    let y = solver.new_bounded_integer(0, 5);
+    let z = solver.new_bounded_integer(0, 5);
+    let objective = solver.new_bounded_integer(0, 100);
+
+    // x + y >= 4 and y + z >= 3
+    solver
+        .add_constraint(constraints::less_than_or_equals(
+            [x.scaled(-1), y.scaled(-1)],
+            -4,
+        ))
+        .post()
+        .expect("no conflict");
+    solver
+        .add_constraint(constraints::less_than_or_equals(
+            [y.scaled(-1), z.scaled(-1)],
+            -3,
+        ))
+        .post()
+        .expect("no conflict");
+    // objective = 3x + 5y + 2z
+    solver
+        .add_constraint(constraints::equals(
+            [x.scaled(3), y.scaled(5), z.scaled(2), objective.scaled(-1)],
+            0,
+        ))
+        .post()
+        .expect("no conflict");
+
+    let mut brancher = solver.default_brancher();
+    let callback: fn(&Solver, SolutionReference, &DefaultBrancher) = |_, _, _| {};
+    let result = solver.optimise(
+        &mut brancher,
+        &mut Indefinite,
+        CoreGuided::new(
+            OptimisationDirection::Minimise,
+            objective,
+            [(3, x), (5, y), (2, z)],
+            callback,
+        ),
+    );
+
+    let OptimisationResult::Optimal(solution) = result else {
+        panic!("expected an optimal solution");
+    };
+    // An optimal solution is x = 4, y = 0 and z = 3
+    assert_eq!(solution.get_integer_value(objective), 18);
+}
+
+#[test]
+fn core_guided_maximises_weighted_objective() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+    let y = solver.new_bounded_integer(0, 5);
+    let objective = solver.new_bounded_integer(-100, 100);
+
+    // x + y >= 6
+    solver
+        .add_constraint(constraints::less_than_or_equals(
+            [x.scaled(-1), y.scaled(-1)],
+            -6,
+        ))
+        .post()
+        .expect("no conflict");
+    // objective = 2x - y
+    solver
+        .add_constraint(constraints::equals(
+            [x.scaled(2), y.scaled(-1), objective.scaled(-1)],
+            0,
+        ))
+        .post()
+        .expect("no conflict");
+
+    let mut brancher = solver.default_brancher();
+    let callback: fn(&Solver, SolutionReference, &DefaultBrancher) = |_, _, _| {};
+    let result = solver.optimise(
+        &mut brancher,
+        &mut Indefinite,
+        CoreGuided::new(
+            OptimisationDirection::Maximise,
+            objective,
+            [(2, x), (-1, y)],
+            callback,
+        ),
+    );
+
+    let OptimisationResult::Optimal(solution) = result else {
+        panic!("expected an optimal solution");
+    };
+    // The optimal solution is x = 5 and y = 1
+    assert_eq!(solution.get_integer_value(objective), 9);
+}
+
+#[test]
+fn large_neighbourhood_search_with_random_neighbourhood() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+    let y = solver.new_bounded_integer(0, 5);
+    let z = solver.new_bounded_integer(0, 5);
+    let objective = solver.new_bounded_integer(0, 100);
+
+    solver
+        .add_constraint(constraints::less_than_or_equals(
+            [x.scaled(-1), y.scaled(-1)],
+            -4,
+        ))
+        .post()
+        .expect("no conflict");
+    solver
+        .add_constraint(constraints::less_than_or_equals(
+            [y.scaled(-1), z.scaled(-1)],
+            -3,
+        ))
+        .post()
+        .expect("no conflict");
+    solver
+        .add_constraint(constraints::equals(
+            [x.scaled(3), y.scaled(5), z.scaled(2), objective.scaled(-1)],
+            0,
+        ))
+        .post()
+        .expect("no conflict");
+
+    let mut brancher = solver.default_brancher();
+    let callback: fn(&Solver, SolutionReference, &DefaultBrancher) = |_, _, _| {};
+    let result = solver.optimise(
+        &mut brancher,
+        &mut Indefinite,
+        LargeNeighbourhoodSearch::new(
+            OptimisationDirection::Minimise,
+            objective,
+            [x, y, z],
+            RandomNeighbourhood,
+            callback,
+        ),
+    );
+
+    let OptimisationResult::Optimal(solution) = result else {
+        panic!("expected an optimal solution");
+    };
+    // An optimal solution is x = 4, y = 0 and z = 3
+    assert_eq!(solution.get_integer_value(objective), 18);
+}
+
+#[test]
+fn large_neighbourhood_search_with_propagation_guided_neighbourhood() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+    let y = solver.new_bounded_integer(0, 5);
+    let z = solver.new_bounded_integer(0, 5);
+    let objective = solver.new_bounded_integer(0, 100);
+
+    solver
+        .add_constraint(constraints::less_than_or_equals(
+            [x.scaled(-1), y.scaled(-1)],
+            -4,
+        ))
+        .post()
+        .expect("no conflict");
+    solver
+        .add_constraint(constraints::less_than_or_equals(
+            [y.scaled(-1), z.scaled(-1)],
+            -3,
+        ))
+        .post()
+        .expect("no conflict");
+    solver
+        .add_constraint(constraints::equals(
+            [x.scaled(3), y.scaled(5), z.scaled(2), objective.scaled(-1)],
+            0,
+        ))
+        .post()
+        .expect("no conflict");
+
+    let mut brancher = solver.default_brancher();
+    let callback: fn(&Solver, SolutionReference, &DefaultBrancher) = |_, _, _| {};
+    let result = solver.optimise(
+        &mut brancher,
+        &mut Indefinite,
+        LargeNeighbourhoodSearch::new(
+            OptimisationDirection::Minimise,
+            objective,
+            [x, y, z],
+            PropagationGuidedNeighbourhood,
+            callback,
+        ),
+    );
+
+    let OptimisationResult::Optimal(solution) = result else {
+        panic!("expected an optimal solution");
+    };
+    // An optimal solution is x = 4, y = 0 and z = 3
+    assert_eq!(solution.get_integer_value(objective), 18);
+}
+
+#[test]
+fn large_neighbourhood_search_with_cost_impact_neighbourhood() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+    let y = solver.new_bounded_integer(0, 5);
+    let z = solver.new_bounded_integer(0, 5);
+    let objective = solver.new_bounded_integer(0, 100);
+
+    solver
+        .add_constraint(constraints::less_than_or_equals(
+            [x.scaled(-1), y.scaled(-1)],
+            -4,
+        ))
+        .post()
+        .expect("no conflict");
+    solver
+        .add_constraint(constraints::less_than_or_equals(
+            [y.scaled(-1), z.scaled(-1)],
+            -3,
+        ))
+        .post()
+        .expect("no conflict");
+    solver
+        .add_constraint(constraints::equals(
+            [x.scaled(3), y.scaled(5), z.scaled(2), objective.scaled(-1)],
+            0,
+        ))
+        .post()
+        .expect("no conflict");
+
+    let mut brancher = solver.default_brancher();
+    let callback: fn(&Solver, SolutionReference, &DefaultBrancher) = |_, _, _| {};
+    let result = solver.optimise(
+        &mut brancher,
+        &mut Indefinite,
+        LargeNeighbourhoodSearch::new(
+            OptimisationDirection::Minimise,
+            objective,
+            [x, y, z],
+            CostImpactNeighbourhood::new(OptimisationDirection::Minimise, objective),
+            callback,
+        ),
+    );
+
+    let OptimisationResult::Optimal(solution) = result else {
+        panic!("expected an optimal solution");
+    };
+    // An optimal solution is x = 4, y = 0 and z = 3
+    assert_eq!(solution.get_integer_value(objective), 18);
+}
+
+/// A [`SolutionCallback`] which records the bounds which are reported to it.
+#[derive(Default)]
+struct BoundsRecorder {
+    bounds: RefCell<Vec<(i64, i64)>>,
+}
+
+impl SolutionCallback<DefaultBrancher> for &BoundsRecorder {
+    fn on_solution_callback(&self, _: &Solver, _: SolutionReference, _: &DefaultBrancher) {}
+
+    fn on_bounds_callback(&self, _: &Solver, lower_bound: i64, upper_bound: i64) {
+        self.bounds.borrow_mut().push((lower_bound, upper_bound));
+    }
+}
+
+#[test]
+fn bisection_minimises_and_reports_bounds() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+    let y = solver.new_bounded_integer(0, 5);
+    let z = solver.new_bounded_integer(0, 5);
+    let objective = solver.new_bounded_integer(0, 100);
+
+    solver
+        .add_constraint(constraints::less_than_or_equals(
+            [x.scaled(-1), y.scaled(-1)],
+            -4,
+        ))
+        .post()
+        .expect("no conflict");
+    solver
+        .add_constraint(constraints::less_than_or_equals(
+            [y.scaled(-1), z.scaled(-1)],
+            -3,
+        ))
+        .post()
+        .expect("no conflict");
+    solver
+        .add_constraint(constraints::equals(
+            [x.scaled(3), y.scaled(5), z.scaled(2), objective.scaled(-1)],
+            0,
+        ))
+        .post()
+        .expect("no conflict");
+
+    let mut brancher = solver.default_brancher();
+    let recorder = BoundsRecorder::default();
+    let result = solver.optimise(
+        &mut brancher,
+        &mut Indefinite,
+        Bisection::new(OptimisationDirection::Minimise, objective, &recorder),
+    );
+
+    let OptimisationResult::Optimal(solution) = result else {
+        panic!("expected an optimal solution");
+    };
+    // An optimal solution is x = 4, y = 0 and z = 3
+    assert_eq!(solution.get_integer_value(objective), 18);
+
+    // The reported bounds should only be tightened, and they should meet at the optimum
+    let bounds = recorder.bounds.into_inner();
+    assert!(bounds
+        .windows(2)
+        .all(|window| window[0].0 <= window[1].0 && window[0].1 >= window[1].1));
+    assert_eq!(bounds.last(), Some(&(18, 18)));
+}
+
+#[test]
+fn bisection_maximises_objective() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+    let y = solver.new_bounded_integer(0, 5);
+    let objective = solver.new_bounded_integer(-100, 100);
+
+    // x + y >= 6
+    solver
+        .add_constraint(constraints::less_than_or_equals(
+            [x.scaled(-1), y.scaled(-1)],
+            -6,
+        ))
+        .post()
+        .expect("no conflict");
+    // objective = 2x - y
+    solver
+        .add_constraint(constraints::equals(
+            [x.scaled(2), y.scaled(-1), objective.scaled(-1)],
+            0,
+        ))
+        .post()
+        .expect("no conflict");
+
+    let mut brancher = solver.default_brancher();
+    let callback: fn(&Solver, SolutionReference, &DefaultBrancher) = |_, _, _| {};
+    let result = solver.optimise(
+        &mut brancher,
+        &mut Indefinite,
+        Bisection::new(OptimisationDirection::Maximise, objective, callback),
+    );
+
+    let OptimisationResult::Optimal(solution) = result else {
+        panic!("expected an optimal solution");
+    };
+    // The optimal solution is x = 5 and y = 1
+    assert_eq!(solution.get_integer_value(objective), 9);
+}
+
+#[test]
+fn lexicographic_freezes_optimum_of_first_objective() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+    let y = solver.new_bounded_integer(0, 5);
+    let sum = solver.new_bounded_integer(0, 10);
+
+    // x + y >= 6
+    solver
+        .add_constraint(constraints::less_than_or_equals(
+            [x.scaled(-1), y.scaled(-1)],
+            -6,
+        ))
+        .post()
+        .expect("no conflict");
+    // sum = x + y
+    solver
+        .add_constraint(constraints::equals(
+            [x.scaled(1), y.scaled(1), sum.scaled(-1)],
+            0,
+        ))
+        .post()
+        .expect("no conflict");
+
+    let mut brancher = solver.default_brancher();
+    let callback: fn(&Solver, SolutionReference, &DefaultBrancher) = |_, _, _| {};
+    let result = solver.optimise(
+        &mut brancher,
+        &mut Indefinite,
+        Lexicographic::new(
+            [
+                (OptimisationDirection::Minimise, sum),
+                (OptimisationDirection::Maximise, x),
+            ],
+            callback,
+        ),
+    );
+
+    let OptimisationResult::Optimal(solution) = result else {
+        panic!("expected an optimal solution");
+    };
+    assert_eq!(solution.get_integer_value(sum), 6);
+    assert_eq!(solution.get_integer_value(x), 5);
+    assert_eq!(solution.get_integer_value(y), 1);
+}
+
+#[test]
+fn pareto_enumerates_non_dominated_solutions() {
+    let mut solver = Solver::default();
+    let x = solver.new_bounded_integer(0, 5);
+    let y = solver.new_bounded_integer(0, 5);
+
+    // x + y >= 4
+    solver
+        .add_constraint(constraints::less_than_or_equals(
+            [x.scaled(-1), y.scaled(-1)],
+            -4,
+        ))
+        .post()
+        .expect("no conflict");
+
+    let mut brancher = solver.default_brancher();
+    let callback: fn(&Solver, SolutionReference, &DefaultBrancher) = |_, _, _| {};
+    let mut pareto = Pareto::new(
+        [
+            (OptimisationDirection::Minimise, x),
+            (OptimisationDirection::Minimise, y),
+        ],
+        callback,
+    );
+    let result = solver.optimise(&mut brancher, &mut Indefinite, &mut pareto);
+    assert!(matches!(result, OptimisationResult::Optimal(_)));
+
+    // The front consists of the solutions for which x + y = 4
+    let mut front = pareto
+        .front()
+        .iter()
+        .map(|solution| (solution.get_integer_value(x), solution.get_integer_value(y)))
+        .collect::<Vec<_>>();
+    front.sort();
+    assert_eq!(front, vec![(0, 4), (1, 3), (2, 2), (3, 1), (4, 0)]);
+}
+
+#[test]
+fn pareto_concludes_proof_when_front_is_complete() {
+    let proof_path =
+        std::env::temp_dir().join(format!("pumpkin_pareto_proof_{}.drcp", std::process::id()));
+    let mut solver = Solver::with_options(SolverOptions {
+        proof_log: ProofLog::cp(&proof_path, drcp_format::Format::Text, true, true)
+            .expect("created proof"),
+        ..Default::default()
+    });
+    let x = solver.new_named_bounded_integer(0, 5, "x");
+    let y = solver.new_named_bounded_integer(0, 5, "y");
+
+    // x + y >= 4
+    solver
+        .add_constraint(constraints::less_than_or_equals(
+            [x.scaled(-1), y.scaled(-1)],
+            -4,
+        ))
+        .with_tag(NonZero::new(1).unwrap())
+        .post()
+        .expect("no conflict");
+
+    let mut brancher = solver.default_brancher();
+    let callback: fn(&Solver, SolutionReference, &DefaultBrancher) = |_, _, _| {};
+    let mut pareto = Pareto::new(
+        [
+            (OptimisationDirection::Minimise, x),
+            (OptimisationDirection::Minimise, y),
+        ],
+        callback,
+    );
+    let result = solver.optimise(&mut brancher, &mut Indefinite, &mut pareto);
+    assert!(matches!(result, OptimisationResult::Optimal(_)));
+
+    let proof = std::fs::File::open(&proof_path).expect("proof exists");
+    let mut reader =
+        ProofReader::with_format(drcp_format::Format::Text, proof, std::convert::identity);
+
+    let mut last_step = None;
+    while let Some(step) = reader.next_step().expect("valid proof step") {
+        last_step = Some(matches!(step, Step::Conclusion(Conclusion::Unsatisfiable)));
+    }
+    assert_eq!(Some(true), last_step);
+}